@@ -1,3 +1,5 @@
+use crate::application::agents::portfolio_cache::PortfolioCache;
+use crate::application::agents::proposal_dispatcher::{DispatchOutcome, ProposalDispatcher};
 use crate::application::market_data::candle_aggregator::CandleAggregator;
 use crate::application::market_data::spread_cache::SpreadCache;
 use crate::application::monitoring::connection_health_service::{
@@ -9,26 +11,58 @@ use crate::application::optimization::win_rate_provider::{StaticWinRateProvider,
 use crate::application::strategies::TradingStrategy;
 
 use crate::application::agents::trade_evaluator::TradeEvaluator;
+use crate::config::ProposalChannelPolicy;
 use crate::domain::ports::{ExecutionService, MarketDataService};
 use crate::domain::repositories::{CandleRepository, StrategyRepository};
+use crate::domain::trading::events::{DecisionEvent, DecisionKind, TradingEvent};
+use crate::domain::trading::time::{ms_to_datetime, now_ms};
 use crate::domain::trading::types::{Candle, MarketEvent, OrderSide, OrderStatus, TradeProposal};
 use rust_decimal::Decimal;
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 use std::path::PathBuf;
 use std::sync::{Arc, Mutex};
 use tokio::sync::broadcast;
-use tokio::sync::mpsc::{Receiver, Sender};
+use tokio::sync::mpsc::{self, Receiver, Sender};
 use tracing::{debug, error, info, instrument, warn};
 
+use crate::infrastructure::core::event_bus::EventBus;
+use crate::infrastructure::observability::Metrics;
+
 use crate::application::ml::data_collector::DataCollector;
 
 use crate::application::trading::symbol_context::SymbolContext;
 
-pub use crate::application::agents::analyst_config::AnalystConfig;
+pub use crate::application::agents::analyst_config::{AnalystConfig, TunedParams};
+
+/// Minimum interval between live benchmark price fetches for the relative stop
+const BENCHMARK_REFRESH_MS: i64 = 60_000;
+/// Delay before retrying a failed re-warmup, doubled on each further failure
+const REWARM_RETRY_MS: i64 = 60_000;
+/// Cap on the re-warmup retry backoff doublings (about 32 minutes)
+const REWARM_MAX_BACKOFF_SHIFT: u32 = 5;
+
+/// Re-warmup history requested for a symbol: `(symbol, start, end, generation)`
+type RewarmRequest = (
+    String,
+    chrono::DateTime<chrono::Utc>,
+    chrono::DateTime<chrono::Utc>,
+    u64,
+);
+
+/// History fetched in the background for a symbol's re-warmup
+struct RewarmResult {
+    symbol: String,
+    generation: u64,
+    /// End of the requested history window (ms), the base of the retry backoff
+    end_ms: i64,
+    bars: super::warmup_service::WarmupBars,
+}
 
 #[derive(Debug)]
 pub enum AnalystCommand {
     UpdateConfig(Box<AnalystConfig>),
+    /// Run one symbol with re-tuned parameters on top of the current default config
+    UpdateSymbolParams(String, Box<TunedParams>),
     ProcessNews(crate::domain::listener::NewsSignal),
 }
 
@@ -50,6 +84,7 @@ pub struct Analyst {
     execution_service: Arc<dyn ExecutionService>,
     default_strategy: Arc<dyn TradingStrategy>, // Fallback
     config: AnalystConfig,                      // Default config
+    symbol_configs: HashMap<String, AnalystConfig>, // Per-symbol overrides
     symbol_states: HashMap<String, SymbolContext>,
     candle_aggregator: CandleAggregator,
     win_rate_provider: Arc<dyn WinRateProvider>,
@@ -58,6 +93,9 @@ pub struct Analyst {
     trade_evaluator: TradeEvaluator,
     pipeline: super::candle_pipeline::CandlePipeline,
     warmup_service: super::warmup_service::WarmupService,
+    // Re-warmup history fetched in the background, applied from the run loop
+    rewarm_tx: mpsc::UnboundedSender<RewarmResult>,
+    rewarm_rx: mpsc::UnboundedReceiver<RewarmResult>,
     // Multi-timeframe configuration
     enabled_timeframes: Vec<crate::domain::market::timeframe::Timeframe>,
     cmd_rx: Receiver<AnalystCommand>,
@@ -66,6 +104,19 @@ pub struct Analyst {
     health_service: Arc<ConnectionHealthService>,
     market_data_online: bool,
     agent_registry: Arc<crate::application::monitoring::agent_status::AgentStatusRegistry>,
+    market_service: Arc<dyn MarketDataService>,
+    // Latest relative-stop benchmark price and when it was last refreshed (ms)
+    benchmark_price: Option<Decimal>,
+    benchmark_refreshed_at: i64,
+    event_bus: EventBus,
+    // Gate new entries on symbols whose warmup found no history
+    warmup_required: bool,
+    proposal_dispatcher: ProposalDispatcher,
+    portfolio_cache: PortfolioCache,
+    // Symbols whose feed was stopped; late quotes/candles for them are ignored
+    unsubscribed: HashSet<String>,
+    // Running default config, shared with the UI
+    config_view: Option<Arc<tokio::sync::RwLock<AnalystConfig>>>,
 }
 
 impl Analyst {
@@ -77,6 +128,8 @@ impl Analyst {
         default_strategy: Arc<dyn TradingStrategy>,
         dependencies: AnalystDependencies,
     ) -> Self {
+        Self::check_candle_history_cap(&config);
+
         // Default to Static 50% if not provided (Conservative baseline)
         let win_rate_provider = dependencies
             .win_rate_provider
@@ -111,11 +164,13 @@ impl Analyst {
         let enabled_timeframes = vec![crate::domain::market::timeframe::Timeframe::OneMin];
 
         // Initialize WarmupService
+        let (rewarm_tx, rewarm_rx) = mpsc::unbounded_channel();
         let warmup_service = super::warmup_service::WarmupService::new(
             dependencies.market_service.clone(),
             dependencies.strategy_repository.clone(),
             dependencies.ui_candle_tx.clone(),
-        );
+        )
+        .with_candle_repository(dependencies.candle_repository.clone());
 
         // Initialize CandlePipeline with its own instances
         let pipeline_cost_evaluator = CostEvaluator::with_spread_cache(
@@ -151,36 +206,127 @@ impl Analyst {
         };
 
         let pipeline = super::candle_pipeline::CandlePipeline::new(
-            dependencies.execution_service.clone(),
             dependencies.candle_repository.clone(),
             pipeline_trade_evaluator,
             data_collector,
         );
 
+        let candle_aggregator = CandleAggregator::new(dependencies.candle_repository.clone())
+            .with_gap_policy(config.candle_gap_policy, config.candle_gap_max_bars);
+
         Self {
             market_rx,
+            proposal_dispatcher: ProposalDispatcher::new(
+                proposal_tx.clone(),
+                ProposalChannelPolicy::default(),
+            ),
             proposal_tx,
+            portfolio_cache: PortfolioCache::new(dependencies.execution_service.clone()),
+            unsubscribed: HashSet::new(),
+            config_view: None,
             execution_service: dependencies.execution_service,
             default_strategy,
             config,
+            symbol_configs: HashMap::new(),
             symbol_states: HashMap::new(),
-            candle_aggregator: CandleAggregator::new(dependencies.candle_repository.clone()),
+            candle_aggregator,
             win_rate_provider,
             trade_evaluator,
             pipeline,
             warmup_service,
+            rewarm_tx,
+            rewarm_rx,
             enabled_timeframes,
             cmd_rx,
             news_handler: crate::application::agents::news_handler::NewsHandler::new(
                 crate::application::agents::signal_processor::SignalProcessor::new(
                     sizing_engine.clone(),
                 ),
+                crate::application::trading::trade_filter::TradeFilter::new(cost_evaluator),
             ),
             ui_candle_tx: dependencies.ui_candle_tx,
             health_service: dependencies.connection_health_service,
             market_data_online: true, // Default to true, will be updated by run loop
             agent_registry: dependencies.agent_registry,
+            market_service: dependencies.market_service,
+            benchmark_price: None,
+            benchmark_refreshed_at: 0,
+            event_bus: EventBus::new(),
+            warmup_required: false,
+        }
+    }
+
+    /// Count generated signals and filter rejections in the given metrics
+    pub fn with_metrics(mut self, metrics: Metrics) -> Self {
+        self.pipeline = self.pipeline.with_metrics(metrics);
+        self
+    }
+
+    /// Publish signal and proposal decisions to the given bus (audit log)
+    pub fn with_event_bus(mut self, event_bus: EventBus) -> Self {
+        self.pipeline = self.pipeline.with_event_bus(event_bus.clone());
+        self.event_bus = event_bus;
+        self
+    }
+
+    /// Secondary history source tried when the broker and the candle repository
+    /// have no warmup bars
+    pub fn with_warmup_fallback(mut self, service: Option<Arc<dyn MarketDataService>>) -> Self {
+        self.warmup_service = self.warmup_service.with_secondary_source(service);
+        self
+    }
+
+    /// Persist completed candles through a shared batching buffer (flushed on shutdown)
+    pub fn with_candle_write_buffer(
+        mut self,
+        buffer: Option<
+            Arc<crate::application::market_data::candle_write_buffer::CandleWriteBuffer>,
+        >,
+    ) -> Self {
+        if let Some(buffer) = buffer {
+            self.candle_aggregator = CandleAggregator::new(None)
+                .with_write_buffer(buffer)
+                .with_gap_policy(
+                    self.config.candle_gap_policy,
+                    self.config.candle_gap_max_bars,
+                );
         }
+        self
+    }
+
+    /// Fetch warmup history for at most `max_concurrency` symbols at a time when
+    /// several subscribe at once
+    pub fn with_warmup_concurrency(mut self, max_concurrency: usize) -> Self {
+        self.warmup_service = self.warmup_service.with_max_concurrency(max_concurrency);
+        self
+    }
+
+    /// Keep symbols out of new entries until a warmup succeeds instead of
+    /// trading on cold indicators
+    pub fn with_warmup_required(mut self, required: bool) -> Self {
+        self.warmup_required = required;
+        self
+    }
+
+    /// Reuse a portfolio snapshot younger than `staleness_ms` instead of asking the broker
+    /// on every candle, and block new entries once the last successful sync is older than
+    /// `max_age_ms` (0 = never block)
+    pub fn with_portfolio_cache(mut self, staleness_ms: i64, max_age_ms: i64) -> Self {
+        self.portfolio_cache = PortfolioCache::new(self.execution_service.clone())
+            .with_staleness(staleness_ms, max_age_ms);
+        self
+    }
+
+    /// Handling of proposals when the RiskManager channel is full
+    pub fn with_proposal_policy(mut self, policy: ProposalChannelPolicy) -> Self {
+        self.proposal_dispatcher = ProposalDispatcher::new(self.proposal_tx.clone(), policy);
+        self
+    }
+
+    /// Keep `view` in sync with the running default config (read by the UI)
+    pub fn with_config_view(mut self, view: Arc<tokio::sync::RwLock<AnalystConfig>>) -> Self {
+        self.config_view = Some(view);
+        self
     }
 
     #[doc(hidden)]
@@ -260,28 +406,38 @@ impl Analyst {
                     match res {
                         Some(event) => {
                             match event {
-                                MarketEvent::Quote {
-                                    symbol,
-                                    price,
-                                    quantity,
-                                    timestamp,
-                                } => {
-                                    if let Some(candle) = self.candle_aggregator.on_quote(&symbol, price, quantity, timestamp)
-                                    {
-                                        self.process_candle(candle).await;
-                                    }
-                                }
-                                MarketEvent::Candle(candle) => {
-                                    self.process_candle(candle).await;
+                                MarketEvent::Quote { .. }
+                                | MarketEvent::Candle(_)
+                                | MarketEvent::SymbolUnsubscription { .. } => {
+                                    self.handle_market_data(event).await;
                                 }
                                 MarketEvent::SymbolSubscription { symbol } => {
-                                    info!("Analyst: Received immediate warmup request for {}", symbol);
-                                    self.ensure_symbol_initialized(&symbol, chrono::Utc::now()).await;
+                                    // Subscriptions arrive in a burst: warm them up as one batch
+                                    let mut symbols = vec![symbol];
+                                    let mut next_event = None;
+                                    while let Ok(event) = self.market_rx.try_recv() {
+                                        match event {
+                                            MarketEvent::SymbolSubscription { symbol } => symbols.push(symbol),
+                                            other => {
+                                                next_event = Some(other);
+                                                break;
+                                            }
+                                        }
+                                    }
+                                    for symbol in &symbols {
+                                        self.unsubscribed.remove(symbol);
+                                    }
+                                    info!("Analyst: Received immediate warmup request for {} symbol(s)", symbols.len());
+                                    self.ensure_symbols_initialized(&symbols, chrono::Utc::now()).await;
+                                    if let Some(event) = next_event {
+                                        self.handle_market_data(event).await;
+                                    }
                                 }
                             }
                         }
                         None => {
                             info!("Analyst: Market event channel closed. Exiting main loop.");
+                            self.candle_aggregator.flush().await;
                             break;
                         }
                     }
@@ -290,6 +446,7 @@ impl Analyst {
                 // Handle Health Events (using persistent subscriber)
                 Ok(health_event) = health_rx.recv() => {
                     if health_event.component == "MarketData" {
+                         #[allow(clippy::collapsible_match)]
                          match health_event.status {
                              ConnectionStatus::Online => {
                                  if !self.market_data_online {
@@ -317,6 +474,9 @@ impl Analyst {
                      }
                 } => {
                     debug!("Analyst: Received Order Update for {}: {:?}", order_update.symbol, order_update.status);
+                    if order_update.status == OrderStatus::Filled {
+                        self.portfolio_cache.invalidate();
+                    }
 
                     if let Some(context) = self.symbol_states.get_mut(&order_update.symbol) {
                          // If order is Filled or Canceled, we clear the pending state immediately
@@ -328,8 +488,8 @@ impl Analyst {
                                      side = ?order_update.side,
                                      "Analyst: Order FILLED. Updating last_entry_time."
                                  );
-                                 context.position_manager.clear_pending();
-                                 if order_update.side == OrderSide::Buy {
+                                 context.position_manager.on_fill(order_update.side);
+                                 if context.is_entry(order_update.side) {
                                      context.last_entry_time = Some(order_update.timestamp.timestamp_millis());
                                  }
                              }
@@ -347,22 +507,76 @@ impl Analyst {
                     }
                 }
 
+                Some(result) = self.rewarm_rx.recv() => {
+                    self.finish_rewarm(result).await;
+                }
                 Some(cmd) = self.cmd_rx.recv() => {
                     match cmd {
                         AnalystCommand::UpdateConfig(new_config) => {
                             info!("Analyst: Updating configuration...");
+                            let structural_change = self.config.has_structural_change(&new_config);
+                            let mode_changed = self.config.strategy_mode != new_config.strategy_mode;
+                            let strategy_changed = self.config.has_strategy_change(&new_config);
+                            if self.config.relative_stop_benchmark != new_config.relative_stop_benchmark {
+                                self.benchmark_price = None;
+                                self.benchmark_refreshed_at = 0;
+                            }
                             self.config = *new_config;
+                            if let Some(view) = &self.config_view {
+                                *view.write().await = self.config.clone();
+                            }
+                            // Overrides keep their tuned parameters on top of the new defaults
+                            for symbol_config in self.symbol_configs.values_mut() {
+                                let mut rebased = self.config.clone();
+                                rebased.apply_tuned_params(symbol_config);
+                                *symbol_config = rebased;
+                            }
+                            Self::check_candle_history_cap(&self.config);
+                            self.candle_aggregator.set_gap_policy(self.config.candle_gap_policy, self.config.candle_gap_max_bars);
+                            if mode_changed {
+                                info!("Analyst: Strategy mode changed to {:?}", self.config.strategy_mode);
+                                self.default_strategy = crate::application::strategies::StrategyFactory::create(self.config.strategy_mode, &self.config);
+                            }
+                            let now = chrono::Utc::now();
+                            let mut rewarms = Vec::new();
                             // Propagate to all existing symbol contexts
-                            for context in self.symbol_states.values_mut() {
-                                context.config = self.config.clone();
-                                // Check if structural parameters changed (periods)
-                                if context.config.rsi_period != self.config.rsi_period ||
-                                   context.config.fast_sma_period != self.config.fast_sma_period ||
-                                   context.config.slow_sma_period != self.config.slow_sma_period {
-                                     warn!("Analyst: Structural config change detected. Re-initializing Feature Service.");
-                                     context.feature_service = Box::new(crate::application::monitoring::feature_engineering_service::TechnicalFeatureEngineeringService::new(&self.config));
+                            for (symbol, context) in self.symbol_states.iter_mut() {
+                                let config = self.symbol_configs.get(symbol).unwrap_or(&self.config);
+                                context.config = config.clone();
+                                // Strategies capture their parameters at construction: rebuild so edits apply
+                                if strategy_changed {
+                                    let mode = if mode_changed { self.config.strategy_mode } else { context.active_strategy_mode };
+                                    context.strategy = crate::application::strategies::StrategyFactory::create(mode, config);
+                                    context.active_strategy_mode = mode;
+                                    context.signal_generator.set_confirmation(SymbolContext::build_confirm_strategy(config));
+                                }
+                                if structural_change || context.missing_features() {
+                                    warn!("Analyst [{}]: Structural config change detected. Re-warming indicators.", symbol);
+                                    rewarms.push(Self::request_rewarm(context, symbol, now));
                                 }
                             }
+                            Self::spawn_rewarm(&self.warmup_service, &self.rewarm_tx, rewarms);
+                        }
+                        AnalystCommand::UpdateSymbolParams(symbol, params) => {
+                            info!("Analyst [{}]: Updating symbol parameters...", symbol);
+                            let mut new_config = self.config.clone();
+                            params.apply_to(&mut new_config);
+                            let current = self.symbol_configs.get(&symbol).unwrap_or(&self.config);
+                            let structural_change = current.has_structural_change(&new_config);
+                            let strategy_changed = current.has_strategy_change(&new_config);
+                            if let Some(context) = self.symbol_states.get_mut(&symbol) {
+                                context.config = new_config.clone();
+                                if strategy_changed {
+                                    context.strategy = crate::application::strategies::StrategyFactory::create(context.active_strategy_mode, &new_config);
+                                    context.signal_generator.set_confirmation(SymbolContext::build_confirm_strategy(&new_config));
+                                }
+                                if structural_change || context.missing_features() {
+                                    warn!("Analyst [{}]: Structural config change detected. Re-warming indicators.", symbol);
+                                    let request = Self::request_rewarm(context, &symbol, chrono::Utc::now());
+                                    Self::spawn_rewarm(&self.warmup_service, &self.rewarm_tx, vec![request]);
+                                }
+                            }
+                            self.symbol_configs.insert(symbol, new_config);
                         }
                         AnalystCommand::ProcessNews(signal) => {
                             info!("Analyst: Received News Signal for {}: {:?} - {}", signal.symbol, signal.sentiment, signal.headline);
@@ -398,6 +612,212 @@ impl Analyst {
         .await
     }
 
+    /// Warn when the candle history is too short for the configured strategies
+    fn check_candle_history_cap(config: &AnalystConfig) {
+        let lookback = config.max_candle_lookback();
+        if config.candle_history_cap() < lookback {
+            warn!(
+                "Analyst: CANDLE_HISTORY_LEN={} is shorter than the largest strategy lookback ({}). Those strategies will not see enough history.",
+                config.candle_history_cap(),
+                lookback
+            );
+        }
+    }
+
+    /// Feed a quote or candle into the candle pipeline
+    async fn handle_market_data(&mut self, event: MarketEvent) {
+        match event {
+            MarketEvent::Quote { ref symbol, .. } if self.unsubscribed.contains(symbol) => {}
+            MarketEvent::Candle(ref candle) if self.unsubscribed.contains(&candle.symbol) => {}
+            MarketEvent::Quote {
+                symbol,
+                price,
+                quantity,
+                timestamp,
+            } => {
+                if let Some(candle) = self
+                    .candle_aggregator
+                    .on_quote(&symbol, price, quantity, timestamp)
+                {
+                    for candle in self.candle_aggregator.sequence(candle) {
+                        self.process_candle(candle).await;
+                    }
+                }
+            }
+            MarketEvent::Candle(candle) => {
+                for candle in self.candle_aggregator.sequence(candle) {
+                    self.process_candle(candle).await;
+                }
+            }
+            MarketEvent::SymbolSubscription { symbol } => {
+                self.unsubscribed.remove(&symbol);
+                self.ensure_symbol_initialized(&symbol, chrono::Utc::now())
+                    .await;
+            }
+            MarketEvent::SymbolUnsubscription { symbol } => {
+                // Never drop the state managing an open position (stops, trailing)
+                let (portfolio, _) = self.portfolio_cache.get(now_ms()).await;
+                match portfolio {
+                    Some(pf)
+                        if pf
+                            .positions
+                            .get(&symbol)
+                            .is_none_or(|p| p.quantity.is_zero()) =>
+                    {
+                        self.release_symbol(&symbol)
+                    }
+                    Some(_) => warn!(
+                        "Analyst [{}]: Unsubscribe ignored, position still open",
+                        symbol
+                    ),
+                    None => warn!(
+                        "Analyst [{}]: Unsubscribe ignored, cannot confirm there is no position",
+                        symbol
+                    ),
+                }
+            }
+        }
+    }
+
+    /// Free everything kept for a symbol whose feed was stopped
+    fn release_symbol(&mut self, symbol: &str) {
+        self.unsubscribed.insert(symbol.to_string());
+        self.candle_aggregator.remove_symbol(symbol);
+        if self.symbol_states.remove(symbol).is_some() {
+            info!("Analyst [{}]: Unsubscribed, symbol state released", symbol);
+        }
+    }
+
+    /// Gates new entries on `symbol` and prepares a re-warmup of its indicators from
+    /// recent history, superseding any fetch still running. Pass the result to
+    /// `spawn_rewarm`.
+    fn request_rewarm(
+        context: &mut SymbolContext,
+        symbol: &str,
+        end: chrono::DateTime<chrono::Utc>,
+    ) -> RewarmRequest {
+        context.rewarm_pending = true;
+        context.rewarm_in_flight = true;
+        context.rewarm_generation += 1;
+        let start = super::warmup_service::WarmupService::warmup_start(&context.config, end);
+        (symbol.to_string(), start, end, context.rewarm_generation)
+    }
+
+    /// Fetches re-warmup history on a background task, at most the warmup concurrency
+    /// at a time, so candles keep flowing meanwhile; `finish_rewarm` applies each result
+    /// from the run loop.
+    fn spawn_rewarm(
+        warmup_service: &super::warmup_service::WarmupService,
+        rewarm_tx: &mpsc::UnboundedSender<RewarmResult>,
+        requests: Vec<RewarmRequest>,
+    ) {
+        if requests.is_empty() {
+            return;
+        }
+        let warmup_service = warmup_service.clone();
+        let rewarm_tx = rewarm_tx.clone();
+        tokio::spawn(async move {
+            let windows: HashMap<String, (u64, i64)> = requests
+                .iter()
+                .map(|(symbol, _, end, generation)| {
+                    (symbol.clone(), (*generation, end.timestamp_millis()))
+                })
+                .collect();
+            let fetched = warmup_service
+                .fetch_warmup_bars_batch(
+                    requests
+                        .into_iter()
+                        .map(|(symbol, start, end, _)| (symbol, start, end))
+                        .collect(),
+                )
+                .await;
+            for (symbol, bars) in fetched {
+                let Some(&(generation, end_ms)) = windows.get(&symbol) else {
+                    continue;
+                };
+                let _ = rewarm_tx.send(RewarmResult {
+                    symbol,
+                    generation,
+                    end_ms,
+                    bars,
+                });
+            }
+        });
+    }
+
+    /// Rebuilds a symbol's indicators from the fetched re-warmup history.
+    ///
+    /// The indicators are only reset once the history was fetched; on failure they are
+    /// kept and the next attempt is delayed with an exponential backoff. New entries stay
+    /// gated (`rewarm_pending`) until the warmup succeeds.
+    async fn finish_rewarm(&mut self, result: RewarmResult) {
+        let symbol = result.symbol;
+        let Some(context) = self.symbol_states.get_mut(&symbol) else {
+            return;
+        };
+        if result.generation != context.rewarm_generation {
+            debug!(
+                "Analyst [{}]: Dropping superseded re-warmup history",
+                symbol
+            );
+            return;
+        }
+        context.rewarm_in_flight = false;
+
+        let Some(bars) = result.bars else {
+            let delay = REWARM_RETRY_MS << context.rewarm_failures.min(REWARM_MAX_BACKOFF_SHIFT);
+            context.rewarm_failures = context.rewarm_failures.saturating_add(1);
+            context.rewarm_retry_at = result.end_ms + delay;
+            warn!(
+                "Analyst [{}]: Re-warmup failed. New entries gated until it succeeds, retrying in {}s.",
+                symbol,
+                delay / 1000
+            );
+            return;
+        };
+
+        context.reset_indicators();
+        self.warmup_service
+            .apply_warmup_bars(context, &symbol, Some(bars))
+            .await;
+        context.rewarm_pending = false;
+        context.rewarm_failures = 0;
+        context.rewarm_retry_at = 0;
+        info!("Analyst [{}]: Re-warmup complete. Trading resumed.", symbol);
+    }
+
+    /// Latest benchmark price for the relative stop: taken from the benchmark's own
+    /// candles when it is streamed, otherwise fetched from market data at most once
+    /// per [`BENCHMARK_REFRESH_MS`].
+    async fn refresh_benchmark_price(&mut self, candle: &Candle) -> Option<Decimal> {
+        let benchmark = self.config.relative_stop_benchmark.clone()?;
+
+        if candle.symbol == benchmark {
+            self.benchmark_price = Some(candle.close);
+            self.benchmark_refreshed_at = candle.timestamp;
+        } else if candle.timestamp - self.benchmark_refreshed_at >= BENCHMARK_REFRESH_MS {
+            // Stamp before fetching so a failing feed is not hammered on every candle
+            self.benchmark_refreshed_at = candle.timestamp;
+            match self
+                .market_service
+                .get_prices(vec![benchmark.clone()])
+                .await
+            {
+                Ok(prices) => {
+                    if let Some(price) = prices.get(&benchmark) {
+                        self.benchmark_price = Some(*price);
+                    }
+                }
+                Err(e) => warn!(
+                    "Analyst: Failed to fetch benchmark {} price: {}",
+                    benchmark, e
+                ),
+            }
+        }
+
+        self.benchmark_price
+    }
+
     #[instrument(skip(self, candle), fields(symbol = %candle.symbol))]
     async fn process_candle(&mut self, candle: crate::domain::trading::types::Candle) {
         let symbol = candle.symbol.clone();
@@ -418,12 +838,26 @@ impl Analyst {
             let _ = tx.send(candle.clone());
         }
 
+        // Retry proposals parked while the RiskManager was backed up
+        for proposal in self.proposal_dispatcher.flush_pending() {
+            info!(
+                "Analyst [{}]: Parked proposal sent to RiskManager ✓",
+                proposal.symbol
+            );
+            self.event_bus
+                .publish(TradingEvent::Decision(DecisionEvent::for_proposal(
+                    DecisionKind::ProposalSent,
+                    &proposal,
+                    proposal.reason.clone(),
+                )))
+                .await;
+        }
+
         // 1. Ensure symbol context is initialized
-        let timestamp_dt = chrono::DateTime::from_timestamp(candle.timestamp / 1000, 0)
-            .unwrap_or_default()
-            .with_timezone(&chrono::Utc);
+        let timestamp_dt = ms_to_datetime(candle.timestamp);
 
         self.ensure_symbol_initialized(&symbol, timestamp_dt).await;
+        let benchmark_price = self.refresh_benchmark_price(&candle).await;
 
         let context = match self.symbol_states.get_mut(&symbol) {
             Some(ctx) => ctx,
@@ -437,10 +871,23 @@ impl Analyst {
         };
 
         // Reset config to default to prevent regime-based config drift
-        context.config = self.config.clone();
+        context.config = self
+            .symbol_configs
+            .get(&symbol)
+            .unwrap_or(&self.config)
+            .clone();
+
+        // Retry a failed re-warmup before trusting the indicators again
+        if context.rewarm_pending
+            && !context.rewarm_in_flight
+            && candle.timestamp >= context.rewarm_retry_at
+        {
+            let request = Self::request_rewarm(context, &symbol, timestamp_dt);
+            Self::spawn_rewarm(&self.warmup_service, &self.rewarm_tx, vec![request]);
+        }
 
         // 2. Get portfolio for pipeline context
-        let portfolio = self.execution_service.get_portfolio().await.ok();
+        let (portfolio, portfolio_too_old) = self.portfolio_cache.get(candle.timestamp).await;
 
         // 3. Build pipeline context
         let mut pipeline_ctx = super::candle_pipeline::PipelineContext {
@@ -448,22 +895,99 @@ impl Analyst {
             candle: &candle,
             context,
             portfolio: portfolio.as_ref(),
+            benchmark_price,
         };
 
         // 4. Process through pipeline (6 discrete stages)
         if let Some(proposal) = self.pipeline.process(&mut pipeline_ctx).await {
+            // Exits (selling a long or covering a short) are never gated;
+            // entries need a recent portfolio sync
+            let is_entry = proposal.increases_exposure(pipeline_ctx.context.position_quantity);
+            if is_entry && portfolio_too_old {
+                warn!(
+                    "Analyst [{}]: {:?} entry dropped - portfolio not synced for over {}ms.",
+                    symbol,
+                    proposal.side,
+                    self.portfolio_cache.max_age_ms()
+                );
+                self.event_bus
+                    .publish(TradingEvent::Decision(
+                        DecisionEvent::for_proposal(
+                            DecisionKind::SignalFiltered,
+                            &proposal,
+                            proposal.reason.clone(),
+                        )
+                        .with_blocked_by("stale_portfolio"),
+                    ))
+                    .await;
+                return;
+            }
+
+            // Exits are never gated; entries wait for indicators to be re-warmed
+            if is_entry && pipeline_ctx.context.rewarm_pending {
+                warn!(
+                    "Analyst [{}]: {:?} entry dropped - re-warmup pending.",
+                    symbol, proposal.side
+                );
+                self.event_bus
+                    .publish(TradingEvent::Decision(
+                        DecisionEvent::for_proposal(
+                            DecisionKind::SignalFiltered,
+                            &proposal,
+                            proposal.reason.clone(),
+                        )
+                        .with_blocked_by("rewarm_pending"),
+                    ))
+                    .await;
+                return;
+            }
+
             // 5. Send proposal to risk manager
-            match self.proposal_tx.try_send(proposal) {
-                Ok(_) => {
+            let sent = DecisionEvent::for_proposal(
+                DecisionKind::ProposalSent,
+                &proposal,
+                proposal.reason.clone(),
+            );
+            match self.proposal_dispatcher.dispatch(proposal, !is_entry).await {
+                DispatchOutcome::Sent => {
                     info!("Analyst [{}]: Proposal sent to RiskManager ✓", symbol);
+                    self.event_bus.publish(TradingEvent::Decision(sent)).await;
                 }
-                Err(tokio::sync::mpsc::error::TrySendError::Full(_)) => {
+                DispatchOutcome::Coalesced(replaced) => {
                     warn!(
-                        "Analyst [{}]: Proposal channel FULL - RiskManager slow. Backpressure applied, proposal dropped.",
+                        "Analyst [{}]: Proposal channel FULL - RiskManager slow. Proposal parked until capacity frees up.",
                         symbol
                     );
+                    if let Some(replaced) = replaced {
+                        self.event_bus
+                            .publish(TradingEvent::Decision(
+                                DecisionEvent::for_proposal(
+                                    DecisionKind::SignalFiltered,
+                                    &replaced,
+                                    replaced.reason.clone(),
+                                )
+                                .with_blocked_by("proposal_coalesced"),
+                            ))
+                            .await;
+                    }
                 }
-                Err(tokio::sync::mpsc::error::TrySendError::Closed(_)) => {
+                DispatchOutcome::Dropped => {
+                    warn!(
+                        "Analyst [{}]: Proposal channel FULL - RiskManager slow. Backpressure applied, proposal dropped ({:?}).",
+                        symbol,
+                        self.proposal_dispatcher.policy()
+                    );
+                    let dropped = DecisionEvent {
+                        kind: DecisionKind::SignalFiltered,
+                        ..sent
+                    };
+                    self.event_bus
+                        .publish(TradingEvent::Decision(
+                            dropped.with_blocked_by("proposal_backpressure"),
+                        ))
+                        .await;
+                }
+                DispatchOutcome::Closed => {
                     error!(
                         "Analyst [{}]: Proposal channel CLOSED. Shutting down.",
                         symbol
@@ -489,7 +1013,25 @@ impl Analyst {
         symbol: &str,
         end_time: chrono::DateTime<chrono::Utc>,
     ) {
-        if !self.symbol_states.contains_key(symbol) {
+        self.ensure_symbols_initialized(&[symbol.to_string()], end_time)
+            .await;
+    }
+
+    /// Initialize every symbol not seen yet, fetching their warmup history
+    /// concurrently (bounded by the warmup concurrency) instead of one by one.
+    #[doc(hidden)]
+    #[instrument(skip(self, symbols), fields(count = symbols.len()))]
+    pub async fn ensure_symbols_initialized(
+        &mut self,
+        symbols: &[String],
+        end_time: chrono::DateTime<chrono::Utc>,
+    ) {
+        let mut contexts: Vec<(String, SymbolContext)> = Vec::new();
+        for symbol in symbols {
+            if self.symbol_states.contains_key(symbol) || contexts.iter().any(|(s, _)| s == symbol)
+            {
+                continue;
+            }
             info!(
                 symbol = %symbol,
                 warmup_end = %end_time,
@@ -497,65 +1039,106 @@ impl Analyst {
             );
             let (strategy, config) = self
                 .warmup_service
-                .resolve_strategy(symbol, self.default_strategy.clone(), &self.config)
+                .resolve_strategy(
+                    symbol,
+                    self.default_strategy.clone(),
+                    self.symbol_configs.get(symbol).unwrap_or(&self.config),
+                )
                 .await;
 
-            let mut context = SymbolContext::new(
+            let context = SymbolContext::new(
                 config,
                 strategy,
                 self.win_rate_provider.clone(),
                 self.enabled_timeframes.clone(),
             );
+            contexts.push((symbol.clone(), context));
+        }
+        if contexts.is_empty() {
+            return;
+        }
+
+        // WARMUP: Fetch historical data to initialize indicators
+        let requests = contexts
+            .iter()
+            .map(|(symbol, context)| {
+                (
+                    symbol.clone(),
+                    super::warmup_service::WarmupService::warmup_start(&context.config, end_time),
+                    end_time,
+                )
+            })
+            .collect();
+        let mut fetched = self.warmup_service.fetch_warmup_bars_batch(requests).await;
 
-            // WARMUP: Fetch historical data to initialize indicators
+        for (symbol, mut context) in contexts {
+            let bars = fetched.remove(&symbol).flatten();
             self.warmup_service
-                .warmup_context(&mut context, symbol, end_time)
+                .apply_warmup_bars(&mut context, &symbol, bars)
                 .await;
+            self.finish_symbol_initialization(&symbol, context).await;
+        }
+    }
 
-            // --- STARTUP RECOVERY: Restore last_entry_time for existing positions ---
-            if let Ok(portfolio) = self.execution_service.get_portfolio().await
-                && let Some(_pos) = portfolio
-                    .positions
-                    .get(symbol)
-                    .filter(|p| p.quantity > Decimal::ZERO)
-            {
-                debug!(
-                    "Analyst [{}]: Recovering entry time for existing position...",
-                    symbol
-                );
-                // Try to find the last filled buy order from today
-                if let Ok(orders) = self.execution_service.get_today_orders().await {
-                    let last_buy = orders
-                        .iter()
-                        .filter(|o| {
-                            o.symbol == symbol
-                                && o.side == OrderSide::Buy
-                                && o.status == OrderStatus::Filled
-                        })
-                        .max_by_key(|o| o.timestamp);
-
-                    if let Some(order) = last_buy {
-                        context.last_entry_time = Some(order.timestamp);
-                        info!(
-                            "Analyst [{}]: Recovered last_entry_time: {} (from today's orders)",
-                            symbol,
-                            context.last_entry_time.unwrap()
-                        );
-                    } else {
-                        // If no order today, we use current time as a safety buffer
-                        // to prevent immediate flip on bot restart.
-                        context.last_entry_time = Some(chrono::Utc::now().timestamp_millis());
-                        warn!(
-                            "Analyst [{}]: No buy order found today for existing position. Using current time as safety buffer for min_hold_time.",
-                            symbol
-                        );
-                    }
+    async fn finish_symbol_initialization(&mut self, symbol: &str, mut context: SymbolContext) {
+        if self.warmup_required && !context.warmup_succeeded {
+            // Never enter on cold indicators: retried on each candle like a re-warmup
+            context.rewarm_pending = true;
+            warn!(
+                "Analyst [{}]: Warmup failed on every source. New entries gated until it succeeds.",
+                symbol
+            );
+        }
+
+        // --- STARTUP RECOVERY: Restore last_entry_time for existing positions ---
+        if let Ok(portfolio) = self.execution_service.get_portfolio().await
+            && let Some(pos) = portfolio
+                .positions
+                .get(symbol)
+                .filter(|p| !p.quantity.is_zero())
+        {
+            // A long was opened by a buy, a short by a sell
+            let entry_side = if pos.quantity > Decimal::ZERO {
+                OrderSide::Buy
+            } else {
+                OrderSide::Sell
+            };
+            debug!(
+                "Analyst [{}]: Recovering entry time for existing position...",
+                symbol
+            );
+            // Try to find the last filled entry order from today
+            if let Ok(orders) = self.execution_service.get_today_orders().await {
+                let last_entry = orders
+                    .iter()
+                    .filter(|o| {
+                        o.symbol == symbol
+                            && o.side == entry_side
+                            && o.status == OrderStatus::Filled
+                    })
+                    .max_by_key(|o| o.timestamp);
+
+                if let Some(order) = last_entry {
+                    context.last_entry_time = Some(order.timestamp);
+                    info!(
+                        "Analyst [{}]: Recovered last_entry_time: {} (from today's orders)",
+                        symbol,
+                        context.last_entry_time.unwrap()
+                    );
+                } else {
+                    // If no order today, we use current time as a safety buffer
+                    // to prevent immediate flip on bot restart.
+                    context.last_entry_time = Some(chrono::Utc::now().timestamp_millis());
+                    warn!(
+                        "Analyst [{}]: No {:?} order found today for existing position. Using current time as safety buffer for min_hold_time.",
+                        symbol, entry_side
+                    );
                 }
             }
-            // -----------------------------------------------------------------------
-
-            self.symbol_states.insert(symbol.to_string(), context);
         }
+        // -----------------------------------------------------------------------
+
+        self.symbol_states.insert(symbol.to_string(), context);
     }
     #[doc(hidden)]
     #[instrument(skip(self, signal), fields(symbol = %signal.symbol, sentiment = ?signal.sentiment))]
@@ -591,6 +1174,13 @@ impl Analyst {
             return;
         }
 
+        if self
+            .news_handler
+            .is_duplicate(&self.config, &signal, timestamp.timestamp_millis())
+        {
+            return;
+        }
+
         use super::news_handler::{NewsAction, process_bearish_news, send_news_proposal};
 
         match signal.sentiment {
@@ -603,7 +1193,7 @@ impl Analyst {
                         &signal,
                         context,
                         price,
-                        timestamp.timestamp(),
+                        timestamp.timestamp_millis(),
                     )
                     .await;
 
@@ -626,7 +1216,7 @@ impl Analyst {
                         context,
                         (pos.quantity, pos.average_price),
                         price,
-                        timestamp.timestamp(),
+                        timestamp.timestamp_millis(),
                     );
 
                     match action {
@@ -1,9 +1,17 @@
+use crate::domain::market::market_regime::RegimeDetectionMethod;
+use crate::domain::market::strategy_config::{CandleGapPolicy, TakeProfitMode};
+use crate::domain::market::timeframe::Timeframe;
+use crate::domain::market::trading_windows::TradingWindows;
+use crate::domain::risk::session_boundary::TradingDayBoundary;
 use crate::domain::trading::fee_model::{ConstantFeeModel, FeeModel};
 use rust_decimal::Decimal;
 use rust_decimal_macros::dec;
 use serde::{Deserialize, Serialize};
 use std::sync::Arc;
 
+/// Auto-sized candle history keeps this many times the largest strategy lookback
+pub const CANDLE_HISTORY_SAFETY_FACTOR: usize = 2;
+
 fn default_fee_model() -> Arc<dyn FeeModel> {
     Arc::new(ConstantFeeModel::new(Decimal::ZERO, Decimal::ZERO))
 }
@@ -73,6 +81,142 @@ pub struct AnalystConfig {
     pub orderflow_volume_profile_lookback: usize,
     pub ensemble_weights: Option<std::collections::HashMap<String, f64>>,
     pub ensemble_voting_threshold: Decimal,
+    // Trading hours: new entries are suppressed outside these windows
+    #[serde(default)]
+    pub trading_windows: TradingWindows,
+    // News: repeated (symbol, headline) pairs within this window are ignored (0 = disabled)
+    #[serde(default = "default_news_dedup_window_seconds")]
+    pub news_dedup_window_seconds: u64,
+    // News-driven entries: size = strategy size * multiplier, optionally capped in USD,
+    // never above max_position_size_pct of equity
+    #[serde(default = "default_news_trade_size_multiplier")]
+    pub news_trade_size_multiplier: Decimal,
+    #[serde(default)]
+    pub news_trade_max_value_usd: Option<Decimal>,
+    // News: new buys are blocked for this long after a news panic-sell (0 = disabled)
+    #[serde(default)]
+    pub news_reentry_cooldown_seconds: u64,
+    // Regime detection algorithm used by each symbol's detector
+    #[serde(default)]
+    pub regime_detection_method: RegimeDetectionMethod,
+    // Prices used for the rolling Hurst exponent feature
+    #[serde(default = "default_hurst_lookback")]
+    pub hurst_lookback: usize,
+    // Relative-strength exit: sell a long that lags this benchmark by more than
+    // relative_stop_pct since entry (None = disabled)
+    #[serde(default)]
+    pub relative_stop_benchmark: Option<String>,
+    #[serde(default = "default_relative_stop_pct")]
+    pub relative_stop_pct: Decimal,
+    // Pyramiding: add to a winner every pyramid_trigger_pct gained since the initial
+    // entry, each add half the previous one (0 = disabled)
+    #[serde(default)]
+    pub pyramid_max_adds: usize,
+    #[serde(default = "default_pyramid_trigger_pct")]
+    pub pyramid_trigger_pct: Decimal,
+    // Sells (strategy exits, trailing/relative stops) skip the expectancy and
+    // profitability filters so costs never keep a losing position open
+    #[serde(default = "default_exits_bypass_cost_filters")]
+    pub exits_bypass_cost_filters: bool,
+    // Sell signals open short positions when flat; buys cover them
+    #[serde(default)]
+    pub allow_shorts: bool,
+    // Buys also need the trend SMA on trend_timeframe to be rising
+    #[serde(default)]
+    pub require_htf_confirmation: bool,
+    #[serde(default = "default_trend_timeframe")]
+    pub trend_timeframe: Timeframe,
+    // New entries per symbol per trading day (0 = unlimited); exits are never capped
+    #[serde(default)]
+    pub max_trades_per_symbol_per_day: u32,
+    // New entries are blocked this many minutes after the 09:30 New York open (0 = disabled)
+    #[serde(default)]
+    pub open_blackout_minutes: u32,
+    // Where the per-symbol entry count restarts
+    #[serde(default)]
+    pub trading_day_boundary: TradingDayBoundary,
+    // Buys on a symbol are blocked for this long after its trailing or relative stop fires (0 = disabled)
+    #[serde(default)]
+    pub post_stop_cooldown_seconds: u64,
+    // Trailing-stop multiplier scaled by ATR vs its recent average, within these bounds
+    // (wider when volatile, tighter when calm; 1/1 = fixed multiplier)
+    #[serde(default = "default_trailing_stop_vol_scale")]
+    pub trailing_stop_vol_scale_min: Decimal,
+    #[serde(default = "default_trailing_stop_vol_scale")]
+    pub trailing_stop_vol_scale_max: Decimal,
+    // Candles kept per symbol for strategies that read raw history
+    // (0 = largest strategy lookback x CANDLE_HISTORY_SAFETY_FACTOR)
+    #[serde(default)]
+    pub candle_history_len: usize,
+    // Fill for missing bars (see CandleGapPolicy); gaps longer than candle_gap_max_bars
+    // are session breaks and stay unfilled
+    #[serde(default)]
+    pub candle_gap_policy: CandleGapPolicy,
+    #[serde(default = "default_candle_gap_max_bars")]
+    pub candle_gap_max_bars: usize,
+    // Reward:risk assumed while a symbol has fewer than cold_start_min_trades closed trades
+    // and no measured ratio, so its first entries can pass the expectancy filter (0 = disabled)
+    #[serde(default = "default_cold_start_reward_risk_ratio")]
+    pub cold_start_reward_risk_ratio: Decimal,
+    #[serde(default = "default_cold_start_min_trades")]
+    pub cold_start_min_trades: usize,
+    // Secondary strategy that must agree with the primary before an entry (primary + confirm)
+    #[serde(default)]
+    pub confirm_strategy: Option<crate::domain::market::strategy_config::StrategyMode>,
+    // Partial take-profit trigger: take_profit_pct above entry, or take_profit_atr_multiplier
+    // x ATR above entry so volatile names get wider targets
+    #[serde(default)]
+    pub take_profit_mode: TakeProfitMode,
+    #[serde(default = "default_take_profit_atr_multiplier")]
+    pub take_profit_atr_multiplier: Decimal,
+}
+
+fn default_news_dedup_window_seconds() -> u64 {
+    300
+}
+
+fn default_news_trade_size_multiplier() -> Decimal {
+    Decimal::ONE
+}
+
+fn default_hurst_lookback() -> usize {
+    50
+}
+
+fn default_relative_stop_pct() -> Decimal {
+    dec!(0.05)
+}
+
+fn default_pyramid_trigger_pct() -> Decimal {
+    dec!(0.02)
+}
+
+fn default_exits_bypass_cost_filters() -> bool {
+    true
+}
+
+fn default_trailing_stop_vol_scale() -> Decimal {
+    Decimal::ONE
+}
+
+fn default_take_profit_atr_multiplier() -> Decimal {
+    dec!(3.0)
+}
+
+fn default_candle_gap_max_bars() -> usize {
+    60
+}
+
+fn default_cold_start_reward_risk_ratio() -> Decimal {
+    Decimal::ONE
+}
+
+fn default_cold_start_min_trades() -> usize {
+    10
+}
+
+fn default_trend_timeframe() -> Timeframe {
+    Timeframe::OneHour
 }
 
 impl Default for AnalystConfig {
@@ -134,6 +278,35 @@ impl Default for AnalystConfig {
             orderflow_volume_profile_lookback: 100,
             ensemble_weights: None,
             ensemble_voting_threshold: dec!(0.5),
+            trading_windows: TradingWindows::Always,
+            news_dedup_window_seconds: default_news_dedup_window_seconds(),
+            news_trade_size_multiplier: default_news_trade_size_multiplier(),
+            news_trade_max_value_usd: None,
+            news_reentry_cooldown_seconds: 0,
+            regime_detection_method: RegimeDetectionMethod::default(),
+            hurst_lookback: default_hurst_lookback(),
+            relative_stop_benchmark: None,
+            relative_stop_pct: default_relative_stop_pct(),
+            pyramid_max_adds: 0,
+            pyramid_trigger_pct: default_pyramid_trigger_pct(),
+            exits_bypass_cost_filters: default_exits_bypass_cost_filters(),
+            allow_shorts: false,
+            require_htf_confirmation: false,
+            trend_timeframe: default_trend_timeframe(),
+            max_trades_per_symbol_per_day: 0,
+            open_blackout_minutes: 0,
+            trading_day_boundary: TradingDayBoundary::default(),
+            post_stop_cooldown_seconds: 0,
+            trailing_stop_vol_scale_min: default_trailing_stop_vol_scale(),
+            trailing_stop_vol_scale_max: default_trailing_stop_vol_scale(),
+            candle_history_len: 0,
+            candle_gap_policy: CandleGapPolicy::default(),
+            candle_gap_max_bars: default_candle_gap_max_bars(),
+            cold_start_reward_risk_ratio: default_cold_start_reward_risk_ratio(),
+            cold_start_min_trades: default_cold_start_min_trades(),
+            confirm_strategy: None,
+            take_profit_mode: TakeProfitMode::default(),
+            take_profit_atr_multiplier: default_take_profit_atr_multiplier(),
         }
     }
 }
@@ -197,11 +370,141 @@ impl From<crate::config::Config> for AnalystConfig {
             orderflow_volume_profile_lookback: 100,
             ensemble_weights: None,
             ensemble_voting_threshold: config.ensemble_voting_threshold,
+            trading_windows: config.trading_windows(),
+            news_dedup_window_seconds: config.news_dedup_window_seconds,
+            news_trade_size_multiplier: config.news_trade_size_multiplier,
+            news_trade_max_value_usd: config.news_trade_max_value_usd,
+            news_reentry_cooldown_seconds: config.news_reentry_cooldown_seconds,
+            regime_detection_method: config.regime_detection_method,
+            hurst_lookback: config.hurst_lookback,
+            relative_stop_benchmark: config.relative_stop_benchmark(),
+            relative_stop_pct: config.relative_stop_pct,
+            pyramid_max_adds: config.pyramid_max_adds,
+            pyramid_trigger_pct: config.pyramid_trigger_pct,
+            exits_bypass_cost_filters: config.exits_bypass_cost_filters,
+            allow_shorts: config.allow_shorts,
+            require_htf_confirmation: config.require_htf_confirmation,
+            trend_timeframe: config.trend_timeframe,
+            max_trades_per_symbol_per_day: config.max_trades_per_symbol_per_day,
+            open_blackout_minutes: config.open_blackout_minutes(),
+            trading_day_boundary: config.trading_day_boundary(),
+            post_stop_cooldown_seconds: config.post_stop_cooldown_seconds,
+            trailing_stop_vol_scale_min: config.trailing_stop_vol_scale_min,
+            trailing_stop_vol_scale_max: config.trailing_stop_vol_scale_max,
+            candle_history_len: config.candle_history_len,
+            candle_gap_policy: config.candle_gap_policy,
+            candle_gap_max_bars: config.candle_gap_max_bars,
+            cold_start_reward_risk_ratio: config.cold_start_reward_risk_ratio,
+            cold_start_min_trades: config.cold_start_min_trades,
+            confirm_strategy: config.confirm_strategy,
+            take_profit_mode: config.take_profit_mode,
+            take_profit_atr_multiplier: config.take_profit_atr_multiplier,
+        }
+    }
+}
+
+/// The parameters the optimizers search over, sent on their own so they land on
+/// whatever config the receiver is running
+#[derive(Debug, Clone, PartialEq)]
+pub struct TunedParams {
+    pub fast_sma_period: usize,
+    pub slow_sma_period: usize,
+    pub rsi_threshold: Decimal,
+    pub trend_divergence_threshold: Decimal,
+    pub trailing_stop_atr_multiplier: Decimal,
+    pub order_cooldown_seconds: u64,
+    pub stat_momentum_lookback: usize,
+    pub stat_momentum_threshold: Decimal,
+    pub zscore_lookback: usize,
+    pub zscore_entry_threshold: Decimal,
+    pub zscore_exit_threshold: Decimal,
+    pub orderflow_ofi_threshold: Decimal,
+    pub smc_ob_lookback: usize,
+    pub smc_min_fvg_size_pct: Decimal,
+}
+
+impl From<&AnalystConfig> for TunedParams {
+    fn from(config: &AnalystConfig) -> Self {
+        Self {
+            fast_sma_period: config.fast_sma_period,
+            slow_sma_period: config.slow_sma_period,
+            rsi_threshold: config.rsi_threshold,
+            trend_divergence_threshold: config.trend_divergence_threshold,
+            trailing_stop_atr_multiplier: config.trailing_stop_atr_multiplier,
+            order_cooldown_seconds: config.order_cooldown_seconds,
+            stat_momentum_lookback: config.stat_momentum_lookback,
+            stat_momentum_threshold: config.stat_momentum_threshold,
+            zscore_lookback: config.zscore_lookback,
+            zscore_entry_threshold: config.zscore_entry_threshold,
+            zscore_exit_threshold: config.zscore_exit_threshold,
+            orderflow_ofi_threshold: config.orderflow_ofi_threshold,
+            smc_ob_lookback: config.smc_ob_lookback,
+            smc_min_fvg_size_pct: config.smc_min_fvg_size_pct,
         }
     }
 }
 
+impl TunedParams {
+    /// Overwrite the tuned parameters of `config`, leaving every other setting as it is
+    pub fn apply_to(&self, config: &mut AnalystConfig) {
+        config.fast_sma_period = self.fast_sma_period;
+        config.slow_sma_period = self.slow_sma_period;
+        config.rsi_threshold = self.rsi_threshold;
+        config.trend_divergence_threshold = self.trend_divergence_threshold;
+        config.trailing_stop_atr_multiplier = self.trailing_stop_atr_multiplier;
+        config.order_cooldown_seconds = self.order_cooldown_seconds;
+        config.stat_momentum_lookback = self.stat_momentum_lookback;
+        config.stat_momentum_threshold = self.stat_momentum_threshold;
+        config.zscore_lookback = self.zscore_lookback;
+        config.zscore_entry_threshold = self.zscore_entry_threshold;
+        config.zscore_exit_threshold = self.zscore_exit_threshold;
+        config.orderflow_ofi_threshold = self.orderflow_ofi_threshold;
+        config.smc_ob_lookback = self.smc_ob_lookback;
+        config.smc_min_fvg_size_pct = self.smc_min_fvg_size_pct;
+    }
+}
+
 impl AnalystConfig {
+    /// Copy the parameters the optimizers search over from `tuned`, leaving sizing,
+    /// costs and every other setting as they are
+    pub fn apply_tuned_params(&mut self, tuned: &AnalystConfig) {
+        TunedParams::from(tuned).apply_to(self);
+    }
+
+    /// Apply the strategy parameters stored by the optimizer for a risk score
+    pub fn apply_optimal_parameters(
+        &mut self,
+        params: &crate::domain::risk::optimal_parameters::OptimalParameters,
+    ) {
+        self.fast_sma_period = params.fast_sma_period;
+        self.slow_sma_period = params.slow_sma_period;
+        self.rsi_threshold = params.rsi_threshold;
+        self.trailing_stop_atr_multiplier = params.trailing_stop_atr_multiplier;
+        self.trend_divergence_threshold = params.trend_divergence_threshold;
+        self.order_cooldown_seconds = params.order_cooldown_seconds;
+    }
+
+    /// One-line summary of the parameters `apply_tuned_params` copies, for logs
+    pub fn tuned_params_summary(&self) -> String {
+        format!(
+            "sma={}/{} rsi<{} trend_div={} atr_stop={}x cooldown={}s stat_mom={}@{} zscore={}@{}/{} ofi={} smc_ob={} fvg={}",
+            self.fast_sma_period,
+            self.slow_sma_period,
+            self.rsi_threshold,
+            self.trend_divergence_threshold,
+            self.trailing_stop_atr_multiplier,
+            self.order_cooldown_seconds,
+            self.stat_momentum_lookback,
+            self.stat_momentum_threshold,
+            self.zscore_lookback,
+            self.zscore_entry_threshold,
+            self.zscore_exit_threshold,
+            self.orderflow_ofi_threshold,
+            self.smc_ob_lookback,
+            self.smc_min_fvg_size_pct
+        )
+    }
+
     pub fn apply_risk_appetite(
         &mut self,
         appetite: &crate::domain::risk::risk_appetite::RiskAppetite,
@@ -244,6 +547,118 @@ impl AnalystConfig {
 
         self.risk_appetite_score = Some(appetite.score());
     }
+
+    /// Longest window of raw candles any strategy reads from a symbol's history
+    pub fn max_candle_lookback(&self) -> usize {
+        [
+            self.smc_ob_lookback,
+            self.breakout_lookback + 1,
+            self.stat_momentum_lookback + 1,
+            self.zscore_lookback,
+            self.orderflow_volume_profile_lookback,
+        ]
+        .into_iter()
+        .max()
+        .unwrap_or(0)
+    }
+
+    /// Candles kept per symbol: `candle_history_len`, or the largest strategy
+    /// lookback times a safety factor when unset
+    pub fn candle_history_cap(&self) -> usize {
+        if self.candle_history_len > 0 {
+            self.candle_history_len
+        } else {
+            self.max_candle_lookback() * CANDLE_HISTORY_SAFETY_FACTOR
+        }
+    }
+
+    pub fn validate(&self) -> Result<(), String> {
+        for (field, value) in [
+            ("fast_sma_period", self.fast_sma_period),
+            ("slow_sma_period", self.slow_sma_period),
+            ("trend_sma_period", self.trend_sma_period),
+            ("rsi_period", self.rsi_period),
+            ("macd_fast_period", self.macd_fast_period),
+            ("macd_slow_period", self.macd_slow_period),
+            ("macd_signal_period", self.macd_signal_period),
+            ("atr_period", self.atr_period),
+            ("adx_period", self.adx_period),
+        ] {
+            if value == 0 {
+                return Err(format!("{} must be > 0", field));
+            }
+        }
+        if self.fast_sma_period >= self.slow_sma_period {
+            return Err(format!(
+                "fast_sma_period ({}) must be below slow_sma_period ({})",
+                self.fast_sma_period, self.slow_sma_period
+            ));
+        }
+        if self.macd_fast_period >= self.macd_slow_period {
+            return Err(format!(
+                "macd_fast_period ({}) must be below macd_slow_period ({})",
+                self.macd_fast_period, self.macd_slow_period
+            ));
+        }
+        if self.rsi_threshold <= Decimal::ZERO || self.rsi_threshold >= Decimal::ONE_HUNDRED {
+            return Err(format!("Invalid rsi_threshold: {}", self.rsi_threshold));
+        }
+        Ok(())
+    }
+
+    /// Returns true if `other` changes any indicator period, meaning feature
+    /// state built under `self` is no longer valid and must be re-warmed.
+    pub fn has_structural_change(&self, other: &AnalystConfig) -> bool {
+        self.fast_sma_period != other.fast_sma_period
+            || self.slow_sma_period != other.slow_sma_period
+            || self.trend_sma_period != other.trend_sma_period
+            || self.rsi_period != other.rsi_period
+            || self.macd_fast_period != other.macd_fast_period
+            || self.macd_slow_period != other.macd_slow_period
+            || self.macd_signal_period != other.macd_signal_period
+            || self.mean_reversion_bb_period != other.mean_reversion_bb_period
+            || self.bb_std_dev != other.bb_std_dev
+            || self.atr_period != other.atr_period
+            || self.ema_fast_period != other.ema_fast_period
+            || self.ema_slow_period != other.ema_slow_period
+            || self.adx_period != other.adx_period
+            || self.hurst_lookback != other.hurst_lookback
+    }
+
+    /// Returns true if `other` changes a parameter strategies capture at construction
+    /// (see `StrategyFactory::create`), meaning they must be rebuilt to apply it.
+    pub fn has_strategy_change(&self, other: &AnalystConfig) -> bool {
+        self.has_structural_change(other)
+            || self.strategy_mode != other.strategy_mode
+            || self.confirm_strategy != other.confirm_strategy
+            || self.sma_threshold != other.sma_threshold
+            || self.rsi_threshold != other.rsi_threshold
+            || self.signal_confirmation_bars != other.signal_confirmation_bars
+            || self.macd_requires_rising != other.macd_requires_rising
+            || self.trend_tolerance_pct != other.trend_tolerance_pct
+            || self.macd_min_threshold != other.macd_min_threshold
+            || self.adx_threshold != other.adx_threshold
+            || self.trend_divergence_threshold != other.trend_divergence_threshold
+            || self.trend_riding_exit_buffer_pct != other.trend_riding_exit_buffer_pct
+            || self.mean_reversion_rsi_exit != other.mean_reversion_rsi_exit
+            || self.smc_ob_lookback != other.smc_ob_lookback
+            || self.smc_min_fvg_size_pct != other.smc_min_fvg_size_pct
+            || self.smc_volume_multiplier != other.smc_volume_multiplier
+            || self.breakout_lookback != other.breakout_lookback
+            || self.breakout_threshold_pct != other.breakout_threshold_pct
+            || self.breakout_volume_mult != other.breakout_volume_mult
+            || self.zscore_lookback != other.zscore_lookback
+            || self.zscore_entry_threshold != other.zscore_entry_threshold
+            || self.zscore_exit_threshold != other.zscore_exit_threshold
+            || self.stat_momentum_lookback != other.stat_momentum_lookback
+            || self.stat_momentum_threshold != other.stat_momentum_threshold
+            || self.stat_momentum_trend_confirmation != other.stat_momentum_trend_confirmation
+            || self.orderflow_ofi_threshold != other.orderflow_ofi_threshold
+            || self.orderflow_stacked_count != other.orderflow_stacked_count
+            || self.orderflow_volume_profile_lookback != other.orderflow_volume_profile_lookback
+            || self.ensemble_voting_threshold != other.ensemble_voting_threshold
+            || self.ensemble_weights != other.ensemble_weights
+    }
 }
 
 impl From<&AnalystConfig> for crate::application::risk_management::sizing_engine::SizingConfig {
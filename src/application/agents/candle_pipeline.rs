@@ -9,18 +9,21 @@
 //! 1. **Regime Analysis** - Detect market regime and apply dynamic risk scaling
 //! 2. **Indicator Updates** - Update technical indicators and features
 //! 3. **Position Synchronization** - Sync local state with portfolio
-//! 4. **Trailing Stop Management** - Check and manage trailing stops
+//! 4. **Trailing Stop Management** - Check and manage trailing stops (and pyramid adds)
 //! 5. **Signal Generation** - Generate and filter trading signals
 //! 6. **Trade Evaluation** - Validate and create trade proposals
 
 use crate::application::agents::trade_evaluator::{EvaluationInput, TradeEvaluator};
 use crate::application::trading::symbol_context::SymbolContext;
 use crate::domain::market::market_regime::MarketRegime;
-use crate::domain::ports::ExecutionService;
 use crate::domain::repositories::CandleRepository;
+use crate::domain::trading::events::{DecisionEvent, DecisionKind, TradingEvent};
 use crate::domain::trading::portfolio::Portfolio;
-use crate::domain::trading::types::{Candle, OrderSide, TradeProposal};
+use crate::domain::trading::types::{Candle, OrderSide, OrderType, SignalOrigin, TradeProposal};
+use crate::infrastructure::core::event_bus::EventBus;
+use crate::infrastructure::observability::Metrics;
 use rust_decimal::Decimal;
+use std::collections::HashMap;
 use std::sync::Arc;
 use tracing::debug;
 
@@ -30,21 +33,23 @@ pub struct PipelineContext<'a> {
     pub candle: &'a Candle,
     pub context: &'a mut SymbolContext,
     pub portfolio: Option<&'a Portfolio>,
+    /// Latest price of the configured relative-stop benchmark, if any
+    pub benchmark_price: Option<Decimal>,
 }
 
 /// Candle processing pipeline
 pub struct CandlePipeline {
-    execution_service: Arc<dyn ExecutionService>,
     candle_repository: Option<Arc<dyn CandleRepository>>,
     trade_evaluator: TradeEvaluator,
     data_collector:
         Option<Arc<std::sync::Mutex<crate::application::ml::data_collector::DataCollector>>>,
+    event_bus: EventBus,
+    metrics: Option<Metrics>,
 }
 
 impl CandlePipeline {
     /// Create a new candle processing pipeline
     pub fn new(
-        execution_service: Arc<dyn ExecutionService>,
         candle_repository: Option<Arc<dyn CandleRepository>>,
         trade_evaluator: TradeEvaluator,
         data_collector: Option<
@@ -52,13 +57,62 @@ impl CandlePipeline {
         >,
     ) -> Self {
         Self {
-            execution_service,
             candle_repository,
             trade_evaluator,
             data_collector,
+            event_bus: EventBus::new(),
+            metrics: None,
         }
     }
 
+    /// Count generated signals and filter rejections in the given metrics
+    pub fn with_metrics(mut self, metrics: Metrics) -> Self {
+        self.trade_evaluator = self.trade_evaluator.with_metrics(metrics.clone());
+        self.metrics = Some(metrics);
+        self
+    }
+
+    /// Publish signal decisions (generated / filtered) to the given bus
+    pub fn with_event_bus(mut self, event_bus: EventBus) -> Self {
+        self.event_bus = event_bus;
+        self
+    }
+
+    async fn record(&self, event: DecisionEvent) {
+        self.event_bus.publish(TradingEvent::Decision(event)).await;
+    }
+
+    /// Drop a signal at one of the pipeline's own filters
+    async fn reject_signal(
+        &self,
+        ctx: &PipelineContext<'_>,
+        signal: &crate::application::strategies::Signal,
+        filter: &str,
+    ) {
+        if let Some(metrics) = &self.metrics {
+            metrics.inc_rejections("filter", filter);
+        }
+        self.record_filtered(ctx, signal, filter).await;
+    }
+
+    async fn record_filtered(
+        &self,
+        ctx: &PipelineContext<'_>,
+        signal: &crate::application::strategies::Signal,
+        blocked_by: &str,
+    ) {
+        self.record(
+            DecisionEvent::new(
+                DecisionKind::SignalFiltered,
+                ctx.symbol,
+                signal.side,
+                signal.reason.clone(),
+            )
+            .with_blocked_by(blocked_by),
+        )
+        .await;
+    }
+
     /// Process a candle through the complete pipeline
     ///
     /// Returns a trade proposal if all stages pass validation
@@ -75,13 +129,26 @@ impl CandlePipeline {
         // Stage 4: Trailing Stop Management
         if let Some(stop_signal) = self.manage_trailing_stops(ctx, has_position) {
             // Trailing stop triggered - evaluate immediately
+            self.record(DecisionEvent::new(
+                DecisionKind::SignalGenerated,
+                ctx.symbol,
+                stop_signal.side,
+                stop_signal.reason.clone(),
+            ))
+            .await;
             return self
                 .evaluate_and_propose(ctx, stop_signal, &regime, has_position)
                 .await;
         }
 
+        // Stage 4b: Scale into a winning position
+        if has_position && let Some(mut proposal) = self.check_pyramid_add(ctx) {
+            proposal.origin = signal_origin(ctx, &regime);
+            return Some(proposal);
+        }
+
         // Stage 5: Signal Generation
-        let signal = self.generate_and_filter_signal(ctx, has_position)?;
+        let signal = self.generate_and_filter_signal(ctx, has_position).await?;
 
         // Stage 6: Trade Evaluation
         self.evaluate_and_propose(ctx, signal, &regime, has_position)
@@ -135,26 +202,25 @@ impl CandlePipeline {
 
     /// Stage 3: Synchronize position state with portfolio
     ///
-    /// Returns whether the symbol has an active position
+    /// Returns whether the symbol has an active position, long or short
     fn sync_position_state(&self, ctx: &mut PipelineContext<'_>) -> bool {
-        let has_position = ctx
+        let position_qty = ctx
             .portfolio
-            .map(|p| {
-                p.positions
-                    .get(ctx.symbol)
-                    .map(|pos| pos.quantity > Decimal::ZERO)
-                    .unwrap_or(false)
-            })
-            .unwrap_or(false);
+            .and_then(|p| p.positions.get(ctx.symbol))
+            .map_or(Decimal::ZERO, |pos| pos.quantity);
+        let has_position = !position_qty.is_zero();
+        ctx.context.position_quantity = position_qty;
 
         // Acknowledge pending orders
         ctx.context
             .position_manager
-            .ack_pending_orders(has_position, ctx.symbol);
+            .ack_pending_orders(position_qty, ctx.symbol);
 
-        // Reset taken_profit flag when position is closed
+        // Reset taken_profit flag, benchmark reference and pyramid state when position is closed
         if !has_position {
             ctx.context.taken_profit = false;
+            ctx.context.position_manager.benchmark_entry_price = None;
+            ctx.context.position_manager.pyramid = None;
         }
 
         // Auto-initialize trailing stop for existing positions
@@ -176,9 +242,8 @@ impl CandlePipeline {
 
     /// Stage 4: Manage trailing stops and check for exit signals
     ///
-    /// Stage 4: Manage trailing stops and check for exit signals
-    ///
-    /// Returns Some(Signal) if trailing stop is triggered (Side = Sell)
+    /// Returns Some(Signal) if the trailing stop or the benchmark-relative stop
+    /// is triggered (Sell for a long, Buy to cover a short)
     fn manage_trailing_stops(
         &self,
         ctx: &mut PipelineContext<'_>,
@@ -189,7 +254,7 @@ impl CandlePipeline {
         }
 
         let atr_decimal = ctx.context.last_features.atr.unwrap_or(Decimal::ZERO);
-        let multiplier_decimal = ctx.context.config.trailing_stop_atr_multiplier;
+        let multiplier_decimal = ctx.context.trailing_stop_multiplier();
 
         let signal_side = ctx.context.position_manager.check_trailing_stop(
             ctx.symbol,
@@ -198,10 +263,20 @@ impl CandlePipeline {
             multiplier_decimal,
         );
 
-        if let Some(_side) = signal_side {
+        if let Some(side) = signal_side {
+            ctx.context.position_manager.last_stop_time = Some(ctx.candle.timestamp);
             // Convert OrderSide to Signal
+            let reason = "Trailing Stop Triggered".to_string();
+            return Some(match side {
+                OrderSide::Sell => crate::application::strategies::Signal::sell(reason),
+                OrderSide::Buy => crate::application::strategies::Signal::buy(reason),
+            });
+        }
+
+        if self.check_relative_stop(ctx) {
+            ctx.context.position_manager.last_stop_time = Some(ctx.candle.timestamp);
             return Some(crate::application::strategies::Signal::sell(
-                "Trailing Stop Triggered".to_string(),
+                "Benchmark-Relative Stop Triggered".to_string(),
             ));
         }
 
@@ -213,7 +288,7 @@ impl CandlePipeline {
                     ctx.context,
                     ctx.symbol,
                     ctx.candle.close,
-                    ctx.candle.timestamp * 1000,
+                    ctx.candle.timestamp,
                     ctx.portfolio.map(|p| &p.positions),
                     ctx.context.last_entry_time,
                     ctx.context.min_hold_time_ms,
@@ -231,8 +306,113 @@ impl CandlePipeline {
         None
     }
 
+    /// Relative-strength exit for longs against the configured benchmark
+    fn check_relative_stop(&self, ctx: &mut PipelineContext<'_>) -> bool {
+        let (Some(benchmark), Some(benchmark_price)) = (
+            ctx.context.config.relative_stop_benchmark.as_deref(),
+            ctx.benchmark_price,
+        ) else {
+            return false;
+        };
+        if benchmark == ctx.symbol {
+            return false;
+        }
+        let Some(pos) = ctx
+            .portfolio
+            .and_then(|p| p.positions.get(ctx.symbol))
+            .filter(|pos| pos.quantity > Decimal::ZERO)
+        else {
+            return false;
+        };
+
+        ctx.context
+            .position_manager
+            .check_relative_stop(
+                ctx.symbol,
+                ctx.candle.close,
+                pos.average_price,
+                benchmark_price,
+                ctx.context.config.relative_stop_pct,
+            )
+            .is_some()
+    }
+
+    /// Pyramiding: add to a winning position at configured profit increments.
+    ///
+    /// Adds bypass strategy sizing; their size comes from the initial entry and is
+    /// clamped to the headroom left under `max_position_size_pct` and the cash.
+    /// The trailing stop is re-anchored on the blended entry.
+    fn check_pyramid_add(&self, ctx: &mut PipelineContext<'_>) -> Option<TradeProposal> {
+        let config = &ctx.context.config;
+        let price = ctx.candle.close;
+        if config.pyramid_max_adds == 0
+            || price <= Decimal::ZERO
+            || !config.trading_windows.is_open(ctx.candle.timestamp)
+        {
+            return None;
+        }
+        let portfolio = ctx.portfolio?;
+        let pos = portfolio
+            .positions
+            .get(ctx.symbol)
+            .filter(|pos| pos.quantity > Decimal::ZERO)?;
+
+        let mut headroom = portfolio.cash;
+        if config.max_position_size_pct > Decimal::ZERO {
+            let prices = HashMap::from([(ctx.symbol.to_string(), price)]);
+            let max_notional = portfolio.total_equity(&prices) * config.max_position_size_pct;
+            headroom = headroom.min(max_notional - pos.quantity * price);
+        }
+        let max_quantity = (headroom / price).max(Decimal::ZERO);
+
+        let quantity = ctx.context.position_manager.check_pyramid_add(
+            ctx.symbol,
+            price,
+            pos.average_price,
+            pos.quantity,
+            config.pyramid_max_adds,
+            config.pyramid_trigger_pct,
+            max_quantity,
+        )?;
+
+        let adds = ctx
+            .context
+            .position_manager
+            .pyramid
+            .map(|p| p.adds)
+            .unwrap_or(0);
+        let blended_entry =
+            (pos.average_price * pos.quantity + price * quantity) / (pos.quantity + quantity);
+
+        ctx.context.position_manager.set_pending_order(
+            OrderSide::Buy,
+            ctx.candle.timestamp,
+            pos.quantity,
+        );
+        ctx.context.last_entry_time = Some(ctx.candle.timestamp);
+        super::position_lifecycle::initialize_trailing_stop_on_buy(ctx.context, blended_entry);
+
+        Some(TradeProposal {
+            symbol: ctx.symbol.to_string(),
+            side: OrderSide::Buy,
+            price,
+            quantity,
+            order_type: OrderType::Limit,
+            reason: format!(
+                "Pyramid Add #{} (+{:.2}% on entry)",
+                adds,
+                (price / pos.average_price - Decimal::ONE) * Decimal::ONE_HUNDRED
+            ),
+            timestamp: ctx.candle.timestamp,
+            stop_loss: None,
+            take_profit: None,
+            reduce_only: false,
+            origin: Default::default(),
+        })
+    }
+
     /// Stage 5: Generate and filter trading signal
-    fn generate_and_filter_signal(
+    async fn generate_and_filter_signal(
         &self,
         ctx: &mut PipelineContext<'_>,
         has_position: bool,
@@ -245,7 +425,13 @@ impl CandlePipeline {
                     entry_price: pos.average_price,
                     quantity: pos.quantity,
                     unrealized_pnl_pct: if pos.average_price > Decimal::ZERO {
-                        (ctx.candle.close - pos.average_price) / pos.average_price
+                        let move_pct = (ctx.candle.close - pos.average_price) / pos.average_price;
+                        // A short gains when the price falls
+                        if pos.quantity < Decimal::ZERO {
+                            -move_pct
+                        } else {
+                            move_pct
+                        }
                     } else {
                         Decimal::ZERO
                     },
@@ -255,21 +441,87 @@ impl CandlePipeline {
         };
 
         // Generate signal from strategy
-        let mut signal = super::signal_processor::SignalProcessor::generate_signal(
+        let generated = super::signal_processor::SignalProcessor::generate_signal(
             ctx.context,
             ctx.symbol,
             ctx.candle.close,
-            ctx.candle.timestamp * 1000,
+            ctx.candle.timestamp,
             has_position,
             position,
-        );
+        )?;
+        if let Some(metrics) = &self.metrics {
+            metrics.inc_signals(
+                &ctx.context.active_strategy_mode.to_string(),
+                &generated.side.to_string(),
+            );
+        }
+        self.record(DecisionEvent::new(
+            DecisionKind::SignalGenerated,
+            ctx.symbol,
+            generated.side,
+            generated.reason.clone(),
+        ))
+        .await;
 
         // Apply RSI filter
-        signal = super::signal_processor::SignalProcessor::apply_rsi_filter(
+        let mut signal = super::signal_processor::SignalProcessor::apply_rsi_filter(
+            Some(generated.clone()),
+            ctx.context,
+            ctx.symbol,
+        );
+        if signal.is_none() {
+            self.reject_signal(ctx, &generated, "rsi_filter").await;
+            return None;
+        }
+
+        // Block new entries outside the configured trading windows
+        signal = super::signal_processor::SignalProcessor::apply_trading_window_filter(
             signal,
             ctx.context,
             ctx.symbol,
+            ctx.candle.timestamp,
         );
+        if signal.is_none() {
+            self.reject_signal(ctx, &generated, "trading_window").await;
+            return None;
+        }
+
+        // Don't re-enter right after a news-driven exit
+        signal = super::signal_processor::SignalProcessor::apply_news_cooldown_filter(
+            signal,
+            ctx.context,
+            ctx.symbol,
+            ctx.candle.timestamp,
+        );
+        if signal.is_none() {
+            self.reject_signal(ctx, &generated, "news_cooldown").await;
+            return None;
+        }
+
+        // Don't walk straight back into the move that just stopped us out
+        signal = super::signal_processor::SignalProcessor::apply_post_stop_cooldown_filter(
+            signal,
+            ctx.context,
+            ctx.symbol,
+            ctx.candle.timestamp,
+        );
+        if signal.is_none() {
+            self.reject_signal(ctx, &generated, "post_stop_cooldown")
+                .await;
+            return None;
+        }
+
+        // Trade in the direction of the higher timeframe
+        signal = super::signal_processor::SignalProcessor::apply_htf_confirmation_filter(
+            signal,
+            ctx.context,
+            ctx.symbol,
+        );
+        if signal.is_none() {
+            self.reject_signal(ctx, &generated, "htf_confirmation")
+                .await;
+            return None;
+        }
 
         // Suppress sell signals when trailing stop is active
         signal = super::signal_processor::SignalProcessor::suppress_sell_if_trailing_stop(
@@ -278,6 +530,10 @@ impl CandlePipeline {
             ctx.symbol,
             false, // trailing_stop_triggered is handled separately
         );
+        if signal.is_none() {
+            self.reject_signal(ctx, &generated, "trailing_stop_active")
+                .await;
+        }
 
         signal
     }
@@ -305,34 +561,64 @@ impl CandlePipeline {
             price: ctx.candle.close,
             timestamp: ctx.candle.timestamp,
             regime,
-            execution_service: &self.execution_service,
+            portfolio: ctx.portfolio,
             has_position,
+            opens_position: ctx
+                .portfolio
+                .and_then(|p| p.positions.get(ctx.symbol))
+                .is_none_or(|pos| pos.quantity.is_zero()),
             strategy_signal: Some(signal.clone()), // I'll add this field
         };
 
-        let proposal = self
+        let mut proposal = match self
             .trade_evaluator
             .evaluate_and_propose(ctx.context, input)
-            .await?;
+            .await
+        {
+            Ok(proposal) => proposal,
+            Err(blocked_by) => {
+                self.record_filtered(ctx, &signal, blocked_by).await;
+                return None;
+            }
+        };
+
+        proposal.origin = signal_origin(ctx, regime);
 
         // Update position manager state
-        ctx.context
-            .position_manager
-            .set_pending_order(signal.side, ctx.candle.timestamp);
+        let position_qty = ctx.context.position_quantity;
+        ctx.context.position_manager.set_pending_order(
+            signal.side,
+            ctx.candle.timestamp,
+            position_qty,
+        );
 
-        // Track entry time for buy signals
-        if signal.side == OrderSide::Buy {
+        // Track entry time and arm the trailing stop for entries, long or short
+        if ctx.context.is_entry(signal.side) {
             ctx.context.last_entry_time = Some(ctx.candle.timestamp);
-            super::position_lifecycle::initialize_trailing_stop_on_buy(
-                ctx.context,
-                ctx.candle.close,
-            );
+            match signal.side {
+                OrderSide::Buy => super::position_lifecycle::initialize_trailing_stop_on_buy(
+                    ctx.context,
+                    ctx.candle.close,
+                ),
+                OrderSide::Sell => super::position_lifecycle::initialize_trailing_stop_on_short(
+                    ctx.context,
+                    ctx.candle.close,
+                ),
+            }
         }
 
         Some(proposal)
     }
 }
 
+/// Active strategy and detected regime, recorded on the proposal for trade analytics
+fn signal_origin(ctx: &PipelineContext<'_>, regime: &MarketRegime) -> SignalOrigin {
+    SignalOrigin {
+        strategy: Some(ctx.context.strategy.name().to_string()),
+        regime: Some(format!("{:?}", regime.regime_type)),
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -342,18 +628,12 @@ mod tests {
     use crate::application::strategies::DualSMAStrategy;
     use crate::application::trading::trade_filter::TradeFilter;
     use crate::domain::trading::fee_model::ConstantFeeModel;
-    use crate::infrastructure::mock::MockExecutionService;
     use rust_decimal::Decimal;
     use rust_decimal::prelude::FromPrimitive;
     use rust_decimal_macros::dec;
     use std::sync::Arc;
 
     fn create_test_pipeline() -> CandlePipeline {
-        use crate::domain::trading::portfolio::Portfolio;
-        use tokio::sync::RwLock;
-
-        let portfolio = Arc::new(RwLock::new(Portfolio::new()));
-        let execution_service = Arc::new(MockExecutionService::new(portfolio));
         let config = AnalystConfig::default();
         let fee_model = Arc::new(ConstantFeeModel::new(Decimal::ZERO, Decimal::ZERO));
         let cost_evaluator = CostEvaluator::new(fee_model, config.spread_bps);
@@ -370,7 +650,7 @@ mod tests {
 
         let trade_evaluator = TradeEvaluator::new(trade_filter, signal_processor);
 
-        CandlePipeline::new(execution_service, None, trade_evaluator, None)
+        CandlePipeline::new(None, trade_evaluator, None)
     }
 
     fn create_test_context() -> SymbolContext {
@@ -410,6 +690,7 @@ mod tests {
             candle: &candle,
             context: &mut context,
             portfolio: None,
+            benchmark_price: None,
         };
 
         pipeline.update_indicators(&mut ctx);
@@ -431,6 +712,7 @@ mod tests {
             candle: &candle,
             context: &mut context,
             portfolio: None,
+            benchmark_price: None,
         };
 
         let has_position = pipeline.sync_position_state(&mut ctx);
@@ -450,6 +732,7 @@ mod tests {
             candle: &candle,
             context: &mut context,
             portfolio: None,
+            benchmark_price: None,
         };
 
         let signal = pipeline.manage_trailing_stops(&mut ctx, false);
@@ -457,8 +740,8 @@ mod tests {
         assert!(signal.is_none());
     }
 
-    #[test]
-    fn test_generate_and_filter_signal() {
+    #[tokio::test]
+    async fn test_generate_and_filter_signal() {
         let pipeline = create_test_pipeline();
         let mut context = create_test_context();
 
@@ -470,6 +753,7 @@ mod tests {
                 candle: &c,
                 context: &mut context,
                 portfolio: None,
+                benchmark_price: None,
             };
             pipeline.update_indicators(&mut ctx);
         }
@@ -481,10 +765,11 @@ mod tests {
             candle: &final_candle,
             context: &mut context,
             portfolio: None,
+            benchmark_price: None,
         };
 
         pipeline.update_indicators(&mut ctx);
-        let signal = pipeline.generate_and_filter_signal(&mut ctx, false);
+        let signal = pipeline.generate_and_filter_signal(&mut ctx, false).await;
 
         // Verify indicators were properly calculated (not panicking, features present)
         assert!(
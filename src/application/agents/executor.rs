@@ -5,26 +5,41 @@ use crate::application::risk_management::{
     order_monitor::{MonitorAction, OrderMonitor},
     order_retry_strategy::RetryConfig,
 };
-use crate::domain::ports::ExecutionService;
+use crate::domain::errors::OrderSubmitError;
+use crate::domain::ports::{ExecutionService, OrderUpdate};
 use crate::domain::repositories::TradeRepository;
-use crate::domain::trading::fee_model::FeeModel;
-use crate::domain::trading::portfolio::{Portfolio, Position};
-use crate::domain::trading::types::{Order, OrderSide};
+use crate::domain::trading::events::{DecisionEvent, DecisionKind, TradingEvent};
+use crate::domain::trading::fee_model::{FeeModel, VolumeTracker};
+use crate::domain::trading::portfolio::Portfolio;
+use crate::domain::trading::time::{MS_PER_DAY, now_ms};
+use crate::domain::trading::types::{Order, OrderSide, OrderStatus, OrderType};
+use crate::infrastructure::core::event_bus::EventBus;
 use anyhow::Result;
+use std::collections::HashMap;
 use std::sync::Arc;
 use tokio::sync::RwLock;
-use tokio::sync::mpsc::Receiver;
+use tokio::sync::mpsc::{self, Receiver};
 use tracing::{error, info, instrument, warn};
 
+/// How long a submitted client order id is remembered for duplicate detection
+const SUBMITTED_ID_RETENTION_MS: i64 = MS_PER_DAY;
+
 pub struct Executor {
     execution_service: Arc<dyn ExecutionService>,
     order_rx: Receiver<Order>,
     portfolio: Arc<RwLock<Portfolio>>,
     repository: Option<Arc<dyn TradeRepository>>,
     order_monitor: Arc<OrderMonitor>,
+    retry_config: RetryConfig,
     health_service: Arc<ConnectionHealthService>,
     fee_model: Arc<dyn FeeModel>,
     agent_registry: Arc<crate::application::monitoring::agent_status::AgentStatusRegistry>,
+    /// Client order ids already sent to the broker (id -> submission time in ms)
+    submitted_ids: RwLock<HashMap<String, i64>>,
+    event_bus: EventBus,
+    /// Outcomes of submissions retried in the background
+    retry_tx: mpsc::UnboundedSender<(Order, Result<()>)>,
+    retry_rx: mpsc::UnboundedReceiver<(Order, Result<()>)>,
 }
 
 impl Executor {
@@ -39,16 +54,133 @@ impl Executor {
         fee_model: Arc<dyn FeeModel>,
         agent_registry: Arc<crate::application::monitoring::agent_status::AgentStatusRegistry>,
     ) -> Self {
+        let (retry_tx, retry_rx) = mpsc::unbounded_channel();
         Self {
             execution_service,
             order_rx,
             portfolio,
             repository,
             order_monitor: Arc::new(OrderMonitor::new(retry_config)),
+            retry_config,
             health_service,
             fee_model,
             agent_registry,
+            submitted_ids: RwLock::new(HashMap::new()),
+            event_bus: EventBus::new(),
+            retry_tx,
+            retry_rx,
+        }
+    }
+
+    /// Publish order submission outcomes to the given bus (audit log)
+    pub fn with_event_bus(mut self, event_bus: EventBus) -> Self {
+        self.event_bus = event_bus;
+        self
+    }
+
+    /// Resubmits `order` after its first attempt failed with the transient `error`,
+    /// backing off exponentially.
+    ///
+    /// Safe because the order id is the broker's client order id: an attempt that did
+    /// land is rejected as a duplicate rather than filled again. Broker rejections are
+    /// returned immediately. Gives up once the next backoff would run past
+    /// `submit_max_retry_ms`; reduce-only exits are resubmitted without waiting.
+    async fn retry_submission(
+        execution_service: &dyn ExecutionService,
+        retry_config: &RetryConfig,
+        order: &Order,
+        mut error: anyhow::Error,
+    ) -> Result<()> {
+        let max_retries = retry_config.submit_max_retries;
+        let started = std::time::Instant::now();
+        for retry in 1..=max_retries {
+            let delay = if order.reduce_only {
+                0
+            } else {
+                retry_config
+                    .submit_backoff_ms
+                    .saturating_mul(2u64.saturating_pow(retry - 1))
+            };
+            let elapsed = started.elapsed().as_millis() as u64;
+            if elapsed.saturating_add(delay) > retry_config.submit_max_retry_ms {
+                warn!(
+                    "Executor: Giving up on {} after {}ms of retries: {}",
+                    order.id, elapsed, error
+                );
+                return Err(error);
+            }
+            warn!(
+                "Executor: Submission of {} failed (attempt {}/{}): {}. Retrying in {}ms...",
+                order.id,
+                retry,
+                max_retries + 1,
+                error,
+                delay
+            );
+            tokio::time::sleep(std::time::Duration::from_millis(delay)).await;
+
+            // The failed attempt may still have reached the broker (e.g. a timeout
+            // after the order was accepted): never place it a second time
+            match execution_service
+                .client_order_exists(&order.symbol, &order.id)
+                .await
+            {
+                Ok(Some(true)) => {
+                    info!(
+                        "Executor: Order {} reached the broker despite the error, not resubmitting",
+                        order.id
+                    );
+                    return Ok(());
+                }
+                Ok(_) => {}
+                Err(lookup) => {
+                    warn!(
+                        "Executor: Cannot tell whether order {} reached the broker ({}), not resubmitting",
+                        order.id, lookup
+                    );
+                    return Err(error);
+                }
+            }
+
+            info!(
+                "Executor: Resubmitting order {} (attempt {}/{})",
+                order.id,
+                retry + 1,
+                max_retries + 1
+            );
+            match execution_service.execute(order.clone()).await {
+                Ok(()) => return Ok(()),
+                Err(e) if OrderSubmitError::is_transient(&e) => error = e,
+                Err(e) => return Err(e),
+            }
         }
+        Err(error)
+    }
+
+    /// Retries `order` on a background task so its backoff does not hold up other
+    /// orders; the outcome comes back through `retry_rx`
+    fn spawn_retry(&self, order: Order, error: anyhow::Error) {
+        let execution_service = self.execution_service.clone();
+        let retry_config = self.retry_config;
+        let retry_tx = self.retry_tx.clone();
+        tokio::spawn(async move {
+            let result =
+                Self::retry_submission(execution_service.as_ref(), &retry_config, &order, error)
+                    .await;
+            let _ = retry_tx.send((order, result));
+        });
+    }
+
+    /// Records `client_order_id` as submitted. Returns false if it already was.
+    async fn mark_submitted(&self, client_order_id: &str) -> bool {
+        let now = chrono::Utc::now().timestamp_millis();
+        let mut submitted = self.submitted_ids.write().await;
+        submitted.retain(|_, at| now - *at < SUBMITTED_ID_RETENTION_MS);
+        if submitted.contains_key(client_order_id) {
+            return false;
+        }
+        submitted.insert(client_order_id.to_string(), now);
+        true
     }
 
     pub async fn run(&mut self) {
@@ -56,6 +188,18 @@ impl Executor {
         if let Err(e) = self.reconcile_on_startup().await {
             error!("Executor: Startup reconciliation failed: {}", e);
         }
+        if let Err(e) = self.seed_fee_volume().await {
+            error!("Executor: Failed to restore traded volume: {}", e);
+        }
+
+        // Fee tiers follow the broker's fills, not accepted submissions
+        let mut order_update_rx = match self.execution_service.subscribe_order_updates().await {
+            Ok(rx) => Some(rx),
+            Err(e) => {
+                error!("Executor: Failed to subscribe to order updates: {}", e);
+                None
+            }
+        };
 
         let mut interval = tokio::time::interval(std::time::Duration::from_secs(1));
         let mut heartbeat_interval = tokio::time::interval(std::time::Duration::from_secs(5));
@@ -73,6 +217,18 @@ impl Executor {
                 Some(order) = self.order_rx.recv() => {
                     self.handle_order(order).await;
                 }
+                Some((order, result)) = self.retry_rx.recv() => {
+                    self.finish_submission(order, result).await;
+                }
+                Ok(update) = async {
+                    if let Some(rx) = &mut order_update_rx {
+                        rx.recv().await
+                    } else {
+                        std::future::pending().await
+                    }
+                } => {
+                    self.record_fill(update).await;
+                }
                 _ = interval.tick() => {
                     self.check_timeouts().await;
                 }
@@ -95,7 +251,54 @@ impl Executor {
             order.id, order.symbol, order.quantity
         );
 
-        // 0. IDEMPOTENCY: Persist with 'Pending' status BEFORE execution
+        // Clamp reduce-only orders to the held size here, so the optimistic portfolio
+        // update below books the quantity actually sent to the broker
+        if order.reduce_only {
+            let held = self
+                .portfolio
+                .read()
+                .await
+                .positions
+                .get(&order.symbol)
+                .map_or(rust_decimal::Decimal::ZERO, |p| p.quantity);
+            match order.reduce_only_quantity(held) {
+                Some(qty) => {
+                    if qty < order.quantity {
+                        info!(
+                            "Executor: Clamping reduce-only {} {} from {} to held {}",
+                            order.side, order.symbol, order.quantity, qty
+                        );
+                    }
+                    order.quantity = qty;
+                }
+                None => {
+                    let reason = format!(
+                        "Reduce-only {} {} would open or increase the position (held {})",
+                        order.side, order.symbol, held
+                    );
+                    warn!("Executor: Rejecting order {}: {}", order.id, reason);
+                    self.event_bus
+                        .publish(TradingEvent::Decision(DecisionEvent::for_order(
+                            DecisionKind::OrderRejected,
+                            &order,
+                            reason,
+                        )))
+                        .await;
+                    return;
+                }
+            }
+        }
+
+        // 0. IDEMPOTENCY: never send the same client order id twice
+        if !self.mark_submitted(&order.id).await {
+            warn!(
+                "Executor: Order {} was already submitted. Skipping duplicate.",
+                order.id
+            );
+            return;
+        }
+
+        // Persist with 'Pending' status BEFORE execution
         order.status = crate::domain::trading::types::OrderStatus::Pending;
         if let Some(repo) = &self.repository
             && let Err(e) = repo.save(&order).await
@@ -104,12 +307,36 @@ impl Executor {
                 "Executor: IDEMPOTENCY SAFETY - Failed to pre-persist order {}: {}. ABORTING execution to prevent potential double-spend.",
                 order.id, e
             );
+            self.submitted_ids.write().await.remove(&order.id);
             return;
         }
 
         // 1. Execute External
+        info!("Executor: Submitting order {}", order.id);
         match self.execution_service.execute(order.clone()).await {
-            Ok(_) => {
+            Err(e)
+                if self.retry_config.submit_max_retries > 0
+                    && OrderSubmitError::is_transient(&e) =>
+            {
+                self.spawn_retry(order, e);
+            }
+            result => self.finish_submission(order, result).await,
+        }
+    }
+
+    /// Books the outcome of a submission: optimistic portfolio update on success,
+    /// rejection bookkeeping on failure
+    async fn finish_submission(&self, order: Order, result: Result<()>) {
+        match result {
+            Ok(()) => {
+                self.event_bus
+                    .publish(TradingEvent::Decision(DecisionEvent::for_order(
+                        DecisionKind::OrderSubmitted,
+                        &order,
+                        "Accepted by broker",
+                    )))
+                    .await;
+
                 // Track for retry monitoring if applicable
                 self.order_monitor.track_order(order.clone()).await;
 
@@ -148,6 +375,15 @@ impl Executor {
             }
             Err(e) => {
                 error!("Executor: Execution failed for {}: {}", order.id, e);
+                self.event_bus
+                    .publish(TradingEvent::Decision(DecisionEvent::for_order(
+                        DecisionKind::OrderRejected,
+                        &order,
+                        e.to_string(),
+                    )))
+                    .await;
+                // Allow a later resubmission; the broker rejects it if this attempt landed
+                self.submitted_ids.write().await.remove(&order.id);
                 self.health_service
                     .set_execution_status(
                         ConnectionStatus::Offline,
@@ -229,6 +465,70 @@ impl Executor {
         Ok(())
     }
 
+    /// Feeds a broker fill into the fee model's traded volume and persists it as
+    /// filled, so `seed_fee_volume` can rebuild the volume after a restart
+    async fn record_fill(&self, update: OrderUpdate) {
+        let Some(price) = update.filled_avg_price else {
+            return;
+        };
+        if update.status != OrderStatus::Filled || update.filled_qty.is_zero() {
+            return;
+        }
+        let timestamp = update.timestamp.timestamp_millis();
+        self.fee_model
+            .record_fill(update.filled_qty, price, timestamp);
+
+        if let Some(repo) = &self.repository {
+            // Updates status, price and quantity of an order we submitted; inserts
+            // orders placed elsewhere (e.g. manually at the broker)
+            let filled = Order {
+                id: if update.client_order_id.is_empty() {
+                    update.order_id
+                } else {
+                    update.client_order_id
+                },
+                symbol: update.symbol,
+                side: update.side,
+                price,
+                quantity: update.filled_qty,
+                order_type: OrderType::Market,
+                status: OrderStatus::Filled,
+                timestamp,
+                reduce_only: false,
+                origin: Default::default(),
+                reason: None,
+            };
+            if let Err(e) = repo.save(&filled).await {
+                warn!("Executor: Failed to persist fill of {}: {}", filled.id, e);
+            }
+        }
+    }
+
+    /// Replays the persisted fills of the trailing volume window into the fee model,
+    /// which otherwise starts every session at the lowest tier
+    async fn seed_fee_volume(&self) -> Result<()> {
+        let Some(repo) = &self.repository else {
+            return Ok(());
+        };
+        let since = now_ms() - VolumeTracker::WINDOW_MS;
+        let mut fills: Vec<Order> = repo
+            .find_by_status(OrderStatus::Filled)
+            .await?
+            .into_iter()
+            .filter(|o| o.timestamp > since)
+            .collect();
+        fills.sort_by_key(|o| o.timestamp);
+        for fill in &fills {
+            self.fee_model
+                .record_fill(fill.quantity, fill.price, fill.timestamp);
+        }
+        info!(
+            "Executor: Restored traded volume from {} fill(s)",
+            fills.len()
+        );
+        Ok(())
+    }
+
     #[instrument(skip(self, order), fields(symbol = %order.symbol, side = ?order.side))]
     async fn update_portfolio(
         &self,
@@ -257,9 +557,13 @@ impl Executor {
             );
             broker_fees
         } else {
-            let trade_cost = self
-                .fee_model
-                .calculate_cost(order.quantity, order.price, order.side);
+            let trade_cost = self.fee_model.calculate_order_cost(
+                &order.symbol,
+                order.quantity,
+                order.price,
+                order.side,
+                order.order_type,
+            );
             let estimated = trade_cost.total_impact;
             info!(
                 "Executor: No broker fees, using model estimate: ${} for order {}",
@@ -273,33 +577,19 @@ impl Executor {
                 // If reversal (Buy), we ADD cash and fees back. If normal (Buy), we SUBTRACT cost + fees.
                 if is_reversal {
                     portfolio.cash += cost + fees;
-                } else {
-                    portfolio.cash -= cost + fees;
-                }
-
-                let position =
-                    portfolio
-                        .positions
-                        .entry(order.symbol.clone())
-                        .or_insert(Position {
-                            symbol: order.symbol.clone(),
-                            quantity: rust_decimal::Decimal::ZERO,
-                            average_price: rust_decimal::Decimal::ZERO,
-                        });
-
-                // Update position logic is complex for reversal of average price
-                // For simplicity in this fix, we primarily care about Quantity and Cash
-                if is_reversal {
-                    position.quantity -= order.quantity;
                     // NOTE: Average price reversal is lossy if we don't store history.
                     // Accepting this limitation for "blind" optimistic updates.
-                } else {
-                    let total_val = (position.quantity * position.average_price) + cost;
-                    let new_qty = position.quantity + order.quantity;
-                    if !new_qty.is_zero() {
-                        position.average_price = total_val / new_qty;
+                    if let Some(position) = portfolio.positions.get_mut(&order.symbol) {
+                        position.quantity -= order.quantity;
                     }
-                    position.quantity = new_qty;
+                } else {
+                    portfolio.apply_buy_fill(
+                        &order.symbol,
+                        order.quantity,
+                        order.price,
+                        fees,
+                        order.timestamp,
+                    );
                 }
             }
             OrderSide::Sell => {
@@ -308,11 +598,11 @@ impl Executor {
                     if let Some(position) = portfolio.positions.get_mut(&order.symbol) {
                         position.quantity += order.quantity;
                     }
+                } else if portfolio.allow_shorts || portfolio.positions.contains_key(&order.symbol)
+                {
+                    portfolio.apply_sell_fill(&order.symbol, order.quantity, order.price, fees);
                 } else {
                     portfolio.cash += cost - fees;
-                    if let Some(position) = portfolio.positions.get_mut(&order.symbol) {
-                        position.quantity -= order.quantity;
-                    }
                 }
             }
         }
@@ -328,6 +618,7 @@ mod tests {
 
     use async_trait::async_trait;
     use rust_decimal::Decimal;
+    use std::sync::atomic::{AtomicUsize, Ordering};
     use tokio::sync::mpsc;
 
     struct MockExecService;
@@ -388,6 +679,295 @@ mod tests {
         }
     }
 
+    /// Fails `failures` times (transient or rejected), then accepts
+    struct FlakyExecService {
+        failures: usize,
+        transient: bool,
+        attempts: AtomicUsize,
+    }
+    #[async_trait]
+    impl ExecutionService for FlakyExecService {
+        async fn execute(&self, _order: Order) -> Result<()> {
+            if self.attempts.fetch_add(1, Ordering::SeqCst) < self.failures {
+                let reason = "simulated".to_string();
+                return Err(if self.transient {
+                    OrderSubmitError::Transient { reason }.into()
+                } else {
+                    OrderSubmitError::Rejected { reason }.into()
+                });
+            }
+            Ok(())
+        }
+        async fn get_portfolio(&self) -> Result<Portfolio> {
+            Ok(Portfolio::new())
+        }
+        async fn get_today_orders(&self) -> Result<Vec<Order>> {
+            Ok(Vec::new())
+        }
+        async fn get_open_orders(&self) -> Result<Vec<Order>> {
+            Ok(Vec::new())
+        }
+        async fn cancel_order(&self, _order_id: &str, _symbol: &str) -> Result<()> {
+            Ok(())
+        }
+        async fn cancel_all_orders(&self) -> Result<()> {
+            Ok(())
+        }
+        async fn subscribe_order_updates(
+            &self,
+        ) -> Result<tokio::sync::broadcast::Receiver<OrderUpdate>> {
+            let (_tx, rx) = tokio::sync::broadcast::channel(1);
+            Ok(rx)
+        }
+    }
+
+    fn retry_config() -> RetryConfig {
+        RetryConfig {
+            submit_max_retries: 2,
+            submit_backoff_ms: 1,
+            ..Default::default()
+        }
+    }
+
+    /// First attempt, then the background retries `handle_order` hands transient failures to
+    async fn submit(
+        service: &dyn ExecutionService,
+        retry_config: &RetryConfig,
+        order: &Order,
+    ) -> Result<()> {
+        match service.execute(order.clone()).await {
+            Err(e) if OrderSubmitError::is_transient(&e) => {
+                Executor::retry_submission(service, retry_config, order, e).await
+            }
+            result => result,
+        }
+    }
+
+    fn flaky_executor(
+        service: Arc<FlakyExecService>,
+        retry_config: RetryConfig,
+        portfolio: Arc<RwLock<Portfolio>>,
+    ) -> (mpsc::Sender<Order>, Executor) {
+        let (tx, rx) = mpsc::channel(2);
+        let executor = Executor::new(
+            service,
+            rx,
+            portfolio,
+            None,
+            retry_config,
+            Arc::new(ConnectionHealthService::new()),
+            Arc::new(ConstantFeeModel::new(Decimal::ZERO, Decimal::ZERO)),
+            Arc::new(
+                crate::application::monitoring::agent_status::AgentStatusRegistry::new(
+                    crate::infrastructure::observability::Metrics::new().unwrap(),
+                ),
+            ),
+        );
+        (tx, executor)
+    }
+
+    fn market_order() -> Order {
+        Order {
+            id: "1".to_string(),
+            symbol: "ABC".to_string(),
+            side: OrderSide::Buy,
+            price: Decimal::from(100),
+            quantity: Decimal::from(2),
+            order_type: crate::domain::trading::types::OrderType::Market,
+            status: crate::domain::trading::types::OrderStatus::New,
+            timestamp: 0,
+            reduce_only: false,
+            origin: Default::default(),
+            reason: None,
+        }
+    }
+
+    #[tokio::test]
+    async fn test_transient_failure_is_retried() {
+        let service = Arc::new(FlakyExecService {
+            failures: 2,
+            transient: true,
+            attempts: AtomicUsize::new(0),
+        });
+        assert!(
+            submit(service.as_ref(), &retry_config(), &market_order())
+                .await
+                .is_ok()
+        );
+        assert_eq!(service.attempts.load(Ordering::SeqCst), 3);
+    }
+
+    #[tokio::test]
+    async fn test_retries_are_bounded() {
+        let service = Arc::new(FlakyExecService {
+            failures: 10,
+            transient: true,
+            attempts: AtomicUsize::new(0),
+        });
+        assert!(
+            submit(service.as_ref(), &retry_config(), &market_order())
+                .await
+                .is_err()
+        );
+        assert_eq!(service.attempts.load(Ordering::SeqCst), 3); // 1 + 2 retries
+    }
+
+    #[tokio::test]
+    async fn test_rejection_is_not_retried() {
+        let service = Arc::new(FlakyExecService {
+            failures: 1,
+            transient: false,
+            attempts: AtomicUsize::new(0),
+        });
+        assert!(
+            submit(service.as_ref(), &retry_config(), &market_order())
+                .await
+                .is_err()
+        );
+        assert_eq!(service.attempts.load(Ordering::SeqCst), 1);
+    }
+
+    /// Accepts every order but reports a timeout on the first submission, like a
+    /// broker that fills the order before the response is lost
+    struct TimeoutAfterAcceptService {
+        placed: std::sync::Mutex<Vec<String>>,
+    }
+    #[async_trait]
+    impl ExecutionService for TimeoutAfterAcceptService {
+        async fn execute(&self, order: Order) -> Result<()> {
+            let mut placed = self.placed.lock().unwrap();
+            placed.push(order.id);
+            if placed.len() == 1 {
+                return Err(OrderSubmitError::Transient {
+                    reason: "timed out".to_string(),
+                }
+                .into());
+            }
+            Ok(())
+        }
+        async fn client_order_exists(
+            &self,
+            _symbol: &str,
+            client_order_id: &str,
+        ) -> Result<Option<bool>> {
+            Ok(Some(
+                self.placed
+                    .lock()
+                    .unwrap()
+                    .iter()
+                    .any(|id| id == client_order_id),
+            ))
+        }
+        async fn get_portfolio(&self) -> Result<Portfolio> {
+            Ok(Portfolio::new())
+        }
+        async fn get_today_orders(&self) -> Result<Vec<Order>> {
+            Ok(Vec::new())
+        }
+        async fn get_open_orders(&self) -> Result<Vec<Order>> {
+            Ok(Vec::new())
+        }
+        async fn cancel_order(&self, _order_id: &str, _symbol: &str) -> Result<()> {
+            Ok(())
+        }
+        async fn cancel_all_orders(&self) -> Result<()> {
+            Ok(())
+        }
+        async fn subscribe_order_updates(
+            &self,
+        ) -> Result<tokio::sync::broadcast::Receiver<OrderUpdate>> {
+            let (_tx, rx) = tokio::sync::broadcast::channel(1);
+            Ok(rx)
+        }
+    }
+
+    #[tokio::test]
+    async fn test_order_accepted_before_timeout_is_not_resubmitted() {
+        let service = Arc::new(TimeoutAfterAcceptService {
+            placed: std::sync::Mutex::new(Vec::new()),
+        });
+        assert!(
+            submit(service.as_ref(), &retry_config(), &market_order())
+                .await
+                .is_ok()
+        );
+        assert_eq!(service.placed.lock().unwrap().len(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_retries_stop_at_time_budget_unless_reduce_only() {
+        let retry_config = RetryConfig {
+            submit_max_retries: 2,
+            submit_backoff_ms: 60_000,
+            submit_max_retry_ms: 1_000,
+            ..Default::default()
+        };
+        let service = Arc::new(FlakyExecService {
+            failures: 1,
+            transient: true,
+            attempts: AtomicUsize::new(0),
+        });
+        // The first backoff alone exceeds the budget
+        assert!(
+            submit(service.as_ref(), &retry_config, &market_order())
+                .await
+                .is_err()
+        );
+        assert_eq!(service.attempts.load(Ordering::SeqCst), 1);
+
+        // An exit is resubmitted right away
+        let service = Arc::new(FlakyExecService {
+            failures: 1,
+            transient: true,
+            attempts: AtomicUsize::new(0),
+        });
+        let exit = Order {
+            side: OrderSide::Sell,
+            reduce_only: true,
+            ..market_order()
+        };
+        assert!(submit(service.as_ref(), &retry_config, &exit).await.is_ok());
+        assert_eq!(service.attempts.load(Ordering::SeqCst), 2);
+    }
+
+    #[tokio::test]
+    async fn test_retry_backoff_does_not_block_other_orders() {
+        let service = Arc::new(FlakyExecService {
+            failures: 1,
+            transient: true,
+            attempts: AtomicUsize::new(0),
+        });
+        let mut port = Portfolio::new();
+        port.cash = Decimal::from(1000);
+        let portfolio = Arc::new(RwLock::new(port));
+        let (tx, mut executor) = flaky_executor(
+            service,
+            RetryConfig {
+                submit_max_retries: 1,
+                submit_backoff_ms: 60_000,
+                submit_max_retry_ms: 120_000,
+                ..Default::default()
+            },
+            portfolio.clone(),
+        );
+        tokio::spawn(async move { executor.run().await });
+
+        // The first order fails and waits out its backoff; the second goes through meanwhile
+        tx.send(market_order()).await.unwrap();
+        tx.send(Order {
+            id: "2".to_string(),
+            symbol: "XYZ".to_string(),
+            ..market_order()
+        })
+        .await
+        .unwrap();
+        tokio::time::sleep(std::time::Duration::from_millis(50)).await;
+
+        let p = portfolio.read().await;
+        assert!(p.positions.contains_key("XYZ"));
+        assert!(!p.positions.contains_key("ABC"));
+    }
+
     #[tokio::test]
     async fn test_buy_updates_portfolio() {
         let (tx, rx) = mpsc::channel(1);
@@ -421,6 +1001,9 @@ mod tests {
             order_type: crate::domain::trading::types::OrderType::Limit,
             status: crate::domain::trading::types::OrderStatus::New,
             timestamp: 0,
+            reduce_only: false,
+            origin: Default::default(),
+            reason: None,
         };
         tx.send(order).await.expect("Failed to send order in test");
 
@@ -466,6 +1049,9 @@ mod tests {
             order_type: crate::domain::trading::types::OrderType::Limit,
             status: crate::domain::trading::types::OrderStatus::New,
             timestamp: 0,
+            reduce_only: false,
+            origin: Default::default(),
+            reason: None,
         };
         tx.send(order).await.expect("Failed to send order in test");
 
@@ -474,4 +1060,220 @@ mod tests {
         let p = portfolio.read().await;
         assert_eq!(p.cash, Decimal::from(1000)); // Unchanged
     }
+
+    #[tokio::test]
+    async fn test_duplicate_client_order_id_is_not_resubmitted() {
+        let (tx, rx) = mpsc::channel(2);
+        let mut port = Portfolio::new();
+        port.cash = Decimal::from(1000);
+        let portfolio = Arc::new(RwLock::new(port));
+
+        let fee_model = Arc::new(ConstantFeeModel::new(Decimal::ZERO, Decimal::ZERO));
+        let mut executor = Executor::new(
+            Arc::new(MockExecService),
+            rx,
+            portfolio.clone(),
+            None,
+            RetryConfig::default(),
+            Arc::new(ConnectionHealthService::new()),
+            fee_model,
+            Arc::new(
+                crate::application::monitoring::agent_status::AgentStatusRegistry::new(
+                    crate::infrastructure::observability::Metrics::new().unwrap(),
+                ),
+            ),
+        );
+        tokio::spawn(async move { executor.run().await });
+
+        let order = Order {
+            id: Order::client_order_id("ABC", OrderSide::Buy, 0, "Test", Decimal::from(2)),
+            symbol: "ABC".to_string(),
+            side: OrderSide::Buy,
+            price: Decimal::from(100),
+            quantity: Decimal::from(2),
+            order_type: crate::domain::trading::types::OrderType::Market,
+            status: crate::domain::trading::types::OrderStatus::New,
+            timestamp: 0,
+            reduce_only: false,
+            origin: Default::default(),
+            reason: None,
+        };
+        tx.send(order.clone()).await.unwrap();
+        tx.send(order).await.unwrap();
+
+        tokio::time::sleep(std::time::Duration::from_millis(50)).await;
+
+        let p = portfolio.read().await;
+        assert_eq!(p.cash, Decimal::from(800)); // Filled once
+        assert_eq!(p.positions.get("ABC").unwrap().quantity, Decimal::from(2));
+    }
+
+    #[tokio::test]
+    async fn test_reduce_only_order_books_clamped_quantity() {
+        let (tx, rx) = mpsc::channel(1);
+        let mut port = Portfolio::new();
+        port.cash = Decimal::from(1000);
+        port.positions.insert(
+            "ABC".to_string(),
+            crate::domain::trading::portfolio::Position {
+                symbol: "ABC".to_string(),
+                quantity: Decimal::from(3),
+                average_price: Decimal::from(100),
+                lots: Default::default(),
+            },
+        );
+        let portfolio = Arc::new(RwLock::new(port));
+
+        let fee_model = Arc::new(ConstantFeeModel::new(Decimal::ZERO, Decimal::ZERO));
+        let mut executor = Executor::new(
+            Arc::new(MockExecService),
+            rx,
+            portfolio.clone(),
+            None,
+            RetryConfig::default(),
+            Arc::new(ConnectionHealthService::new()),
+            fee_model,
+            Arc::new(
+                crate::application::monitoring::agent_status::AgentStatusRegistry::new(
+                    crate::infrastructure::observability::Metrics::new().unwrap(),
+                ),
+            ),
+        );
+        tokio::spawn(async move { executor.run().await });
+
+        // Sells more than held: only the held 3 may execute
+        let order = Order {
+            side: OrderSide::Sell,
+            quantity: Decimal::from(5),
+            reduce_only: true,
+            ..market_order()
+        };
+        tx.send(order).await.expect("Failed to send order in test");
+
+        tokio::time::sleep(std::time::Duration::from_millis(50)).await;
+
+        let p = portfolio.read().await;
+        assert_eq!(p.cash, Decimal::from(1300)); // 1000 + 100*3, not 100*5
+        assert!(
+            p.positions
+                .get("ABC")
+                .is_none_or(|pos| pos.quantity.is_zero()),
+            "The position closes instead of reversing short"
+        );
+    }
+
+    /// Accepts every order; the test plays the broker's order updates
+    struct StreamingExecService {
+        updates: tokio::sync::broadcast::Sender<OrderUpdate>,
+    }
+    #[async_trait]
+    impl ExecutionService for StreamingExecService {
+        async fn execute(&self, _order: Order) -> Result<()> {
+            Ok(())
+        }
+        async fn get_portfolio(&self) -> Result<Portfolio> {
+            Ok(Portfolio::new())
+        }
+        async fn get_today_orders(&self) -> Result<Vec<Order>> {
+            Ok(Vec::new())
+        }
+        async fn get_open_orders(&self) -> Result<Vec<Order>> {
+            Ok(Vec::new())
+        }
+        async fn cancel_order(&self, _order_id: &str, _symbol: &str) -> Result<()> {
+            Ok(())
+        }
+        async fn cancel_all_orders(&self) -> Result<()> {
+            Ok(())
+        }
+        async fn subscribe_order_updates(
+            &self,
+        ) -> Result<tokio::sync::broadcast::Receiver<OrderUpdate>> {
+            Ok(self.updates.subscribe())
+        }
+    }
+
+    #[tokio::test]
+    async fn test_fee_volume_is_restored_and_follows_fills() {
+        use crate::domain::trading::fee_model::TieredFeeModel;
+        use crate::infrastructure::persistence::in_memory::InMemoryTradeRepository;
+
+        let fee_model = Arc::new(TieredFeeModel::new(
+            Decimal::ZERO,
+            Decimal::ZERO,
+            Decimal::ZERO,
+        ));
+        let volume = fee_model.volume_tracker();
+        let repository = Arc::new(InMemoryTradeRepository::new());
+        for (id, days_ago) in [("recent", 1), ("expired", 40)] {
+            repository
+                .save(&Order {
+                    id: id.to_string(),
+                    price: Decimal::from(1000),
+                    quantity: Decimal::ONE,
+                    status: OrderStatus::Filled,
+                    timestamp: now_ms() - days_ago * MS_PER_DAY,
+                    ..market_order()
+                })
+                .await
+                .unwrap();
+        }
+
+        let service = Arc::new(StreamingExecService {
+            updates: tokio::sync::broadcast::channel(4).0,
+        });
+        let (tx, rx) = mpsc::channel(1);
+        let mut executor = Executor::new(
+            service.clone(),
+            rx,
+            Arc::new(RwLock::new(Portfolio::new())),
+            Some(repository.clone()),
+            RetryConfig::default(),
+            Arc::new(ConnectionHealthService::new()),
+            fee_model,
+            Arc::new(
+                crate::application::monitoring::agent_status::AgentStatusRegistry::new(
+                    crate::infrastructure::observability::Metrics::new().unwrap(),
+                ),
+            ),
+        );
+        tokio::spawn(async move { executor.run().await });
+        tokio::time::sleep(std::time::Duration::from_millis(50)).await;
+        assert_eq!(
+            volume.volume(),
+            Decimal::from(1000),
+            "Only fills inside the window"
+        );
+
+        // Accepted is not traded
+        tx.send(market_order()).await.unwrap();
+        tokio::time::sleep(std::time::Duration::from_millis(50)).await;
+        assert_eq!(volume.volume(), Decimal::from(1000));
+
+        service
+            .updates
+            .send(OrderUpdate {
+                order_id: "broker-1".to_string(),
+                client_order_id: "1".to_string(),
+                symbol: "ABC".to_string(),
+                side: OrderSide::Buy,
+                status: OrderStatus::Filled,
+                filled_qty: Decimal::from(2),
+                filled_avg_price: Some(Decimal::from(100)),
+                timestamp: chrono::Utc::now(),
+                fees: None,
+            })
+            .unwrap();
+        tokio::time::sleep(std::time::Duration::from_millis(50)).await;
+        assert_eq!(volume.volume(), Decimal::from(1200));
+        assert!(
+            repository
+                .find_by_status(OrderStatus::Filled)
+                .await
+                .unwrap()
+                .iter()
+                .any(|o| o.id == "1"),
+            "The fill is persisted for the next restart"
+        );
+    }
 }
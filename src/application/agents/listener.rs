@@ -1,10 +1,11 @@
 use anyhow::{Context, Result};
 use std::sync::Arc;
 use tokio::sync::{broadcast, mpsc};
-use tracing::{error, info, warn};
+use tracing::{debug, error, info, warn};
 
 use crate::domain::listener::{ListenerAction, ListenerConfig, ListenerRule, NewsEvent};
 use crate::domain::ports::NewsDataService;
+use crate::infrastructure::news::sentiment_analyzer::SentimentAnalyzer;
 
 pub struct ListenerAgent {
     news_service: Arc<dyn NewsDataService>,
@@ -13,6 +14,8 @@ pub struct ListenerAgent {
     /// Optional broadcast sender to forward news events to UI
     news_broadcast_tx: Option<broadcast::Sender<NewsEvent>>,
     agent_registry: Arc<crate::application::monitoring::agent_status::AgentStatusRegistry>,
+    /// Scores events the provider left unscored, like the RSS/JSON feeds do
+    sentiment_analyzer: SentimentAnalyzer,
 }
 
 impl ListenerAgent {
@@ -28,6 +31,7 @@ impl ListenerAgent {
             analyst_cmd_tx,
             news_broadcast_tx: None,
             agent_registry,
+            sentiment_analyzer: SentimentAnalyzer::new(),
         }
     }
 
@@ -45,6 +49,7 @@ impl ListenerAgent {
             analyst_cmd_tx,
             news_broadcast_tx: Some(news_broadcast_tx),
             agent_registry,
+            sentiment_analyzer: SentimentAnalyzer::new(),
         }
     }
 
@@ -166,6 +171,39 @@ impl ListenerAgent {
     async fn trigger_action(&self, rule: &ListenerRule, event: &NewsEvent) -> Result<()> {
         let sentiment = match rule.action {
             ListenerAction::NotifyAnalyst(s) => s,
+            ListenerAction::NotifyAnalystScored => {
+                let (score, sentiment) = match event.sentiment_score {
+                    Some(score) => (
+                        score,
+                        crate::domain::listener::NewsSentiment::from_score(
+                            score,
+                            self.config.provider_score_threshold,
+                        ),
+                    ),
+                    None => {
+                        let score = self
+                            .sentiment_analyzer
+                            .analyze_news(&event.title, &event.content);
+                        (
+                            score,
+                            crate::domain::listener::NewsSentiment::from_score(
+                                score,
+                                self.config.headline_score_threshold,
+                            ),
+                        )
+                    }
+                };
+                match sentiment {
+                    crate::domain::listener::NewsSentiment::Neutral => {
+                        debug!(
+                            "Listener: '{}' scored neutral ({:.2}), not notifying Analyst",
+                            event.title, score
+                        );
+                        return Ok(());
+                    }
+                    s => s,
+                }
+            }
             // Map legacy actions to sentiment
             ListenerAction::BuyImmediate => crate::domain::listener::NewsSentiment::Bullish,
             ListenerAction::SellImmediate => crate::domain::listener::NewsSentiment::Bearish,
@@ -236,6 +274,8 @@ mod tests {
                 ),
                 active: true,
             }],
+            headline_score_threshold: 0.3,
+            provider_score_threshold: 0.3,
         };
 
         let agent_registry = Arc::new(
@@ -278,4 +318,118 @@ mod tests {
             _ => panic!("Expected ProcessNews command"),
         }
     }
+
+    #[tokio::test]
+    async fn test_scored_rule_uses_headline_when_unlabelled() {
+        let (_news_tx, news_rx) = mpsc::channel(1);
+        let (analyst_cmd_tx, mut analyst_cmd_rx) = mpsc::channel(10);
+        let news_service = Arc::new(TestNewsService {
+            rx: tokio::sync::Mutex::new(Some(news_rx)),
+        });
+        let rule = ListenerRule {
+            id: "scored".to_string(),
+            keywords: vec!["Exchange".to_string()],
+            target_symbol: "TEST/USD".to_string(),
+            action: ListenerAction::NotifyAnalystScored,
+            active: true,
+        };
+        let config = ListenerConfig {
+            poll_interval_seconds: 1,
+            rules: vec![rule.clone()],
+            headline_score_threshold: 0.3,
+            provider_score_threshold: 0.3,
+        };
+        let agent_registry = Arc::new(
+            crate::application::monitoring::agent_status::AgentStatusRegistry::new(
+                crate::infrastructure::observability::Metrics::new().unwrap(),
+            ),
+        );
+        let agent = ListenerAgent::new(news_service, config, analyst_cmd_tx, agent_registry);
+
+        let event = |title: &str| NewsEvent {
+            id: Uuid::new_v4().to_string(),
+            source: "Test".to_string(),
+            title: title.to_string(),
+            content: String::new(),
+            url: None,
+            timestamp: Utc::now(),
+            sentiment_score: None,
+        };
+
+        // Neutral headline is not forwarded
+        agent
+            .trigger_action(&rule, &event("Exchange publishes quarterly report"))
+            .await
+            .unwrap();
+        assert!(analyst_cmd_rx.try_recv().is_err());
+
+        agent
+            .trigger_action(&rule, &event("Exchange hacked, withdrawals halted"))
+            .await
+            .unwrap();
+        match analyst_cmd_rx.try_recv().expect("Bearish signal expected") {
+            crate::application::agents::analyst::AnalystCommand::ProcessNews(signal) => {
+                assert_eq!(
+                    signal.sentiment,
+                    crate::domain::listener::NewsSentiment::Bearish
+                );
+            }
+            _ => panic!("Expected ProcessNews command"),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_scored_rule_classifies_provider_score_with_provider_threshold() {
+        let (_news_tx, news_rx) = mpsc::channel(1);
+        let (analyst_cmd_tx, mut analyst_cmd_rx) = mpsc::channel(10);
+        let news_service = Arc::new(TestNewsService {
+            rx: tokio::sync::Mutex::new(Some(news_rx)),
+        });
+        let rule = ListenerRule {
+            id: "scored".to_string(),
+            keywords: vec!["Exchange".to_string()],
+            target_symbol: "TEST/USD".to_string(),
+            action: ListenerAction::NotifyAnalystScored,
+            active: true,
+        };
+        // Strict headline threshold, loose provider threshold
+        let config = ListenerConfig {
+            poll_interval_seconds: 1,
+            rules: vec![rule.clone()],
+            headline_score_threshold: 0.9,
+            provider_score_threshold: 0.2,
+        };
+        let agent_registry = Arc::new(
+            crate::application::monitoring::agent_status::AgentStatusRegistry::new(
+                crate::infrastructure::observability::Metrics::new().unwrap(),
+            ),
+        );
+        let agent = ListenerAgent::new(news_service, config, analyst_cmd_tx, agent_registry);
+
+        let event = |score: f64| NewsEvent {
+            id: Uuid::new_v4().to_string(),
+            source: "Test".to_string(),
+            title: "Exchange publishes quarterly report".to_string(),
+            content: String::new(),
+            url: None,
+            timestamp: Utc::now(),
+            sentiment_score: Some(score),
+        };
+
+        // Below the provider threshold: not forwarded
+        agent.trigger_action(&rule, &event(0.1)).await.unwrap();
+        assert!(analyst_cmd_rx.try_recv().is_err());
+
+        // Above the provider threshold but below the headline one: forwarded
+        agent.trigger_action(&rule, &event(0.5)).await.unwrap();
+        match analyst_cmd_rx.try_recv().expect("Bullish signal expected") {
+            crate::application::agents::analyst::AnalystCommand::ProcessNews(signal) => {
+                assert_eq!(
+                    signal.sentiment,
+                    crate::domain::listener::NewsSentiment::Bullish
+                );
+            }
+            _ => panic!("Expected ProcessNews command"),
+        }
+    }
 }
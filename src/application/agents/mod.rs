@@ -5,7 +5,9 @@ pub mod candle_pipeline;
 pub mod executor;
 pub mod listener;
 pub mod news_handler;
+pub mod portfolio_cache;
 pub mod position_lifecycle;
+pub mod proposal_dispatcher;
 pub mod regime_handler;
 pub mod scanner;
 pub mod sentinel;
@@ -7,13 +7,18 @@
 
 use crate::application::agents::analyst_config::AnalystConfig;
 use crate::application::trading::symbol_context::SymbolContext;
+use crate::application::trading::trade_filter::TradeFilter;
 use crate::domain::listener::NewsSignal;
 use crate::domain::ports::ExecutionService;
+use crate::domain::trading::time::secs_to_ms;
 use crate::domain::trading::types::{OrderSide, TradeProposal};
 use rust_decimal::Decimal;
+use std::collections::hash_map::DefaultHasher;
+use std::collections::{HashMap, VecDeque};
+use std::hash::{Hash, Hasher};
 use std::sync::Arc;
 use tokio::sync::mpsc::Sender;
-use tracing::{info, warn};
+use tracing::{debug, info, warn};
 
 /// Result of news signal processing
 pub enum NewsAction {
@@ -33,13 +38,119 @@ use rust_decimal_macros::dec;
 
 use crate::application::agents::signal_processor::SignalProcessor;
 
+/// Maximum number of (symbol, headline) keys remembered for deduplication.
+const NEWS_DEDUP_CAPACITY: usize = 512;
+
+/// Bounded LRU of recently seen (symbol, normalized headline) hashes.
+///
+/// Syndicated copies of the same story arrive from several feeds within
+/// minutes; only the first one should reach the trading logic.
+pub struct NewsDeduplicator {
+    capacity: usize,
+    last_seen: HashMap<u64, i64>,
+    order: VecDeque<u64>,
+}
+
+impl NewsDeduplicator {
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            capacity: capacity.max(1),
+            last_seen: HashMap::with_capacity(capacity),
+            order: VecDeque::with_capacity(capacity),
+        }
+    }
+
+    /// Records the signal and returns true if the same story for the same
+    /// symbol was already seen within `window_ms` of `timestamp_ms`.
+    pub fn is_duplicate(&mut self, signal: &NewsSignal, timestamp_ms: i64, window_ms: i64) -> bool {
+        let key = dedup_key(&signal.symbol, &signal.headline);
+        let duplicate = self
+            .last_seen
+            .get(&key)
+            .is_some_and(|seen| timestamp_ms - seen < window_ms);
+
+        // Refresh recency (LRU): move key to the back
+        if self.last_seen.insert(key, timestamp_ms).is_some() {
+            self.order.retain(|k| *k != key);
+        }
+        self.order.push_back(key);
+
+        while self.order.len() > self.capacity {
+            if let Some(evicted) = self.order.pop_front() {
+                self.last_seen.remove(&evicted);
+            }
+        }
+
+        duplicate
+    }
+
+    pub fn len(&self) -> usize {
+        self.order.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.order.is_empty()
+    }
+}
+
+/// Hash of symbol + headline lowercased, stripped of punctuation, whitespace collapsed.
+fn dedup_key(symbol: &str, headline: &str) -> u64 {
+    let normalized = headline
+        .chars()
+        .map(|c| {
+            if c.is_alphanumeric() {
+                c.to_ascii_lowercase()
+            } else {
+                ' '
+            }
+        })
+        .collect::<String>();
+    let normalized = normalized.split_whitespace().collect::<Vec<_>>().join(" ");
+
+    let mut hasher = DefaultHasher::new();
+    symbol.hash(&mut hasher);
+    normalized.hash(&mut hasher);
+    hasher.finish()
+}
+
 pub struct NewsHandler {
     signal_processor: SignalProcessor,
+    trade_filter: TradeFilter,
+    deduplicator: NewsDeduplicator,
 }
 
 impl NewsHandler {
-    pub fn new(signal_processor: SignalProcessor) -> Self {
-        Self { signal_processor }
+    pub fn new(signal_processor: SignalProcessor, trade_filter: TradeFilter) -> Self {
+        Self {
+            signal_processor,
+            trade_filter,
+            deduplicator: NewsDeduplicator::new(NEWS_DEDUP_CAPACITY),
+        }
+    }
+
+    /// Returns true (and logs at debug) if the signal repeats a story already
+    /// handled within `config.news_dedup_window_seconds`. A zero window disables dedup.
+    pub fn is_duplicate(
+        &mut self,
+        config: &AnalystConfig,
+        signal: &NewsSignal,
+        timestamp_ms: i64,
+    ) -> bool {
+        if config.news_dedup_window_seconds == 0 {
+            return false;
+        }
+        let duplicate = self.deduplicator.is_duplicate(
+            signal,
+            timestamp_ms,
+            secs_to_ms(config.news_dedup_window_seconds as i64),
+        );
+        if duplicate {
+            debug!(
+                "NewsHandler: Suppressed duplicate news for {}: {}",
+                signal.symbol, signal.headline
+            );
+        }
+        duplicate
     }
 
     /// Processes a bullish news signal with technical filters.
@@ -48,9 +159,9 @@ impl NewsHandler {
         config: &AnalystConfig,
         execution_service: &Arc<dyn ExecutionService>,
         signal: &NewsSignal,
-        context: &SymbolContext,
+        context: &mut SymbolContext,
         price: Decimal,
-        timestamp: i64,
+        timestamp_ms: i64,
     ) -> NewsAction {
         let sma_50 = context.last_features.sma_50.unwrap_or(Decimal::ZERO);
         let rsi = context.last_features.rsi.unwrap_or(dec!(50.0));
@@ -60,6 +171,36 @@ impl NewsHandler {
             signal.symbol, price, sma_50, rsi
         );
 
+        // 0. Trading Hours: no new entries outside the configured windows
+        if !config.trading_windows.is_open(timestamp_ms) {
+            let reason = format!("Outside trading window ({})", config.trading_windows);
+            warn!(
+                "NewsHandler: REJECTED Bullish News for {}. {}",
+                signal.symbol, reason
+            );
+            return NewsAction::Rejected(reason);
+        }
+
+        // 0b. Re-entry cooldown after a news-driven exit
+        if context.in_news_reentry_cooldown(timestamp_ms) {
+            let reason = "News re-entry cooldown active".to_string();
+            warn!(
+                "NewsHandler: REJECTED Bullish News for {}. {}",
+                signal.symbol, reason
+            );
+            return NewsAction::Rejected(reason);
+        }
+
+        // 0c. Indicators are being re-warmed after a structural config change
+        if context.rewarm_pending {
+            let reason = "Indicator re-warmup pending".to_string();
+            warn!(
+                "NewsHandler: REJECTED Bullish News for {}. {}",
+                signal.symbol, reason
+            );
+            return NewsAction::Rejected(reason);
+        }
+
         // 1. Trend Filter: Avoid buying falling knives
         if price < sma_50 {
             let reason = format!("Price ({}) below SMA50 ({}) - Bearish Trend", price, sma_50);
@@ -83,28 +224,147 @@ impl NewsHandler {
         // 3. Construct Proposal
         let reason = format!("News (Trend Correct & RSI OK): {}", signal.headline);
         let signal_obj = crate::application::strategies::Signal::buy(reason);
-        if let Some(mut proposal) = self
-            .signal_processor
-            .build_proposal(
-                config,
-                execution_service,
-                signal.symbol.clone(),
-                signal_obj,
-                price,
-                timestamp * 1000,
-            )
-            .await
+        let portfolio = match execution_service.get_portfolio().await {
+            Ok(p) => p,
+            Err(e) => {
+                debug!(
+                    "NewsHandler [{}]: Failed to get portfolio: {}",
+                    signal.symbol, e
+                );
+                return NewsAction::NoAction;
+            }
+        };
+        let held_quantity = portfolio
+            .positions
+            .get(&signal.symbol)
+            .map(|p| p.quantity)
+            .unwrap_or(Decimal::ZERO);
+
+        // 4. Same entry gates as the candle path
+        if let Some(reason) =
+            self.entry_gate_reason(config, signal, context, held_quantity, timestamp_ms)
         {
+            warn!(
+                "NewsHandler: REJECTED Bullish News for {}. {}",
+                signal.symbol, reason
+            );
+            return NewsAction::Rejected(reason);
+        }
+
+        if let Some(mut proposal) = self.signal_processor.build_proposal(
+            config,
+            &portfolio,
+            signal.symbol.clone(),
+            signal_obj,
+            price,
+            timestamp_ms,
+        ) {
             proposal.order_type = crate::domain::trading::types::OrderType::Market;
+
+            let mut prices = std::collections::HashMap::new();
+            prices.insert(signal.symbol.clone(), price);
+            let total_equity = portfolio.total_equity(&prices);
+            proposal.quantity = scale_news_quantity(
+                config,
+                proposal.quantity,
+                price,
+                total_equity,
+                portfolio.cash,
+                held_quantity,
+            );
+            if proposal.quantity <= Decimal::ZERO {
+                return NewsAction::NoAction;
+            }
+
+            // Track it like a candle entry so its fill counts against the daily cap
+            context
+                .position_manager
+                .set_pending_order(OrderSide::Buy, timestamp_ms, held_quantity);
+            if held_quantity.is_zero() {
+                context.position_manager.pending_entry_day =
+                    Some(config.trading_day_boundary.trading_day_at_ms(timestamp_ms));
+            }
+
             info!(
-                "NewsHandler: Proposing BUY based on Validated News: {}",
-                signal.headline
+                "NewsHandler: Proposing BUY based on Validated News: {} (qty: {})",
+                signal.headline, proposal.quantity
             );
             return NewsAction::Buy(proposal);
         }
 
         NewsAction::NoAction
     }
+
+    /// Open blackout, daily trade cap and post-stop cooldown, as checked for candle entries.
+    /// A buy that covers a short is an exit and is never gated.
+    fn entry_gate_reason(
+        &self,
+        config: &AnalystConfig,
+        signal: &NewsSignal,
+        context: &SymbolContext,
+        held_quantity: Decimal,
+        timestamp_ms: i64,
+    ) -> Option<String> {
+        if !OrderSide::Buy.increases_exposure(held_quantity) {
+            return None;
+        }
+        if held_quantity.is_zero() {
+            if !self
+                .trade_filter
+                .validate_open_blackout(&signal.symbol, config, timestamp_ms)
+            {
+                return Some("Inside the open blackout".to_string());
+            }
+            if !self.trade_filter.validate_daily_trade_cap(
+                &signal.symbol,
+                &context.position_manager,
+                config,
+                timestamp_ms,
+            ) {
+                return Some("Daily trade cap reached".to_string());
+            }
+        }
+        if context
+            .position_manager
+            .in_post_stop_cooldown(timestamp_ms, config.post_stop_cooldown_seconds)
+        {
+            return Some("Post-stop cooldown active".to_string());
+        }
+        None
+    }
+}
+
+/// Applies news-specific sizing to a strategy-sized buy quantity.
+///
+/// The quantity is scaled by `news_trade_size_multiplier`, capped by
+/// `news_trade_max_value_usd` when set, and clamped so the resulting position
+/// (including the `held_quantity` already owned) never exceeds
+/// `max_position_size_pct` of equity, and the notional never exceeds the
+/// available cash. No cash means no size.
+pub fn scale_news_quantity(
+    config: &AnalystConfig,
+    base_quantity: Decimal,
+    price: Decimal,
+    total_equity: Decimal,
+    available_cash: Decimal,
+    held_quantity: Decimal,
+) -> Decimal {
+    if price <= Decimal::ZERO || available_cash <= Decimal::ZERO {
+        return Decimal::ZERO;
+    }
+
+    let mut notional = base_quantity * price * config.news_trade_size_multiplier;
+
+    if let Some(cap) = config.news_trade_max_value_usd {
+        notional = notional.min(cap);
+    }
+    if config.max_position_size_pct > Decimal::ZERO {
+        let held_exposure = held_quantity.max(Decimal::ZERO) * price;
+        notional = notional.min(total_equity * config.max_position_size_pct - held_exposure);
+    }
+    notional = notional.min(available_cash);
+
+    (notional / price).round_dp(4).max(Decimal::ZERO)
 }
 
 /// Processes a bearish news signal for an existing position.
@@ -118,7 +378,7 @@ impl NewsHandler {
 /// * `context` - Symbol context with position and indicators
 /// * `portfolio_position` - Current position data (quantity, average price)
 /// * `current_price` - Current market price
-/// * `timestamp` - Current timestamp
+/// * `timestamp_ms` - Current timestamp (Unix milliseconds)
 ///
 /// # Returns
 /// A `NewsAction` indicating what action was taken.
@@ -127,7 +387,7 @@ pub fn process_bearish_news(
     context: &mut SymbolContext,
     portfolio_position: (Decimal, Decimal), // (quantity, average_price)
     current_price: Decimal,
-    timestamp: i64,
+    timestamp_ms: i64,
 ) -> NewsAction {
     let (quantity, avg_price) = portfolio_position;
     let pnl_pct = if !avg_price.is_zero() {
@@ -153,6 +413,7 @@ pub fn process_bearish_news(
         "NewsHandler: News Triggering PANIC SELL for {} to limit potential loss.",
         signal.symbol
     );
+    context.last_news_exit_time = Some(timestamp_ms);
 
     let proposal = TradeProposal {
         symbol: signal.symbol.clone(),
@@ -165,9 +426,11 @@ pub fn process_bearish_news(
             pnl_pct * dec!(100.0),
             signal.headline
         ),
-        timestamp,
+        timestamp: timestamp_ms,
         stop_loss: None,
         take_profit: None,
+        reduce_only: false,
+        origin: Default::default(),
     };
 
     NewsAction::PanicSell(proposal)
@@ -261,7 +524,7 @@ mod tests {
             &mut context,
             (Decimal::from(10), Decimal::from(100)),
             Decimal::from(110),
-            1000,
+            1_000_000,
         );
 
         match action {
@@ -274,6 +537,7 @@ mod tests {
     fn test_bearish_news_action_losing() {
         let mut context = create_test_context();
         context.last_features.atr = Some(dec!(1.0));
+        context.config.news_reentry_cooldown_seconds = 3600;
 
         let signal = NewsSignal {
             symbol: "TEST".to_string(),
@@ -289,7 +553,7 @@ mod tests {
             &mut context,
             (Decimal::from(10), Decimal::from(100)),
             Decimal::from(95),
-            1000,
+            1_000_000,
         );
 
         match action {
@@ -297,8 +561,206 @@ mod tests {
                 assert_eq!(proposal.symbol, "TEST");
                 assert_eq!(proposal.side, OrderSide::Sell);
                 assert_eq!(proposal.quantity, Decimal::from(10));
+                assert_eq!(proposal.timestamp, 1_000_000);
             }
             _ => panic!("Expected PanicSell action for losing position"),
         }
+
+        // Re-entry is blocked for the cooldown window, then allowed again
+        let cooldown_ms = 3_600_000;
+        assert_eq!(context.last_news_exit_time, Some(1_000_000));
+        assert!(context.in_news_reentry_cooldown(1_000_000 + cooldown_ms - 1));
+        assert!(!context.in_news_reentry_cooldown(1_000_000 + cooldown_ms));
+
+        // Off by default: no cooldown
+        context.config.news_reentry_cooldown_seconds = 0;
+        assert!(!context.in_news_reentry_cooldown(1_000_001));
+    }
+
+    #[tokio::test]
+    async fn test_bullish_news_respects_candle_entry_gates() {
+        use crate::application::market_data::spread_cache::SpreadCache;
+        use crate::application::monitoring::cost_evaluator::CostEvaluator;
+        use crate::application::risk_management::sizing_engine::SizingEngine;
+        use crate::domain::trading::fee_model::ConstantFeeModel;
+        use crate::domain::trading::portfolio::Portfolio;
+        use crate::infrastructure::mock::MockExecutionService;
+
+        let handler = NewsHandler::new(
+            SignalProcessor::new(Arc::new(SizingEngine::new(Arc::new(SpreadCache::new())))),
+            TradeFilter::new(CostEvaluator::new(
+                Arc::new(ConstantFeeModel::new(dec!(0), dec!(0))),
+                dec!(0),
+            )),
+        );
+        let mut portfolio = Portfolio::new();
+        portfolio.cash = dec!(100000);
+        let execution_service: Arc<dyn ExecutionService> = Arc::new(MockExecutionService::new(
+            Arc::new(tokio::sync::RwLock::new(portfolio)),
+        ));
+        let config = AnalystConfig {
+            open_blackout_minutes: 15,
+            post_stop_cooldown_seconds: 600,
+            max_trades_per_symbol_per_day: 1,
+            ..AnalystConfig::default()
+        };
+        let mut context = create_test_context();
+        context.last_features.sma_50 = Some(dec!(90));
+        context.last_features.rsi = Some(dec!(50));
+        let signal = NewsSignal {
+            symbol: "TEST".to_string(),
+            headline: "Test bullish news".to_string(),
+            sentiment: NewsSentiment::Bullish,
+            source: "test".to_string(),
+            url: None,
+        };
+        // Monday 2025-03-03, 09:31 and 10:31 New York (EST)
+        let at_open = 1_741_012_260_000;
+        let later = at_open + 3_600_000;
+
+        macro_rules! process {
+            ($timestamp_ms:expr) => {
+                handler
+                    .process_bullish_news(
+                        &config,
+                        &execution_service,
+                        &signal,
+                        &mut context,
+                        dec!(100),
+                        $timestamp_ms,
+                    )
+                    .await
+            };
+        }
+
+        assert!(
+            matches!(process!(at_open), NewsAction::Rejected(r) if r.contains("open blackout"))
+        );
+
+        context.position_manager.last_stop_time = Some(later - 1_000);
+        assert!(matches!(process!(later), NewsAction::Rejected(r) if r.contains("Post-stop")));
+
+        // Allowed once clear, and tracked so its fill counts against the daily cap
+        context.position_manager.last_stop_time = None;
+        assert!(matches!(process!(later), NewsAction::Buy(_)));
+        context.position_manager.on_fill(OrderSide::Buy);
+        let day = config.trading_day_boundary.trading_day_at_ms(later);
+        assert_eq!(context.position_manager.entries_on(day), 1);
+        assert!(matches!(
+            process!(later + 1_000),
+            NewsAction::Rejected(r) if r.contains("Daily trade cap")
+        ));
+    }
+
+    fn news(symbol: &str, headline: &str) -> NewsSignal {
+        NewsSignal {
+            symbol: symbol.to_string(),
+            headline: headline.to_string(),
+            sentiment: NewsSentiment::Bullish,
+            source: "test".to_string(),
+            url: None,
+        }
+    }
+
+    #[test]
+    fn test_dedup_suppresses_syndicated_copies_within_window() {
+        let mut dedup = NewsDeduplicator::new(16);
+        assert!(!dedup.is_duplicate(
+            &news("BTC/USD", "Bitcoin ETF Approved!"),
+            1_000_000,
+            300_000
+        ));
+        assert!(dedup.is_duplicate(
+            &news("BTC/USD", "bitcoin  ETF approved"),
+            1_100_000,
+            300_000
+        ));
+        // Same headline for another symbol is a distinct event
+        assert!(!dedup.is_duplicate(&news("ETH/USD", "Bitcoin ETF approved"), 1_100_000, 300_000));
+        // Outside the window the story is processed again
+        assert!(!dedup.is_duplicate(&news("BTC/USD", "Bitcoin ETF approved"), 1_500_000, 300_000));
+    }
+
+    #[test]
+    fn test_dedup_is_bounded_lru() {
+        let mut dedup = NewsDeduplicator::new(2);
+        dedup.is_duplicate(&news("A", "one"), 0, 300_000);
+        dedup.is_duplicate(&news("A", "two"), 1_000, 300_000);
+        // Touch "one" so "two" becomes least recently used
+        assert!(dedup.is_duplicate(&news("A", "one"), 2_000, 300_000));
+        dedup.is_duplicate(&news("A", "three"), 3_000, 300_000);
+
+        assert_eq!(dedup.len(), 2);
+        assert!(dedup.is_duplicate(&news("A", "one"), 4_000, 300_000));
+        assert!(!dedup.is_duplicate(&news("A", "two"), 5_000, 300_000));
+    }
+
+    #[test]
+    fn test_news_sizing_multiplier_and_caps() {
+        let mut config = AnalystConfig {
+            max_position_size_pct: dec!(0.10),
+            news_trade_size_multiplier: dec!(1.5),
+            ..AnalystConfig::default()
+        };
+
+        // 10 @ $100 = $1000 -> x1.5 = $1500, under 10% of $100k equity
+        let qty = scale_news_quantity(
+            &config,
+            dec!(10),
+            dec!(100),
+            dec!(100000),
+            dec!(50000),
+            dec!(0),
+        );
+        assert_eq!(qty, dec!(15));
+
+        // Absolute cap
+        config.news_trade_max_value_usd = Some(dec!(1200));
+        let qty = scale_news_quantity(
+            &config,
+            dec!(10),
+            dec!(100),
+            dec!(100000),
+            dec!(50000),
+            dec!(0),
+        );
+        assert_eq!(qty, dec!(12));
+
+        // Global max_position_size_pct still wins: 10% of $10k = $1000
+        let qty = scale_news_quantity(
+            &config,
+            dec!(10),
+            dec!(100),
+            dec!(10000),
+            dec!(50000),
+            dec!(0),
+        );
+        assert_eq!(qty, dec!(10));
+
+        // The position already held counts against the limit: $1000 - 6 @ $100
+        let qty = scale_news_quantity(
+            &config,
+            dec!(10),
+            dec!(100),
+            dec!(10000),
+            dec!(50000),
+            dec!(6),
+        );
+        assert_eq!(qty, dec!(4));
+
+        // Already at the limit: nothing more to buy
+        let qty = scale_news_quantity(
+            &config,
+            dec!(10),
+            dec!(100),
+            dec!(10000),
+            dec!(50000),
+            dec!(12),
+        );
+        assert_eq!(qty, dec!(0));
+
+        // No cash means no size rather than an uncapped order
+        let qty = scale_news_quantity(&config, dec!(10), dec!(100), dec!(10000), dec!(0), dec!(0));
+        assert_eq!(qty, dec!(0));
     }
 }
@@ -0,0 +1,174 @@
+//! Portfolio reads for the Analyst's candle loop.
+//!
+//! Every candle needs the broker portfolio (position sync, partial take-profit, sizing),
+//! which in live mode is a network round-trip. The portfolio is read once per candle and
+//! candles of the same tick share that read across symbols. Optionally, a staleness
+//! window lets snapshots be reused across ticks too, in which case new entries are
+//! blocked once the last successful sync gets too old. A fill always forces a fresh read.
+
+use crate::application::monitoring::portfolio_state_manager::PortfolioStateManager;
+use crate::domain::ports::ExecutionService;
+use crate::domain::trading::portfolio::Portfolio;
+use crate::domain::trading::time::now_ms;
+use std::sync::Arc;
+use tracing::warn;
+
+/// Per-tick portfolio read shared by every symbol's candle
+pub struct PortfolioCache {
+    execution_service: Arc<dyn ExecutionService>,
+    // Snapshot reuse across ticks (None = read on every tick)
+    state: Option<PortfolioStateManager>,
+    // Last successful sync older than this blocks new entries (0 = disabled)
+    max_age_ms: i64,
+    // A fill landed since the last read
+    refresh_due: bool,
+    // Candle timestamp of the last read and its result
+    tick: Option<(i64, Portfolio)>,
+}
+
+impl PortfolioCache {
+    pub fn new(execution_service: Arc<dyn ExecutionService>) -> Self {
+        Self {
+            execution_service,
+            state: None,
+            max_age_ms: 0,
+            refresh_due: false,
+            tick: None,
+        }
+    }
+
+    /// Reuse snapshots younger than `staleness_ms` across ticks and report entries as
+    /// blocked once the last successful sync is older than `max_age_ms` (0 = never)
+    pub fn with_staleness(mut self, staleness_ms: i64, max_age_ms: i64) -> Self {
+        self.state = Some(PortfolioStateManager::new(
+            self.execution_service.clone(),
+            staleness_ms,
+        ));
+        self.max_age_ms = max_age_ms;
+        self
+    }
+
+    pub fn max_age_ms(&self) -> i64 {
+        self.max_age_ms
+    }
+
+    /// Force a fresh read on the next candle (e.g. after a fill)
+    pub fn invalidate(&mut self) {
+        self.refresh_due = true;
+    }
+
+    /// Portfolio for a candle at `timestamp_ms`, and whether it is too old to open
+    /// positions on
+    pub async fn get(&mut self, timestamp_ms: i64) -> (Option<Portfolio>, bool) {
+        if !self.refresh_due
+            && let Some((tick, portfolio)) = &self.tick
+            && *tick == timestamp_ms
+        {
+            let too_old = self.too_old().await;
+            return (Some(portfolio.clone()), too_old);
+        }
+
+        let (portfolio, too_old, fresh) = match &self.state {
+            None => {
+                let portfolio = self.execution_service.get_portfolio().await.ok();
+                let fresh = portfolio.is_some();
+                (portfolio, false, fresh)
+            }
+            Some(state) => {
+                let mut snapshot = state.get_snapshot().await;
+                let mut fresh = false;
+                // Version 0 is the placeholder from before the first sync
+                if snapshot.version == 0 || self.refresh_due || state.is_stale(&snapshot) {
+                    match state.refresh().await {
+                        Ok(refreshed) => {
+                            snapshot = refreshed;
+                            fresh = true;
+                        }
+                        Err(e) => {
+                            warn!(
+                                "Analyst: Portfolio refresh failed, using last snapshot: {}",
+                                e
+                            )
+                        }
+                    }
+                }
+                if snapshot.version == 0 {
+                    (None, true, false)
+                } else {
+                    let too_old = self.is_too_old(snapshot.timestamp);
+                    (
+                        Some(snapshot.portfolio),
+                        too_old,
+                        fresh || !self.refresh_due,
+                    )
+                }
+            }
+        };
+
+        // Only a successful read is shared with the rest of the tick
+        self.tick = None;
+        if fresh && let Some(p) = &portfolio {
+            self.refresh_due = false;
+            self.tick = Some((timestamp_ms, p.clone()));
+        }
+        (portfolio, too_old)
+    }
+
+    async fn too_old(&self) -> bool {
+        match &self.state {
+            Some(state) => self.is_too_old(state.get_snapshot().await.timestamp),
+            None => false,
+        }
+    }
+
+    fn is_too_old(&self, synced_at_ms: i64) -> bool {
+        self.max_age_ms > 0 && now_ms() - synced_at_ms > self.max_age_ms
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::infrastructure::mock::MockExecutionService;
+    use rust_decimal::Decimal;
+    use tokio::sync::RwLock;
+
+    fn cache() -> (PortfolioCache, Arc<RwLock<Portfolio>>) {
+        let portfolio = Arc::new(RwLock::new(Portfolio::new()));
+        let execution_service = Arc::new(MockExecutionService::new(portfolio.clone()));
+        (PortfolioCache::new(execution_service), portfolio)
+    }
+
+    #[tokio::test]
+    async fn test_same_tick_shares_one_read() {
+        let (mut cache, broker) = cache();
+        broker.write().await.cash = Decimal::from(100);
+        assert_eq!(cache.get(1_000).await.0.unwrap().cash, Decimal::from(100));
+
+        // Another symbol's candle on the same tick does not ask the broker again
+        broker.write().await.cash = Decimal::from(50);
+        assert_eq!(cache.get(1_000).await.0.unwrap().cash, Decimal::from(100));
+
+        // A new tick or a fill reads again
+        assert_eq!(cache.get(2_000).await.0.unwrap().cash, Decimal::from(50));
+        broker.write().await.cash = Decimal::from(25);
+        cache.invalidate();
+        assert_eq!(cache.get(2_000).await.0.unwrap().cash, Decimal::from(25));
+    }
+
+    #[tokio::test]
+    async fn test_staleness_window_reuses_snapshot_across_ticks() {
+        let (cache, broker) = cache();
+        let mut cache = cache.with_staleness(3_600_000, 0);
+        broker.write().await.cash = Decimal::from(100);
+        assert_eq!(cache.get(1_000).await.0.unwrap().cash, Decimal::from(100));
+
+        broker.write().await.cash = Decimal::from(50);
+        let (portfolio, too_old) = cache.get(2_000).await;
+        assert_eq!(portfolio.unwrap().cash, Decimal::from(100));
+        assert!(!too_old);
+
+        cache.invalidate();
+        assert_eq!(cache.get(3_000).await.0.unwrap().cash, Decimal::from(50));
+    }
+}
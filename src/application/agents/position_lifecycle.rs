@@ -7,6 +7,7 @@
 //!
 //! Extracted from [`Analyst`] to reduce module complexity.
 
+use crate::application::risk_management::trailing_stops::StopState;
 use crate::application::trading::symbol_context::SymbolContext;
 use crate::domain::ports::ExecutionService;
 use rust_decimal::Decimal;
@@ -104,14 +105,13 @@ pub fn initialize_trailing_stop_if_needed(
     }
 
     let atr_val = atr.unwrap_or(dec!(1.0));
-    let multiplier = context.config.trailing_stop_atr_multiplier;
+    let multiplier = context.trailing_stop_multiplier();
 
-    context.position_manager.trailing_stop =
-        crate::application::risk_management::trailing_stops::StopState::on_buy(
-            entry_price,
-            atr_val,
-            multiplier,
-        );
+    context.position_manager.trailing_stop = if context.position_quantity < Decimal::ZERO {
+        StopState::on_short(entry_price, atr_val, multiplier)
+    } else {
+        StopState::on_buy(entry_price, atr_val, multiplier)
+    };
 
     if let Some(stop_price) = context.position_manager.trailing_stop.get_stop_price() {
         info!(
@@ -128,15 +128,18 @@ pub fn initialize_trailing_stop_on_buy(context: &mut SymbolContext, price: Decim
     if let Some(atr) = context.last_features.atr
         && atr > Decimal::ZERO
     {
-        let atr_decimal = atr;
-        let multiplier = context.config.trailing_stop_atr_multiplier;
+        let multiplier = context.trailing_stop_multiplier();
+        context.position_manager.trailing_stop = StopState::on_buy(price, atr, multiplier);
+    }
+}
 
-        context.position_manager.trailing_stop =
-            crate::application::risk_management::trailing_stops::StopState::on_buy(
-                price,
-                atr_decimal,
-                multiplier,
-            );
+/// Arms a trailing stop above a new short entry, once ATR is known.
+pub fn initialize_trailing_stop_on_short(context: &mut SymbolContext, price: Decimal) {
+    if let Some(atr) = context.last_features.atr
+        && atr > Decimal::ZERO
+    {
+        let multiplier = context.trailing_stop_multiplier();
+        context.position_manager.trailing_stop = StopState::on_short(price, atr, multiplier);
     }
 }
 
@@ -155,7 +158,7 @@ pub fn check_trailing_stop(
     current_price: Decimal,
 ) -> Option<crate::domain::trading::types::OrderSide> {
     let atr_decimal = context.last_features.atr.unwrap_or(Decimal::ZERO);
-    let multiplier_decimal = context.config.trailing_stop_atr_multiplier;
+    let multiplier_decimal = context.trailing_stop_multiplier();
 
     context.position_manager.check_trailing_stop(
         symbol,
@@ -172,14 +175,15 @@ pub fn check_trailing_stop(
 /// # Arguments
 /// * `context` - Symbol context to update
 /// * `symbol` - Trading symbol
-/// * `has_position` - Whether portfolio has a position in this symbol
-pub fn sync_position_state(context: &mut SymbolContext, symbol: &str, has_position: bool) {
+/// * `position_qty` - Portfolio quantity in this symbol (negative = short)
+pub fn sync_position_state(context: &mut SymbolContext, symbol: &str, position_qty: Decimal) {
+    context.position_quantity = position_qty;
     context
         .position_manager
-        .ack_pending_orders(has_position, symbol);
+        .ack_pending_orders(position_qty, symbol);
 
     // Reset taken_profit flag when position is closed
-    if !has_position {
+    if position_qty.is_zero() {
         context.taken_profit = false;
     }
 }
@@ -206,7 +210,7 @@ mod tests {
         let mut context = create_test_context();
         context.taken_profit = true;
 
-        sync_position_state(&mut context, "TEST", false);
+        sync_position_state(&mut context, "TEST", Decimal::ZERO);
 
         assert!(!context.taken_profit);
     }
@@ -216,7 +220,7 @@ mod tests {
         let mut context = create_test_context();
         context.taken_profit = true;
 
-        sync_position_state(&mut context, "TEST", true);
+        sync_position_state(&mut context, "TEST", dec!(10));
 
         assert!(context.taken_profit);
     }
@@ -0,0 +1,349 @@
+//! Delivery of Analyst proposals to the RiskManager under backpressure.
+//!
+//! The proposal channel is bounded; when the RiskManager falls behind, the configured
+//! [`ProposalChannelPolicy`] decides whether an entry is dropped, waits briefly for
+//! capacity, or is parked and coalesced per symbol and side until the channel drains.
+//! Exits (selling a long or covering a short) are parked under every policy and flushed
+//! ahead of entries.
+
+use crate::config::ProposalChannelPolicy;
+use crate::domain::trading::types::TradeProposal;
+use std::collections::VecDeque;
+use std::time::Duration;
+use tokio::sync::mpsc::Sender;
+use tokio::sync::mpsc::error::TrySendError;
+
+/// Result of handing one proposal to the dispatcher
+#[derive(Debug)]
+pub enum DispatchOutcome {
+    Sent,
+    /// Channel full: parked for a later flush. Carries the older proposal for the same
+    /// symbol and side, or the entry an exit superseded, if any.
+    Coalesced(Option<TradeProposal>),
+    /// Channel full and the policy gave up on the proposal
+    Dropped,
+    Closed,
+}
+
+pub struct ProposalDispatcher {
+    tx: Sender<TradeProposal>,
+    policy: ProposalChannelPolicy,
+    // Parked proposals with whether they are exits, at most one per symbol and side:
+    // exits first, then entries, oldest first
+    pending: VecDeque<(TradeProposal, bool)>,
+}
+
+impl ProposalDispatcher {
+    pub fn new(tx: Sender<TradeProposal>, policy: ProposalChannelPolicy) -> Self {
+        Self {
+            tx,
+            policy,
+            pending: VecDeque::new(),
+        }
+    }
+
+    pub fn policy(&self) -> ProposalChannelPolicy {
+        self.policy
+    }
+
+    pub fn pending_len(&self) -> usize {
+        self.pending.len()
+    }
+
+    /// Send parked proposals, exits first, until the channel is full again.
+    /// Returns the proposals that went out.
+    pub fn flush_pending(&mut self) -> Vec<TradeProposal> {
+        let mut sent = Vec::new();
+        while let Some((proposal, is_exit)) = self.pending.pop_front() {
+            match self.tx.try_send(proposal.clone()) {
+                Ok(()) => sent.push(proposal),
+                Err(TrySendError::Full(_)) => {
+                    self.pending.push_front((proposal, is_exit));
+                    break;
+                }
+                Err(TrySendError::Closed(_)) => {
+                    self.pending.clear();
+                    break;
+                }
+            }
+        }
+        sent
+    }
+
+    /// Hand a proposal to the RiskManager, applying the policy if the channel is full.
+    /// `is_exit` tells whether it reduces the held position.
+    pub async fn dispatch(&mut self, proposal: TradeProposal, is_exit: bool) -> DispatchOutcome {
+        // Parked proposals go first: the channel is still backed up
+        if !self.pending.is_empty() {
+            if is_exit || self.policy == ProposalChannelPolicy::Coalesce {
+                return DispatchOutcome::Coalesced(self.park(proposal, is_exit));
+            }
+            return DispatchOutcome::Dropped;
+        }
+
+        let proposal = match self.tx.try_send(proposal) {
+            Ok(()) => return DispatchOutcome::Sent,
+            Err(TrySendError::Closed(_)) => return DispatchOutcome::Closed,
+            Err(TrySendError::Full(proposal)) => proposal,
+        };
+
+        // Risk-reducing orders are never dropped
+        if is_exit {
+            return DispatchOutcome::Coalesced(self.park(proposal, is_exit));
+        }
+
+        match self.policy {
+            ProposalChannelPolicy::DropNewest => DispatchOutcome::Dropped,
+            ProposalChannelPolicy::BlockWithTimeout(timeout_ms) => {
+                match tokio::time::timeout(
+                    Duration::from_millis(timeout_ms),
+                    self.tx.send(proposal),
+                )
+                .await
+                {
+                    Ok(Ok(())) => DispatchOutcome::Sent,
+                    Ok(Err(_)) => DispatchOutcome::Closed,
+                    Err(_) => DispatchOutcome::Dropped,
+                }
+            }
+            ProposalChannelPolicy::Coalesce => {
+                DispatchOutcome::Coalesced(self.park(proposal, is_exit))
+            }
+        }
+    }
+
+    /// Queue for a later flush, replacing a parked proposal for the same symbol and side.
+    /// An exit also supersedes a parked entry for its symbol, but an entry never replaces
+    /// a parked exit. Exits are kept ahead of every parked entry.
+    fn park(&mut self, proposal: TradeProposal, is_exit: bool) -> Option<TradeProposal> {
+        let replaced = self
+            .pending
+            .iter()
+            .position(|(p, parked_exit)| {
+                p.symbol == proposal.symbol
+                    && (p.side == proposal.side || (is_exit && !parked_exit))
+            })
+            .and_then(|i| self.pending.remove(i))
+            .map(|(p, _)| p);
+        let index = if is_exit {
+            self.pending
+                .iter()
+                .position(|(_, parked_exit)| !parked_exit)
+                .unwrap_or(self.pending.len())
+        } else {
+            self.pending.len()
+        };
+        self.pending.insert(index, (proposal, is_exit));
+        replaced
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::trading::types::{OrderSide, OrderType};
+    use rust_decimal::Decimal;
+    use tokio::sync::mpsc;
+
+    fn proposal(symbol: &str, side: OrderSide) -> TradeProposal {
+        TradeProposal {
+            symbol: symbol.to_string(),
+            side,
+            price: Decimal::from(100),
+            quantity: Decimal::ONE,
+            order_type: OrderType::Market,
+            reason: "test".to_string(),
+            timestamp: 0,
+            stop_loss: None,
+            take_profit: None,
+            reduce_only: false,
+            origin: Default::default(),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_drop_newest_drops_when_full() {
+        let (tx, mut rx) = mpsc::channel(1);
+        let mut dispatcher = ProposalDispatcher::new(tx, ProposalChannelPolicy::DropNewest);
+
+        assert!(matches!(
+            dispatcher
+                .dispatch(proposal("AAPL", OrderSide::Buy), false)
+                .await,
+            DispatchOutcome::Sent
+        ));
+        assert!(matches!(
+            dispatcher
+                .dispatch(proposal("MSFT", OrderSide::Buy), false)
+                .await,
+            DispatchOutcome::Dropped
+        ));
+        assert_eq!(rx.recv().await.unwrap().symbol, "AAPL");
+        assert!(rx.try_recv().is_err());
+    }
+
+    #[tokio::test]
+    async fn test_block_with_timeout_waits_for_capacity() {
+        let (tx, mut rx) = mpsc::channel(1);
+        let mut dispatcher =
+            ProposalDispatcher::new(tx, ProposalChannelPolicy::BlockWithTimeout(20));
+        dispatcher
+            .dispatch(proposal("AAPL", OrderSide::Buy), false)
+            .await;
+
+        // Nobody drains the channel: gives up after the timeout
+        assert!(matches!(
+            dispatcher
+                .dispatch(proposal("MSFT", OrderSide::Buy), false)
+                .await,
+            DispatchOutcome::Dropped
+        ));
+
+        // A consumer freeing capacity within the timeout lets the send through
+        let consumer = tokio::spawn(async move {
+            tokio::time::sleep(Duration::from_millis(5)).await;
+            let first = rx.recv().await.unwrap();
+            let second = rx.recv().await.unwrap();
+            (first.symbol, second.symbol)
+        });
+        assert!(matches!(
+            dispatcher
+                .dispatch(proposal("TSLA", OrderSide::Buy), false)
+                .await,
+            DispatchOutcome::Sent
+        ));
+        assert_eq!(
+            consumer.await.unwrap(),
+            ("AAPL".to_string(), "TSLA".to_string())
+        );
+    }
+
+    #[tokio::test]
+    async fn test_coalesce_replaces_pending_proposal_for_same_symbol() {
+        let (tx, mut rx) = mpsc::channel(1);
+        let mut dispatcher = ProposalDispatcher::new(tx, ProposalChannelPolicy::Coalesce);
+        dispatcher
+            .dispatch(proposal("AAPL", OrderSide::Buy), false)
+            .await;
+
+        assert!(matches!(
+            dispatcher
+                .dispatch(proposal("MSFT", OrderSide::Buy), false)
+                .await,
+            DispatchOutcome::Coalesced(None)
+        ));
+        assert!(matches!(
+            dispatcher
+                .dispatch(proposal("TSLA", OrderSide::Buy), false)
+                .await,
+            DispatchOutcome::Coalesced(None)
+        ));
+        // The MSFT exit supersedes the parked MSFT entry and stays ahead of entries
+        match dispatcher
+            .dispatch(proposal("MSFT", OrderSide::Sell), true)
+            .await
+        {
+            DispatchOutcome::Coalesced(Some(replaced)) => {
+                assert_eq!(replaced.side, OrderSide::Buy)
+            }
+            other => panic!("expected coalesced replacement, got {:?}", other),
+        }
+        assert_eq!(dispatcher.pending_len(), 2);
+
+        // Still full: nothing flushed
+        assert!(dispatcher.flush_pending().is_empty());
+
+        assert_eq!(rx.recv().await.unwrap().symbol, "AAPL");
+        let sent = dispatcher.flush_pending();
+        assert_eq!(sent.len(), 1);
+        assert_eq!(
+            (sent[0].symbol.as_str(), sent[0].side),
+            ("MSFT", OrderSide::Sell)
+        );
+        assert_eq!(rx.recv().await.unwrap().side, OrderSide::Sell);
+
+        assert_eq!(dispatcher.flush_pending()[0].symbol, "TSLA");
+        assert_eq!(dispatcher.pending_len(), 0);
+    }
+
+    #[tokio::test]
+    async fn test_exits_are_parked_and_flushed_before_entries() {
+        let (tx, mut rx) = mpsc::channel(1);
+        let mut dispatcher = ProposalDispatcher::new(tx, ProposalChannelPolicy::DropNewest);
+        dispatcher
+            .dispatch(proposal("AAPL", OrderSide::Buy), false)
+            .await;
+
+        // Full channel: the exit is kept even though entries are dropped
+        assert!(matches!(
+            dispatcher
+                .dispatch(proposal("MSFT", OrderSide::Sell), true)
+                .await,
+            DispatchOutcome::Coalesced(None)
+        ));
+        // An entry cannot jump ahead of a parked exit
+        assert!(matches!(
+            dispatcher
+                .dispatch(proposal("TSLA", OrderSide::Buy), false)
+                .await,
+            DispatchOutcome::Dropped
+        ));
+        assert!(matches!(
+            dispatcher
+                .dispatch(proposal("NVDA", OrderSide::Sell), true)
+                .await,
+            DispatchOutcome::Coalesced(None)
+        ));
+
+        assert_eq!(rx.recv().await.unwrap().symbol, "AAPL");
+        assert_eq!(dispatcher.flush_pending()[0].symbol, "MSFT");
+        assert_eq!(rx.recv().await.unwrap().symbol, "MSFT");
+        assert_eq!(dispatcher.flush_pending()[0].symbol, "NVDA");
+        assert_eq!(dispatcher.pending_len(), 0);
+    }
+
+    #[tokio::test]
+    async fn test_entry_never_replaces_parked_exit() {
+        let (tx, mut rx) = mpsc::channel(1);
+        let mut dispatcher = ProposalDispatcher::new(tx, ProposalChannelPolicy::Coalesce);
+        dispatcher
+            .dispatch(proposal("AAPL", OrderSide::Buy), false)
+            .await;
+
+        // Covering a short is an exit even though it buys
+        assert!(matches!(
+            dispatcher
+                .dispatch(proposal("MSFT", OrderSide::Buy), true)
+                .await,
+            DispatchOutcome::Coalesced(None)
+        ));
+        // A short entry on the same symbol is parked behind the cover, not in its place
+        assert!(matches!(
+            dispatcher
+                .dispatch(proposal("MSFT", OrderSide::Sell), false)
+                .await,
+            DispatchOutcome::Coalesced(None)
+        ));
+        // A newer entry on the same side coalesces with the parked entry only
+        match dispatcher
+            .dispatch(proposal("MSFT", OrderSide::Sell), false)
+            .await
+        {
+            DispatchOutcome::Coalesced(Some(replaced)) => {
+                assert_eq!(replaced.side, OrderSide::Sell)
+            }
+            other => panic!("expected coalesced replacement, got {:?}", other),
+        }
+        assert_eq!(dispatcher.pending_len(), 2);
+
+        assert_eq!(rx.recv().await.unwrap().symbol, "AAPL");
+        let sent = dispatcher.flush_pending();
+        assert_eq!(
+            (sent[0].symbol.as_str(), sent[0].side),
+            ("MSFT", OrderSide::Buy)
+        );
+        assert_eq!(rx.recv().await.unwrap().side, OrderSide::Buy);
+        assert_eq!(dispatcher.flush_pending()[0].side, OrderSide::Sell);
+        assert_eq!(dispatcher.pending_len(), 0);
+    }
+}
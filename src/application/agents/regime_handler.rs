@@ -8,6 +8,7 @@ use crate::application::trading::symbol_context::SymbolContext;
 use crate::domain::market::market_regime::{MarketRegime, MarketRegimeType};
 use crate::domain::repositories::CandleRepository;
 use crate::domain::risk::risk_appetite::RiskAppetite;
+use crate::domain::trading::time::MS_PER_DAY;
 use std::sync::Arc;
 use tracing::info;
 
@@ -48,7 +49,7 @@ pub async fn detect_market_regime(
     // Necessary during warmup or if advanced features are not yet ready (need ~50 bars)
     if let Some(repo) = repo {
         let end_ts = candle_timestamp;
-        let start_ts = end_ts - (30 * 24 * 60 * 60); // 30 days lookback
+        let start_ts = end_ts - 30 * MS_PER_DAY; // 30 days lookback
 
         if let Ok(candles) = repo.get_range(symbol, start_ts, end_ts).await {
             return context
@@ -161,8 +162,12 @@ pub fn apply_adaptive_strategy_switching(
         return false;
     }
 
-    let (new_mode, new_strategy) =
-        StrategySelector::select_strategy(regime, config, context.active_strategy_mode);
+    let (new_mode, new_strategy) = StrategySelector::select_strategy_with_hurst(
+        regime,
+        context.last_features.hurst_exponent,
+        config,
+        context.active_strategy_mode,
+    );
 
     if new_mode != context.active_strategy_mode {
         info!(
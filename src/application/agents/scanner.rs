@@ -1,10 +1,16 @@
 use crate::application::agents::sentinel::SentinelCommand;
 use crate::domain::ports::{ExecutionService, MarketDataService};
+use crate::domain::trading::portfolio::Portfolio;
+use rust_decimal::Decimal;
+use std::collections::HashMap;
 use std::sync::Arc;
 use tokio::sync::mpsc::Sender;
 use tokio::time::{self, Duration};
 use tracing::{error, info, warn};
 
+/// Movers within the same block of this many ranks count as equally ranked for sector rotation
+pub const SECTOR_ROTATION_TIER: usize = 5;
+
 pub struct MarketScanner {
     market_service: Arc<dyn MarketDataService>,
     execution_service: Arc<dyn ExecutionService>,
@@ -12,6 +18,13 @@ pub struct MarketScanner {
     scan_interval: Duration,
     is_enabled: bool,
     agent_registry: Arc<crate::application::monitoring::agent_status::AgentStatusRegistry>,
+    // symbol -> sector used to favour under-held sectors (None = pure ranking)
+    sector_rotation: Option<HashMap<String, String>>,
+    // Movers kept per scan, held symbols excluded (0 = all)
+    max_symbols: usize,
+    // Movers priced outside [min_price, max_price] are dropped (0 / None = no limit)
+    min_price: Decimal,
+    max_price: Option<Decimal>,
 }
 
 impl MarketScanner {
@@ -30,9 +43,32 @@ impl MarketScanner {
             scan_interval,
             is_enabled,
             agent_registry,
+            sector_rotation: None,
+            max_symbols: 0,
+            min_price: Decimal::ZERO,
+            max_price: None,
         }
     }
 
+    /// Prefer movers from sectors the portfolio holds least among equally-ranked candidates
+    pub fn with_sector_rotation(mut self, sector_map: HashMap<String, String>) -> Self {
+        self.sector_rotation = Some(sector_map);
+        self
+    }
+
+    /// Keep only the first `max_symbols` movers of each scan (0 = all)
+    pub fn with_max_symbols(mut self, max_symbols: usize) -> Self {
+        self.max_symbols = max_symbols;
+        self
+    }
+
+    /// Drop movers priced below `min_price` or above `max_price` (0 / None = no limit)
+    pub fn with_price_range(mut self, min_price: Decimal, max_price: Option<Decimal>) -> Self {
+        self.min_price = min_price;
+        self.max_price = max_price;
+        self
+    }
+
     pub async fn run(&self) {
         if !self.is_enabled {
             info!("MarketScanner is disabled.");
@@ -85,9 +121,34 @@ impl MarketScanner {
                         }
                     };
 
+                    if (self.min_price > Decimal::ZERO || self.max_price.is_some())
+                        && !symbols.is_empty()
+                    {
+                        match self.market_service.get_prices(symbols.clone()).await {
+                            Ok(prices) => {
+                                symbols = filter_by_price(
+                                    symbols,
+                                    &prices,
+                                    self.min_price,
+                                    self.max_price,
+                                );
+                            }
+                            Err(e) => warn!(
+                                "MarketScanner: Failed to fetch prices, skipping price filter: {}",
+                                e
+                            ),
+                        }
+                    }
+
                     // 2. Get Portfolio Holdings
                     match self.execution_service.get_portfolio().await {
                         Ok(portfolio) => {
+                            if let Some(sector_map) = &self.sector_rotation {
+                                symbols = rotate_by_sector(symbols, sector_map, &portfolio);
+                            }
+                            if self.max_symbols > 0 {
+                                symbols.truncate(self.max_symbols);
+                            }
                             let held_symbols: Vec<String> = portfolio.positions.keys().cloned().collect();
                             if !held_symbols.is_empty() {
                                 info!("MarketScanner: Including held symbols: {:?}", held_symbols);
@@ -130,11 +191,78 @@ impl MarketScanner {
     }
 }
 
+/// Keep the movers priced within `[min_price, max_price]`, in rank order. Movers without
+/// a quote are kept since their price cannot be judged.
+pub fn filter_by_price(
+    movers: Vec<String>,
+    prices: &HashMap<String, Decimal>,
+    min_price: Decimal,
+    max_price: Option<Decimal>,
+) -> Vec<String> {
+    movers
+        .into_iter()
+        .filter(|symbol| {
+            let Some(&price) = prices.get(symbol) else {
+                return true;
+            };
+            let in_range = price >= min_price && max_price.is_none_or(|max| price <= max);
+            if !in_range {
+                info!(
+                    "MarketScanner: Excluding {} at {} (price range {} - {})",
+                    symbol,
+                    price,
+                    min_price,
+                    max_price.map_or("none".to_string(), |max| max.to_string())
+                );
+            }
+            in_range
+        })
+        .collect()
+}
+
+/// Reorder ranked movers so that, within each block of `SECTOR_ROTATION_TIER` ranks, symbols
+/// from the sectors with the lowest portfolio exposure (cost basis) come first. The sort is
+/// stable, so movers from equally exposed sectors keep their rank; unmapped symbols share
+/// an "Unknown" sector.
+pub fn rotate_by_sector(
+    movers: Vec<String>,
+    sector_map: &HashMap<String, String>,
+    portfolio: &Portfolio,
+) -> Vec<String> {
+    let sector_of = |symbol: &str| {
+        sector_map
+            .get(symbol)
+            .map(String::as_str)
+            .unwrap_or("Unknown")
+    };
+
+    let mut exposure: HashMap<&str, Decimal> = HashMap::new();
+    for (symbol, position) in &portfolio.positions {
+        *exposure.entry(sector_of(symbol)).or_default() +=
+            position.quantity.abs() * position.average_price;
+    }
+
+    let mut ranked: Vec<(usize, Decimal, String)> = movers
+        .into_iter()
+        .enumerate()
+        .map(|(rank, symbol)| {
+            let held = exposure
+                .get(sector_of(&symbol))
+                .copied()
+                .unwrap_or_default();
+            (rank / SECTOR_ROTATION_TIER, held, symbol)
+        })
+        .collect();
+    ranked.sort_by_key(|(tier, held, _)| (*tier, *held));
+    ranked.into_iter().map(|(_, _, symbol)| symbol).collect()
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
     use crate::domain::ports::{ExecutionService, MarketDataService, OrderUpdate};
     use crate::domain::trading::portfolio::{Portfolio, Position};
+    use std::collections::VecDeque;
 
     use crate::domain::trading::types::{MarketEvent, Order};
     use anyhow::Result;
@@ -231,6 +359,7 @@ mod tests {
                 symbol: "MSFT".to_string(),
                 quantity: Decimal::from(10),
                 average_price: Decimal::ZERO,
+                lots: VecDeque::new(),
             },
         );
         // AAPL is also held, to test dedup
@@ -240,6 +369,7 @@ mod tests {
                 symbol: "AAPL".to_string(),
                 quantity: Decimal::from(5),
                 average_price: Decimal::ZERO,
+                lots: VecDeque::new(),
             },
         );
 
@@ -281,4 +411,61 @@ mod tests {
             panic!("Expected UpdateSymbols, got {:?}", update);
         }
     }
+
+    #[test]
+    fn test_rotate_by_sector_prefers_underheld_sectors() {
+        let sector_map: HashMap<String, String> = [
+            ("AAPL", "Tech"),
+            ("MSFT", "Tech"),
+            ("NVDA", "Tech"),
+            ("XOM", "Energy"),
+            ("JPM", "Finance"),
+        ]
+        .into_iter()
+        .map(|(s, sec)| (s.to_string(), sec.to_string()))
+        .collect();
+
+        let mut portfolio = Portfolio::new();
+        portfolio.positions.insert(
+            "MSFT".to_string(),
+            Position {
+                symbol: "MSFT".to_string(),
+                quantity: Decimal::from(10),
+                average_price: Decimal::from(400),
+                lots: VecDeque::new(),
+            },
+        );
+
+        let movers: Vec<String> = ["NVDA", "XOM", "AAPL", "JPM", "TSLA", "GOOG"]
+            .iter()
+            .map(|s| s.to_string())
+            .collect();
+        let rotated = rotate_by_sector(movers, &sector_map, &portfolio);
+
+        // Tech is already held: its movers drop behind the rest of the first tier,
+        // while GOOG (rank 6) stays in the second tier
+        assert_eq!(rotated, vec!["XOM", "JPM", "TSLA", "NVDA", "AAPL", "GOOG"]);
+    }
+
+    #[test]
+    fn test_filter_by_price_drops_out_of_range_movers() {
+        let prices: HashMap<String, Decimal> = [
+            ("PENNY", Decimal::new(45, 2)),
+            ("AAPL", Decimal::from(190)),
+            ("BRK.A", Decimal::from(600000)),
+        ]
+        .into_iter()
+        .map(|(s, p)| (s.to_string(), p))
+        .collect();
+        let movers: Vec<String> = ["PENNY", "AAPL", "BRK.A", "NOQUOTE"]
+            .iter()
+            .map(|s| s.to_string())
+            .collect();
+
+        let floored = filter_by_price(movers.clone(), &prices, Decimal::ONE, None);
+        assert_eq!(floored, vec!["AAPL", "BRK.A", "NOQUOTE"]);
+
+        let ranged = filter_by_price(movers, &prices, Decimal::ONE, Some(Decimal::from(1000)));
+        assert_eq!(ranged, vec!["AAPL", "NOQUOTE"]);
+    }
 }
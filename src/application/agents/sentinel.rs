@@ -3,10 +3,13 @@ use crate::application::monitoring::connection_health_service::{
 };
 use crate::application::monitoring::heartbeat::StreamHealthMonitor;
 use crate::domain::ports::MarketDataService;
+use crate::domain::trading::portfolio::Portfolio;
 use crate::domain::trading::types::MarketEvent;
 use crate::domain::validation::data_quality::StrictEventValidator;
+use std::collections::HashSet;
 use std::sync::Arc;
 use std::time::Duration;
+use tokio::sync::RwLock;
 use tokio::sync::mpsc::{Receiver, Sender};
 use tracing::{error, info, warn};
 
@@ -14,6 +17,10 @@ use tracing::{error, info, warn};
 pub enum SentinelCommand {
     Shutdown,
     UpdateSymbols(Vec<String>),
+    /// Add one symbol to the live feed
+    Subscribe(String),
+    /// Stop one symbol's feed and release its Analyst state
+    Unsubscribe(String),
     /// Request available tradable symbols from the market data service
     LoadAvailableSymbols(tokio::sync::oneshot::Sender<Vec<String>>),
     /// Request top movers (by volume)
@@ -29,6 +36,10 @@ pub struct Sentinel {
     heartbeat: StreamHealthMonitor,
     last_heal_attempt: Option<std::time::Instant>,
     agent_registry: Arc<crate::application::monitoring::agent_status::AgentStatusRegistry>,
+    // Checked before a symbol's feed is stopped (None = no position guard)
+    portfolio: Option<Arc<RwLock<Portfolio>>>,
+    // Dropped from the universe while a position was open; released once flat
+    pending_release: HashSet<String>,
 }
 
 impl Sentinel {
@@ -52,9 +63,17 @@ impl Sentinel {
             heartbeat,
             last_heal_attempt: None,
             agent_registry,
+            portfolio: None,
+            pending_release: HashSet::new(),
         }
     }
 
+    /// Keep the feed of symbols with an open position until the position is closed
+    pub fn with_portfolio(mut self, portfolio: Arc<RwLock<Portfolio>>) -> Self {
+        self.portfolio = Some(portfolio);
+        self
+    }
+
     pub async fn run(&mut self) {
         let mut current_symbols = self.symbols.clone();
 
@@ -159,6 +178,27 @@ impl Sentinel {
                             crate::application::monitoring::agent_status::HealthStatus::Healthy,
                         )
                         .await;
+
+                    // Release symbols dropped while held, now that they are flat
+                    let flat = self.take_flat_pending().await;
+                    if !flat.is_empty() {
+                        let new_symbols: Vec<String> = current_symbols
+                            .iter()
+                            .filter(|s| !flat.contains(s))
+                            .cloned()
+                            .collect();
+                        if let Some(new_rx) = self.resubscribe(&new_symbols).await {
+                            market_rx = new_rx;
+                            current_symbols = new_symbols;
+                            info!("Sentinel: Positions closed, releasing {:?}", flat);
+                            if !self.announce_unsubscribed(flat).await {
+                                return;
+                            }
+                        } else {
+                            // Retry on the next tick
+                            self.pending_release.extend(flat);
+                        }
+                    }
                 }
 
                 // Only poll cmd_rx if it exists
@@ -177,9 +217,28 @@ impl Sentinel {
                                     warn!("Sentinel received Shutdown command. Exiting loop.");
                                     return;
                                 }
-                                SentinelCommand::UpdateSymbols(new_symbols) => {
+                                SentinelCommand::UpdateSymbols(mut new_symbols) => {
+                                    // Requested again: no longer waiting to be released
+                                    self.pending_release.retain(|s| !new_symbols.contains(s));
+                                    let dropped: Vec<String> = current_symbols
+                                        .iter()
+                                        .filter(|s| !new_symbols.contains(s))
+                                        .cloned()
+                                        .collect();
+                                    let dropped = self.hold_open_positions(dropped).await;
+                                    // Held symbols stay on the feed
+                                    new_symbols.extend(
+                                        current_symbols
+                                            .iter()
+                                            .filter(|s| self.pending_release.contains(*s) && !new_symbols.contains(s))
+                                            .cloned()
+                                            .collect::<Vec<_>>(),
+                                    );
+
                                     // Skip if symbols haven't changed
-                                    if new_symbols == current_symbols {
+                                    if new_symbols.len() == current_symbols.len()
+                                        && new_symbols.iter().all(|s| current_symbols.contains(s))
+                                    {
                                         info!("Sentinel: Symbols unchanged, skipping update");
                                         continue;
                                     }
@@ -188,14 +247,48 @@ impl Sentinel {
 
                                     // Update subscription WITHOUT creating new connection
                                     // The WebSocket manager handles this dynamically
-                                    match self.market_service.subscribe(new_symbols.clone()).await {
-                                        Ok(new_rx) => {
-                                            market_rx = new_rx;
-                                            current_symbols = new_symbols;
-                                            info!("Sentinel: Subscription updated and receiver replaced");
+                                    if let Some(new_rx) = self.resubscribe(&new_symbols).await {
+                                        market_rx = new_rx;
+                                        current_symbols = new_symbols;
+                                        info!("Sentinel: Subscription updated and receiver replaced");
+                                        if !self.announce_unsubscribed(dropped).await {
+                                            return;
                                         }
-                                        Err(e) => {
-                                            error!("Sentinel: Failed to update subscription: {}", e);
+                                    }
+                                }
+                                SentinelCommand::Subscribe(symbol) => {
+                                    self.pending_release.remove(&symbol);
+                                    if current_symbols.contains(&symbol) {
+                                        info!("Sentinel: Already subscribed to {}", symbol);
+                                        continue;
+                                    }
+                                    let mut new_symbols = current_symbols.clone();
+                                    new_symbols.push(symbol.clone());
+                                    if let Some(new_rx) = self.resubscribe(&new_symbols).await {
+                                        market_rx = new_rx;
+                                        current_symbols = new_symbols;
+                                        info!("Sentinel: Subscribed to {}", symbol);
+                                    }
+                                }
+                                SentinelCommand::Unsubscribe(symbol) => {
+                                    if !current_symbols.contains(&symbol) {
+                                        info!("Sentinel: Not subscribed to {}, nothing to stop", symbol);
+                                        continue;
+                                    }
+                                    if self.hold_open_positions(vec![symbol.clone()]).await.is_empty() {
+                                        continue;
+                                    }
+                                    let new_symbols: Vec<String> = current_symbols
+                                        .iter()
+                                        .filter(|s| **s != symbol)
+                                        .cloned()
+                                        .collect();
+                                    if let Some(new_rx) = self.resubscribe(&new_symbols).await {
+                                        market_rx = new_rx;
+                                        current_symbols = new_symbols;
+                                        info!("Sentinel: Unsubscribed from {}", symbol);
+                                        if !self.announce_unsubscribed(vec![symbol]).await {
+                                            return;
                                         }
                                     }
                                 }
@@ -242,6 +335,85 @@ impl Sentinel {
     }
 }
 
+impl Sentinel {
+    /// Point the shared stream at `symbols`; returns the receiver replacing the current one
+    async fn resubscribe(&self, symbols: &[String]) -> Option<Receiver<MarketEvent>> {
+        match self.market_service.subscribe(symbols.to_vec()).await {
+            Ok(rx) => Some(rx),
+            Err(e) => {
+                error!("Sentinel: Failed to update subscription: {}", e);
+                None
+            }
+        }
+    }
+
+    /// Split off symbols that still have an open position: they are parked in
+    /// `pending_release` and keep their feed. Returns the symbols safe to stop now.
+    async fn hold_open_positions(&mut self, symbols: Vec<String>) -> Vec<String> {
+        let Some(portfolio) = &self.portfolio else {
+            return symbols;
+        };
+        let pf = portfolio.read().await;
+        let (held, flat): (Vec<String>, Vec<String>) =
+            symbols.into_iter().partition(|s| has_open_position(&pf, s));
+        drop(pf);
+
+        for symbol in held {
+            warn!(
+                "Sentinel: {} still has an open position, keeping its feed until it is closed",
+                symbol
+            );
+            self.pending_release.insert(symbol);
+        }
+        flat
+    }
+
+    /// Remove and return the parked symbols whose position is now closed
+    async fn take_flat_pending(&mut self) -> Vec<String> {
+        if self.pending_release.is_empty() {
+            return Vec::new();
+        }
+        let Some(portfolio) = &self.portfolio else {
+            return self.pending_release.drain().collect();
+        };
+        let pf = portfolio.read().await;
+        let flat: Vec<String> = self
+            .pending_release
+            .iter()
+            .filter(|s| !has_open_position(&pf, s))
+            .cloned()
+            .collect();
+        drop(pf);
+        for symbol in &flat {
+            self.pending_release.remove(symbol);
+        }
+        flat
+    }
+
+    /// Tell the Analyst these feeds stopped so it releases their state.
+    /// Returns false when the internal channel is closed.
+    async fn announce_unsubscribed(&self, symbols: Vec<String>) -> bool {
+        for symbol in symbols {
+            if let Err(e) = self
+                .market_tx
+                .send(MarketEvent::SymbolUnsubscription { symbol })
+                .await
+            {
+                error!("Sentinel: Failed to forward unsubscription: {}", e);
+                return false;
+            }
+        }
+        true
+    }
+}
+
+fn has_open_position(portfolio: &Portfolio, symbol: &str) -> bool {
+    portfolio
+        .positions
+        .get(symbol)
+        .is_some_and(|p| !p.quantity.is_zero())
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -335,7 +507,45 @@ mod tests {
                 assert_eq!(timestamp, 1234567890);
             }
             MarketEvent::Candle(_) => panic!("Unexpected candle event"),
-            MarketEvent::SymbolSubscription { .. } => panic!("Unexpected subscription event"),
+            MarketEvent::SymbolSubscription { .. } | MarketEvent::SymbolUnsubscription { .. } => {
+                panic!("Unexpected subscription event")
+            }
         }
     }
+
+    #[tokio::test]
+    async fn test_open_positions_keep_their_feed_until_flat() {
+        let (market_tx, _market_rx) = mpsc::channel(10);
+        let mut portfolio = Portfolio::new();
+        portfolio.positions.insert(
+            "AAPL".to_string(),
+            crate::domain::trading::portfolio::Position {
+                symbol: "AAPL".to_string(),
+                quantity: dec!(10),
+                average_price: dec!(150),
+                lots: Default::default(),
+            },
+        );
+        let portfolio = Arc::new(RwLock::new(portfolio));
+
+        let mut sentinel = Sentinel::new(
+            Arc::new(TestMarketDataService { events: vec![] }),
+            market_tx,
+            vec!["AAPL".to_string(), "MSFT".to_string()],
+            None,
+            Arc::new(crate::application::monitoring::connection_health_service::ConnectionHealthService::new()),
+            Arc::new(crate::application::monitoring::agent_status::AgentStatusRegistry::new(crate::infrastructure::observability::Metrics::new().unwrap())),
+        )
+        .with_portfolio(portfolio.clone());
+
+        let released = sentinel
+            .hold_open_positions(vec!["AAPL".to_string(), "MSFT".to_string()])
+            .await;
+        assert_eq!(released, vec!["MSFT".to_string()]);
+        assert!(sentinel.take_flat_pending().await.is_empty());
+
+        portfolio.write().await.positions.remove("AAPL");
+        assert_eq!(sentinel.take_flat_pending().await, vec!["AAPL".to_string()]);
+        assert!(sentinel.pending_release.is_empty());
+    }
 }
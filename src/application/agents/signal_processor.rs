@@ -1,5 +1,6 @@
 use crate::application::trading::symbol_context::SymbolContext;
-use crate::domain::ports::ExecutionService;
+use crate::domain::market::strategy_config::TakeProfitMode;
+use crate::domain::trading::portfolio::Portfolio;
 use crate::domain::trading::types::{OrderSide, OrderType, TradeProposal};
 use rust_decimal::Decimal;
 use rust_decimal_macros::dec;
@@ -60,47 +61,39 @@ impl SignalProcessor {
     /// ready to be sent to the risk manager.
     ///
     /// For SELL orders, uses the actual position quantity (not a calculated size).
+    /// With `allow_shorts`, a SELL while flat is sized like an entry and a BUY while
+    /// short covers the whole short.
     #[allow(clippy::too_many_arguments)]
-    pub async fn build_proposal(
+    pub fn build_proposal(
         &self,
         config: &super::analyst::AnalystConfig,
-        execution_service: &Arc<dyn ExecutionService>,
+        portfolio: &Portfolio,
         symbol: String,
         signal: crate::application::strategies::Signal,
         price: Decimal,
         timestamp: i64,
     ) -> Option<TradeProposal> {
+        let position_qty = portfolio
+            .positions
+            .get(&symbol)
+            .map_or(Decimal::ZERO, |pos| pos.quantity);
+
         // For SELL orders, use position quantity (sell what we own)
         // For BUY orders, calculate new position size
         let quantity = match signal.side {
-            OrderSide::Sell => {
-                // Get actual position quantity from portfolio
-                let portfolio = match execution_service.get_portfolio().await {
-                    Ok(p) => p,
-                    Err(e) => {
-                        debug!(
-                            "SignalProcessor [{}]: Failed to get portfolio for sell: {}",
-                            symbol, e
-                        );
-                        return None;
-                    }
-                };
-
-                match portfolio.positions.get(&symbol) {
-                    Some(pos) if pos.quantity > Decimal::ZERO => pos.quantity,
-                    _ => {
-                        debug!(
-                            "SignalProcessor [{}]: No position to sell. Skipping proposal.",
-                            symbol
-                        );
-                        return None;
-                    }
-                }
+            OrderSide::Sell if position_qty > Decimal::ZERO => position_qty,
+            OrderSide::Sell if config.allow_shorts && position_qty.is_zero() => {
+                self.calculate_trade_quantity(config, portfolio, &symbol, price)
             }
-            OrderSide::Buy => {
-                self.calculate_trade_quantity(config, execution_service, &symbol, price)
-                    .await
+            OrderSide::Sell => {
+                debug!(
+                    "SignalProcessor [{}]: No position to sell. Skipping proposal.",
+                    symbol
+                );
+                return None;
             }
+            OrderSide::Buy if position_qty < Decimal::ZERO => -position_qty,
+            OrderSide::Buy => self.calculate_trade_quantity(config, portfolio, &symbol, price),
         };
 
         if quantity <= Decimal::ZERO {
@@ -121,28 +114,21 @@ impl SignalProcessor {
             timestamp,
             stop_loss: signal.suggested_stop_loss,
             take_profit: signal.suggested_take_profit,
+            reduce_only: false,
+            origin: Default::default(),
         })
     }
 
     /// Calculate trade quantity based on position sizing rules.
     ///
-    /// Uses the execution service to get current portfolio state and calculates
-    /// appropriate position size based on configuration.
-    async fn calculate_trade_quantity(
+    /// Sizes from the given portfolio state based on configuration.
+    fn calculate_trade_quantity(
         &self,
         config: &super::analyst::AnalystConfig,
-        execution_service: &Arc<dyn ExecutionService>,
+        portfolio: &Portfolio,
         symbol: &str,
         price: Decimal,
     ) -> Decimal {
-        let portfolio = match execution_service.get_portfolio().await {
-            Ok(p) => p,
-            Err(e) => {
-                debug!("SignalProcessor: Failed to fetch portfolio: {}", e);
-                return Decimal::ZERO;
-            }
-        };
-
         // Get current prices for total equity calculation
         let mut current_prices = std::collections::HashMap::new();
         current_prices.insert(symbol.to_string(), price);
@@ -172,14 +158,14 @@ impl SignalProcessor {
         )
     }
 
-    /// Apply RSI filter to buy signals.
+    /// Apply RSI filter to long entries (covering a short is never blocked).
     pub fn apply_rsi_filter(
         signal: Option<crate::application::strategies::Signal>,
         context: &SymbolContext,
         symbol: &str,
     ) -> Option<crate::application::strategies::Signal> {
         match &signal {
-            Some(s) if s.side == OrderSide::Buy => {
+            Some(s) if s.side == OrderSide::Buy && context.is_entry(s.side) => {
                 if let Some(rsi) = context.last_features.rsi
                     && rsi > context.config.rsi_threshold
                 {
@@ -195,6 +181,105 @@ impl SignalProcessor {
         signal
     }
 
+    /// Block new entries outside the configured trading windows.
+    ///
+    /// Only entries (long or short) are gated: exits and stop management keep
+    /// running so existing positions are still managed outside the window.
+    pub fn apply_trading_window_filter(
+        signal: Option<crate::application::strategies::Signal>,
+        context: &SymbolContext,
+        symbol: &str,
+        timestamp_ms: i64,
+    ) -> Option<crate::application::strategies::Signal> {
+        match &signal {
+            Some(s)
+                if context.is_entry(s.side)
+                    && !context.config.trading_windows.is_open(timestamp_ms) =>
+            {
+                debug!(
+                    "SignalProcessor: {:?} entry BLOCKED for {} - Outside trading window ({})",
+                    s.side, symbol, context.config.trading_windows
+                );
+                None
+            }
+            _ => signal,
+        }
+    }
+
+    /// Block long entries unless the trend SMA on the higher `trend_timeframe` is rising.
+    ///
+    /// Only applies when `require_htf_confirmation` is set; until enough
+    /// higher-timeframe bars have completed, the trend is unknown and buys are blocked.
+    pub fn apply_htf_confirmation_filter(
+        signal: Option<crate::application::strategies::Signal>,
+        context: &SymbolContext,
+        symbol: &str,
+    ) -> Option<crate::application::strategies::Signal> {
+        match &signal {
+            Some(s)
+                if s.side == OrderSide::Buy
+                    && context.is_entry(s.side)
+                    && context.config.require_htf_confirmation
+                    && !context.htf_trend.is_bullish() =>
+            {
+                debug!(
+                    "SignalProcessor: Buy signal BLOCKED for {} - {} trend not rising (slope {:?})",
+                    symbol,
+                    context.htf_trend.timeframe(),
+                    context.htf_trend.slope()
+                );
+                None
+            }
+            _ => signal,
+        }
+    }
+
+    /// Block new entries during the cooldown after a news-driven panic sell.
+    pub fn apply_news_cooldown_filter(
+        signal: Option<crate::application::strategies::Signal>,
+        context: &SymbolContext,
+        symbol: &str,
+        timestamp_ms: i64,
+    ) -> Option<crate::application::strategies::Signal> {
+        match &signal {
+            Some(s)
+                if context.is_entry(s.side) && context.in_news_reentry_cooldown(timestamp_ms) =>
+            {
+                debug!(
+                    "SignalProcessor: {:?} entry BLOCKED for {} - News re-entry cooldown active",
+                    s.side, symbol
+                );
+                None
+            }
+            _ => signal,
+        }
+    }
+
+    /// Block new entries during the cooldown after the symbol was stopped out.
+    pub fn apply_post_stop_cooldown_filter(
+        signal: Option<crate::application::strategies::Signal>,
+        context: &SymbolContext,
+        symbol: &str,
+        timestamp_ms: i64,
+    ) -> Option<crate::application::strategies::Signal> {
+        match &signal {
+            Some(s)
+                if context.is_entry(s.side)
+                    && context.position_manager.in_post_stop_cooldown(
+                        timestamp_ms,
+                        context.config.post_stop_cooldown_seconds,
+                    ) =>
+            {
+                debug!(
+                    "SignalProcessor: {:?} entry BLOCKED for {} - Post-stop cooldown active",
+                    s.side, symbol
+                );
+                None
+            }
+            _ => signal,
+        }
+    }
+
     /// Suppress sell signals when trailing stop is active.
     ///
     /// When a trailing stop is managing the exit, we don't want regular
@@ -206,25 +291,45 @@ impl SignalProcessor {
         trailing_stop_triggered: bool,
     ) -> Option<crate::application::strategies::Signal> {
         match &signal {
-            Some(s) if s.side == OrderSide::Sell => {
-                if context.position_manager.trailing_stop.is_active() && !trailing_stop_triggered {
-                    debug!(
-                        "SignalProcessor: Sell signal SUPPRESSED for {} - Using trailing stop exit instead",
-                        symbol
-                    );
-                    return None;
-                }
+            Some(s)
+                if s.side == OrderSide::Sell
+                    && context.position_manager.trailing_stop.is_active()
+                    && !trailing_stop_triggered =>
+            {
+                debug!(
+                    "SignalProcessor: Sell signal SUPPRESSED for {} - Using trailing stop exit instead",
+                    symbol
+                );
+                return None;
             }
             _ => {}
         }
         signal
     }
 
+    /// Whether a long's gain has reached the partial take-profit target.
+    ///
+    /// In ATR mode the target is `take_profit_atr_multiplier` x ATR above entry; until ATR
+    /// is known it falls back to the fixed `take_profit_pct`.
+    fn take_profit_reached(
+        context: &SymbolContext,
+        entry_price: Decimal,
+        current_price: Decimal,
+        pnl_pct: Decimal,
+    ) -> bool {
+        match (context.config.take_profit_mode, context.last_features.atr) {
+            (TakeProfitMode::Atr, Some(atr)) if atr > Decimal::ZERO => {
+                current_price - entry_price >= atr * context.config.take_profit_atr_multiplier
+            }
+            _ => pnl_pct >= context.config.take_profit_pct,
+        }
+    }
+
     /// Check if partial take-profit conditions are met.
     ///
     /// Returns a TradeProposal for a partial sell if:
     /// - Position exists and has quantity
-    /// - PnL exceeds take_profit_pct
+    /// - The gain reaches the take-profit target (see `take_profit_reached`)
     /// - Partial profit hasn't been taken yet
     pub fn check_partial_take_profit(
         context: &SymbolContext,
@@ -267,7 +372,7 @@ impl SignalProcessor {
             Decimal::ZERO
         };
 
-        if pnl_pct >= context.config.take_profit_pct {
+        if Self::take_profit_reached(context, pos.average_price, current_price, pnl_pct) {
             let quantity_to_sell = (pos.quantity * Decimal::new(5, 1)).round_dp(4); // 50%
 
             if quantity_to_sell > Decimal::ZERO {
@@ -287,6 +392,8 @@ impl SignalProcessor {
                     timestamp,
                     stop_loss: None,
                     take_profit: None,
+                    reduce_only: true,
+                    origin: Default::default(),
                 });
             }
         }
@@ -380,6 +487,210 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_trading_window_blocks_entries_only() {
+        let mut context = create_test_context();
+        context.config.trading_windows = "10:00-11:00@UTC".parse().unwrap();
+        let outside = 8 * 3600 * 1000; // 08:00 UTC
+        let inside = 10 * 3600 * 1000 + 60_000; // 10:01 UTC
+
+        let buy = Some(crate::application::strategies::Signal::buy(
+            "Test".to_string(),
+        ));
+        assert_eq!(
+            SignalProcessor::apply_trading_window_filter(buy.clone(), &context, "AAPL", outside),
+            None
+        );
+        assert!(
+            SignalProcessor::apply_trading_window_filter(buy, &context, "AAPL", inside).is_some()
+        );
+
+        // A sell that opens a short is an entry and is gated too
+        let sell = Some(crate::application::strategies::Signal::sell(
+            "Test".to_string(),
+        ));
+        assert_eq!(
+            SignalProcessor::apply_trading_window_filter(sell.clone(), &context, "AAPL", outside),
+            None
+        );
+
+        // Closing a long is not
+        context.position_quantity = dec!(10);
+        assert!(
+            SignalProcessor::apply_trading_window_filter(sell, &context, "AAPL", outside).is_some()
+        );
+    }
+
+    #[test]
+    fn test_htf_confirmation_requires_rising_trend() {
+        use crate::application::market_data::htf_trend::HigherTimeframeTrend;
+        use crate::domain::market::timeframe::Timeframe;
+        use crate::domain::trading::types::Candle;
+
+        let mut context = create_test_context();
+        let buy = Some(crate::application::strategies::Signal::buy(
+            "Test".to_string(),
+        ));
+
+        // Disabled: the unknown trend does not matter
+        assert!(
+            SignalProcessor::apply_htf_confirmation_filter(buy.clone(), &context, "AAPL").is_some()
+        );
+
+        context.config.require_htf_confirmation = true;
+        context.htf_trend = HigherTimeframeTrend::new(Timeframe::FiveMin, 2);
+        assert_eq!(
+            SignalProcessor::apply_htf_confirmation_filter(buy.clone(), &context, "AAPL"),
+            None
+        );
+
+        // Three rising 5-minute bars give a positive SMA slope
+        for minute in 0..15i64 {
+            let close = Decimal::from(100 + minute / 5);
+            context.htf_trend.update(&Candle {
+                symbol: "AAPL".to_string(),
+                open: close,
+                high: close,
+                low: close,
+                close,
+                volume: dec!(100),
+                timestamp: minute * 60_000,
+            });
+        }
+        assert!(SignalProcessor::apply_htf_confirmation_filter(buy, &context, "AAPL").is_some());
+
+        // Sells are never blocked
+        context.htf_trend.clear();
+        let sell = Some(crate::application::strategies::Signal::sell(
+            "Test".to_string(),
+        ));
+        assert!(SignalProcessor::apply_htf_confirmation_filter(sell, &context, "AAPL").is_some());
+    }
+
+    #[test]
+    fn test_post_stop_cooldown_blocks_reentry() {
+        let mut context = create_test_context();
+        context.config.post_stop_cooldown_seconds = 600;
+        context.position_manager.last_stop_time = Some(1_000_000);
+        let buy = Some(crate::application::strategies::Signal::buy(
+            "Test".to_string(),
+        ));
+
+        assert_eq!(
+            SignalProcessor::apply_post_stop_cooldown_filter(
+                buy.clone(),
+                &context,
+                "AAPL",
+                1_000_000 + 599_999
+            ),
+            None
+        );
+        assert!(
+            SignalProcessor::apply_post_stop_cooldown_filter(
+                buy,
+                &context,
+                "AAPL",
+                1_000_000 + 600_000
+            )
+            .is_some()
+        );
+
+        let sell = Some(crate::application::strategies::Signal::sell(
+            "Test".to_string(),
+        ));
+        assert_eq!(
+            SignalProcessor::apply_post_stop_cooldown_filter(
+                sell.clone(),
+                &context,
+                "AAPL",
+                1_000_000
+            ),
+            None
+        );
+
+        // Covering a short is an exit and passes
+        context.position_quantity = dec!(-10);
+        let buy = Some(crate::application::strategies::Signal::buy(
+            "Test".to_string(),
+        ));
+        assert!(
+            SignalProcessor::apply_post_stop_cooldown_filter(buy, &context, "AAPL", 1_000_000)
+                .is_some()
+        );
+    }
+
+    #[test]
+    fn test_partial_take_profit_min_hold_uses_milliseconds() {
+        let mut context = create_test_context();
+        context.config.take_profit_pct = dec!(0.05);
+        let mut positions = std::collections::HashMap::new();
+        positions.insert(
+            "AAPL".to_string(),
+            crate::domain::trading::portfolio::Position {
+                symbol: "AAPL".to_string(),
+                quantity: dec!(10),
+                average_price: dec!(100),
+                lots: Default::default(),
+            },
+        );
+
+        // Entry and candle timestamps are both Unix ms; min hold is 60s
+        let entry_ms = 1_700_000_000_000;
+        let check = |now_ms| {
+            SignalProcessor::check_partial_take_profit(
+                &context,
+                "AAPL",
+                dec!(110),
+                now_ms,
+                Some(&positions),
+                Some(entry_ms),
+                60_000,
+            )
+        };
+
+        assert!(check(entry_ms + 59_999).is_none());
+        let proposal = check(entry_ms + 60_000).expect("min hold elapsed");
+        assert_eq!(proposal.quantity, dec!(5));
+        assert_eq!(proposal.timestamp, entry_ms + 60_000);
+    }
+
+    #[test]
+    fn test_partial_take_profit_atr_mode() {
+        let mut context = create_test_context();
+        context.config.take_profit_pct = dec!(0.05);
+        context.config.take_profit_mode = TakeProfitMode::Atr;
+        context.config.take_profit_atr_multiplier = dec!(3);
+        let mut positions = std::collections::HashMap::new();
+        positions.insert(
+            "AAPL".to_string(),
+            crate::domain::trading::portfolio::Position {
+                symbol: "AAPL".to_string(),
+                quantity: dec!(10),
+                average_price: dec!(100),
+                lots: Default::default(),
+            },
+        );
+        let check = |context: &SymbolContext, price| {
+            SignalProcessor::check_partial_take_profit(
+                context,
+                "AAPL",
+                price,
+                1_000_000,
+                Some(&positions),
+                Some(0),
+                0,
+            )
+        };
+
+        // No ATR yet: falls back to the fixed 5%
+        assert!(check(&context, dec!(105)).is_some());
+
+        // ATR 2 x 3 = target at 106, so +5% is not enough
+        context.last_features.atr = Some(dec!(2));
+        assert!(check(&context, dec!(105)).is_none());
+        assert!(check(&context, dec!(106)).is_some());
+    }
+
     #[test]
     fn test_trailing_stop_suppression() {
         let mut context = create_test_context();
@@ -428,4 +739,66 @@ mod tests {
             Some(crate::domain::trading::types::OrderSide::Sell)
         );
     }
+
+    #[test]
+    fn test_build_proposal_short_entry_and_cover() {
+        let mut portfolio = Portfolio::new();
+        portfolio.allow_shorts = true;
+        portfolio.reset(dec!(10000));
+        let processor = SignalProcessor::new(Arc::new(
+            crate::application::risk_management::sizing_engine::SizingEngine::new(Arc::new(
+                crate::application::market_data::spread_cache::SpreadCache::new(),
+            )),
+        ));
+        let sell = || crate::application::strategies::Signal::sell("Test".to_string());
+        let mut config = super::super::analyst::AnalystConfig::default();
+
+        // Long-only: no position, no sell proposal
+        let proposal = processor.build_proposal(
+            &config,
+            &portfolio,
+            "AAPL".to_string(),
+            sell(),
+            dec!(100),
+            0,
+        );
+        assert!(proposal.is_none());
+
+        // Shorts allowed: a sell while flat is sized like an entry
+        config.allow_shorts = true;
+        let proposal = processor
+            .build_proposal(
+                &config,
+                &portfolio,
+                "AAPL".to_string(),
+                sell(),
+                dec!(100),
+                0,
+            )
+            .expect("short entry");
+        assert!(proposal.quantity > Decimal::ZERO);
+
+        // Already short: no further sell, and a buy covers the whole short
+        portfolio.apply_sell_fill("AAPL", dec!(7), dec!(100), Decimal::ZERO);
+        let proposal = processor.build_proposal(
+            &config,
+            &portfolio,
+            "AAPL".to_string(),
+            sell(),
+            dec!(100),
+            0,
+        );
+        assert!(proposal.is_none());
+        let proposal = processor
+            .build_proposal(
+                &config,
+                &portfolio,
+                "AAPL".to_string(),
+                crate::application::strategies::Signal::buy("Test".to_string()),
+                dec!(100),
+                0,
+            )
+            .expect("cover");
+        assert_eq!(proposal.quantity, dec!(7));
+    }
 }
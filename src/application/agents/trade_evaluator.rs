@@ -1,22 +1,23 @@
 use rust_decimal::Decimal;
-use std::sync::Arc;
 use tracing::info;
 
 use crate::application::agents::signal_processor::SignalProcessor;
 use crate::application::trading::symbol_context::SymbolContext;
 use crate::application::trading::trade_filter::TradeFilter;
 use crate::domain::market::market_regime::MarketRegime;
-use crate::domain::ports::ExecutionService;
+use crate::domain::trading::portfolio::Portfolio;
 use crate::domain::trading::types::{OrderSide, OrderType, TradeProposal};
+use crate::infrastructure::observability::Metrics;
 
 /// Service responsible for evaluating trade signals and generating proposals.
 ///
 /// Encapsulates the logic for:
 /// - Post-signal validation (Long-Only, Pending, Cooldown)
-/// - Expectancy evaluation
+/// - Entry gates (daily trade cap, open blackout)
+/// - Expectancy evaluation (entries only unless `exits_bypass_cost_filters` is off)
 /// - Minimum hold time checks
 /// - Trade proposal construction (quantity, order type)
-/// - Cost-aware profitability analysis
+/// - Cost-aware profitability analysis (same gating as expectancy)
 pub struct TradeEvaluator {
     trade_filter: TradeFilter,
     signal_processor: SignalProcessor,
@@ -27,8 +28,11 @@ pub struct EvaluationInput<'a> {
     pub price: Decimal,
     pub timestamp: i64,
     pub regime: &'a MarketRegime,
-    pub execution_service: &'a Arc<dyn ExecutionService>,
+    /// Portfolio read for this candle (None = the broker could not be reached)
+    pub portfolio: Option<&'a Portfolio>,
     pub has_position: bool,
+    /// Flat before this signal (no long or short): a fill would be a new entry
+    pub opens_position: bool,
     pub strategy_signal: Option<crate::application::strategies::Signal>,
 }
 
@@ -40,12 +44,20 @@ impl TradeEvaluator {
         }
     }
 
+    /// Count filter rejections in the given metrics
+    pub fn with_metrics(mut self, metrics: Metrics) -> Self {
+        self.trade_filter = self.trade_filter.with_metrics(metrics);
+        self
+    }
+
     /// Evaluate a signal and generate a valid trade proposal if it passes all checks.
+    ///
+    /// On failure, returns the name of the check that blocked the signal.
     pub async fn evaluate_and_propose(
         &self,
         context: &mut SymbolContext,
         input: EvaluationInput<'_>,
-    ) -> Option<TradeProposal> {
+    ) -> Result<TradeProposal, &'static str> {
         // 1. Basic Signal Validation (Long-Only, Pending, Cooldown)
         if !self.trade_filter.validate_signal(
             input.signal,
@@ -55,30 +67,75 @@ impl TradeEvaluator {
             input.timestamp,
             input.has_position,
         ) {
-            return None;
+            return Err("signal_filter");
+        }
+
+        // Per-symbol daily entry cap
+        if input.opens_position
+            && !self.trade_filter.validate_daily_trade_cap(
+                input.symbol,
+                &context.position_manager,
+                &context.config,
+                input.timestamp,
+            )
+        {
+            return Err("daily_trade_cap");
+        }
+
+        // No-trade zone right after the session open
+        if input.opens_position
+            && !self.trade_filter.validate_open_blackout(
+                input.symbol,
+                &context.config,
+                input.timestamp,
+            )
+        {
+            return Err("open_blackout");
         }
 
         // 2. Execution Logic (Expectancy & Quantity)
         context.position_manager.last_signal_time = input.timestamp;
 
+        // Only entries are cost-gated; an exit (selling a long or covering a short)
+        // must never be trapped by its costs
+        let position_qty = input
+            .portfolio
+            .and_then(|p| p.positions.get(input.symbol))
+            .map_or(Decimal::ZERO, |pos| pos.quantity);
+        let cost_gated = input.signal.increases_exposure(position_qty)
+            || !context.config.exits_bypass_cost_filters;
+
         // Use already calculated regime for expectancy
         let expectancy = context
             .expectancy_evaluator
             .evaluate(input.symbol, input.price, input.regime)
             .await;
 
+        let cold_start = expectancy.sample_size < context.config.cold_start_min_trades;
         let risk_ratio = if expectancy.reward_risk_ratio > Decimal::ZERO {
             expectancy.reward_risk_ratio
+        } else if context.cached_reward_risk_ratio > Decimal::ZERO {
+            context.cached_reward_risk_ratio
+        } else if cold_start && context.config.cold_start_reward_risk_ratio > Decimal::ZERO {
+            info!(
+                "Analyst [{}]: cold start ({}/{} closed trades, no measured reward/risk) - assuming {}",
+                input.symbol,
+                expectancy.sample_size,
+                context.config.cold_start_min_trades,
+                context.config.cold_start_reward_risk_ratio
+            );
+            context.config.cold_start_reward_risk_ratio
         } else {
             context.cached_reward_risk_ratio
         };
 
         // Validate using calculated or cached ratio
-        if !self
-            .trade_filter
-            .validate_expectancy(input.symbol, risk_ratio)
+        if cost_gated
+            && !self
+                .trade_filter
+                .validate_expectancy(input.symbol, risk_ratio)
         {
-            return None;
+            return Err("expectancy");
         }
 
         // Check minimum hold time for sell signals
@@ -89,7 +146,7 @@ impl TradeEvaluator {
             context.last_entry_time,
             context.min_hold_time_ms,
         ) {
-            return None;
+            return Err("min_hold_time");
         }
 
         let order_type = match input.signal {
@@ -110,55 +167,275 @@ impl TradeEvaluator {
         });
 
         // 3. Build Proposal via SignalProcessor
-        let mut proposal = match self
-            .signal_processor
-            .build_proposal(
-                &context.config,
-                input.execution_service,
-                input.symbol.to_string(),
-                signal_object,
-                input.price,
-                input.timestamp,
-            )
-            .await
-        {
+        let Some(portfolio) = input.portfolio else {
+            return Err("sizing");
+        };
+        let mut proposal = match self.signal_processor.build_proposal(
+            &context.config,
+            portfolio,
+            input.symbol.to_string(),
+            signal_object,
+            input.price,
+            input.timestamp,
+        ) {
             Some(p) => p,
-            None => return None,
+            None => return Err("sizing"),
         };
 
         proposal.order_type = order_type;
 
         // 4. Cost-Aware Trading Filter
-        let atr = context.last_features.atr.unwrap_or(Decimal::ZERO);
+        if cost_gated {
+            let atr = context.last_features.atr.unwrap_or(Decimal::ZERO);
 
-        info!(
-            "Analyst [{}]: Calculating Profit Expectancy - ATR={}, Multiplier={}, Quantity={}",
-            input.symbol, atr, context.config.profit_target_multiplier, proposal.quantity
-        );
+            info!(
+                "Analyst [{}]: Calculating Profit Expectancy - ATR={}, Multiplier={}, Quantity={}",
+                input.symbol, atr, context.config.profit_target_multiplier, proposal.quantity
+            );
 
-        // Use fresh expectancy value if available
-        let expected_profit = if expectancy.expected_value > Decimal::ZERO {
-            expectancy.expected_value * proposal.quantity
-        } else {
-            self.trade_filter.calculate_expected_profit(
+            // Use fresh expectancy value if available
+            let expected_profit = if expectancy.expected_value > Decimal::ZERO {
+                expectancy.expected_value * proposal.quantity
+            } else {
+                self.trade_filter.calculate_expected_profit(
+                    &proposal,
+                    atr,
+                    context.config.profit_target_multiplier,
+                )
+            };
+
+            let costs = self.trade_filter.evaluate_costs(&proposal);
+
+            if !self.trade_filter.validate_profitability(
                 &proposal,
-                atr,
-                context.config.profit_target_multiplier,
-            )
+                expected_profit,
+                costs.total_cost,
+                context.config.min_profit_ratio,
+                input.symbol,
+            ) {
+                return Err("profitability");
+            }
+        }
+
+        // Counted against the daily cap only once the entry fills
+        if input.opens_position {
+            context.position_manager.pending_entry_day = Some(
+                context
+                    .config
+                    .trading_day_boundary
+                    .trading_day_at_ms(input.timestamp),
+            );
+        }
+
+        Ok(proposal)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::application::agents::analyst_config::AnalystConfig;
+    use crate::application::market_data::spread_cache::SpreadCache;
+    use crate::application::monitoring::cost_evaluator::CostEvaluator;
+    use crate::application::optimization::win_rate_provider::StaticWinRateProvider;
+    use crate::application::risk_management::sizing_engine::SizingEngine;
+    use crate::application::strategies::{DualSMAStrategy, Signal};
+    use crate::domain::market::market_regime::MarketRegimeType;
+    use crate::domain::trading::fee_model::ConstantFeeModel;
+    use crate::domain::trading::portfolio::Position;
+    use rust_decimal_macros::dec;
+    use std::collections::VecDeque;
+    use std::sync::Arc;
+
+    const NOW_MS: i64 = 1_700_000_000_000;
+
+    /// Fees so high that no trade can ever clear the cost filters
+    fn prohibitive_evaluator() -> TradeEvaluator {
+        let fee_model = Arc::new(ConstantFeeModel::new(dec!(1000), dec!(0.5)));
+        let trade_filter = TradeFilter::new(CostEvaluator::new(fee_model, dec!(0)));
+        let signal_processor =
+            SignalProcessor::new(Arc::new(SizingEngine::new(Arc::new(SpreadCache::new()))));
+        TradeEvaluator::new(trade_filter, signal_processor)
+    }
+
+    fn context(exits_bypass_cost_filters: bool) -> SymbolContext {
+        let config = AnalystConfig {
+            min_profit_ratio: dec!(1000),
+            exits_bypass_cost_filters,
+            ..AnalystConfig::default()
         };
+        let mut context = SymbolContext::new(
+            config,
+            Arc::new(DualSMAStrategy::new(20, 50, dec!(0.0))),
+            Arc::new(StaticWinRateProvider::new(0.5)),
+            vec![],
+        );
+        context.cached_reward_risk_ratio = Decimal::ZERO;
+        context
+    }
+
+    fn portfolio_with_position(quantity: Decimal) -> Portfolio {
+        let mut portfolio = Portfolio::new();
+        portfolio.cash = dec!(100000);
+        portfolio.positions.insert(
+            "AAPL".to_string(),
+            Position {
+                symbol: "AAPL".to_string(),
+                quantity,
+                average_price: dec!(150),
+                lots: VecDeque::new(),
+            },
+        );
+        portfolio
+    }
 
-        let costs = self.trade_filter.evaluate_costs(&proposal);
+    async fn evaluate_stop_exit(
+        exits_bypass_cost_filters: bool,
+    ) -> Result<TradeProposal, &'static str> {
+        let evaluator = prohibitive_evaluator();
+        let mut context = context(exits_bypass_cost_filters);
+        let portfolio = portfolio_with_position(dec!(10));
+        let regime = MarketRegime::new(MarketRegimeType::Unknown, dec!(0), dec!(0), dec!(0));
 
-        if !self.trade_filter.validate_profitability(
-            &proposal,
-            expected_profit,
-            costs.total_cost,
-            context.config.min_profit_ratio,
-            input.symbol,
-        ) {
-            return None;
-        }
+        evaluator
+            .evaluate_and_propose(
+                &mut context,
+                EvaluationInput {
+                    signal: OrderSide::Sell,
+                    symbol: "AAPL",
+                    price: dec!(140),
+                    timestamp: NOW_MS,
+                    regime: &regime,
+                    portfolio: Some(&portfolio),
+                    has_position: true,
+                    opens_position: false,
+                    strategy_signal: Some(Signal::sell("Trailing Stop Triggered")),
+                },
+            )
+            .await
+    }
+
+    /// Sell signal while flat with shorts enabled (opens a short)
+    async fn evaluate_short_entry() -> Result<TradeProposal, &'static str> {
+        let evaluator = prohibitive_evaluator();
+        let mut context = context(true);
+        context.config.allow_shorts = true;
+        let mut portfolio = Portfolio::new();
+        portfolio.cash = dec!(100000);
+        portfolio.allow_shorts = true;
+        let regime = MarketRegime::new(MarketRegimeType::Unknown, dec!(0), dec!(0), dec!(0));
+
+        evaluator
+            .evaluate_and_propose(
+                &mut context,
+                EvaluationInput {
+                    signal: OrderSide::Sell,
+                    symbol: "AAPL",
+                    price: dec!(150),
+                    timestamp: NOW_MS,
+                    regime: &regime,
+                    portfolio: Some(&portfolio),
+                    has_position: false,
+                    opens_position: true,
+                    strategy_signal: Some(Signal::sell("Death Cross")),
+                },
+            )
+            .await
+    }
 
-        Some(proposal)
+    /// Buy signal while short (covers the short)
+    async fn evaluate_short_cover() -> Result<TradeProposal, &'static str> {
+        let evaluator = prohibitive_evaluator();
+        let mut context = context(true);
+        context.config.allow_shorts = true;
+        let mut portfolio = portfolio_with_position(dec!(-10));
+        portfolio.allow_shorts = true;
+        let regime = MarketRegime::new(MarketRegimeType::Unknown, dec!(0), dec!(0), dec!(0));
+
+        evaluator
+            .evaluate_and_propose(
+                &mut context,
+                EvaluationInput {
+                    signal: OrderSide::Buy,
+                    symbol: "AAPL",
+                    price: dec!(160),
+                    timestamp: NOW_MS,
+                    regime: &regime,
+                    portfolio: Some(&portfolio),
+                    has_position: true,
+                    opens_position: false,
+                    strategy_signal: Some(Signal::buy("Trailing Stop Triggered")),
+                },
+            )
+            .await
+    }
+
+    #[tokio::test]
+    async fn test_stop_loss_exit_never_suppressed_by_cost_filters() {
+        let proposal = evaluate_stop_exit(true)
+            .await
+            .expect("stop exit must bypass cost filters");
+        assert_eq!(proposal.side, OrderSide::Sell);
+        assert_eq!(proposal.quantity, dec!(10));
+    }
+
+    #[tokio::test]
+    async fn test_exit_cost_gating_can_be_enabled() {
+        assert!(evaluate_stop_exit(false).await.is_err());
+    }
+
+    #[tokio::test]
+    async fn test_cost_bypass_follows_position_direction() {
+        // Opening a short is an entry and stays cost-gated
+        assert_eq!(evaluate_short_entry().await.unwrap_err(), "profitability");
+
+        // Covering a short is an exit and bypasses the cost filters
+        let proposal = evaluate_short_cover()
+            .await
+            .expect("short cover must bypass cost filters");
+        assert_eq!(proposal.side, OrderSide::Buy);
+        assert_eq!(proposal.quantity, dec!(10));
+    }
+
+    /// Entry on a symbol with no trade history and no measured reward/risk
+    async fn evaluate_cold_start_entry(
+        cold_start_reward_risk_ratio: Decimal,
+    ) -> Result<TradeProposal, &'static str> {
+        let evaluator = prohibitive_evaluator();
+        let mut context = context(true);
+        context.config.cold_start_reward_risk_ratio = cold_start_reward_risk_ratio;
+        let mut portfolio = Portfolio::new();
+        portfolio.cash = dec!(100000);
+        let regime = MarketRegime::new(MarketRegimeType::Unknown, dec!(0), dec!(0), dec!(0));
+
+        evaluator
+            .evaluate_and_propose(
+                &mut context,
+                EvaluationInput {
+                    signal: OrderSide::Buy,
+                    symbol: "AAPL",
+                    price: dec!(150),
+                    timestamp: NOW_MS,
+                    regime: &regime,
+                    portfolio: Some(&portfolio),
+                    has_position: false,
+                    opens_position: true,
+                    strategy_signal: Some(Signal::buy("Golden Cross")),
+                },
+            )
+            .await
+    }
+
+    #[tokio::test]
+    async fn test_cold_start_ratio_lets_first_entries_reach_cost_filters() {
+        assert_eq!(
+            evaluate_cold_start_entry(Decimal::ZERO).await.unwrap_err(),
+            "expectancy"
+        );
+        // Past the expectancy gate; the prohibitive fees reject it further down
+        assert_ne!(
+            evaluate_cold_start_entry(dec!(1.0)).await.unwrap_err(),
+            "expectancy"
+        );
     }
 }
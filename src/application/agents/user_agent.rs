@@ -1,4 +1,3 @@
-use crate::application::agents::analyst::AnalystCommand;
 use crate::application::agents::sentinel::SentinelCommand;
 use crate::application::client::{SystemClient, SystemEvent};
 use crate::application::risk_management::commands::RiskCommand;
@@ -18,8 +17,9 @@ use rust_decimal_macros::dec;
 use std::collections::VecDeque;
 use std::str::FromStr;
 use std::sync::Arc;
+use std::time::Duration;
 use tokio::sync::RwLock;
-use tracing::{debug, error, info};
+use tracing::{debug, error, info, warn};
 
 /// Activity event type for the activity feed
 #[derive(Clone, Debug)]
@@ -65,7 +65,7 @@ pub struct UserAgent {
     pub portfolio: Arc<RwLock<Portfolio>>,
 
     // UI State
-    pub chat_history: Vec<(String, String)>, // (Sender, Message)
+    pub chat_history: VecDeque<(String, String)>, // (Sender, Message)
     pub input_text: String,
     pub is_focused: bool,
     pub market_data: std::collections::HashMap<String, Vec<Candle>>, // Store history
@@ -100,7 +100,9 @@ pub struct UserAgent {
 
     // Performance & Risk metrics (Dynamic)
     pub latency_ms: u64,
-    pub risk_score: u8, // Risk appetite score (1-9)
+    pub risk_score: u8,             // Risk appetite score (1-9)
+    pub trading_paused: bool,       // Operator pause: RiskManager rejects new entries
+    pub flatten_confirm_open: bool, // "Flatten All" confirmation dialog visible
     pub market_sentiment: Option<Sentiment>,
 
     // Phase 4: Analytics State
@@ -112,8 +114,44 @@ pub struct UserAgent {
     pub active_symbols: Vec<String>,
     pub symbols_loading: bool,
     pub symbol_selector_state: crate::interfaces::settings_components::SymbolSelectorState,
+
+    // Manual order entry form
+    pub order_entry: crate::interfaces::dashboard_components::order_entry::OrderEntryState,
+
+    // Price chart indicator overlays
+    pub chart_overlays: crate::interfaces::dashboard_components::chart_panel::ChartOverlays,
+
+    // Session equity samples (timestamp ms, total value) for the drawdown panel
+    pub equity_samples: VecDeque<(i64, Decimal)>,
+
+    // Chart candle retention per symbol
+    pub chart_candle_limit: usize,
+    pub chart_downsample_older: bool,
+
+    // Log panel retention
+    pub log_max_lines: usize,
+    pub log_keep_warnings: bool,
+
+    // Repaint throttle
+    pub max_fps: u32,
+    pub idle_repaint_hz: u32,
+    // (cash, open positions, trades) seen on the last update, to spot portfolio changes
+    last_portfolio_fingerprint: Option<(Decimal, usize, usize)>,
 }
 
+/// Minimum spacing between two session equity samples
+const EQUITY_SAMPLE_INTERVAL_MS: i64 = 10_000;
+/// 24h of samples at the interval above
+const MAX_EQUITY_SAMPLES: usize = 8_640;
+/// Candles kept per symbol for the charts by default (UI_CHART_CANDLES)
+pub const DEFAULT_CHART_CANDLE_LIMIT: usize = 100;
+/// Lines kept in the logs panel by default (UI_LOG_MAX_LINES)
+pub const DEFAULT_LOG_MAX_LINES: usize = 1_000;
+/// Repaint cap while events are flowing by default (UI_MAX_FPS)
+pub const DEFAULT_UI_MAX_FPS: u32 = 30;
+/// Heartbeat repaint rate when nothing changed by default (UI_IDLE_REPAINT_HZ)
+pub const DEFAULT_UI_IDLE_REPAINT_HZ: u32 = 4;
+
 /// Direction of the market trend for a symbol
 #[derive(Clone, Copy, PartialEq, Eq, Debug)]
 pub enum TrendDirection {
@@ -146,6 +184,94 @@ pub struct StrategyInfo {
 pub struct UserAgentConfig {
     pub strategy_mode: StrategyMode,
     pub risk_appetite: Option<crate::domain::risk::risk_appetite::RiskAppetite>,
+    /// Candles kept per symbol for the charts
+    pub chart_candle_limit: usize,
+    /// Merge older chart candles pairwise instead of dropping them once the limit is hit
+    pub chart_downsample_older: bool,
+    /// Lines kept in the logs panel
+    pub log_max_lines: usize,
+    /// Evict INFO/DEBUG lines before WARN/ERROR ones once the panel is full
+    pub log_keep_warnings: bool,
+    /// Upper bound on repaints per second while events are arriving
+    pub max_fps: u32,
+    /// Heartbeat repaints per second when no event arrived
+    pub idle_repaint_hz: u32,
+}
+
+fn is_warning_line(msg: &str) -> bool {
+    msg.contains("ERROR") || msg.contains("WARN")
+}
+
+/// Trim the logs panel to `max_lines`, oldest first.
+///
+/// With `keep_warnings`, the oldest INFO/DEBUG (and chat) lines go first, so WARN/ERROR
+/// lines outlive a chatty INFO stream; they are only dropped once they alone fill the panel.
+pub fn retain_log_lines(
+    history: &mut VecDeque<(String, String)>,
+    max_lines: usize,
+    keep_warnings: bool,
+) {
+    let mut excess = history.len().saturating_sub(max_lines);
+    if excess == 0 {
+        return;
+    }
+
+    if keep_warnings {
+        history.retain(|(_, msg)| {
+            if excess > 0 && !is_warning_line(msg) {
+                excess -= 1;
+                false
+            } else {
+                true
+            }
+        });
+    }
+    history.drain(..excess);
+}
+
+/// Delay before the next repaint.
+///
+/// New activity schedules the next frame at the `max_fps` cadence; otherwise the UI only
+/// wakes at the `idle_repaint_hz` heartbeat. Input events still repaint immediately (egui).
+pub fn repaint_delay(had_activity: bool, max_fps: u32, idle_repaint_hz: u32) -> Duration {
+    let hz = if had_activity {
+        max_fps.max(1)
+    } else {
+        idle_repaint_hz.clamp(1, max_fps.max(1))
+    };
+    Duration::from_secs_f64(1.0 / hz as f64)
+}
+
+/// Trim a symbol's chart candles to `limit`.
+///
+/// Without downsampling the oldest candles are dropped. With it, the older half of the
+/// buffer is merged pairwise instead, so history fades to coarser bars rather than
+/// disappearing while the recent half stays at full resolution.
+fn retain_chart_candles(candles: &mut Vec<Candle>, limit: usize, downsample: bool) {
+    if candles.len() <= limit {
+        return;
+    }
+    // Too few candles to split into a merged and a full-resolution half
+    if !downsample || limit < 4 {
+        let excess = candles.len() - limit;
+        candles.drain(0..excess);
+        return;
+    }
+
+    let older = (candles.len() / 2) & !1;
+    let merged: Vec<Candle> = candles[..older]
+        .chunks(2)
+        .map(|pair| Candle {
+            symbol: pair[0].symbol.clone(),
+            open: pair[0].open,
+            high: pair[0].high.max(pair[1].high),
+            low: pair[0].low.min(pair[1].low),
+            close: pair[1].close,
+            volume: pair[0].volume + pair[1].volume,
+            timestamp: pair[0].timestamp,
+        })
+        .collect();
+    candles.splice(..older, merged);
 }
 
 impl UserAgent {
@@ -156,23 +282,20 @@ impl UserAgent {
     ) -> Self {
         // Initialize I18n and SettingsPanel first
         let i18n = I18nService::new();
-        let settings_panel = crate::interfaces::ui_components::SettingsPanel::new();
+        let mut settings_panel = crate::interfaces::ui_components::SettingsPanel::new();
 
         // --- Sync Persisted Settings to Agents ---
-        // Since SettingsPanel loads from disk on ::new(), we send those values
-        // to the backend agents (RiskManager, Analyst) to ensure they are insync on startup.
-        let risk_config = settings_panel.to_risk_config();
-        if let Err(e) = client.send_risk_command(RiskCommand::UpdateConfig(Box::new(risk_config))) {
-            error!("Failed to sync risk settings on startup: {}", e);
-        }
-
-        let analyst_config = settings_panel.to_analyst_config();
-        if let Err(e) =
-            client.send_analyst_command(AnalystCommand::UpdateConfig(Box::new(analyst_config)))
-        {
-            error!("Failed to sync analyst settings on startup: {}", e);
-        } else {
-            info!("Successfully synced persisted settings to Analyst and RiskManager");
+        // Settings saved in an earlier session are layered onto the engine's running config;
+        // without any, the form shows what the engine runs with and nothing is sent.
+        if settings_panel.has_persisted_settings {
+            crate::interfaces::ui_components::send_settings_to_agents(&mut settings_panel, &client);
+            info!("Synced persisted settings to Analyst and RiskManager");
+        } else if let (Ok(risk), Ok(analyst)) = (
+            client.risk_config().try_read(),
+            client.analyst_config().try_read(),
+        ) {
+            settings_panel.apply_engine_config(&risk, &analyst);
+            settings_panel.mark_applied(analyst.clone());
         }
 
         let initial_risk_score = settings_panel.risk_score;
@@ -180,7 +303,7 @@ impl UserAgent {
         Self {
             client,
             portfolio,
-            chat_history: Vec::new(),
+            chat_history: VecDeque::new(),
             input_text: String::new(),
             is_focused: true,
             market_data: std::collections::HashMap::new(),
@@ -198,6 +321,8 @@ impl UserAgent {
             current_view: crate::interfaces::ui_components::DashboardView::Dashboard,
             latency_ms: 12,                 // Default initial value
             risk_score: initial_risk_score, // Use the score from the loaded settings
+            trading_paused: false,
+            flatten_confirm_open: false,
             market_sentiment: None,
             monte_carlo_result: None,
             correlation_matrix: std::collections::HashMap::new(),
@@ -207,6 +332,17 @@ impl UserAgent {
             symbols_loading: false,
             symbol_selector_state: crate::interfaces::settings_components::SymbolSelectorState::new(
             ),
+            order_entry: crate::interfaces::dashboard_components::order_entry::OrderEntryState::new(
+            ),
+            chart_overlays: Default::default(),
+            equity_samples: VecDeque::new(),
+            chart_candle_limit: config.chart_candle_limit.max(1),
+            chart_downsample_older: config.chart_downsample_older,
+            log_max_lines: config.log_max_lines.max(1),
+            log_keep_warnings: config.log_keep_warnings,
+            max_fps: config.max_fps.max(1),
+            idle_repaint_hz: config.idle_repaint_hz.max(1),
+            last_portfolio_fingerprint: None,
         }
     }
 
@@ -218,7 +354,7 @@ impl UserAgent {
         }
 
         self.chat_history
-            .push((self.i18n.t("sender_user").to_string(), input.clone()));
+            .push_back((self.i18n.t("sender_user").to_string(), input.clone()));
         self.input_text.clear();
 
         // Simple Natural Language Parsing
@@ -228,11 +364,15 @@ impl UserAgent {
                 let _ = self.client.send_sentinel_command(SentinelCommand::Shutdown);
                 Some(self.i18n.t("cmd_shutdown_sent").to_string())
             }
+            ["pause"] => Some(self.set_trading_paused(true)),
+            ["resume"] => Some(self.set_trading_paused(false)),
             ["status"] => {
                 // In a real agent, we might query the system.
                 // For now, we just print local state or rely on logs.
                 Some(self.i18n.t("cmd_status_request").to_string())
             }
+            ["subscribe", symbol] => Some(self.set_symbol_subscribed(symbol, true)),
+            ["unsubscribe", symbol] => Some(self.set_symbol_subscribed(symbol, false)),
             ["buy", symbol, quantity] => {
                 self.handle_trade_command(symbol, quantity, OrderSide::Buy)
             }
@@ -243,6 +383,207 @@ impl UserAgent {
         }
     }
 
+    /// Start or stop a symbol's feed on the running bot. Returns a chat/activity message.
+    pub fn set_symbol_subscribed(&mut self, symbol: &str, subscribed: bool) -> String {
+        let symbol = symbol.to_uppercase();
+        let cmd = if subscribed {
+            SentinelCommand::Subscribe(symbol.clone())
+        } else {
+            SentinelCommand::Unsubscribe(symbol.clone())
+        };
+        match self.client.send_sentinel_command(cmd) {
+            Ok(_) => {
+                if subscribed {
+                    if !self.active_symbols.contains(&symbol) {
+                        self.active_symbols.push(symbol.clone());
+                    }
+                } else {
+                    self.active_symbols.retain(|s| *s != symbol);
+                    self.market_data.remove(&symbol);
+                    self.strategy_info.remove(&symbol);
+                }
+                let message = self.i18n.tf(
+                    if subscribed {
+                        "cmd_symbol_subscribed"
+                    } else {
+                        "cmd_symbol_unsubscribed"
+                    },
+                    &[("symbol", &symbol)],
+                );
+                self.add_activity(
+                    ActivityEventType::System,
+                    message.clone(),
+                    EventSeverity::Info,
+                );
+                message
+            }
+            Err(e) => {
+                error!("Failed to send subscription command: {}", e);
+                self.i18n
+                    .tf("cmd_subscription_failed", &[("error", &e.to_string())])
+            }
+        }
+    }
+
+    /// Pause or resume new entries via the RiskManager. Returns a chat/activity message.
+    pub fn set_trading_paused(&mut self, paused: bool) -> String {
+        let cmd = if paused {
+            RiskCommand::Pause
+        } else {
+            RiskCommand::Resume
+        };
+        match self.client.send_risk_command(cmd) {
+            Ok(_) => {
+                self.trading_paused = paused;
+                let message = self
+                    .i18n
+                    .t(if paused {
+                        "cmd_trading_paused"
+                    } else {
+                        "cmd_trading_resumed"
+                    })
+                    .to_string();
+                self.add_activity(
+                    ActivityEventType::System,
+                    message.clone(),
+                    if paused {
+                        EventSeverity::Warning
+                    } else {
+                        EventSeverity::Info
+                    },
+                );
+                message
+            }
+            Err(e) => {
+                error!("Failed to send pause/resume command: {}", e);
+                self.i18n
+                    .tf("cmd_proposal_failed", &[("error", &e.to_string())])
+            }
+        }
+    }
+
+    /// Submit a market order closing the full position in `symbol` (a sell for a long,
+    /// a buy for a short) through the normal proposal pipeline. The outcome is pushed
+    /// to the activity feed.
+    pub fn close_position(&mut self, symbol: &str) {
+        // A contended lock is not the same as "no position": say so instead of guessing
+        let Ok(portfolio) = self.portfolio.try_read() else {
+            self.report_portfolio_busy();
+            return;
+        };
+        let quantity = portfolio
+            .positions
+            .get(symbol)
+            .map(|p| p.quantity)
+            .filter(|q| !q.is_zero());
+        drop(portfolio);
+
+        let Some(quantity) = quantity else {
+            self.add_activity(
+                ActivityEventType::Alert,
+                self.i18n.tf("close_no_position", &[("symbol", symbol)]),
+                EventSeverity::Warning,
+            );
+            return;
+        };
+
+        let price = self
+            .market_data
+            .get(symbol)
+            .and_then(|candles| candles.last())
+            .map(|c| c.close)
+            .or_else(|| self.strategy_info.get(symbol).map(|i| i.current_price))
+            .filter(|p| *p > Decimal::ZERO);
+
+        // Without a mark the close would be sized and reserved at zero
+        let Some(price) = price else {
+            warn!(
+                "UserAgent: not closing {} - no market price known yet",
+                symbol
+            );
+            self.add_activity(
+                ActivityEventType::Alert,
+                self.i18n.tf("close_no_price", &[("symbol", symbol)]),
+                EventSeverity::Warning,
+            );
+            return;
+        };
+
+        let proposal = close_proposal(
+            symbol,
+            quantity,
+            price,
+            self.i18n.t("activity_manual_close"),
+        );
+        let side = self
+            .i18n
+            .t(&format!(
+                "side_{}",
+                proposal.side.to_string().to_lowercase()
+            ))
+            .to_string();
+        let quantity = proposal.quantity;
+
+        match self.client.submit_proposal(proposal) {
+            Ok(_) => self.add_activity(
+                ActivityEventType::TradeExecuted,
+                self.i18n.tf(
+                    "close_order_sent",
+                    &[
+                        ("side", &side),
+                        ("qty", &quantity.to_string()),
+                        ("symbol", symbol),
+                    ],
+                ),
+                EventSeverity::Info,
+            ),
+            Err(e) => self.add_activity(
+                ActivityEventType::Alert,
+                self.i18n
+                    .tf("cmd_proposal_failed", &[("error", &e.to_string())]),
+                EventSeverity::Error,
+            ),
+        }
+    }
+
+    /// Close every open position, long or short, through the normal pipeline.
+    pub fn flatten_all(&mut self) {
+        let Ok(portfolio) = self.portfolio.try_read() else {
+            self.report_portfolio_busy();
+            return;
+        };
+        let symbols: Vec<String> = portfolio
+            .positions
+            .iter()
+            .filter(|(_, p)| !p.quantity.is_zero())
+            .map(|(s, _)| s.clone())
+            .collect();
+        drop(portfolio);
+
+        warn!(
+            "UserAgent: FLATTEN ALL requested for {} positions",
+            symbols.len()
+        );
+        self.add_activity(
+            ActivityEventType::Alert,
+            self.i18n
+                .tf("flatten_all_sent", &[("count", &symbols.len().to_string())]),
+            EventSeverity::Warning,
+        );
+        for symbol in symbols {
+            self.close_position(&symbol);
+        }
+    }
+
+    fn report_portfolio_busy(&mut self) {
+        warn!("UserAgent: portfolio lock busy, manual close not sent");
+        self.add_activity(
+            ActivityEventType::Alert,
+            self.i18n.t("portfolio_busy_retry").to_string(),
+            EventSeverity::Warning,
+        );
+    }
+
     fn handle_trade_command(
         &self,
         symbol: &str,
@@ -260,6 +601,8 @@ impl UserAgent {
                 timestamp: chrono::Utc::now().timestamp_millis(), // i64
                 stop_loss: None,
                 take_profit: None,
+                reduce_only: false,
+                origin: Default::default(),
             };
 
             match self.client.submit_proposal(proposal) {
@@ -287,10 +630,16 @@ impl UserAgent {
         }
     }
 
-    /// Update internal state from incoming events
-    pub fn update(&mut self) {
+    /// Update internal state from incoming events.
+    ///
+    /// Returns whether anything changed (an event arrived or the portfolio moved), which
+    /// drives the repaint cadence.
+    pub fn update(&mut self) -> bool {
+        let mut had_activity = self.portfolio_changed();
+
         // Poll all events from the client
         while let Some(event) = self.client.poll_next() {
+            had_activity = true;
             match event {
                 SystemEvent::Log(msg) => {
                     // Parse logs for activity events
@@ -317,7 +666,7 @@ impl UserAgent {
 
                     // Add to chat history
                     self.chat_history
-                        .push((self.i18n.t("sender_system").to_string(), msg));
+                        .push_back((self.i18n.t("sender_system").to_string(), msg));
                 }
                 SystemEvent::Sentiment(sentiment) => {
                     debug!(
@@ -344,18 +693,23 @@ impl UserAgent {
                     );
                     let entry = self.market_data.entry(candle.symbol.clone()).or_default();
                     entry.push(candle.clone());
-                    // Keep last 100 candles
-                    if entry.len() > 100 {
-                        entry.remove(0);
-                    }
+                    retain_chart_candles(
+                        entry,
+                        self.chart_candle_limit,
+                        self.chart_downsample_older,
+                    );
+
+                    self.record_equity_sample(chrono::Utc::now().timestamp_millis());
 
                     // Calculate SMAs and trend for this symbol
+                    let config = self.active_analyst_config();
                     let (fast_sma_value, slow_sma_value, trend) =
-                        self.calculate_trend(&candle.symbol);
+                        self.calculate_trend(&candle.symbol, &config);
 
                     // Initialize or update strategy info
                     if let Some(info) = self.strategy_info.get_mut(&candle.symbol) {
                         // Update existing entry
+                        info.mode = config.strategy_mode.to_string();
                         info.fast_sma = fast_sma_value;
                         info.slow_sma = slow_sma_value;
                         info.trend = trend;
@@ -365,7 +719,7 @@ impl UserAgent {
                         self.strategy_info.insert(
                             candle.symbol.clone(),
                             StrategyInfo {
-                                mode: self.strategy_mode.to_string(),
+                                mode: config.strategy_mode.to_string(),
                                 fast_sma: fast_sma_value,
                                 slow_sma: slow_sma_value,
                                 last_signal: None,
@@ -379,15 +733,52 @@ impl UserAgent {
         }
 
         // Keep history manageable (outside the loop to do it once per update tick)
-        if self.chat_history.len() > 1000 {
-            self.chat_history.drain(0..100);
-        }
+        retain_log_lines(
+            &mut self.chat_history,
+            self.log_max_lines,
+            self.log_keep_warnings,
+        );
+
+        had_activity
+    }
+
+    /// Delay before the next repaint given whether the last update saw activity
+    pub fn next_repaint_delay(&self, had_activity: bool) -> Duration {
+        repaint_delay(had_activity, self.max_fps, self.idle_repaint_hz)
+    }
+
+    /// Cheap change check on the shared portfolio (skipped while it is locked)
+    fn portfolio_changed(&mut self) -> bool {
+        let Ok(pf) = self.portfolio.try_read() else {
+            return false;
+        };
+        let fingerprint = (pf.cash, pf.positions.len(), pf.trade_history.len());
+        drop(pf);
+        self.last_portfolio_fingerprint
+            .replace(fingerprint)
+            .is_none_or(|previous| previous != fingerprint)
+    }
+
+    /// The config the Analyst is running with, falling back to the last
+    /// settings sent from the UI while the engine is publishing an update.
+    pub fn active_analyst_config(
+        &self,
+    ) -> crate::application::agents::analyst_config::AnalystConfig {
+        self.client
+            .analyst_config()
+            .try_read()
+            .map(|config| config.clone())
+            .unwrap_or_else(|_| self.settings_panel.applied_analyst_config.clone())
     }
 
     /// Calculate SMAs and trend direction for a symbol
-    fn calculate_trend(&self, symbol: &str) -> (f64, f64, TrendDirection) {
-        let fast_period = 20;
-        let slow_period = 50;
+    fn calculate_trend(
+        &self,
+        symbol: &str,
+        config: &crate::application::agents::analyst_config::AnalystConfig,
+    ) -> (f64, f64, TrendDirection) {
+        let fast_period = config.fast_sma_period.max(1);
+        let slow_period = config.slow_sma_period.max(1);
 
         let candles = match self.market_data.get(symbol) {
             Some(c) => c,
@@ -667,6 +1058,35 @@ impl UserAgent {
         }
     }
 
+    /// Samples total portfolio value, at most once per `EQUITY_SAMPLE_INTERVAL_MS`
+    fn record_equity_sample(&mut self, now_ms: i64) {
+        if self
+            .equity_samples
+            .back()
+            .is_some_and(|(ts, _)| now_ms - ts < EQUITY_SAMPLE_INTERVAL_MS)
+        {
+            return;
+        }
+        let equity = self.calculate_total_value();
+        if equity <= Decimal::ZERO {
+            return;
+        }
+        self.equity_samples.push_back((now_ms, equity));
+        while self.equity_samples.len() > MAX_EQUITY_SAMPLES {
+            self.equity_samples.pop_front();
+        }
+    }
+
+    /// Drawdown from peak over the session equity samples, including the live value
+    pub fn get_underwater_curve(&self) -> crate::domain::performance::drawdown::UnderwaterCurve {
+        let mut samples: Vec<(i64, Decimal)> = self.equity_samples.iter().copied().collect();
+        let equity = self.calculate_total_value();
+        if equity > Decimal::ZERO {
+            samples.push((chrono::Utc::now().timestamp_millis(), equity));
+        }
+        crate::domain::performance::drawdown::UnderwaterCurve::from_equity(&samples)
+    }
+
     /// Generate equity curve points for plotting [timestamp, equity]
     pub fn get_equity_curve_points(&self) -> Vec<[f64; 2]> {
         if let Ok(pf) = self.portfolio.try_read() {
@@ -710,3 +1130,117 @@ impl UserAgent {
         }
     }
 }
+
+/// Market order closing a position of `position_qty` (negative = short): a sell of a
+/// long, a buy covering a short. Reduce-only, so it can never open the opposite side.
+fn close_proposal(
+    symbol: &str,
+    position_qty: Decimal,
+    price: Decimal,
+    reason: &str,
+) -> TradeProposal {
+    TradeProposal {
+        symbol: symbol.to_string(),
+        side: if position_qty > Decimal::ZERO {
+            OrderSide::Sell
+        } else {
+            OrderSide::Buy
+        },
+        price,
+        quantity: position_qty.abs(),
+        order_type: crate::domain::trading::types::OrderType::Market,
+        reason: reason.to_string(),
+        timestamp: chrono::Utc::now().timestamp_millis(),
+        stop_loss: None,
+        take_profit: None,
+        reduce_only: true,
+        origin: Default::default(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn candle(timestamp: i64, close: i64) -> Candle {
+        Candle {
+            symbol: "AAPL".to_string(),
+            open: Decimal::from(close),
+            high: Decimal::from(close),
+            low: Decimal::from(close),
+            close: Decimal::from(close),
+            volume: Decimal::ONE,
+            timestamp,
+        }
+    }
+
+    #[test]
+    fn test_chart_candles_drop_oldest_beyond_limit() {
+        let mut candles: Vec<Candle> = (0..12).map(|i| candle(i, i)).collect();
+        retain_chart_candles(&mut candles, 10, false);
+        assert_eq!(candles.len(), 10);
+        assert_eq!(candles[0].timestamp, 2);
+    }
+
+    #[test]
+    fn test_chart_candles_downsample_older_half() {
+        let mut candles: Vec<Candle> = (0..11).map(|i| candle(i, i)).collect();
+        retain_chart_candles(&mut candles, 10, true);
+
+        // Oldest 4 merged into 2, the rest untouched
+        assert_eq!(candles.len(), 9);
+        assert_eq!(candles[0].timestamp, 0);
+        assert_eq!(candles[0].open, Decimal::from(0));
+        assert_eq!(candles[0].close, Decimal::from(1));
+        assert_eq!(candles[0].high, Decimal::from(1));
+        assert_eq!(candles[0].volume, Decimal::from(2));
+        assert_eq!(candles[1].timestamp, 2);
+        assert_eq!(candles[2].timestamp, 4);
+        assert_eq!(candles.last().unwrap().timestamp, 10);
+    }
+
+    fn log_lines(messages: &[&str]) -> VecDeque<(String, String)> {
+        messages
+            .iter()
+            .map(|m| ("System".to_string(), m.to_string()))
+            .collect()
+    }
+
+    #[test]
+    fn test_log_lines_keep_warnings_longer() {
+        let mut history = log_lines(&["WARN a", "INFO b", "ERROR c", "INFO d", "INFO e"]);
+        retain_log_lines(&mut history, 3, true);
+        let kept: Vec<&str> = history.iter().map(|(_, m)| m.as_str()).collect();
+        assert_eq!(kept, vec!["WARN a", "ERROR c", "INFO e"]);
+
+        // Warnings alone over the cap: oldest go
+        let mut history = log_lines(&["WARN a", "ERROR b", "WARN c"]);
+        retain_log_lines(&mut history, 2, true);
+        assert_eq!(history.front().unwrap().1, "ERROR b");
+
+        let mut history = log_lines(&["WARN a", "INFO b", "INFO c"]);
+        retain_log_lines(&mut history, 2, false);
+        assert_eq!(history.front().unwrap().1, "INFO b");
+    }
+
+    #[test]
+    fn test_repaint_delay_follows_activity() {
+        assert_eq!(repaint_delay(true, 20, 4), Duration::from_millis(50));
+        assert_eq!(repaint_delay(false, 20, 4), Duration::from_millis(250));
+        // The heartbeat never outpaces the FPS cap
+        assert_eq!(repaint_delay(false, 2, 4), Duration::from_millis(500));
+    }
+
+    #[test]
+    fn test_close_proposal_covers_shorts() {
+        let long = close_proposal("AAPL", Decimal::from(5), Decimal::from(100), "close");
+        assert_eq!(long.side, OrderSide::Sell);
+        assert_eq!(long.quantity, Decimal::from(5));
+        assert!(long.reduce_only);
+
+        let short = close_proposal("AAPL", Decimal::from(-3), Decimal::from(100), "close");
+        assert_eq!(short.side, OrderSide::Buy);
+        assert_eq!(short.quantity, Decimal::from(3));
+        assert!(short.reduce_only);
+    }
+}
@@ -2,26 +2,68 @@ use crate::application::strategies::{StrategyFactory, TradingStrategy};
 use crate::application::trading::symbol_context::SymbolContext;
 use crate::domain::market::market_regime::MarketRegime;
 use crate::domain::ports::MarketDataService;
-use crate::domain::repositories::StrategyRepository;
+use crate::domain::repositories::{CandleRepository, StrategyRepository};
 use crate::domain::trading::types::Candle;
+use futures::stream::{self, StreamExt};
 use rust_decimal::Decimal;
 
+use std::collections::HashMap;
+use std::fmt;
 use std::sync::Arc;
 use tokio::sync::broadcast;
 use tracing::{debug, info, warn};
 
+/// Trailing warmup candles replayed one by one; the earlier ones are primed in bulk.
+/// Covers the longest per-candle history (RSI history and ATR baseline keep 100).
+const WARMUP_DETAIL_BARS: usize = 100;
+
+/// Where warmup history came from, in fallback order
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum WarmupSource {
+    /// The trading broker's market data service
+    Primary,
+    /// Candles persisted locally by the aggregator
+    CandleRepository,
+    /// Secondary history provider (WARMUP_FALLBACK_SOURCE)
+    Secondary,
+}
+
+impl fmt::Display for WarmupSource {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            WarmupSource::Primary => write!(f, "primary broker"),
+            WarmupSource::CandleRepository => write!(f, "candle repository"),
+            WarmupSource::Secondary => write!(f, "secondary source"),
+        }
+    }
+}
+
+/// Bars fetched for one symbol's warmup, or None when every source failed
+pub type WarmupBars = Option<(WarmupSource, Vec<Candle>)>;
+
+/// Symbols warmed up in parallel when many initialize at once (WARMUP_CONCURRENCY)
+pub const DEFAULT_WARMUP_CONCURRENCY: usize = 4;
+
 /// Service responsible for warming up symbol contexts with historical data.
 ///
 /// This service handles:
-/// - Loading historical candles from market data service
+/// - Loading historical candles: primary broker, then the candle repository,
+///   then the secondary source, first non-empty result wins
 /// - Initializing technical indicators
 /// - Calculating and caching reward/risk ratios
 /// - Broadcasting historical candles to UI
 /// - Resolving per-symbol strategy configurations
+///
+/// Clones share the same sources, so a copy can fetch history on a background task.
+#[derive(Clone)]
 pub struct WarmupService {
     market_service: Arc<dyn MarketDataService>,
+    candle_repository: Option<Arc<dyn CandleRepository>>,
+    secondary_market_service: Option<Arc<dyn MarketDataService>>,
     strategy_repository: Option<Arc<dyn StrategyRepository>>,
     ui_candle_tx: Option<broadcast::Sender<Candle>>,
+    // Concurrent history fetches in a batch; the brokers' circuit breakers still apply
+    max_concurrency: usize,
 }
 
 impl WarmupService {
@@ -32,9 +74,163 @@ impl WarmupService {
     ) -> Self {
         Self {
             market_service,
+            candle_repository: None,
+            secondary_market_service: None,
             strategy_repository,
             ui_candle_tx,
+            max_concurrency: DEFAULT_WARMUP_CONCURRENCY,
+        }
+    }
+
+    /// Bound on concurrent history fetches in `fetch_warmup_bars_batch` (min 1)
+    pub fn with_max_concurrency(mut self, max_concurrency: usize) -> Self {
+        self.max_concurrency = max_concurrency.max(1);
+        self
+    }
+
+    /// Fall back to locally persisted candles when the broker returns no history
+    pub fn with_candle_repository(mut self, repo: Option<Arc<dyn CandleRepository>>) -> Self {
+        self.candle_repository = repo;
+        self
+    }
+
+    /// Last-resort history provider, tried after the candle repository
+    pub fn with_secondary_source(mut self, service: Option<Arc<dyn MarketDataService>>) -> Self {
+        self.secondary_market_service = service;
+        self
+    }
+
+    /// Fetch warmup bars from the first source that returns any.
+    ///
+    /// Errors and empty results fall through to the next source; None when all failed.
+    pub async fn fetch_warmup_bars(
+        &self,
+        symbol: &str,
+        start: chrono::DateTime<chrono::Utc>,
+        end: chrono::DateTime<chrono::Utc>,
+    ) -> Option<(WarmupSource, Vec<Candle>)> {
+        match self
+            .market_service
+            .get_historical_bars(symbol, start, end, "1Min")
+            .await
+        {
+            Ok(bars) if !bars.is_empty() => return Some((WarmupSource::Primary, bars)),
+            Ok(_) => warn!(
+                "WarmupService: {} returned no bars for {}",
+                WarmupSource::Primary,
+                symbol
+            ),
+            Err(e) => warn!(
+                "WarmupService: {} failed for {}: {}",
+                WarmupSource::Primary,
+                symbol,
+                e
+            ),
+        }
+
+        if let Some(repo) = &self.candle_repository {
+            match repo
+                .get_range(symbol, start.timestamp_millis(), end.timestamp_millis())
+                .await
+            {
+                Ok(bars) if !bars.is_empty() => {
+                    return Some((WarmupSource::CandleRepository, bars));
+                }
+                Ok(_) => warn!(
+                    "WarmupService: {} has no bars for {}",
+                    WarmupSource::CandleRepository,
+                    symbol
+                ),
+                Err(e) => warn!(
+                    "WarmupService: {} failed for {}: {}",
+                    WarmupSource::CandleRepository,
+                    symbol,
+                    e
+                ),
+            }
+        }
+
+        if let Some(secondary) = &self.secondary_market_service {
+            match secondary
+                .get_historical_bars(symbol, start, end, "1Min")
+                .await
+            {
+                Ok(bars) if !bars.is_empty() => return Some((WarmupSource::Secondary, bars)),
+                Ok(_) => warn!(
+                    "WarmupService: {} returned no bars for {}",
+                    WarmupSource::Secondary,
+                    symbol
+                ),
+                Err(e) => warn!(
+                    "WarmupService: {} failed for {}: {}",
+                    WarmupSource::Secondary,
+                    symbol,
+                    e
+                ),
+            }
         }
+
+        None
+    }
+
+    /// Fetch warmup bars for several symbols, at most `max_concurrency` at a time.
+    ///
+    /// Each request is `(symbol, start, end)` and goes through the same fallback chain
+    /// as `fetch_warmup_bars`.
+    pub async fn fetch_warmup_bars_batch(
+        &self,
+        requests: Vec<(
+            String,
+            chrono::DateTime<chrono::Utc>,
+            chrono::DateTime<chrono::Utc>,
+        )>,
+    ) -> HashMap<String, WarmupBars> {
+        info!(
+            "WarmupService: Fetching history for {} symbols ({} at a time)",
+            requests.len(),
+            self.max_concurrency
+        );
+        stream::iter(requests)
+            .map(|(symbol, start, end)| async move {
+                let bars = self.fetch_warmup_bars(&symbol, start, end).await;
+                (symbol, bars)
+            })
+            .buffer_unordered(self.max_concurrency)
+            .collect()
+            .await
+    }
+
+    /// Start of the history window needed to warm up indicators for `config`
+    pub fn warmup_start(
+        config: &super::analyst::AnalystConfig,
+        end: chrono::DateTime<chrono::Utc>,
+    ) -> chrono::DateTime<chrono::Utc> {
+        // Assuming 1-minute bars.
+        // Market is open 6.5h a day ~ 390mins.
+        // 2000 bars is ~5.1 trading days.
+        // We fetch enough calendar days back to cover weekends/holidays
+        let days_back = (Self::required_bars(config) / 300) + 3;
+        end - chrono::Duration::days(days_back as i64)
+    }
+
+    /// Longest indicator lookback for `config` plus a 10% buffer
+    fn required_bars(config: &super::analyst::AnalystConfig) -> usize {
+        (Self::max_period(config) as f64 * 1.1) as usize
+    }
+
+    // Max(TrendSMA, SlowSMA, EMA, RSI, MACD_Slow)
+    fn max_period(config: &super::analyst::AnalystConfig) -> usize {
+        [
+            config.trend_sma_period,
+            config.slow_sma_period,
+            config.ema_slow_period,
+            config.rsi_period * 2, // General rule for RSI stability
+            config.macd_slow_period + config.macd_signal_period,
+        ]
+        .iter()
+        .max()
+        .copied()
+        .unwrap_or(200)
     }
 
     /// Resolve the strategy and configuration for a given symbol.
@@ -78,61 +274,56 @@ impl WarmupService {
     ///
     /// This method:
     /// 1. Calculates required lookback period based on indicator periods
-    /// 2. Fetches historical candles from market data service
-    /// 3. Updates context with each candle to initialize indicators
+    /// 2. Fetches historical candles through the source fallback chain
+    /// 3. Primes indicators in bulk, then updates the context with the last candles
     /// 4. Calculates and caches reward/risk ratio
     /// 5. Broadcasts recent candles to UI for chart initialization
     /// 6. Marks warmup as successful
+    ///
+    /// When every source fails, `warmup_succeeded` stays false and the caller keeps
+    /// the symbol out of new entries.
     pub async fn warmup_context(
         &self,
         context: &mut SymbolContext,
         symbol: &str,
         end: chrono::DateTime<chrono::Utc>,
     ) {
-        // Calculate needed lookback
-        // Max(TrendSMA, SlowSMA, EMA, RSI, MACD_Slow)
-        let config = &context.config;
-        let max_period = [
-            config.trend_sma_period,
-            config.slow_sma_period,
-            config.ema_slow_period,
-            config.rsi_period * 2, // General rule for RSI stability
-            config.macd_slow_period + config.macd_signal_period,
-        ]
-        .iter()
-        .max()
-        .copied()
-        .unwrap_or(200);
-
-        // Add 10% buffer
-        let required_bars = (max_period as f64 * 1.1) as usize;
-
+        let max_period = Self::max_period(&context.config);
         info!(
             "WarmupService: Warming up {} with {} bars (Max Period: {}) ending at {}",
-            symbol, required_bars, max_period, end
+            symbol,
+            Self::required_bars(&context.config),
+            max_period,
+            end
         );
 
-        // Assuming 1-minute bars.
-        // Market is open 6.5h a day ~ 390mins.
-        // 2000 bars is ~5.1 trading days.
-        // We fetch enough calendar days back to cover weekends/holidays
-        let days_back = (required_bars / 300) + 3;
-        let start = end - chrono::Duration::days(days_back as i64);
+        let start = Self::warmup_start(&context.config, end);
+        let fetched = self.fetch_warmup_bars(symbol, start, end).await;
+        self.apply_warmup_bars(context, symbol, fetched).await;
+    }
 
-        match self
-            .market_service
-            .get_historical_bars(symbol, start, end, "1Min")
-            .await
-        {
-            Ok(bars) => {
-                let bars_count = bars.len();
+    /// Warm up a symbol context from bars fetched ahead of time (steps 3-6 of
+    /// `warmup_context`), e.g. by `fetch_warmup_bars_batch`.
+    pub async fn apply_warmup_bars(
+        &self,
+        context: &mut SymbolContext,
+        symbol: &str,
+        fetched: WarmupBars,
+    ) {
+        match fetched {
+            Some((source, bars)) => {
                 info!(
-                    "WarmupService: Fetched {} historical bars for {}",
-                    bars_count, symbol
+                    "WarmupService: Fetched {} historical bars for {} from {}",
+                    bars.len(),
+                    symbol,
+                    source
                 );
 
-                // Update context with each candle
-                for candle in &bars {
+                // Prime indicators in bulk, then replay the tail candle by candle for the
+                // per-candle histories (RSI, ATR baseline, ML sequence) and strategy warmup
+                let detail_start = bars.len().saturating_sub(WARMUP_DETAIL_BARS);
+                context.prime(&bars[..detail_start]);
+                for candle in &bars[detail_start..] {
                     context.update(candle);
 
                     // Construct minimal AnalysisContext for warmup (Sequential ML models need this)
@@ -160,6 +351,8 @@ impl WarmupService {
                         bb_upper: None,
                         bb_middle: None,
                         adx: fs.adx,
+                        plus_di: fs.plus_di,
+                        minus_di: fs.minus_di,
                         has_position: false,
                         position: None,
                         timestamp: candle.timestamp,
@@ -241,14 +434,12 @@ impl WarmupService {
                     bars.len()
                 );
             }
-            Err(e) => {
+            None => {
                 warn!(
-                    "WarmupService: Failed to warmup {}: {}. Indicators will start from zero (degraded mode)",
-                    symbol, e
+                    "WarmupService: All warmup sources failed for {}. Symbol not ready for new entries",
+                    symbol
                 );
                 // warmup_succeeded remains false
-                // Indicators are already initialized to zero/default in SymbolContext::new()
-                // The system will continue trading but with less historical context
             }
         }
     }
@@ -288,6 +479,139 @@ mod tests {
         assert_eq!(config.strategy_mode, default_config.strategy_mode);
     }
 
+    struct FixedCandleRepository(Vec<Candle>);
+
+    #[async_trait::async_trait]
+    impl CandleRepository for FixedCandleRepository {
+        async fn save(&self, _candle: &Candle) -> anyhow::Result<()> {
+            Ok(())
+        }
+        async fn get_range(
+            &self,
+            _symbol: &str,
+            _start: i64,
+            _end: i64,
+        ) -> anyhow::Result<Vec<Candle>> {
+            Ok(self.0.clone())
+        }
+        async fn get_latest_timestamp(&self, _symbol: &str) -> anyhow::Result<Option<i64>> {
+            Ok(self.0.last().map(|c| c.timestamp))
+        }
+        async fn count_candles(
+            &self,
+            _symbol: &str,
+            _start: i64,
+            _end: i64,
+        ) -> anyhow::Result<usize> {
+            Ok(self.0.len())
+        }
+        async fn prune(&self, _days_retention: i64) -> anyhow::Result<u64> {
+            Ok(0)
+        }
+    }
+
+    #[tokio::test]
+    async fn test_warmup_falls_back_to_candle_repository() {
+        let end = chrono::Utc::now();
+        let start = end - chrono::Duration::days(1);
+        let candle = Candle {
+            symbol: "AAPL".to_string(),
+            open: Decimal::ONE_HUNDRED,
+            high: Decimal::ONE_HUNDRED,
+            low: Decimal::ONE_HUNDRED,
+            close: Decimal::ONE_HUNDRED,
+            volume: Decimal::ONE_HUNDRED,
+            timestamp: end.timestamp_millis(),
+        };
+
+        // The mock broker has no history and nothing else is configured
+        let service = WarmupService::new(Arc::new(MockMarketDataService::new()), None, None);
+        assert!(
+            service
+                .fetch_warmup_bars("AAPL", start, end)
+                .await
+                .is_none()
+        );
+
+        let service =
+            service.with_candle_repository(Some(Arc::new(FixedCandleRepository(vec![candle]))));
+        let (source, bars) = service.fetch_warmup_bars("AAPL", start, end).await.unwrap();
+        assert_eq!(source, WarmupSource::CandleRepository);
+        assert_eq!(bars.len(), 1);
+    }
+
+    /// Records how many history requests are in flight at once
+    #[derive(Default)]
+    struct ConcurrencyTrackingMarketData {
+        in_flight: std::sync::atomic::AtomicUsize,
+        peak: std::sync::atomic::AtomicUsize,
+    }
+
+    #[async_trait::async_trait]
+    impl MarketDataService for ConcurrencyTrackingMarketData {
+        async fn subscribe(
+            &self,
+            _symbols: Vec<String>,
+        ) -> anyhow::Result<tokio::sync::mpsc::Receiver<crate::domain::trading::types::MarketEvent>>
+        {
+            anyhow::bail!("not used")
+        }
+        async fn get_top_movers(&self) -> anyhow::Result<Vec<String>> {
+            Ok(vec![])
+        }
+        async fn get_tradable_assets(&self) -> anyhow::Result<Vec<String>> {
+            Ok(vec![])
+        }
+        async fn get_prices(
+            &self,
+            _symbols: Vec<String>,
+        ) -> anyhow::Result<HashMap<String, Decimal>> {
+            Ok(HashMap::new())
+        }
+        async fn get_historical_bars(
+            &self,
+            symbol: &str,
+            _start: chrono::DateTime<chrono::Utc>,
+            end: chrono::DateTime<chrono::Utc>,
+            _timeframe: &str,
+        ) -> anyhow::Result<Vec<Candle>> {
+            use std::sync::atomic::Ordering;
+            let now = self.in_flight.fetch_add(1, Ordering::SeqCst) + 1;
+            self.peak.fetch_max(now, Ordering::SeqCst);
+            tokio::time::sleep(std::time::Duration::from_millis(10)).await;
+            self.in_flight.fetch_sub(1, Ordering::SeqCst);
+            Ok(vec![Candle {
+                symbol: symbol.to_string(),
+                open: Decimal::ONE,
+                high: Decimal::ONE,
+                low: Decimal::ONE,
+                close: Decimal::ONE,
+                volume: Decimal::ONE,
+                timestamp: end.timestamp_millis(),
+            }])
+        }
+    }
+
+    #[tokio::test]
+    async fn test_batch_fetch_bounds_concurrency() {
+        let market_data = Arc::new(ConcurrencyTrackingMarketData::default());
+        let service = WarmupService::new(market_data.clone(), None, None).with_max_concurrency(3);
+        let end = chrono::Utc::now();
+        let start = end - chrono::Duration::days(1);
+        let requests = (0..10).map(|i| (format!("SYM{}", i), start, end)).collect();
+
+        let fetched = service.fetch_warmup_bars_batch(requests).await;
+
+        assert_eq!(fetched.len(), 10);
+        assert!(
+            fetched
+                .values()
+                .all(|bars| matches!(bars, Some((WarmupSource::Primary, b)) if b.len() == 1))
+        );
+        let peak = market_data.peak.load(std::sync::atomic::Ordering::SeqCst);
+        assert!(peak > 1 && peak <= 3, "peak concurrency {}", peak);
+    }
+
     #[tokio::test]
     async fn test_warmup_context_success() {
         let market_service = Arc::new(MockMarketDataService::new());
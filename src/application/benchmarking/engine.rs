@@ -6,7 +6,6 @@ use crate::domain::performance::metrics::PerformanceMetrics;
 use crate::domain::risk::risk_appetite::RiskAppetite;
 use crate::domain::trading::fee_model::ConstantFeeModel;
 use crate::domain::trading::portfolio::Portfolio;
-use crate::domain::trading::types::{Order, OrderSide, Trade};
 use crate::infrastructure::alpaca::AlpacaMarketDataService;
 use crate::infrastructure::mock::MockExecutionService;
 use chrono::{DateTime, Duration, Utc};
@@ -131,7 +130,11 @@ impl BenchmarkEngine {
         app_config.strategy_mode = strategy;
         let config: AnalystConfig = app_config.into();
 
-        let runner = ParallelBenchmarkRunner::new(self.market_service.clone(), config);
+        let runner = ParallelBenchmarkRunner::new(
+            self.market_service.clone(),
+            config,
+            self.base_config.initial_cash,
+        );
         runner.run_parallel(symbols, start, end).await
     }
 
@@ -224,7 +227,7 @@ impl BenchmarkEngine {
                 .execute_simulation(symbol, test_start, test_end, config.clone())
                 .await?;
 
-            let trades = orders_to_trades(&result.trades);
+            let trades = result.round_trip_trades();
             let metrics = PerformanceMetrics::calculate_time_series_metrics(
                 &trades,
                 &result.daily_closes,
@@ -272,7 +275,9 @@ impl BenchmarkEngine {
         config: AnalystConfig,
     ) -> anyhow::Result<BacktestResult> {
         let mut portfolio = Portfolio::new();
-        portfolio.cash = Decimal::new(100000, 0);
+        portfolio.lot_tracking = self.base_config.lot_tracking_enabled;
+        portfolio.allow_shorts = config.allow_shorts;
+        portfolio.reset(self.base_config.initial_cash);
         let portfolio_lock = Arc::new(RwLock::new(portfolio));
 
         // Use standard benchmark costs
@@ -306,43 +311,13 @@ impl BenchmarkEngine {
             ))
         };
 
-        let simulator = Simulator::new(self.market_service.clone(), execution_service, config);
+        let simulator = Simulator::new(self.market_service.clone(), execution_service, config)
+            .with_order_throttle(
+                self.base_config
+                    .backtest_order_throttle
+                    .then_some(self.base_config.max_orders_per_minute),
+            );
 
         simulator.run(symbol, start, end).await
     }
 }
-
-/// Convert backtest orders (Buy/Sell pairs) into Trade records for metrics.
-fn orders_to_trades(orders: &[Order]) -> Vec<Trade> {
-    let mut trades = Vec::new();
-    let mut open_position: Option<&Order> = None;
-    for order in orders {
-        match order.side {
-            OrderSide::Buy => open_position = Some(order),
-            OrderSide::Sell => {
-                if let Some(buy) = open_position {
-                    let pnl = (order.price - buy.price) * order.quantity;
-                    trades.push(Trade {
-                        id: order.id.clone(),
-                        symbol: order.symbol.clone(),
-                        side: OrderSide::Buy,
-                        entry_price: buy.price,
-                        exit_price: Some(order.price),
-                        quantity: order.quantity,
-                        pnl,
-                        entry_timestamp: buy.timestamp,
-                        exit_timestamp: Some(order.timestamp),
-                        strategy_used: None,
-                        regime_detected: None,
-                        entry_reason: None,
-                        exit_reason: None,
-                        slippage: None,
-                        fees: rust_decimal::Decimal::ZERO,
-                    });
-                    open_position = None;
-                }
-            }
-        }
-    }
-    trades
-}
@@ -1,4 +1,5 @@
 use crate::application::optimization::simulator::BacktestResult;
+use crate::domain::performance::metrics::PerformanceMetrics;
 use chrono::{DateTime, Utc};
 use rust_decimal::Decimal;
 use rust_decimal::prelude::ToPrimitive;
@@ -38,6 +39,63 @@ pub struct BenchmarkSummary {
     pub worst_performer: String,
 }
 
+/// Out-of-sample results of one risk profile's stored parameters, across the benchmarked symbols
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ProfileComparisonRow {
+    pub profile: String,
+    /// Risk appetite score the parameters were run with
+    pub risk_score: u8,
+    /// Where the stored parameters came from (symbol and optimization date)
+    pub source: String,
+    pub symbols: usize,
+    /// Mean annualized Sharpe across symbols
+    pub sharpe: f64,
+    /// Mean total return across symbols (%)
+    pub return_pct: f64,
+    /// Worst max drawdown across symbols (%)
+    pub max_drawdown_pct: f64,
+    pub trades: usize,
+}
+
+impl ProfileComparisonRow {
+    /// Aggregate one backtest per symbol into a row
+    pub fn from_backtests(
+        profile: String,
+        risk_score: u8,
+        source: String,
+        backtests: &[BacktestResult],
+    ) -> Self {
+        let mut sharpe = 0.0;
+        let mut return_pct = 0.0;
+        let mut max_drawdown_pct: f64 = 0.0;
+        let mut trades = 0;
+        for backtest in backtests {
+            let round_trips = backtest.round_trip_trades();
+            let metrics = PerformanceMetrics::calculate_time_series_metrics(
+                &round_trips,
+                &backtest.daily_closes,
+                backtest.initial_equity,
+            );
+            sharpe += metrics.sharpe_ratio;
+            return_pct += backtest.total_return_pct.to_f64().unwrap_or(0.0);
+            max_drawdown_pct =
+                max_drawdown_pct.max(metrics.max_drawdown_pct.to_f64().unwrap_or(0.0));
+            trades += round_trips.len();
+        }
+        let n = backtests.len().max(1) as f64;
+        Self {
+            profile,
+            risk_score,
+            source,
+            symbols: backtests.len(),
+            sharpe: sharpe / n,
+            return_pct: return_pct / n,
+            max_drawdown_pct,
+            trades,
+        }
+    }
+}
+
 pub struct BenchmarkReporter {
     output_dir: PathBuf,
 }
@@ -174,6 +232,40 @@ impl BenchmarkReporter {
         }
     }
 
+    /// Table of out-of-sample Sharpe/return/drawdown per risk profile
+    pub fn print_profile_comparison(&self, rows: &[ProfileComparisonRow], window: &str) {
+        if rows.is_empty() {
+            println!("⚠️ No stored optimal parameters to compare.");
+            return;
+        }
+
+        println!("\n{}", "=".repeat(110));
+        println!("📊 RISK PROFILE COMPARISON (holdout {})", window);
+        println!("{}", "=".repeat(110));
+        println!(
+            "{:<13} | {:>5} | {:>7} | {:>9} | {:>8} | {:>6} | {:>7} | {:<30}",
+            "Profile", "Risk", "Sharpe", "Return%", "MaxDD%", "Trades", "Symbols", "Params from"
+        );
+        println!("{}", "-".repeat(110));
+        for row in rows {
+            println!(
+                "{:<13} | {:>5} | {:>7.2} | {:>8.2}% | {:>7.2}% | {:>6} | {:>7} | {:<30}",
+                row.profile,
+                row.risk_score,
+                row.sharpe,
+                row.return_pct,
+                row.max_drawdown_pct,
+                row.trades,
+                row.symbols,
+                row.source
+            );
+        }
+        println!("{}", "=".repeat(110));
+        println!(
+            "  Pick the profile whose realized drawdown and return fit your appetite. Results are only\n  out-of-sample if the holdout window is after the data the parameters were optimized on.\n"
+        );
+    }
+
     fn calculate_summary(&self, results: &[BenchmarkResultEntry]) -> BenchmarkSummary {
         if results.is_empty() {
             return BenchmarkSummary {
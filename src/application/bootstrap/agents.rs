@@ -1,5 +1,6 @@
 use anyhow::Result;
 use chrono::Timelike;
+use rust_decimal::prelude::ToPrimitive;
 use std::sync::Arc;
 use tokio::sync::{RwLock, broadcast, mpsc};
 use tracing::{error, info, warn};
@@ -13,26 +14,41 @@ use crate::application::agents::{
 };
 use crate::application::bootstrap::persistence::PersistenceHandle;
 use crate::application::bootstrap::services::ServicesHandle;
+use crate::application::market_data::candle_write_buffer::CandleWriteBuffer;
+use crate::application::monitoring::channel_monitor::{
+    ChannelMonitor, DEFAULT_CHANNEL_SAMPLE_INTERVAL,
+};
 use crate::application::monitoring::connection_health_service::ConnectionHealthService;
 use crate::application::monitoring::correlation_service::CorrelationService;
-use crate::application::optimization::win_rate_provider::HistoricalWinRateProvider;
+use crate::application::optimization::win_rate_provider::{
+    BayesianWinRateProvider, HistoricalWinRateProvider, StaticWinRateProvider, WinRateProvider,
+};
 use crate::application::risk_management::{
-    commands::RiskCommand, order_throttler::OrderThrottler, risk_manager::RiskManager,
+    commands::RiskCommand, order_throttler::OrderThrottler, rebalancer::Rebalancer,
+    risk_manager::RiskManager,
 };
 use crate::application::strategies::*;
-use crate::config::{Config, Mode};
+use crate::config::{Config, Mode, NewsProviderKind};
 use crate::domain::listener::NewsEvent;
 use crate::domain::listener::{ListenerAction, ListenerConfig};
+use crate::domain::optimization::win_rate_source::WinRateSource;
+use crate::domain::ports::NewsDataService;
+use crate::domain::repositories::TradeRepository;
 use crate::domain::sentiment::Sentiment;
 use crate::domain::sentiment::SentimentProvider;
 use crate::domain::trading::portfolio::Portfolio;
 use crate::domain::trading::types::{Candle, TradeProposal};
 use crate::infrastructure::alpaca::AlpacaSectorProvider;
 use crate::infrastructure::binance::BinanceSectorProvider;
+use crate::infrastructure::core::event_bus::EventBus;
+use crate::infrastructure::earnings::FinnhubEarningsCalendar;
+use crate::infrastructure::factory::ServiceFactory;
+use crate::infrastructure::news::json_feed::JsonFeedNewsService;
 use crate::infrastructure::news::mock_news::MockNewsService;
 use crate::infrastructure::news::rss::RssNewsService;
 use crate::infrastructure::oanda::OandaSectorProvider;
-use crate::infrastructure::observability::Metrics;
+use crate::infrastructure::observability::{DecisionLogSink, Metrics};
+use crate::infrastructure::optimal_parameters_persistence::OptimalParametersPersistence;
 use crate::infrastructure::sentiment::alternative_me::AlternativeMeSentimentProvider;
 
 // We need a struct to return all the control channels
@@ -44,6 +60,12 @@ pub struct AgentsHandle {
     pub candle_rx: broadcast::Receiver<Candle>,
     pub sentiment_rx: broadcast::Receiver<Sentiment>,
     pub news_rx: broadcast::Receiver<NewsEvent>,
+    /// Batched candle persistence, flushed by the shutdown sequence
+    pub candle_write_buffer: Arc<CandleWriteBuffer>,
+    /// Default analyst config the Analyst is running with
+    pub analyst_config: Arc<RwLock<AnalystConfig>>,
+    /// Risk config the RiskManager is running with
+    pub risk_config: Arc<RwLock<crate::domain::risk::risk_config::RiskConfig>>,
 }
 
 pub struct AgentsBootstrap;
@@ -61,18 +83,44 @@ impl AgentsBootstrap {
         info!("Initializing Agents...");
 
         // Channel creation
-        let (market_tx, market_rx) = mpsc::channel(500);
-        let (proposal_tx, proposal_rx) = mpsc::channel(100);
-        let (order_tx, order_rx) = mpsc::channel(50);
-        let (throttled_order_tx, throttled_order_rx) = mpsc::channel(50);
+        let capacities = &config.channel_capacities;
+        let (market_tx, market_rx) = mpsc::channel(capacities.market_channel_capacity);
+        let (proposal_tx, proposal_rx) = mpsc::channel(capacities.proposal_channel_capacity);
+        let (order_tx, order_rx) = mpsc::channel(capacities.order_channel_capacity);
+        let (throttled_order_tx, throttled_order_rx) =
+            mpsc::channel(capacities.order_channel_capacity);
         let (sentinel_cmd_tx, sentinel_cmd_rx) = mpsc::channel(10);
         let (risk_cmd_tx, risk_cmd_rx) = mpsc::channel(10);
         let (analyst_cmd_tx, analyst_cmd_rx) = mpsc::channel(10);
 
         // Broadcast channels
-        let (candle_tx, candle_rx) = broadcast::channel(100);
-        let (sentiment_broadcast_tx, sentiment_broadcast_rx) = broadcast::channel(8);
-        let (news_broadcast_tx, news_broadcast_rx) = broadcast::channel(20);
+        let (candle_tx, candle_rx) = broadcast::channel(capacities.candle_channel_capacity);
+        let (sentiment_broadcast_tx, sentiment_broadcast_rx) =
+            broadcast::channel(capacities.sentiment_channel_capacity);
+        let (news_broadcast_tx, news_broadcast_rx) =
+            broadcast::channel(capacities.news_channel_capacity);
+
+        // Queue depth of the event bus (lagged drops are counted where receivers poll)
+        let channel_monitor = ChannelMonitor::new(metrics.clone())
+            .watch_mpsc("market_events", &market_tx)
+            .watch_mpsc("proposals", &proposal_tx)
+            .watch_mpsc("orders", &order_tx)
+            .watch_mpsc("throttled_orders", &throttled_order_tx)
+            .watch_broadcast("candles", &candle_tx)
+            .watch_broadcast("sentiment", &sentiment_broadcast_tx)
+            .watch_broadcast("news", &news_broadcast_tx);
+
+        // Decision audit log (signals -> proposals -> orders -> fills)
+        let event_bus = EventBus::new();
+        if let Some(path) = &config.decision_log_path {
+            match DecisionLogSink::open(path) {
+                Ok(sink) => {
+                    info!("Decision log enabled: {}", path);
+                    event_bus.subscribe(Arc::new(sink)).await;
+                }
+                Err(e) => warn!("Decision log disabled, cannot open {}: {}", path, e),
+            }
+        }
 
         // 1. Sentinel
         let mut sentinel = Sentinel::new(
@@ -82,30 +130,43 @@ impl AgentsBootstrap {
             Some(sentinel_cmd_rx),
             connection_health_service.clone(),
             agent_registry.clone(),
-        );
+        )
+        .with_portfolio(portfolio.clone());
 
         // 2. Market Scanner
         let scanner_interval =
             std::time::Duration::from_secs(config.dynamic_scan_interval_minutes * 60);
-        let scanner = MarketScanner::new(
+        let mut scanner = MarketScanner::new(
             services.market_service.clone(),
             services.execution_service.clone(),
             sentinel_cmd_tx.clone(),
             scanner_interval,
             config.dynamic_symbol_mode,
             agent_registry.clone(),
-        );
+        )
+        .with_max_symbols(config.scanner_max_symbols)
+        .with_price_range(config.scanner_min_price, config.scanner_max_price);
+        if config.scanner_sector_rotation {
+            scanner = scanner.with_sector_rotation(config.sector_map.clone());
+        }
 
         // 3. Analyst
-        let analyst_config = create_analyst_config(config);
+        let mut analyst_config = create_analyst_config(config);
+        if config.load_optimal_parameters || config.adaptive_optimization_enabled {
+            apply_stored_optimal_parameters(config, &mut analyst_config);
+        }
         let strategy = create_strategy(config, &analyst_config);
+        let analyst_config_view = Arc::new(RwLock::new(analyst_config.clone()));
 
-        let win_rate_provider = Arc::new(HistoricalWinRateProvider::new(
-            persistence.order_repository.clone(),
-            0.50,
-            10,
-        ));
+        let win_rate_provider =
+            create_win_rate_provider(config, persistence.order_repository.clone());
 
+        let candle_write_buffer = Arc::new(CandleWriteBuffer::new(
+            persistence.candle_repository.clone(),
+            config.candle_persist_batch_size,
+            config.candle_persist_flush_ms,
+        ));
+        candle_write_buffer.clone().start_periodic_flush();
         let mut analyst = Analyst::new(
             market_rx,
             analyst_cmd_rx,
@@ -123,7 +184,19 @@ impl AgentsBootstrap {
                 connection_health_service: connection_health_service.clone(),
                 agent_registry: agent_registry.clone(),
             },
-        );
+        )
+        .with_metrics(metrics.clone())
+        .with_event_bus(event_bus.clone())
+        .with_warmup_fallback(ServiceFactory::create_warmup_fallback(config))
+        .with_warmup_required(config.require_warmup)
+        .with_warmup_concurrency(config.warmup_concurrency)
+        .with_candle_write_buffer(Some(candle_write_buffer.clone()))
+        .with_proposal_policy(config.proposal_channel_policy)
+        .with_portfolio_cache(
+            config.portfolio_staleness_ms.try_into().unwrap_or(5000),
+            config.portfolio_max_age_ms.try_into().unwrap_or(i64::MAX),
+        )
+        .with_config_view(analyst_config_view.clone());
 
         // 4. Risk Manager
         let sector_provider: Option<Arc<dyn crate::domain::ports::SectorProvider>> =
@@ -138,58 +211,16 @@ impl AgentsBootstrap {
                 Mode::Binance => Some(Arc::new(BinanceSectorProvider)),
             };
 
-        let base_risk = if config.asset_class == crate::config::AssetClass::Crypto {
-            crate::domain::risk::risk_config::RiskConfig::crypto_default()
-        } else {
-            crate::domain::risk::risk_config::RiskConfig::default()
-        };
+        let earnings_provider: Option<Arc<dyn crate::domain::ports::EarningsCalendarProvider>> =
+            match (&config.finnhub_api_key, config.earnings_blackout_days()) {
+                (Some(key), days) if days > 0 => {
+                    Some(Arc::new(FinnhubEarningsCalendar::new(key.clone())))
+                }
+                _ => None,
+            };
 
-        // When risk appetite is set, it drives all risk limits (prise de risque).
-        let risk_config = if let Some(ref ra) = config.risk_appetite {
-            crate::domain::risk::risk_config::RiskConfig {
-                max_position_size_pct: ra.calculate_max_position_size_pct(),
-                max_daily_loss_pct: ra.calculate_max_daily_loss_pct(),
-                max_drawdown_pct: ra.calculate_max_drawdown_pct(),
-                consecutive_loss_limit: ra.calculate_consecutive_loss_limit(),
-                valuation_interval_seconds: base_risk.valuation_interval_seconds,
-                max_sector_exposure_pct: config.max_sector_exposure_pct,
-                sector_provider: sector_provider.clone(),
-                pending_order_ttl_ms: config.pending_order_ttl_ms,
-                allow_pdt_risk: base_risk.allow_pdt_risk,
-                correlation_config: base_risk.correlation_config.clone(),
-                volatility_config: base_risk.volatility_config.clone(),
-            }
-        } else {
-            crate::domain::risk::risk_config::RiskConfig {
-                max_position_size_pct: if config.asset_class == crate::config::AssetClass::Crypto {
-                    base_risk.max_position_size_pct
-                } else {
-                    config.max_position_size_pct
-                },
-                max_daily_loss_pct: if config.asset_class == crate::config::AssetClass::Crypto {
-                    base_risk.max_daily_loss_pct
-                } else {
-                    config.max_daily_loss_pct
-                },
-                max_drawdown_pct: if config.asset_class == crate::config::AssetClass::Crypto {
-                    base_risk.max_drawdown_pct
-                } else {
-                    config.max_drawdown_pct
-                },
-                consecutive_loss_limit: if config.asset_class == crate::config::AssetClass::Crypto {
-                    base_risk.consecutive_loss_limit
-                } else {
-                    config.consecutive_loss_limit
-                },
-                valuation_interval_seconds: base_risk.valuation_interval_seconds,
-                max_sector_exposure_pct: config.max_sector_exposure_pct,
-                sector_provider,
-                pending_order_ttl_ms: config.pending_order_ttl_ms,
-                allow_pdt_risk: base_risk.allow_pdt_risk,
-                correlation_config: base_risk.correlation_config,
-                volatility_config: base_risk.volatility_config,
-            }
-        };
+        let risk_config = create_risk_config(config, sector_provider, earnings_provider);
+        let risk_config_view = Arc::new(RwLock::new(risk_config.clone()));
 
         let correlation_svc = Arc::new(CorrelationService::new(
             persistence.candle_repository.clone(),
@@ -216,7 +247,7 @@ impl AgentsBootstrap {
             order_tx,
             services.execution_service.clone(),
             services.market_service.clone(),
-            portfolio_state_manager,
+            portfolio_state_manager.clone(),
             config.non_pdt_mode,
             config.asset_class,
             risk_config,
@@ -228,7 +259,9 @@ impl AgentsBootstrap {
             connection_health_service.clone(),
             metrics.clone(),
             agent_registry.clone(),
-        )?;
+        )?
+        .with_event_bus(event_bus.clone())
+        .with_config_view(risk_config_view.clone());
 
         // 5. Order Throttler & Executor
         let mut order_throttler = OrderThrottler::new(
@@ -241,6 +274,9 @@ impl AgentsBootstrap {
         let retry_config = crate::application::risk_management::order_retry_strategy::RetryConfig {
             limit_timeout_ms: config.pending_order_ttl_ms.unwrap_or(5000) as u64,
             enable_retry: true,
+            submit_max_retries: config.order_submit_max_retries,
+            submit_backoff_ms: config.order_submit_backoff_ms,
+            submit_max_retry_ms: config.order_submit_max_retry_ms,
         };
 
         let mut executor = Executor::new(
@@ -250,9 +286,11 @@ impl AgentsBootstrap {
             Some(persistence.order_repository.clone()),
             retry_config,
             connection_health_service.clone(),
-            config.create_fee_model(),
+            // Shared with the analyst so cost estimates see the same volume tier
+            analyst_config.fee_model.clone(),
             agent_registry.clone(),
-        );
+        )
+        .with_event_bus(event_bus);
 
         // SPAWN TASKS
         tokio::spawn(async move { sentinel.run().await });
@@ -261,9 +299,13 @@ impl AgentsBootstrap {
         tokio::spawn(async move { risk_manager.run().await });
         tokio::spawn(async move { order_throttler.run().await });
         tokio::spawn(async move { executor.run().await });
+        tokio::spawn(async move { channel_monitor.run(DEFAULT_CHANNEL_SAMPLE_INTERVAL).await });
 
         // Listener Agent
         spawn_listener(
+            create_news_service(config),
+            config.news_sentiment_threshold,
+            config.news_provider_sentiment_threshold,
             analyst_cmd_tx.clone(),
             news_broadcast_tx.clone(),
             agent_registry.clone(),
@@ -277,8 +319,24 @@ impl AgentsBootstrap {
             metrics.clone(),
         );
 
+        // Broker position reconciliation
+        spawn_position_reconciler(
+            config,
+            portfolio_state_manager,
+            portfolio.clone(),
+            metrics.clone(),
+        );
+
+        // Position trims back to the size limit
+        spawn_rebalancer(config, risk_cmd_tx.clone());
+
         // Adaptive Optimization
-        spawn_adaptive_optimization(config, services.adaptive_optimization_service.clone());
+        spawn_adaptive_optimization(
+            config,
+            services.adaptive_optimization_service.clone(),
+            analyst_cmd_tx.clone(),
+            analyst_config_view.clone(),
+        );
 
         Ok(AgentsHandle {
             sentinel_cmd_tx,
@@ -288,13 +346,52 @@ impl AgentsBootstrap {
             candle_rx,
             sentiment_rx: sentiment_broadcast_rx,
             news_rx: news_broadcast_rx,
+            candle_write_buffer,
+            analyst_config: analyst_config_view,
+            risk_config: risk_config_view,
         })
     }
 }
 
 // Helper functions to keep init clean
 
-fn create_analyst_config(config: &Config) -> AnalystConfig {
+/// Start from the best parameters `optimize` stored for the configured asset class and risk score
+fn apply_stored_optimal_parameters(config: &Config, analyst_config: &mut AnalystConfig) {
+    let Some(appetite) = config.risk_appetite else {
+        info!("No RISK_APPETITE_SCORE set, not loading stored optimal parameters");
+        return;
+    };
+    let score = appetite.score();
+    let asset_type = config.asset_class.into();
+    let stored = OptimalParametersPersistence::new()
+        .and_then(|persistence| persistence.get_best(asset_type, appetite.profile(), Some(score)));
+    match stored {
+        Ok(Some(params)) => {
+            analyst_config.apply_optimal_parameters(&params);
+            info!(
+                "Applied stored optimal parameters for {} risk {} ({:?}, score {:?}, optimized on {} at {}): SMA {}/{}, RSI {}, ATR stop {}x, Sharpe {}",
+                asset_type,
+                score,
+                params.risk_profile,
+                params.risk_score,
+                params.symbol_used,
+                params.optimization_date.format("%Y-%m-%d"),
+                params.fast_sma_period,
+                params.slow_sma_period,
+                params.rsi_threshold,
+                params.trailing_stop_atr_multiplier,
+                params.sharpe_ratio
+            );
+        }
+        Ok(None) => info!(
+            "No stored optimal parameters for {} risk {}, using configured values",
+            asset_type, score
+        ),
+        Err(e) => warn!("Failed to load stored optimal parameters: {}", e),
+    }
+}
+
+pub(crate) fn create_analyst_config(config: &Config) -> AnalystConfig {
     use rust_decimal_macros::dec;
 
     let mut analyst_config = AnalystConfig {
@@ -324,6 +421,8 @@ fn create_analyst_config(config: &Config) -> AnalystConfig {
         ema_fast_period: config.ema_fast_period,
         ema_slow_period: config.ema_slow_period,
         take_profit_pct: config.take_profit_pct,
+        take_profit_mode: config.take_profit_mode,
+        take_profit_atr_multiplier: config.take_profit_atr_multiplier,
         min_hold_time_minutes: config.min_hold_time_minutes,
         signal_confirmation_bars: config.signal_confirmation_bars,
         spread_bps: config.spread_bps,
@@ -354,6 +453,33 @@ fn create_analyst_config(config: &Config) -> AnalystConfig {
         orderflow_volume_profile_lookback: 100,
         ensemble_weights: None,
         ensemble_voting_threshold: config.ensemble_voting_threshold,
+        trading_windows: config.trading_windows(),
+        news_dedup_window_seconds: config.news_dedup_window_seconds,
+        news_trade_size_multiplier: config.news_trade_size_multiplier,
+        news_trade_max_value_usd: config.news_trade_max_value_usd,
+        news_reentry_cooldown_seconds: config.news_reentry_cooldown_seconds,
+        regime_detection_method: config.regime_detection_method,
+        hurst_lookback: config.hurst_lookback,
+        relative_stop_benchmark: config.relative_stop_benchmark(),
+        relative_stop_pct: config.relative_stop_pct,
+        pyramid_max_adds: config.pyramid_max_adds,
+        pyramid_trigger_pct: config.pyramid_trigger_pct,
+        exits_bypass_cost_filters: config.exits_bypass_cost_filters,
+        allow_shorts: config.allow_shorts,
+        require_htf_confirmation: config.require_htf_confirmation,
+        trend_timeframe: config.trend_timeframe,
+        max_trades_per_symbol_per_day: config.max_trades_per_symbol_per_day,
+        open_blackout_minutes: config.open_blackout_minutes(),
+        trading_day_boundary: config.trading_day_boundary(),
+        post_stop_cooldown_seconds: config.post_stop_cooldown_seconds,
+        trailing_stop_vol_scale_min: config.trailing_stop_vol_scale_min,
+        trailing_stop_vol_scale_max: config.trailing_stop_vol_scale_max,
+        candle_history_len: config.candle_history_len,
+        candle_gap_policy: config.candle_gap_policy,
+        candle_gap_max_bars: config.candle_gap_max_bars,
+        cold_start_reward_risk_ratio: config.cold_start_reward_risk_ratio,
+        cold_start_min_trades: config.cold_start_min_trades,
+        confirm_strategy: config.confirm_strategy,
     };
 
     // Apply risk appetite settings if present to override base values
@@ -364,6 +490,91 @@ fn create_analyst_config(config: &Config) -> AnalystConfig {
     analyst_config
 }
 
+/// Builds the risk limits from `config`. When a risk appetite is set it drives
+/// all limits; crypto falls back to the crypto defaults otherwise.
+pub(crate) fn create_risk_config(
+    config: &Config,
+    sector_provider: Option<Arc<dyn crate::domain::ports::SectorProvider>>,
+    earnings_provider: Option<Arc<dyn crate::domain::ports::EarningsCalendarProvider>>,
+) -> crate::domain::risk::risk_config::RiskConfig {
+    let base_risk = if config.asset_class == crate::config::AssetClass::Crypto {
+        crate::domain::risk::risk_config::RiskConfig::crypto_default()
+    } else {
+        crate::domain::risk::risk_config::RiskConfig::default()
+    };
+
+    // When risk appetite is set, it drives all risk limits (prise de risque).
+    if let Some(ref ra) = config.risk_appetite {
+        crate::domain::risk::risk_config::RiskConfig {
+            max_position_size_pct: ra.calculate_max_position_size_pct(),
+            max_daily_loss_pct: ra.calculate_max_daily_loss_pct(),
+            max_drawdown_pct: ra.calculate_max_drawdown_pct(),
+            consecutive_loss_limit: ra.calculate_consecutive_loss_limit(),
+            valuation_interval_seconds: base_risk.valuation_interval_seconds,
+            max_sector_exposure_pct: config.max_sector_exposure_pct,
+            max_gross_exposure_pct: config.max_gross_exposure_pct,
+            sector_provider,
+            pending_order_ttl_ms: config.pending_order_ttl_ms,
+            allow_pdt_risk: base_risk.allow_pdt_risk,
+            correlation_config: base_risk.correlation_config.clone(),
+            volatility_config: base_risk.volatility_config.clone(),
+            daily_reset: config.daily_reset,
+            allow_average_down: config.allow_average_down,
+            max_average_down_loss_pct: config.max_average_down_loss_pct,
+            min_order_notional: config.min_order_notional,
+            allow_min_notional_bump: config.allow_min_notional_bump,
+            allow_shorts: config.allow_shorts,
+            leverage: config.leverage,
+            max_open_orders: config.max_open_orders,
+            earnings_provider,
+            earnings_blackout_days: config.earnings_blackout_days(),
+            earnings_flatten: config.earnings_flatten,
+        }
+    } else {
+        crate::domain::risk::risk_config::RiskConfig {
+            max_position_size_pct: if config.asset_class == crate::config::AssetClass::Crypto {
+                base_risk.max_position_size_pct
+            } else {
+                config.max_position_size_pct
+            },
+            max_daily_loss_pct: if config.asset_class == crate::config::AssetClass::Crypto {
+                base_risk.max_daily_loss_pct
+            } else {
+                config.max_daily_loss_pct
+            },
+            max_drawdown_pct: if config.asset_class == crate::config::AssetClass::Crypto {
+                base_risk.max_drawdown_pct
+            } else {
+                config.max_drawdown_pct
+            },
+            consecutive_loss_limit: if config.asset_class == crate::config::AssetClass::Crypto {
+                base_risk.consecutive_loss_limit
+            } else {
+                config.consecutive_loss_limit
+            },
+            valuation_interval_seconds: base_risk.valuation_interval_seconds,
+            max_sector_exposure_pct: config.max_sector_exposure_pct,
+            max_gross_exposure_pct: config.max_gross_exposure_pct,
+            sector_provider,
+            pending_order_ttl_ms: config.pending_order_ttl_ms,
+            allow_pdt_risk: base_risk.allow_pdt_risk,
+            correlation_config: base_risk.correlation_config,
+            volatility_config: base_risk.volatility_config,
+            daily_reset: config.daily_reset,
+            allow_average_down: config.allow_average_down,
+            max_average_down_loss_pct: config.max_average_down_loss_pct,
+            min_order_notional: config.min_order_notional,
+            allow_min_notional_bump: config.allow_min_notional_bump,
+            allow_shorts: config.allow_shorts,
+            leverage: config.leverage,
+            max_open_orders: config.max_open_orders,
+            earnings_provider,
+            earnings_blackout_days: config.earnings_blackout_days(),
+            earnings_flatten: config.earnings_flatten,
+        }
+    }
+}
+
 fn create_strategy(config: &Config, analyst_config: &AnalystConfig) -> Arc<dyn TradingStrategy> {
     match config.strategy_mode {
         crate::domain::market::strategy_config::StrategyMode::Standard => {
@@ -471,7 +682,83 @@ fn create_strategy(config: &Config, analyst_config: &AnalystConfig) -> Arc<dyn T
     }
 }
 
+/// Build the win rate backend selected by `WIN_RATE_PROVIDER`.
+fn create_win_rate_provider(
+    config: &Config,
+    order_repository: Arc<dyn TradeRepository>,
+) -> Arc<dyn WinRateProvider> {
+    let empirical = || {
+        HistoricalWinRateProvider::new(
+            order_repository.clone(),
+            config.win_rate_default,
+            config.win_rate_min_trades,
+        )
+        .with_lookback(config.expectancy_lookback)
+        .with_halflife(config.expectancy_halflife)
+    };
+
+    match config.win_rate_provider {
+        WinRateSource::Static => {
+            info!("Using static win rate {:.2}", config.win_rate_default);
+            Arc::new(StaticWinRateProvider::new(config.win_rate_default))
+        }
+        WinRateSource::Empirical => {
+            info!(
+                "Using empirical win rate (default {:.2} below {} trades)",
+                config.win_rate_default, config.win_rate_min_trades
+            );
+            Arc::new(empirical())
+        }
+        WinRateSource::Blended => {
+            info!(
+                "Using blended win rate (prior {:.2} weighted as {} trades)",
+                config.win_rate_default, config.win_rate_prior_weight
+            );
+            Arc::new(empirical().with_prior_weight(config.win_rate_prior_weight))
+        }
+        WinRateSource::Bayesian => {
+            info!(
+                "Using bayesian win rate (prior Beta({}, {}))",
+                config.win_rate_prior_alpha, config.win_rate_prior_beta
+            );
+            Arc::new(
+                BayesianWinRateProvider::new(
+                    order_repository,
+                    config.win_rate_prior_alpha,
+                    config.win_rate_prior_beta,
+                )
+                .with_lookback(config.expectancy_lookback)
+                .with_halflife(config.expectancy_halflife),
+            )
+        }
+    }
+}
+
+/// Build the news provider selected by `NEWS_PROVIDER`.
+fn create_news_service(config: &Config) -> Arc<dyn NewsDataService> {
+    let url = config.news_feed_url.as_deref().unwrap_or_default();
+    let poll_interval = config.news_poll_interval_seconds;
+
+    match config.news_provider {
+        NewsProviderKind::Rss => {
+            info!("Using RSS News Service with URL: {}", url);
+            Arc::new(RssNewsService::new(url, poll_interval))
+        }
+        NewsProviderKind::JsonFeed => {
+            info!("Using JSON Feed News Service with URL: {}", url);
+            Arc::new(JsonFeedNewsService::new(url, poll_interval))
+        }
+        NewsProviderKind::Mock => {
+            info!("Using Mock News Service (NEWS_PROVIDER=mock)");
+            Arc::new(MockNewsService::new())
+        }
+    }
+}
+
 fn spawn_listener(
+    news_service: Arc<dyn NewsDataService>,
+    headline_score_threshold: f64,
+    provider_score_threshold: f64,
     logger_analyst_tx: mpsc::Sender<AnalystCommand>,
     news_tx_for_listener: broadcast::Sender<NewsEvent>,
     agent_registry: Arc<crate::application::monitoring::agent_status::AgentStatusRegistry>,
@@ -486,9 +773,7 @@ fn spawn_listener(
                     id: "elon-doge".to_string(),
                     keywords: vec!["Elon Musk".to_string(), "Dogecoin".to_string()],
                     target_symbol: "DOGE/USD".to_string(),
-                    action: ListenerAction::NotifyAnalyst(
-                        crate::domain::listener::NewsSentiment::Bullish,
-                    ),
+                    action: ListenerAction::NotifyAnalystScored,
                     active: true,
                 },
                 crate::domain::listener::ListenerRule {
@@ -499,25 +784,14 @@ fn spawn_listener(
                         "Binance".to_string(),
                     ],
                     target_symbol: "BNB/USD".to_string(), // Assuming Binance Coin or broad market selloff
-                    action: ListenerAction::NotifyAnalyst(
-                        crate::domain::listener::NewsSentiment::Bearish,
-                    ),
+                    action: ListenerAction::NotifyAnalystScored,
                     active: true,
                 },
             ],
+            headline_score_threshold,
+            provider_score_threshold,
         };
 
-        let news_rss_url = std::env::var("NEWS_RSS_URL").ok();
-
-        let news_service: Arc<dyn crate::domain::ports::NewsDataService> =
-            if let Some(url) = news_rss_url {
-                info!("Using RSS News Service with URL: {}", url);
-                Arc::new(RssNewsService::new(&url, 60))
-            } else {
-                info!("Using Mock News Service (NEWS_RSS_URL not set)");
-                Arc::new(MockNewsService::new())
-            };
-
         let listener = ListenerAgent::with_news_broadcast(
             news_service,
             config,
@@ -584,10 +858,66 @@ fn spawn_sentiment_poller(
     });
 }
 
+fn spawn_position_reconciler(
+    config: &Config,
+    portfolio_state_manager: Arc<
+        crate::application::monitoring::portfolio_state_manager::PortfolioStateManager,
+    >,
+    portfolio: Arc<RwLock<Portfolio>>,
+    metrics: Metrics,
+) {
+    if config.position_reconcile_interval_secs == 0 {
+        return;
+    }
+    let interval_secs = config.position_reconcile_interval_secs;
+    let correct = config.position_reconcile_correct;
+
+    tokio::spawn(async move {
+        info!(
+            "Starting position reconciliation every {}s (correct: {})",
+            interval_secs, correct
+        );
+        let period = tokio::time::Duration::from_secs(interval_secs);
+        let mut interval = tokio::time::interval_at(tokio::time::Instant::now() + period, period);
+        loop {
+            interval.tick().await;
+            match portfolio_state_manager
+                .reconcile_positions(&portfolio, correct)
+                .await
+            {
+                Ok(report) => {
+                    metrics
+                        .position_divergence_usd
+                        .set(report.divergence_value.to_f64().unwrap_or(0.0));
+                }
+                Err(e) => warn!("Position reconciliation failed: {}", e),
+            }
+        }
+    });
+}
+
+fn spawn_rebalancer(config: &Config, risk_cmd_tx: mpsc::Sender<RiskCommand>) {
+    if config.rebalance_interval_minutes == 0 {
+        return;
+    }
+    info!(
+        "Starting rebalancer every {} min (band {})",
+        config.rebalance_interval_minutes, config.rebalance_band_pct
+    );
+    let rebalancer = Rebalancer::new(risk_cmd_tx, config.rebalance_band_pct);
+    let interval = std::time::Duration::from_secs(config.rebalance_interval_minutes * 60);
+    tokio::spawn(async move { rebalancer.run(interval).await });
+}
+
 fn spawn_adaptive_optimization(
     config: &Config,
     adaptive_service: Option<Arc<crate::application::optimization::adaptive_optimization_service::AdaptiveOptimizationService>>,
+    analyst_cmd_tx: mpsc::Sender<AnalystCommand>,
+    analyst_config: Arc<RwLock<AnalystConfig>>,
 ) {
+    if let Some(service) = &adaptive_service {
+        service.connect_analyst(analyst_cmd_tx, analyst_config);
+    }
     let symbols = config.symbols.clone();
     let eval_hour = config.adaptive_evaluation_hour;
 
@@ -9,7 +9,7 @@ use crate::infrastructure::persistence::database::Database;
 use crate::infrastructure::persistence::repositories::{
     SqliteCandleRepository, SqliteOptimizationHistoryRepository, SqliteOrderRepository,
     SqlitePerformanceSnapshotRepository, SqliteReoptimizationTriggerRepository,
-    SqliteRiskStateRepository, SqliteStrategyRepository,
+    SqliteRiskStateRepository, SqliteShadowTrialRepository, SqliteStrategyRepository,
 };
 
 pub struct PersistenceHandle {
@@ -22,6 +22,7 @@ pub struct PersistenceHandle {
     pub opt_history_repo: Arc<SqliteOptimizationHistoryRepository>,
     pub snapshot_repo: Arc<SqlitePerformanceSnapshotRepository>,
     pub trigger_repo: Arc<SqliteReoptimizationTriggerRepository>,
+    pub shadow_trial_repo: Arc<SqliteShadowTrialRepository>,
 }
 
 pub struct PersistenceBootstrap;
@@ -45,6 +46,7 @@ impl PersistenceBootstrap {
         let opt_history_repo = Arc::new(SqliteOptimizationHistoryRepository::new(db.pool.clone()));
         let snapshot_repo = Arc::new(SqlitePerformanceSnapshotRepository::new(db.pool.clone()));
         let trigger_repo = Arc::new(SqliteReoptimizationTriggerRepository::new(db.pool.clone()));
+        let shadow_trial_repo = Arc::new(SqliteShadowTrialRepository::new(db.pool.clone()));
 
         Ok(PersistenceHandle {
             db,
@@ -55,6 +57,7 @@ impl PersistenceBootstrap {
             opt_history_repo,
             snapshot_repo,
             trigger_repo,
+            shadow_trial_repo,
         })
     }
 }
@@ -11,7 +11,8 @@ use crate::application::monitoring::connection_health_service::ConnectionHealthS
 use crate::application::monitoring::performance_monitoring_service::PerformanceMonitoringService;
 use crate::application::optimization::{
     adaptive_optimization_service::AdaptiveOptimizationService,
-    optimizer::{GridSearchOptimizer, ParameterGrid},
+    optimizer::{GridSearchOptimizer, ObjectiveFunction, ParameterGrid, TradeCountPenalty},
+    parameter_guardrails::ParameterGuardrails,
 };
 use crate::domain::performance::performance_evaluator::{
     EvaluationThresholds, PerformanceEvaluator,
@@ -60,44 +61,65 @@ impl ServicesBootstrap {
                 portfolio.clone(),
                 persistence.order_repository.clone(),
                 config.regime_detection_window,
+                config.regime_detection_method,
             )))
         } else {
             None
         };
 
         let adaptive_optimization_service = if config.adaptive_optimization_enabled {
-            let initial_cash = rust_decimal::Decimal::ZERO; // Start empty, require explicit funding
+            let initial_cash = config.initial_cash;
             let execution_factory: Arc<dyn Fn() -> Arc<dyn ExecutionService> + Send + Sync> =
                 Arc::new(move || {
                     let portfolio = Arc::new(RwLock::new({
                         let mut p = Portfolio::new();
-                        p.cash = initial_cash;
+                        p.reset(initial_cash);
                         p
                     }));
                     Arc::new(MockExecutionService::new(portfolio))
                 });
 
-            let optimizer = Arc::new(GridSearchOptimizer::new(
-                market_service.clone(),
-                execution_factory,
-                ParameterGrid::default(), // Load from file in real world
-                config.strategy_mode,
-                config.min_profit_ratio, // Use config value
-            ));
+            let optimizer = Arc::new(
+                GridSearchOptimizer::new(
+                    market_service.clone(),
+                    execution_factory,
+                    ParameterGrid::default(), // Load from file in real world
+                    config.strategy_mode,
+                    config.min_profit_ratio, // Use config value
+                )
+                .with_objective(ObjectiveFunction::from_env()?)
+                .with_trade_penalty(TradeCountPenalty::from_env()?),
+            );
 
-            Some(Arc::new(AdaptiveOptimizationService::new(
-                optimizer,
-                persistence.opt_history_repo.clone(),
-                persistence.snapshot_repo.clone(),
-                persistence.trigger_repo.clone(),
-                persistence.strategy_repository.clone(),
-                persistence.candle_repository.clone(),
-                PerformanceEvaluator::new(EvaluationThresholds::default()),
-                config.regime_detection_window,
-                config.adx_threshold,
-                config.regime_volatility_threshold,
-                true,
-            )))
+            Some(Arc::new(
+                AdaptiveOptimizationService::new(
+                    optimizer,
+                    persistence.opt_history_repo.clone(),
+                    persistence.snapshot_repo.clone(),
+                    persistence.trigger_repo.clone(),
+                    persistence.shadow_trial_repo.clone(),
+                    persistence.strategy_repository.clone(),
+                    persistence.candle_repository.clone(),
+                    PerformanceEvaluator::new(EvaluationThresholds::default()),
+                    config.regime_detection_window,
+                    config.regime_detection_method,
+                    config.adx_threshold,
+                    config.regime_volatility_threshold,
+                    true,
+                )
+                .with_lookback_days(config.adaptive_lookback_days)
+                .with_timeframe(config.primary_timeframe)
+                .with_guardrails(ParameterGuardrails {
+                    max_period_step_pct: config.adaptive_max_period_step_pct,
+                    max_risk_step_pct: config.adaptive_max_risk_step_pct,
+                    rollback_sharpe_drop: config.adaptive_rollback_sharpe_drop,
+                    rollback_drawdown_rise: config.adaptive_rollback_drawdown_rise,
+                })
+                .with_shadow(
+                    config.adaptive_shadow_days,
+                    config.adaptive_shadow_min_improvement_pct,
+                ),
+            ))
         } else {
             None
         };
@@ -5,8 +5,11 @@ use crate::application::system::SystemHandle;
 use crate::domain::listener::NewsEvent;
 use crate::domain::sentiment::Sentiment;
 use crate::domain::trading::types::{Candle, TradeProposal};
+use crate::infrastructure::observability::Metrics;
 use anyhow::Result;
 use crossbeam_channel::Receiver;
+use tokio::sync::broadcast;
+use tracing::warn;
 
 /// Unified event type for the User Interface
 #[derive(Clone, Debug)]
@@ -40,17 +43,20 @@ impl SystemClient {
 
         // 2. Check Candles (High priority for charts)
         // Broadcast channels use try_recv for non-blocking
-        if let Ok(candle) = self.handle.candle_rx.try_recv() {
+        let metrics = &self.handle.metrics;
+        if let Some(candle) = try_recv_counted(&mut self.handle.candle_rx, "candles", metrics) {
             return Some(SystemEvent::Candle(candle));
         }
 
         // 3. Check Sentiment
-        if let Ok(sentiment) = self.handle.sentiment_rx.try_recv() {
+        if let Some(sentiment) =
+            try_recv_counted(&mut self.handle.sentiment_rx, "sentiment", metrics)
+        {
             return Some(SystemEvent::Sentiment(sentiment));
         }
 
         // 4. Check News
-        if let Ok(news) = self.handle.news_rx.try_recv() {
+        if let Some(news) = try_recv_counted(&mut self.handle.news_rx, "news", metrics) {
             return Some(SystemEvent::News(news));
         }
 
@@ -123,9 +129,46 @@ impl SystemClient {
         self.handle.risk_appetite
     }
 
+    /// Default analyst config the engine is running with
+    pub fn analyst_config(
+        &self,
+    ) -> std::sync::Arc<
+        tokio::sync::RwLock<crate::application::agents::analyst_config::AnalystConfig>,
+    > {
+        self.handle.analyst_config.clone()
+    }
+
+    /// Risk config the engine is running with
+    pub fn risk_config(
+        &self,
+    ) -> std::sync::Arc<tokio::sync::RwLock<crate::domain::risk::risk_config::RiskConfig>> {
+        self.handle.risk_config.clone()
+    }
+
     pub fn agent_registry(
         &self,
     ) -> std::sync::Arc<crate::application::monitoring::agent_status::AgentStatusRegistry> {
         self.handle.agent_registry.clone()
     }
 }
+
+/// Non-blocking receive that counts messages skipped by a lagging receiver
+fn try_recv_counted<T: Clone>(
+    rx: &mut broadcast::Receiver<T>,
+    channel: &str,
+    metrics: &Metrics,
+) -> Option<T> {
+    loop {
+        match rx.try_recv() {
+            Ok(value) => return Some(value),
+            Err(broadcast::error::TryRecvError::Lagged(missed)) => {
+                metrics.inc_channel_lagged(channel, missed);
+                warn!(
+                    "SystemClient: {} receiver lagged, missed {} messages",
+                    channel, missed
+                );
+            }
+            Err(_) => return None,
+        }
+    }
+}
@@ -1,3 +1,5 @@
+use crate::application::market_data::candle_write_buffer::CandleWriteBuffer;
+use crate::domain::market::strategy_config::CandleGapPolicy;
 use crate::domain::repositories::CandleRepository;
 use crate::domain::trading::types::Candle;
 use chrono::{DateTime, TimeZone, Timelike, Utc};
@@ -5,7 +7,7 @@ use rust_decimal::Decimal;
 use rust_decimal_macros::dec;
 use std::collections::HashMap;
 use std::sync::Arc;
-use tracing::{error, info, warn};
+use tracing::{debug, error, info, warn};
 
 /// Maximum allowed deviation from the current close price (as a ratio).
 /// Quotes deviating more than this from the last known price are rejected as outliers.
@@ -93,18 +95,156 @@ pub struct CandleAggregator {
     builders: HashMap<String, CandleBuilder>,
     /// Last confirmed close price per symbol (used for cross-candle outlier filtering)
     last_close: HashMap<String, Decimal>,
-    repository: Option<Arc<dyn CandleRepository>>,
+    /// Persistence for completed candles (None = not persisted)
+    write_buffer: Option<Arc<CandleWriteBuffer>>,
+    /// Fill for missing bars between consecutive candles of a symbol
+    gap_policy: CandleGapPolicy,
+    /// Longest gap filled, in bars (longer gaps are session breaks)
+    gap_max_bars: usize,
+    /// Last candle admitted by `sequence`, per symbol
+    last_candle: HashMap<String, Candle>,
+    /// Bar spacing per symbol: the smallest step seen between consecutive candles
+    bar_interval_ms: HashMap<String, i64>,
 }
 
 impl CandleAggregator {
+    /// Completed candles are written to `repository` one by one
     pub fn new(repository: Option<Arc<dyn CandleRepository>>) -> Self {
         Self {
             builders: HashMap::new(),
             last_close: HashMap::new(),
-            repository,
+            write_buffer: repository.map(|repo| Arc::new(CandleWriteBuffer::write_through(repo))),
+            gap_policy: CandleGapPolicy::None,
+            gap_max_bars: 0,
+            last_candle: HashMap::new(),
+            bar_interval_ms: HashMap::new(),
         }
     }
 
+    /// Fill gaps of up to `max_bars` missing bars according to `policy`
+    pub fn with_gap_policy(mut self, policy: CandleGapPolicy, max_bars: usize) -> Self {
+        self.set_gap_policy(policy, max_bars);
+        self
+    }
+
+    pub fn set_gap_policy(&mut self, policy: CandleGapPolicy, max_bars: usize) {
+        self.gap_policy = policy;
+        self.gap_max_bars = max_bars;
+    }
+
+    /// Persist completed candles through a shared, batching buffer instead
+    pub fn with_write_buffer(mut self, buffer: Arc<CandleWriteBuffer>) -> Self {
+        self.write_buffer = Some(buffer);
+        self
+    }
+
+    /// Write any candles still queued for persistence
+    pub async fn flush(&self) {
+        if let Some(buffer) = &self.write_buffer {
+            buffer.flush().await;
+        }
+    }
+
+    /// Drop the partial candle and last close kept for a symbol
+    pub fn remove_symbol(&mut self, symbol: &str) {
+        self.builders.remove(symbol);
+        self.last_close.remove(symbol);
+        self.last_candle.remove(symbol);
+        self.bar_interval_ms.remove(symbol);
+    }
+
+    /// Admit a completed candle into the series fed to the indicators. Returns the
+    /// synthetic bars filling the gap before `candle` (if any), followed by `candle` itself.
+    /// Applies to both aggregated quotes and streamed or replayed candles, so live trading
+    /// and backtests see the same series.
+    ///
+    /// Candles at or before the last one admitted for the symbol (replays, reconnect
+    /// backfills) are dropped so the indicators never count a bar twice or step back in time.
+    pub fn sequence(&mut self, candle: Candle) -> Vec<Candle> {
+        if let Some(prev) = self.last_candle.get(&candle.symbol)
+            && candle.timestamp <= prev.timestamp
+        {
+            warn!(
+                "CandleAggregator: {} {} candle dropped (ts {} <= last {})",
+                candle.symbol,
+                if candle.timestamp == prev.timestamp {
+                    "duplicate"
+                } else {
+                    "out-of-order"
+                },
+                candle.timestamp,
+                prev.timestamp
+            );
+            return Vec::new();
+        }
+
+        let mut bars = self.fill_gaps(&candle);
+        self.last_candle
+            .insert(candle.symbol.clone(), candle.clone());
+        bars.push(candle);
+        bars
+    }
+
+    /// Synthetic bars for the gap between the last admitted candle and `candle`.
+    ///
+    /// The bar spacing is learned per symbol from consecutive candles, so 5-minute
+    /// backtest bars are not padded with 1-minute fillers. Synthetic bars are not persisted.
+    fn fill_gaps(&mut self, candle: &Candle) -> Vec<Candle> {
+        let Some(prev) = self.last_candle.get(&candle.symbol) else {
+            return Vec::new();
+        };
+        let step = candle.timestamp - prev.timestamp;
+        let interval = self
+            .bar_interval_ms
+            .entry(candle.symbol.clone())
+            .or_insert(step);
+        *interval = (*interval).min(step);
+        let interval = *interval;
+
+        let missing = (step / interval - 1) as usize;
+        if missing == 0 || missing > self.gap_max_bars || self.gap_policy == CandleGapPolicy::None {
+            return Vec::new();
+        }
+        info!(
+            "CandleAggregator: {} filled {} missing bar(s) ({})",
+            candle.symbol, missing, self.gap_policy
+        );
+        Self::gap_bars(prev, candle, interval, missing, self.gap_policy)
+    }
+
+    /// Zero-volume bars for the `missing` intervals between `prev` and `next`
+    fn gap_bars(
+        prev: &Candle,
+        next: &Candle,
+        interval_ms: i64,
+        missing: usize,
+        policy: CandleGapPolicy,
+    ) -> Vec<Candle> {
+        let steps = Decimal::from(missing as u64 + 1);
+        let mut last = prev.close;
+        (1..=missing)
+            .map(|i| {
+                let close = match policy {
+                    CandleGapPolicy::Interpolate => {
+                        prev.close + (next.open - prev.close) * Decimal::from(i as u64) / steps
+                    }
+                    _ => prev.close,
+                };
+                let bar = Candle {
+                    symbol: prev.symbol.clone(),
+                    open: last,
+                    high: last.max(close),
+                    low: last.min(close),
+                    close,
+                    volume: Decimal::ZERO,
+                    timestamp: prev.timestamp + interval_ms * i as i64,
+                };
+                last = close;
+                bar
+            })
+            .collect()
+    }
+
     /// Check if a price is an outlier for a symbol, using both the current candle
     /// and the last confirmed close price.
     fn is_price_outlier(&self, symbol: &str, price: Decimal) -> bool {
@@ -171,7 +311,14 @@ impl CandleAggregator {
 
         // Check if we have an existing builder for this symbol
         if let Some(builder) = self.builders.get_mut(symbol) {
-            if builder.start_time == current_minute {
+            if current_minute < builder.start_time {
+                // A late tick must not close the current candle into an earlier minute
+                debug!(
+                    "CandleAggregator: {} late quote dropped ({} before candle start {})",
+                    symbol, timestamp, builder.start_time
+                );
+                None
+            } else if builder.start_time == current_minute {
                 // Same minute, update existing candle
                 builder.update(price, quantity, timestamp);
                 None
@@ -196,17 +343,8 @@ impl CandleAggregator {
                 // Start new candle
                 *builder = CandleBuilder::new(symbol.to_string(), price, timestamp);
 
-                if let Some(repo) = &self.repository {
-                    let candle_clone = completed_candle.clone();
-                    let repo = repo.clone();
-                    tokio::spawn(async move {
-                        if let Err(e) = repo.save(&candle_clone).await {
-                            error!(
-                                "Failed to persist candle for {}: {}",
-                                candle_clone.symbol, e
-                            );
-                        }
-                    });
+                if let Some(buffer) = &self.write_buffer {
+                    buffer.enqueue(completed_candle.clone());
                 }
 
                 Some(completed_candle)
@@ -404,4 +542,134 @@ mod tests {
             );
         }
     }
+
+    fn minute_candle(minute: i64, open: Decimal, close: Decimal) -> Candle {
+        Candle {
+            symbol: "BTC/USD".to_string(),
+            open,
+            high: open.max(close),
+            low: open.min(close),
+            close,
+            volume: dec!(1),
+            timestamp: minute * 60_000,
+        }
+    }
+
+    #[test]
+    fn test_gap_policy_none_leaves_gaps() {
+        let mut agg = CandleAggregator::new(None);
+        agg.sequence(minute_candle(0, dec!(100), dec!(100)));
+        agg.sequence(minute_candle(1, dec!(100), dec!(100)));
+        assert_eq!(
+            agg.sequence(minute_candle(5, dec!(104), dec!(104))).len(),
+            1
+        );
+    }
+
+    #[test]
+    fn test_gap_forward_fill() {
+        let mut agg = CandleAggregator::new(None).with_gap_policy(CandleGapPolicy::ForwardFill, 60);
+        agg.sequence(minute_candle(0, dec!(100), dec!(100)));
+        agg.sequence(minute_candle(1, dec!(100), dec!(101)));
+
+        let bars = agg.sequence(minute_candle(4, dec!(104), dec!(105)));
+        assert_eq!(bars.len(), 3);
+        for (bar, minute) in bars[..2].iter().zip([2, 3]) {
+            assert_eq!(bar.timestamp, minute * 60_000);
+            assert_eq!(bar.close, dec!(101));
+            assert_eq!(bar.high, dec!(101));
+            assert_eq!(bar.volume, Decimal::ZERO);
+        }
+        assert_eq!(bars[2].close, dec!(105));
+    }
+
+    #[test]
+    fn test_gap_interpolate() {
+        let mut agg = CandleAggregator::new(None).with_gap_policy(CandleGapPolicy::Interpolate, 60);
+        agg.sequence(minute_candle(0, dec!(100), dec!(100)));
+        agg.sequence(minute_candle(1, dec!(100), dec!(100)));
+
+        let bars = agg.sequence(minute_candle(5, dec!(104), dec!(104)));
+        let closes: Vec<Decimal> = bars.iter().map(|b| b.close).collect();
+        assert_eq!(closes, vec![dec!(101), dec!(102), dec!(103), dec!(104)]);
+        assert_eq!(bars[1].open, dec!(101));
+    }
+
+    #[test]
+    fn test_gap_longer_than_max_bars_not_filled() {
+        let mut agg = CandleAggregator::new(None).with_gap_policy(CandleGapPolicy::ForwardFill, 10);
+        agg.sequence(minute_candle(0, dec!(100), dec!(100)));
+        agg.sequence(minute_candle(1, dec!(100), dec!(100)));
+        // Overnight session break
+        assert_eq!(
+            agg.sequence(minute_candle(960, dec!(100), dec!(100))).len(),
+            1
+        );
+    }
+
+    #[test]
+    fn test_gap_interval_learned_from_bars() {
+        let mut agg = CandleAggregator::new(None).with_gap_policy(CandleGapPolicy::ForwardFill, 60);
+        // 5-minute bars: consecutive bars are not gaps
+        agg.sequence(minute_candle(0, dec!(100), dec!(100)));
+        assert_eq!(
+            agg.sequence(minute_candle(5, dec!(100), dec!(100))).len(),
+            1
+        );
+        assert_eq!(
+            agg.sequence(minute_candle(10, dec!(100), dec!(100))).len(),
+            1
+        );
+        // One 5-minute bar missing
+        let bars = agg.sequence(minute_candle(20, dec!(100), dec!(100)));
+        assert_eq!(bars.len(), 2);
+        assert_eq!(bars[0].timestamp, 15 * 60_000);
+    }
+
+    #[test]
+    fn test_duplicate_and_out_of_order_candles_dropped() {
+        let mut agg = CandleAggregator::new(None);
+        assert_eq!(
+            agg.sequence(minute_candle(1, dec!(100), dec!(100))).len(),
+            1
+        );
+        assert_eq!(
+            agg.sequence(minute_candle(2, dec!(100), dec!(101))).len(),
+            1
+        );
+        // Reconnect backfill replays bars already processed
+        assert!(
+            agg.sequence(minute_candle(2, dec!(100), dec!(101)))
+                .is_empty()
+        );
+        assert!(
+            agg.sequence(minute_candle(1, dec!(100), dec!(100)))
+                .is_empty()
+        );
+        assert_eq!(
+            agg.sequence(minute_candle(3, dec!(101), dec!(102))).len(),
+            1
+        );
+    }
+
+    #[test]
+    fn test_late_quote_does_not_close_candle() {
+        let mut agg = CandleAggregator::new(None);
+        let t0 = Utc
+            .with_ymd_and_hms(2024, 1, 1, 0, 1, 5)
+            .unwrap()
+            .timestamp_millis();
+        agg.on_quote("BTC/USD", dec!(68000), dec!(1.0), t0);
+        // Tick from the previous minute arrives late
+        assert!(
+            agg.on_quote("BTC/USD", dec!(68010), dec!(1.0), t0 - 10_000)
+                .is_none()
+        );
+
+        let candle = agg
+            .on_quote("BTC/USD", dec!(68020), dec!(1.0), t0 + 60_000)
+            .expect("next minute completes the candle");
+        assert_eq!(candle.close, dec!(68000));
+        assert_eq!(candle.volume, dec!(1.0));
+    }
 }
@@ -0,0 +1,235 @@
+//! Buffered persistence for completed candles.
+//!
+//! Writing every completed candle on its own costs one repository round-trip per symbol
+//! per bar. The buffer collects candles and writes them with `save_batch` once
+//! `batch_size` are pending or the oldest queued candle is `flush_interval_ms` old.
+//! `start_periodic_flush` writes partial batches on a timer so a quiet feed does not
+//! hold candles back; `flush` writes whatever is left, e.g. on shutdown.
+
+use crate::domain::repositories::CandleRepository;
+use crate::domain::trading::time::now_ms;
+use crate::domain::trading::types::Candle;
+use std::sync::{Arc, Mutex};
+use tracing::{debug, error, info};
+
+struct Pending {
+    candles: Vec<Candle>,
+    // When the oldest candle still pending was queued
+    oldest_queued_ms: Option<i64>,
+}
+
+/// Shared by the aggregator (queues) and the shutdown sequence (final flush)
+pub struct CandleWriteBuffer {
+    repository: Arc<dyn CandleRepository>,
+    // Candles per write (1 = write-through)
+    batch_size: usize,
+    // Flush a partial batch after this long (0 = only when full)
+    flush_interval_ms: i64,
+    pending: Mutex<Pending>,
+}
+
+impl CandleWriteBuffer {
+    pub fn new(
+        repository: Arc<dyn CandleRepository>,
+        batch_size: usize,
+        flush_interval_ms: i64,
+    ) -> Self {
+        Self {
+            repository,
+            batch_size: batch_size.max(1),
+            flush_interval_ms,
+            pending: Mutex::new(Pending {
+                candles: Vec::new(),
+                oldest_queued_ms: None,
+            }),
+        }
+    }
+
+    /// Write-through buffer: every candle is written as soon as it is queued
+    pub fn write_through(repository: Arc<dyn CandleRepository>) -> Self {
+        Self::new(repository, 1, 0)
+    }
+
+    pub fn batch_size(&self) -> usize {
+        self.batch_size
+    }
+
+    /// Queue a candle; returns the batch to write when a flush is due
+    pub fn push(&self, candle: Candle) -> Option<Vec<Candle>> {
+        self.push_at(candle, now_ms())
+    }
+
+    fn push_at(&self, candle: Candle, now: i64) -> Option<Vec<Candle>> {
+        let mut pending = self.pending.lock().unwrap_or_else(|e| e.into_inner());
+        pending.candles.push(candle);
+        let oldest = *pending.oldest_queued_ms.get_or_insert(now);
+
+        let oldest_due = self.flush_interval_ms > 0 && now - oldest >= self.flush_interval_ms;
+        if pending.candles.len() >= self.batch_size || oldest_due {
+            pending.oldest_queued_ms = None;
+            return Some(std::mem::take(&mut pending.candles));
+        }
+        None
+    }
+
+    /// Queue a candle and write the batch in the background when a flush is due
+    pub fn enqueue(&self, candle: Candle) {
+        if let Some(batch) = self.push(candle) {
+            let repository = self.repository.clone();
+            tokio::spawn(async move {
+                Self::write(repository.as_ref(), batch).await;
+            });
+        }
+    }
+
+    /// Write every queued candle now
+    pub async fn flush(&self) {
+        let batch = {
+            let mut pending = self.pending.lock().unwrap_or_else(|e| e.into_inner());
+            pending.oldest_queued_ms = None;
+            std::mem::take(&mut pending.candles)
+        };
+        if !batch.is_empty() {
+            Self::write(self.repository.as_ref(), batch).await;
+        }
+    }
+
+    /// Start the background task writing partial batches every `flush_interval_ms`
+    /// (no-op when the interval is 0)
+    pub fn start_periodic_flush(self: Arc<Self>) {
+        if self.flush_interval_ms <= 0 {
+            return;
+        }
+        info!(
+            "CandleWriteBuffer: Flushing partial batches every {}ms",
+            self.flush_interval_ms
+        );
+        tokio::spawn(async move {
+            let mut interval = tokio::time::interval(std::time::Duration::from_millis(
+                self.flush_interval_ms as u64,
+            ));
+            loop {
+                interval.tick().await;
+                self.flush().await;
+            }
+        });
+    }
+
+    pub fn pending_len(&self) -> usize {
+        self.pending
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .candles
+            .len()
+    }
+
+    async fn write(repository: &dyn CandleRepository, batch: Vec<Candle>) {
+        match repository.save_batch(&batch).await {
+            Ok(()) => debug!("CandleWriteBuffer: Persisted {} candles", batch.len()),
+            Err(e) => error!(
+                "CandleWriteBuffer: Failed to persist {} candles: {}",
+                batch.len(),
+                e
+            ),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rust_decimal::Decimal;
+
+    /// Records the size of every batch written
+    #[derive(Default)]
+    struct RecordingCandleRepository {
+        batches: Mutex<Vec<usize>>,
+    }
+
+    #[async_trait::async_trait]
+    impl CandleRepository for RecordingCandleRepository {
+        async fn save(&self, candle: &Candle) -> anyhow::Result<()> {
+            self.save_batch(std::slice::from_ref(candle)).await
+        }
+        async fn save_batch(&self, candles: &[Candle]) -> anyhow::Result<()> {
+            self.batches.lock().unwrap().push(candles.len());
+            Ok(())
+        }
+        async fn get_range(&self, _: &str, _: i64, _: i64) -> anyhow::Result<Vec<Candle>> {
+            Ok(vec![])
+        }
+        async fn get_latest_timestamp(&self, _: &str) -> anyhow::Result<Option<i64>> {
+            Ok(None)
+        }
+        async fn count_candles(&self, _: &str, _: i64, _: i64) -> anyhow::Result<usize> {
+            Ok(0)
+        }
+        async fn prune(&self, _: i64) -> anyhow::Result<u64> {
+            Ok(0)
+        }
+    }
+
+    fn candle(timestamp: i64) -> Candle {
+        Candle {
+            symbol: "AAPL".to_string(),
+            open: Decimal::ONE,
+            high: Decimal::ONE,
+            low: Decimal::ONE,
+            close: Decimal::ONE,
+            volume: Decimal::ONE,
+            timestamp,
+        }
+    }
+
+    #[tokio::test]
+    async fn test_flushes_full_batches_and_on_demand() {
+        let repository = Arc::new(RecordingCandleRepository::default());
+        let buffer = CandleWriteBuffer::new(repository.clone(), 3, 0);
+
+        assert!(buffer.push(candle(1)).is_none());
+        assert!(buffer.push(candle(2)).is_none());
+        assert_eq!(buffer.push(candle(3)).map(|b| b.len()), Some(3));
+        assert_eq!(buffer.pending_len(), 0);
+
+        // A partial batch waits for the flush (e.g. on shutdown)
+        buffer.push(candle(4));
+        buffer.push(candle(5));
+        buffer.flush().await;
+        assert_eq!(buffer.pending_len(), 0);
+        assert_eq!(*repository.batches.lock().unwrap(), vec![2]);
+    }
+
+    #[test]
+    fn test_flushes_once_oldest_candle_is_due() {
+        let buffer =
+            CandleWriteBuffer::new(Arc::new(RecordingCandleRepository::default()), 10, 1_000);
+
+        assert!(buffer.push_at(candle(1), 0).is_none());
+        assert!(buffer.push_at(candle(2), 900).is_none());
+        // Measured from the oldest queued candle, not the latest one
+        assert_eq!(buffer.push_at(candle(3), 1_000).map(|b| b.len()), Some(3));
+        // The next batch starts its own clock
+        assert!(buffer.push_at(candle(4), 1_500).is_none());
+    }
+
+    #[tokio::test]
+    async fn test_periodic_flush_writes_partial_batch() {
+        let repository = Arc::new(RecordingCandleRepository::default());
+        let buffer = Arc::new(CandleWriteBuffer::new(repository.clone(), 10, 50));
+        buffer.clone().start_periodic_flush();
+
+        // No further candle arrives to trigger the flush
+        buffer.enqueue(candle(1));
+        tokio::time::sleep(std::time::Duration::from_millis(150)).await;
+
+        assert_eq!(buffer.pending_len(), 0);
+        assert_eq!(*repository.batches.lock().unwrap(), vec![1]);
+    }
+
+    #[test]
+    fn test_write_through_returns_every_candle() {
+        let buffer =
+            CandleWriteBuffer::write_through(Arc::new(RecordingCandleRepository::default()));
+        assert_eq!(buffer.push(candle(1)).map(|b| b.len()), Some(1));
+    }
+}
@@ -0,0 +1,124 @@
+//! Higher-timeframe trend tracking for entry confirmation.
+//!
+//! Primary-timeframe candles are rolled up into the configured `trend_timeframe` with the
+//! [`TimeframeAggregator`]; each completed higher-timeframe close feeds a simple moving
+//! average whose slope tells whether the bigger picture agrees with a long entry.
+
+use crate::application::market_data::timeframe_aggregator::TimeframeAggregator;
+use crate::domain::market::timeframe::Timeframe;
+use crate::domain::trading::types::Candle;
+use rust_decimal::Decimal;
+use std::collections::VecDeque;
+
+/// Higher-timeframe bars averaged into the trend SMA
+pub const HTF_TREND_SMA_PERIOD: usize = 20;
+
+pub struct HigherTimeframeTrend {
+    timeframe: Timeframe,
+    period: usize,
+    aggregator: TimeframeAggregator,
+    closes: VecDeque<Decimal>,
+    sma: Option<Decimal>,
+    previous_sma: Option<Decimal>,
+}
+
+impl HigherTimeframeTrend {
+    pub fn new(timeframe: Timeframe, period: usize) -> Self {
+        Self {
+            timeframe,
+            period: period.max(1),
+            aggregator: TimeframeAggregator::new(),
+            closes: VecDeque::with_capacity(period + 1),
+            sma: None,
+            previous_sma: None,
+        }
+    }
+
+    pub fn timeframe(&self) -> Timeframe {
+        self.timeframe
+    }
+
+    /// Feed one primary-timeframe candle; the SMA moves when a higher-timeframe bar completes
+    pub fn update(&mut self, candle: &Candle) {
+        for completed in self.aggregator.process_candle(candle, &[self.timeframe]) {
+            self.push_close(completed.close);
+        }
+    }
+
+    fn push_close(&mut self, close: Decimal) {
+        self.closes.push_back(close);
+        if self.closes.len() > self.period {
+            self.closes.pop_front();
+        }
+        if self.closes.len() == self.period {
+            self.previous_sma = self.sma;
+            self.sma = Some(self.closes.iter().sum::<Decimal>() / Decimal::from(self.period));
+        }
+    }
+
+    /// Slope of the higher-timeframe SMA over the last completed bar.
+    /// None until two SMA values exist.
+    pub fn slope(&self) -> Option<Decimal> {
+        Some(self.sma? - self.previous_sma?)
+    }
+
+    /// True only once the trend SMA is known and rising
+    pub fn is_bullish(&self) -> bool {
+        self.slope().is_some_and(|slope| slope > Decimal::ZERO)
+    }
+
+    pub fn clear(&mut self) {
+        self.aggregator.clear();
+        self.closes.clear();
+        self.sma = None;
+        self.previous_sma = None;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rust_decimal_macros::dec;
+
+    fn candle(minute: i64, close: Decimal) -> Candle {
+        Candle {
+            symbol: "AAPL".to_string(),
+            open: close,
+            high: close,
+            low: close,
+            close,
+            volume: dec!(100),
+            timestamp: minute * 60_000,
+        }
+    }
+
+    #[test]
+    fn test_htf_trend_follows_completed_bars_only() {
+        let mut trend = HigherTimeframeTrend::new(Timeframe::FiveMin, 2);
+
+        // Two 5-minute bars closing at 100 and 101: one SMA, no slope yet
+        for minute in 0..10 {
+            trend.update(&candle(minute, Decimal::from(100 + minute / 5)));
+        }
+        assert_eq!(trend.slope(), None);
+        assert!(!trend.is_bullish());
+
+        // An incomplete third bar does not move the SMA
+        for minute in 10..14 {
+            trend.update(&candle(minute, dec!(102)));
+        }
+        assert_eq!(trend.slope(), None);
+
+        // Completing it at 102: SMA 100.5 -> 101.5
+        trend.update(&candle(14, dec!(102)));
+        assert_eq!(trend.slope(), Some(dec!(1)));
+        assert!(trend.is_bullish());
+
+        // A falling fourth bar turns the slope negative: 101.5 -> 96
+        for minute in 15..20 {
+            trend.update(&candle(minute, dec!(90)));
+        }
+        assert_eq!(trend.slope(), Some(dec!(-5.5)));
+        assert!(!trend.is_bullish());
+    }
+}
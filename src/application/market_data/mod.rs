@@ -1,6 +1,8 @@
 // Market data processing modules
 pub mod candle_aggregator;
+pub mod candle_write_buffer;
+pub mod htf_trend;
 pub mod signal_generator;
 pub mod spread_cache;
-pub mod statistical_features; // NEW: Advanced statistical features
+pub use crate::domain::market::statistical_features;
 pub mod timeframe_aggregator;
@@ -7,6 +7,8 @@ use tracing::info;
 
 pub struct SignalGenerator {
     pub last_was_above: Option<bool>,
+    /// Secondary strategy whose signal must agree with the primary's entries
+    confirm_strategy: Option<Arc<dyn TradingStrategy>>,
 }
 
 impl Default for SignalGenerator {
@@ -19,9 +21,16 @@ impl SignalGenerator {
     pub fn new() -> Self {
         Self {
             last_was_above: None,
+            confirm_strategy: None,
         }
     }
 
+    /// Primary + confirm: entries are only taken when `strategy` signals the same side.
+    /// Exits (signals while a position is open) follow the primary strategy alone.
+    pub fn set_confirmation(&mut self, strategy: Option<Arc<dyn TradingStrategy>>) {
+        self.confirm_strategy = strategy;
+    }
+
     #[allow(clippy::too_many_arguments)]
     pub fn generate_signal(
         &self,
@@ -61,6 +70,8 @@ impl SignalGenerator {
             bb_upper: features.bb_upper,
             bb_middle: features.bb_middle,
             adx: features.adx,
+            plus_di: features.plus_di,
+            minus_di: features.minus_di,
             has_position,
             position,
             timestamp,
@@ -82,6 +93,21 @@ impl SignalGenerator {
         };
 
         if let Some(strategy_signal) = strategy.analyze(&analysis_ctx) {
+            if !has_position && let Some(confirm) = &self.confirm_strategy {
+                let confirmed = confirm
+                    .analyze(&analysis_ctx)
+                    .is_some_and(|s| s.side == strategy_signal.side);
+                if !confirmed {
+                    info!(
+                        "SignalGenerator [{}]: {} - {:?} not confirmed by {}",
+                        strategy.name(),
+                        symbol,
+                        strategy_signal.side,
+                        confirm.name()
+                    );
+                    return None;
+                }
+            }
             info!(
                 "SignalGenerator [{}]: {} - {}",
                 strategy.name(),
@@ -241,4 +267,51 @@ mod tests {
 
         assert!(result.is_none());
     }
+
+    #[test]
+    fn test_confirmation_gates_entries_only() {
+        let features = FeatureSet::default();
+        let candles = VecDeque::new();
+        let rsi_history = VecDeque::new();
+        let ofi_history = VecDeque::new();
+        let primary: Arc<dyn TradingStrategy> =
+            Arc::new(MockStrategy::new(Some(Signal::buy("Momentum buy"))));
+        let generate = |generator: &SignalGenerator, has_position: bool| {
+            generator
+                .generate_signal(
+                    "BTC",
+                    dec!(100.0),
+                    0,
+                    &features,
+                    &primary,
+                    has_position,
+                    None,
+                    None,
+                    &candles,
+                    &rsi_history,
+                    dec!(0.0),
+                    dec!(0.0),
+                    None,
+                    &ofi_history,
+                )
+                .map(|s| s.side)
+        };
+
+        let mut generator = SignalGenerator::new();
+        generator.set_confirmation(Some(Arc::new(MockStrategy::new(Some(Signal::buy(
+            "Trend up",
+        ))))));
+        assert_eq!(generate(&generator, false), Some(OrderSide::Buy));
+
+        // Confirm strategy silent or disagreeing: no entry
+        generator.set_confirmation(Some(Arc::new(MockStrategy::new(None))));
+        assert_eq!(generate(&generator, false), None);
+        generator.set_confirmation(Some(Arc::new(MockStrategy::new(Some(Signal::sell(
+            "Trend down",
+        ))))));
+        assert_eq!(generate(&generator, false), None);
+
+        // With a position open the primary drives alone
+        assert_eq!(generate(&generator, true), Some(OrderSide::Buy));
+    }
 }
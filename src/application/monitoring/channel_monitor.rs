@@ -0,0 +1,110 @@
+//! Event-bus channel depth sampling.
+//!
+//! Periodically records how many messages sit in each watched channel
+//! (`rustrade_channel_depth`). Channels are held through weak senders so watching one
+//! never keeps it open; a closed channel reports a depth of 0.
+
+use crate::infrastructure::observability::Metrics;
+use std::time::Duration;
+use tokio::sync::{broadcast, mpsc};
+
+/// Default spacing between two depth samples
+pub const DEFAULT_CHANNEL_SAMPLE_INTERVAL: Duration = Duration::from_secs(5);
+
+type DepthProbe = Box<dyn Fn() -> usize + Send + Sync>;
+
+pub struct ChannelMonitor {
+    metrics: Metrics,
+    probes: Vec<(&'static str, DepthProbe)>,
+}
+
+impl ChannelMonitor {
+    pub fn new(metrics: Metrics) -> Self {
+        Self {
+            metrics,
+            probes: Vec::new(),
+        }
+    }
+
+    /// Watch a bounded mpsc channel (depth = messages waiting for the receiver)
+    pub fn watch_mpsc<T: Send + 'static>(
+        mut self,
+        name: &'static str,
+        tx: &mpsc::Sender<T>,
+    ) -> Self {
+        let weak = tx.downgrade();
+        self.probes.push((
+            name,
+            Box::new(move || {
+                weak.upgrade()
+                    .map(|tx| tx.max_capacity() - tx.capacity())
+                    .unwrap_or(0)
+            }),
+        ));
+        self
+    }
+
+    /// Watch a broadcast channel (depth = messages not yet seen by every receiver)
+    pub fn watch_broadcast<T: Send + 'static>(
+        mut self,
+        name: &'static str,
+        tx: &broadcast::Sender<T>,
+    ) -> Self {
+        let weak = tx.downgrade();
+        self.probes.push((
+            name,
+            Box::new(move || weak.upgrade().map(|tx| tx.len()).unwrap_or(0)),
+        ));
+        self
+    }
+
+    /// Record the current depth of every watched channel
+    pub fn sample(&self) {
+        for (name, probe) in &self.probes {
+            self.metrics.set_channel_depth(name, probe());
+        }
+    }
+
+    /// Sample every `interval`, forever
+    pub async fn run(self, interval: Duration) {
+        let mut ticker = tokio::time::interval(interval);
+        loop {
+            ticker.tick().await;
+            self.sample();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_samples_queued_messages() {
+        let metrics = Metrics::new().unwrap();
+        let (mpsc_tx, _mpsc_rx) = mpsc::channel::<u32>(10);
+        let (bc_tx, _bc_rx) = broadcast::channel::<u32>(10);
+        let monitor = ChannelMonitor::new(metrics.clone())
+            .watch_mpsc("proposals", &mpsc_tx)
+            .watch_broadcast("candles", &bc_tx);
+
+        mpsc_tx.send(1).await.unwrap();
+        mpsc_tx.send(2).await.unwrap();
+        bc_tx.send(1).unwrap();
+        monitor.sample();
+
+        let stats = metrics.channel_stats();
+        assert_eq!(
+            stats,
+            vec![
+                ("candles".to_string(), 1.0, 0.0),
+                ("proposals".to_string(), 2.0, 0.0)
+            ]
+        );
+
+        // Watching does not keep a channel open
+        drop(mpsc_tx);
+        monitor.sample();
+        assert_eq!(metrics.channel_stats()[1].1, 0.0);
+    }
+}
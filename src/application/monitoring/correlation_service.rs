@@ -1,4 +1,5 @@
 use crate::domain::repositories::CandleRepository;
+use crate::domain::trading::time::{MS_PER_DAY, now_ms};
 use crate::domain::trading::types::Candle;
 use anyhow::{Context, Result};
 use rust_decimal::prelude::ToPrimitive;
@@ -43,8 +44,8 @@ impl CorrelationService {
             "CorrelationService: Refreshing correlation matrix for {} symbols",
             symbols.len()
         );
-        let end_ts = chrono::Utc::now().timestamp();
-        let start_ts = end_ts - (30 * 24 * 60 * 60); // 30 days
+        let end_ts = now_ms();
+        let start_ts = end_ts - 30 * MS_PER_DAY; // 30 days
 
         let mut returns = HashMap::new();
 
@@ -47,6 +47,8 @@ pub struct TradeCost {
 ///     timestamp: 0,
 ///     stop_loss: None,
 ///     take_profit: None,
+///     reduce_only: false,
+///     origin: Default::default(),
 /// };
 /// let costs = evaluator.evaluate(&proposal);
 /// let expected_profit = Decimal::from(5);
@@ -97,9 +99,13 @@ impl CostEvaluator {
     /// TradeCost breakdown with detailed cost components
     pub fn evaluate(&self, proposal: &TradeProposal) -> TradeCost {
         // Delegate to FeeModel
-        let trade_costs =
-            self.fee_model
-                .calculate_cost(proposal.quantity, proposal.price, proposal.side);
+        let trade_costs = self.fee_model.calculate_order_cost(
+            &proposal.symbol,
+            proposal.quantity,
+            proposal.price,
+            proposal.side,
+            proposal.order_type,
+        );
         let commission = trade_costs.fee;
         let estimated_slippage = trade_costs.slippage_cost;
 
@@ -112,21 +118,26 @@ impl CostEvaluator {
             Decimal::from(15) // 15 bps max for stocks
         };
 
+        // Per-symbol spread override replaces the global default
+        let default_spread_bps = self
+            .fee_model
+            .spread_bps_for(&proposal.symbol)
+            .unwrap_or(self.default_spread_bps);
+
         // Spread: Use REAL spread from cache if available, otherwise use default
         let raw_spread_bps = if let Some(ref cache) = self.spread_cache {
             if let Some(real_spread_pct) = cache.get_spread_pct(&proposal.symbol) {
-                Decimal::from_f64_retain(real_spread_pct * 10000.0)
-                    .unwrap_or(self.default_spread_bps)
+                Decimal::from_f64_retain(real_spread_pct * 10000.0).unwrap_or(default_spread_bps)
             } else {
                 tracing::debug!(
                     "CostEvaluator: No real spread for {}, using DEFAULT {:.2} bps",
                     proposal.symbol,
-                    self.default_spread_bps
+                    default_spread_bps
                 );
-                self.default_spread_bps
+                default_spread_bps
             }
         } else {
-            self.default_spread_bps
+            default_spread_bps
         };
 
         // Apply spread cap
@@ -209,6 +220,8 @@ impl CostEvaluator {
     ///     timestamp: 0,
     ///     stop_loss: None,
     ///     take_profit: None,
+    ///     reduce_only: false,
+    ///     origin: Default::default(),
     /// };
     ///
     /// // Trade costs $1.50, expected profit is $5.00, min ratio is 2.0
@@ -303,6 +316,8 @@ mod tests {
             timestamp: 0,
             stop_loss: None,
             take_profit: None,
+            reduce_only: false,
+            origin: Default::default(),
         }
     }
 
@@ -476,4 +491,28 @@ mod tests {
         // Expected profit: $5.00 < $7.75 ❌
         assert!(!evaluator.is_profitable(&proposal, dec!(5.0), dec!(5.0)));
     }
+
+    #[test]
+    fn test_per_symbol_cost_override() {
+        use crate::domain::trading::fee_model::{ConstantFeeModel, PerSymbolFeeModel};
+        let fee_model =
+            PerSymbolFeeModel::new(Arc::new(ConstantFeeModel::new(dec!(0.005), dec!(0.001))))
+                .with_symbol_model(
+                    "TEST",
+                    Arc::new(ConstantFeeModel::new(dec!(0.0), dec!(0.002))),
+                )
+                .with_symbol_spread("TEST", dec!(10.0));
+        let evaluator = CostEvaluator::new(Arc::new(fee_model), dec!(5.0));
+
+        let costs = evaluator.evaluate(&create_test_proposal(dec!(100.0), dec!(10.0)));
+        // Override: no commission, 0.2% slippage, 10 bps spread (half = $0.50)
+        assert_eq!(costs.commission, Decimal::ZERO);
+        assert_eq!(costs.estimated_slippage, dec!(2.0));
+        assert_eq!(costs.spread_cost, dec!(0.5));
+
+        // Other symbols keep the global defaults ($1.30, see above)
+        let mut other = create_test_proposal(dec!(100.0), dec!(10.0));
+        other.symbol = "OTHER".to_string();
+        assert_eq!(evaluator.evaluate(&other).total_cost, dec!(1.30));
+    }
 }
@@ -340,6 +340,9 @@ mod tests {
             order_type: crate::domain::trading::types::OrderType::Market,
             status: crate::domain::trading::types::OrderStatus::Filled,
             timestamp: 0,
+            reduce_only: false,
+            origin: Default::default(),
+            reason: None,
         }
     }
 
@@ -353,6 +356,9 @@ mod tests {
             order_type: crate::domain::trading::types::OrderType::Market,
             status: crate::domain::trading::types::OrderStatus::Filled,
             timestamp: 1000,
+            reduce_only: false,
+            origin: Default::default(),
+            reason: None,
         }
     }
 
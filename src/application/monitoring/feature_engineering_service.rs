@@ -1,17 +1,16 @@
 use crate::application::agents::analyst_config::AnalystConfig;
-use crate::application::market_data::statistical_features::{
-    calculate_hurst_exponent, calculate_skewness,
-};
-use crate::application::risk_management::volatility::calculate_realized_volatility;
+use crate::domain::market::statistical_features::{calculate_hurst_exponent, calculate_skewness};
+use crate::domain::market::volatility::calculate_realized_volatility;
 use crate::domain::ports::FeatureEngineeringService;
-use crate::domain::trading::types::{Candle, FeatureSet};
+use crate::domain::trading::types::{Candle, FeatureRequirements, FeatureSet};
 use rust_decimal::Decimal;
 use rust_decimal::prelude::ToPrimitive;
 use std::collections::VecDeque;
 use ta::Next;
 use ta::indicators::{
-    AverageTrueRange, BollingerBands, ExponentialMovingAverage, MovingAverageConvergenceDivergence,
-    RelativeStrengthIndex, SimpleMovingAverage,
+    BollingerBands, BollingerBandsOutput, ExponentialMovingAverage,
+    MovingAverageConvergenceDivergence, MovingAverageConvergenceDivergenceOutput,
+    SimpleMovingAverage,
 };
 
 /// Manual ADX implementation using standard Wilder's smoothing
@@ -29,6 +28,8 @@ pub struct ManualAdx {
     plus_dm_smooth: f64,
     minus_dm_smooth: f64,
     adx_smooth: f64,
+    plus_di: f64,
+    minus_di: f64,
     count: usize,
 }
 
@@ -46,6 +47,8 @@ impl ManualAdx {
             plus_dm_smooth: 0.0,
             minus_dm_smooth: 0.0,
             adx_smooth: 0.0,
+            plus_di: 0.0,
+            minus_di: 0.0,
             count: 0,
         }
     }
@@ -100,6 +103,8 @@ impl ManualAdx {
         if self.count >= self.period && self.tr_smooth > 0.0 {
             let plus_di = 100.0 * self.plus_dm_smooth / self.tr_smooth;
             let minus_di = 100.0 * self.minus_dm_smooth / self.tr_smooth;
+            self.plus_di = plus_di;
+            self.minus_di = minus_di;
             let sum_di = plus_di + minus_di;
             let dx = if sum_di > 0.0 {
                 100.0 * (plus_di - minus_di).abs() / sum_di
@@ -121,28 +126,141 @@ impl ManualAdx {
         self.prev_close = Some(close);
         adx
     }
+
+    /// +DI from the last update (0.0 until `period` bars have been seen)
+    pub fn plus_di(&self) -> f64 {
+        self.plus_di
+    }
+
+    /// −DI from the last update (0.0 until `period` bars have been seen)
+    pub fn minus_di(&self) -> f64 {
+        self.minus_di
+    }
+}
+
+/// Wilder's running mean: the plain average of the first `period` samples seeds it
+/// (the average so far is returned until then), then `(prev × (n − 1) + x) / n`. O(1) per update.
+#[derive(Debug, Clone)]
+struct WilderAverage {
+    period: usize,
+    count: usize,
+    value: f64,
+}
+
+impl WilderAverage {
+    fn new(period: usize) -> Self {
+        Self {
+            period: period.max(1),
+            count: 0,
+            value: 0.0,
+        }
+    }
+
+    fn next(&mut self, sample: f64) -> f64 {
+        if self.count < self.period {
+            self.count += 1;
+            self.value += (sample - self.value) / self.count as f64;
+        } else {
+            let n = self.period as f64;
+            self.value = (self.value * (n - 1.0) + sample) / n;
+        }
+        self.value
+    }
+
+    /// True once the first `period` samples have been averaged
+    fn is_seeded(&self) -> bool {
+        self.count >= self.period
+    }
+}
+
+/// RSI with Wilder's smoothing of average gains and losses
+pub struct WilderRsi {
+    gains: WilderAverage,
+    losses: WilderAverage,
+    prev_close: Option<f64>,
+}
+
+impl WilderRsi {
+    pub fn new(period: usize) -> Self {
+        Self {
+            gains: WilderAverage::new(period),
+            losses: WilderAverage::new(period),
+            prev_close: None,
+        }
+    }
+
+    /// RSI after `close`; neutral (50.0) until `period` price changes seed the averages
+    pub fn next(&mut self, close: f64) -> f64 {
+        let Some(prev) = self.prev_close.replace(close) else {
+            return 50.0;
+        };
+        let change = close - prev;
+        let avg_gain = self.gains.next(change.max(0.0));
+        let avg_loss = self.losses.next((-change).max(0.0));
+
+        if !self.gains.is_seeded() {
+            50.0
+        } else if avg_loss == 0.0 {
+            if avg_gain == 0.0 { 50.0 } else { 100.0 }
+        } else {
+            100.0 - 100.0 / (1.0 + avg_gain / avg_loss)
+        }
+    }
+}
+
+/// Average True Range with Wilder's smoothing
+pub struct WilderAtr {
+    true_range: WilderAverage,
+    prev_close: Option<f64>,
+}
+
+impl WilderAtr {
+    pub fn new(period: usize) -> Self {
+        Self {
+            true_range: WilderAverage::new(period),
+            prev_close: None,
+        }
+    }
+
+    pub fn next(&mut self, high: f64, low: f64, close: f64) -> f64 {
+        let tr = match self.prev_close.replace(close) {
+            Some(prev) => (high - low)
+                .max((high - prev).abs())
+                .max((low - prev).abs()),
+            None => high - low,
+        };
+        self.true_range.next(tr)
+    }
 }
 
 pub struct TechnicalFeatureEngineeringService {
-    rsi: RelativeStrengthIndex,
+    rsi: WilderRsi,
     macd: MovingAverageConvergenceDivergence,
     sma_20: SimpleMovingAverage,
     sma_50: SimpleMovingAverage,
     sma_200: SimpleMovingAverage,
     bb: BollingerBands,
-    atr: AverageTrueRange,
+    atr: WilderAtr,
     ema_fast: ExponentialMovingAverage,
     ema_slow: ExponentialMovingAverage,
     adx: ManualAdx,
     /// Price history kept in Decimal until conversion for statistical functions (hurst, skewness, volatility).
     price_history: VecDeque<Decimal>,
+    /// Prices used for the rolling Hurst exponent (min 20)
+    hurst_lookback: usize,
+    /// Optional indicator groups to compute (the rest stay None)
+    requirements: FeatureRequirements,
 }
 
+/// Prices used for skewness, realized volatility and momentum
+const STATS_HISTORY_LEN: usize = 100;
+/// Minimum prices for a meaningful R/S estimate
+const MIN_HURST_LOOKBACK: usize = 20;
+
 impl TechnicalFeatureEngineeringService {
     pub fn new(config: &AnalystConfig) -> Self {
         Self {
-            rsi: RelativeStrengthIndex::new(config.rsi_period)
-                .expect("rsi_period from AnalystConfig must be > 0"),
+            rsi: WilderRsi::new(config.rsi_period),
             macd: MovingAverageConvergenceDivergence::new(
                 config.macd_fast_period,
                 config.macd_slow_period,
@@ -160,16 +278,23 @@ impl TechnicalFeatureEngineeringService {
                 config.bb_std_dev.to_f64().unwrap_or(2.0),
             )
             .expect("mean_reversion_bb_period from AnalystConfig must be > 0"),
-            atr: AverageTrueRange::new(config.atr_period)
-                .expect("atr_period from AnalystConfig must be > 0"),
+            atr: WilderAtr::new(config.atr_period),
             ema_fast: ExponentialMovingAverage::new(config.ema_fast_period)
                 .expect("ema_fast_period from AnalystConfig must be > 0"),
             ema_slow: ExponentialMovingAverage::new(config.ema_slow_period)
                 .expect("ema_slow_period from AnalystConfig must be > 0"),
             adx: ManualAdx::new(config.adx_period),
-            price_history: VecDeque::with_capacity(100),
+            price_history: VecDeque::with_capacity(STATS_HISTORY_LEN.max(config.hurst_lookback)),
+            hurst_lookback: config.hurst_lookback.max(MIN_HURST_LOOKBACK),
+            requirements: FeatureRequirements::ALL,
         }
     }
+
+    /// Compute only the optional indicator groups in `requirements`
+    pub fn with_requirements(mut self, requirements: FeatureRequirements) -> Self {
+        self.requirements = requirements;
+        self
+    }
 }
 
 /// Convert Decimal price history to f64 only for statistical library boundaries (statrs, etc.).
@@ -177,57 +302,102 @@ fn price_history_to_f64(history: &VecDeque<Decimal>) -> Vec<f64> {
     history.iter().filter_map(|d| d.to_f64()).collect()
 }
 
-impl FeatureEngineeringService for TechnicalFeatureEngineeringService {
-    fn update(&mut self, candle: &Candle) -> FeatureSet {
+/// Streaming indicator outputs for one candle, before the statistical features
+struct IndicatorValues {
+    price: f64,
+    rsi: f64,
+    macd: Option<MovingAverageConvergenceDivergenceOutput>,
+    bb: Option<BollingerBandsOutput>,
+    atr: f64,
+    sma_20: f64,
+    sma_50: f64,
+    sma_200: f64,
+    ema_fast: f64,
+    ema_slow: f64,
+    adx: Option<f64>,
+}
+
+impl TechnicalFeatureEngineeringService {
+    /// Feed one candle to every streaming indicator and the price history
+    fn advance(&mut self, candle: &Candle) -> IndicatorValues {
         let price = candle.close.to_f64().unwrap_or(0.0);
         let high = candle.high.to_f64().unwrap_or(0.0);
         let low = candle.low.to_f64().unwrap_or(0.0);
         let open = candle.open.to_f64().unwrap_or(0.0);
 
-        let rsi_val = self.rsi.next(price);
-        let macd_val = self.macd.next(price);
-        let bb_val = self.bb.next(price);
-
-        // DATA ITEM for indicators needing OHLC (ATR)
-        let item = ta::DataItem::builder()
-            .high(high)
-            .low(low)
-            .close(price)
-            .open(open)
-            .volume(candle.volume.to_f64().unwrap_or(0.0))
-            .build()
-            .unwrap_or_else(|e| {
+        let rsi = self.rsi.next(price);
+        let macd = self.requirements.macd.then(|| self.macd.next(price));
+        let bb = self.requirements.bollinger.then(|| self.bb.next(price));
+
+        // Malformed bars (close or open outside the high/low range) count as zero-range
+        let (atr_high, atr_low) =
+            if low <= high && (low..=high).contains(&price) && (low..=high).contains(&open) {
+                (high, low)
+            } else {
                 tracing::warn!(
-                    "Failed to build DataItem: {:?}. Using close price as fallback. H:{}, L:{}",
-                    e,
+                    "Inconsistent OHLC bar. Using close price as fallback. H:{}, L:{}",
                     high,
                     low
                 );
-                ta::DataItem::builder()
-                    .high(price)
-                    .low(price)
-                    .close(price)
-                    .open(price)
-                    .volume(0.0)
-                    .build()
-                    .unwrap()
-            });
+                (price, price)
+            };
 
         // Calculate ATR early as it is needed for momentum normalization
-        let atr_val = self.atr.next(&item);
+        let atr = self.atr.next(atr_high, atr_low, price);
 
         // Update price history (keep as Decimal until statistical boundaries)
         self.price_history.push_back(candle.close);
-        if self.price_history.len() > 100 {
+        if self.price_history.len() > STATS_HISTORY_LEN.max(self.hurst_lookback) {
             self.price_history.pop_front();
         }
 
-        // Convert to f64 only for statistical library boundaries
-        let prices_vec: Vec<f64> = price_history_to_f64(&self.price_history);
+        IndicatorValues {
+            price,
+            rsi,
+            macd,
+            bb,
+            atr,
+            sma_20: self.sma_20.next(price),
+            sma_50: self.sma_50.next(price),
+            sma_200: self.sma_200.next(price),
+            ema_fast: self.ema_fast.next(price),
+            ema_slow: self.ema_slow.next(price),
+            adx: self
+                .requirements
+                .adx
+                .then(|| self.adx.next(high, low, price)),
+        }
+    }
+}
 
-        // Hurst Exponent (requires ~50 periods)
-        let hurst_exponent = if prices_vec.len() >= 50 {
-            calculate_hurst_exponent(&prices_vec, &[2, 4, 8, 16])
+impl FeatureEngineeringService for TechnicalFeatureEngineeringService {
+    fn update(&mut self, candle: &Candle) -> FeatureSet {
+        let IndicatorValues {
+            price,
+            rsi: rsi_val,
+            macd: macd_val,
+            bb: bb_val,
+            atr: atr_val,
+            sma_20,
+            sma_50,
+            sma_200,
+            ema_fast,
+            ema_slow,
+            adx: adx_val,
+        } = self.advance(candle);
+
+        // Convert to f64 only for statistical library boundaries
+        let full_history: Vec<f64> = price_history_to_f64(&self.price_history);
+        let prices_vec = &full_history[full_history.len().saturating_sub(STATS_HISTORY_LEN)..];
+
+        // Hurst Exponent over the configured lookback (None until the window is full)
+        let hurst_exponent = if full_history.len() >= self.hurst_lookback {
+            let window = &full_history[full_history.len() - self.hurst_lookback..];
+            let lags: Vec<usize> = [2, 4, 8, 16, 32]
+                .into_iter()
+                .filter(|lag| lag * 2 <= window.len())
+                .collect();
+            calculate_hurst_exponent(window, &lags).filter(|h| h.is_finite())
         } else {
             None
         };
@@ -246,7 +416,7 @@ impl FeatureEngineeringService for TechnicalFeatureEngineeringService {
 
         // Realized Volatility
         let realized_volatility = if prices_vec.len() >= 20 {
-            calculate_realized_volatility(&prices_vec, 525600.0) // 1 minute candles -> 525600 minutes/year
+            calculate_realized_volatility(prices_vec, 525600.0) // 1 minute candles -> 525600 minutes/year
         } else {
             None
         };
@@ -261,17 +431,21 @@ impl FeatureEngineeringService for TechnicalFeatureEngineeringService {
             None
         };
 
-        let bb_width = if bb_val.average > 0.0 {
-            (bb_val.upper - bb_val.lower) / bb_val.average
-        } else {
-            0.0
-        };
+        let bb_width = bb_val.as_ref().map(|bb| {
+            if bb.average > 0.0 {
+                (bb.upper - bb.lower) / bb.average
+            } else {
+                0.0
+            }
+        });
 
-        let bb_position = if bb_val.upper - bb_val.lower > 1e-9 {
-            (price - bb_val.lower) / (bb_val.upper - bb_val.lower)
-        } else {
-            0.5
-        };
+        let bb_position = bb_val.as_ref().map(|bb| {
+            if bb.upper - bb.lower > 1e-9 {
+                (price - bb.lower) / (bb.upper - bb.lower)
+            } else {
+                0.5
+            }
+        });
 
         let atr_pct = if price > 0.0 { atr_val / price } else { 0.0 };
 
@@ -282,21 +456,23 @@ impl FeatureEngineeringService for TechnicalFeatureEngineeringService {
         FeatureSet {
             last_price: to_dec(price),
             rsi: to_dec(rsi_val),
-            macd_line: to_dec(macd_val.macd),
-            macd_signal: to_dec(macd_val.signal),
-            macd_hist: to_dec(macd_val.histogram),
-            sma_20: to_dec(self.sma_20.next(price)),
-            sma_50: to_dec(self.sma_50.next(price)),
-            sma_200: to_dec(self.sma_200.next(price)),
-            bb_upper: to_dec(bb_val.upper),
-            bb_middle: to_dec(bb_val.average),
-            bb_lower: to_dec(bb_val.lower),
+            macd_line: to_dec_opt(macd_val.as_ref().map(|m| m.macd)),
+            macd_signal: to_dec_opt(macd_val.as_ref().map(|m| m.signal)),
+            macd_hist: to_dec_opt(macd_val.as_ref().map(|m| m.histogram)),
+            sma_20: to_dec(sma_20),
+            sma_50: to_dec(sma_50),
+            sma_200: to_dec(sma_200),
+            bb_upper: to_dec_opt(bb_val.as_ref().map(|bb| bb.upper)),
+            bb_middle: to_dec_opt(bb_val.as_ref().map(|bb| bb.average)),
+            bb_lower: to_dec_opt(bb_val.as_ref().map(|bb| bb.lower)),
             atr: to_dec(atr_val),
-            ema_fast: to_dec(self.ema_fast.next(price)),
-            ema_slow: to_dec(self.ema_slow.next(price)),
-            adx: to_dec(self.adx.next(high, low, price)),
-            bb_width: to_dec(bb_width),
-            bb_position: to_dec(bb_position),
+            ema_fast: to_dec(ema_fast),
+            ema_slow: to_dec(ema_slow),
+            adx: to_dec_opt(adx_val),
+            plus_di: to_dec_opt(adx_val.map(|_| self.adx.plus_di())),
+            minus_di: to_dec_opt(adx_val.map(|_| self.adx.minus_di())),
+            bb_width: to_dec_opt(bb_width),
+            bb_position: to_dec_opt(bb_position),
             atr_pct: to_dec(atr_pct),
 
             // Advanced Statistical Features (Phase 2)
@@ -309,6 +485,15 @@ impl FeatureEngineeringService for TechnicalFeatureEngineeringService {
             ..Default::default()
         }
     }
+
+    /// Runs the streaming indicators over the whole window in one pass; the statistical
+    /// features (Hurst, skewness, volatility) and the Decimal feature set are skipped,
+    /// since only their value on the last bar matters and it is rebuilt by the next update.
+    fn prime(&mut self, candles: &[Candle]) {
+        for candle in candles {
+            self.advance(candle);
+        }
+    }
 }
 
 #[cfg(test)]
@@ -441,6 +626,23 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_directional_indicators_follow_trend() {
+        let config = AnalystConfig::default();
+        let mut up = TechnicalFeatureEngineeringService::new(&config);
+        let mut down = TechnicalFeatureEngineeringService::new(&config);
+
+        let mut up_features = FeatureSet::default();
+        let mut down_features = FeatureSet::default();
+        for i in 0..40 {
+            up_features = up.update(&create_trending_candle(100.0 + i as f64, 0.5));
+            down_features = down.update(&create_trending_candle(200.0 - i as f64, 0.5));
+        }
+
+        assert!(up_features.plus_di.unwrap() > up_features.minus_di.unwrap());
+        assert!(down_features.minus_di.unwrap() > down_features.plus_di.unwrap());
+    }
+
     #[test]
     fn test_momentum_normalized_positive_uptrend() {
         let config = AnalystConfig::default();
@@ -472,4 +674,150 @@ mod tests {
         assert!(features.realized_volatility.is_none());
         assert!(features.momentum_normalized.is_none());
     }
+
+    #[test]
+    fn test_hurst_respects_configured_lookback() {
+        let config = AnalystConfig {
+            hurst_lookback: 30,
+            ..AnalystConfig::default()
+        };
+        let mut service = TechnicalFeatureEngineeringService::new(&config);
+
+        let mut features = FeatureSet::default();
+        for i in 0..29 {
+            features = service.update(&create_test_candle(100.0 + (i % 5) as f64));
+        }
+        assert!(features.hurst_exponent.is_none(), "window not full yet");
+
+        features = service.update(&create_test_candle(101.0));
+        let h = features.hurst_exponent.expect("Hurst after 30 prices");
+        assert!(h >= Decimal::ZERO && h <= Decimal::ONE);
+    }
+
+    #[test]
+    fn test_hurst_none_for_flat_prices() {
+        let config = AnalystConfig {
+            hurst_lookback: 20,
+            ..AnalystConfig::default()
+        };
+        let mut service = TechnicalFeatureEngineeringService::new(&config);
+
+        let mut features = FeatureSet::default();
+        for _ in 0..40 {
+            features = service.update(&create_test_candle(100.0));
+        }
+        // Zero variance must not leak NaN into the feature set
+        assert!(features.hurst_exponent.is_none());
+    }
+
+    #[test]
+    fn test_skips_unrequired_indicator_groups() {
+        let config = AnalystConfig::default();
+        let mut service = TechnicalFeatureEngineeringService::new(&config).with_requirements(
+            FeatureRequirements {
+                bollinger: true,
+                ..FeatureRequirements::CORE
+            },
+        );
+
+        let mut features = FeatureSet::default();
+        for i in 0..40 {
+            features = service.update(&create_trending_candle(100.0 + i as f64, 0.5));
+        }
+
+        assert!(features.sma_20.is_some() && features.rsi.is_some() && features.atr.is_some());
+        assert!(features.bb_middle.is_some() && features.bb_width.is_some());
+        assert!(features.macd_hist.is_none());
+        assert!(features.adx.is_none() && features.plus_di.is_none());
+    }
+
+    #[test]
+    fn test_prime_matches_incremental_updates() {
+        let config = AnalystConfig::default();
+        let candles: Vec<Candle> = (0..400)
+            .map(|i| {
+                let price = 100.0 + (i as f64 * 0.3).sin() * 5.0 + i as f64 * 0.05;
+                create_trending_candle(price, 0.4 + (i % 7) as f64 * 0.1)
+            })
+            .collect();
+        let (warmup, live) = candles.split_at(300);
+
+        let mut incremental = TechnicalFeatureEngineeringService::new(&config);
+        for candle in warmup {
+            incremental.update(candle);
+        }
+        let mut bulk = TechnicalFeatureEngineeringService::new(&config);
+        bulk.prime(warmup);
+
+        for candle in live {
+            let expected = incremental.update(candle);
+            let actual = bulk.update(candle);
+            assert_eq!(
+                serde_json::to_string(&actual).unwrap(),
+                serde_json::to_string(&expected).unwrap()
+            );
+        }
+    }
+
+    /// Wilder average recomputed from scratch over the whole series
+    fn full_window_wilder(values: &[f64], period: usize) -> f64 {
+        let seed: f64 = values[..period].iter().sum::<f64>() / period as f64;
+        values[period..].iter().fold(seed, |avg, v| {
+            (avg * (period as f64 - 1.0) + v) / period as f64
+        })
+    }
+
+    #[test]
+    fn test_wilder_incremental_matches_full_window() {
+        let period = 14;
+        let bars: Vec<(f64, f64, f64)> = (0..200)
+            .map(|i| {
+                let close = 100.0 + (i as f64 * 0.37).sin() * 4.0 + i as f64 * 0.02;
+                let range = 0.5 + (i % 5) as f64 * 0.2;
+                (close + range, close - range, close)
+            })
+            .collect();
+
+        let mut rsi = WilderRsi::new(period);
+        let mut atr = WilderAtr::new(period);
+        let (mut gains, mut losses, mut trs) = (Vec::new(), Vec::new(), Vec::new());
+        for (i, &(high, low, close)) in bars.iter().enumerate() {
+            let rsi_val = rsi.next(close);
+            let atr_val = atr.next(high, low, close);
+
+            trs.push(if i == 0 {
+                high - low
+            } else {
+                let prev = bars[i - 1].2;
+                (high - low)
+                    .max((high - prev).abs())
+                    .max((low - prev).abs())
+            });
+            if i > 0 {
+                let change = close - bars[i - 1].2;
+                gains.push(change.max(0.0));
+                losses.push((-change).max(0.0));
+            }
+
+            if trs.len() >= period {
+                assert!((atr_val - full_window_wilder(&trs, period)).abs() < 1e-9);
+            }
+            if gains.len() >= period {
+                let avg_gain = full_window_wilder(&gains, period);
+                let avg_loss = full_window_wilder(&losses, period);
+                let expected = 100.0 - 100.0 / (1.0 + avg_gain / avg_loss);
+                assert!((rsi_val - expected).abs() < 1e-9);
+            }
+        }
+    }
+
+    #[test]
+    fn test_wilder_rsi_bounds() {
+        let mut rsi = WilderRsi::new(14);
+        // Neutral until 14 price changes are in
+        for i in 0..14 {
+            assert_eq!(rsi.next(100.0 + i as f64), 50.0);
+        }
+        assert_eq!(rsi.next(114.0), 100.0);
+    }
 }
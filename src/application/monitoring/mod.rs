@@ -1,5 +1,6 @@
 // Performance monitoring and feature engineering modules
 pub mod agent_status;
+pub mod channel_monitor;
 pub mod connection_health_service;
 pub mod correlation_service;
 pub mod cost_evaluator;
@@ -1,10 +1,11 @@
-use crate::domain::market::market_regime::MarketRegimeDetector;
+use crate::domain::market::market_regime::{MarketRegimeDetector, RegimeDetectionMethod};
 use crate::domain::performance::calculator;
 use crate::domain::performance::performance_snapshot::PerformanceSnapshot;
 use crate::domain::ports::MarketDataService;
 use crate::domain::repositories::TradeRepository;
 use crate::domain::repositories::{CandleRepository, PerformanceSnapshotRepository};
 use crate::domain::trading::portfolio::Portfolio;
+use crate::domain::trading::time::{MS_PER_DAY, now_ms};
 use crate::domain::trading::types::Order;
 use anyhow::Result;
 use rust_decimal::prelude::ToPrimitive;
@@ -32,12 +33,14 @@ impl PerformanceMonitoringService {
         portfolio: Arc<RwLock<Portfolio>>,
         trade_repository: Arc<dyn TradeRepository>,
         regime_window_size: usize,
+        regime_method: RegimeDetectionMethod,
     ) -> Self {
         Self {
             snapshot_repository,
             candle_repository,
             market_service,
-            regime_detector: MarketRegimeDetector::new(regime_window_size, dec!(25.0), dec!(2.0)), // Defaults, should come from config
+            regime_detector: MarketRegimeDetector::new(regime_window_size, dec!(25.0), dec!(2.0)) // Defaults, should come from config
+                .with_method(regime_method),
             portfolio,
             trade_repository,
         }
@@ -70,8 +73,8 @@ impl PerformanceMonitoringService {
         };
 
         // Detect current regime
-        let end_ts = chrono::Utc::now().timestamp();
-        let start_ts = end_ts - (30 * 24 * 60 * 60); // Last 30 days for candle data if needed, or just enough for window
+        let end_ts = now_ms();
+        let start_ts = end_ts - 30 * MS_PER_DAY; // Last 30 days for candle data if needed, or just enough for window
 
         // We need enough candles for the detector window
         // This is a bit inefficient to fetch every time, in prod we'd cache recent candles
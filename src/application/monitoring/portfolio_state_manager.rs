@@ -1,10 +1,10 @@
 use crate::domain::ports::ExecutionService;
 use crate::domain::trading::portfolio::Portfolio;
 use rust_decimal::Decimal;
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 use std::sync::Arc;
 use tokio::sync::RwLock;
-use tracing::{info, warn};
+use tracing::{debug, info, warn};
 
 /// Versioned portfolio snapshot with timestamp and reserved exposure tracking
 #[derive(Debug, Clone)]
@@ -48,6 +48,26 @@ impl ReservationToken {
     }
 }
 
+/// Quantity mismatch for one symbol between the local portfolio and the broker
+#[derive(Debug, Clone, PartialEq)]
+pub struct PositionDivergence {
+    pub symbol: String,
+    pub local_quantity: Decimal,
+    pub broker_quantity: Decimal,
+    /// Absolute quantity difference valued at the position's average price
+    pub value: Decimal,
+}
+
+/// Outcome of a reconciliation pass
+#[derive(Debug, Clone, Default)]
+pub struct ReconciliationReport {
+    pub divergences: Vec<PositionDivergence>,
+    /// Total value of all divergences
+    pub divergence_value: Decimal,
+    /// Whether the local positions were overwritten with the broker's
+    pub corrected: bool,
+}
+
 /// Manages portfolio state with versioning and staleness detection
 ///
 /// This service provides:
@@ -165,6 +185,94 @@ impl PortfolioStateManager {
         Ok(state.clone())
     }
 
+    /// Compare local positions with the broker's and optionally correct them
+    ///
+    /// Fetches the broker portfolio (refreshing the snapshot), logs every symbol whose
+    /// quantity differs from `local` and, when `correct` is set, replaces the diverging
+    /// local positions with the broker's. Cash is left untouched.
+    ///
+    /// Symbols with working orders at the broker are skipped: their local quantity
+    /// already reflects the optimistic fill and the broker's will catch up.
+    pub async fn reconcile_positions(
+        &self,
+        local: &RwLock<Portfolio>,
+        correct: bool,
+    ) -> anyhow::Result<ReconciliationReport> {
+        let in_flight: HashSet<String> = self
+            .execution_service
+            .get_open_orders()
+            .await?
+            .into_iter()
+            .map(|order| order.symbol)
+            .collect();
+        let broker = self.refresh().await?.portfolio;
+        let mut local = local.write().await;
+
+        let mut symbols: Vec<&String> = local
+            .positions
+            .keys()
+            .chain(broker.positions.keys())
+            .collect();
+        symbols.sort();
+        symbols.dedup();
+
+        let mut report = ReconciliationReport::default();
+        for symbol in symbols {
+            if in_flight.contains(symbol) {
+                debug!(
+                    "PortfolioStateManager: Skipping {} reconciliation - order in flight",
+                    symbol
+                );
+                continue;
+            }
+            let local_pos = local.positions.get(symbol);
+            let broker_pos = broker.positions.get(symbol);
+            let local_quantity = local_pos.map_or(Decimal::ZERO, |p| p.quantity);
+            let broker_quantity = broker_pos.map_or(Decimal::ZERO, |p| p.quantity);
+            if local_quantity == broker_quantity {
+                continue;
+            }
+
+            let price = broker_pos
+                .or(local_pos)
+                .map_or(Decimal::ZERO, |p| p.average_price);
+            let value = (broker_quantity - local_quantity).abs() * price;
+            warn!(
+                "PortfolioStateManager: Position mismatch for {}: local {} vs broker {} (${})",
+                symbol, local_quantity, broker_quantity, value
+            );
+            report.divergence_value += value;
+            report.divergences.push(PositionDivergence {
+                symbol: symbol.clone(),
+                local_quantity,
+                broker_quantity,
+                value,
+            });
+        }
+
+        if correct && !report.divergences.is_empty() {
+            for divergence in &report.divergences {
+                match broker.positions.get(&divergence.symbol) {
+                    Some(position) => {
+                        local
+                            .positions
+                            .insert(divergence.symbol.clone(), position.clone());
+                    }
+                    None => {
+                        local.positions.remove(&divergence.symbol);
+                    }
+                }
+            }
+            report.corrected = true;
+            info!(
+                "PortfolioStateManager: Corrected {} local position(s) from broker",
+                report.divergences.len()
+            );
+        }
+
+        Ok(report)
+    }
+
     /// Reserve exposure for a pending trade with optimistic locking
     ///
     /// # Arguments
@@ -266,11 +374,13 @@ impl PortfolioStateManager {
 mod tests {
     use super::*;
     use crate::domain::ports::OrderUpdate;
+    use crate::domain::trading::portfolio::Position;
     use async_trait::async_trait;
     use rust_decimal_macros::dec;
 
     struct MockExecutionService {
         portfolio: Arc<RwLock<Portfolio>>,
+        open_orders: Vec<crate::domain::trading::types::Order>,
     }
 
     #[async_trait]
@@ -296,7 +406,7 @@ mod tests {
         async fn get_open_orders(
             &self,
         ) -> anyhow::Result<Vec<crate::domain::trading::types::Order>> {
-            Ok(Vec::new())
+            Ok(self.open_orders.clone())
         }
 
         async fn cancel_order(&self, _order_id: &str, _symbol: &str) -> anyhow::Result<()> {
@@ -322,6 +432,7 @@ mod tests {
 
         let mock_service = Arc::new(MockExecutionService {
             portfolio: Arc::new(RwLock::new(portfolio)),
+            open_orders: Vec::new(),
         });
 
         let manager = PortfolioStateManager::new(mock_service, 5000);
@@ -343,6 +454,7 @@ mod tests {
         let portfolio = Portfolio::new();
         let mock_service = Arc::new(MockExecutionService {
             portfolio: Arc::new(RwLock::new(portfolio)),
+            open_orders: Vec::new(),
         });
 
         let manager = PortfolioStateManager::new(mock_service, 100); // 100ms threshold
@@ -363,6 +475,7 @@ mod tests {
 
         let mock_service = Arc::new(MockExecutionService {
             portfolio: Arc::new(RwLock::new(portfolio)),
+            open_orders: Vec::new(),
         });
 
         let manager = PortfolioStateManager::new(mock_service, 5000);
@@ -390,6 +503,7 @@ mod tests {
 
         let mock_service = Arc::new(MockExecutionService {
             portfolio: Arc::new(RwLock::new(portfolio)),
+            open_orders: Vec::new(),
         });
 
         let manager = PortfolioStateManager::new(mock_service, 5000);
@@ -414,6 +528,7 @@ mod tests {
 
         let mock_service = Arc::new(MockExecutionService {
             portfolio: Arc::new(RwLock::new(portfolio)),
+            open_orders: Vec::new(),
         });
 
         let manager = PortfolioStateManager::new(mock_service, 5000);
@@ -439,6 +554,7 @@ mod tests {
 
         let mock_service = Arc::new(MockExecutionService {
             portfolio: Arc::new(RwLock::new(portfolio)),
+            open_orders: Vec::new(),
         });
 
         let manager = PortfolioStateManager::new(mock_service, 5000);
@@ -466,6 +582,7 @@ mod tests {
 
         let mock_service = Arc::new(MockExecutionService {
             portfolio: Arc::new(RwLock::new(portfolio)),
+            open_orders: Vec::new(),
         });
 
         let manager = Arc::new(PortfolioStateManager::new(mock_service, 5000));
@@ -509,6 +626,7 @@ mod tests {
 
         let mock_service = Arc::new(MockExecutionService {
             portfolio: Arc::new(RwLock::new(portfolio)),
+            open_orders: Vec::new(),
         });
 
         let manager = PortfolioStateManager::new(mock_service, 5000);
@@ -529,4 +647,114 @@ mod tests {
         let snapshot2 = manager.get_snapshot().await;
         assert_eq!(snapshot2.available_cash(), dec!(7000));
     }
+
+    fn position(symbol: &str, quantity: Decimal, average_price: Decimal) -> Position {
+        Position {
+            symbol: symbol.to_string(),
+            quantity,
+            average_price,
+            lots: Default::default(),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_reconcile_reports_divergence() {
+        let mut broker = Portfolio::new();
+        broker
+            .positions
+            .insert("AAPL".to_string(), position("AAPL", dec!(10), dec!(150)));
+        broker
+            .positions
+            .insert("MSFT".to_string(), position("MSFT", dec!(5), dec!(300)));
+
+        let mut local = Portfolio::new();
+        local
+            .positions
+            .insert("AAPL".to_string(), position("AAPL", dec!(8), dec!(150)));
+        local
+            .positions
+            .insert("TSLA".to_string(), position("TSLA", dec!(2), dec!(200)));
+        let local = RwLock::new(local);
+
+        let mock_service = Arc::new(MockExecutionService {
+            portfolio: Arc::new(RwLock::new(broker)),
+            open_orders: Vec::new(),
+        });
+        let manager = PortfolioStateManager::new(mock_service, 5000);
+
+        let report = manager.reconcile_positions(&local, false).await.unwrap();
+
+        assert_eq!(report.divergences.len(), 3);
+        assert_eq!(report.divergences[0].symbol, "AAPL");
+        assert_eq!(report.divergences[0].value, dec!(300));
+        // 300 (AAPL) + 1500 (MSFT missing locally) + 400 (TSLA missing at broker)
+        assert_eq!(report.divergence_value, dec!(2200));
+        assert!(!report.corrected);
+        assert_eq!(local.read().await.positions["AAPL"].quantity, dec!(8));
+    }
+
+    #[tokio::test]
+    async fn test_reconcile_corrects_local_positions() {
+        let mut broker = Portfolio::new();
+        broker
+            .positions
+            .insert("AAPL".to_string(), position("AAPL", dec!(10), dec!(150)));
+
+        let mut local = Portfolio::new();
+        local.cash = dec!(1000);
+        local
+            .positions
+            .insert("TSLA".to_string(), position("TSLA", dec!(2), dec!(200)));
+        let local = RwLock::new(local);
+
+        let mock_service = Arc::new(MockExecutionService {
+            portfolio: Arc::new(RwLock::new(broker)),
+            open_orders: Vec::new(),
+        });
+        let manager = PortfolioStateManager::new(mock_service, 5000);
+
+        let report = manager.reconcile_positions(&local, true).await.unwrap();
+        assert!(report.corrected);
+
+        let local = local.read().await;
+        assert_eq!(local.positions.len(), 1);
+        assert_eq!(local.positions["AAPL"].quantity, dec!(10));
+        assert_eq!(local.cash, dec!(1000));
+    }
+
+    #[tokio::test]
+    async fn test_reconcile_skips_symbols_with_orders_in_flight() {
+        let broker = Portfolio::new();
+
+        // Optimistic fill applied locally while the order is still working at the broker
+        let mut local = Portfolio::new();
+        local
+            .positions
+            .insert("AAPL".to_string(), position("AAPL", dec!(10), dec!(150)));
+        let local = RwLock::new(local);
+
+        let working = crate::domain::trading::types::Order {
+            id: "o1".to_string(),
+            symbol: "AAPL".to_string(),
+            side: crate::domain::trading::types::OrderSide::Buy,
+            price: dec!(150),
+            quantity: dec!(10),
+            order_type: crate::domain::trading::types::OrderType::Limit,
+            status: crate::domain::trading::types::OrderStatus::New,
+            timestamp: 0,
+            reduce_only: false,
+            origin: Default::default(),
+            reason: None,
+        };
+        let mock_service = Arc::new(MockExecutionService {
+            portfolio: Arc::new(RwLock::new(broker)),
+            open_orders: vec![working],
+        });
+        let manager = PortfolioStateManager::new(mock_service, 5000);
+
+        let report = manager.reconcile_positions(&local, true).await.unwrap();
+        assert!(report.divergences.is_empty());
+        assert!(!report.corrected);
+        assert_eq!(local.read().await.positions["AAPL"].quantity, dec!(10));
+    }
 }
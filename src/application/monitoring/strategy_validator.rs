@@ -333,6 +333,9 @@ mod tests {
                 order_type: OrderType::Market,
                 status: crate::domain::trading::types::OrderStatus::Filled,
                 timestamp: 0,
+                reduce_only: false,
+                origin: Default::default(),
+                reason: None,
             },
             Order {
                 id: uuid::Uuid::new_v4().to_string(),
@@ -343,6 +346,9 @@ mod tests {
                 order_type: OrderType::Market,
                 status: crate::domain::trading::types::OrderStatus::Filled,
                 timestamp: 1000,
+                reduce_only: false,
+                origin: Default::default(),
+                reason: None,
             },
         ]
     }
@@ -358,6 +364,9 @@ mod tests {
                 order_type: OrderType::Market,
                 status: crate::domain::trading::types::OrderStatus::Filled,
                 timestamp: 0,
+                reduce_only: false,
+                origin: Default::default(),
+                reason: None,
             },
             Order {
                 id: uuid::Uuid::new_v4().to_string(),
@@ -368,6 +377,9 @@ mod tests {
                 order_type: OrderType::Market,
                 status: crate::domain::trading::types::OrderStatus::Filled,
                 timestamp: 1000,
+                reduce_only: false,
+                origin: Default::default(),
+                reason: None,
             },
         ]
     }
@@ -0,0 +1,272 @@
+//! A/B comparison of two strategy configurations on identical historical data.
+//!
+//! Both configs are replayed through the [`Simulator`] on the same prefetched bars, each
+//! with a fresh execution service, and summarized side by side. Per-trade PnL is compared
+//! with a bootstrap test so a "better" variant can be told apart from noise.
+
+use crate::application::agents::analyst_config::AnalystConfig;
+use crate::application::optimization::optimizer::{
+    OptimizationResult, backtest_result_to_opt_result_impl,
+};
+use crate::application::optimization::simulator::{BacktestResult, Simulator};
+use crate::domain::performance::stats::Stats;
+use crate::domain::ports::{ExecutionService, MarketDataService};
+use crate::domain::trading::types::Candle;
+use anyhow::Result;
+use chrono::{DateTime, Utc};
+use rust_decimal::Decimal;
+use std::sync::Arc;
+
+const DEFAULT_BOOTSTRAP_ITERATIONS: usize = 2000;
+const BOOTSTRAP_SEED: u64 = 42;
+
+/// Backtest summary of one variant
+#[derive(Debug, Clone)]
+pub struct VariantResult {
+    pub summary: OptimizationResult,
+    pub trade_pnls: Vec<Decimal>,
+}
+
+impl VariantResult {
+    pub fn from_backtest(config: AnalystConfig, result: BacktestResult) -> Self {
+        let trade_pnls = result
+            .round_trip_trades()
+            .into_iter()
+            .map(|t| t.pnl)
+            .collect();
+        Self {
+            summary: backtest_result_to_opt_result_impl(config, result),
+            trade_pnls,
+        }
+    }
+
+    pub fn mean_trade_pnl(&self) -> Decimal {
+        if self.trade_pnls.is_empty() {
+            return Decimal::ZERO;
+        }
+        self.trade_pnls.iter().sum::<Decimal>() / Decimal::from(self.trade_pnls.len())
+    }
+}
+
+/// Side-by-side comparison of variant A (baseline) and B (candidate)
+#[derive(Debug, Clone)]
+pub struct AbComparison {
+    pub a: VariantResult,
+    pub b: VariantResult,
+    /// Bootstrap p-value for a difference in mean per-trade PnL.
+    /// None when either variant closed fewer than 2 trades.
+    pub p_value: Option<f64>,
+}
+
+impl AbComparison {
+    pub fn new(a: VariantResult, b: VariantResult, bootstrap_iterations: usize) -> Self {
+        let p_value = Stats::bootstrap_mean_diff_p_value(
+            &a.trade_pnls,
+            &b.trade_pnls,
+            bootstrap_iterations,
+            BOOTSTRAP_SEED,
+        );
+        Self { a, b, p_value }
+    }
+
+    /// B return minus A return (percentage points)
+    pub fn return_diff(&self) -> Decimal {
+        self.b.summary.total_return - self.a.summary.total_return
+    }
+
+    /// B Sharpe minus A Sharpe
+    pub fn sharpe_diff(&self) -> Decimal {
+        self.b.summary.sharpe_ratio - self.a.summary.sharpe_ratio
+    }
+
+    /// True when B's mean trade PnL beats A's and the difference is significant at `alpha`
+    pub fn b_significantly_better(&self, alpha: f64) -> bool {
+        self.b.mean_trade_pnl() > self.a.mean_trade_pnl() && self.p_value.is_some_and(|p| p < alpha)
+    }
+
+    /// Print a formatted side-by-side table
+    pub fn print_summary(&self) {
+        let row = |label: &str, a: String, b: String| {
+            println!("{:<22} {:>16} {:>16}", label, a, b);
+        };
+        println!("{}", "=".repeat(56));
+        println!("🆚 A/B COMPARISON");
+        println!("{}", "=".repeat(56));
+        row(
+            "Strategy",
+            format!("{}", self.a.summary.params.strategy_mode),
+            format!("{}", self.b.summary.params.strategy_mode),
+        );
+        row(
+            "Total Return",
+            format!("{:.2}%", self.a.summary.total_return),
+            format!("{:.2}%", self.b.summary.total_return),
+        );
+        row(
+            "Sharpe",
+            format!("{:.2}", self.a.summary.sharpe_ratio),
+            format!("{:.2}", self.b.summary.sharpe_ratio),
+        );
+        row(
+            "Max Drawdown",
+            format!("{:.2}%", self.a.summary.max_drawdown),
+            format!("{:.2}%", self.b.summary.max_drawdown),
+        );
+        row(
+            "Trades",
+            self.a.summary.total_trades.to_string(),
+            self.b.summary.total_trades.to_string(),
+        );
+        row(
+            "Mean Trade PnL",
+            format!("${:.2}", self.a.mean_trade_pnl()),
+            format!("${:.2}", self.b.mean_trade_pnl()),
+        );
+        match self.p_value {
+            Some(p) => println!("Bootstrap p-value (mean trade PnL): {:.4}", p),
+            None => println!("Bootstrap p-value: n/a (fewer than 2 trades)"),
+        }
+        println!("{}", "=".repeat(56));
+    }
+}
+
+/// Runs two configs through the simulator on the same bars
+pub struct AbTestHarness {
+    market_data: Arc<dyn MarketDataService>,
+    execution_service_factory: Arc<dyn Fn() -> Arc<dyn ExecutionService> + Send + Sync>,
+    spy_bars: Option<Vec<Candle>>,
+    bootstrap_iterations: usize,
+}
+
+impl AbTestHarness {
+    pub fn new(
+        market_data: Arc<dyn MarketDataService>,
+        execution_service_factory: Arc<dyn Fn() -> Arc<dyn ExecutionService> + Send + Sync>,
+    ) -> Self {
+        Self {
+            market_data,
+            execution_service_factory,
+            spy_bars: None,
+            bootstrap_iterations: DEFAULT_BOOTSTRAP_ITERATIONS,
+        }
+    }
+
+    /// Reuse prefetched benchmark bars for alpha/beta (otherwise SPY is fetched per run)
+    pub fn with_benchmark_bars(mut self, spy_bars: Vec<Candle>) -> Self {
+        self.spy_bars = Some(spy_bars);
+        self
+    }
+
+    pub fn with_bootstrap_iterations(mut self, iterations: usize) -> Self {
+        self.bootstrap_iterations = iterations;
+        self
+    }
+
+    /// Backtest `config_a` (baseline) and `config_b` (candidate) on identical `bars`
+    pub async fn compare(
+        &self,
+        symbol: &str,
+        bars: &[Candle],
+        start: DateTime<Utc>,
+        end: DateTime<Utc>,
+        config_a: AnalystConfig,
+        config_b: AnalystConfig,
+    ) -> Result<AbComparison> {
+        let a = self.run_variant(symbol, bars, start, end, config_a).await?;
+        let b = self.run_variant(symbol, bars, start, end, config_b).await?;
+        Ok(AbComparison::new(a, b, self.bootstrap_iterations))
+    }
+
+    async fn run_variant(
+        &self,
+        symbol: &str,
+        bars: &[Candle],
+        start: DateTime<Utc>,
+        end: DateTime<Utc>,
+        config: AnalystConfig,
+    ) -> Result<VariantResult> {
+        let sim = Simulator::new(
+            self.market_data.clone(),
+            (self.execution_service_factory)(),
+            config.clone(),
+        );
+        let result = sim
+            .run_with_bars(symbol, bars, start, end, self.spy_bars.clone())
+            .await?;
+        Ok(VariantResult::from_backtest(config, result))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::trading::types::{Order, OrderSide, OrderStatus, OrderType};
+    use rust_decimal_macros::dec;
+
+    fn round_trips(pnls: &[Decimal]) -> BacktestResult {
+        let order = |side, price, ts| Order {
+            id: uuid::Uuid::new_v4().to_string(),
+            symbol: "AAPL".to_string(),
+            side,
+            price,
+            quantity: Decimal::ONE,
+            order_type: OrderType::Market,
+            status: OrderStatus::Filled,
+            timestamp: ts,
+            reduce_only: false,
+            origin: Default::default(),
+            reason: None,
+        };
+        let trades = pnls
+            .iter()
+            .enumerate()
+            .flat_map(|(i, pnl)| {
+                let ts = i as i64 * 2;
+                [
+                    order(OrderSide::Buy, dec!(100), ts),
+                    order(OrderSide::Sell, dec!(100) + pnl, ts + 1),
+                ]
+            })
+            .collect();
+        BacktestResult {
+            trades,
+            fill_costs: vec![],
+            initial_equity: dec!(10000),
+            final_equity: dec!(10000) + pnls.iter().sum::<Decimal>(),
+            total_return_pct: pnls.iter().sum::<Decimal>() / dec!(100),
+            buy_and_hold_return_pct: Decimal::ZERO,
+            daily_closes: vec![],
+            alpha: 0.0,
+            beta: 0.0,
+            benchmark_correlation: 0.0,
+        }
+    }
+
+    #[test]
+    fn test_ab_comparison_detects_better_variant() {
+        let losing: Vec<Decimal> = (0..20).map(|i| Decimal::from(-2 + i % 3)).collect();
+        let winning: Vec<Decimal> = (0..20).map(|i| Decimal::from(5 + i % 3)).collect();
+
+        let cmp = AbComparison::new(
+            VariantResult::from_backtest(AnalystConfig::default(), round_trips(&losing)),
+            VariantResult::from_backtest(AnalystConfig::default(), round_trips(&winning)),
+            1000,
+        );
+
+        assert_eq!(cmp.a.trade_pnls.len(), 20);
+        assert!(cmp.b.mean_trade_pnl() > cmp.a.mean_trade_pnl());
+        assert!(cmp.return_diff() > Decimal::ZERO);
+        assert!(cmp.b_significantly_better(0.05));
+    }
+
+    #[test]
+    fn test_ab_comparison_too_few_trades_has_no_p_value() {
+        let cmp = AbComparison::new(
+            VariantResult::from_backtest(AnalystConfig::default(), round_trips(&[dec!(1)])),
+            VariantResult::from_backtest(AnalystConfig::default(), round_trips(&[dec!(3)])),
+            1000,
+        );
+        assert_eq!(cmp.p_value, None);
+        assert!(!cmp.b_significantly_better(0.05));
+    }
+}
@@ -1,30 +1,70 @@
-use crate::application::optimization::optimizer::GridSearchOptimizer;
-use crate::domain::market::market_regime::{MarketRegimeDetector, MarketRegimeType};
+use crate::application::agents::analyst::AnalystCommand;
+use crate::application::agents::analyst_config::{AnalystConfig, TunedParams};
+use crate::application::optimization::optimizer::{GridSearchOptimizer, OptimizationResult};
+use crate::application::optimization::parameter_guardrails::ParameterGuardrails;
+use crate::domain::market::market_regime::{
+    MarketRegimeDetector, MarketRegimeType, RegimeDetectionMethod,
+};
 use crate::domain::market::strategy_config::{StrategyDefinition, StrategyMode};
+use crate::domain::market::timeframe::Timeframe;
 use crate::domain::optimization::optimization_history::OptimizationHistory;
 use crate::domain::optimization::reoptimization_trigger::{ReoptimizationTrigger, TriggerReason};
+use crate::domain::optimization::shadow_trial::ShadowTrial;
 use crate::domain::performance::performance_evaluator::PerformanceEvaluator;
+use crate::domain::performance::performance_snapshot::PerformanceSnapshot;
 use crate::domain::repositories::{
     CandleRepository, OptimizationHistoryRepository, PerformanceSnapshotRepository,
-    ReoptimizationTriggerRepository, StrategyRepository,
+    ReoptimizationTriggerRepository, ShadowTrialRepository, StrategyRepository,
 };
+use crate::domain::trading::time::{MS_PER_DAY, now_ms};
 use anyhow::Result;
 use chrono::{Duration, Utc};
 use rust_decimal::Decimal;
 use rust_decimal_macros::dec;
-use std::sync::Arc;
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+use tokio::sync::{RwLock, mpsc};
 use tracing::{error, info, warn};
 
+/// Share of the lookback window used for training; the rest scores the candidates
+const TRAIN_RATIO: f64 = 0.70;
+
+/// Live analyst the re-tuned parameters are pushed to
+struct AnalystLink {
+    cmd_tx: mpsc::Sender<AnalystCommand>,
+    /// Default config the analyst is running (kept current by the analyst)
+    live_config: Arc<RwLock<AnalystConfig>>,
+    /// Per-symbol parameters the analyst is currently running on top of it, as last sent
+    symbol_params: HashMap<String, TunedParams>,
+}
+
+/// What an applied update replaced, kept until the next evaluation judges it
+struct PendingReview {
+    previous_strategy: Option<StrategyDefinition>,
+    previous_live: Option<AnalystConfig>,
+    /// Live performance when the update went out
+    reference: PerformanceSnapshot,
+}
+
 pub struct AdaptiveOptimizationService {
     optimizer: Arc<GridSearchOptimizer>,
     history_repo: Arc<dyn OptimizationHistoryRepository>,
     snapshot_repo: Arc<dyn PerformanceSnapshotRepository>,
     trigger_repo: Arc<dyn ReoptimizationTriggerRepository>,
+    shadow_repo: Arc<dyn ShadowTrialRepository>,
     strategy_repo: Arc<dyn StrategyRepository>,
     candle_repo: Arc<dyn CandleRepository>,
     evaluator: PerformanceEvaluator,
     regime_detector: MarketRegimeDetector,
     enabled: bool,
+    lookback_days: i64,
+    /// Bar resolution for re-tuning and for scoring candidates against the live parameters
+    timeframe: Timeframe,
+    guardrails: ParameterGuardrails,
+    shadow_days: i64,
+    shadow_min_improvement: Decimal,
+    analyst: Mutex<Option<AnalystLink>>,
+    reviews: Mutex<HashMap<String, PendingReview>>,
 }
 
 impl AdaptiveOptimizationService {
@@ -34,10 +74,12 @@ impl AdaptiveOptimizationService {
         history_repo: Arc<dyn OptimizationHistoryRepository>,
         snapshot_repo: Arc<dyn PerformanceSnapshotRepository>,
         trigger_repo: Arc<dyn ReoptimizationTriggerRepository>,
+        shadow_repo: Arc<dyn ShadowTrialRepository>,
         strategy_repo: Arc<dyn StrategyRepository>,
         candle_repo: Arc<dyn CandleRepository>,
         evaluator: PerformanceEvaluator,
         regime_window: usize,
+        regime_method: RegimeDetectionMethod,
         adx_threshold: Decimal,
         regime_volatility_threshold: Decimal,
         enabled: bool,
@@ -47,6 +89,7 @@ impl AdaptiveOptimizationService {
             history_repo,
             snapshot_repo,
             trigger_repo,
+            shadow_repo,
             strategy_repo,
             candle_repo,
             evaluator,
@@ -54,9 +97,79 @@ impl AdaptiveOptimizationService {
                 regime_window,
                 adx_threshold,
                 regime_volatility_threshold,
-            ),
+            )
+            .with_method(regime_method),
             enabled,
+            lookback_days: 90,
+            timeframe: Timeframe::OneMin,
+            guardrails: ParameterGuardrails::default(),
+            shadow_days: 0,
+            shadow_min_improvement: Decimal::ZERO,
+            analyst: Mutex::new(None),
+            reviews: Mutex::new(HashMap::new()),
+        }
+    }
+
+    /// Days of recent history the re-optimization trains and scores on
+    pub fn with_lookback_days(mut self, lookback_days: u32) -> Self {
+        self.lookback_days = i64::from(lookback_days.max(1));
+        self
+    }
+
+    /// Bar resolution to re-tune and score on; should match the timeframe the analyst trades
+    pub fn with_timeframe(mut self, timeframe: Timeframe) -> Self {
+        self.timeframe = timeframe;
+        self
+    }
+
+    /// Step limits on each update and the degradation that rolls it back
+    pub fn with_guardrails(mut self, guardrails: ParameterGuardrails) -> Self {
+        self.guardrails = guardrails;
+        self
+    }
+
+    /// Hold candidates for `days` before promoting them, then replay the candidate and the
+    /// incumbent over the bars of that period (which the search never saw) and only promote
+    /// when the candidate returns at least `min_improvement_pct` points more. Trials are
+    /// persisted, so a restart resumes them. 0 days applies candidates immediately.
+    pub fn with_shadow(mut self, days: u32, min_improvement_pct: Decimal) -> Self {
+        self.shadow_days = i64::from(days);
+        self.shadow_min_improvement = min_improvement_pct;
+        self
+    }
+
+    /// Push improved parameters to the running analyst via `AnalystCommand::UpdateSymbolParams`.
+    /// `live_config` is the default config the analyst is running; only the tuned parameters
+    /// of the re-tuned symbol are sent, so every other setting stays as the analyst has it.
+    pub fn connect_analyst(
+        &self,
+        cmd_tx: mpsc::Sender<AnalystCommand>,
+        live_config: Arc<RwLock<AnalystConfig>>,
+    ) {
+        if let Ok(mut analyst) = self.analyst.lock() {
+            *analyst = Some(AnalystLink {
+                cmd_tx,
+                live_config,
+                symbol_params: HashMap::new(),
+            });
+        }
+    }
+
+    /// Config the analyst is running for `symbol`
+    async fn live_config(&self, symbol: &str) -> Option<AnalystConfig> {
+        let (live_config, params) = self.analyst.lock().ok().and_then(|analyst| {
+            analyst.as_ref().map(|link| {
+                (
+                    link.live_config.clone(),
+                    link.symbol_params.get(symbol).cloned(),
+                )
+            })
+        })?;
+        let mut config = live_config.read().await.clone();
+        if let Some(params) = params {
+            params.apply_to(&mut config);
         }
+        Some(config)
     }
 
     /// Primary entry point: Run daily evaluation to see if we need to re-optimize
@@ -77,7 +190,19 @@ impl AdaptiveOptimizationService {
         // 1. Get latest performance snapshot
         let snapshot = self.snapshot_repo.get_latest(symbol).await?;
 
-        // 2. Evaluate performance
+        // 2. Judge the last update; a rollback stands until the next evaluation
+        if let Some(snap) = &snapshot
+            && self.review_last_update(symbol, snap).await?
+        {
+            return Ok(());
+        }
+
+        // 3. A candidate in shadow trading is judged before anything new is searched
+        if self.review_shadow_trial(symbol).await? {
+            return Ok(());
+        }
+
+        // 4. Evaluate performance
         if let Some(snap) = snapshot {
             if let Some(reason) = self.evaluator.evaluate(&snap) {
                 warn!(
@@ -88,8 +213,8 @@ impl AdaptiveOptimizationService {
             } else {
                 // Check for regime change even if performance is okay
                 // Fetch recent candles
-                let end_ts = Utc::now().timestamp();
-                let start_ts = end_ts - (30 * 24 * 60 * 60);
+                let end_ts = now_ms();
+                let start_ts = end_ts - 30 * MS_PER_DAY;
                 let candles = self.candle_repo.get_range(symbol, start_ts, end_ts).await?;
 
                 let current_regime = self.regime_detector.detect(&candles)?;
@@ -150,61 +275,379 @@ impl AdaptiveOptimizationService {
         // For MVP we just run it.
 
         let end_date = Utc::now();
-        let start_date = end_date - Duration::days(90); // Optimize on last quarter
+        let start_date = end_date - Duration::days(self.lookback_days);
+        let objective = self.optimizer.objective();
 
         // Run Grid Search
         let results = self
             .optimizer
-            .run_optimization(symbol, start_date, end_date, 0.70)
+            .run_optimization(
+                symbol,
+                start_date,
+                end_date,
+                TRAIN_RATIO,
+                self.timeframe.to_alpaca_string(),
+            )
             .await?;
         let top_results = self.optimizer.rank_results(results, 1);
 
         if let Some(best) = top_results.first() {
-            info!(
-                "Found new optimal parameters for {}: Sharpe={}",
-                symbol, best.sharpe_ratio
-            );
+            let live_config = self.live_config(symbol).await;
+            let previous_strategy = self.strategy_repo.find_by_symbol(symbol).await?;
 
-            // Serialize config
-            let config_json = serde_json::to_string(&best.params)?;
-            let metrics_json = serde_json::to_string(&best)?;
+            // Keep the update within one step of the parameters it replaces
+            let previous_params = live_config.clone().or_else(|| {
+                previous_strategy
+                    .as_ref()
+                    .and_then(|s| serde_json::from_str::<AnalystConfig>(&s.config_json).ok())
+            });
+            let mut params = best.params.clone();
+            if let Some(previous) = &previous_params {
+                for clamp in self.guardrails.clamp(previous, &mut params) {
+                    warn!("Adaptive guardrail for {}: {}", symbol, clamp);
+                }
+            }
 
-            // Determine regime of the optimization period
-            let candles = self
-                .candle_repo
-                .get_range(symbol, start_date.timestamp(), end_date.timestamp())
-                .await?;
-            let regime = self.regime_detector.detect(&candles)?;
+            // Score the live and the clamped parameters on the candidates' out-of-sample window
+            let test_secs =
+                ((end_date - start_date).num_seconds() as f64 * (1.0 - TRAIN_RATIO)) as i64;
+            let test_start = end_date - Duration::seconds(test_secs);
+            let candidate_score = if previous_params.is_some() {
+                self.optimizer
+                    .evaluate_config(
+                        symbol,
+                        params.clone(),
+                        test_start,
+                        end_date,
+                        self.timeframe.to_alpaca_string(),
+                    )
+                    .await?
+                    .objective_score
+            } else {
+                best.objective_score
+            };
+            let baseline_score = match &live_config {
+                Some(live) => {
+                    let mut baseline = best.params.clone();
+                    baseline.apply_tuned_params(live);
+                    match self
+                        .optimizer
+                        .evaluate_config(
+                            symbol,
+                            baseline,
+                            test_start,
+                            end_date,
+                            self.timeframe.to_alpaca_string(),
+                        )
+                        .await
+                    {
+                        Ok(result) => Some(result.objective_score),
+                        Err(e) => {
+                            warn!("Could not score the live parameters for {}: {}", symbol, e);
+                            None
+                        }
+                    }
+                }
+                None => None,
+            };
 
-            // Save History
-            // Deactivate old
-            self.history_repo.deactivate_old(symbol).await?;
+            if let Some(baseline) = baseline_score
+                && candidate_score <= baseline
+            {
+                info!(
+                    "Keeping current parameters for {}: {:?} objective {} >= best candidate {}",
+                    symbol, objective, baseline, candidate_score
+                );
+                return Ok(());
+            }
 
-            let history = OptimizationHistory::new(
-                symbol.to_string(),
-                config_json.clone(),
-                metrics_json,
-                regime.regime_type,
-                best.sharpe_ratio,
-                best.total_return,
-                best.win_rate,
-            );
-            self.history_repo.save(&history).await?;
-
-            // Update Active Strategy
-            let strategy_def = StrategyDefinition {
-                symbol: symbol.to_string(),
-                mode: StrategyMode::Advanced, // Assuming Advanced for now
-                config_json,
-                is_active: true,
-            };
-            self.strategy_repo.save(&strategy_def).await?;
+            match baseline_score {
+                Some(baseline) => info!(
+                    "Found new optimal parameters for {}: {:?} objective {} -> {} (+{}), Sharpe={}",
+                    symbol,
+                    objective,
+                    baseline,
+                    candidate_score,
+                    candidate_score - baseline,
+                    best.sharpe_ratio
+                ),
+                None => info!(
+                    "Found new optimal parameters for {}: {:?} objective {}, Sharpe={}",
+                    symbol, objective, best.objective_score, best.sharpe_ratio
+                ),
+            }
 
-            info!("Successfully applied new parameters for {}", symbol);
+            if self.shadow_days > 0 {
+                info!(
+                    "Shadow trading the candidate for {} for {} days: {}",
+                    symbol,
+                    self.shadow_days,
+                    params.tuned_params_summary()
+                );
+                if self.shadow_repo.find_by_symbol(symbol).await?.is_some() {
+                    info!("Replacing the shadow candidate for {}", symbol);
+                }
+                self.shadow_repo
+                    .save(&ShadowTrial {
+                        symbol: symbol.to_string(),
+                        parameters_json: serde_json::to_string(&params)?,
+                        performance_metrics_json: serde_json::to_string(best)?,
+                        started_at: end_date,
+                    })
+                    .await?;
+                return Ok(());
+            }
+
+            self.apply_params(symbol, &params, best).await?;
         } else {
             error!("Optimization failed to produce result for {}", symbol);
         }
 
         Ok(())
     }
+
+    /// Make `params` the active strategy, push them to the analyst and watch the result
+    async fn apply_params(
+        &self,
+        symbol: &str,
+        params: &AnalystConfig,
+        metrics: &OptimizationResult,
+    ) -> Result<()> {
+        let live_config = self.live_config(symbol).await;
+        let previous_strategy = self.strategy_repo.find_by_symbol(symbol).await?;
+
+        // Serialize config
+        let config_json = serde_json::to_string(params)?;
+        let metrics_json = serde_json::to_string(metrics)?;
+        let end_date = Utc::now();
+        let start_date = end_date - Duration::days(self.lookback_days);
+
+        // Determine regime of the optimization period
+        let candles = self
+            .candle_repo
+            .get_range(
+                symbol,
+                start_date.timestamp_millis(),
+                end_date.timestamp_millis(),
+            )
+            .await?;
+        let regime = self.regime_detector.detect(&candles)?;
+
+        // Save History
+        // Deactivate old
+        self.history_repo.deactivate_old(symbol).await?;
+
+        let history = OptimizationHistory::new(
+            symbol.to_string(),
+            config_json.clone(),
+            metrics_json,
+            regime.regime_type,
+            metrics.sharpe_ratio,
+            metrics.total_return,
+            metrics.win_rate,
+        );
+        self.history_repo.save(&history).await?;
+
+        // Update Active Strategy
+        let strategy_def = StrategyDefinition {
+            symbol: symbol.to_string(),
+            mode: StrategyMode::Advanced, // Assuming Advanced for now
+            config_json,
+            is_active: true,
+        };
+        self.strategy_repo.save(&strategy_def).await?;
+
+        if let Some(live) = &live_config {
+            self.push_to_analyst(symbol, live, params).await;
+        }
+
+        match self.snapshot_repo.get_latest(symbol).await? {
+            Some(reference) => {
+                if let Ok(mut reviews) = self.reviews.lock() {
+                    reviews.insert(
+                        symbol.to_string(),
+                        PendingReview {
+                            previous_strategy,
+                            previous_live: live_config,
+                            reference,
+                        },
+                    );
+                }
+            }
+            None => warn!(
+                "No performance snapshot for {}, the update cannot be rolled back",
+                symbol
+            ),
+        }
+
+        info!("Successfully applied new parameters for {}", symbol);
+        Ok(())
+    }
+
+    /// Settle the shadow trial for `symbol` once it has run its course: replay the candidate
+    /// and the incumbent over the bars since the trial started (a forward test on data the
+    /// search never saw, not a record of live fills) and promote the candidate if it beat
+    /// the incumbent by the threshold. Returns true while a trial is running or when one
+    /// was settled, so no new search starts on the same day.
+    async fn review_shadow_trial(&self, symbol: &str) -> Result<bool> {
+        let now = Utc::now();
+        let Some(trial) = self.shadow_repo.find_by_symbol(symbol).await? else {
+            return Ok(false);
+        };
+        if now - trial.started_at < Duration::days(self.shadow_days) {
+            info!(
+                "Shadow trial for {} running since {}, {} days to go",
+                symbol,
+                trial.started_at,
+                (trial.started_at + Duration::days(self.shadow_days) - now).num_days()
+            );
+            return Ok(true);
+        }
+        let (params, metrics): (AnalystConfig, OptimizationResult) = match (
+            serde_json::from_str(&trial.parameters_json),
+            serde_json::from_str(&trial.performance_metrics_json),
+        ) {
+            (Ok(params), Ok(metrics)) => (params, metrics),
+            (Err(e), _) | (_, Err(e)) => {
+                // A trial that can't be read will never be reviewable
+                self.shadow_repo.delete(symbol).await?;
+                return Err(e.into());
+            }
+        };
+
+        let candidate = self
+            .optimizer
+            .evaluate_config(
+                symbol,
+                params.clone(),
+                trial.started_at,
+                now,
+                self.timeframe.to_alpaca_string(),
+            )
+            .await?;
+
+        let live_config = self.live_config(symbol).await;
+        let incumbent_params = match live_config {
+            Some(live) => Some(live),
+            None => self
+                .strategy_repo
+                .find_by_symbol(symbol)
+                .await?
+                .and_then(|s| serde_json::from_str::<AnalystConfig>(&s.config_json).ok()),
+        };
+        // Without incumbent parameters the candidate has to beat staying flat
+        let incumbent_return = match incumbent_params {
+            Some(current) => {
+                let mut incumbent = params.clone();
+                incumbent.apply_tuned_params(&current);
+                self.optimizer
+                    .evaluate_config(
+                        symbol,
+                        incumbent,
+                        trial.started_at,
+                        now,
+                        self.timeframe.to_alpaca_string(),
+                    )
+                    .await?
+                    .total_return
+            }
+            None => Decimal::ZERO,
+        };
+        // Only now: a failed evaluation keeps the trial for the next run
+        self.shadow_repo.delete(symbol).await?;
+
+        let improvement = candidate.total_return - incumbent_return;
+        if improvement < self.shadow_min_improvement {
+            info!(
+                "Rejecting shadow candidate for {}: return {:.2}% vs incumbent {:.2}% (needs +{}%)",
+                symbol, candidate.total_return, incumbent_return, self.shadow_min_improvement
+            );
+            return Ok(true);
+        }
+
+        info!(
+            "Promoting shadow candidate for {}: return {:.2}% vs incumbent {:.2}% over {} trades",
+            symbol, candidate.total_return, incumbent_return, candidate.total_trades
+        );
+        self.apply_params(symbol, &params, &metrics).await?;
+        Ok(true)
+    }
+
+    /// Compare live performance since the last update with `snapshot` and revert the update
+    /// if it degraded. Returns true when a rollback was applied.
+    async fn review_last_update(
+        &self,
+        symbol: &str,
+        snapshot: &PerformanceSnapshot,
+    ) -> Result<bool> {
+        let review = match self.reviews.lock() {
+            Ok(mut reviews) => match reviews.get(symbol) {
+                Some(pending) if snapshot.timestamp > pending.reference.timestamp => {
+                    reviews.remove(symbol)
+                }
+                _ => None,
+            },
+            Err(_) => None,
+        };
+        let Some(review) = review else {
+            return Ok(false);
+        };
+
+        let Some(reason) = self.guardrails.degradation(&review.reference, snapshot) else {
+            info!("Adaptive update for {} holds up, keeping it", symbol);
+            return Ok(false);
+        };
+
+        warn!(
+            "Rolling back the adaptive update for {}: {}",
+            symbol, reason
+        );
+        self.history_repo.deactivate_old(symbol).await?;
+        if let Some(strategy) = &review.previous_strategy {
+            self.strategy_repo.save(strategy).await?;
+        }
+        if let (Some(previous), Some(live)) =
+            (&review.previous_live, self.live_config(symbol).await)
+        {
+            self.push_to_analyst(symbol, &live, previous).await;
+        }
+        Ok(true)
+    }
+
+    /// Send the tuned parameters of `tuned` to the analyst, which runs `live` for `symbol`
+    async fn push_to_analyst(&self, symbol: &str, live: &AnalystConfig, tuned: &AnalystConfig) {
+        let params = TunedParams::from(tuned);
+        info!(
+            "Adaptive optimization for {}: {} -> {}",
+            symbol,
+            live.tuned_params_summary(),
+            tuned.tuned_params_summary()
+        );
+
+        let cmd_tx = match self.analyst.lock() {
+            Ok(analyst) => analyst.as_ref().map(|link| link.cmd_tx.clone()),
+            Err(_) => None,
+        };
+        let Some(cmd_tx) = cmd_tx else {
+            return;
+        };
+        if let Err(e) = cmd_tx
+            .send(AnalystCommand::UpdateSymbolParams(
+                symbol.to_string(),
+                Box::new(params.clone()),
+            ))
+            .await
+        {
+            error!(
+                "Failed to send re-tuned parameters for {} to the analyst: {}",
+                symbol, e
+            );
+            return;
+        }
+        if let Ok(mut analyst) = self.analyst.lock()
+            && let Some(link) = analyst.as_mut()
+        {
+            link.symbol_params.insert(symbol.to_string(), params);
+        }
+    }
 }
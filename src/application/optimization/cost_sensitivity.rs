@@ -0,0 +1,237 @@
+//! Fee/slippage sensitivity sweep for a single strategy configuration.
+//!
+//! Re-runs the same config on identical bars under a grid of cost assumptions so an edge
+//! that only exists at near-zero costs shows up before going live.
+
+use crate::application::agents::analyst_config::AnalystConfig;
+use crate::application::optimization::optimizer::{
+    OptimizationResult, backtest_result_to_opt_result_impl,
+};
+use crate::application::optimization::simulator::Simulator;
+use crate::domain::ports::MarketDataService;
+use crate::domain::trading::fee_model::ConstantFeeModel;
+use crate::domain::trading::portfolio::Portfolio;
+use crate::domain::trading::types::Candle;
+use crate::infrastructure::mock::MockExecutionService;
+use anyhow::Result;
+use chrono::{DateTime, Utc};
+use rust_decimal::Decimal;
+use rust_decimal_macros::dec;
+use std::collections::BTreeMap;
+use std::sync::Arc;
+use tokio::sync::RwLock;
+
+/// (commission per share, slippage in basis points)
+pub type CostKey = (Decimal, Decimal);
+
+/// Backtest outcome under one cost assumption
+#[derive(Debug, Clone)]
+pub struct CostPoint {
+    pub summary: OptimizationResult,
+    pub total_fees: Decimal,
+}
+
+/// Sweep results keyed by (commission, slippage_bps)
+#[derive(Debug, Clone, Default)]
+pub struct CostSensitivity {
+    pub points: BTreeMap<CostKey, CostPoint>,
+}
+
+impl CostSensitivity {
+    pub fn get(&self, commission: Decimal, slippage_bps: Decimal) -> Option<&CostPoint> {
+        self.points.get(&(commission, slippage_bps))
+    }
+
+    /// Cheapest scenario, used as the reference for degradation
+    pub fn baseline(&self) -> Option<&CostPoint> {
+        self.points.values().next()
+    }
+
+    /// Sharpe at `key` minus the baseline Sharpe
+    pub fn sharpe_change(&self, key: CostKey) -> Option<Decimal> {
+        Some(self.points.get(&key)?.summary.sharpe_ratio - self.baseline()?.summary.sharpe_ratio)
+    }
+
+    /// Return at `key` minus the baseline return (percentage points)
+    pub fn return_change(&self, key: CostKey) -> Option<Decimal> {
+        Some(self.points.get(&key)?.summary.total_return - self.baseline()?.summary.total_return)
+    }
+
+    /// Scenarios where the config still makes money
+    pub fn profitable_scenarios(&self) -> usize {
+        self.points
+            .values()
+            .filter(|p| p.summary.total_return > Decimal::ZERO)
+            .count()
+    }
+
+    /// Print a table with one row per (commission, slippage_bps)
+    pub fn print_table(&self) {
+        println!("{}", "=".repeat(86));
+        println!("💸 COST SENSITIVITY");
+        println!("{}", "=".repeat(86));
+        println!(
+            "{:>10} {:>9} {:>8} {:>9} {:>10} {:>11} {:>7} {:>12}",
+            "Comm/sh", "Slip bps", "Sharpe", "ΔSharpe", "Return", "ΔReturn", "Trades", "Fees"
+        );
+        for (&key, point) in &self.points {
+            println!(
+                "{:>10} {:>9} {:>8.2} {:>9.2} {:>9.2}% {:>10.2}% {:>7} {:>12.2}",
+                key.0,
+                key.1,
+                point.summary.sharpe_ratio,
+                self.sharpe_change(key).unwrap_or_default(),
+                point.summary.total_return,
+                self.return_change(key).unwrap_or_default(),
+                point.summary.total_trades,
+                point.total_fees
+            );
+        }
+        println!(
+            "Profitable in {}/{} cost scenarios",
+            self.profitable_scenarios(),
+            self.points.len()
+        );
+        println!("{}", "=".repeat(86));
+    }
+}
+
+/// Re-runs one config across a grid of commission/slippage assumptions
+pub struct CostSensitivitySweep {
+    market_data: Arc<dyn MarketDataService>,
+    initial_cash: Decimal,
+    commissions: Vec<Decimal>,
+    slippage_bps: Vec<Decimal>,
+    spy_bars: Option<Vec<Candle>>,
+}
+
+impl CostSensitivitySweep {
+    pub fn new(market_data: Arc<dyn MarketDataService>, initial_cash: Decimal) -> Self {
+        Self {
+            market_data,
+            initial_cash,
+            commissions: vec![dec!(0), dec!(0.001), dec!(0.005), dec!(0.01)],
+            slippage_bps: vec![dec!(0), dec!(5), dec!(10), dec!(25)],
+            spy_bars: None,
+        }
+    }
+
+    /// Commission per share values to sweep
+    pub fn with_commissions(mut self, commissions: Vec<Decimal>) -> Self {
+        self.commissions = commissions;
+        self
+    }
+
+    /// Slippage values to sweep, in basis points of trade value
+    pub fn with_slippage_bps(mut self, slippage_bps: Vec<Decimal>) -> Self {
+        self.slippage_bps = slippage_bps;
+        self
+    }
+
+    /// Reuse prefetched benchmark bars for alpha/beta (otherwise SPY is fetched per run)
+    pub fn with_benchmark_bars(mut self, spy_bars: Vec<Candle>) -> Self {
+        self.spy_bars = Some(spy_bars);
+        self
+    }
+
+    /// Backtest `config` on `bars` once per (commission, slippage) pair
+    pub async fn run(
+        &self,
+        symbol: &str,
+        bars: &[Candle],
+        start: DateTime<Utc>,
+        end: DateTime<Utc>,
+        config: &AnalystConfig,
+    ) -> Result<CostSensitivity> {
+        let mut sensitivity = CostSensitivity::default();
+        for &commission in &self.commissions {
+            for &slippage_bps in &self.slippage_bps {
+                let fee_model = Arc::new(ConstantFeeModel::new(
+                    commission,
+                    slippage_bps / dec!(10000),
+                ));
+                let mut scenario_config = config.clone();
+                scenario_config.fee_model = fee_model.clone();
+
+                let mut portfolio = Portfolio::new();
+                portfolio.allow_shorts = config.allow_shorts;
+                portfolio.reset(self.initial_cash);
+                let execution_service = Arc::new(MockExecutionService::with_costs(
+                    Arc::new(RwLock::new(portfolio)),
+                    fee_model,
+                ));
+                let sim = Simulator::new(
+                    self.market_data.clone(),
+                    execution_service,
+                    scenario_config.clone(),
+                );
+                let result = sim
+                    .run_with_bars(symbol, bars, start, end, self.spy_bars.clone())
+                    .await?;
+                let total_fees = result.total_fees();
+                sensitivity.points.insert(
+                    (commission, slippage_bps),
+                    CostPoint {
+                        summary: backtest_result_to_opt_result_impl(scenario_config, result),
+                        total_fees,
+                    },
+                );
+            }
+        }
+        Ok(sensitivity)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn point(sharpe: Decimal, total_return: Decimal, trades: usize) -> CostPoint {
+        CostPoint {
+            summary: OptimizationResult {
+                params: AnalystConfig::default(),
+                sharpe_ratio: sharpe,
+                total_return,
+                max_drawdown: dec!(5),
+                win_rate: dec!(50),
+                total_trades: trades,
+                objective_score: Decimal::ZERO,
+                alpha: Decimal::ZERO,
+                beta: Decimal::ZERO,
+                in_sample_sharpe: None,
+                risk_score: None,
+            },
+            total_fees: Decimal::ZERO,
+        }
+    }
+
+    #[test]
+    fn test_cost_sensitivity_degradation_vs_cheapest_scenario() {
+        let mut sensitivity = CostSensitivity::default();
+        sensitivity
+            .points
+            .insert((dec!(0.005), dec!(10)), point(dec!(0.2), dec!(-1.5), 40));
+        sensitivity
+            .points
+            .insert((dec!(0), dec!(0)), point(dec!(1.4), dec!(12), 40));
+        sensitivity
+            .points
+            .insert((dec!(0.001), dec!(5)), point(dec!(0.9), dec!(6), 40));
+
+        assert_eq!(
+            sensitivity.baseline().unwrap().summary.total_return,
+            dec!(12)
+        );
+        assert_eq!(
+            sensitivity.sharpe_change((dec!(0.005), dec!(10))),
+            Some(dec!(-1.2))
+        );
+        assert_eq!(
+            sensitivity.return_change((dec!(0.001), dec!(5))),
+            Some(dec!(-6))
+        );
+        assert_eq!(sensitivity.return_change((dec!(1), dec!(1))), None);
+        assert_eq!(sensitivity.profitable_scenarios(), 2);
+        assert!(sensitivity.get(dec!(0.001), dec!(5)).is_some());
+    }
+}
@@ -3,8 +3,11 @@
 //! Uses a genetic algorithm by default (single period, parallel evaluation).
 //! Bounds are derived from ParameterGrid when provided.
 
+use crate::application::agents::analyst_config::AnalystConfig;
+use crate::application::optimization::cost_sensitivity::{CostSensitivity, CostSensitivitySweep};
 use crate::application::optimization::optimizer::{
-    GeneticOptimizer, OptimizationResult, ParameterGrid,
+    AdaptiveMutation, BasketAggregation, EarlyStopping, GeneticOptimizer, ObjectiveFunction,
+    OptimizationResult, ParameterGrid, PopulationSeeding, TradeCountPenalty,
 };
 use crate::config::{AssetClass, Config, StrategyMode};
 use crate::domain::ports::ExecutionService;
@@ -28,6 +31,8 @@ use crate::domain::ports::MarketDataService;
 pub struct OptimizeEngine {
     market_service: Arc<dyn MarketDataService>,
     base_config: Config,
+    objective: ObjectiveFunction,
+    trade_penalty: TradeCountPenalty,
 }
 
 impl OptimizeEngine {
@@ -48,6 +53,8 @@ impl OptimizeEngine {
         let asset_class = AssetClass::from_str(&asset_class_str).unwrap_or(AssetClass::Stock);
 
         let base_config = Config::from_env().context("Failed to load config from environment")?;
+        let objective = ObjectiveFunction::from_env()?;
+        let trade_penalty = TradeCountPenalty::from_env()?;
 
         let market_service = Arc::new(
             AlpacaMarketDataService::builder()
@@ -65,6 +72,8 @@ impl OptimizeEngine {
         Ok(Self {
             market_service: market_service as Arc<dyn MarketDataService>,
             base_config,
+            objective,
+            trade_penalty,
         })
     }
 
@@ -76,9 +85,31 @@ impl OptimizeEngine {
         Self {
             market_service,
             base_config,
+            objective: ObjectiveFunction::default(),
+            trade_penalty: TradeCountPenalty::default(),
         }
     }
 
+    /// Override the objective used to rank configurations (default: OPTIMIZER_OBJECTIVE or weighted blend)
+    pub fn with_objective(mut self, objective: ObjectiveFunction) -> Self {
+        self.objective = objective;
+        self
+    }
+
+    pub fn objective(&self) -> ObjectiveFunction {
+        self.objective
+    }
+
+    /// Override the low-trade-count penalty (default: OPTIMIZER_MIN_TRADES / OPTIMIZER_MIN_TRADES_PENALTY)
+    pub fn with_trade_penalty(mut self, trade_penalty: TradeCountPenalty) -> Self {
+        self.trade_penalty = trade_penalty;
+        self
+    }
+
+    pub fn trade_penalty(&self) -> TradeCountPenalty {
+        self.trade_penalty
+    }
+
     /// Runs parameter optimization for a single symbol using a genetic algorithm.
     /// Bounds are derived from parameter_grid; population/generations control the search.
     #[allow(clippy::too_many_arguments)]
@@ -93,7 +124,8 @@ impl OptimizeEngine {
         risk_score: Option<u8>,
     ) -> Result<Vec<OptimizationResult>> {
         self.run_genetic_optimization(
-            symbol,
+            &[symbol.to_string()],
+            BasketAggregation::Mean,
             start,
             end,
             strategy,
@@ -103,15 +135,20 @@ impl OptimizeEngine {
             None,
             None,
             risk_score,
+            None,
+            None,
+            None,
         )
         .await
     }
 
-    /// Runs genetic optimization with optional tuning (population, generations, mutation_rate, timeframe, risk_score).
+    /// Runs genetic optimization with optional tuning (population, generations, mutation_rate, timeframe, risk_score,
+    /// early stopping, adaptive mutation, seeding). With several symbols, each config is scored across the basket.
     #[allow(clippy::too_many_arguments)]
     pub async fn run_genetic_optimization(
         &self,
-        symbol: &str,
+        symbols: &[String],
+        aggregation: BasketAggregation,
         start: DateTime<Utc>,
         end: DateTime<Utc>,
         strategy: StrategyMode,
@@ -121,6 +158,9 @@ impl OptimizeEngine {
         mutation_rate: Option<f64>,
         timeframe: Option<String>,
         risk_score: Option<u8>,
+        early_stopping: Option<EarlyStopping>,
+        adaptive_mutation: Option<AdaptiveMutation>,
+        seeding: Option<PopulationSeeding>,
     ) -> Result<Vec<OptimizationResult>> {
         let execution_service_factory = self.create_execution_factory();
         let bounds = parameter_grid.gene_bounds();
@@ -129,7 +169,7 @@ impl OptimizeEngine {
         let mutation_rate = mutation_rate.unwrap_or(0.15);
         let timeframe = timeframe.as_deref().unwrap_or("1Min");
 
-        let optimizer = GeneticOptimizer::new(
+        let mut optimizer = GeneticOptimizer::new(
             self.market_service.clone(),
             execution_service_factory,
             bounds,
@@ -139,10 +179,21 @@ impl OptimizeEngine {
             generations,
             mutation_rate,
             risk_score,
-        );
+        )
+        .with_objective(self.objective)
+        .with_trade_penalty(self.trade_penalty);
+        if let Some(early_stopping) = early_stopping {
+            optimizer = optimizer.with_early_stopping(early_stopping);
+        }
+        if let Some(adaptive_mutation) = adaptive_mutation {
+            optimizer = optimizer.with_adaptive_mutation(adaptive_mutation);
+        }
+        if let Some(seeding) = seeding {
+            optimizer = optimizer.with_seeding(seeding);
+        }
 
         optimizer
-            .run_optimization(symbol, start, end, timeframe)
+            .run_basket_optimization(symbols, start, end, timeframe, aggregation)
             .await
     }
 
@@ -177,6 +228,37 @@ impl OptimizeEngine {
         results
     }
 
+    /// Re-runs `config` on `symbol` across a grid of commission (per share) and slippage (bps)
+    /// assumptions, showing how its metrics degrade as costs rise.
+    #[allow(clippy::too_many_arguments)]
+    pub async fn run_cost_sensitivity(
+        &self,
+        symbol: &str,
+        start: DateTime<Utc>,
+        end: DateTime<Utc>,
+        timeframe: &str,
+        config: &AnalystConfig,
+        commissions: Vec<Decimal>,
+        slippage_bps: Vec<Decimal>,
+    ) -> Result<CostSensitivity> {
+        let bars = self
+            .market_service
+            .get_historical_bars(symbol, start, end, timeframe)
+            .await
+            .with_context(|| format!("Failed to fetch bars for {}", symbol))?;
+        let spy_bars = self
+            .market_service
+            .get_historical_bars("SPY", start, end, "1Day")
+            .await
+            .unwrap_or_default();
+        CostSensitivitySweep::new(self.market_service.clone(), self.base_config.initial_cash)
+            .with_commissions(commissions)
+            .with_slippage_bps(slippage_bps)
+            .with_benchmark_bars(spy_bars)
+            .run(symbol, &bars, start, end, config)
+            .await
+    }
+
     /// Ranks results and returns the top N configurations.
     pub fn rank_results(
         &self,
@@ -195,9 +277,12 @@ impl OptimizeEngine {
 
     /// Creates a new execution service factory for each optimization run.
     fn create_execution_factory(&self) -> Arc<dyn Fn() -> Arc<dyn ExecutionService> + Send + Sync> {
+        let initial_cash = self.base_config.initial_cash;
+        let allow_shorts = self.base_config.allow_shorts;
         Arc::new(move || {
             let mut portfolio = Portfolio::new();
-            portfolio.cash = Decimal::new(100000, 0);
+            portfolio.allow_shorts = allow_shorts;
+            portfolio.reset(initial_cash);
             let portfolio_lock = Arc::new(RwLock::new(portfolio));
 
             let slippage_pct = env::var("SLIPPAGE_PCT")
@@ -13,9 +13,11 @@ impl MarketExpectancyEvaluator {
         Self { win_rate_provider }
     }
 
-    async fn calculate_win_prob(&self, symbol: &str, regime: &MarketRegime) -> Decimal {
+    /// Regime-adjusted win probability and the closed trades it is based on
+    async fn calculate_win_prob(&self, symbol: &str, regime: &MarketRegime) -> (Decimal, usize) {
         // 1. Get Empirical Win Rate
-        let empirical_rate_f64 = self.win_rate_provider.get_win_rate(symbol).await;
+        let (empirical_rate_f64, sample_size) =
+            self.win_rate_provider.get_win_rate_sample(symbol).await;
         let empirical_rate = Decimal::from_f64_retain(empirical_rate_f64).unwrap_or(dec!(0.5));
 
         // 2. Adjust based on Regime Confidence
@@ -30,7 +32,10 @@ impl MarketExpectancyEvaluator {
         };
 
         // Clamp between 0.1 and 0.9
-        (empirical_rate + regime_modifier).clamp(dec!(0.1), dec!(0.9))
+        (
+            (empirical_rate + regime_modifier).clamp(dec!(0.1), dec!(0.9)),
+            sample_size,
+        )
     }
 }
 
@@ -40,7 +45,7 @@ impl ExpectancyEvaluator for MarketExpectancyEvaluator {
         // Dynamic Reward/Risk estimation
         use rust_decimal_macros::dec;
 
-        let win_prob = self.calculate_win_prob(symbol, regime).await;
+        let (win_prob, sample_size) = self.calculate_win_prob(symbol, regime).await;
 
         let reward = if price > Decimal::ZERO {
             regime.confidence * price * dec!(0.03)
@@ -66,6 +71,7 @@ impl ExpectancyEvaluator for MarketExpectancyEvaluator {
             reward_risk_ratio,
             win_prob,
             expected_value,
+            sample_size,
         }
     }
 }
@@ -1,11 +1,14 @@
 // Strategy optimization and backtesting modules
+pub mod ab_comparison;
 pub mod adaptive_optimization_service;
 pub mod benchmark_metrics;
+pub mod cost_sensitivity;
 pub mod crypto_clusters;
 pub mod engine;
 pub mod expectancy_evaluator;
 pub mod optimizer;
 pub mod parallel_benchmark;
+pub mod parameter_guardrails;
 pub mod reporting;
 pub mod simulator;
 pub mod win_rate_provider;
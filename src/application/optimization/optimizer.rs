@@ -1,9 +1,11 @@
 use crate::application::agents::analyst_config::AnalystConfig;
 use crate::application::optimization::simulator::{BacktestResult, Simulator};
 use crate::config::StrategyMode;
+use crate::domain::market::timeframe::Timeframe;
 use crate::domain::ports::{ExecutionService, MarketDataService};
 use crate::domain::risk::optimal_parameters::{AssetType, OptimalParameters};
 use crate::domain::risk::risk_appetite::{RiskAppetite, RiskProfile};
+use crate::domain::risk::session_boundary::TradingDayBoundary;
 use crate::domain::trading::fee_model::ConstantFeeModel;
 use crate::domain::trading::types::Candle;
 use anyhow::{Context, Result};
@@ -15,7 +17,7 @@ use rust_decimal_macros::dec;
 use serde::{Deserialize, Serialize};
 use std::sync::Arc;
 use std::time::Instant;
-use tracing::{debug, info};
+use tracing::{debug, info, warn};
 
 /// Parameter grid for optimization
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -61,6 +63,11 @@ impl Default for ParameterGrid {
 }
 
 impl ParameterGrid {
+    /// Fewest bars a backtest needs for any config in this grid to trade (see `GeneBounds::min_required_bars`)
+    pub fn min_required_bars(&self) -> usize {
+        self.gene_bounds().min_required_bars()
+    }
+
     /// Bounds for genetic algorithm (min/max per dimension). Uses grid extremes or defaults.
     pub fn gene_bounds(&self) -> GeneBounds {
         let fast = (
@@ -237,6 +244,43 @@ pub struct GeneBounds {
     pub smc_min_fvg_size_pct: (f64, f64),
 }
 
+/// Bars required per bar of the longest lookback: one lookback to warm the indicators up,
+/// and at least as many again for the strategy to trade on.
+const BARS_PER_LOOKBACK: usize = 2;
+
+impl GeneBounds {
+    /// Fewest bars a backtest needs for the longest lookback in these bounds to produce trades
+    pub fn min_required_bars(&self) -> usize {
+        let longest = [
+            self.fast_sma.1,
+            self.slow_sma.1,
+            self.stat_momentum_lookback.1,
+            self.zscore_lookback.1,
+            self.smc_ob_lookback.1,
+        ]
+        .into_iter()
+        .fold(0.0, f64::max)
+        .ceil() as usize;
+        longest * BARS_PER_LOOKBACK
+    }
+}
+
+/// Fail fast when `symbol` has too little history for the configured lookbacks; every
+/// backtest would otherwise run without trading and the optimizer would rank noise.
+fn ensure_enough_bars(symbol: &str, window: &str, actual: usize, required: usize) -> Result<()> {
+    if actual < required {
+        anyhow::bail!(
+            "Insufficient {} data for {}: got {} bars, need at least {} for the longest configured lookback. \
+             Use a longer date range or a finer timeframe.",
+            window,
+            symbol,
+            actual,
+            required
+        );
+    }
+    Ok(())
+}
+
 /// Genome: 14 genes in [0, 1], decoded with GeneBounds to AnalystConfig params.
 fn decode_genome(
     genome: &[f64; 14],
@@ -315,6 +359,8 @@ fn decode_genome(
         ema_fast_period: 50,
         ema_slow_period: 150,
         take_profit_pct: dec!(0.05),
+        take_profit_mode: Default::default(),
+        take_profit_atr_multiplier: dec!(3.0),
         min_hold_time_minutes: 0,
         signal_confirmation_bars: 1,
         spread_bps: dec!(5.0),
@@ -345,6 +391,221 @@ fn decode_genome(
         orderflow_volume_profile_lookback: 100,
         ensemble_weights: None,
         ensemble_voting_threshold: dec!(0.5),
+        trading_windows: Default::default(),
+        news_dedup_window_seconds: 300,
+        news_trade_size_multiplier: dec!(1.0),
+        news_trade_max_value_usd: None,
+        news_reentry_cooldown_seconds: 0,
+        regime_detection_method: Default::default(),
+        hurst_lookback: 50,
+        relative_stop_benchmark: None,
+        relative_stop_pct: dec!(0.05),
+        pyramid_max_adds: 0,
+        pyramid_trigger_pct: dec!(0.02),
+        exits_bypass_cost_filters: true,
+        allow_shorts: false,
+        require_htf_confirmation: false,
+        trend_timeframe: Timeframe::OneHour,
+        max_trades_per_symbol_per_day: 0,
+        open_blackout_minutes: 0,
+        trading_day_boundary: TradingDayBoundary::default(),
+        post_stop_cooldown_seconds: 0,
+        trailing_stop_vol_scale_min: Decimal::ONE,
+        trailing_stop_vol_scale_max: Decimal::ONE,
+        candle_history_len: 0,
+        candle_gap_policy: Default::default(),
+        candle_gap_max_bars: 60,
+        cold_start_reward_risk_ratio: dec!(1.0),
+        cold_start_min_trades: 10,
+        confirm_strategy: None,
+    }
+}
+
+/// Inverse of `decode_genome` for the parameters `OptimalParameters` stores (the first six
+/// genes); the genes it does not store are drawn at random.
+fn encode_optimal_parameters(params: &OptimalParameters, bounds: &GeneBounds) -> [f64; 14] {
+    let unlerp = |v: f64, (lo, hi): (f64, f64)| {
+        if hi > lo {
+            ((v - lo) / (hi - lo)).clamp(0.0, 1.0)
+        } else {
+            0.5
+        }
+    };
+    let mut genome: [f64; 14] = std::array::from_fn(|_| rand::random::<f64>());
+    genome[0] = unlerp(params.fast_sma_period as f64, bounds.fast_sma);
+    genome[1] = unlerp(params.slow_sma_period as f64, bounds.slow_sma);
+    genome[2] = unlerp(
+        params.rsi_threshold.to_f64().unwrap_or(0.0),
+        bounds.rsi_threshold,
+    );
+    genome[3] = unlerp(
+        params.trend_divergence_threshold.to_f64().unwrap_or(0.0),
+        bounds.trend_divergence_threshold,
+    );
+    genome[4] = unlerp(
+        params.trailing_stop_atr_multiplier.to_f64().unwrap_or(0.0),
+        bounds.trailing_stop_atr_multiplier,
+    );
+    genome[5] = unlerp(
+        params.order_cooldown_seconds as f64,
+        bounds.order_cooldown_seconds,
+    );
+    genome
+}
+
+/// Objective score assigned to results the selected objective rejects outright.
+/// Low enough to lose every ranking, but finite so basket means stay well-defined.
+pub const REJECTED_OBJECTIVE_SCORE: Decimal = dec!(-1000000);
+
+/// Objective the optimizers rank configurations by. Higher scores are better.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub enum ObjectiveFunction {
+    /// Sharpe, return and win rate minus a drawdown penalty
+    #[default]
+    WeightedBlend,
+    /// Sharpe ratio
+    Sharpe,
+    /// Total return divided by max drawdown
+    Calmar,
+    /// Smallest max drawdown (configs that never trade are rejected)
+    MinDrawdown,
+    /// Total return, rejecting configs whose drawdown exceeds the cap (percent)
+    ReturnWithDrawdownCap { max_drawdown_pct: Decimal },
+}
+
+impl std::str::FromStr for ObjectiveFunction {
+    type Err = anyhow::Error;
+
+    /// Accepts `blend`, `sharpe`, `calmar`, `min_drawdown` or `return:<max drawdown %>`
+    fn from_str(s: &str) -> Result<Self> {
+        let lower = s.trim().to_lowercase();
+        let (name, arg) = match lower.split_once(':') {
+            Some((name, arg)) => (name, Some(arg.trim())),
+            None => (lower.as_str(), None),
+        };
+        match (name, arg) {
+            ("blend" | "weighted" | "weighted_blend" | "default", None) => Ok(Self::WeightedBlend),
+            ("sharpe", None) => Ok(Self::Sharpe),
+            ("calmar", None) => Ok(Self::Calmar),
+            ("min_drawdown" | "min-drawdown" | "drawdown", None) => Ok(Self::MinDrawdown),
+            ("return" | "max_return" | "max-return", Some(cap)) => {
+                let max_drawdown_pct = cap
+                    .parse::<Decimal>()
+                    .with_context(|| format!("Invalid drawdown cap in objective: {}", s))?;
+                if max_drawdown_pct <= Decimal::ZERO {
+                    anyhow::bail!("Drawdown cap must be positive, got {}", max_drawdown_pct);
+                }
+                Ok(Self::ReturnWithDrawdownCap { max_drawdown_pct })
+            }
+            ("return" | "max_return" | "max-return", None) => {
+                anyhow::bail!("Objective {} needs a drawdown cap, e.g. return:20", s)
+            }
+            _ => anyhow::bail!(
+                "Invalid objective: {} (expected blend, sharpe, calmar, min_drawdown or return:<cap>)",
+                s
+            ),
+        }
+    }
+}
+
+impl ObjectiveFunction {
+    /// Objective selected by `OPTIMIZER_OBJECTIVE` (weighted blend when unset)
+    pub fn from_env() -> Result<Self> {
+        match std::env::var("OPTIMIZER_OBJECTIVE") {
+            Ok(value) => value.parse().context("Invalid OPTIMIZER_OBJECTIVE"),
+            Err(_) => Ok(Self::default()),
+        }
+    }
+
+    /// Score a result under this objective
+    pub fn score(&self, result: &OptimizationResult) -> Decimal {
+        let drawdown = result.max_drawdown.abs();
+        match *self {
+            Self::WeightedBlend => result.weighted_blend_score(),
+            Self::Sharpe => result.sharpe_ratio,
+            Self::Calmar => {
+                if drawdown.is_zero() {
+                    result.total_return
+                } else {
+                    result.total_return / drawdown
+                }
+            }
+            Self::MinDrawdown => {
+                if result.total_trades == 0 {
+                    REJECTED_OBJECTIVE_SCORE
+                } else {
+                    -drawdown
+                }
+            }
+            Self::ReturnWithDrawdownCap { max_drawdown_pct } => {
+                if drawdown > max_drawdown_pct {
+                    REJECTED_OBJECTIVE_SCORE
+                } else {
+                    result.total_return
+                }
+            }
+        }
+    }
+}
+
+/// Fitness penalty for configs that trade too rarely for their metrics to mean much.
+/// Falls linearly from `strength` at zero trades to nothing at `min_trades`, and is
+/// subtracted from every objective so lucky low-sample configs cannot win the ranking.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct TradeCountPenalty {
+    pub min_trades: usize,
+    pub strength: Decimal,
+}
+
+impl Default for TradeCountPenalty {
+    fn default() -> Self {
+        Self {
+            min_trades: 30,
+            strength: dec!(2.0),
+        }
+    }
+}
+
+impl TradeCountPenalty {
+    pub fn new(min_trades: usize, strength: Decimal) -> Self {
+        Self {
+            min_trades,
+            strength,
+        }
+    }
+
+    /// Penalty selected by `OPTIMIZER_MIN_TRADES` and `OPTIMIZER_MIN_TRADES_PENALTY`
+    /// (30 trades, strength 2.0 when unset)
+    pub fn from_env() -> Result<Self> {
+        let mut penalty = Self::default();
+        if let Ok(value) = std::env::var("OPTIMIZER_MIN_TRADES") {
+            penalty.min_trades = value
+                .trim()
+                .parse()
+                .context("Invalid OPTIMIZER_MIN_TRADES")?;
+        }
+        if let Ok(value) = std::env::var("OPTIMIZER_MIN_TRADES_PENALTY") {
+            penalty.strength = value
+                .trim()
+                .parse()
+                .context("Invalid OPTIMIZER_MIN_TRADES_PENALTY")?;
+        }
+        if penalty.strength < Decimal::ZERO {
+            anyhow::bail!(
+                "OPTIMIZER_MIN_TRADES_PENALTY must not be negative, got {}",
+                penalty.strength
+            );
+        }
+        Ok(penalty)
+    }
+
+    /// Amount to subtract from the objective score of a result with `total_trades` trades
+    pub fn penalty(&self, total_trades: usize) -> Decimal {
+        if total_trades >= self.min_trades {
+            return Decimal::ZERO;
+        }
+        let min_trades = Decimal::from(self.min_trades);
+        (min_trades - Decimal::from(total_trades)) / min_trades * self.strength
     }
 }
 
@@ -369,10 +630,34 @@ pub struct OptimizationResult {
 }
 
 impl OptimizationResult {
-    /// Calculate a weighted objective score for ranking configurations
+    /// Calculate the default (weighted blend) objective score for ranking configurations
     /// Higher is better
     pub fn calculate_objective_score(&mut self) {
-        // Multi-criteria optimization (Sharpe + MaxDD + Stability)
+        self.apply_objective(&ObjectiveFunction::default(), &TradeCountPenalty::default());
+    }
+
+    /// Set `objective_score` using the selected objective, less the low-trade-count penalty.
+    /// Rejected results stay rejected.
+    pub fn apply_objective(
+        &mut self,
+        objective: &ObjectiveFunction,
+        trade_penalty: &TradeCountPenalty,
+    ) {
+        let score = objective.score(self);
+        self.objective_score = if score <= REJECTED_OBJECTIVE_SCORE {
+            score
+        } else {
+            score - trade_penalty.penalty(self.total_trades)
+        };
+    }
+
+    /// True when the objective rejected this result (e.g. drawdown cap exceeded)
+    pub fn is_rejected(&self) -> bool {
+        self.objective_score <= REJECTED_OBJECTIVE_SCORE
+    }
+
+    fn weighted_blend_score(&self) -> Decimal {
+        // Multi-criteria optimization (Sharpe + MaxDD); the trade-count penalty is applied on top
 
         // 1. Return/Risk components
         let sharpe_component = self.sharpe_ratio * dec!(0.5);
@@ -383,18 +668,7 @@ impl OptimizationResult {
         let dd_ratio = self.max_drawdown.abs() / dec!(100.0);
         let dd_penalty = dd_ratio * dd_ratio * dec!(2.0); // Quadratic penalty
 
-        // 3. Stability Penalty (penalize if total trades is less than 30)
-        let trades_dec = Decimal::from(self.total_trades);
-        let stability_penalty = if self.total_trades < 30 {
-            let penalty_factor = (dec!(30.0) - trades_dec) / dec!(30.0);
-            penalty_factor * dec!(2.0)
-        } else {
-            Decimal::ZERO
-        };
-
-        self.objective_score = sharpe_component + return_component + win_rate_component
-            - dd_penalty
-            - stability_penalty;
+        sharpe_component + return_component + win_rate_component - dd_penalty
     }
 
     /// Build OptimalParameters for persistence (e.g. ~/.rustrade/optimal_parameters.json) from this result.
@@ -480,6 +754,8 @@ pub struct GridSearchOptimizer {
     parameter_grid: ParameterGrid,
     strategy_mode: StrategyMode,
     min_profit_ratio: Decimal, // From Config - scales with Risk Appetite
+    objective: ObjectiveFunction,
+    trade_penalty: TradeCountPenalty,
 }
 
 impl GridSearchOptimizer {
@@ -496,9 +772,70 @@ impl GridSearchOptimizer {
             parameter_grid,
             strategy_mode,
             min_profit_ratio,
+            objective: ObjectiveFunction::default(),
+            trade_penalty: TradeCountPenalty::default(),
         }
     }
 
+    /// Rank results by `objective` instead of the default weighted blend
+    pub fn with_objective(mut self, objective: ObjectiveFunction) -> Self {
+        self.objective = objective;
+        self
+    }
+
+    /// Penalize configs with fewer trades than `trade_penalty.min_trades`
+    pub fn with_trade_penalty(mut self, trade_penalty: TradeCountPenalty) -> Self {
+        self.trade_penalty = trade_penalty;
+        self
+    }
+
+    pub fn objective(&self) -> ObjectiveFunction {
+        self.objective
+    }
+
+    /// Backtest one config over `start..end` on `timeframe` bars (empty = 1Min) and score it
+    /// under the selected objective
+    pub async fn evaluate_config(
+        &self,
+        symbol: &str,
+        config: AnalystConfig,
+        start: DateTime<Utc>,
+        end: DateTime<Utc>,
+        timeframe: &str,
+    ) -> Result<OptimizationResult> {
+        let timeframe = if timeframe.is_empty() {
+            "1Min"
+        } else {
+            timeframe
+        };
+        let bars = self
+            .market_data
+            .get_historical_bars(symbol, start, end, timeframe)
+            .await
+            .context("Failed to fetch bars")?;
+        let spy_bars = self
+            .market_data
+            .get_historical_bars("SPY", start, end, "1Day")
+            .await
+            .unwrap_or_default();
+        let prefetched = Arc::new(SinglePeriodBars {
+            bars,
+            start,
+            end,
+            spy_bars,
+        });
+        let mut result = Self::evaluate_one_single_period(
+            self.market_data.clone(),
+            self.execution_service_factory.clone(),
+            config,
+            symbol.to_string(),
+            prefetched,
+        )
+        .await?;
+        result.apply_objective(&self.objective, &self.trade_penalty);
+        Ok(result)
+    }
+
     /// Generate all parameter combinations from the grid
     pub fn generate_combinations(&self) -> Vec<AnalystConfig> {
         let mut combinations = Vec::new();
@@ -601,6 +938,8 @@ impl GridSearchOptimizer {
                                                                     ema_fast_period: 50,
                                                                     ema_slow_period: 150,
                                                                     take_profit_pct: dec!(0.05),
+                                                                    take_profit_mode: Default::default(),
+                                                                    take_profit_atr_multiplier: dec!(3.0),
                                                                     min_hold_time_minutes: 0,
                                                                     signal_confirmation_bars: 1,
                                                                     spread_bps: dec!(5.0),
@@ -631,6 +970,33 @@ impl GridSearchOptimizer {
                                                                     orderflow_volume_profile_lookback: 100,
                                                                     ensemble_weights: None,
                                                                     ensemble_voting_threshold: dec!(0.5),
+                                                                    trading_windows: Default::default(),
+                                                                    news_dedup_window_seconds: 300,
+                                                                    news_trade_size_multiplier: dec!(1.0),
+                                                                    news_trade_max_value_usd: None,
+                                                                    news_reentry_cooldown_seconds: 0,
+                                                                    regime_detection_method: Default::default(),
+                                                                    hurst_lookback: 50,
+                                                                    relative_stop_benchmark: None,
+                                                                    relative_stop_pct: dec!(0.05),
+                                                                    pyramid_max_adds: 0,
+                                                                    pyramid_trigger_pct: dec!(0.02),
+                                                                    exits_bypass_cost_filters: true,
+                                                                    allow_shorts: false,
+                                                                    require_htf_confirmation: false,
+                                                                    trend_timeframe: Timeframe::OneHour,
+                                                                    max_trades_per_symbol_per_day: 0,
+                                                                    open_blackout_minutes: 0,
+                                                                    trading_day_boundary: TradingDayBoundary::default(),
+                                                                    post_stop_cooldown_seconds: 0,
+                                                                    trailing_stop_vol_scale_min: Decimal::ONE,
+                                                                    trailing_stop_vol_scale_max: Decimal::ONE,
+                                                                    candle_history_len: 0,
+                                                                    candle_gap_policy: Default::default(),
+                                                                    candle_gap_max_bars: 60,
+                                                                    cold_start_reward_risk_ratio: dec!(1.0),
+                                                                    cold_start_min_trades: 10,
+                                                                    confirm_strategy: None,
                                                                 });
                                                             }
                                                         }
@@ -681,44 +1047,102 @@ async fn run_single_period_eval(
     Ok(backtest_result_to_opt_result_impl(config, result))
 }
 
+/// How per-symbol objective scores are combined when optimizing over a basket.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum BasketAggregation {
+    /// Mean objective across symbols
+    #[default]
+    Mean,
+    /// Lowest objective across symbols (most robust config wins)
+    WorstCase,
+}
+
+impl std::str::FromStr for BasketAggregation {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        match s.to_lowercase().as_str() {
+            "mean" | "avg" | "average" => Ok(Self::Mean),
+            "worst" | "worst_case" | "worst-case" | "min" => Ok(Self::WorstCase),
+            _ => anyhow::bail!("Invalid basket aggregation: {} (expected mean or worst)", s),
+        }
+    }
+}
+
+/// Combine one config's per-symbol results into a single basket result.
+///
+/// Metrics are averaged (trade counts summed); the objective score is the mean or the
+/// minimum of the per-symbol objective scores depending on `aggregation`. A config the
+/// objective rejected on any symbol stays rejected for the whole basket.
+pub fn aggregate_basket_results(
+    results: Vec<OptimizationResult>,
+    aggregation: BasketAggregation,
+) -> Option<OptimizationResult> {
+    let n = Decimal::from(results.len());
+    let mut iter = results.iter();
+    let first = iter.next()?;
+    if results.len() == 1 {
+        return Some(first.clone());
+    }
+    let mean = |f: fn(&OptimizationResult) -> Decimal| results.iter().map(f).sum::<Decimal>() / n;
+
+    let mut aggregated = first.clone();
+    aggregated.sharpe_ratio = mean(|r| r.sharpe_ratio);
+    aggregated.total_return = mean(|r| r.total_return);
+    aggregated.max_drawdown = mean(|r| r.max_drawdown);
+    aggregated.win_rate = mean(|r| r.win_rate);
+    aggregated.alpha = mean(|r| r.alpha);
+    aggregated.beta = mean(|r| r.beta);
+    aggregated.total_trades = results.iter().map(|r| r.total_trades).sum();
+    aggregated.objective_score = if results.iter().any(OptimizationResult::is_rejected) {
+        REJECTED_OBJECTIVE_SCORE
+    } else {
+        match aggregation {
+            BasketAggregation::Mean => mean(|r| r.objective_score),
+            BasketAggregation::WorstCase => results
+                .iter()
+                .map(|r| r.objective_score)
+                .min()
+                .unwrap_or(Decimal::ZERO),
+        }
+    };
+    Some(aggregated)
+}
+
+/// Evaluate one config on every symbol of the basket and aggregate the results.
+async fn run_basket_eval(
+    market_data: Arc<dyn MarketDataService>,
+    execution_service_factory: Arc<dyn Fn() -> Arc<dyn ExecutionService> + Send + Sync>,
+    config: AnalystConfig,
+    basket: Arc<Vec<(String, Arc<SinglePeriodBars>)>>,
+    aggregation: BasketAggregation,
+    objective: ObjectiveFunction,
+    trade_penalty: TradeCountPenalty,
+) -> Result<OptimizationResult> {
+    let mut per_symbol = Vec::with_capacity(basket.len());
+    for (symbol, prefetched) in basket.iter() {
+        let mut result = run_single_period_eval(
+            market_data.clone(),
+            execution_service_factory.clone(),
+            config.clone(),
+            symbol.clone(),
+            Arc::clone(prefetched),
+        )
+        .await
+        .with_context(|| format!("Basket evaluation failed on {}", symbol))?;
+        result.apply_objective(&objective, &trade_penalty);
+        per_symbol.push(result);
+    }
+    aggregate_basket_results(per_symbol, aggregation)
+        .ok_or_else(|| anyhow::anyhow!("Basket evaluation produced no results"))
+}
+
 /// Shared: build OptimizationResult from backtest (used by grid and genetic).
-fn backtest_result_to_opt_result_impl(
+pub(crate) fn backtest_result_to_opt_result_impl(
     config: AnalystConfig,
     result: BacktestResult,
 ) -> OptimizationResult {
-    let mut trades: Vec<crate::domain::trading::types::Trade> = Vec::new();
-    let mut open_position: Option<&crate::domain::trading::types::Order> = None;
-
-    for order in &result.trades {
-        match order.side {
-            crate::domain::trading::types::OrderSide::Buy => {
-                open_position = Some(order);
-            }
-            crate::domain::trading::types::OrderSide::Sell => {
-                if let Some(buy_order) = open_position {
-                    let pnl = (order.price - buy_order.price) * order.quantity;
-                    trades.push(crate::domain::trading::types::Trade {
-                        id: order.id.clone(),
-                        symbol: order.symbol.clone(),
-                        side: crate::domain::trading::types::OrderSide::Buy,
-                        entry_price: buy_order.price,
-                        exit_price: Some(order.price),
-                        quantity: order.quantity,
-                        pnl,
-                        entry_timestamp: buy_order.timestamp,
-                        exit_timestamp: Some(order.timestamp),
-                        strategy_used: None,
-                        regime_detected: None,
-                        entry_reason: None,
-                        exit_reason: None,
-                        slippage: None,
-                        fees: rust_decimal::Decimal::ZERO,
-                    });
-                    open_position = None;
-                }
-            }
-        }
-    }
+    let trades = result.round_trip_trades();
 
     let metrics =
         crate::domain::performance::metrics::PerformanceMetrics::calculate_time_series_metrics(
@@ -806,28 +1230,47 @@ impl GridSearchOptimizer {
     /// Run grid search optimization.
     /// - train_ratio >= 1.0: single period (one backtest on full range, fastest).
     /// - train_ratio in [0.5, 0.9]: walk-forward (train + test), rejects overfitting.
+    ///
+    /// `timeframe` sets the symbol bar resolution (e.g. "1Min", "5Min", "15Min", "1Hour"; empty = "1Min").
+    /// Coarser frames cut the bars per backtest and speed up every combination, as with
+    /// `GeneticOptimizer::run_optimization`. The SPY benchmark is always daily.
     pub async fn run_optimization(
         &self,
         symbol: &str,
         start: DateTime<Utc>,
         end: DateTime<Utc>,
         train_ratio: f64,
+        timeframe: &str,
     ) -> Result<Vec<OptimizationResult>> {
         use chrono::Duration;
 
         const PARALLEL_WORKERS: usize = 4;
+        let timeframe = if timeframe.is_empty() {
+            "1Min"
+        } else {
+            timeframe
+        };
         let combinations = self.generate_combinations();
         let total_combinations = combinations.len();
 
         // Single period: one backtest per config on full range (no train/test split)
         if train_ratio >= 1.0 {
             info!("GridSearch: Single-period mode (train_ratio >= 1.0) — one backtest per config");
-            info!("GridSearch: Pre-fetching bars for full period...");
+            info!(
+                "GridSearch: Pre-fetching {} bars for full period...",
+                timeframe
+            );
             let bars = self
                 .market_data
-                .get_historical_bars(symbol, start, end, "1Min")
+                .get_historical_bars(symbol, start, end, timeframe)
                 .await
                 .context("Failed to fetch bars")?;
+            ensure_enough_bars(
+                symbol,
+                "history",
+                bars.len(),
+                self.parameter_grid.min_required_bars(),
+            )?;
             let spy_bars = self
                 .market_data
                 .get_historical_bars("SPY", start, end, "1Day")
@@ -879,11 +1322,12 @@ impl GridSearchOptimizer {
             let mut results: Vec<OptimizationResult> =
                 completed.into_iter().filter_map(|(_, r)| r.ok()).collect();
             for r in &mut results {
-                r.calculate_objective_score();
+                r.apply_objective(&self.objective, &self.trade_penalty);
             }
+            results.retain(|r| !r.is_rejected());
             results.sort_by(|a, b| {
-                b.sharpe_ratio
-                    .partial_cmp(&a.sharpe_ratio)
+                b.objective_score
+                    .partial_cmp(&a.objective_score)
                     .unwrap_or(std::cmp::Ordering::Equal)
             });
             return Ok(results);
@@ -912,17 +1356,23 @@ impl GridSearchOptimizer {
             end
         );
 
-        info!("GridSearch: Pre-fetching train/test and SPY bars...");
+        info!(
+            "GridSearch: Pre-fetching {} train/test and SPY bars...",
+            timeframe
+        );
         let train_bars = self
             .market_data
-            .get_historical_bars(symbol, start, train_end, "1Min")
+            .get_historical_bars(symbol, start, train_end, timeframe)
             .await
             .context("Failed to fetch train bars")?;
         let test_bars = self
             .market_data
-            .get_historical_bars(symbol, test_start, end, "1Min")
+            .get_historical_bars(symbol, test_start, end, timeframe)
             .await
             .context("Failed to fetch test bars")?;
+        let required_bars = self.parameter_grid.min_required_bars();
+        ensure_enough_bars(symbol, "train", train_bars.len(), required_bars)?;
+        ensure_enough_bars(symbol, "test", test_bars.len(), required_bars)?;
         let spy_train = self
             .market_data
             .get_historical_bars("SPY", start, train_end, "1Day")
@@ -1016,7 +1466,14 @@ impl GridSearchOptimizer {
                         continue;
                     }
 
-                    test.calculate_objective_score();
+                    test.apply_objective(&self.objective, &self.trade_penalty);
+                    if test.is_rejected() {
+                        debug!(
+                            "GridSearch: Rejected by {:?} objective - OOS drawdown={:.2}%, trades={}",
+                            self.objective, test.max_drawdown, test.total_trades
+                        );
+                        continue;
+                    }
                     debug!(
                         "GridSearch: Result - Sharpe IS={:.2} OOS={:.2}, Return={:.2}%, Score={:.4}",
                         sharpe_is, sharpe_oos, test.total_return, test.objective_score
@@ -1037,10 +1494,10 @@ impl GridSearchOptimizer {
             results.len()
         );
 
-        // Sort by OOS Sharpe (descending)
+        // Sort by OOS objective (descending)
         results.sort_by(|a, b| {
-            b.sharpe_ratio
-                .partial_cmp(&a.sharpe_ratio)
+            b.objective_score
+                .partial_cmp(&a.objective_score)
                 .unwrap_or(std::cmp::Ordering::Equal)
         });
 
@@ -1058,6 +1515,163 @@ impl GridSearchOptimizer {
 }
 
 /// Genetic algorithm optimizer: evolves a population of parameter configs toward higher fitness.
+/// Early-stopping rule for the genetic optimizer: stop once the global-best objective
+/// score has not improved by more than `min_improvement` for `patience` generations.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct EarlyStopping {
+    /// Generations without improvement before stopping (0 = disabled)
+    pub patience: usize,
+    /// Minimum objective-score gain that counts as an improvement
+    pub min_improvement: f64,
+}
+
+impl EarlyStopping {
+    pub fn new(patience: usize, min_improvement: f64) -> Self {
+        Self {
+            patience,
+            min_improvement,
+        }
+    }
+}
+
+/// Adaptive mutation schedule for the genetic optimizer.
+///
+/// The rate starts at `initial_rate` and decays geometrically by `decay` per generation
+/// down to `floor`. When population diversity collapses below `diversity_threshold`
+/// (mean per-gene standard deviation), the rate jumps back to `initial_rate` to escape
+/// the local optimum.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct AdaptiveMutation {
+    pub initial_rate: f64,
+    pub floor: f64,
+    /// Multiplier applied each generation (e.g. 0.9)
+    pub decay: f64,
+    pub diversity_threshold: f64,
+}
+
+impl AdaptiveMutation {
+    /// Uniform random genes in [0, 1] have a std dev of ~0.29; below this the population has converged
+    pub const DEFAULT_DIVERSITY_THRESHOLD: f64 = 0.05;
+
+    pub fn new(initial_rate: f64, floor: f64, decay: f64) -> Self {
+        Self {
+            initial_rate,
+            floor,
+            decay,
+            diversity_threshold: Self::DEFAULT_DIVERSITY_THRESHOLD,
+        }
+    }
+
+    pub fn with_diversity_threshold(mut self, diversity_threshold: f64) -> Self {
+        self.diversity_threshold = diversity_threshold;
+        self
+    }
+
+    /// Mutation rate for `generation` (0-based) given the current population diversity
+    pub fn rate(&self, generation: usize, diversity: f64) -> f64 {
+        if diversity < self.diversity_threshold {
+            return self.initial_rate;
+        }
+        let decayed = self.initial_rate * self.decay.powi(generation.min(i32::MAX as usize) as i32);
+        decayed.max(self.floor)
+    }
+}
+
+/// Seeds part of the genetic optimizer's initial population from previously found optima
+/// (e.g. `~/.rustrade/optimal_parameters.json`) so evolution starts near them.
+#[derive(Debug, Clone)]
+pub struct PopulationSeeding {
+    pub seeds: Vec<OptimalParameters>,
+    /// Share of the initial population built from seeds (0.0–1.0); the rest stays random
+    pub fraction: f64,
+}
+
+impl PopulationSeeding {
+    /// Max per-gene perturbation applied to repeated seeds so they don't duplicate work
+    pub const REPEAT_JITTER: f64 = 0.05;
+
+    pub fn new(seeds: Vec<OptimalParameters>, fraction: f64) -> Self {
+        Self {
+            seeds,
+            fraction: fraction.clamp(0.0, 1.0),
+        }
+    }
+
+    /// Seeded genomes for a population of `population_size`. Seeds are used in order;
+    /// when there are more seeded slots than seeds, repeats are jittered.
+    fn genomes(&self, population_size: usize, bounds: &GeneBounds) -> Vec<[f64; 14]> {
+        if self.seeds.is_empty() {
+            return Vec::new();
+        }
+        let count =
+            ((population_size as f64 * self.fraction).round() as usize).min(population_size);
+        (0..count)
+            .map(|i| {
+                let mut genome =
+                    encode_optimal_parameters(&self.seeds[i % self.seeds.len()], bounds);
+                if i >= self.seeds.len() {
+                    for gene in genome.iter_mut().take(6) {
+                        let jitter = (rand::random::<f64>() * 2.0 - 1.0) * Self::REPEAT_JITTER;
+                        *gene = (*gene + jitter).clamp(0.0, 1.0);
+                    }
+                }
+                genome
+            })
+            .collect()
+    }
+}
+
+/// Mean per-gene standard deviation across the population (0 = all genomes identical).
+fn population_diversity<const N: usize>(population: &[[f64; N]]) -> f64 {
+    if population.len() < 2 || N == 0 {
+        return 0.0;
+    }
+    let n = population.len() as f64;
+    let total_std: f64 = (0..N)
+        .map(|gene| {
+            let mean = population.iter().map(|g| g[gene]).sum::<f64>() / n;
+            let var = population
+                .iter()
+                .map(|g| (g[gene] - mean).powi(2))
+                .sum::<f64>()
+                / n;
+            var.sqrt()
+        })
+        .sum();
+    total_std / N as f64
+}
+
+/// Tracks generations since the last significant improvement of the global best.
+struct PlateauTracker {
+    rule: EarlyStopping,
+    reference: Option<f64>,
+    stale_generations: usize,
+}
+
+impl PlateauTracker {
+    fn new(rule: EarlyStopping) -> Self {
+        Self {
+            rule,
+            reference: None,
+            stale_generations: 0,
+        }
+    }
+
+    /// Record the global-best score after a generation; returns true when the search should stop.
+    fn observe(&mut self, best_score: f64) -> bool {
+        if self
+            .reference
+            .is_none_or(|r| best_score > r + self.rule.min_improvement)
+        {
+            self.reference = Some(best_score);
+            self.stale_generations = 0;
+        } else {
+            self.stale_generations += 1;
+        }
+        self.rule.patience > 0 && self.stale_generations >= self.rule.patience
+    }
+}
+
 pub struct GeneticOptimizer {
     market_data: Arc<dyn MarketDataService>,
     execution_service_factory: Arc<dyn Fn() -> Arc<dyn ExecutionService> + Send + Sync>,
@@ -1070,6 +1684,12 @@ pub struct GeneticOptimizer {
     tournament_size: usize,
     /// When set, apply this risk appetite to each decoded config before evaluation.
     risk_score: Option<u8>,
+    early_stopping: Option<EarlyStopping>,
+    /// Overrides the fixed `mutation_rate` when set
+    adaptive_mutation: Option<AdaptiveMutation>,
+    seeding: Option<PopulationSeeding>,
+    objective: ObjectiveFunction,
+    trade_penalty: TradeCountPenalty,
 }
 
 impl GeneticOptimizer {
@@ -1096,9 +1716,51 @@ impl GeneticOptimizer {
             mutation_rate,
             tournament_size: 3,
             risk_score,
+            early_stopping: None,
+            adaptive_mutation: None,
+            seeding: None,
+            objective: ObjectiveFunction::default(),
+            trade_penalty: TradeCountPenalty::default(),
         }
     }
 
+    /// Rank candidates by `objective` instead of the default weighted blend
+    pub fn with_objective(mut self, objective: ObjectiveFunction) -> Self {
+        self.objective = objective;
+        self
+    }
+
+    /// Penalize low-trade genomes in the fitness used for selection, not just the final ranking
+    pub fn with_trade_penalty(mut self, trade_penalty: TradeCountPenalty) -> Self {
+        self.trade_penalty = trade_penalty;
+        self
+    }
+
+    /// Replace the fixed mutation rate with a decaying, diversity-aware schedule
+    pub fn with_adaptive_mutation(mut self, adaptive_mutation: AdaptiveMutation) -> Self {
+        self.adaptive_mutation = Some(adaptive_mutation);
+        self
+    }
+
+    fn mutation_rate_for(&self, generation: usize, population: &[[f64; 14]]) -> f64 {
+        match self.adaptive_mutation {
+            Some(schedule) => schedule.rate(generation, population_diversity(population)),
+            None => self.mutation_rate,
+        }
+    }
+
+    /// Start part of the population from known-good parameters instead of at random
+    pub fn with_seeding(mut self, seeding: PopulationSeeding) -> Self {
+        self.seeding = Some(seeding);
+        self
+    }
+
+    /// Stop before `generations` once the global best plateaus
+    pub fn with_early_stopping(mut self, early_stopping: EarlyStopping) -> Self {
+        self.early_stopping = Some(early_stopping);
+        self
+    }
+
     /// Run genetic optimization (single period, parallel evaluation). Returns results sorted by objective.
     ///
     /// # Bottlenecks
@@ -1114,6 +1776,27 @@ impl GeneticOptimizer {
         start: DateTime<Utc>,
         end: DateTime<Utc>,
         timeframe: &str,
+    ) -> Result<Vec<OptimizationResult>> {
+        self.run_basket_optimization(
+            &[symbol.to_string()],
+            start,
+            end,
+            timeframe,
+            BasketAggregation::Mean,
+        )
+        .await
+    }
+
+    /// Run genetic optimization where each candidate is backtested on every symbol of `symbols`
+    /// and ranked by the aggregated objective, so parameters generalize across the basket.
+    /// Cost grows linearly with the basket size.
+    pub async fn run_basket_optimization(
+        &self,
+        symbols: &[String],
+        start: DateTime<Utc>,
+        end: DateTime<Utc>,
+        timeframe: &str,
+        aggregation: BasketAggregation,
     ) -> Result<Vec<OptimizationResult>> {
         const PARALLEL_WORKERS: usize = 4;
         let timeframe = if timeframe.is_empty() {
@@ -1125,70 +1808,97 @@ impl GeneticOptimizer {
             "GeneticOptimizer: Pre-fetching bars (timeframe={})...",
             timeframe
         );
-        eprintln!(
-            "[optimize] Fetching {} bars for {} ({} to {}) — may take several minutes for large ranges...",
-            timeframe,
-            symbol,
-            start.format("%Y-%m-%d"),
-            end.format("%Y-%m-%d")
-        );
-        let bars = self
-            .market_data
-            .get_historical_bars(symbol, start, end, timeframe)
-            .await
-            .context("Failed to fetch bars")?;
-        eprintln!(
-            "[optimize] Loaded {} bars. Fetching SPY benchmark...",
-            bars.len()
-        );
         let spy_bars = self
             .market_data
             .get_historical_bars("SPY", start, end, "1Day")
             .await
             .unwrap_or_default();
+        let required_bars = self.bounds.min_required_bars();
+        let mut basket = Vec::with_capacity(symbols.len());
+        let mut bar_count = 0;
+        for symbol in symbols {
+            eprintln!(
+                "[optimize] Fetching {} bars for {} ({} to {}) — may take several minutes for large ranges...",
+                timeframe,
+                symbol,
+                start.format("%Y-%m-%d"),
+                end.format("%Y-%m-%d")
+            );
+            let bars = self
+                .market_data
+                .get_historical_bars(symbol, start, end, timeframe)
+                .await
+                .with_context(|| format!("Failed to fetch bars for {}", symbol))?;
+            eprintln!("[optimize] Loaded {} bars for {}", bars.len(), symbol);
+            if bars.len() < required_bars && symbols.len() > 1 {
+                warn!(
+                    "GeneticOptimizer: {} has {} bars (need {}), dropping it from the basket",
+                    symbol,
+                    bars.len(),
+                    required_bars
+                );
+                continue;
+            }
+            ensure_enough_bars(symbol, "history", bars.len(), required_bars)?;
+            bar_count += bars.len();
+            basket.push((
+                symbol.clone(),
+                Arc::new(SinglePeriodBars {
+                    bars,
+                    start,
+                    end,
+                    spy_bars: spy_bars.clone(),
+                }),
+            ));
+        }
+        if basket.is_empty() {
+            anyhow::bail!(
+                "GeneticOptimizer: no symbol in {:?} has the {} bars the longest lookback needs",
+                symbols,
+                required_bars
+            );
+        }
         eprintln!("[optimize] Data ready. Starting evolution...");
-        let bar_count = bars.len();
-        let prefetched = Arc::new(SinglePeriodBars {
-            bars,
-            start,
-            end,
-            spy_bars,
-        });
+        let basket = Arc::new(basket);
         info!(
-            "GeneticOptimizer: Loaded {} bars. Running {} individuals × {} generations ({} workers)",
-            bar_count, self.population_size, self.generations, PARALLEL_WORKERS
+            "GeneticOptimizer: Loaded {} bars across {} symbol(s), {:?} aggregation, {:?} objective. Running {} individuals × {} generations ({} workers)",
+            bar_count,
+            basket.len(),
+            aggregation,
+            self.objective,
+            self.population_size,
+            self.generations,
+            PARALLEL_WORKERS
         );
         let progress_start = Instant::now();
 
         const GENOME_LEN: usize = 14;
-        let mut population: Vec<[f64; GENOME_LEN]> = (0..self.population_size)
-            .map(|_| {
-                [
-                    rand::random::<f64>(),
-                    rand::random::<f64>(),
-                    rand::random::<f64>(),
-                    rand::random::<f64>(),
-                    rand::random::<f64>(),
-                    rand::random::<f64>(),
-                    rand::random::<f64>(),
-                    rand::random::<f64>(),
-                    rand::random::<f64>(),
-                    rand::random::<f64>(),
-                    rand::random::<f64>(),
-                    rand::random::<f64>(),
-                    rand::random::<f64>(),
-                    rand::random::<f64>(),
-                ]
-            })
-            .collect();
+        let mut population: Vec<[f64; GENOME_LEN]> = self
+            .seeding
+            .as_ref()
+            .map(|seeding| seeding.genomes(self.population_size, &self.bounds))
+            .unwrap_or_default();
+        if !population.is_empty() {
+            info!(
+                "GeneticOptimizer: Seeded {} of {} individuals from {} known optima",
+                population.len(),
+                self.population_size,
+                self.seeding.as_ref().map_or(0, |s| s.seeds.len())
+            );
+        }
+        while population.len() < self.population_size {
+            population.push(std::array::from_fn(|_| rand::random::<f64>()));
+        }
 
         let market_data = self.market_data.clone();
         let execution_service_factory = self.execution_service_factory.clone();
         let bounds = self.bounds.clone();
         let strategy_mode = self.strategy_mode;
         let min_profit_ratio = self.min_profit_ratio;
-        let symbol_owned = symbol.to_string();
+        let objective = self.objective;
+        let trade_penalty = self.trade_penalty;
         let mut global_best: Option<(OptimizationResult, [f64; GENOME_LEN])> = None;
+        let mut plateau = self.early_stopping.map(PlateauTracker::new);
 
         for generation in 0..self.generations {
             let elapsed_secs = progress_start.elapsed().as_secs();
@@ -1228,15 +1938,16 @@ impl GeneticOptimizer {
                     .map(|(i, config)| {
                         let market_data = market_data.clone();
                         let execution_service_factory = execution_service_factory.clone();
-                        let prefetched = Arc::clone(&prefetched);
-                        let symbol_owned = symbol_owned.clone();
+                        let basket = Arc::clone(&basket);
                         async move {
-                            let r = run_single_period_eval(
+                            let r = run_basket_eval(
                                 market_data,
                                 execution_service_factory,
                                 config,
-                                symbol_owned,
-                                prefetched,
+                                basket,
+                                aggregation,
+                                objective,
+                                trade_penalty,
                             )
                             .await;
                             (i, r)
@@ -1246,13 +1957,11 @@ impl GeneticOptimizer {
                     .collect()
                     .await;
 
+            // Objective scores are already set (and aggregated across the basket) by run_basket_eval
             let mut scored: Vec<(usize, OptimizationResult)> = completed
                 .into_iter()
                 .filter_map(|(i, r)| r.ok().map(|res| (i, res)))
                 .collect();
-            for (_, res) in &mut scored {
-                res.calculate_objective_score();
-            }
             scored.sort_by(|a, b| {
                 b.1.objective_score
                     .partial_cmp(&a.1.objective_score)
@@ -1295,9 +2004,38 @@ impl GeneticOptimizer {
                 gen_elapsed_min
             );
 
-            if generation + 1 == self.generations {
-                let mut results: Vec<OptimizationResult> =
-                    scored.into_iter().map(|(_, r)| r).collect();
+            let global_best_score = global_best
+                .as_ref()
+                .and_then(|(g, _)| g.objective_score.to_f64())
+                .unwrap_or(-1e9);
+            let plateaued = plateau
+                .as_mut()
+                .is_some_and(|p| p.observe(global_best_score));
+            if plateaued && let Some(rule) = self.early_stopping {
+                eprintln!(
+                    "[optimize] Early stop at generation {}/{} — best score {:.4} not improved by > {} for {} generations",
+                    generation + 1,
+                    self.generations,
+                    global_best_score,
+                    rule.min_improvement,
+                    rule.patience
+                );
+                info!(
+                    "GeneticOptimizer: Early stop at generation {}/{} — best score {:.4} not improved by > {} for {} generations",
+                    generation + 1,
+                    self.generations,
+                    global_best_score,
+                    rule.min_improvement,
+                    rule.patience
+                );
+            }
+
+            if generation + 1 == self.generations || plateaued {
+                let mut results: Vec<OptimizationResult> = scored
+                    .into_iter()
+                    .map(|(_, r)| r)
+                    .filter(|r| !r.is_rejected())
+                    .collect();
                 for r in &mut results {
                     r.risk_score = self.risk_score;
                 }
@@ -1307,7 +2045,9 @@ impl GeneticOptimizer {
                         .unwrap_or(std::cmp::Ordering::Equal)
                 });
                 // Ensure global best across all generations is in results and first
-                if let Some((ref mut gb_res, _)) = global_best {
+                if let Some((ref mut gb_res, _)) = global_best
+                    && !gb_res.is_rejected()
+                {
                     gb_res.risk_score = self.risk_score;
                     let gb_score = gb_res.objective_score.to_f64().unwrap_or(-1e9);
                     let current_best = results
@@ -1333,13 +2073,22 @@ impl GeneticOptimizer {
             }
 
             let indices_by_fitness: Vec<usize> = scored.into_iter().map(|(i, _)| i).collect();
+            let mutation_rate = self.mutation_rate_for(generation, &population);
+            if self.adaptive_mutation.is_some() {
+                debug!(
+                    "GeneticOptimizer: gen {} mutation rate = {:.3} (diversity {:.3})",
+                    generation + 1,
+                    mutation_rate,
+                    population_diversity(&population)
+                );
+            }
             let mut new_population = Vec::with_capacity(self.population_size);
             new_population.push(population[indices_by_fitness[0]]);
             while new_population.len() < self.population_size {
                 let p1 = self.tournament_select(&population, &indices_by_fitness);
                 let p2 = self.tournament_select(&population, &indices_by_fitness);
                 let mut child = self.crossover(&p1, &p2);
-                self.mutate(&mut child);
+                Self::mutate(&mut child, mutation_rate);
                 new_population.push(child);
             }
             population = new_population;
@@ -1377,9 +2126,9 @@ impl GeneticOptimizer {
         child
     }
 
-    fn mutate(&self, genome: &mut [f64; 14]) {
+    fn mutate(genome: &mut [f64; 14], mutation_rate: f64) {
         for g in genome.iter_mut() {
-            if rand::random::<f64>() < self.mutation_rate {
+            if rand::random::<f64>() < mutation_rate {
                 *g = (*g + rand::random_range(-0.2_f64..=0.2)).clamp(0.0, 1.0);
             }
         }
@@ -1469,6 +2218,8 @@ mod tests {
                 ema_fast_period: 50,
                 ema_slow_period: 150,
                 take_profit_pct: dec!(0.05),
+                take_profit_mode: Default::default(),
+                take_profit_atr_multiplier: dec!(3.0),
                 min_hold_time_minutes: 0,
                 signal_confirmation_bars: 1,
                 spread_bps: dec!(5.0),
@@ -1498,6 +2249,33 @@ mod tests {
                 orderflow_stacked_count: 3,
                 orderflow_volume_profile_lookback: 100,
                 ensemble_weights: Default::default(),
+                trading_windows: Default::default(),
+                news_dedup_window_seconds: 300,
+                news_trade_size_multiplier: dec!(1.0),
+                news_trade_max_value_usd: None,
+                news_reentry_cooldown_seconds: 0,
+                regime_detection_method: Default::default(),
+                hurst_lookback: 50,
+                relative_stop_benchmark: None,
+                relative_stop_pct: dec!(0.05),
+                pyramid_max_adds: 0,
+                pyramid_trigger_pct: dec!(0.02),
+                exits_bypass_cost_filters: true,
+                allow_shorts: false,
+                require_htf_confirmation: false,
+                trend_timeframe: Timeframe::OneHour,
+                max_trades_per_symbol_per_day: 0,
+                open_blackout_minutes: 0,
+                trading_day_boundary: TradingDayBoundary::default(),
+                post_stop_cooldown_seconds: 0,
+                trailing_stop_vol_scale_min: Decimal::ONE,
+                trailing_stop_vol_scale_max: Decimal::ONE,
+                candle_history_len: 0,
+                candle_gap_policy: Default::default(),
+                candle_gap_max_bars: 60,
+                cold_start_reward_risk_ratio: dec!(1.0),
+                cold_start_min_trades: 10,
+                confirm_strategy: None,
             },
             sharpe_ratio: dec!(2.0),
             total_return: dec!(15.0),
@@ -1552,6 +2330,268 @@ mod tests {
         assert!((result.objective_score - dec!(0.59)).abs() < dec!(0.01));
     }
 
+    #[test]
+    fn test_objective_functions_rank_differently() {
+        let result = |sharpe, ret, dd, trades| OptimizationResult {
+            params: AnalystConfig::default(),
+            sharpe_ratio: sharpe,
+            total_return: ret,
+            max_drawdown: dd,
+            win_rate: dec!(50.0),
+            total_trades: trades,
+            objective_score: Decimal::ZERO,
+            alpha: Decimal::ZERO,
+            beta: Decimal::ZERO,
+            in_sample_sharpe: None,
+            risk_score: None,
+        };
+        // Aggressive: higher return and Sharpe, deep drawdown. Defensive: shallow drawdown.
+        let aggressive = result(dec!(1.5), dec!(40.0), dec!(-25.0), 40);
+        let defensive = result(dec!(1.0), dec!(12.0), dec!(-4.0), 40);
+
+        assert!(
+            ObjectiveFunction::Sharpe.score(&aggressive)
+                > ObjectiveFunction::Sharpe.score(&defensive)
+        );
+        assert_eq!(ObjectiveFunction::Calmar.score(&aggressive), dec!(1.6));
+        assert_eq!(ObjectiveFunction::Calmar.score(&defensive), dec!(3));
+        assert_eq!(ObjectiveFunction::MinDrawdown.score(&defensive), dec!(-4.0));
+
+        let capped: ObjectiveFunction = "return:20".parse().unwrap();
+        assert_eq!(capped.score(&defensive), dec!(12.0));
+        let mut rejected = aggressive.clone();
+        rejected.apply_objective(&capped, &TradeCountPenalty::default());
+        assert!(rejected.is_rejected());
+
+        // Never trading is not a drawdown win
+        let idle = result(Decimal::ZERO, Decimal::ZERO, Decimal::ZERO, 0);
+        assert_eq!(
+            ObjectiveFunction::MinDrawdown.score(&idle),
+            REJECTED_OBJECTIVE_SCORE
+        );
+
+        // Default objective matches the weighted blend
+        let mut blended = aggressive.clone();
+        blended.calculate_objective_score();
+        assert_eq!(
+            ObjectiveFunction::default().score(&aggressive),
+            blended.objective_score
+        );
+    }
+
+    #[test]
+    fn test_trade_count_penalty_applies_to_every_objective() {
+        let result = |trades| OptimizationResult {
+            params: AnalystConfig::default(),
+            sharpe_ratio: dec!(3.0),
+            total_return: dec!(10.0),
+            max_drawdown: dec!(-30.0),
+            win_rate: dec!(100.0),
+            total_trades: trades,
+            objective_score: Decimal::ZERO,
+            alpha: Decimal::ZERO,
+            beta: Decimal::ZERO,
+            in_sample_sharpe: None,
+            risk_score: None,
+        };
+        let penalty = TradeCountPenalty::new(20, dec!(1.0));
+        assert_eq!(penalty.penalty(0), dec!(1.0));
+        assert_eq!(penalty.penalty(15), dec!(0.25));
+        assert_eq!(penalty.penalty(20), Decimal::ZERO);
+
+        // A lucky two-trade config no longer outranks a well-sampled one under Sharpe
+        let mut lucky = result(2);
+        let mut sampled = result(40);
+        sampled.sharpe_ratio = dec!(2.5);
+        lucky.apply_objective(&ObjectiveFunction::Sharpe, &penalty);
+        sampled.apply_objective(&ObjectiveFunction::Sharpe, &penalty);
+        assert_eq!(lucky.objective_score, dec!(2.1));
+        assert!(sampled.objective_score > lucky.objective_score);
+
+        // Rejected stays rejected, and a zero-strength penalty is a no-op
+        let cap = ObjectiveFunction::ReturnWithDrawdownCap {
+            max_drawdown_pct: dec!(20),
+        };
+        lucky.apply_objective(&cap, &penalty);
+        assert_eq!(lucky.objective_score, REJECTED_OBJECTIVE_SCORE);
+        lucky.apply_objective(
+            &ObjectiveFunction::Sharpe,
+            &TradeCountPenalty::new(20, dec!(0)),
+        );
+        assert_eq!(lucky.objective_score, dec!(3.0));
+    }
+
+    #[test]
+    fn test_objective_function_parsing() {
+        assert_eq!(
+            "blend".parse::<ObjectiveFunction>().unwrap(),
+            ObjectiveFunction::WeightedBlend
+        );
+        assert_eq!(
+            "Sharpe".parse::<ObjectiveFunction>().unwrap(),
+            ObjectiveFunction::Sharpe
+        );
+        assert_eq!(
+            "min_drawdown".parse::<ObjectiveFunction>().unwrap(),
+            ObjectiveFunction::MinDrawdown
+        );
+        assert_eq!(
+            "return:15.5".parse::<ObjectiveFunction>().unwrap(),
+            ObjectiveFunction::ReturnWithDrawdownCap {
+                max_drawdown_pct: dec!(15.5)
+            }
+        );
+        assert!("return".parse::<ObjectiveFunction>().is_err());
+        assert!("return:-5".parse::<ObjectiveFunction>().is_err());
+        assert!("sortino".parse::<ObjectiveFunction>().is_err());
+    }
+
+    #[test]
+    fn test_plateau_tracker_stops_after_patience() {
+        let mut tracker = PlateauTracker::new(EarlyStopping::new(2, 0.01));
+        assert!(!tracker.observe(1.0));
+        assert!(!tracker.observe(1.5)); // improvement resets
+        assert!(!tracker.observe(1.505)); // below min_improvement: stale 1
+        assert!(tracker.observe(1.509)); // stale 2 -> stop
+
+        // Small gains accumulate against the last significant improvement
+        let mut tracker = PlateauTracker::new(EarlyStopping::new(3, 0.01));
+        tracker.observe(1.0);
+        tracker.observe(1.006);
+        assert!(!tracker.observe(1.012)); // 0.012 above reference -> reset
+        assert_eq!(tracker.stale_generations, 0);
+    }
+
+    #[test]
+    fn test_aggregate_basket_results() {
+        let result = |score: Decimal, trades: usize| OptimizationResult {
+            params: AnalystConfig::default(),
+            sharpe_ratio: score,
+            total_return: score * dec!(10),
+            max_drawdown: dec!(5),
+            win_rate: dec!(50),
+            total_trades: trades,
+            objective_score: score,
+            alpha: Decimal::ZERO,
+            beta: Decimal::ONE,
+            in_sample_sharpe: None,
+            risk_score: None,
+        };
+        let basket = vec![
+            result(dec!(1.0), 10),
+            result(dec!(-0.5), 4),
+            result(dec!(2.0), 6),
+        ];
+
+        let mean = aggregate_basket_results(basket.clone(), BasketAggregation::Mean).unwrap();
+        assert_eq!(mean.objective_score, dec!(2.5) / dec!(3));
+        assert_eq!(mean.total_return, dec!(25) / dec!(3));
+        assert_eq!(mean.total_trades, 20);
+
+        let worst = aggregate_basket_results(basket.clone(), BasketAggregation::WorstCase).unwrap();
+        assert_eq!(worst.objective_score, dec!(-0.5));
+
+        // A rejection on one symbol rejects the whole basket
+        let mut with_rejected = basket;
+        with_rejected[1].objective_score = REJECTED_OBJECTIVE_SCORE;
+        assert!(
+            aggregate_basket_results(with_rejected, BasketAggregation::Mean)
+                .unwrap()
+                .is_rejected()
+        );
+
+        assert!(aggregate_basket_results(vec![], BasketAggregation::Mean).is_none());
+        assert_eq!(
+            "worst".parse::<BasketAggregation>().unwrap(),
+            BasketAggregation::WorstCase
+        );
+    }
+
+    #[test]
+    fn test_adaptive_mutation_decays_to_floor() {
+        let schedule = AdaptiveMutation::new(0.4, 0.05, 0.5);
+        assert_eq!(schedule.rate(0, 0.3), 0.4);
+        assert_eq!(schedule.rate(1, 0.3), 0.2);
+        assert_eq!(schedule.rate(2, 0.3), 0.1);
+        assert_eq!(schedule.rate(10, 0.3), 0.05);
+    }
+
+    #[test]
+    fn test_adaptive_mutation_resets_on_diversity_collapse() {
+        let schedule = AdaptiveMutation::new(0.4, 0.05, 0.5);
+        let converged = vec![[0.5_f64; 14]; 8];
+        let diversity = population_diversity(&converged);
+        assert_eq!(diversity, 0.0);
+        assert_eq!(schedule.rate(10, diversity), 0.4);
+
+        let spread: Vec<[f64; 14]> = (0..8).map(|i| [i as f64 / 7.0; 14]).collect();
+        assert!(population_diversity(&spread) > AdaptiveMutation::DEFAULT_DIVERSITY_THRESHOLD);
+    }
+
+    fn saved_optimum() -> OptimalParameters {
+        OptimalParameters::new(
+            AssetType::Stock,
+            RiskProfile::Balanced,
+            20,
+            80,
+            dec!(62.0),
+            dec!(3.0),
+            dec!(0.006),
+            300,
+            "AAPL".to_string(),
+            dec!(1.2),
+            dec!(15.0),
+            dec!(8.0),
+            dec!(55.0),
+            40,
+        )
+    }
+
+    #[test]
+    fn test_encode_optimal_parameters_round_trips() {
+        let bounds = ParameterGrid::default().gene_bounds();
+        let genome = encode_optimal_parameters(&saved_optimum(), &bounds);
+        let config = decode_genome(&genome, &bounds, StrategyMode::Advanced, dec!(1.5));
+
+        assert_eq!(config.fast_sma_period, 20);
+        assert_eq!(config.slow_sma_period, 80);
+        assert_eq!(config.order_cooldown_seconds, 300);
+        let close = |a: Decimal, b: f64| (a.to_f64().unwrap() - b).abs() < 1e-9;
+        assert!(close(config.rsi_threshold, 62.0));
+        assert!(close(config.trailing_stop_atr_multiplier, 3.0));
+        assert!(close(config.trend_divergence_threshold, 0.006));
+    }
+
+    #[test]
+    fn test_population_seeding_fills_fraction() {
+        let bounds = ParameterGrid::default().gene_bounds();
+        let seeding = PopulationSeeding::new(vec![saved_optimum()], 0.25);
+        let genomes = seeding.genomes(20, &bounds);
+        assert_eq!(genomes.len(), 5);
+        // The first copy is exact; repeats stay within the jitter
+        let exact = encode_optimal_parameters(&saved_optimum(), &bounds);
+        assert_eq!(genomes[0][..6], exact[..6]);
+        for genome in &genomes[1..] {
+            for gene in 0..6 {
+                assert!((genome[gene] - exact[gene]).abs() <= PopulationSeeding::REPEAT_JITTER);
+            }
+        }
+
+        assert!(
+            PopulationSeeding::new(Vec::new(), 0.5)
+                .genomes(20, &bounds)
+                .is_empty()
+        );
+    }
+
+    #[test]
+    fn test_plateau_tracker_zero_patience_never_stops() {
+        let mut tracker = PlateauTracker::new(EarlyStopping::new(0, 0.0));
+        for _ in 0..10 {
+            assert!(!tracker.observe(1.0));
+        }
+    }
+
     #[test]
     fn test_gene_bounds_includes_modern_params() {
         let grid = ParameterGrid::default();
@@ -1592,4 +2632,38 @@ mod tests {
         assert_eq!(first.zscore_lookback, 20);
         assert_eq!(first.orderflow_stacked_count, 3);
     }
+
+    #[tokio::test]
+    async fn test_run_optimization_rejects_insufficient_history() {
+        use crate::domain::ports::ExecutionService;
+        use tokio::sync::RwLock;
+
+        let grid = ParameterGrid::default();
+        // Longest default lookback is the 100-bar slow SMA
+        assert_eq!(grid.min_required_bars(), 200);
+
+        let portfolio = Arc::new(RwLock::new(
+            crate::domain::trading::portfolio::Portfolio::default(),
+        ));
+        let exec_factory: Arc<dyn Fn() -> Arc<dyn ExecutionService> + Send + Sync> =
+            Arc::new(move || {
+                Arc::new(crate::infrastructure::mock::MockExecutionService::new(
+                    portfolio.clone(),
+                ))
+            });
+        let optimizer = GridSearchOptimizer::new(
+            Arc::new(crate::infrastructure::mock::MockMarketDataService::new()),
+            exec_factory,
+            grid,
+            StrategyMode::Ensemble,
+            dec!(1.5),
+        );
+        let end = Utc::now();
+        let err = optimizer
+            .run_optimization("TEST", end - chrono::Duration::days(5), end, 1.0, "1Min")
+            .await
+            .unwrap_err()
+            .to_string();
+        assert!(err.contains("got 0 bars, need at least 200"), "{}", err);
+    }
 }
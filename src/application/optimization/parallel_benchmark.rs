@@ -32,7 +32,7 @@ pub struct BatchBacktestResult {
 /// use chrono::Utc;
 ///
 /// # async fn example(market_service: Arc<dyn rustrade::domain::ports::MarketDataService>, config: AnalystConfig) {
-/// let runner = ParallelBenchmarkRunner::new(market_service, config);
+/// let runner = ParallelBenchmarkRunner::new(market_service, config, rust_decimal::Decimal::from(100_000));
 /// let symbols = vec!["AAPL".to_string(), "TSLA".to_string(), "NVDA".to_string()];
 /// let start = Utc::now() - chrono::Duration::days(30);
 /// let end = Utc::now();
@@ -49,6 +49,7 @@ pub struct BatchBacktestResult {
 pub struct ParallelBenchmarkRunner {
     market_service: Arc<dyn MarketDataService>,
     config: AnalystConfig,
+    initial_cash: Decimal,
 }
 
 impl ParallelBenchmarkRunner {
@@ -58,10 +59,16 @@ impl ParallelBenchmarkRunner {
     ///
     /// * `market_service` - Market data service for fetching historical data
     /// * `config` - Analyst configuration to use for all backtests
-    pub fn new(market_service: Arc<dyn MarketDataService>, config: AnalystConfig) -> Self {
+    /// * `initial_cash` - Starting capital of each backtest's portfolio
+    pub fn new(
+        market_service: Arc<dyn MarketDataService>,
+        config: AnalystConfig,
+        initial_cash: Decimal,
+    ) -> Self {
         Self {
             market_service,
             config,
+            initial_cash,
         }
     }
 
@@ -97,11 +104,20 @@ impl ParallelBenchmarkRunner {
             .map(|symbol| {
                 let market_service = self.market_service.clone();
                 let config = self.config.clone();
+                let initial_cash = self.initial_cash;
                 let symbol_clone = symbol.clone(); // Clone before moving into async
 
                 // Block on the async task from within the Rayon thread pool
                 let result = handle.block_on(async move {
-                    Self::run_single(&market_service, &config, &symbol_clone, start, end).await
+                    Self::run_single(
+                        &market_service,
+                        &config,
+                        &symbol_clone,
+                        start,
+                        end,
+                        initial_cash,
+                    )
+                    .await
                 });
 
                 BatchBacktestResult {
@@ -122,10 +138,12 @@ impl ParallelBenchmarkRunner {
         symbol: &str,
         start: DateTime<Utc>,
         end: DateTime<Utc>,
+        initial_cash: Decimal,
     ) -> Result<BacktestResult> {
         // Create a fresh portfolio for this backtest
         let mut portfolio = Portfolio::new();
-        portfolio.cash = Decimal::ZERO; // Explicitly zero. Capital must be injected via events or config.
+        portfolio.allow_shorts = config.allow_shorts;
+        portfolio.reset(initial_cash);
         let portfolio_lock = Arc::new(RwLock::new(portfolio));
 
         // Get transaction costs from environment or use defaults
@@ -204,7 +222,7 @@ mod tests {
         let market_service = Arc::new(MockMarketDataService { candles: vec![] });
         let config = AnalystConfig::default();
 
-        let runner = ParallelBenchmarkRunner::new(market_service, config);
+        let runner = ParallelBenchmarkRunner::new(market_service, config, dec!(100000));
 
         // Just verify it compiles and constructs
         let prices = runner
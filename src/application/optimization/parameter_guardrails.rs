@@ -0,0 +1,239 @@
+use crate::application::agents::analyst_config::AnalystConfig;
+use crate::domain::performance::performance_snapshot::PerformanceSnapshot;
+use rust_decimal::Decimal;
+use rust_decimal::prelude::{FromPrimitive, ToPrimitive};
+
+/// Limits on how far one adaptive re-optimization may move the live parameters,
+/// and how much worse live performance may get before an update is rolled back
+#[derive(Debug, Clone)]
+pub struct ParameterGuardrails {
+    /// Max relative move of SMA periods and lookbacks per update (0.20 = ±20%)
+    pub max_period_step_pct: f64,
+    /// Max relative move of the ATR stop multiplier, signal thresholds and cooldown per update
+    pub max_risk_step_pct: f64,
+    /// Roll back when the rolling Sharpe falls by more than this after an update
+    pub rollback_sharpe_drop: f64,
+    /// Roll back when drawdown grows by more than this fraction after an update
+    pub rollback_drawdown_rise: f64,
+}
+
+impl Default for ParameterGuardrails {
+    fn default() -> Self {
+        Self {
+            max_period_step_pct: 0.20,
+            max_risk_step_pct: 0.20,
+            rollback_sharpe_drop: 0.25,
+            rollback_drawdown_rise: 0.02,
+        }
+    }
+}
+
+impl ParameterGuardrails {
+    /// Pull each tuned parameter of `candidate` back within one step of `previous`.
+    /// Returns a description of every clamp applied.
+    pub fn clamp(&self, previous: &AnalystConfig, candidate: &mut AnalystConfig) -> Vec<String> {
+        let mut clamps = Vec::new();
+        let period = self.max_period_step_pct;
+        let risk = self.max_risk_step_pct;
+
+        macro_rules! clamp_period {
+            ($field:ident) => {
+                if let Some(clamped) = clamp_usize(previous.$field, candidate.$field, period) {
+                    clamps.push(format!(
+                        "{} {} -> {} clamped to {}",
+                        stringify!($field),
+                        previous.$field,
+                        candidate.$field,
+                        clamped
+                    ));
+                    candidate.$field = clamped;
+                }
+            };
+        }
+        macro_rules! clamp_risk {
+            ($field:ident) => {
+                if let Some(clamped) = clamp_decimal(previous.$field, candidate.$field, risk) {
+                    clamps.push(format!(
+                        "{} {} -> {} clamped to {}",
+                        stringify!($field),
+                        previous.$field,
+                        candidate.$field,
+                        clamped
+                    ));
+                    candidate.$field = clamped;
+                }
+            };
+        }
+
+        clamp_period!(fast_sma_period);
+        clamp_period!(slow_sma_period);
+        clamp_period!(stat_momentum_lookback);
+        clamp_period!(zscore_lookback);
+        clamp_period!(smc_ob_lookback);
+
+        clamp_risk!(trailing_stop_atr_multiplier);
+        clamp_risk!(rsi_threshold);
+        clamp_risk!(trend_divergence_threshold);
+        clamp_risk!(stat_momentum_threshold);
+        clamp_risk!(zscore_entry_threshold);
+        clamp_risk!(zscore_exit_threshold);
+        clamp_risk!(orderflow_ofi_threshold);
+        clamp_risk!(smc_min_fvg_size_pct);
+
+        if let Some(clamped) = clamp_usize(
+            previous.order_cooldown_seconds as usize,
+            candidate.order_cooldown_seconds as usize,
+            risk,
+        ) {
+            clamps.push(format!(
+                "order_cooldown_seconds {} -> {} clamped to {}",
+                previous.order_cooldown_seconds, candidate.order_cooldown_seconds, clamped
+            ));
+            candidate.order_cooldown_seconds = clamped as u64;
+        }
+
+        // Independent clamps can cross the SMAs; keep the previous pair then
+        if candidate.fast_sma_period >= candidate.slow_sma_period {
+            clamps.push(format!(
+                "sma {}/{} crossed, kept {}/{}",
+                candidate.fast_sma_period,
+                candidate.slow_sma_period,
+                previous.fast_sma_period,
+                previous.slow_sma_period
+            ));
+            candidate.fast_sma_period = previous.fast_sma_period;
+            candidate.slow_sma_period = previous.slow_sma_period;
+        }
+
+        clamps
+    }
+
+    /// Why live performance in `after` counts as degraded from `before`, if it does
+    pub fn degradation(
+        &self,
+        before: &PerformanceSnapshot,
+        after: &PerformanceSnapshot,
+    ) -> Option<String> {
+        let sharpe_drop = before.sharpe_rolling_30d - after.sharpe_rolling_30d;
+        if sharpe_drop > self.rollback_sharpe_drop {
+            return Some(format!(
+                "Sharpe fell {:.2} -> {:.2}",
+                before.sharpe_rolling_30d, after.sharpe_rolling_30d
+            ));
+        }
+
+        let drawdown_before = before.drawdown_pct.to_f64().unwrap_or(0.0);
+        let drawdown_after = after.drawdown_pct.to_f64().unwrap_or(0.0);
+        if drawdown_after - drawdown_before > self.rollback_drawdown_rise {
+            return Some(format!(
+                "drawdown grew {:.2}% -> {:.2}%",
+                drawdown_before * 100.0,
+                drawdown_after * 100.0
+            ));
+        }
+
+        None
+    }
+}
+
+/// `candidate` limited to `previous` ± `pct` (at least one step), or None if already inside
+fn clamp_usize(previous: usize, candidate: usize, pct: f64) -> Option<usize> {
+    let step = ((previous as f64 * pct).round() as usize).max(1);
+    let clamped = candidate.clamp(previous.saturating_sub(step), previous + step);
+    (clamped != candidate).then_some(clamped)
+}
+
+/// `candidate` limited to `previous` ± `pct` of its magnitude, or None if already inside.
+/// A zero previous value gives no scale to bound against, so it is left free.
+fn clamp_decimal(previous: Decimal, candidate: Decimal, pct: f64) -> Option<Decimal> {
+    let step = previous.abs() * Decimal::from_f64(pct).unwrap_or(Decimal::ZERO);
+    if step.is_zero() {
+        return None;
+    }
+    let clamped = candidate.clamp(previous - step, previous + step);
+    (clamped != candidate).then_some(clamped)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::market::market_regime::MarketRegimeType;
+    use rust_decimal_macros::dec;
+
+    fn snapshot(drawdown: Decimal, sharpe: f64) -> PerformanceSnapshot {
+        PerformanceSnapshot::new(
+            "TEST".to_string(),
+            dec!(10000),
+            drawdown,
+            sharpe,
+            0.5,
+            MarketRegimeType::TrendingUp,
+        )
+    }
+
+    #[test]
+    fn test_clamp_limits_each_step() {
+        let guardrails = ParameterGuardrails::default();
+        let previous = AnalystConfig {
+            fast_sma_period: 20,
+            slow_sma_period: 50,
+            trailing_stop_atr_multiplier: dec!(3.0),
+            ..AnalystConfig::default()
+        };
+        let mut candidate = AnalystConfig {
+            fast_sma_period: 5,
+            slow_sma_period: 55,
+            trailing_stop_atr_multiplier: dec!(6.0),
+            ..previous.clone()
+        };
+
+        let clamps = guardrails.clamp(&previous, &mut candidate);
+
+        assert_eq!(candidate.fast_sma_period, 16);
+        assert_eq!(candidate.slow_sma_period, 55, "Within ±20%, unchanged");
+        assert_eq!(candidate.trailing_stop_atr_multiplier, dec!(3.6));
+        assert_eq!(clamps.len(), 2);
+    }
+
+    #[test]
+    fn test_clamp_keeps_previous_smas_when_they_cross() {
+        let guardrails = ParameterGuardrails::default();
+        let previous = AnalystConfig {
+            fast_sma_period: 10,
+            slow_sma_period: 11,
+            ..AnalystConfig::default()
+        };
+        let mut candidate = AnalystConfig {
+            fast_sma_period: 30,
+            slow_sma_period: 8,
+            ..previous.clone()
+        };
+
+        guardrails.clamp(&previous, &mut candidate);
+
+        assert_eq!(candidate.fast_sma_period, 10);
+        assert_eq!(candidate.slow_sma_period, 11);
+    }
+
+    #[test]
+    fn test_degradation() {
+        let guardrails = ParameterGuardrails::default();
+        let before = snapshot(dec!(0.05), 1.2);
+
+        assert!(
+            guardrails
+                .degradation(&before, &snapshot(dec!(0.06), 1.0))
+                .is_none()
+        );
+        assert!(
+            guardrails
+                .degradation(&before, &snapshot(dec!(0.05), 0.8))
+                .is_some()
+        );
+        assert!(
+            guardrails
+                .degradation(&before, &snapshot(dec!(0.08), 1.2))
+                .is_some()
+        );
+    }
+}
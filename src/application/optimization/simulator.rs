@@ -1,7 +1,9 @@
 use crate::application::agents::analyst::{Analyst, AnalystConfig, AnalystDependencies};
+use crate::application::risk_management::order_throttler::OrderRateLimiter;
 use crate::domain::ports::{ExecutionService, MarketDataService};
+use crate::domain::trading::portfolio::Portfolio;
 use crate::domain::trading::types::MarketEvent;
-use crate::domain::trading::types::{Candle, Order};
+use crate::domain::trading::types::{Candle, Order, OrderSide, Trade};
 use anyhow::{Context, Result};
 use chrono::{DateTime, Utc};
 
@@ -13,11 +15,22 @@ use rust_decimal::prelude::ToPrimitive;
 use std::sync::Arc;
 use std::sync::Mutex;
 use tokio::sync::mpsc;
-use tracing::info;
+use tracing::{debug, info};
+
+/// Costs of one simulated fill under the configured fee model
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct FillCost {
+    /// Commission (and any other fee) charged on the fill
+    pub fee: Decimal,
+    /// Slippage cost, already reflected in the fill price
+    pub slippage: Decimal,
+}
 
 #[derive(Debug, Clone)]
 pub struct BacktestResult {
     pub trades: Vec<Order>,
+    /// Costs of each fill in `trades` (same order)
+    pub fill_costs: Vec<FillCost>,
     pub initial_equity: Decimal,
     pub final_equity: Decimal,
     pub total_return_pct: Decimal,
@@ -28,10 +41,106 @@ pub struct BacktestResult {
     pub benchmark_correlation: f64,
 }
 
+impl BacktestResult {
+    /// Total fees charged across all fills
+    pub fn total_fees(&self) -> Decimal {
+        self.fill_costs.iter().map(|c| c.fee).sum()
+    }
+
+    /// Pair each exit with the preceding entry into closed round-trip trades.
+    /// A buy followed by a sell is a long trade; a sell opening the book followed by a
+    /// buy is a short trade (side = Sell).
+    /// Trade PnL is net of entry and exit fees; slippage is already in the fill prices.
+    pub fn round_trip_trades(&self) -> Vec<Trade> {
+        let cost_of = |i: usize| self.fill_costs.get(i).copied().unwrap_or_default();
+        let mut trades = Vec::new();
+        let mut open_position: Option<(&Order, FillCost)> = None;
+        let mut net_quantity = Decimal::ZERO;
+
+        for (i, order) in self.trades.iter().enumerate() {
+            let net_before = net_quantity;
+            net_quantity += match order.side {
+                OrderSide::Buy => order.quantity,
+                OrderSide::Sell => -order.quantity,
+            };
+            let Some((entry_order, entry_cost)) =
+                open_position.filter(|(entry, _)| entry.side != order.side)
+            else {
+                // A sell only opens a trade when it starts a short, not when it trims a long
+                if order.side == OrderSide::Buy || net_before <= Decimal::ZERO {
+                    open_position = Some((order, cost_of(i)));
+                }
+                continue;
+            };
+            let exit_cost = cost_of(i);
+            // Entry costs are attributed in proportion to the quantity closed
+            let closed_fraction = if entry_order.quantity.is_zero() {
+                Decimal::ONE
+            } else {
+                (order.quantity / entry_order.quantity).min(Decimal::ONE)
+            };
+            let fees = entry_cost.fee * closed_fraction + exit_cost.fee;
+            let slippage = entry_cost.slippage * closed_fraction + exit_cost.slippage;
+            let price_move = match entry_order.side {
+                OrderSide::Buy => order.price - entry_order.price,
+                OrderSide::Sell => entry_order.price - order.price,
+            };
+            trades.push(Trade {
+                id: order.id.clone(),
+                symbol: order.symbol.clone(),
+                side: entry_order.side,
+                entry_price: entry_order.price,
+                exit_price: Some(order.price),
+                quantity: order.quantity,
+                pnl: price_move * order.quantity - fees,
+                entry_timestamp: entry_order.timestamp,
+                exit_timestamp: Some(order.timestamp),
+                strategy_used: entry_order.origin.strategy.clone(),
+                regime_detected: entry_order.origin.regime.clone(),
+                entry_reason: entry_order.reason.clone(),
+                exit_reason: order.reason.clone(),
+                slippage: Some(slippage),
+                fees,
+            });
+            open_position = None;
+        }
+        trades
+    }
+}
+
+/// Quantity actually filled and fees the execution service booked for one order,
+/// inferred from the portfolio before and after execution.
+fn booked_fill(
+    before: &Portfolio,
+    after: &Portfolio,
+    symbol: &str,
+    side: OrderSide,
+    price: Decimal,
+) -> (Decimal, Decimal) {
+    let qty = |p: &Portfolio| {
+        p.positions
+            .get(symbol)
+            .map(|pos| pos.quantity)
+            .unwrap_or(Decimal::ZERO)
+    };
+    let filled = (qty(after) - qty(before)).abs();
+    let cash_delta = after.cash - before.cash;
+    let booked = match side {
+        OrderSide::Buy => -cash_delta - price * filled,
+        OrderSide::Sell => price * filled - cash_delta,
+    };
+    (filled, booked.max(Decimal::ZERO))
+}
+
+/// Live default for MAX_ORDERS_PER_MINUTE
+pub const DEFAULT_MAX_ORDERS_PER_MINUTE: u32 = 10;
+
 pub struct Simulator {
     market_data: Arc<dyn MarketDataService>,
     execution_service: Arc<dyn ExecutionService>,
     config: AnalystConfig,
+    /// Orders per minute of bar time, as enforced live by the OrderThrottler. None = idealized fills.
+    max_orders_per_minute: Option<u32>,
 }
 
 impl Simulator {
@@ -58,9 +167,18 @@ impl Simulator {
             market_data,
             execution_service,
             config,
+            max_orders_per_minute: Some(DEFAULT_MAX_ORDERS_PER_MINUTE),
         }
     }
 
+    /// Cap orders per minute like the live OrderThrottler (`None` disables it for idealized fills).
+    /// Proposals over the cap are skipped: live would queue them past the bar they priced.
+    /// The per-symbol `order_cooldown_seconds` is already enforced by the Analyst on bar time.
+    pub fn with_order_throttle(mut self, max_orders_per_minute: Option<u32>) -> Self {
+        self.max_orders_per_minute = max_orders_per_minute;
+        self
+    }
+
     pub async fn run(
         &self,
         symbol: &str,
@@ -238,6 +356,11 @@ impl Simulator {
         });
 
         let mut executed_trades = Vec::new();
+        let mut fill_costs = Vec::new();
+        // Fees under the configured model that the execution service did not deduct itself
+        let mut unbooked_fees = Decimal::ZERO;
+        let mut rate_limiter = self.max_orders_per_minute.map(OrderRateLimiter::per_minute);
+        let mut throttled_count = 0usize;
         let max_drawdown_pct = Decimal::new(-50, 0); // -50% max loss
 
         while let Some(prop) = proposal_rx.recv().await {
@@ -247,7 +370,8 @@ impl Simulator {
             );
 
             // Circuit Breaker: Check equity before executing
-            if let Ok(portfolio) = self.execution_service.get_portfolio().await {
+            let portfolio_before = self.execution_service.get_portfolio().await.ok();
+            if let Some(portfolio) = &portfolio_before {
                 let current_equity = portfolio.cash
                     + portfolio
                         .positions
@@ -274,10 +398,24 @@ impl Simulator {
                 }
             }
 
-            let costs = self
-                .config
-                .fee_model
-                .calculate_cost(prop.quantity, prop.price, prop.side);
+            if let Some(limiter) = rate_limiter.as_mut()
+                && !limiter.try_acquire(prop.timestamp)
+            {
+                throttled_count += 1;
+                debug!(
+                    "Simulator: Order throttle reached, skipping {:?} {} @ {}",
+                    prop.side, prop.symbol, prop.price
+                );
+                continue;
+            }
+
+            let costs = self.config.fee_model.calculate_order_cost(
+                &prop.symbol,
+                prop.quantity,
+                prop.price,
+                prop.side,
+                prop.order_type,
+            );
             let slippage_amount = costs.slippage_cost;
             let slippage_per_unit = if prop.quantity.is_zero() {
                 Decimal::ZERO
@@ -287,12 +425,12 @@ impl Simulator {
                     .unwrap_or(Decimal::ZERO)
             };
             let execution_price = match prop.side {
-                crate::domain::trading::types::OrderSide::Buy => prop.price + slippage_per_unit,
-                crate::domain::trading::types::OrderSide::Sell => prop.price - slippage_per_unit,
+                OrderSide::Buy => prop.price + slippage_per_unit,
+                OrderSide::Sell => prop.price - slippage_per_unit,
             };
 
             // Execute Immediately to update Portfolio State for next Analyst check
-            let order = crate::domain::trading::types::Order {
+            let mut order = crate::domain::trading::types::Order {
                 id: uuid::Uuid::new_v4().to_string(),
                 symbol: prop.symbol.clone(),
                 side: prop.side,
@@ -301,6 +439,9 @@ impl Simulator {
                 order_type: crate::domain::trading::types::OrderType::Market,
                 status: crate::domain::trading::types::OrderStatus::Filled,
                 timestamp: prop.timestamp,
+                reduce_only: false,
+                origin: prop.origin.clone(),
+                reason: Some(prop.reason.clone()),
             };
 
             if let Err(e) = self.execution_service.execute(order.clone()).await {
@@ -310,6 +451,37 @@ impl Simulator {
                     e
                 );
             } else {
+                // The execution service may fill less than requested (cash/position limits)
+                // and may or may not charge fees itself; reconcile against the portfolio.
+                let portfolio_after = self.execution_service.get_portfolio().await.ok();
+                let (filled_qty, booked_fees) = match (&portfolio_before, &portfolio_after) {
+                    (Some(before), Some(after)) => {
+                        booked_fill(before, after, &prop.symbol, prop.side, execution_price)
+                    }
+                    _ => (order.quantity, Decimal::ZERO),
+                };
+                if filled_qty > Decimal::ZERO && filled_qty != order.quantity {
+                    order.quantity = filled_qty;
+                }
+                let fee = if order.quantity == prop.quantity {
+                    costs.fee
+                } else {
+                    self.config
+                        .fee_model
+                        .calculate_order_cost(
+                            &prop.symbol,
+                            order.quantity,
+                            prop.price,
+                            prop.side,
+                            prop.order_type,
+                        )
+                        .fee
+                };
+                unbooked_fees += (fee - booked_fees).max(Decimal::ZERO);
+                fill_costs.push(FillCost {
+                    fee,
+                    slippage: slippage_per_unit * order.quantity,
+                });
                 executed_trades.push(order);
             }
         }
@@ -318,10 +490,17 @@ impl Simulator {
         feeder_handle.await?;
         analyst_handle.await?;
 
+        if throttled_count > 0 {
+            info!(
+                "Simulator: {} proposal(s) skipped by the order throttle ({:?}/min)",
+                throttled_count, self.max_orders_per_minute
+            );
+        }
+
         // Calculate Final Metrics
         let final_portfolio = self.execution_service.get_portfolio().await?;
 
-        let mut final_equity = final_portfolio.cash;
+        let mut final_equity = final_portfolio.cash - unbooked_fees;
 
         // Recalculate Final Equity with positions valued at `last_close`
         for pos in final_portfolio.positions.values() {
@@ -427,6 +606,7 @@ impl Simulator {
 
         Ok(BacktestResult {
             trades: executed_trades,
+            fill_costs,
             initial_equity,
             final_equity,
             total_return_pct,
@@ -490,6 +670,8 @@ impl CandleRepository for InMemoryCandleRepository {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::domain::trading::types::SignalOrigin;
+    use rust_decimal_macros::dec;
 
     #[test]
     fn test_alpha_beta_calculation() {
@@ -584,6 +766,135 @@ mod tests {
         );
     }
 
+    fn filled(side: OrderSide, price: Decimal, quantity: Decimal, timestamp: i64) -> Order {
+        Order {
+            id: uuid::Uuid::new_v4().to_string(),
+            symbol: "AAPL".to_string(),
+            side,
+            price,
+            quantity,
+            order_type: crate::domain::trading::types::OrderType::Market,
+            status: crate::domain::trading::types::OrderStatus::Filled,
+            timestamp,
+            reduce_only: false,
+            origin: Default::default(),
+            reason: None,
+        }
+    }
+
+    #[test]
+    fn test_round_trip_trades_net_of_fees() {
+        let mut entry = filled(OrderSide::Buy, dec!(100), dec!(10), 1);
+        entry.origin = SignalOrigin {
+            strategy: Some("SMC".to_string()),
+            regime: Some("TrendingUp".to_string()),
+        };
+        entry.reason = Some("Order block retest".to_string());
+        let mut exit = filled(OrderSide::Sell, dec!(110), dec!(5), 2);
+        exit.reason = Some("Partial Take-Profit".to_string());
+        let result = BacktestResult {
+            trades: vec![entry, exit],
+            fill_costs: vec![
+                FillCost {
+                    fee: dec!(2),
+                    slippage: dec!(1),
+                },
+                FillCost {
+                    fee: dec!(1),
+                    slippage: dec!(0.5),
+                },
+            ],
+            initial_equity: dec!(10000),
+            final_equity: dec!(10000),
+            total_return_pct: Decimal::ZERO,
+            buy_and_hold_return_pct: Decimal::ZERO,
+            daily_closes: vec![],
+            alpha: 0.0,
+            beta: 0.0,
+            benchmark_correlation: 0.0,
+        };
+
+        let trades = result.round_trip_trades();
+        assert_eq!(trades.len(), 1);
+        // Half the entry is closed: fees = 2 * 0.5 + 1 = 2, gross = 10 * 5 = 50
+        assert_eq!(trades[0].fees, dec!(2));
+        assert_eq!(trades[0].slippage, Some(dec!(1)));
+        assert_eq!(trades[0].pnl, dec!(48));
+        assert_eq!(result.total_fees(), dec!(3));
+        // Attribution comes from the entry, the exit reason from the closing order
+        assert_eq!(trades[0].strategy_used.as_deref(), Some("SMC"));
+        assert_eq!(trades[0].regime_detected.as_deref(), Some("TrendingUp"));
+        assert_eq!(
+            trades[0].entry_reason.as_deref(),
+            Some("Order block retest")
+        );
+        assert_eq!(
+            trades[0].exit_reason.as_deref(),
+            Some("Partial Take-Profit")
+        );
+    }
+
+    #[test]
+    fn test_round_trip_trades_pairs_short_entries() {
+        let result = BacktestResult {
+            trades: vec![
+                filled(OrderSide::Buy, dec!(100), dec!(10), 1),
+                filled(OrderSide::Sell, dec!(105), dec!(5), 2),
+                // Trims the remaining long: not a short entry
+                filled(OrderSide::Sell, dec!(106), dec!(5), 3),
+                // Opens a short, covered lower
+                filled(OrderSide::Sell, dec!(110), dec!(4), 4),
+                filled(OrderSide::Buy, dec!(100), dec!(4), 5),
+            ],
+            fill_costs: vec![],
+            initial_equity: dec!(10000),
+            final_equity: dec!(10000),
+            total_return_pct: Decimal::ZERO,
+            buy_and_hold_return_pct: Decimal::ZERO,
+            daily_closes: vec![],
+            alpha: 0.0,
+            beta: 0.0,
+            benchmark_correlation: 0.0,
+        };
+
+        let trades = result.round_trip_trades();
+        assert_eq!(trades.len(), 2);
+        assert_eq!(trades[0].side, OrderSide::Buy);
+        assert_eq!(trades[0].pnl, dec!(25));
+        assert_eq!(trades[1].side, OrderSide::Sell);
+        assert_eq!(trades[1].entry_price, dec!(110));
+        assert_eq!(trades[1].pnl, dec!(40));
+    }
+
+    #[test]
+    fn test_booked_fill_detects_fees_and_partial_fills() {
+        let mut before = Portfolio::new();
+        before.reset(dec!(1000));
+
+        // Fee-free service: full fill, nothing booked
+        let mut after = before.clone();
+        after.apply_buy_fill("AAPL", dec!(5), dec!(100), Decimal::ZERO, 1);
+        assert_eq!(
+            booked_fill(&before, &after, "AAPL", OrderSide::Buy, dec!(100)),
+            (dec!(5), Decimal::ZERO)
+        );
+
+        // Service charging its own commission on a reduced fill
+        let mut after = before.clone();
+        after.apply_buy_fill("AAPL", dec!(3), dec!(100), dec!(1.5), 1);
+        assert_eq!(
+            booked_fill(&before, &after, "AAPL", OrderSide::Buy, dec!(100)),
+            (dec!(3), dec!(1.5))
+        );
+
+        let mut closed = after.clone();
+        closed.apply_sell_fill("AAPL", dec!(3), dec!(110), dec!(0.5));
+        assert_eq!(
+            booked_fill(&after, &closed, "AAPL", OrderSide::Sell, dec!(110)),
+            (dec!(3), dec!(0.5))
+        );
+    }
+
     #[test]
     fn test_alpha_beta_empty_returns() {
         let (alpha, beta, correlation) = Simulator::calculate_alpha_beta(&[], &[]);
@@ -1,7 +1,7 @@
 use async_trait::async_trait;
 use rust_decimal::Decimal;
 use std::sync::Arc;
-use tracing::{info, warn};
+use tracing::{debug, info, warn};
 
 use crate::domain::repositories::TradeRepository;
 use crate::domain::trading::types::{Order, OrderSide};
@@ -11,6 +11,11 @@ use crate::domain::trading::types::{Order, OrderSide};
 pub trait WinRateProvider: Send + Sync {
     /// Get the win rate for a symbol (0.0 to 1.0)
     async fn get_win_rate(&self, symbol: &str) -> f64;
+
+    /// Win rate together with the closed trades it is based on (0 = assumed, no history)
+    async fn get_win_rate_sample(&self, symbol: &str) -> (f64, usize) {
+        (self.get_win_rate(symbol).await, 0)
+    }
 }
 
 /// Static win rate provider for testing or safe defaults
@@ -36,6 +41,9 @@ pub struct HistoricalWinRateProvider {
     repository: Arc<dyn TradeRepository>,
     default_win_rate: f64,
     min_trades: usize, // Minimum trades required to use historical data
+    lookback: usize,   // Most recent closed trades considered (0 = full history)
+    halflife: usize,   // Trades after which a result's weight halves (0 = equal weights)
+    prior_weight: f64, // Pseudo-trades at default_win_rate blended in (0 = min_trades cutoff)
 }
 
 impl HistoricalWinRateProvider {
@@ -48,7 +56,79 @@ impl HistoricalWinRateProvider {
             repository,
             default_win_rate,
             min_trades,
+            lookback: 0,
+            halflife: 0,
+            prior_weight: 0.0,
+        }
+    }
+
+    /// Shrink the empirical rate toward `default_win_rate` as if it had been observed over
+    /// `prior_weight` extra trades, instead of ignoring history below `min_trades`:
+    /// rate = (wins + default x prior_weight) / (trades + prior_weight)
+    pub fn with_prior_weight(mut self, prior_weight: f64) -> Self {
+        self.prior_weight = prior_weight.max(0.0);
+        self
+    }
+
+    /// Only the last `lookback` closed trades feed the win rate (0 = full history)
+    pub fn with_lookback(mut self, lookback: usize) -> Self {
+        self.lookback = lookback;
+        self
+    }
+
+    /// Weigh each closed trade by 0.5^(age / halflife), age counted in trades from the
+    /// most recent one, so the rate follows recent results (0 = equal weights)
+    pub fn with_halflife(mut self, halflife: usize) -> Self {
+        self.halflife = halflife;
+        self
+    }
+
+    /// Win rate over the last `lookback` outcomes (oldest first), decayed by `halflife`.
+    /// Returns the rate and the number of trades it was computed from.
+    fn weighted_win_rate(
+        outcomes: &[bool],
+        lookback: usize,
+        halflife: usize,
+    ) -> Option<(f64, usize)> {
+        let (wins, total, trades) = Self::weighted_tally(outcomes, lookback, halflife);
+        if trades == 0 {
+            return None;
         }
+
+        Some((wins / total, trades))
+    }
+
+    /// Decayed weight of the wins and of all trades in the window, and its trade count
+    fn weighted_tally(outcomes: &[bool], lookback: usize, halflife: usize) -> (f64, f64, usize) {
+        let window = if lookback > 0 && outcomes.len() > lookback {
+            &outcomes[outcomes.len() - lookback..]
+        } else {
+            outcomes
+        };
+
+        let mut wins = 0.0;
+        let mut total = 0.0;
+        for (age, &won) in window.iter().rev().enumerate() {
+            let weight = if halflife > 0 {
+                0.5_f64.powf(age as f64 / halflife as f64)
+            } else {
+                1.0
+            };
+            total += weight;
+            if won {
+                wins += weight;
+            }
+        }
+
+        (wins, total, window.len())
+    }
+
+    /// Empirical rate shrunk toward `prior` by `prior_weight` pseudo-trades
+    fn blended_win_rate(wins: f64, total: f64, prior: f64, prior_weight: f64) -> f64 {
+        if total + prior_weight <= 0.0 {
+            return prior;
+        }
+        (wins + prior * prior_weight) / (total + prior_weight)
     }
 
     /// Calculate profit/loss for a closed trade pair (simplistic FIFO matching)
@@ -74,9 +154,8 @@ impl HistoricalWinRateProvider {
     /// Better approach:
     /// Iterate all orders for symbol. Sort by time.
     /// Replay history to calculate PnL of closed positions.
-    fn calculate_win_rate_from_orders(orders: &[Order]) -> Option<(f64, usize)> {
-        let mut wins = 0;
-        let mut total_closed = 0;
+    fn closed_trade_outcomes(orders: &[Order]) -> Vec<bool> {
+        let mut outcomes = Vec::new();
 
         // Simple FIFO Replay
         let mut inventory: Vec<(Decimal, Decimal)> = Vec::new(); // (Price, Qty)
@@ -108,26 +187,23 @@ impl HistoricalWinRateProvider {
 
                     if qty_to_sell == Decimal::ZERO {
                         // We successfully closed some volume
-                        total_closed += 1;
-                        if realized_pnl > Decimal::ZERO {
-                            wins += 1;
-                        }
+                        outcomes.push(realized_pnl > Decimal::ZERO);
                     }
                 }
             }
         }
 
-        if total_closed == 0 {
-            return None;
-        }
-
-        Some((wins as f64 / total_closed as f64, total_closed))
+        outcomes
     }
 }
 
 #[async_trait]
 impl WinRateProvider for HistoricalWinRateProvider {
     async fn get_win_rate(&self, symbol: &str) -> f64 {
+        self.get_win_rate_sample(symbol).await.0
+    }
+
+    async fn get_win_rate_sample(&self, symbol: &str) -> (f64, usize) {
         let orders = match self.repository.find_by_symbol(symbol).await {
             Ok(o) => o,
             Err(e) => {
@@ -135,7 +211,7 @@ impl WinRateProvider for HistoricalWinRateProvider {
                     "Failed to fetch history for {}: {}. Using default.",
                     symbol, e
                 );
-                return self.default_win_rate;
+                return (self.default_win_rate, 0);
             }
         };
 
@@ -143,27 +219,185 @@ impl WinRateProvider for HistoricalWinRateProvider {
         let mut sorted_orders = orders;
         sorted_orders.sort_by_key(|o| o.timestamp);
 
-        let calculated_rate = Self::calculate_win_rate_from_orders(&sorted_orders);
+        let outcomes = Self::closed_trade_outcomes(&sorted_orders);
+
+        if self.prior_weight > 0.0 {
+            let (wins, total, trades) =
+                Self::weighted_tally(&outcomes, self.lookback, self.halflife);
+            let rate =
+                Self::blended_win_rate(wins, total, self.default_win_rate, self.prior_weight);
+            debug!(
+                "Blended Win Rate for {}: {:.2} ({} trades, prior {:.2} x {})",
+                symbol, rate, trades, self.default_win_rate, self.prior_weight
+            );
+            return (rate, outcomes.len());
+        }
+
+        let calculated_rate = Self::weighted_win_rate(&outcomes, self.lookback, self.halflife);
 
         if let Some((rate, total_closed)) = calculated_rate {
             if total_closed < self.min_trades {
-                return self.default_win_rate;
+                return (self.default_win_rate, outcomes.len());
             }
 
             // Weighted blend with default if low sample size?
             // Or just return it if we met min_trades threshold (sort of).
-            // `calculate_win_rate_from_orders` returns None if 0 trades.
+            // `weighted_win_rate` returns None if 0 trades.
 
             // Check if we have enough data points to strictly trust it?
-            // The logic inside `calculate_win_rate_from_orders` counts "Sell Events" as trades.
+            // The logic inside `closed_trade_outcomes` counts "Sell Events" as trades.
             // Let's trust it for now as "Empirical".
             info!(
                 "Empirical Win Rate for {}: {:.2} ({} trades)",
                 symbol, rate, total_closed
             );
-            rate
+            (rate, outcomes.len())
         } else {
-            self.default_win_rate
+            (self.default_win_rate, 0)
+        }
+    }
+}
+
+/// Beta-Binomial win rate: closed trades update a Beta(alpha, beta) prior and the
+/// posterior mean is returned, so early results can't pin the rate to 0% or 100%
+pub struct BayesianWinRateProvider {
+    repository: Arc<dyn TradeRepository>,
+    prior_alpha: f64, // Prior wins (pseudo-count)
+    prior_beta: f64,  // Prior losses (pseudo-count)
+    lookback: usize,  // Most recent closed trades considered (0 = full history)
+    halflife: usize,  // Trades after which a result's weight halves (0 = equal weights)
+}
+
+impl BayesianWinRateProvider {
+    /// Beta(prior_alpha, prior_beta) prior; Beta(1, 1) is uniform and starts at 50%
+    pub fn new(repository: Arc<dyn TradeRepository>, prior_alpha: f64, prior_beta: f64) -> Self {
+        Self {
+            repository,
+            prior_alpha: prior_alpha.max(0.0),
+            prior_beta: prior_beta.max(0.0),
+            lookback: 0,
+            halflife: 0,
         }
     }
+
+    /// Only the last `lookback` closed trades update the prior (0 = full history)
+    pub fn with_lookback(mut self, lookback: usize) -> Self {
+        self.lookback = lookback;
+        self
+    }
+
+    /// Decay older trades by 0.5^(age / halflife) before they update the prior (0 = equal weights)
+    pub fn with_halflife(mut self, halflife: usize) -> Self {
+        self.halflife = halflife;
+        self
+    }
+
+    /// Mean of the prior: the rate returned before any trade closes
+    fn prior_mean(&self) -> f64 {
+        Self::posterior_mean(0.0, 0.0, self.prior_alpha, self.prior_beta)
+    }
+
+    /// Posterior mean of Beta(alpha + wins, beta + losses), with `total` = wins + losses
+    fn posterior_mean(wins: f64, total: f64, alpha: f64, beta: f64) -> f64 {
+        let denominator = total + alpha + beta;
+        if denominator <= 0.0 {
+            return 0.5;
+        }
+        (wins + alpha) / denominator
+    }
+}
+
+#[async_trait]
+impl WinRateProvider for BayesianWinRateProvider {
+    async fn get_win_rate(&self, symbol: &str) -> f64 {
+        self.get_win_rate_sample(symbol).await.0
+    }
+
+    async fn get_win_rate_sample(&self, symbol: &str) -> (f64, usize) {
+        let mut orders = match self.repository.find_by_symbol(symbol).await {
+            Ok(o) => o,
+            Err(e) => {
+                warn!(
+                    "Failed to fetch history for {}: {}. Using prior mean.",
+                    symbol, e
+                );
+                return (self.prior_mean(), 0);
+            }
+        };
+        orders.sort_by_key(|o| o.timestamp);
+
+        let outcomes = HistoricalWinRateProvider::closed_trade_outcomes(&orders);
+        let (wins, total, trades) =
+            HistoricalWinRateProvider::weighted_tally(&outcomes, self.lookback, self.halflife);
+        let rate = Self::posterior_mean(wins, total, self.prior_alpha, self.prior_beta);
+        debug!(
+            "Bayesian Win Rate for {}: {:.2} ({} trades, prior Beta({}, {}))",
+            symbol, rate, trades, self.prior_alpha, self.prior_beta
+        );
+        (rate, outcomes.len())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_weighted_win_rate_lookback_keeps_recent_trades() {
+        // Four old losses, then three wins
+        let outcomes = [false, false, false, false, true, true, true];
+
+        let (rate, trades) = HistoricalWinRateProvider::weighted_win_rate(&outcomes, 0, 0).unwrap();
+        assert!((rate - 3.0 / 7.0).abs() < 1e-9);
+        assert_eq!(trades, 7);
+
+        let (rate, trades) = HistoricalWinRateProvider::weighted_win_rate(&outcomes, 4, 0).unwrap();
+        assert!((rate - 0.75).abs() < 1e-9);
+        assert_eq!(trades, 4);
+
+        assert!(HistoricalWinRateProvider::weighted_win_rate(&[], 10, 5).is_none());
+    }
+
+    #[test]
+    fn test_blended_win_rate_shrinks_small_samples_toward_prior() {
+        // 3 wins out of 3 with a 50% prior worth 10 trades: (3 + 5) / 13, not 100%
+        let rate = HistoricalWinRateProvider::blended_win_rate(3.0, 3.0, 0.5, 10.0);
+        assert!((rate - 8.0 / 13.0).abs() < 1e-9);
+
+        // A large sample dominates the prior
+        let rate = HistoricalWinRateProvider::blended_win_rate(700.0, 1000.0, 0.5, 10.0);
+        assert!((rate - 705.0 / 1010.0).abs() < 1e-9);
+
+        // No history: the prior itself
+        let rate = HistoricalWinRateProvider::blended_win_rate(0.0, 0.0, 0.5, 10.0);
+        assert!((rate - 0.5).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_bayesian_posterior_mean() {
+        // Uniform prior: no trades reads 50%, 3 wins in 3 reads 80% rather than 100%
+        assert!((BayesianWinRateProvider::posterior_mean(0.0, 0.0, 1.0, 1.0) - 0.5).abs() < 1e-9);
+        assert!((BayesianWinRateProvider::posterior_mean(3.0, 3.0, 1.0, 1.0) - 0.8).abs() < 1e-9);
+
+        // A stronger pessimistic prior Beta(2, 8) pulls the same record further down
+        let rate = BayesianWinRateProvider::posterior_mean(3.0, 3.0, 2.0, 8.0);
+        assert!((rate - 5.0 / 13.0).abs() < 1e-9);
+
+        // Degenerate Beta(0, 0) with no data falls back to a coin flip
+        assert!((BayesianWinRateProvider::posterior_mean(0.0, 0.0, 0.0, 0.0) - 0.5).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_weighted_win_rate_halflife_favours_recent_results() {
+        // Old win, recent loss: equal weights give 50%, decay tilts towards the loss
+        let outcomes = [true, false];
+        let (flat, _) = HistoricalWinRateProvider::weighted_win_rate(&outcomes, 0, 0).unwrap();
+        assert!((flat - 0.5).abs() < 1e-9);
+
+        // halflife 1: the older trade weighs 0.5, so rate = 0.5 / 1.5
+        let (decayed, trades) =
+            HistoricalWinRateProvider::weighted_win_rate(&outcomes, 0, 1).unwrap();
+        assert!((decayed - 1.0 / 3.0).abs() < 1e-9);
+        assert_eq!(trades, 2);
+    }
 }
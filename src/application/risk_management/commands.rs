@@ -28,6 +28,16 @@ pub enum RiskCommand {
 
     /// Manually trigger circuit breaker (Testing/Panic)
     CircuitBreakerTrigger,
+
+    /// Reject new buy proposals until resumed (sells and stops keep flowing)
+    Pause,
+
+    /// Lift a previous `Pause`
+    Resume,
+
+    /// Trim positions grown past `max_position_size_pct` by more than `band`
+    /// (triggered by the rebalancer's interval timer)
+    Rebalance { band: rust_decimal::Decimal },
 }
 
 impl RiskCommand {
@@ -41,6 +51,9 @@ impl RiskCommand {
             Self::UpdateSentiment(_) => "UpdateSentiment",
             Self::UpdateConfig(_) => "UpdateConfig",
             Self::CircuitBreakerTrigger => "CircuitBreakerTrigger",
+            Self::Pause => "Pause",
+            Self::Resume => "Resume",
+            Self::Rebalance { .. } => "Rebalance",
         }
     }
 }
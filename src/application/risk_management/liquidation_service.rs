@@ -69,7 +69,7 @@ impl LiquidationService {
             .unwrap_or_default();
 
         for (symbol, position) in &snapshot.portfolio.positions {
-            if position.quantity > Decimal::ZERO {
+            if !position.quantity.is_zero() {
                 // Get spread data for intelligent order placement
                 let spread_data = self.spread_cache.get_spread_data(symbol);
 
@@ -90,11 +90,18 @@ impl LiquidationService {
                     );
                 }
 
+                // Longs are sold, shorts are bought back
+                let side = if position.is_short() {
+                    OrderSide::Buy
+                } else {
+                    OrderSide::Sell
+                };
+
                 // Create smart liquidation order (Try Limit first if possible)
                 let order = self.order_retry_strategy.create_liquidation_order(
                     symbol,
-                    OrderSide::Sell,
-                    position.quantity,
+                    side,
+                    position.quantity.abs(),
                     spread_data,
                     panic_mode,
                 );
@@ -105,6 +112,39 @@ impl LiquidationService {
         orders
     }
 
+    /// Close only the positions in `symbols` (no trading halt)
+    pub async fn liquidate_symbols(
+        &self,
+        reason: &str,
+        symbols: &[String],
+        current_prices: &HashMap<String, Decimal>,
+    ) {
+        let Some(tx) = &self.order_tx else {
+            error!("LiquidationService: Cannot liquidate via channel - channel is missing");
+            return;
+        };
+
+        let orders = self
+            .generate_liquidation_orders(reason, current_prices)
+            .await
+            .into_iter()
+            .filter(|order| symbols.contains(&order.symbol));
+
+        for order in orders {
+            info!(
+                "LiquidationService: Closing {} ({:?} {:?}, Qty: {}) - {}",
+                order.symbol, order.order_type, order.side, order.quantity, reason
+            );
+            let symbol = order.symbol.clone();
+            if let Err(e) = tx.send(order).await {
+                error!(
+                    "LiquidationService: Failed to send liquidation order for {}: {}",
+                    symbol, e
+                );
+            }
+        }
+    }
+
     /// Execute emergency liquidation of entire portfolio via configured channel
     pub async fn liquidate_portfolio(
         &self,
@@ -123,8 +163,8 @@ impl LiquidationService {
 
         for order in orders {
             warn!(
-                "LiquidationService: Placing EMERGENCY {:?} SELL for {} (Qty: {}) @ {}",
-                order.order_type, order.symbol, order.quantity, order.price
+                "LiquidationService: Placing EMERGENCY {:?} {:?} for {} (Qty: {}) @ {}",
+                order.order_type, order.side, order.symbol, order.quantity, order.price
             );
 
             if let Err(e) = tx.send(order).await {
@@ -303,6 +343,9 @@ mod tests {
             price: dec!(0),
             status: OrderStatus::New,
             timestamp: 0,
+            reduce_only: false,
+            origin: Default::default(),
+            reason: None,
         };
 
         service
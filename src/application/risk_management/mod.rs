@@ -10,9 +10,11 @@ pub mod order_throttler;
 pub mod pipeline;
 pub mod portfolio_valuation_service;
 pub mod position_manager;
+pub mod proposal_queue;
+pub mod rebalancer;
 pub mod risk_manager;
 pub mod session_manager;
 pub mod sizing_engine;
 pub mod state;
 pub mod trailing_stops; // New
-pub mod volatility; // NEW: Volatility calculation for vol targeting
+pub use crate::domain::market::volatility;
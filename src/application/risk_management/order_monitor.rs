@@ -96,6 +96,9 @@ impl OrderMonitor {
                     order_type: OrderType::Market,
                     status: crate::domain::trading::types::OrderStatus::New,
                     timestamp: now,
+                    reduce_only: monitored.order.reduce_only,
+                    origin: monitored.order.origin.clone(),
+                    reason: monitored.order.reason.clone(),
                 };
 
                 actions.push(MonitorAction::CancelAndReplace {
@@ -137,6 +140,9 @@ mod tests {
             order_type: OrderType::Limit,
             status: crate::domain::trading::types::OrderStatus::New,
             timestamp: chrono::Utc::now().timestamp_millis(),
+            reduce_only: false,
+            origin: Default::default(),
+            reason: None,
         }
     }
 
@@ -145,6 +151,7 @@ mod tests {
         let config = RetryConfig {
             limit_timeout_ms: 100, // Short timeout for testing
             enable_retry: true,
+            ..Default::default()
         };
         let monitor = OrderMonitor::new(config);
 
@@ -193,6 +200,7 @@ mod tests {
         let config = RetryConfig {
             limit_timeout_ms: 5000,
             enable_retry: true,
+            ..Default::default()
         };
         let monitor = OrderMonitor::new(config);
 
@@ -213,6 +221,7 @@ mod tests {
         let config = RetryConfig {
             limit_timeout_ms: 5000,
             enable_retry: true,
+            ..Default::default()
         };
         let monitor = OrderMonitor::new(config);
 
@@ -231,6 +240,7 @@ mod tests {
         let config = RetryConfig {
             limit_timeout_ms: 100,
             enable_retry: false, // Disabled
+            ..Default::default()
         };
         let monitor = OrderMonitor::new(config);
 
@@ -9,6 +9,12 @@ use uuid::Uuid;
 pub struct RetryConfig {
     pub limit_timeout_ms: u64,
     pub enable_retry: bool,
+    /// Resubmissions after a transient submission failure
+    pub submit_max_retries: u32,
+    /// Backoff before the first resubmission (doubles per attempt)
+    pub submit_backoff_ms: u64,
+    /// Total time resubmissions of one order may take, backoff included
+    pub submit_max_retry_ms: u64,
 }
 
 impl Default for RetryConfig {
@@ -16,6 +22,9 @@ impl Default for RetryConfig {
         Self {
             limit_timeout_ms: 5000,
             enable_retry: true,
+            submit_max_retries: 3,
+            submit_backoff_ms: 500,
+            submit_max_retry_ms: 5000,
         }
     }
 }
@@ -59,6 +68,9 @@ impl OrderRetryStrategy {
                 order_type: OrderType::Limit,
                 status: crate::domain::trading::types::OrderStatus::New,
                 timestamp: chrono::Utc::now().timestamp_millis(),
+                reduce_only: true,
+                origin: Default::default(),
+                reason: None,
             };
         }
 
@@ -76,6 +88,9 @@ impl OrderRetryStrategy {
             order_type: OrderType::Market,
             status: crate::domain::trading::types::OrderStatus::New,
             timestamp: chrono::Utc::now().timestamp_millis(),
+            reduce_only: true,
+            origin: Default::default(),
+            reason: None,
         }
     }
 }
@@ -5,12 +5,76 @@ use tokio::sync::mpsc::{Receiver, Sender};
 use tokio::time;
 use tracing::{info, warn};
 
+/// Sliding-window order rate limit over millisecond timestamps.
+///
+/// Clock-agnostic so the live throttler (wall clock) and the simulator (bar time)
+/// apply the same rule.
+#[derive(Debug, Clone)]
+pub struct OrderRateLimiter {
+    max_orders: u32,
+    window_ms: i64,
+    recent: VecDeque<i64>,
+}
+
+impl OrderRateLimiter {
+    pub fn new(max_orders: u32, window: Duration) -> Self {
+        Self {
+            max_orders,
+            window_ms: window.as_millis() as i64,
+            recent: VecDeque::new(),
+        }
+    }
+
+    pub fn per_minute(max_orders: u32) -> Self {
+        Self::new(max_orders, Duration::from_secs(60))
+    }
+
+    /// Drop orders that left the window as of `now_ms`
+    pub fn prune(&mut self, now_ms: i64) {
+        while let Some(&ts) = self.recent.front() {
+            if ts < now_ms - self.window_ms {
+                self.recent.pop_front();
+            } else {
+                break;
+            }
+        }
+    }
+
+    pub fn has_capacity(&self) -> bool {
+        (self.recent.len() as u32) < self.max_orders
+    }
+
+    pub fn record(&mut self, now_ms: i64) {
+        self.recent.push_back(now_ms);
+    }
+
+    /// Record an order at `now_ms` if the window has room
+    pub fn try_acquire(&mut self, now_ms: i64) -> bool {
+        self.prune(now_ms);
+        if self.has_capacity() {
+            self.record(now_ms);
+            true
+        } else {
+            false
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.recent.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.recent.is_empty()
+    }
+}
+
 pub struct OrderThrottler {
     order_rx: Receiver<Order>,
     throttled_order_tx: Sender<Order>,
     max_orders_per_minute: u32,
-    window_duration: Duration, // Configurable for testing
-    recent_orders: VecDeque<Instant>,
+    rate_limiter: OrderRateLimiter,
+    /// Origin of the monotonic clock fed to `rate_limiter`
+    clock_origin: Instant,
 
     queued_orders: VecDeque<Order>,
     agent_registry:
@@ -48,8 +112,8 @@ impl OrderThrottler {
             order_rx,
             throttled_order_tx,
             max_orders_per_minute,
-            window_duration,
-            recent_orders: VecDeque::new(),
+            rate_limiter: OrderRateLimiter::new(max_orders_per_minute, window_duration),
+            clock_origin: Instant::now(),
             queued_orders: VecDeque::new(),
             agent_registry,
         }
@@ -144,7 +208,8 @@ impl OrderThrottler {
     }
 
     async fn forward_order(&mut self, order: Order) {
-        self.recent_orders.push_back(Instant::now());
+        let now_ms = self.now_ms();
+        self.rate_limiter.record(now_ms);
 
         if let Err(e) = self.throttled_order_tx.send(order.clone()).await {
             tracing::error!(
@@ -158,19 +223,16 @@ impl OrderThrottler {
     }
 
     fn can_accept_order(&self) -> bool {
-        (self.recent_orders.len() as u32) < self.max_orders_per_minute
+        self.rate_limiter.has_capacity()
     }
 
-    fn cleanup_old_timestamps(&mut self) {
-        let window_ago = Instant::now() - self.window_duration;
+    fn now_ms(&self) -> i64 {
+        self.clock_origin.elapsed().as_millis() as i64
+    }
 
-        while let Some(&timestamp) = self.recent_orders.front() {
-            if timestamp < window_ago {
-                self.recent_orders.pop_front();
-            } else {
-                break;
-            }
-        }
+    fn cleanup_old_timestamps(&mut self) {
+        let now_ms = self.now_ms();
+        self.rate_limiter.prune(now_ms);
     }
 }
 
@@ -193,9 +255,26 @@ mod tests {
             order_type: OrderType::Market,
             status: OrderStatus::New,
             timestamp: Utc::now().timestamp_millis(),
+            reduce_only: false,
+            origin: Default::default(),
+            reason: None,
         }
     }
 
+    #[test]
+    fn test_rate_limiter_sliding_window() {
+        let mut limiter = OrderRateLimiter::per_minute(2);
+        assert!(limiter.try_acquire(0));
+        assert!(limiter.try_acquire(10_000));
+        assert!(!limiter.try_acquire(30_000));
+        // First order leaves the window once more than 60s have passed
+        assert!(!limiter.try_acquire(60_000));
+        assert!(limiter.try_acquire(60_001));
+        assert!(!limiter.try_acquire(65_000));
+        assert!(limiter.try_acquire(70_001));
+        assert_eq!(limiter.len(), 2);
+    }
+
     #[tokio::test]
     async fn test_accepts_order_under_limit() {
         let (order_tx, order_rx) = mpsc::channel(10);
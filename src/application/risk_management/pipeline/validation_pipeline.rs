@@ -8,7 +8,7 @@ use crate::domain::risk::filters::validator_trait::{
 ///
 /// The pipeline executes validators in a specific order (defined by their priority).
 /// If any validator returns a Rejection, the pipeline stops immediately and returns
-/// that rejection. This implements a "Fail Fast" strategy.
+/// that rejection (naming the validator). This implements a "Fail Fast" strategy.
 pub struct RiskValidationPipeline {
     validators: Vec<Box<dyn RiskValidator>>,
 }
@@ -47,9 +47,13 @@ impl RiskValidationPipeline {
             }
 
             match validator.validate(ctx).await {
-                ValidationResult::Reject(reason) => {
-                    info!("⛔ Validation FAILED at [{}]: {}", validator.name(), reason);
-                    return ValidationResult::Reject(reason);
+                rejection @ ValidationResult::Reject { .. } => {
+                    info!(
+                        "⛔ Validation FAILED at [{}]: {}",
+                        validator.name(),
+                        rejection.rejection_reason().unwrap_or_default()
+                    );
+                    return rejection;
                 }
                 ValidationResult::Approve => {
                     debug!("Validator passed: {}", validator.name());
@@ -100,7 +104,7 @@ mod tests {
             if self.should_pass {
                 ValidationResult::Approve
             } else {
-                ValidationResult::Reject(format!("Rejected by {}", self.name))
+                ValidationResult::reject(self.name(), format!("Rejected by {}", self.name))
             }
         }
 
@@ -124,6 +128,8 @@ mod tests {
             timestamp: 0,
             stop_loss: None,
             take_profit: None,
+            reduce_only: false,
+            origin: Default::default(),
         }));
 
         let portfolio = Box::leak(Box::new(Portfolio::new()));
@@ -142,6 +148,7 @@ mod tests {
             symbol_pending_exposure: rust_decimal::Decimal::ZERO,
             available_cash: dec!(100000),
             recent_candles: None, // Added for test
+            symbol_max_leverage: None,
         }
     }
 
@@ -194,6 +201,7 @@ mod tests {
         let result = pipeline.validate(&ctx).await;
         assert!(result.is_rejected());
         assert_eq!(result.rejection_reason(), Some("Rejected by V2"));
+        assert_eq!(result.rejected_by(), Some("V2"));
     }
 
     #[tokio::test]
@@ -1,12 +1,36 @@
 use crate::application::risk_management::trailing_stops::StopState;
+use crate::domain::trading::time::secs_to_ms;
 use crate::domain::trading::types::OrderSide;
+use chrono::NaiveDate;
+use rust_decimal::Decimal;
 use tracing::info;
 
+/// Scale-in bookkeeping for the current position
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct PyramidState {
+    /// Price and quantity of the initial entry
+    pub base_price: Decimal,
+    pub base_quantity: Decimal,
+    pub adds: usize,
+}
+
 pub struct PositionManager {
     pub trailing_stop: StopState,
     pub pending_order: Option<OrderSide>,
     pub pending_order_timestamp: i64,
+    /// Position quantity when the pending order was placed (negative = short)
+    pub pending_position_qty: Decimal,
     pub last_signal_time: i64,
+    /// Benchmark price when the current position was first seen (relative-strength exit)
+    pub benchmark_entry_price: Option<Decimal>,
+    pub pyramid: Option<PyramidState>,
+    /// New entries opened on `entry_day` (per-symbol daily trade cap)
+    pub entries_today: u32,
+    pub entry_day: Option<NaiveDate>,
+    /// Trading day of a proposed new position; counted against the cap once it fills
+    pub pending_entry_day: Option<NaiveDate>,
+    /// When a trailing or relative stop last fired (ms), for the post-stop cooldown
+    pub last_stop_time: Option<i64>,
 }
 
 impl Default for PositionManager {
@@ -21,13 +45,54 @@ impl PositionManager {
             trailing_stop: StopState::NoPosition,
             pending_order: None,
             pending_order_timestamp: 0,
+            pending_position_qty: Decimal::ZERO,
             last_signal_time: 0,
+            benchmark_entry_price: None,
+            pyramid: None,
+            entries_today: 0,
+            entry_day: None,
+            pending_entry_day: None,
+            last_stop_time: None,
+        }
+    }
+
+    /// Returns true while re-entry is blocked after a stop-out
+    pub fn in_post_stop_cooldown(&self, timestamp_ms: i64, cooldown_seconds: u64) -> bool {
+        let cooldown_ms = secs_to_ms(cooldown_seconds as i64);
+        self.last_stop_time
+            .is_some_and(|stopped| timestamp_ms - stopped < cooldown_ms)
+    }
+
+    /// Entries opened on `day`; the count restarts on each new trading day
+    pub fn entries_on(&self, day: NaiveDate) -> u32 {
+        if self.entry_day == Some(day) {
+            self.entries_today
+        } else {
+            0
+        }
+    }
+
+    /// Count a new entry on `day` and return the day's total
+    pub fn record_entry(&mut self, day: NaiveDate) -> u32 {
+        if self.entry_day != Some(day) {
+            self.entry_day = Some(day);
+            self.entries_today = 0;
         }
+        self.entries_today += 1;
+        self.entries_today
     }
 
-    pub fn set_pending_order(&mut self, side: OrderSide, timestamp: i64) {
+    /// Count the pending new position once it filled; dropped or rejected entries never are
+    fn confirm_pending_entry(&mut self) {
+        if let Some(day) = self.pending_entry_day.take() {
+            self.record_entry(day);
+        }
+    }
+
+    pub fn set_pending_order(&mut self, side: OrderSide, timestamp: i64, position_qty: Decimal) {
         self.pending_order = Some(side);
         self.pending_order_timestamp = timestamp;
+        self.pending_position_qty = position_qty;
     }
 
     pub fn check_timeout(&mut self, current_time: i64, ttl_ms: i64) -> bool {
@@ -40,25 +105,51 @@ impl PositionManager {
     pub fn clear_pending(&mut self) {
         self.pending_order = None;
         self.pending_order_timestamp = 0;
+        self.pending_entry_day = None;
     }
 
-    pub fn ack_pending_orders(&mut self, has_position: bool, symbol: &str) {
-        if let Some(pending) = self.pending_order {
-            match pending {
-                OrderSide::Buy => {
-                    if has_position {
-                        info!("PositionManager: Pending Buy for {} CONFIRMED.", symbol);
-                        self.pending_order = None;
-                    }
-                }
-                OrderSide::Sell => {
-                    if !has_position {
-                        info!("PositionManager: Pending Sell for {} CONFIRMED.", symbol);
-                        self.pending_order = None;
-                        self.trailing_stop.on_sell();
-                    }
-                }
-            }
+    /// Clear the pending order on a broker fill for `side`
+    pub fn on_fill(&mut self, side: OrderSide) {
+        if self.pending_order == Some(side) && !self.exit_pending() {
+            self.confirm_pending_entry();
+        }
+        self.clear_pending();
+    }
+
+    /// True while an order that reduces or closes the position is waiting to fill
+    pub fn exit_pending(&self) -> bool {
+        self.pending_order
+            .is_some_and(|side| !side.increases_exposure(self.pending_position_qty))
+    }
+
+    /// Clear the pending order once the position (`position_qty`, negative = short) shows it
+    /// filled: entries once the position is open, exits once it is closed.
+    pub fn ack_pending_orders(&mut self, position_qty: Decimal, symbol: &str) {
+        let Some(pending) = self.pending_order else {
+            return;
+        };
+        let exit = !pending.increases_exposure(self.pending_position_qty);
+        let confirmed = match (pending, exit) {
+            (OrderSide::Buy, false) => position_qty > Decimal::ZERO,
+            (OrderSide::Sell, false) => position_qty < Decimal::ZERO,
+            (OrderSide::Buy, true) => position_qty >= Decimal::ZERO,
+            (OrderSide::Sell, true) => position_qty <= Decimal::ZERO,
+        };
+        if !confirmed {
+            return;
+        }
+        info!(
+            "PositionManager: Pending {:?} for {} CONFIRMED.",
+            pending, symbol
+        );
+        self.pending_order = None;
+        if !exit {
+            self.confirm_pending_entry();
+        }
+        if exit {
+            self.trailing_stop.on_sell();
+            self.benchmark_entry_price = None;
+            self.pyramid = None;
         }
     }
 
@@ -69,9 +160,10 @@ impl PositionManager {
         atr: rust_decimal::Decimal,
         multiplier: rust_decimal::Decimal,
     ) -> Option<OrderSide> {
-        if self.pending_order == Some(OrderSide::Sell) {
+        if self.exit_pending() {
             return None;
         }
+        let exit_side = self.trailing_stop.exit_side()?;
 
         if atr > rust_decimal::Decimal::ZERO
             && let Some(trigger) = self.trailing_stop.on_price_update(price, atr, multiplier)
@@ -80,8 +172,199 @@ impl PositionManager {
                 "PositionManager: Trailing stop HIT for {} at {} (Stop: {}, Entry: {})",
                 symbol, trigger.exit, trigger.stop, trigger.entry
             );
+            return Some(exit_side);
+        }
+        None
+    }
+
+    /// Sells when the position has underperformed the benchmark by more than
+    /// `threshold` (e.g. 0.05 = 5 points) since entry.
+    ///
+    /// The benchmark reference is captured on the first call for a position.
+    pub fn check_relative_stop(
+        &mut self,
+        symbol: &str,
+        price: Decimal,
+        entry_price: Decimal,
+        benchmark_price: Decimal,
+        threshold: Decimal,
+    ) -> Option<OrderSide> {
+        if self.exit_pending() || entry_price <= Decimal::ZERO || benchmark_price <= Decimal::ZERO {
+            return None;
+        }
+
+        let benchmark_entry = *self.benchmark_entry_price.get_or_insert(benchmark_price);
+        let position_return = price / entry_price - Decimal::ONE;
+        let benchmark_return = benchmark_price / benchmark_entry - Decimal::ONE;
+        let relative = position_return - benchmark_return;
+
+        if relative < -threshold {
+            info!(
+                "PositionManager: Relative stop HIT for {} (position {:.2}%, benchmark {:.2}%)",
+                symbol,
+                position_return * Decimal::ONE_HUNDRED,
+                benchmark_return * Decimal::ONE_HUNDRED
+            );
             return Some(OrderSide::Sell);
         }
         None
     }
+
+    /// Quantity to add to a winning position, if any.
+    ///
+    /// Add `n` (1-based) fires once price is `n * trigger_pct` above the initial entry
+    /// and is half the size of the previous one (base/2, base/4, ...). `max_quantity`
+    /// is the headroom left under the position size cap; the add is clamped to it.
+    /// The initial entry is captured on the first call for a position.
+    #[allow(clippy::too_many_arguments)]
+    pub fn check_pyramid_add(
+        &mut self,
+        symbol: &str,
+        price: Decimal,
+        entry_price: Decimal,
+        quantity: Decimal,
+        max_adds: usize,
+        trigger_pct: Decimal,
+        max_quantity: Decimal,
+    ) -> Option<Decimal> {
+        if max_adds == 0 || self.pending_order.is_some() || entry_price <= Decimal::ZERO {
+            return None;
+        }
+
+        let state = self.pyramid.get_or_insert(PyramidState {
+            base_price: entry_price,
+            base_quantity: quantity,
+            adds: 0,
+        });
+        if state.adds >= max_adds {
+            return None;
+        }
+
+        let next = state.adds + 1;
+        let trigger_price = state.base_price * (Decimal::ONE + trigger_pct * Decimal::from(next));
+        if price < trigger_price {
+            return None;
+        }
+
+        let size = (state.base_quantity / Decimal::from(1u64 << next.min(63)))
+            .min(max_quantity)
+            .round_dp(4);
+        if size <= Decimal::ZERO {
+            return None;
+        }
+
+        state.adds = next;
+        info!(
+            "PositionManager: Pyramid add #{} for {} at {} (+{} above {})",
+            next, symbol, price, size, state.base_price
+        );
+        Some(size)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rust_decimal_macros::dec;
+
+    #[test]
+    fn test_relative_stop_triggers_on_underperformance() {
+        let mut pm = PositionManager::new();
+
+        // Benchmark reference captured on first check
+        assert!(
+            pm.check_relative_stop("AAPL", dec!(100), dec!(100), dec!(400), dec!(0.05))
+                .is_none()
+        );
+        assert_eq!(pm.benchmark_entry_price, Some(dec!(400)));
+
+        // Both down 4%: no relative underperformance
+        assert!(
+            pm.check_relative_stop("AAPL", dec!(96), dec!(100), dec!(384), dec!(0.05))
+                .is_none()
+        );
+
+        // Position -2% while benchmark +4%: lags by 6 points
+        assert_eq!(
+            pm.check_relative_stop("AAPL", dec!(98), dec!(100), dec!(416), dec!(0.05)),
+            Some(OrderSide::Sell)
+        );
+
+        // Confirmed exit clears the reference
+        pm.set_pending_order(OrderSide::Sell, 0, dec!(10));
+        pm.ack_pending_orders(Decimal::ZERO, "AAPL");
+        assert!(pm.benchmark_entry_price.is_none());
+    }
+
+    #[test]
+    fn test_ack_pending_short_entry_and_cover() {
+        let mut pm = PositionManager::new();
+
+        // Short entry: still pending while flat, confirmed once short
+        pm.set_pending_order(OrderSide::Sell, 0, Decimal::ZERO);
+        assert!(!pm.exit_pending());
+        pm.ack_pending_orders(Decimal::ZERO, "AAPL");
+        assert_eq!(pm.pending_order, Some(OrderSide::Sell));
+        pm.ack_pending_orders(dec!(-5), "AAPL");
+        assert!(pm.pending_order.is_none());
+
+        // Cover: an exit, confirmed once flat, which resets the stop
+        pm.trailing_stop = StopState::on_short(dec!(100), dec!(2), dec!(3));
+        pm.set_pending_order(OrderSide::Buy, 0, dec!(-5));
+        assert!(pm.exit_pending());
+        pm.ack_pending_orders(dec!(-5), "AAPL");
+        assert_eq!(pm.pending_order, Some(OrderSide::Buy));
+        pm.ack_pending_orders(Decimal::ZERO, "AAPL");
+        assert!(pm.pending_order.is_none());
+        assert!(!pm.trailing_stop.is_active());
+    }
+
+    #[test]
+    fn test_only_filled_entries_count_toward_daily_cap() {
+        let mut pm = PositionManager::new();
+        let day = NaiveDate::from_ymd_opt(2025, 3, 3).unwrap();
+
+        // Proposed, then dropped or rejected: the pending order times out unfilled
+        pm.pending_entry_day = Some(day);
+        pm.set_pending_order(OrderSide::Buy, 0, Decimal::ZERO);
+        pm.clear_pending();
+        assert_eq!(pm.entries_on(day), 0);
+
+        // Confirmed by the portfolio sync
+        pm.pending_entry_day = Some(day);
+        pm.set_pending_order(OrderSide::Buy, 0, Decimal::ZERO);
+        pm.ack_pending_orders(dec!(5), "AAPL");
+        assert_eq!(pm.entries_on(day), 1);
+
+        // Confirmed by the fill event; a later sync doesn't count it twice
+        pm.pending_entry_day = Some(day);
+        pm.set_pending_order(OrderSide::Buy, 0, Decimal::ZERO);
+        pm.on_fill(OrderSide::Buy);
+        pm.ack_pending_orders(dec!(5), "AAPL");
+        assert_eq!(pm.entries_on(day), 2);
+    }
+
+    #[test]
+    fn test_pyramid_adds_shrink_and_respect_cap() {
+        let mut pm = PositionManager::new();
+        let add = |pm: &mut PositionManager, price, cap| {
+            pm.check_pyramid_add("AAPL", price, dec!(100), dec!(8), 3, dec!(0.02), cap)
+        };
+
+        // Not yet +2%
+        assert!(add(&mut pm, dec!(101), dec!(100)).is_none());
+
+        // +2%: half the initial size
+        assert_eq!(add(&mut pm, dec!(102), dec!(100)), Some(dec!(4)));
+        // Second add needs +4% from the initial entry, not from the blended one
+        assert!(add(&mut pm, dec!(103), dec!(100)).is_none());
+        assert_eq!(add(&mut pm, dec!(104), dec!(100)), Some(dec!(2)));
+
+        // Third add clamped by remaining headroom
+        assert_eq!(add(&mut pm, dec!(107), dec!(0.5)), Some(dec!(0.5)));
+
+        // Max adds reached
+        assert!(add(&mut pm, dec!(120), dec!(100)).is_none());
+        assert_eq!(pm.pyramid.map(|p| p.adds), Some(3));
+    }
 }
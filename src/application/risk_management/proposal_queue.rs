@@ -0,0 +1,173 @@
+//! Prioritized buffer between the proposal channel and proposal processing.
+//!
+//! The RiskManager drains everything waiting on the channel into this queue and always
+//! reviews exits (orders that reduce a long or cover a short: strategy exits, stops,
+//! panic sells) before entries, so a flood of entry signals cannot delay risk-reducing
+//! orders. Only entries are ever dropped.
+
+use crate::domain::trading::types::TradeProposal;
+use rust_decimal::Decimal;
+use std::collections::VecDeque;
+
+/// Entries held at most; exits are never turned away
+pub const PROPOSAL_QUEUE_CAPACITY: usize = 100;
+
+pub struct ProposalQueue {
+    capacity: usize,
+    exits: VecDeque<TradeProposal>,
+    entries: VecDeque<TradeProposal>,
+}
+
+impl ProposalQueue {
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            capacity: capacity.max(1),
+            exits: VecDeque::new(),
+            entries: VecDeque::new(),
+        }
+    }
+
+    /// Orders that do not add to the held position jump ahead of entries
+    pub fn is_exit(proposal: &TradeProposal, position_qty: Decimal) -> bool {
+        !proposal.increases_exposure(position_qty)
+    }
+
+    /// Queue a proposal given the signed quantity held in its symbol. When full, an
+    /// arriving entry is refused and an arriving exit evicts the newest queued entry;
+    /// the proposal dropped either way is returned.
+    pub fn push(
+        &mut self,
+        proposal: TradeProposal,
+        position_qty: Decimal,
+    ) -> Option<TradeProposal> {
+        let full = self.len() >= self.capacity;
+        if Self::is_exit(&proposal, position_qty) {
+            self.exits.push_back(proposal);
+            if full { self.entries.pop_back() } else { None }
+        } else if full {
+            Some(proposal)
+        } else {
+            self.entries.push_back(proposal);
+            None
+        }
+    }
+
+    /// Next proposal to review: oldest exit first, then oldest entry
+    pub fn pop(&mut self) -> Option<TradeProposal> {
+        self.exits.pop_front().or_else(|| self.entries.pop_front())
+    }
+
+    pub fn len(&self) -> usize {
+        self.exits.len() + self.entries.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.exits.is_empty() && self.entries.is_empty()
+    }
+}
+
+impl Default for ProposalQueue {
+    fn default() -> Self {
+        Self::new(PROPOSAL_QUEUE_CAPACITY)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::trading::types::{OrderSide, OrderType};
+    use rust_decimal_macros::dec;
+
+    fn proposal(symbol: &str, side: OrderSide) -> TradeProposal {
+        TradeProposal {
+            symbol: symbol.to_string(),
+            side,
+            price: Decimal::from(100),
+            quantity: Decimal::ONE,
+            order_type: OrderType::Market,
+            reason: "test".to_string(),
+            timestamp: 0,
+            stop_loss: None,
+            take_profit: None,
+            reduce_only: false,
+            origin: Default::default(),
+        }
+    }
+
+    #[test]
+    fn test_exits_are_reviewed_before_entries() {
+        let mut queue = ProposalQueue::new(10);
+        queue.push(proposal("AAPL", OrderSide::Buy), Decimal::ZERO);
+        queue.push(proposal("MSFT", OrderSide::Buy), Decimal::ZERO);
+        queue.push(proposal("TSLA", OrderSide::Sell), dec!(5));
+        queue.push(proposal("NVDA", OrderSide::Sell), dec!(5));
+
+        let order: Vec<String> = std::iter::from_fn(|| queue.pop())
+            .map(|p| p.symbol)
+            .collect();
+        assert_eq!(order, ["TSLA", "NVDA", "AAPL", "MSFT"]);
+        assert!(queue.is_empty());
+    }
+
+    #[test]
+    fn test_full_queue_only_drops_entries() {
+        let mut queue = ProposalQueue::new(2);
+        assert!(
+            queue
+                .push(proposal("AAPL", OrderSide::Buy), Decimal::ZERO)
+                .is_none()
+        );
+        assert!(
+            queue
+                .push(proposal("MSFT", OrderSide::Buy), Decimal::ZERO)
+                .is_none()
+        );
+
+        // Another entry is refused
+        let refused = queue
+            .push(proposal("TSLA", OrderSide::Buy), Decimal::ZERO)
+            .unwrap();
+        assert_eq!(refused.symbol, "TSLA");
+
+        // An exit evicts the newest entry
+        let evicted = queue
+            .push(proposal("NVDA", OrderSide::Sell), dec!(5))
+            .unwrap();
+        assert_eq!(evicted.symbol, "MSFT");
+
+        assert_eq!(
+            queue
+                .push(proposal("AMD", OrderSide::Sell), dec!(5))
+                .unwrap()
+                .symbol,
+            "AAPL"
+        );
+
+        // With no entries left to evict, exits are still accepted past capacity
+        assert!(
+            queue
+                .push(proposal("INTC", OrderSide::Sell), dec!(5))
+                .is_none()
+        );
+        assert_eq!(queue.len(), 3);
+        assert_eq!(queue.pop().unwrap().symbol, "NVDA");
+    }
+
+    #[test]
+    fn test_exits_are_classified_by_position_direction() {
+        let mut queue = ProposalQueue::new(10);
+        // Opening a short is an entry, covering one is an exit
+        queue.push(proposal("AAPL", OrderSide::Sell), Decimal::ZERO);
+        queue.push(proposal("TSLA", OrderSide::Buy), dec!(-5));
+
+        // A reduce-only sell is an exit even when flat
+        let mut reduce = proposal("NVDA", OrderSide::Sell);
+        reduce.reduce_only = true;
+        queue.push(reduce, Decimal::ZERO);
+
+        let order: Vec<String> = std::iter::from_fn(|| queue.pop())
+            .map(|p| p.symbol)
+            .collect();
+        assert_eq!(order, ["TSLA", "NVDA", "AAPL"]);
+    }
+}
@@ -0,0 +1,168 @@
+//! Periodic position trimming.
+//!
+//! Winners can grow past `max_position_size_pct` long after entry, where the position-size
+//! validator no longer looks. Every `rebalance_interval_minutes` the rebalancer asks the
+//! RiskManager to sell down (or buy back, for shorts) any position whose weight exceeds the
+//! live target by more than the band, back to the target weight. Trims are reduce-only
+//! market-order proposals reviewed as exits by the normal risk pipeline.
+
+use crate::application::risk_management::commands::RiskCommand;
+use crate::domain::trading::portfolio::Portfolio;
+use crate::domain::trading::types::{OrderSide, OrderType, TradeProposal};
+use rust_decimal::{Decimal, RoundingStrategy};
+use std::collections::HashMap;
+use std::time::Duration;
+use tokio::sync::mpsc::Sender;
+use tokio::time::Instant;
+use tracing::warn;
+
+pub struct Rebalancer {
+    risk_cmd_tx: Sender<RiskCommand>,
+    /// Tolerance above the target before a position is trimmed
+    band: Decimal,
+}
+
+impl Rebalancer {
+    pub fn new(risk_cmd_tx: Sender<RiskCommand>, band: Decimal) -> Self {
+        Self { risk_cmd_tx, band }
+    }
+
+    /// Request a rebalance every `interval`, starting one interval after launch so the
+    /// initial broker sync settles first
+    pub async fn run(self, interval: Duration) {
+        let mut ticker = tokio::time::interval_at(Instant::now() + interval, interval);
+        loop {
+            ticker.tick().await;
+            if let Err(e) = self
+                .risk_cmd_tx
+                .send(RiskCommand::Rebalance { band: self.band })
+                .await
+            {
+                warn!("Rebalancer: Failed to request rebalance: {}", e);
+                return;
+            }
+        }
+    }
+}
+
+/// Proposals bringing every position above `target_weight + band` back to `target_weight`.
+/// Positions without a price are skipped, since their weight is unknown.
+pub fn trim_proposals(
+    portfolio: &Portfolio,
+    prices: &HashMap<String, Decimal>,
+    target_weight: Decimal,
+    band: Decimal,
+    timestamp: i64,
+) -> Vec<TradeProposal> {
+    let equity = portfolio.total_equity(prices);
+    if equity <= Decimal::ZERO {
+        return Vec::new();
+    }
+
+    let mut proposals = Vec::new();
+    for (symbol, position) in &portfolio.positions {
+        let Some(&price) = prices.get(symbol) else {
+            continue;
+        };
+        if price <= Decimal::ZERO || position.quantity.is_zero() {
+            continue;
+        }
+
+        let weight = position.quantity.abs() * price / equity;
+        if weight <= target_weight + band {
+            continue;
+        }
+
+        let excess_value = (weight - target_weight) * equity;
+        let quantity = (excess_value / price).round_dp_with_strategy(4, RoundingStrategy::ToZero);
+        if quantity.is_zero() {
+            continue;
+        }
+
+        proposals.push(TradeProposal {
+            symbol: symbol.clone(),
+            side: if position.is_short() {
+                OrderSide::Buy
+            } else {
+                OrderSide::Sell
+            },
+            price,
+            quantity,
+            order_type: OrderType::Market,
+            reason: format!(
+                "Rebalance: weight {:.1}% above target {:.1}%",
+                weight * Decimal::ONE_HUNDRED,
+                target_weight * Decimal::ONE_HUNDRED
+            ),
+            timestamp,
+            stop_loss: None,
+            take_profit: None,
+            reduce_only: true,
+            origin: Default::default(),
+        });
+    }
+    proposals
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::trading::portfolio::Position;
+    use rust_decimal_macros::dec;
+    use std::collections::VecDeque;
+
+    fn position(symbol: &str, quantity: Decimal) -> Position {
+        Position {
+            symbol: symbol.to_string(),
+            quantity,
+            average_price: dec!(100),
+            lots: VecDeque::new(),
+        }
+    }
+
+    #[test]
+    fn test_trims_only_positions_beyond_the_band() {
+        let mut portfolio = Portfolio::new();
+        portfolio.cash = dec!(6800);
+        // 2000 of 10000 equity: 20%, beyond 10% + 5% band
+        portfolio
+            .positions
+            .insert("AAPL".to_string(), position("AAPL", dec!(20)));
+        // 12%: over target but inside the band
+        portfolio
+            .positions
+            .insert("MSFT".to_string(), position("MSFT", dec!(12)));
+        let prices = HashMap::from([
+            ("AAPL".to_string(), dec!(100)),
+            ("MSFT".to_string(), dec!(100)),
+        ]);
+
+        let proposals = trim_proposals(&portfolio, &prices, dec!(0.10), dec!(0.05), 0);
+        assert_eq!(proposals.len(), 1);
+        assert_eq!(proposals[0].symbol, "AAPL");
+        assert_eq!(proposals[0].side, OrderSide::Sell);
+        assert_eq!(proposals[0].order_type, OrderType::Market);
+        // Back to 10% of equity
+        assert_eq!(proposals[0].quantity, dec!(10));
+    }
+
+    #[test]
+    fn test_short_is_bought_back_and_unpriced_skipped() {
+        let mut portfolio = Portfolio::new();
+        portfolio.cash = dec!(12000);
+        portfolio
+            .positions
+            .insert("XOM".to_string(), position("XOM", dec!(-20)));
+        let prices = HashMap::from([("XOM".to_string(), dec!(100))]);
+
+        let proposals = trim_proposals(&portfolio, &prices, dec!(0.10), dec!(0.05), 0);
+        assert_eq!(proposals.len(), 1);
+        assert_eq!(proposals[0].side, OrderSide::Buy);
+        assert_eq!(proposals[0].quantity, dec!(10));
+        // The buy-back is an exit, never an entry
+        assert!(proposals[0].reduce_only);
+        assert!(!proposals[0].increases_exposure(dec!(-20)));
+
+        assert!(trim_proposals(&portfolio, &HashMap::new(), dec!(0.10), dec!(0.05), 0).is_empty());
+    }
+}
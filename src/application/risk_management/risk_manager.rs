@@ -5,17 +5,23 @@ use crate::application::risk_management::liquidation_service::LiquidationService
 use crate::application::risk_management::order_reconciler::{OrderReconciler, PendingOrder}; // Added PendingOrder import
 use crate::application::risk_management::pipeline::validation_pipeline::RiskValidationPipeline;
 use crate::application::risk_management::portfolio_valuation_service::PortfolioValuationService;
+use crate::application::risk_management::proposal_queue::ProposalQueue;
+use crate::application::risk_management::rebalancer::trim_proposals;
 use crate::application::risk_management::session_manager::SessionManager;
 
 use crate::application::market_data::spread_cache::SpreadCache;
 use crate::application::risk_management::state::risk_state_manager::RiskStateManager;
-use crate::domain::ports::{ExecutionService, MarketDataService, OrderUpdate};
+use crate::domain::ports::{ExecutionService, MarketDataService, OrderUpdate, QuantityRounder};
 use crate::domain::repositories::{CandleRepository, RiskStateRepository};
 use crate::domain::risk::filters::{
     RiskValidator, ValidationContext, ValidationResult,
-    buying_power_validator::{BuyingPowerConfig, BuyingPowerValidator},
+    average_down_validator::{AverageDownConfig, AverageDownValidator},
+    buying_power_validator::{BuyingPowerConfig, BuyingPowerValidator, effective_leverage},
     circuit_breaker_validator::{CircuitBreakerConfig, CircuitBreakerValidator},
     correlation_filter::CorrelationFilter,
+    earnings_blackout_validator::{EarningsBlackoutConfig, EarningsBlackoutValidator},
+    gross_exposure_validator::{GrossExposureConfig, GrossExposureValidator},
+    min_notional_validator::MinNotionalValidator,
     pdt_validator::{PdtConfig, PdtValidator},
     position_size_validator::{PositionSizeConfig, PositionSizeValidator},
     price_anomaly_validator::{PriceAnomalyConfig, PriceAnomalyValidator},
@@ -23,21 +29,23 @@ use crate::domain::risk::filters::{
     sentiment_validator::{SentimentConfig, SentimentValidator},
 };
 
+use crate::domain::risk::earnings_calendar::EarningsCalendar;
 use crate::domain::risk::state::RiskState;
 use crate::domain::risk::volatility_manager::VolatilityManager; // Added
 use crate::domain::sentiment::Sentiment;
+use crate::domain::trading::events::{DecisionEvent, DecisionKind, TradingEvent};
 use crate::domain::trading::portfolio::Portfolio;
-use crate::domain::trading::types::{Order, OrderSide, TradeProposal};
-use chrono::Utc;
+use crate::domain::trading::time::{now_ms, secs_to_ms};
+use crate::domain::trading::types::{Order, OrderStatus, TradeProposal};
+use chrono::{NaiveDate, Utc};
 use rust_decimal::Decimal;
 use rust_decimal::prelude::ToPrimitive;
 use rust_decimal_macros::dec;
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 use std::sync::Arc;
 use tokio::sync::RwLock; // Added
 use tokio::sync::mpsc::{Receiver, Sender};
 use tracing::{debug, error, info, instrument, warn};
-use uuid::Uuid;
 
 use crate::application::monitoring::connection_health_service::ConnectionHealthService;
 use crate::application::monitoring::correlation_service::CorrelationService;
@@ -45,12 +53,18 @@ use crate::application::monitoring::performance_monitoring_service::PerformanceM
 use crate::application::monitoring::portfolio_state_manager::PortfolioStateManager;
 use crate::application::risk_management::commands::RiskCommand;
 use crate::config::AssetClass;
+use crate::infrastructure::core::event_bus::EventBus;
 use crate::infrastructure::observability::Metrics;
 
 pub use crate::domain::risk::risk_config::{RiskConfig, RiskConfigError};
 
+/// Queued proposals reviewed per event-loop turn before other events get a chance
+const PROPOSALS_PER_TURN: usize = 16;
+
 pub struct RiskManager {
     proposal_rx: Receiver<TradeProposal>,
+    // Proposals drained from the channel, exits reviewed first
+    proposal_queue: ProposalQueue,
     external_cmd_rx: Receiver<RiskCommand>,
     order_tx: Sender<Order>,
     execution_service: Arc<dyn ExecutionService>,
@@ -62,6 +76,7 @@ pub struct RiskManager {
     volatility_manager: Arc<RwLock<VolatilityManager>>,
 
     asset_class: AssetClass,
+    non_pdt_mode: bool,
 
     // NEW Architecture Components
     validation_pipeline: RiskValidationPipeline,
@@ -80,11 +95,17 @@ pub struct RiskManager {
     // Runtime flags
     // halted moved to CircuitBreakerService
     daily_pnl: Decimal,
+    /// Operator pause: new entries are rejected, exits still pass
+    trading_paused: bool,
 
     // NEW Resilience State
     connection_health_service: Arc<ConnectionHealthService>,
     last_quote_timestamp: i64,
 
+    // Earnings calendar (None = no provider) and positions already flattened per announcement
+    earnings_calendar: Option<Arc<EarningsCalendar>>,
+    earnings_flattened: HashSet<(String, NaiveDate)>,
+
     // Cache
     current_prices: HashMap<String, Decimal>,
     // pending_reservations moved to OrderReconciler
@@ -96,6 +117,74 @@ pub struct RiskManager {
     metrics: Metrics,
     agent_registry: Arc<crate::application::monitoring::agent_status::AgentStatusRegistry>,
     startup_time: i64,
+    event_bus: EventBus,
+    // Running risk config, shared with the UI
+    config_view: Option<Arc<RwLock<RiskConfig>>>,
+}
+
+/// Validators applied to every proposal, in priority order
+fn build_validation_pipeline(
+    risk_config: &RiskConfig,
+    asset_class: AssetClass,
+    non_pdt_mode: bool,
+    earnings_calendar: Option<Arc<EarningsCalendar>>,
+) -> RiskValidationPipeline {
+    let validators: Vec<Box<dyn RiskValidator>> = vec![
+        // 1. Top Priority: Circuit Breaker
+        Box::new(CircuitBreakerValidator::new(CircuitBreakerConfig {
+            max_daily_loss_pct: risk_config.max_daily_loss_pct,
+            max_drawdown_pct: risk_config.max_drawdown_pct,
+            consecutive_loss_limit: risk_config.consecutive_loss_limit,
+        })),
+        // 2. Price Anomaly Detection (Fat Finger Protection)
+        Box::new(PriceAnomalyValidator::new(PriceAnomalyConfig::default())),
+        // 3. Broker minimum: order value floor
+        Box::new(MinNotionalValidator::new(risk_config.min_notional_config())),
+        // 4. Regulatory: PDT
+        Box::new(PdtValidator::new(PdtConfig {
+            enabled: !non_pdt_mode && !risk_config.allow_pdt_risk,
+            asset_class,
+            ..Default::default()
+        })),
+        // 5. Discipline: no averaging down into losers
+        Box::new(AverageDownValidator::new(AverageDownConfig {
+            allow_average_down: risk_config.allow_average_down,
+            max_loss_pct: risk_config.max_average_down_loss_pct,
+        })),
+        // 6. Event risk: no new entries into earnings
+        Box::new(EarningsBlackoutValidator::new(EarningsBlackoutConfig {
+            blackout_days: risk_config.earnings_blackout_days,
+            calendar: earnings_calendar,
+            trading_day_boundary: risk_config.trading_day_boundary(asset_class),
+        })),
+        // 7. Diversification: Sector Exposure
+        Box::new(SectorExposureValidator::new(SectorExposureConfig {
+            max_sector_exposure_pct: risk_config.max_sector_exposure_pct,
+            sector_provider: risk_config.sector_provider.clone(),
+        })),
+        // 8. Portfolio-wide cap on deployed capital
+        Box::new(GrossExposureValidator::new(GrossExposureConfig {
+            max_gross_exposure_pct: risk_config.max_gross_exposure_pct,
+        })),
+        // 9. Diversification: Correlation
+        Box::new(CorrelationFilter::new(
+            risk_config.correlation_config.clone(),
+        )),
+        // 10. Risk Sizing: Position Size
+        Box::new(PositionSizeValidator::new(PositionSizeConfig {
+            max_position_size_pct: risk_config.max_position_size_pct,
+        })),
+        // 11. Optimization: Sentiment
+        Box::new(SentimentValidator::new(SentimentConfig::default())),
+        // 12. Affordability: Buying Power (Available Cash)
+        Box::new(BuyingPowerValidator::new(BuyingPowerConfig {
+            allow_shorts: risk_config.allow_shorts,
+            leverage: risk_config.leverage,
+            ..BuyingPowerConfig::default()
+        })),
+    ];
+
+    RiskValidationPipeline::new(validators)
 }
 
 impl RiskManager {
@@ -124,47 +213,25 @@ impl RiskManager {
             .validate()
             .map_err(RiskConfigError::ValidationError)?;
 
-        // --- Build Validation Pipeline ---
-        let validators: Vec<Box<dyn RiskValidator>> = vec![
-            // 1. Top Priority: Circuit Breaker
-            Box::new(CircuitBreakerValidator::new(CircuitBreakerConfig {
-                max_daily_loss_pct: risk_config.max_daily_loss_pct,
-                max_drawdown_pct: risk_config.max_drawdown_pct,
-                consecutive_loss_limit: risk_config.consecutive_loss_limit,
-            })),
-            // 2. Price Anomaly Detection (Fat Finger Protection)
-            Box::new(PriceAnomalyValidator::new(PriceAnomalyConfig::default())),
-            // 3. Regulatory: PDT
-            Box::new(PdtValidator::new(PdtConfig {
-                enabled: !non_pdt_mode && !risk_config.allow_pdt_risk,
-                asset_class,
-                ..Default::default()
-            })),
-            // 4. Diversification: Sector Exposure
-            Box::new(SectorExposureValidator::new(SectorExposureConfig {
-                max_sector_exposure_pct: risk_config.max_sector_exposure_pct,
-                sector_provider: risk_config.sector_provider.clone(),
-            })),
-            // 5. Diversification: Correlation
-            Box::new(CorrelationFilter::new(
-                risk_config.correlation_config.clone(),
-            )),
-            // 6. Risk Sizing: Position Size
-            Box::new(PositionSizeValidator::new(PositionSizeConfig {
-                max_position_size_pct: risk_config.max_position_size_pct,
-            })),
-            // 7. Optimization: Sentiment
-            Box::new(SentimentValidator::new(SentimentConfig::default())),
-            // 8. Affordability: Buying Power (Available Cash)
-            Box::new(BuyingPowerValidator::new(BuyingPowerConfig::default())),
-        ];
-
-        let validation_pipeline = RiskValidationPipeline::new(validators);
+        // One cached calendar shared by the entry blackout and the pre-earnings flatten
+        let earnings_calendar = risk_config
+            .earnings_provider
+            .clone()
+            .map(|provider| Arc::new(EarningsCalendar::new(provider)));
+
+        let validation_pipeline = build_validation_pipeline(
+            &risk_config,
+            asset_class,
+            non_pdt_mode,
+            earnings_calendar.clone(),
+        );
 
         // --- State Management ---
+        let trading_day_boundary = risk_config.trading_day_boundary(asset_class);
         let state_manager = RiskStateManager::new(
             risk_state_repository.clone(),
             Decimal::ZERO, // Initialized later in initialize_session
+            trading_day_boundary,
         );
 
         let volatility_manager = Arc::new(RwLock::new(VolatilityManager::new(
@@ -173,7 +240,8 @@ impl RiskManager {
 
         // Initialize extracted services
         let session_manager =
-            SessionManager::new(risk_state_repository.clone(), market_service.clone());
+            SessionManager::new(risk_state_repository.clone(), market_service.clone())
+                .with_trading_day_boundary(trading_day_boundary);
 
         let portfolio_valuation_service = PortfolioValuationService::new(
             market_service.clone(),
@@ -191,6 +259,7 @@ impl RiskManager {
 
         Ok(Self {
             proposal_rx,
+            proposal_queue: ProposalQueue::default(),
             external_cmd_rx,
             order_tx,
             execution_service,
@@ -198,6 +267,7 @@ impl RiskManager {
             portfolio_state_manager,
 
             asset_class,
+            non_pdt_mode,
 
             volatility_manager,
 
@@ -218,12 +288,15 @@ impl RiskManager {
             order_reconciler: OrderReconciler::new(risk_config.pending_order_ttl_ms),
 
             // pending_orders removed
+            earnings_calendar,
+            earnings_flattened: HashSet::new(),
             current_prices: HashMap::new(),
             performance_monitor,
             correlation_service,
 
             // halted removed
             daily_pnl: Decimal::ZERO,
+            trading_paused: false,
 
             // pending_reservations removed
             current_sentiment: None,
@@ -235,9 +308,133 @@ impl RiskManager {
             metrics,
             agent_registry,
             startup_time: Utc::now().timestamp(),
+            event_bus: EventBus::new(),
+            config_view: None,
         })
     }
 
+    /// Publish proposal and fill decisions to the given bus (audit log)
+    pub fn with_event_bus(mut self, event_bus: EventBus) -> Self {
+        self.event_bus = event_bus;
+        self
+    }
+
+    /// Keep `view` in sync with the running risk config (read by the UI)
+    pub fn with_config_view(mut self, view: Arc<RwLock<RiskConfig>>) -> Self {
+        self.config_view = Some(view);
+        self
+    }
+
+    async fn record_decision(&self, event: DecisionEvent) {
+        self.event_bus.publish(TradingEvent::Decision(event)).await;
+    }
+
+    /// Lot-rounded exit quantity, raised to the whole remaining position when rounding
+    /// would leave behind a remainder too small for the broker to accept on its own.
+    async fn exit_without_dust(
+        &self,
+        rounder: &dyn QuantityRounder,
+        proposal: &TradeProposal,
+        rounded: Decimal,
+        position_qty: Decimal,
+        is_entry: bool,
+    ) -> Decimal {
+        let held = position_qty.abs();
+        let remainder = held - rounded;
+        if is_entry || remainder <= Decimal::ZERO {
+            return rounded;
+        }
+        let sellable = |qty| rounder.round_quantity(&proposal.symbol, qty, proposal.price);
+        if !matches!(sellable(remainder).await, Ok(r) if r.is_zero()) {
+            return rounded;
+        }
+        match sellable(held).await {
+            Ok(full) if full > rounded => {
+                info!(
+                    "RiskManager: Raising {} exit from {} to {} so no unsellable remainder is left",
+                    proposal.symbol, rounded, full
+                );
+                full
+            }
+            _ => rounded,
+        }
+    }
+
+    async fn record_rejection(
+        &self,
+        proposal: &TradeProposal,
+        reason: impl Into<String>,
+        blocked_by: Option<&str>,
+    ) {
+        self.metrics
+            .inc_rejections("risk", blocked_by.unwrap_or("unknown"));
+        let mut event =
+            DecisionEvent::for_proposal(DecisionKind::ProposalRejected, proposal, reason);
+        if let Some(blocked_by) = blocked_by {
+            event = event.with_blocked_by(blocked_by);
+        }
+        self.record_decision(event).await;
+    }
+
+    /// Rejection reason when `max_open_orders` broker orders are already working.
+    /// Fails closed: if the broker can't be asked, entries wait.
+    async fn open_orders_cap_reason(&self) -> Option<String> {
+        let max_open_orders = self.risk_config.max_open_orders;
+        if max_open_orders == 0 {
+            return None;
+        }
+        match self.execution_service.get_open_orders().await {
+            Ok(orders) if orders.len() >= max_open_orders => Some(format!(
+                "{} open orders (max {})",
+                orders.len(),
+                max_open_orders
+            )),
+            Ok(_) => None,
+            Err(e) => Some(format!("Open orders unavailable: {}", e)),
+        }
+    }
+
+    /// Move the proposals waiting on the channel into the priority queue (not the ones
+    /// a busy sender adds meanwhile, so a flood cannot keep this looping)
+    async fn drain_proposal_channel(&mut self) {
+        for _ in 0..self.proposal_rx.len() {
+            let Ok(proposal) = self.proposal_rx.try_recv() else {
+                break;
+            };
+            self.enqueue_proposal(proposal).await;
+        }
+    }
+
+    async fn enqueue_proposal(&mut self, proposal: TradeProposal) {
+        let position_qty = self.position_qty(&proposal.symbol).await;
+        if let Some(dropped) = self.proposal_queue.push(proposal, position_qty) {
+            warn!(
+                "RiskManager: Proposal queue full. Dropping {:?} entry for {}",
+                dropped.side, dropped.symbol
+            );
+            self.record_rejection(&dropped, "Proposal queue full", Some("proposal_queue_full"))
+                .await;
+        }
+    }
+
+    /// Review up to `PROPOSALS_PER_TURN` queued proposals, re-draining the channel between
+    /// proposals so an exit arriving mid-backlog still goes next. The rest stays queued
+    /// so commands, valuation ticks and order updates are not starved by a flood.
+    async fn process_proposal_queue(&mut self) {
+        for _ in 0..PROPOSALS_PER_TURN {
+            self.drain_proposal_channel().await;
+            let Some(proposal) = self.proposal_queue.pop() else {
+                break;
+            };
+            if let Err(e) = self
+                .handle_command(RiskCommand::ProcessProposal(proposal))
+                .await
+            {
+                error!("RiskManager: Proposal processing failed: {}", e);
+            }
+        }
+    }
+
     /// Persist current risk state to database
     async fn persist_state(&self) {
         self.state_manager.persist().await;
@@ -312,6 +509,25 @@ impl RiskManager {
             .order_reconciler
             .handle_order_update(&update, self.state_manager.get_state_mut());
 
+        if update.status == OrderStatus::Filled {
+            let mut event = DecisionEvent::new(
+                DecisionKind::OrderFilled,
+                &update.symbol,
+                update.side,
+                "Order filled",
+            )
+            .with_size(
+                update.filled_qty,
+                update.filled_avg_price.unwrap_or(Decimal::ZERO),
+            );
+            event.order_id = Some(if update.client_order_id.is_empty() {
+                update.order_id.clone()
+            } else {
+                update.client_order_id.clone()
+            });
+            self.record_decision(event).await;
+        }
+
         // Release reservation token synchronously in this async context
         if let Some(t) = token {
             self.portfolio_state_manager.release_reservation(t).await;
@@ -459,6 +675,40 @@ impl RiskManager {
         Ok(())
     }
 
+    /// Queue reduce-only trims for positions above the live `max_position_size_pct`
+    /// plus `band`. The trims are reviewed as exits on the next queue pass.
+    async fn cmd_handle_rebalance(&mut self, band: Decimal) {
+        let snapshot = self.portfolio_state_manager.get_snapshot().await;
+        if snapshot.portfolio.positions.is_empty() {
+            return;
+        }
+        let symbols: Vec<String> = snapshot.portfolio.positions.keys().cloned().collect();
+        let prices = match self.market_service.get_prices(symbols).await {
+            Ok(prices) => prices,
+            Err(e) => {
+                warn!(
+                    "RiskManager: Rebalance skipped, failed to fetch prices: {}",
+                    e
+                );
+                return;
+            }
+        };
+
+        for proposal in trim_proposals(
+            &snapshot.portfolio,
+            &prices,
+            self.risk_config.max_position_size_pct,
+            band,
+            now_ms(),
+        ) {
+            info!(
+                "RiskManager: Rebalance trimming {} by {} ({})",
+                proposal.symbol, proposal.quantity, proposal.reason
+            );
+            self.enqueue_proposal(proposal).await;
+        }
+    }
+
     /// Emergency liquidation of entire portfolio
     /// Delegates to LiquidationService for emergency liquidation logic
     #[instrument(skip(self))]
@@ -469,29 +719,55 @@ impl RiskManager {
             .await;
     }
 
-    /// Check if we need to reset session stats (for 24/7 Crypto markets)
-    pub fn check_daily_reset(&mut self, current_equity: Decimal) -> bool {
-        let old_reset = self.state_manager.get_state().daily_drawdown_reset;
+    /// Close positions whose symbol reports earnings within the blackout (EARNINGS_FLATTEN).
+    /// Each position is flattened once per announcement.
+    async fn flatten_before_earnings(&mut self) {
+        if !self.risk_config.earnings_flatten || self.risk_config.earnings_blackout_days == 0 {
+            return;
+        }
+        let Some(calendar) = self.earnings_calendar.clone() else {
+            return;
+        };
 
-        // Delegate to RiskStateManager
-        self.state_manager.check_daily_reset(current_equity);
+        let today = self
+            .risk_config
+            .trading_day_boundary(self.asset_class)
+            .trading_day(Utc::now());
+        let snapshot = self.portfolio_state_manager.get_snapshot().await;
+        let mut symbols = Vec::new();
+        for (symbol, position) in &snapshot.portfolio.positions {
+            if position.quantity.is_zero() {
+                continue;
+            }
+            if let Some(earnings) = calendar
+                .upcoming_within(symbol, today, self.risk_config.earnings_blackout_days)
+                .await
+                && self.earnings_flattened.insert((symbol.clone(), earnings))
+            {
+                warn!(
+                    "RiskManager: Flattening {} ahead of earnings on {}",
+                    symbol, earnings
+                );
+                symbols.push(symbol.clone());
+            }
+        }
 
-        let new_reset = self.state_manager.get_state().daily_drawdown_reset;
+        if !symbols.is_empty() {
+            self.liquidation_service
+                .liquidate_symbols("earnings blackout", &symbols, &self.current_prices)
+                .await;
+        }
+    }
 
-        if new_reset && !old_reset {
+    /// Check if a new trading day started and reset the daily loss baseline
+    pub fn check_daily_reset(&mut self, current_equity: Decimal) -> bool {
+        // Delegate to RiskStateManager
+        if self.state_manager.check_daily_reset(current_equity) {
             self.daily_pnl = Decimal::ZERO;
             self.circuit_breaker_service.set_halted(HaltLevel::Normal);
             self.metrics.circuit_breaker_status.set(0.0);
             return true;
         }
-
-        // Check if reference date changed (handled by state manager logic above, so implied by new_reset usually)
-        // But if we want to be safe about updated_at check:
-        if self.asset_class == AssetClass::Crypto
-            && self.state_manager.get_state().updated_at >= Utc::now().timestamp() - 1
-        {
-            return true;
-        }
         false
     }
 
@@ -509,6 +785,10 @@ impl RiskManager {
         self.circuit_breaker_service.is_halted()
     }
 
+    pub fn is_paused(&self) -> bool {
+        self.trading_paused
+    }
+
     pub fn get_state(&self) -> &RiskState {
         self.state_manager.get_state()
     }
@@ -550,15 +830,64 @@ impl RiskManager {
                     .await;
                 Ok(())
             }
+            RiskCommand::Pause => {
+                warn!("RiskManager: Trading PAUSED by operator. New entries will be rejected.");
+                self.set_trading_paused(true).await;
+                Ok(())
+            }
+            RiskCommand::Resume => {
+                info!("RiskManager: Trading RESUMED by operator.");
+                self.set_trading_paused(false).await;
+                Ok(())
+            }
+            RiskCommand::Rebalance { band } => {
+                self.cmd_handle_rebalance(band).await;
+                Ok(())
+            }
         }
     }
 
+    async fn set_trading_paused(&mut self, paused: bool) {
+        self.trading_paused = paused;
+        self.metrics
+            .trading_paused
+            .set(if paused { 1.0 } else { 0.0 });
+        self.agent_registry
+            .update_metric(
+                "RiskManager",
+                "trading_paused",
+                if paused { "PAUSED" } else { "ACTIVE" }.to_string(),
+            )
+            .await;
+    }
+
     async fn cmd_handle_update_config(
         &mut self,
         config: Box<RiskConfig>,
     ) -> Result<(), Box<dyn std::error::Error>> {
         info!("RiskManager: Updating risk configuration: {:?}", config);
-        self.risk_config = *config;
+        let mut config = *config;
+        // Updates from the UI or a config reload don't carry the broker-bound sector provider
+        if config.sector_provider.is_none() {
+            config.sector_provider = self.risk_config.sector_provider.take();
+        }
+        if config.earnings_provider.is_none() {
+            config.earnings_provider = self.risk_config.earnings_provider.take();
+        }
+        if let Some(boundary) = config.daily_reset {
+            self.state_manager.set_boundary(boundary);
+        }
+        // Validators copy their limits at construction, so rebuild them from the new config
+        self.validation_pipeline = build_validation_pipeline(
+            &config,
+            self.asset_class,
+            self.non_pdt_mode,
+            self.earnings_calendar.clone(),
+        );
+        if let Some(view) = &self.config_view {
+            *view.write().await = config.clone();
+        }
+        self.risk_config = config;
         Ok(())
     }
 
@@ -595,7 +924,9 @@ impl RiskManager {
     /// Handle valuation tick command
     async fn cmd_handle_valuation(&mut self) -> Result<(), Box<dyn std::error::Error>> {
         self.update_portfolio_valuation().await?;
+        self.record_gross_exposure().await;
         if !self.circuit_breaker_service.is_halted() {
+            self.flatten_before_earnings().await;
             let snapshot = self.portfolio_state_manager.get_snapshot().await;
             if self.check_daily_reset(snapshot.portfolio.total_equity(&self.current_prices)) {
                 self.persist_state().await;
@@ -610,22 +941,74 @@ impl RiskManager {
         Ok(())
     }
 
+    /// Publish the share of equity deployed across all positions
+    async fn record_gross_exposure(&self) {
+        let snapshot = self.portfolio_state_manager.get_snapshot().await;
+        let equity = snapshot.portfolio.total_equity(&self.current_prices);
+        if equity > Decimal::ZERO {
+            let gross_pct = snapshot.portfolio.gross_exposure(&self.current_prices) / equity;
+            self.metrics
+                .gross_exposure_pct
+                .set(gross_pct.to_f64().unwrap_or(0.0));
+        }
+    }
+
+    /// Signed quantity currently held in `symbol` (negative when short)
+    async fn position_qty(&self, symbol: &str) -> Decimal {
+        self.portfolio_state_manager
+            .get_snapshot()
+            .await
+            .portfolio
+            .positions
+            .get(symbol)
+            .map_or(Decimal::ZERO, |pos| pos.quantity)
+    }
+
     /// Handle trade proposal command
     #[instrument(skip(self, proposal), fields(symbol = %proposal.symbol, side = ?proposal.side))]
     async fn cmd_handle_proposal(
         &mut self,
         proposal: TradeProposal,
     ) -> Result<(), Box<dyn std::error::Error>> {
+        self.metrics.inc_proposals(&proposal.side.to_string());
+        // Opening or adding to a long or short; exits are never held back by these gates
+        let position_qty = self.position_qty(&proposal.symbol).await;
+        let is_entry = proposal.increases_exposure(position_qty);
         let level = self.circuit_breaker_service.halt_level();
-        if level == HaltLevel::Reduced || level == HaltLevel::FullHalt {
+        if is_entry && (level == HaltLevel::Reduced || level == HaltLevel::FullHalt) {
             info!(
                 "RiskManager: Trading HALTED ({:?}). Rejecting proposal for {}",
                 level, proposal.symbol
             );
+            self.record_rejection(
+                &proposal,
+                format!("Trading halted ({:?})", level),
+                Some("circuit_breaker"),
+            )
+            .await;
+            return Ok(());
+        }
+        if self.trading_paused && is_entry {
+            info!(
+                "RiskManager: Trading PAUSED. Rejecting {:?} entry for {}",
+                proposal.side, proposal.symbol
+            );
+            self.record_rejection(&proposal, "Trading paused", Some("trading_paused"))
+                .await;
+            return Ok(());
+        }
+        if is_entry && let Some(reason) = self.open_orders_cap_reason().await {
+            info!(
+                "RiskManager: Rejecting {:?} entry for {}: {}",
+                proposal.side, proposal.symbol, reason
+            );
+            self.record_rejection(&proposal, reason, Some("max_open_orders"))
+                .await;
             return Ok(());
         }
         let mut proposal = proposal;
-        if level == HaltLevel::Warning {
+        // Exits close the full position; halving them would leave half of it open
+        if is_entry && level == HaltLevel::Warning {
             let mult = rust_decimal::Decimal::from_f64_retain(HaltLevel::Warning.size_multiplier())
                 .unwrap_or(Decimal::ONE);
             proposal.quantity = (proposal.quantity * mult).round_dp(4);
@@ -634,6 +1017,12 @@ impl RiskManager {
                     "RiskManager: Proposal for {} scaled to zero under Warning level, skipping",
                     proposal.symbol
                 );
+                self.record_rejection(
+                    &proposal,
+                    "Quantity scaled to zero under Warning level",
+                    Some("circuit_breaker"),
+                )
+                .await;
                 return Ok(());
             }
             debug!(
@@ -655,10 +1044,77 @@ impl RiskManager {
                 "RiskManager: Market Data OFFLINE. Rejecting proposal for {}",
                 proposal.symbol
             );
+            self.record_rejection(&proposal, "Market data offline", Some("market_data"))
+                .await;
             return Ok(());
         }
         // -------------------------
 
+        // Raise undersized entries to the minimum order value when allowed
+        if let Some(quantity) = MinNotionalValidator::new(self.risk_config.min_notional_config())
+            .bumped_quantity(&proposal, position_qty)
+        {
+            info!(
+                "RiskManager: Bumping {} {:?} entry from {} to {} to meet the ${} minimum notional",
+                proposal.symbol,
+                proposal.side,
+                proposal.quantity,
+                quantity,
+                self.risk_config.min_order_notional
+            );
+            proposal.quantity = quantity;
+        }
+
+        // Round to the broker's lot size before anything is validated or reserved
+        if let Some(rounder) = self.execution_service.quantity_rounder() {
+            match rounder
+                .round_quantity(&proposal.symbol, proposal.quantity, proposal.price)
+                .await
+            {
+                Ok(quantity) if quantity > Decimal::ZERO => {
+                    proposal.quantity = self
+                        .exit_without_dust(
+                            rounder.as_ref(),
+                            &proposal,
+                            quantity,
+                            position_qty,
+                            is_entry,
+                        )
+                        .await
+                }
+                Ok(_) if !is_entry => {
+                    warn!(
+                        "RiskManager: Cannot exit {} - {:?} of {} (position {}) is below the broker's lot size / minimum notional",
+                        proposal.symbol, proposal.side, proposal.quantity, position_qty
+                    );
+                    self.record_rejection(
+                        &proposal,
+                        "Exit quantity is below the broker's lot size, position left open",
+                        Some("lot_size"),
+                    )
+                    .await;
+                    return Ok(());
+                }
+                Ok(_) => {
+                    warn!(
+                        "RiskManager: Rejecting {:?} order for {} - quantity {} rounds to zero under the broker's lot size / minimum notional",
+                        proposal.side, proposal.symbol, proposal.quantity
+                    );
+                    self.record_rejection(
+                        &proposal,
+                        "Quantity rounds to zero under the broker's lot size",
+                        Some("lot_size"),
+                    )
+                    .await;
+                    return Ok(());
+                }
+                Err(e) => warn!(
+                    "RiskManager: Lot size unavailable for {} ({}). Submitting quantity {} unrounded",
+                    proposal.symbol, e, proposal.quantity
+                ),
+            }
+        }
+
         info!("RiskManager: reviewing proposal {:?}", proposal);
 
         // Update current price
@@ -709,6 +1165,8 @@ impl RiskManager {
             } else {
                 self.liquidate_portfolio(&reason).await;
             }
+            self.record_rejection(&proposal, reason, Some("circuit_breaker"))
+                .await;
             return Ok(());
         }
 
@@ -737,14 +1195,14 @@ impl RiskManager {
 
         let pending_exposure = self
             .order_reconciler
-            .get_pending_exposure(&proposal.symbol, OrderSide::Buy);
+            .get_pending_exposure(&proposal.symbol, proposal.side);
 
         let recent_candles = if let Some(repo) = &self.candle_repository {
             // Fetch last 20 recent candles for price anomaly validation
             // We use a safe lookback window (e.g. 5 min * 20 = 100 min)
-            let now_ts = Utc::now().timestamp();
+            let now_ts = now_ms();
             // Assumed get_range handles sort order
-            repo.get_range(&proposal.symbol, now_ts - 7200, now_ts)
+            repo.get_range(&proposal.symbol, now_ts - secs_to_ms(7200), now_ts)
                 .await
                 .ok()
         } else {
@@ -758,6 +1216,23 @@ impl RiskManager {
 
         let available_cash = snapshot.available_cash();
 
+        // Per-symbol leverage limits only matter on margin accounts
+        let symbol_max_leverage = if self.risk_config.leverage > Decimal::ONE {
+            self.execution_service
+                .max_leverage(&proposal.symbol)
+                .await
+                .unwrap_or_else(|e| {
+                    warn!(
+                        "RiskManager: Max leverage unavailable for {} ({}). Using account leverage",
+                        proposal.symbol, e
+                    );
+                    None
+                })
+        } else {
+            None
+        };
+        let leverage = effective_leverage(self.risk_config.leverage, symbol_max_leverage);
+
         let ctx = ValidationContext::new(
             &proposal,
             &snapshot.portfolio,
@@ -770,16 +1245,18 @@ impl RiskManager {
             pending_exposure,
             available_cash,
             candles_ref, // Pass recent candles from CandleRepository for PriceAnomalyValidator
-        );
+        )
+        .with_symbol_max_leverage(symbol_max_leverage);
 
         // Execute Pipeline
         match self.validation_pipeline.validate(&ctx).await {
             ValidationResult::Approve => {
-                // Reserve exposure for BUY orders to prevent over-allocation.
+                // Reserve exposure for entries (long or short) to prevent over-allocation.
                 // This ensures that subsequent proposals see reduced available_cash
                 // and won't exceed the actual balance at the broker.
-                let reservation_token = if proposal.side == OrderSide::Buy {
-                    let order_cost = proposal.price * proposal.quantity;
+                let reservation_token = if is_entry {
+                    // On margin only the initial margin is held back from cash
+                    let order_cost = proposal.price * proposal.quantity / leverage;
                     match self
                         .portfolio_state_manager
                         .reserve_exposure(&proposal.symbol, order_cost, snapshot.version)
@@ -815,6 +1292,12 @@ impl RiskManager {
                                                  Rejecting to prevent over-allocation.",
                                                 proposal.symbol, retry_err
                                             );
+                                            self.record_rejection(
+                                                &proposal,
+                                                format!("Reservation failed: {}", retry_err),
+                                                Some("reservation"),
+                                            )
+                                            .await;
                                             return Ok(());
                                         }
                                     }
@@ -825,6 +1308,12 @@ impl RiskManager {
                                          Original error: {}. Rejecting proposal.",
                                         proposal.symbol, refresh_err, e
                                     );
+                                    self.record_rejection(
+                                        &proposal,
+                                        format!("Portfolio refresh failed: {}", refresh_err),
+                                        Some("reservation"),
+                                    )
+                                    .await;
                                     return Ok(());
                                 }
                             }
@@ -838,11 +1327,14 @@ impl RiskManager {
                 self.execute_proposal_internal(proposal, reservation_token)
                     .await?;
             }
-            ValidationResult::Reject(reason) => {
+            ValidationResult::Reject { validator, reason } => {
                 info!(
-                    "RiskManager: Rejecting {:?} order for {} - {}",
-                    proposal.side, proposal.symbol, reason
+                    validator = %validator,
+                    "RiskManager: Rejecting {:?} order for {} - [{}] {}",
+                    proposal.side, proposal.symbol, validator, reason
                 );
+                self.record_rejection(&proposal, reason, Some(&validator))
+                    .await;
             }
         }
 
@@ -862,9 +1354,16 @@ impl RiskManager {
             crate::application::monitoring::portfolio_state_manager::ReservationToken,
         >,
     ) -> Result<(), Box<dyn std::error::Error>> {
-        // Create order with correct structure
+        // Create order with correct structure. The id doubles as the broker client order
+        // id and is derived from the signal, so a resubmission of it is deduplicated.
         let order = Order {
-            id: Uuid::new_v4().to_string(),
+            id: Order::client_order_id(
+                &proposal.symbol,
+                proposal.side,
+                proposal.timestamp,
+                &proposal.reason,
+                proposal.quantity,
+            ),
             symbol: proposal.symbol.clone(),
             side: proposal.side,
             price: proposal.price,
@@ -872,6 +1371,9 @@ impl RiskManager {
             order_type: proposal.order_type,
             status: crate::domain::trading::types::OrderStatus::Pending,
             timestamp: Utc::now().timestamp_millis(),
+            reduce_only: proposal.reduce_only,
+            origin: proposal.origin.clone(),
+            reason: Some(proposal.reason.clone()),
         };
 
         // Track as pending
@@ -914,6 +1416,13 @@ impl RiskManager {
             return Err(Box::new(e));
         }
 
+        self.record_decision(DecisionEvent::for_order(
+            DecisionKind::ProposalApproved,
+            &order,
+            proposal.reason,
+        ))
+        .await;
+
         Ok(())
     }
 
@@ -1095,6 +1604,7 @@ impl RiskManager {
                             }
                         }
                         Err(tokio::sync::broadcast::error::RecvError::Lagged(n)) => {
+                            self.metrics.inc_channel_lagged("order_updates", n);
                             warn!(
                                 "RiskManager: Order update receiver lagged, missed {} updates! Forcing refresh.",
                                 n
@@ -1119,9 +1629,13 @@ impl RiskManager {
 
                 // Process trade proposals
                 Some(proposal) = self.proposal_rx.recv() => {
-                    if let Err(e) = self.handle_command(RiskCommand::ProcessProposal(proposal)).await {
-                        error!("RiskManager: Proposal processing failed: {}", e);
-                    }
+                    self.enqueue_proposal(proposal).await;
+                    self.process_proposal_queue().await;
+                }
+
+                // Proposals left over from the previous batch
+                _ = std::future::ready(()), if !self.proposal_queue.is_empty() => {
+                    self.process_proposal_queue().await;
                 }
 
                 // External commands (Sentiment, etc.)
@@ -1129,6 +1643,10 @@ impl RiskManager {
                     if let Err(e) = self.handle_command(cmd).await {
                         error!("RiskManager: External command processing failed: {}", e);
                     }
+                    // Commands such as Rebalance queue proposals of their own
+                    if !self.proposal_queue.is_empty() {
+                        self.process_proposal_queue().await;
+                    }
                 }
             }
         }
@@ -5,6 +5,7 @@
 
 use crate::domain::ports::MarketDataService;
 use crate::domain::repositories::RiskStateRepository;
+use crate::domain::risk::session_boundary::TradingDayBoundary;
 use crate::domain::risk::state::RiskState;
 use crate::domain::trading::portfolio::Portfolio;
 use anyhow::Result;
@@ -25,10 +26,11 @@ use tracing::{debug, error, info, warn};
 pub struct SessionManager {
     risk_state_repository: Option<Arc<dyn RiskStateRepository>>,
     market_service: Arc<dyn MarketDataService>,
+    boundary: TradingDayBoundary,
 }
 
 impl SessionManager {
-    /// Create a new SessionManager
+    /// Create a new SessionManager (trading days roll at midnight UTC)
     pub fn new(
         risk_state_repository: Option<Arc<dyn RiskStateRepository>>,
         market_service: Arc<dyn MarketDataService>,
@@ -36,9 +38,16 @@ impl SessionManager {
         Self {
             risk_state_repository,
             market_service,
+            boundary: TradingDayBoundary::utc_midnight(),
         }
     }
 
+    /// Use `boundary` to decide when a new trading day starts
+    pub fn with_trading_day_boundary(mut self, boundary: TradingDayBoundary) -> Self {
+        self.boundary = boundary;
+        self
+    }
+
     /// Initialize session tracking with starting equity
     ///
     /// # Process
@@ -75,7 +84,7 @@ impl SessionManager {
             daily_start_equity: initial_equity,
             equity_high_water_mark: initial_equity,
             consecutive_losses: 0,
-            reference_date: Utc::now().date_naive(),
+            reference_date: self.boundary.trading_day(Utc::now()),
             updated_at: Utc::now().timestamp(),
             daily_drawdown_reset: false,
         };
@@ -122,8 +131,8 @@ impl SessionManager {
                         risk_state.equity_high_water_mark = state.equity_high_water_mark;
                         risk_state.consecutive_losses = state.consecutive_losses;
 
-                        // Restore Daily/Session logic ONLY if it's the same day
-                        let today = Utc::now().date_naive();
+                        // Restore Daily/Session logic ONLY if it's the same trading day
+                        let today = self.boundary.trading_day(Utc::now());
                         if state.reference_date == today {
                             risk_state.session_start_equity = state.session_start_equity;
                             risk_state.daily_start_equity = state.daily_start_equity;
@@ -172,16 +181,17 @@ impl SessionManager {
         current_state: &mut RiskState,
         current_equity: Decimal,
     ) -> bool {
-        let today = Utc::now().date_naive();
+        let today = self.boundary.trading_day(Utc::now());
 
         if current_state.reference_date < today {
             info!(
-                "SessionManager: Daily reset detected. Old date: {}, New date: {}",
-                current_state.reference_date, today
+                "SessionManager: Daily loss limit reset. Old trading day: {}, New trading day: {} (boundary {})",
+                current_state.reference_date, today, self.boundary
             );
 
             // Reset daily baseline
             current_state.daily_start_equity = current_equity;
+            current_state.session_start_equity = current_equity;
             current_state.reference_date = today;
             current_state.updated_at = Utc::now().timestamp();
             current_state.daily_drawdown_reset = true;
@@ -208,6 +218,7 @@ impl SessionManager {
 mod tests {
     use super::*;
     use crate::domain::trading::portfolio::Position;
+    use std::collections::VecDeque;
 
     struct MockRiskStateRepo {
         state: Arc<tokio::sync::RwLock<Option<RiskState>>>,
@@ -286,6 +297,7 @@ mod tests {
                 symbol: "AAPL".to_string(),
                 quantity: Decimal::from(10),
                 average_price: Decimal::from(140),
+                lots: VecDeque::new(),
             },
         );
 
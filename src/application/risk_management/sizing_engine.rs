@@ -6,8 +6,8 @@ use tracing::info;
 use crate::application::market_data::spread_cache::SpreadCache;
 use crate::application::monitoring::cost_evaluator::CostEvaluator;
 use crate::application::risk_management::circuit_breaker_service::HaltLevel;
-use crate::application::risk_management::volatility::calculate_realized_volatility;
 use crate::domain::market::market_regime::{MarketRegime, MarketRegimeType};
+use crate::domain::market::volatility::calculate_realized_volatility;
 use crate::domain::trading::types::{OrderSide, OrderType, TradeProposal};
 
 #[derive(Debug, Clone)]
@@ -217,6 +217,8 @@ impl SizingEngine {
                 timestamp: 0,
                 stop_loss: None,
                 take_profit: None,
+                reduce_only: false,
+                origin: Default::default(),
             };
             let costs = evaluator.evaluate(&proposal);
             target_amt = (target_amt - costs.total_cost).max(Decimal::ZERO);
@@ -1,4 +1,5 @@
 use crate::domain::repositories::RiskStateRepository;
+use crate::domain::risk::session_boundary::TradingDayBoundary;
 use crate::domain::risk::state::RiskState;
 use chrono::{DateTime, Utc};
 use rust_decimal::Decimal;
@@ -10,13 +11,19 @@ use tracing::{info, warn};
 pub struct RiskStateManager {
     risk_state: RiskState,
     repository: Option<Arc<dyn RiskStateRepository>>,
+    boundary: TradingDayBoundary,
 }
 
 impl RiskStateManager {
-    pub fn new(repository: Option<Arc<dyn RiskStateRepository>>, initial_equity: Decimal) -> Self {
+    pub fn new(
+        repository: Option<Arc<dyn RiskStateRepository>>,
+        initial_equity: Decimal,
+        boundary: TradingDayBoundary,
+    ) -> Self {
         let mut manager = Self {
             risk_state: RiskState::default(),
             repository,
+            boundary,
         };
 
         // Initialize state
@@ -81,23 +88,39 @@ impl RiskStateManager {
         }
     }
 
-    /// Check if a new trading day has started and reset daily metrics
-    pub fn check_daily_reset(&mut self, current_equity: Decimal) {
-        let now = Utc::now();
-        let last_update =
-            DateTime::<Utc>::from_timestamp(self.risk_state.updated_at, 0).unwrap_or(Utc::now());
-
-        // Simple check: if day of year changed
-        if now.date_naive() > last_update.date_naive() {
-            info!(
-                "New trading day detected ({}). Resetting daily session metrics.",
-                now
-            );
-            self.risk_state.session_start_equity = current_equity;
-            self.risk_state.daily_drawdown_reset = true;
-            self.risk_state.updated_at = now.timestamp();
-            self.risk_state.reference_date = now.date_naive();
+    pub fn boundary(&self) -> TradingDayBoundary {
+        self.boundary
+    }
+
+    pub fn set_boundary(&mut self, boundary: TradingDayBoundary) {
+        self.boundary = boundary;
+    }
+
+    /// Check if a new trading day has started and reset daily metrics.
+    ///
+    /// The persisted `reference_date` is the trading day of the current baseline,
+    /// so a restart within the same trading day keeps the daily loss counter.
+    /// Returns true when a reset happened.
+    pub fn check_daily_reset(&mut self, current_equity: Decimal) -> bool {
+        self.check_daily_reset_at(current_equity, Utc::now())
+    }
+
+    pub fn check_daily_reset_at(&mut self, current_equity: Decimal, now: DateTime<Utc>) -> bool {
+        let trading_day = self.boundary.trading_day(now);
+        if trading_day <= self.risk_state.reference_date {
+            return false;
         }
+
+        info!(
+            "New trading day {} (boundary {}). Daily loss limit reset: baseline {} -> {}",
+            trading_day, self.boundary, self.risk_state.session_start_equity, current_equity
+        );
+        self.risk_state.session_start_equity = current_equity;
+        self.risk_state.daily_start_equity = current_equity;
+        self.risk_state.daily_drawdown_reset = true;
+        self.risk_state.updated_at = now.timestamp();
+        self.risk_state.reference_date = trading_day;
+        true
     }
 
     /// Record a loss (increments consecutive losses)
@@ -123,3 +146,37 @@ impl RiskStateManager {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+    use rust_decimal_macros::dec;
+
+    #[test]
+    fn test_daily_reset_follows_trading_day_boundary() {
+        let mut manager =
+            RiskStateManager::new(None, dec!(1000), TradingDayBoundary::us_equities());
+        let state = manager.get_state_mut();
+        state.session_start_equity = dec!(1000);
+        state.reference_date = chrono::NaiveDate::from_ymd_opt(2026, 1, 14).unwrap();
+
+        // 23:00 New York on Jan 14 is already Jan 15 in UTC: same trading day
+        let late_evening = Utc.with_ymd_and_hms(2026, 1, 15, 4, 0, 0).unwrap();
+        assert!(!manager.check_daily_reset_at(dec!(900), late_evening));
+        assert_eq!(manager.get_state().session_start_equity, dec!(1000));
+
+        // Midnight New York starts the next trading day
+        let next_day = Utc.with_ymd_and_hms(2026, 1, 15, 5, 0, 0).unwrap();
+        assert!(manager.check_daily_reset_at(dec!(900), next_day));
+        assert_eq!(manager.get_state().session_start_equity, dec!(900));
+        assert_eq!(manager.get_state().daily_start_equity, dec!(900));
+        assert_eq!(
+            manager.get_state().reference_date,
+            chrono::NaiveDate::from_ymd_opt(2026, 1, 15).unwrap()
+        );
+
+        // Only once per trading day
+        assert!(!manager.check_daily_reset_at(dec!(850), next_day));
+    }
+}
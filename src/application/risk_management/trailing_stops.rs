@@ -9,6 +9,7 @@
 //! The `StopState` enum represents three distinct states:
 //! - `NoPosition`: No active position to protect
 //! - `ActiveStop`: Trailing stop is active and tracking price movements
+//! - `ActiveShortStop`: The same for a short, trailing above the lowest price
 //! - `Triggered`: Stop loss was hit
 //!
 //! # Example
@@ -32,6 +33,7 @@
 //! assert!(trigger.is_some()); // Stop triggered at 104
 //! ```
 
+use crate::domain::trading::types::OrderSide;
 use rust_decimal::Decimal;
 
 /// State machine for trailing stop loss management
@@ -46,6 +48,13 @@ pub enum StopState {
         stop_price: Decimal,
         atr: Decimal,
     },
+    /// Active trailing stop above a short position
+    ActiveShortStop {
+        entry_price: Decimal,
+        trough_price: Decimal,
+        stop_price: Decimal,
+        atr: Decimal,
+    },
     /// Stop was triggered
     Triggered {
         entry_price: Decimal,
@@ -74,6 +83,16 @@ impl StopState {
         }
     }
 
+    /// Create a new active stop when selling short
+    pub fn on_short(price: Decimal, atr: Decimal, multiplier: Decimal) -> Self {
+        StopState::ActiveShortStop {
+            entry_price: price,
+            trough_price: price,
+            stop_price: price + (atr * multiplier),
+            atr,
+        }
+    }
+
     /// Update stop on price movement
     /// Returns Some(TriggerEvent) if stop is hit
     pub fn on_price_update(
@@ -113,6 +132,44 @@ impl StopState {
 
                 None
             }
+            StopState::ActiveShortStop {
+                entry_price,
+                trough_price,
+                stop_price,
+                ..
+            } => {
+                // Update trough if new low
+                if price < *trough_price {
+                    *trough_price = price;
+                    *stop_price = price + (atr * multiplier);
+                    return None;
+                }
+
+                if price > *stop_price {
+                    let trigger = TriggerEvent {
+                        entry: *entry_price,
+                        exit: price,
+                        stop: *stop_price,
+                    };
+                    *self = StopState::Triggered {
+                        entry_price: *entry_price,
+                        exit_price: price,
+                        stop_price: *stop_price,
+                    };
+                    return Some(trigger);
+                }
+
+                None
+            }
+            _ => None,
+        }
+    }
+
+    /// Order side that closes the protected position, if a stop is active
+    pub fn exit_side(&self) -> Option<OrderSide> {
+        match self {
+            StopState::ActiveStop { .. } => Some(OrderSide::Sell),
+            StopState::ActiveShortStop { .. } => Some(OrderSide::Buy),
             _ => None,
         }
     }
@@ -124,13 +181,17 @@ impl StopState {
 
     /// Check if stop is currently active
     pub fn is_active(&self) -> bool {
-        matches!(self, StopState::ActiveStop { .. })
+        matches!(
+            self,
+            StopState::ActiveStop { .. } | StopState::ActiveShortStop { .. }
+        )
     }
 
     /// Get current stop price if active
     pub fn get_stop_price(&self) -> Option<Decimal> {
         match self {
-            StopState::ActiveStop { stop_price, .. } => Some(*stop_price),
+            StopState::ActiveStop { stop_price, .. }
+            | StopState::ActiveShortStop { stop_price, .. } => Some(*stop_price),
             _ => None,
         }
     }
@@ -260,4 +321,27 @@ mod tests {
         assert!(trigger.is_none());
         assert!(matches!(stop, StopState::NoPosition));
     }
+
+    #[test]
+    fn test_short_stop_trails_above_lows() {
+        let mut stop = StopState::on_short(Decimal::from(100), Decimal::from(2), Decimal::from(3));
+        assert_eq!(stop.get_stop_price(), Some(Decimal::from(106)));
+        assert_eq!(stop.exit_side(), Some(OrderSide::Buy));
+
+        // Price falls to 90: stop lowered to 96
+        let trigger = stop.on_price_update(Decimal::from(90), Decimal::from(2), Decimal::from(3));
+        assert!(trigger.is_none());
+        assert_eq!(stop.get_stop_price(), Some(Decimal::from(96)));
+
+        // Bounce below the stop holds, above it triggers
+        assert!(
+            stop.on_price_update(Decimal::from(95), Decimal::from(2), Decimal::from(3))
+                .is_none()
+        );
+        let trigger = stop
+            .on_price_update(Decimal::from(97), Decimal::from(2), Decimal::from(3))
+            .expect("short stop hit");
+        assert_eq!(trigger.stop, Decimal::from(96));
+        assert!(!stop.is_active());
+    }
 }
@@ -2,6 +2,7 @@ use super::traits::{AnalysisContext, Signal, TradingStrategy};
 use crate::application::strategies::legacy::advanced::{
     AdvancedTripleFilterConfig, AdvancedTripleFilterStrategy,
 };
+use crate::domain::trading::types::FeatureRequirements;
 use rust_decimal::Decimal;
 
 /// Configuration for Dynamic Regime Strategy
@@ -237,6 +238,10 @@ impl TradingStrategy for DynamicRegimeStrategy {
     fn name(&self) -> &str {
         "DynamicRegime"
     }
+
+    fn required_features(&self) -> FeatureRequirements {
+        self.advanced_strategy.required_features()
+    }
 }
 
 #[cfg(test)]
@@ -286,6 +291,8 @@ mod tests {
             momentum_normalized: None,
             realized_volatility: None,
             feature_set: None,
+            plus_di: None,
+            minus_di: None,
         }
     }
 
@@ -1,6 +1,7 @@
 use super::traits::{AnalysisContext, Signal, TradingStrategy};
 use super::{SMCStrategy, StatisticalMomentumStrategy, ZScoreMeanReversionStrategy};
 use crate::application::agents::analyst_config::AnalystConfig;
+use crate::domain::trading::types::FeatureRequirements;
 use std::collections::HashMap;
 use std::sync::Arc;
 
@@ -216,6 +217,14 @@ impl TradingStrategy for EnsembleStrategy {
     fn name(&self) -> &str {
         "Ensemble"
     }
+
+    fn required_features(&self) -> FeatureRequirements {
+        self.strategies
+            .iter()
+            .fold(FeatureRequirements::CORE, |acc, s| {
+                acc.union(s.required_features())
+            })
+    }
 }
 
 // Implement Debug manually since Arc<dyn TradingStrategy> doesn't impl Debug
@@ -278,6 +287,8 @@ mod tests {
             momentum_normalized: None,
             realized_volatility: None,
             feature_set: None,
+            plus_di: None,
+            minus_di: None,
         }
     }
 
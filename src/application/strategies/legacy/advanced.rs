@@ -1,6 +1,6 @@
 use crate::application::strategies::legacy::dual_sma::DualSMAStrategy;
 use crate::application::strategies::traits::{AnalysisContext, Signal, TradingStrategy};
-use crate::domain::trading::types::OrderSide;
+use crate::domain::trading::types::{FeatureRequirements, OrderSide};
 use rust_decimal::Decimal;
 
 use std::collections::HashMap;
@@ -140,11 +140,15 @@ impl AdvancedTripleFilterStrategy {
         match side {
             OrderSide::Buy => {
                 // Require strong trend for buying
-                if let Some(adx) = ctx.adx {
-                    adx > self.adx_threshold
-                } else {
-                    false // Missing ADX -> deny
-                }
+                let Some(adx) = ctx.adx else {
+                    return false; // Missing ADX -> deny
+                };
+                // Wilder setup: a strong trend must also point up (+DI > −DI)
+                let trend_up = match (ctx.plus_di, ctx.minus_di) {
+                    (Some(plus), Some(minus)) => plus > minus,
+                    _ => true, // Direction unknown -> ADX alone decides
+                };
+                adx > self.adx_threshold && trend_up
             }
             OrderSide::Sell => {
                 // Sells can happen in weak trends (e.g. stop loss or reversal)
@@ -296,6 +300,14 @@ impl TradingStrategy for AdvancedTripleFilterStrategy {
     fn name(&self) -> &str {
         "AdvancedTripleFilter"
     }
+
+    fn required_features(&self) -> FeatureRequirements {
+        FeatureRequirements {
+            macd: true,
+            adx: true,
+            ..FeatureRequirements::CORE
+        }
+    }
 }
 
 #[cfg(test)]
@@ -339,6 +351,8 @@ mod tests {
             momentum_normalized: None,
             realized_volatility: None,
             feature_set: None,
+            plus_di: None,
+            minus_di: None,
         }
     }
 
@@ -489,4 +503,40 @@ mod tests {
 
         assert!(signal.is_none(), "Should reject buy when ADX is weak");
     }
+
+    #[test]
+    fn test_advanced_buy_rejected_strong_downtrend_di() {
+        let strategy = AdvancedTripleFilterStrategy::new(AdvancedTripleFilterConfig {
+            fast_period: 20,
+            slow_period: 60,
+            sma_threshold: dec!(0.001),
+            trend_sma_period: 200,
+            rsi_threshold: dec!(75.0),
+            signal_confirmation_bars: 1,
+            macd_requires_rising: false,
+            trend_tolerance_pct: Decimal::ZERO,
+            macd_min_threshold: Decimal::ZERO,
+            adx_threshold: dec!(25.0),
+        });
+        let mut ctx = create_test_context();
+        ctx.adx = Some(dec!(35.0)); // Strong trend...
+        ctx.plus_di = Some(dec!(12.0));
+        ctx.minus_di = Some(dec!(30.0)); // ...but pointing down
+
+        ctx.current_price = dec!(105.0);
+        ctx.trend_sma = Some(dec!(100.0));
+        ctx.rsi = Some(dec!(50.0));
+        ctx.macd_histogram = Some(dec!(0.5));
+        ctx.fast_sma = Some(dec!(101.0));
+        ctx.slow_sma = Some(dec!(100.0));
+
+        assert!(
+            strategy.analyze(&ctx).is_none(),
+            "Should reject buy when -DI dominates"
+        );
+
+        ctx.plus_di = Some(dec!(30.0));
+        ctx.minus_di = Some(dec!(12.0));
+        assert!(strategy.adx_filter(&ctx, OrderSide::Buy));
+    }
 }
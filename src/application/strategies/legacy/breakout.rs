@@ -1,4 +1,5 @@
 use crate::application::strategies::traits::{AnalysisContext, Signal, TradingStrategy};
+use crate::domain::trading::types::FeatureRequirements;
 use rust_decimal::Decimal;
 
 /// Breakout Strategy
@@ -124,6 +125,10 @@ impl TradingStrategy for BreakoutStrategy {
     fn name(&self) -> &str {
         "Breakout"
     }
+
+    fn required_features(&self) -> FeatureRequirements {
+        FeatureRequirements::CORE
+    }
 }
 
 #[cfg(test)]
@@ -187,6 +192,8 @@ mod tests {
             momentum_normalized: None,
             realized_volatility: None,
             feature_set: None,
+            plus_di: None,
+            minus_di: None,
         }
     }
 
@@ -1,4 +1,5 @@
 use crate::application::strategies::traits::{AnalysisContext, Signal, TradingStrategy};
+use crate::domain::trading::types::FeatureRequirements;
 use rust_decimal::Decimal;
 
 /// Dual Simple Moving Average (SMA) crossover strategy
@@ -64,6 +65,10 @@ impl TradingStrategy for DualSMAStrategy {
     fn name(&self) -> &str {
         "DualSMA"
     }
+
+    fn required_features(&self) -> FeatureRequirements {
+        FeatureRequirements::CORE
+    }
 }
 
 #[cfg(test)]
@@ -111,6 +116,8 @@ mod tests {
             momentum_normalized: None,
             realized_volatility: None,
             feature_set: None,
+            plus_di: None,
+            minus_di: None,
         }
     }
 
@@ -1,4 +1,5 @@
 use crate::application::strategies::traits::{AnalysisContext, Signal, TradingStrategy};
+use crate::domain::trading::types::FeatureRequirements;
 use rust_decimal::Decimal;
 
 /// Mean Reversion Strategy
@@ -60,6 +61,13 @@ impl TradingStrategy for MeanReversionStrategy {
     fn name(&self) -> &str {
         "MeanReversion"
     }
+
+    fn required_features(&self) -> FeatureRequirements {
+        FeatureRequirements {
+            bollinger: true,
+            ..FeatureRequirements::CORE
+        }
+    }
 }
 
 #[cfg(test)]
@@ -111,6 +119,8 @@ mod tests {
             realized_volatility: None,
             timeframe_features: None,
             feature_set: None,
+            plus_di: None,
+            minus_di: None,
         }
     }
 
@@ -1,4 +1,5 @@
 use crate::application::strategies::traits::{AnalysisContext, Signal, TradingStrategy};
+use crate::domain::trading::types::FeatureRequirements;
 use rust_decimal::Decimal;
 use rust_decimal_macros::dec;
 
@@ -206,6 +207,10 @@ impl TradingStrategy for MomentumDivergenceStrategy {
     fn name(&self) -> &str {
         "MomentumDivergence"
     }
+
+    fn required_features(&self) -> FeatureRequirements {
+        FeatureRequirements::CORE
+    }
 }
 
 #[cfg(test)]
@@ -271,6 +276,8 @@ mod tests {
             realized_volatility: None,
             timeframe_features: None,
             feature_set: None,
+            plus_di: None,
+            minus_di: None,
         }
     }
 
@@ -1,4 +1,5 @@
 use crate::application::strategies::traits::{AnalysisContext, Signal, TradingStrategy};
+use crate::domain::trading::types::FeatureRequirements;
 use rust_decimal::Decimal;
 
 /// Trend Riding Strategy
@@ -61,6 +62,10 @@ impl TradingStrategy for TrendRidingStrategy {
     fn name(&self) -> &str {
         "TrendRiding"
     }
+
+    fn required_features(&self) -> FeatureRequirements {
+        FeatureRequirements::CORE
+    }
 }
 
 #[cfg(test)]
@@ -110,6 +115,8 @@ mod tests {
             momentum_normalized: None,
             realized_volatility: None,
             feature_set: None,
+            plus_di: None,
+            minus_di: None,
         }
     }
 
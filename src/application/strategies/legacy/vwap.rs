@@ -1,4 +1,6 @@
 use crate::application::strategies::traits::{AnalysisContext, Signal, TradingStrategy};
+use crate::domain::trading::time::utc_day_start_ms;
+use crate::domain::trading::types::FeatureRequirements;
 use rust_decimal::Decimal;
 use rust_decimal_macros::dec;
 
@@ -36,9 +38,8 @@ impl VWAPStrategy {
         }
 
         // Get current day start (midnight UTC) to reset VWAP
-        // timestamp is i64 seconds, so modulo 86400 gives seconds since midnight
-        let current_ts = ctx.timestamp;
-        let day_start = current_ts - (current_ts % 86400);
+        // Timestamps are Unix milliseconds
+        let day_start = utc_day_start_ms(ctx.timestamp);
 
         // Check data sufficiency: Do we have data since the start of the day?
         // If the first candle is after day_start, we are missing early volume data.
@@ -149,11 +150,16 @@ impl TradingStrategy for VWAPStrategy {
     fn name(&self) -> &str {
         "VWAP"
     }
+
+    fn required_features(&self) -> FeatureRequirements {
+        FeatureRequirements::CORE
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::domain::trading::time::MS_PER_DAY;
     use crate::domain::trading::types::Candle;
     use rust_decimal::Decimal;
     use rust_decimal::prelude::FromPrimitive;
@@ -203,7 +209,7 @@ mod tests {
             adx: Some(dec!(25.0)),
             has_position,
             position: None,
-            timestamp: candles.back().map(|c| c.timestamp).unwrap_or(100000),
+            timestamp: candles.back().map(|c| c.timestamp).unwrap_or(100_000_000),
             timeframe_features: None,
             candles,
             rsi_history: VecDeque::new(),
@@ -217,6 +223,8 @@ mod tests {
             momentum_normalized: None,
             realized_volatility: None,
             feature_set: None,
+            plus_di: None,
+            minus_di: None,
         }
     }
 
@@ -224,13 +232,10 @@ mod tests {
     fn test_vwap_calculation() {
         let strategy = VWAPStrategy::default();
 
-        // Midnight is 0.
-        // Candle 1 at 100 which is > 0.
-        // Logic requires start timestamp > day_start for correct daily VWAP calculation.
-        // timestamp is 100000. day_start = 100000 - (100000 % 86400) = 86400.
-        // So candles must be >= 86400.
+        // Logic requires start timestamp >= day_start for correct daily VWAP calculation.
+        // Timestamps are in ms, so the second UTC midnight is MS_PER_DAY.
 
-        let ts_start = 86400; // Start at midnight
+        let ts_start = MS_PER_DAY; // Start at midnight
 
         let mut candles = VecDeque::new();
         candles.push_back(mock_candle_with_ts(110.0, 90.0, 100.0, 1000.0, ts_start));
@@ -239,7 +244,7 @@ mod tests {
             95.0,
             105.0,
             2000.0,
-            ts_start + 60,
+            ts_start + 60_000,
         ));
 
         let ctx = create_context(100.0, 50.0, candles, false);
@@ -260,7 +265,7 @@ mod tests {
     fn test_buy_signal_below_vwap() {
         let strategy = VWAPStrategy::new(dec!(0.02), dec!(35.0), dec!(65.0));
 
-        let ts_start = 86400;
+        let ts_start = MS_PER_DAY;
         let mut candles = VecDeque::new();
         candles.push_back(mock_candle_with_ts(105.0, 95.0, 100.0, 1000.0, ts_start));
         candles.push_back(mock_candle_with_ts(
@@ -268,7 +273,7 @@ mod tests {
             95.0,
             100.0,
             1000.0,
-            ts_start + 60,
+            ts_start + 60_000,
         ));
 
         // Price 97 = 3% below VWAP (100), RSI 30 < 35 (oversold)
@@ -288,7 +293,7 @@ mod tests {
     fn test_sell_signal_short_entry() {
         let strategy = VWAPStrategy::new(dec!(0.02), dec!(35.0), dec!(65.0));
 
-        let ts_start = 86400;
+        let ts_start = MS_PER_DAY;
         let mut candles = VecDeque::new();
         candles.push_back(mock_candle_with_ts(105.0, 95.0, 100.0, 1000.0, ts_start));
         candles.push_back(mock_candle_with_ts(
@@ -296,7 +301,7 @@ mod tests {
             95.0,
             100.0,
             1000.0,
-            ts_start + 60,
+            ts_start + 60_000,
         ));
 
         // Price 103 = 3% above VWAP (100), HAS POSITION
@@ -316,14 +321,12 @@ mod tests {
     fn test_partial_data_calculation() {
         let strategy = VWAPStrategy::default();
 
-        // Current time: 100000 (~1 day + 3.7h). Day start: 86400.
-        // First candle time: 90000 (valid, > 86400).
-        // Scenario 2: Connecting at 90000. Fetch 10 candles.
-        // We are missing data from 86400 to 89400.
-        // VWAP will be a "Rolling VWAP" from 90000. This is allowed now (with warning).
+        // Current time: 1 day + ~3.7h. Day start: MS_PER_DAY.
+        // First candle ~1h after midnight: we are missing the early session.
+        // VWAP will be a "Rolling VWAP" from the first candle. This is allowed now (with warning).
 
-        let current_ts = 100000;
-        let late_start = 90000;
+        let current_ts = 100_000_000;
+        let late_start = 90_000_000;
 
         let mut candles = VecDeque::new();
         candles.push_back(mock_candle_with_ts(100.0, 100.0, 100.0, 1000.0, late_start));
@@ -337,4 +340,29 @@ mod tests {
             "Should calculate partial VWAP even with missing daily history"
         );
     }
+
+    #[test]
+    fn test_vwap_resets_at_utc_midnight_in_ms() {
+        let strategy = VWAPStrategy::default();
+
+        // One minute before midnight (previous day) at a far-away price, then today's bar
+        let mut candles = VecDeque::new();
+        candles.push_back(mock_candle_with_ts(
+            200.0,
+            200.0,
+            200.0,
+            5000.0,
+            MS_PER_DAY - 60_000,
+        ));
+        candles.push_back(mock_candle_with_ts(
+            100.0,
+            100.0,
+            100.0,
+            1000.0,
+            MS_PER_DAY + 60_000,
+        ));
+
+        let ctx = create_context(100.0, 50.0, candles, false);
+        assert_eq!(strategy.calculate_vwap(&ctx), Some(dec!(100)));
+    }
 }
@@ -1,5 +1,6 @@
 use super::{AnalysisContext, Signal, TradingStrategy};
 use crate::domain::market::order_flow::detect_stacked_imbalances;
+use crate::domain::trading::types::FeatureRequirements;
 use rust_decimal::Decimal;
 use rust_decimal_macros::dec;
 
@@ -172,6 +173,10 @@ impl TradingStrategy for OrderFlowStrategy {
     fn name(&self) -> &str {
         "OrderFlow"
     }
+
+    fn required_features(&self) -> FeatureRequirements {
+        FeatureRequirements::CORE
+    }
 }
 
 #[cfg(test)]
@@ -238,6 +243,8 @@ mod tests {
             realized_volatility: None,
             timeframe_features: None,
             feature_set: None,
+            plus_di: None,
+            minus_di: None,
         }
     }
 
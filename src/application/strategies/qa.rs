@@ -47,6 +47,8 @@ impl ContextBuilder {
                 momentum_normalized: None,
                 realized_volatility: None,
                 feature_set: None,
+                plus_di: None,
+                minus_di: None,
             },
         }
     }
@@ -167,6 +169,7 @@ fn test_qa_scenario_bull_market() {
 
             // VERIFICATION: Check if strategies supporting SL/TP are actually returning it
             // SMC, ZScoreMR, StatMomentum should return SL
+            #[allow(clippy::collapsible_match)]
             match strategy.name() {
                 "SMC" | "ZScoreMR" | "StatMomentum" => {
                     if !signal.reason.contains("blocked") {
@@ -358,6 +361,8 @@ fn test_precision_vwap() {
         momentum_normalized: None,
         realized_volatility: None,
         feature_set: None,
+        plus_di: None,
+        minus_di: None,
     };
 
     let vwap = strategy
@@ -519,6 +524,8 @@ fn test_precision_zscore() {
         momentum_normalized: None,
         realized_volatility: None,
         feature_set: None,
+        plus_di: None,
+        minus_di: None,
     };
 
     let (zscore, _, _) = strategy
@@ -608,6 +615,8 @@ fn test_precision_rsi_alignment() {
         momentum_normalized: None,
         realized_volatility: None,
         feature_set: None,
+        plus_di: None,
+        minus_di: None,
     };
 
     let div = strategy.find_divergence(&ctx);
@@ -1,5 +1,5 @@
 use super::traits::{AnalysisContext, Signal, TradingStrategy};
-use crate::domain::trading::types::{Candle, OrderSide};
+use crate::domain::trading::types::{Candle, FeatureRequirements, OrderSide};
 use rust_decimal::Decimal;
 use std::collections::VecDeque;
 
@@ -482,6 +482,10 @@ impl TradingStrategy for SMCStrategy {
     fn name(&self) -> &str {
         "SMC"
     }
+
+    fn required_features(&self) -> FeatureRequirements {
+        FeatureRequirements::CORE
+    }
 }
 
 #[cfg(test)]
@@ -1,4 +1,5 @@
 use crate::application::strategies::traits::{AnalysisContext, Signal, TradingStrategy};
+use crate::domain::trading::types::FeatureRequirements;
 use rust_decimal::prelude::*;
 use statrs::statistics::{Data, Distribution};
 
@@ -127,6 +128,10 @@ impl TradingStrategy for ZScoreMeanReversionStrategy {
     fn name(&self) -> &str {
         "ZScoreMR"
     }
+
+    fn required_features(&self) -> FeatureRequirements {
+        FeatureRequirements::CORE
+    }
 }
 
 #[cfg(test)]
@@ -187,6 +192,8 @@ mod tests {
             momentum_normalized: None,
             realized_volatility: None,
             feature_set: None,
+            plus_di: None,
+            minus_di: None,
         }
     }
 
@@ -24,7 +24,29 @@ impl StrategySelector {
         config: &AnalystConfig,
         current_mode: StrategyMode,
     ) -> (StrategyMode, Arc<dyn TradingStrategy>) {
-        let proposed_mode = Self::select_mode_for_regime(regime, current_mode);
+        Self::select_strategy_with_hurst(regime, None, config, current_mode)
+    }
+
+    /// Same as [`Self::select_strategy`], but lets the Hurst exponent decide
+    /// between momentum and mean reversion when it is clearly away from 0.5:
+    /// - **H > 0.55** (persistent) → StatMomentum
+    /// - **H < 0.45** (anti-persistent) → ZScoreMR
+    ///
+    /// Volatile regimes keep their mapping; inside the band the regime decides.
+    /// A Hurst-driven switch is held back by the same confidence hysteresis as a
+    /// regime-driven one.
+    pub fn select_strategy_with_hurst(
+        regime: &MarketRegime,
+        hurst: Option<Decimal>,
+        config: &AnalystConfig,
+        current_mode: StrategyMode,
+    ) -> (StrategyMode, Arc<dyn TradingStrategy>) {
+        let proposed_mode = if Self::holds_current_mode(regime, current_mode) {
+            current_mode
+        } else {
+            Self::select_mode_for_hurst(regime, hurst)
+                .unwrap_or_else(|| Self::select_mode_for_regime(regime))
+        };
 
         if proposed_mode != current_mode {
             info!(
@@ -40,20 +62,39 @@ impl StrategySelector {
         (proposed_mode, strategy)
     }
 
-    /// Core logic for mapping regime to strategy mode
-    ///
-    /// Enhanced with hysteresis: requires high confidence (>= 0.6) to switch strategies,
+    /// Hurst-based mode, or None when H is missing, near 0.5, or the market is volatile
+    fn select_mode_for_hurst(
+        regime: &MarketRegime,
+        hurst: Option<Decimal>,
+    ) -> Option<StrategyMode> {
+        const HURST_NEUTRAL_BAND: Decimal = dec!(0.05);
+
+        if regime.regime_type == MarketRegimeType::Volatile {
+            return None;
+        }
+        let h = hurst?;
+        if h > dec!(0.5) + HURST_NEUTRAL_BAND {
+            Some(StrategyMode::StatMomentum)
+        } else if h < dec!(0.5) - HURST_NEUTRAL_BAND {
+            Some(StrategyMode::ZScoreMR)
+        } else {
+            None
+        }
+    }
+
+    /// Hysteresis: requires high confidence (>= 0.6) to switch strategies,
     /// preventing whipsaw from rapid regime changes.
-    fn select_mode_for_regime(regime: &MarketRegime, current_mode: StrategyMode) -> StrategyMode {
-        // Hysteresis: Only switch if confidence is high enough
+    fn holds_current_mode(regime: &MarketRegime, current_mode: StrategyMode) -> bool {
+        // Only switch if confidence is high enough
         // This prevents rapid switching (whipsawing) between strategies
         const MIN_CONFIDENCE_TO_SWITCH: Decimal = dec!(0.6);
 
-        if regime.confidence < MIN_CONFIDENCE_TO_SWITCH && current_mode != StrategyMode::Standard {
-            // Low confidence in new regime - stick with current strategy
-            return current_mode;
-        }
+        // Low confidence in new regime - stick with current strategy
+        regime.confidence < MIN_CONFIDENCE_TO_SWITCH && current_mode != StrategyMode::Standard
+    }
 
+    /// Core logic for mapping regime to strategy mode
+    fn select_mode_for_regime(regime: &MarketRegime) -> StrategyMode {
         match regime.regime_type {
             MarketRegimeType::TrendingUp | MarketRegimeType::TrendingDown => {
                 // Strong trends → Statistical Momentum (Modern)
@@ -140,4 +181,69 @@ mod tests {
         let (mode, _) = StrategySelector::select_strategy(&regime, &config, StrategyMode::Standard);
         assert_eq!(mode, StrategyMode::Standard);
     }
+
+    #[test]
+    fn test_hurst_overrides_regime_mapping() {
+        let config = default_config();
+        let ranging = make_regime(MarketRegimeType::Ranging, 0.7);
+
+        let (mode, _) = StrategySelector::select_strategy_with_hurst(
+            &ranging,
+            Some(dec!(0.7)),
+            &config,
+            StrategyMode::Standard,
+        );
+        assert_eq!(mode, StrategyMode::StatMomentum);
+
+        let trending = make_regime(MarketRegimeType::TrendingUp, 0.8);
+        let (mode, _) = StrategySelector::select_strategy_with_hurst(
+            &trending,
+            Some(dec!(0.3)),
+            &config,
+            StrategyMode::Standard,
+        );
+        assert_eq!(mode, StrategyMode::ZScoreMR);
+
+        // Near 0.5 the regime decides; volatile regimes ignore Hurst
+        let (mode, _) = StrategySelector::select_strategy_with_hurst(
+            &trending,
+            Some(dec!(0.52)),
+            &config,
+            StrategyMode::Standard,
+        );
+        assert_eq!(mode, StrategyMode::StatMomentum);
+        let volatile = make_regime(MarketRegimeType::Volatile, 0.75);
+        let (mode, _) = StrategySelector::select_strategy_with_hurst(
+            &volatile,
+            Some(dec!(0.3)),
+            &config,
+            StrategyMode::Standard,
+        );
+        assert_eq!(mode, StrategyMode::Momentum);
+    }
+
+    #[test]
+    fn test_hurst_switch_respects_confidence_hysteresis() {
+        let config = default_config();
+        let weak_ranging = make_regime(MarketRegimeType::Ranging, 0.4);
+
+        // Low-confidence regime: a clear Hurst reading does not force a switch
+        let (mode, _) = StrategySelector::select_strategy_with_hurst(
+            &weak_ranging,
+            Some(dec!(0.7)),
+            &config,
+            StrategyMode::ZScoreMR,
+        );
+        assert_eq!(mode, StrategyMode::ZScoreMR);
+
+        // Once confident, the Hurst reading decides
+        let ranging = make_regime(MarketRegimeType::Ranging, 0.7);
+        let (mode, _) = StrategySelector::select_strategy_with_hurst(
+            &ranging,
+            Some(dec!(0.7)),
+            &config,
+            StrategyMode::ZScoreMR,
+        );
+        assert_eq!(mode, StrategyMode::StatMomentum);
+    }
 }
@@ -37,6 +37,8 @@ fn create_empty_context() -> AnalysisContext {
         momentum_normalized: None,
         realized_volatility: None,
         feature_set: None,
+        plus_di: None,
+        minus_di: None,
     }
 }
 
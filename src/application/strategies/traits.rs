@@ -1,6 +1,6 @@
 use crate::domain::market::order_flow::VolumeProfile;
 use crate::domain::market::timeframe::Timeframe;
-use crate::domain::trading::types::{Candle, OrderSide};
+use crate::domain::trading::types::{Candle, FeatureRequirements, OrderSide};
 use rust_decimal::Decimal;
 use rust_decimal::prelude::FromPrimitive;
 
@@ -53,13 +53,16 @@ pub struct AnalysisContext {
     pub bb_upper: Option<Decimal>,
     pub bb_middle: Option<Decimal>,
     pub adx: Option<Decimal>,
+    /// Directional indicators: +DI > −DI means the trend points up
+    pub plus_di: Option<Decimal>,
+    pub minus_di: Option<Decimal>,
 
     // Position state
     pub has_position: bool,
     /// Position details (None if no position)
     pub position: Option<PositionInfo>,
 
-    // Timestamp
+    // Timestamp (Unix milliseconds, same as `Candle::timestamp`)
     pub timestamp: i64,
 
     // Candle history (for SMC patterns)
@@ -273,6 +276,12 @@ pub trait TradingStrategy: Send + Sync {
 
     /// Strategy name for logging and identification
     fn name(&self) -> &str;
+
+    /// Optional indicator groups `analyze` reads; the rest are not computed.
+    /// Defaults to all of them.
+    fn required_features(&self) -> FeatureRequirements {
+        FeatureRequirements::ALL
+    }
 }
 
 #[cfg(test)]
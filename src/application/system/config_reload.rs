@@ -0,0 +1,486 @@
+//! Live configuration reload.
+//!
+//! On SIGHUP the `.env` file and process environment are re-read into a fresh
+//! [`Config`], diffed against the previously loaded one, and only the fields
+//! that changed are applied on top of each agent's running config. Settings the
+//! reload did not touch (stored optimal parameters, edits made in the UI) are
+//! kept. Settings that require a restart (broker, mode, symbols) are reloaded
+//! but not applied.
+
+use crate::application::agents::analyst::AnalystCommand;
+use crate::application::agents::analyst_config::AnalystConfig;
+use crate::application::bootstrap::agents::{create_analyst_config, create_risk_config};
+use crate::application::risk_management::commands::RiskCommand;
+use crate::config::Config;
+use crate::domain::risk::risk_config::RiskConfig;
+use anyhow::{Context, Result};
+use std::sync::Arc;
+use tokio::sync::{RwLock, mpsc};
+use tracing::{error, info, warn};
+
+/// Re-reads the configuration and forwards changed settings to running agents.
+pub struct ConfigReloader {
+    current: Config,
+    analyst_cmd_tx: mpsc::Sender<AnalystCommand>,
+    risk_cmd_tx: mpsc::Sender<RiskCommand>,
+    running_analyst: Arc<RwLock<AnalystConfig>>,
+    running_risk: Arc<RwLock<RiskConfig>>,
+}
+
+impl ConfigReloader {
+    pub fn new(
+        current: Config,
+        analyst_cmd_tx: mpsc::Sender<AnalystCommand>,
+        risk_cmd_tx: mpsc::Sender<RiskCommand>,
+        running_analyst: Arc<RwLock<AnalystConfig>>,
+        running_risk: Arc<RwLock<RiskConfig>>,
+    ) -> Self {
+        Self {
+            current,
+            analyst_cmd_tx,
+            risk_cmd_tx,
+            running_analyst,
+            running_risk,
+        }
+    }
+
+    /// Reloads `.env` (overriding the process environment) and applies the result.
+    pub async fn reload(&mut self) -> Result<()> {
+        dotenvy::dotenv_override().ok();
+        let new_config = Config::from_env().context("Failed to reload config")?;
+        self.apply(new_config).await
+    }
+
+    /// Diffs `new_config` against the previously loaded config and applies the
+    /// changed fields onto the agents' running configs.
+    /// Nothing is applied unless both merged configs are valid.
+    pub async fn apply(&mut self, new_config: Config) -> Result<()> {
+        let old_analyst = create_analyst_config(&self.current);
+        let new_analyst = create_analyst_config(&new_config);
+        let analyst_changes = analyst_changed_fields(&old_analyst, &new_analyst);
+
+        let old_risk = create_risk_config(&self.current, None, None);
+        let new_risk = create_risk_config(&new_config, None, None);
+        let risk_changes = risk_changed_fields(&old_risk, &new_risk);
+
+        if analyst_changes.is_empty() && risk_changes.is_empty() {
+            info!("ConfigReloader: Config reloaded, no changes detected.");
+            self.current = new_config;
+            return Ok(());
+        }
+
+        let merged_analyst = if analyst_changes.is_empty() {
+            None
+        } else {
+            let running = self.running_analyst.read().await.clone();
+            Some(merge_analyst_changes(&old_analyst, &new_analyst, &running)?)
+        };
+        let merged_risk = if risk_changes.is_empty() {
+            None
+        } else {
+            let mut running = self.running_risk.read().await.clone();
+            merge_risk_changes(&old_risk, &new_risk, &mut running);
+            Some(running)
+        };
+
+        // Validate before any agent sees the new settings so a bad reload is all-or-nothing
+        if let Some(analyst) = &merged_analyst {
+            analyst
+                .validate()
+                .map_err(|e| anyhow::anyhow!("Reloaded analyst config is invalid: {}", e))?;
+        }
+        if let Some(risk) = &merged_risk {
+            risk.validate()
+                .map_err(|e| anyhow::anyhow!("Reloaded risk config is invalid: {}", e))?;
+        }
+
+        if let Some(analyst) = merged_analyst {
+            info!(
+                "ConfigReloader: Analyst settings changed: {}",
+                analyst_changes.join(", ")
+            );
+            self.analyst_cmd_tx
+                .send(AnalystCommand::UpdateConfig(Box::new(analyst)))
+                .await
+                .context("Analyst command channel closed")?;
+        }
+
+        if let Some(risk) = merged_risk {
+            info!(
+                "ConfigReloader: Risk settings changed: {}",
+                risk_changes.join(", ")
+            );
+            self.risk_cmd_tx
+                .send(RiskCommand::UpdateConfig(Box::new(risk)))
+                .await
+                .context("Risk command channel closed")?;
+        }
+
+        self.current = new_config;
+        Ok(())
+    }
+
+    /// Spawns a task that reloads the config every time the process receives SIGHUP.
+    #[cfg(unix)]
+    pub fn spawn_sighup_handler(mut self) {
+        use tokio::signal::unix::{SignalKind, signal};
+
+        tokio::spawn(async move {
+            let mut hangup = match signal(SignalKind::hangup()) {
+                Ok(s) => s,
+                Err(e) => {
+                    error!("ConfigReloader: Unable to listen for SIGHUP: {}", e);
+                    return;
+                }
+            };
+            info!("ConfigReloader: Send SIGHUP to reload configuration.");
+
+            while hangup.recv().await.is_some() {
+                info!("ConfigReloader: Received SIGHUP, reloading configuration...");
+                if let Err(e) = self.reload().await {
+                    warn!(
+                        "ConfigReloader: Reload failed, keeping current config: {:#}",
+                        e
+                    );
+                }
+            }
+        });
+    }
+
+    #[cfg(not(unix))]
+    pub fn spawn_sighup_handler(self) {
+        warn!("ConfigReloader: SIGHUP reload is not supported on this platform.");
+    }
+}
+
+/// Names of the analyst settings that differ between `old` and `new`.
+pub fn analyst_changed_fields(old: &AnalystConfig, new: &AnalystConfig) -> Vec<String> {
+    let (Ok(serde_json::Value::Object(old)), Ok(serde_json::Value::Object(new))) =
+        (serde_json::to_value(old), serde_json::to_value(new))
+    else {
+        return vec!["<unserializable>".to_string()];
+    };
+
+    let mut changed: Vec<String> = new
+        .iter()
+        .filter(|(key, value)| old.get(*key) != Some(*value))
+        .map(|(key, value)| {
+            let before = old
+                .get(key)
+                .map(|v| v.to_string())
+                .unwrap_or_else(|| "-".to_string());
+            format!("{} {} -> {}", key, before, value)
+        })
+        .collect();
+    changed.sort();
+    changed
+}
+
+/// Copies the analyst settings that differ between `old` and `new` onto `running`.
+pub fn merge_analyst_changes(
+    old: &AnalystConfig,
+    new: &AnalystConfig,
+    running: &AnalystConfig,
+) -> Result<AnalystConfig> {
+    let (serde_json::Value::Object(old), serde_json::Value::Object(new)) =
+        (serde_json::to_value(old)?, serde_json::to_value(new)?)
+    else {
+        anyhow::bail!("Analyst config does not serialize to an object");
+    };
+    let mut merged = serde_json::to_value(running)?;
+    if let serde_json::Value::Object(merged) = &mut merged {
+        for (key, value) in new {
+            if old.get(&key) != Some(&value) {
+                merged.insert(key, value);
+            }
+        }
+    }
+    let mut merged: AnalystConfig = serde_json::from_value(merged)?;
+    // The fee model is not serialized
+    merged.fee_model = running.fee_model.clone();
+    Ok(merged)
+}
+
+/// Invokes `$apply!` with every reloadable `RiskConfig` field.
+macro_rules! for_each_risk_field {
+    ($apply:ident) => {
+        $apply!(
+            max_position_size_pct,
+            max_daily_loss_pct,
+            max_drawdown_pct,
+            consecutive_loss_limit,
+            valuation_interval_seconds,
+            max_sector_exposure_pct,
+            max_gross_exposure_pct,
+            allow_pdt_risk,
+            pending_order_ttl_ms,
+            allow_average_down,
+            max_average_down_loss_pct,
+            min_order_notional,
+            allow_min_notional_bump,
+            daily_reset,
+            allow_shorts,
+            leverage,
+            max_open_orders,
+            earnings_blackout_days,
+            earnings_flatten,
+            correlation_config,
+            volatility_config
+        )
+    };
+}
+
+/// Names of the risk limits that differ between `old` and `new`.
+pub fn risk_changed_fields(old: &RiskConfig, new: &RiskConfig) -> Vec<String> {
+    let mut changed = Vec::new();
+    macro_rules! diff {
+        ($($field:ident),*) => {
+            // Exhaustive: a new RiskConfig field fails to compile until it is listed here
+            let RiskConfig {
+                $($field: _,)*
+                sector_provider: _,
+                earnings_provider: _,
+            } = new;
+            $(
+                if old.$field != new.$field {
+                    changed.push(format!(
+                        "{} {:?} -> {:?}",
+                        stringify!($field),
+                        old.$field,
+                        new.$field
+                    ));
+                }
+            )*
+        };
+    }
+    for_each_risk_field!(diff);
+    changed
+}
+
+/// Copies the risk limits that differ between `old` and `new` onto `running`.
+pub fn merge_risk_changes(old: &RiskConfig, new: &RiskConfig, running: &mut RiskConfig) {
+    macro_rules! merge {
+        ($($field:ident),*) => {
+            $(
+                if old.$field != new.$field {
+                    running.$field = new.$field.clone();
+                }
+            )*
+        };
+    }
+    for_each_risk_field!(merge);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::risk::session_boundary::TradingDayBoundary;
+    use rust_decimal_macros::dec;
+
+    fn reloader_for(
+        base: &Config,
+        analyst_tx: mpsc::Sender<AnalystCommand>,
+        risk_tx: mpsc::Sender<RiskCommand>,
+    ) -> ConfigReloader {
+        ConfigReloader::new(
+            base.clone(),
+            analyst_tx,
+            risk_tx,
+            Arc::new(RwLock::new(create_analyst_config(base))),
+            Arc::new(RwLock::new(create_risk_config(base, None, None))),
+        )
+    }
+
+    #[test]
+    fn test_analyst_changed_fields() {
+        let old = AnalystConfig::default();
+        assert!(analyst_changed_fields(&old, &old.clone()).is_empty());
+
+        let mut new = old.clone();
+        new.rsi_threshold = dec!(65.0);
+        new.fast_sma_period = 12;
+        let changed = analyst_changed_fields(&old, &new);
+        assert_eq!(changed.len(), 2);
+        assert!(changed[0].starts_with("fast_sma_period 10 -> 12"));
+        assert!(changed[1].starts_with("rsi_threshold"));
+    }
+
+    #[test]
+    fn test_risk_changed_fields() {
+        let old = RiskConfig::default();
+        assert!(risk_changed_fields(&old, &old.clone()).is_empty());
+
+        let new = RiskConfig {
+            max_daily_loss_pct: dec!(0.03),
+            ..RiskConfig::default()
+        };
+        assert_eq!(
+            risk_changed_fields(&old, &new),
+            vec!["max_daily_loss_pct 0.02 -> 0.03".to_string()]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_apply_dispatches_only_changed_agents() {
+        let (analyst_tx, mut analyst_rx) = mpsc::channel(4);
+        let (risk_tx, mut risk_rx) = mpsc::channel(4);
+        let base = Config::from_env().unwrap();
+        let mut reloader = reloader_for(&base, analyst_tx, risk_tx);
+
+        let mut changed = base;
+        changed.fast_sma_period += 1;
+        reloader.apply(changed).await.unwrap();
+
+        assert!(matches!(
+            analyst_rx.try_recv(),
+            Ok(AnalystCommand::UpdateConfig(_))
+        ));
+        assert!(risk_rx.try_recv().is_err());
+    }
+
+    #[tokio::test]
+    async fn test_apply_dispatches_every_reloadable_risk_field() {
+        type Edit = fn(&mut Config);
+        let base = Config::from_env().unwrap();
+        let edits: Vec<(&str, Edit)> = vec![
+            ("max_daily_loss_pct", |c| c.max_daily_loss_pct += dec!(0.01)),
+            ("max_sector_exposure_pct", |c| {
+                c.max_sector_exposure_pct -= dec!(0.01)
+            }),
+            ("max_gross_exposure_pct", |c| {
+                c.max_gross_exposure_pct += dec!(0.5)
+            }),
+            ("pending_order_ttl_ms", |c| {
+                c.pending_order_ttl_ms = Some(c.pending_order_ttl_ms.unwrap_or(0) + 1)
+            }),
+            ("allow_average_down", |c| {
+                c.allow_average_down = !c.allow_average_down
+            }),
+            ("max_average_down_loss_pct", |c| {
+                c.max_average_down_loss_pct += dec!(0.01)
+            }),
+            ("min_order_notional", |c| c.min_order_notional += dec!(1)),
+            ("allow_min_notional_bump", |c| {
+                c.allow_min_notional_bump = !c.allow_min_notional_bump
+            }),
+            ("daily_reset", |c| {
+                c.daily_reset = Some(match c.daily_reset {
+                    Some(b) if b == TradingDayBoundary::utc_midnight() => {
+                        TradingDayBoundary::us_equities()
+                    }
+                    _ => TradingDayBoundary::utc_midnight(),
+                })
+            }),
+            ("allow_shorts", |c| c.allow_shorts = !c.allow_shorts),
+            ("leverage", |c| c.leverage += dec!(1)),
+            ("max_open_orders", |c| c.max_open_orders += 1),
+            ("earnings_blackout_days", |c| c.earnings_blackout_days += 1),
+            ("earnings_flatten", |c| {
+                c.earnings_flatten = !c.earnings_flatten
+            }),
+        ];
+
+        for (field, edit) in edits {
+            let (analyst_tx, _analyst_rx) = mpsc::channel(4);
+            let (risk_tx, mut risk_rx) = mpsc::channel(4);
+            let mut reloader = reloader_for(&base, analyst_tx, risk_tx);
+
+            let mut changed = base.clone();
+            edit(&mut changed);
+            reloader.apply(changed).await.unwrap();
+
+            assert!(
+                matches!(risk_rx.try_recv(), Ok(RiskCommand::UpdateConfig(_))),
+                "Changing {} must dispatch a risk update",
+                field
+            );
+        }
+    }
+
+    #[test]
+    fn test_risk_changed_fields_covers_nested_configs() {
+        let old = RiskConfig::default();
+        let mut new = old.clone();
+        new.correlation_config.max_correlation_threshold = dec!(0.5);
+        new.volatility_config.lookback_period += 1;
+        let changed = risk_changed_fields(&old, &new);
+        assert_eq!(changed.len(), 2);
+        assert!(changed[0].starts_with("correlation_config"));
+        assert!(changed[1].starts_with("volatility_config"));
+    }
+
+    #[tokio::test]
+    async fn test_apply_rejects_invalid_config_before_dispatching() {
+        let (analyst_tx, mut analyst_rx) = mpsc::channel(4);
+        let (risk_tx, mut risk_rx) = mpsc::channel(4);
+        let base = Config::from_env().unwrap();
+        let mut reloader = reloader_for(&base, analyst_tx, risk_tx);
+
+        let mut changed = base;
+        changed.fast_sma_period += 1;
+        changed.max_daily_loss_pct = dec!(0.9);
+        assert!(reloader.apply(changed).await.is_err());
+
+        assert!(
+            analyst_rx.try_recv().is_err(),
+            "Analyst settings must not be applied when the risk settings are invalid"
+        );
+        assert!(risk_rx.try_recv().is_err());
+    }
+
+    #[tokio::test]
+    async fn test_apply_rejects_invalid_analyst_config() {
+        let (analyst_tx, mut analyst_rx) = mpsc::channel(4);
+        let (risk_tx, mut risk_rx) = mpsc::channel(4);
+        let base = Config::from_env().unwrap();
+        let mut reloader = reloader_for(&base, analyst_tx, risk_tx);
+
+        let mut changed = base;
+        changed.fast_sma_period = changed.slow_sma_period;
+        changed.max_daily_loss_pct += dec!(0.01);
+        assert!(reloader.apply(changed).await.is_err());
+
+        assert!(analyst_rx.try_recv().is_err());
+        assert!(
+            risk_rx.try_recv().is_err(),
+            "Risk settings must not be applied when the analyst settings are invalid"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_apply_keeps_running_values_the_reload_did_not_change() {
+        let (analyst_tx, mut analyst_rx) = mpsc::channel(4);
+        let (risk_tx, mut risk_rx) = mpsc::channel(4);
+        let base = Config::from_env().unwrap();
+        let mut reloader = reloader_for(&base, analyst_tx, risk_tx);
+
+        // Values tuned at startup or edited in the UI since the last load
+        let tuned_slow = {
+            let mut analyst = reloader.running_analyst.write().await;
+            analyst.rsi_threshold = dec!(63);
+            analyst.slow_sma_period += 5;
+            analyst.slow_sma_period
+        };
+        reloader.running_risk.write().await.max_drawdown_pct = dec!(0.07);
+
+        let mut changed = base;
+        changed.fast_sma_period += 1;
+        changed.max_daily_loss_pct += dec!(0.01);
+        let expected_analyst = create_analyst_config(&changed);
+        let expected_risk = create_risk_config(&changed, None, None);
+        reloader.apply(changed).await.unwrap();
+
+        let Ok(AnalystCommand::UpdateConfig(analyst)) = analyst_rx.try_recv() else {
+            panic!("Expected an analyst update");
+        };
+        assert_eq!(analyst.fast_sma_period, expected_analyst.fast_sma_period);
+        assert_eq!(analyst.rsi_threshold, dec!(63));
+        assert_eq!(analyst.slow_sma_period, tuned_slow);
+
+        let Ok(RiskCommand::UpdateConfig(risk)) = risk_rx.try_recv() else {
+            panic!("Expected a risk update");
+        };
+        assert_eq!(risk.max_daily_loss_pct, expected_risk.max_daily_loss_pct);
+        assert_eq!(risk.max_drawdown_pct, dec!(0.07));
+    }
+}
@@ -4,6 +4,7 @@ use std::sync::Arc;
 use tokio::sync::{RwLock, broadcast, mpsc};
 use tracing::{error, info, warn};
 
+pub mod config_reload;
 pub mod shutdown_service;
 
 use crate::application::bootstrap::{
@@ -21,7 +22,7 @@ use crate::application::{
     risk_management::commands::RiskCommand,
     system::shutdown_service::ShutdownService, // Import ShutdownService
 };
-use crate::config::Config;
+use crate::config::{Config, Mode};
 use crate::infrastructure::observability::Metrics;
 
 use crate::domain::ports::{ExecutionService, MarketDataService};
@@ -46,6 +47,10 @@ pub struct SystemHandle {
     pub risk_appetite: Option<crate::domain::risk::risk_appetite::RiskAppetite>,
     pub metrics: Metrics,
     pub agent_registry: Arc<crate::application::monitoring::agent_status::AgentStatusRegistry>,
+    /// Default analyst config the engine is running with
+    pub analyst_config: Arc<RwLock<crate::application::agents::analyst_config::AnalystConfig>>,
+    /// Risk config the engine is running with
+    pub risk_config: Arc<RwLock<crate::domain::risk::risk_config::RiskConfig>>,
 }
 
 pub struct Application {
@@ -79,8 +84,15 @@ impl Application {
         info!("Building Rustrade Application (Mode: {:?})...", config.mode);
 
         // 1. Initialize Shared State
-        let initial_portfolio = Portfolio::new();
-        // initial_portfolio.cash = config.initial_cash; // Removed dangerous default
+        // Real brokers fund the portfolio on sync; the mock broker needs explicit capital.
+        let mut initial_portfolio = Portfolio::new();
+        initial_portfolio.lot_tracking = config.lot_tracking_enabled;
+        initial_portfolio.allow_shorts = config.allow_shorts;
+        if matches!(config.mode, Mode::Mock) {
+            let initial_cash = config.mock_initial_cash()?;
+            initial_portfolio.reset(initial_cash);
+            info!("Mock portfolio seeded with {} initial cash", initial_cash);
+        }
         let portfolio = Arc::new(RwLock::new(initial_portfolio));
 
         // 1. Initialize Metrics & Persistence
@@ -175,14 +187,17 @@ impl Application {
                 liquidation_timeout_ms: 10000,
             };
 
-        let shutdown_service = Arc::new(ShutdownService::new(
-            self.execution_service.clone(),
-            self.risk_state_repository.clone(),
-            self.portfolio.clone(),
-            self.market_service.clone(),
-            self.spread_cache.clone(),
-            shutdown_config,
-        ));
+        let shutdown_service = Arc::new(
+            ShutdownService::new(
+                self.execution_service.clone(),
+                self.risk_state_repository.clone(),
+                self.portfolio.clone(),
+                self.market_service.clone(),
+                self.spread_cache.clone(),
+                shutdown_config,
+            )
+            .with_candle_write_buffer(agents.candle_write_buffer.clone()),
+        );
 
         let service_clone = shutdown_service.clone();
         tokio::spawn(async move {
@@ -199,6 +214,16 @@ impl Application {
             }
         });
 
+        // Live config reload: `kill -HUP <pid>` re-reads .env and pushes changes to agents
+        config_reload::ConfigReloader::new(
+            self.config.clone(),
+            agents.analyst_cmd_tx.clone(),
+            agents.risk_cmd_tx.clone(),
+            agents.analyst_config.clone(),
+            agents.risk_config.clone(),
+        )
+        .spawn_sighup_handler();
+
         Ok(SystemHandle {
             sentinel_cmd_tx: agents.sentinel_cmd_tx,
             risk_cmd_tx: agents.risk_cmd_tx,
@@ -213,6 +238,8 @@ impl Application {
             risk_appetite: self.config.risk_appetite,
             metrics: self.metrics.clone(),
             agent_registry: self.agent_registry.clone(),
+            analyst_config: agents.analyst_config,
+            risk_config: agents.risk_config,
         })
     }
 }
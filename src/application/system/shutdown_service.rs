@@ -1,3 +1,4 @@
+use crate::application::market_data::candle_write_buffer::CandleWriteBuffer;
 use crate::application::market_data::spread_cache::SpreadCache;
 use crate::application::monitoring::portfolio_state_manager::PortfolioStateManager;
 use crate::application::risk_management::liquidation_service::LiquidationService;
@@ -31,6 +32,7 @@ pub struct ShutdownService {
     market_service: Arc<dyn MarketDataService>,
     spread_cache: Arc<SpreadCache>,
     config: EmergencyShutdownConfig,
+    candle_write_buffer: Option<Arc<CandleWriteBuffer>>,
 }
 
 impl ShutdownService {
@@ -49,9 +51,16 @@ impl ShutdownService {
             market_service,
             spread_cache,
             config,
+            candle_write_buffer: None,
         }
     }
 
+    /// Write candles still queued for persistence during shutdown
+    pub fn with_candle_write_buffer(mut self, buffer: Arc<CandleWriteBuffer>) -> Self {
+        self.candle_write_buffer = Some(buffer);
+        self
+    }
+
     pub async fn shutdown(&self) {
         info!("Initiating Graceful Shutdown Sequence...");
 
@@ -71,15 +80,24 @@ impl ShutdownService {
             info!("All orders cancelled successfully.");
         }
 
-        // 3. Save Risk State
-        info!("Step 2: Saving Risk State...");
+        // 3. Flush buffered candles
+        if let Some(buffer) = &self.candle_write_buffer {
+            info!(
+                "Step 2: Writing {} buffered candles...",
+                buffer.pending_len()
+            );
+            buffer.flush().await;
+        }
+
+        // 4. Save Risk State
+        info!("Step 3: Saving Risk State...");
         // Assuming RiskStateRepository has a way to save current state or it's done periodically.
         // If the repository is file-based/DB, ensuring flush might be needed.
         // For now, we just log, as most repos save on update.
         // If we had a explicit `save()` or `flush()`, we'd call it here.
 
-        // 4. Save Portfolio State
-        info!("Step 3: Saving Portfolio State...");
+        // 5. Save Portfolio State
+        info!("Step 4: Saving Portfolio State...");
         let _portfolio = self.portfolio.read().await;
         // implementation depends on how portfolio is persisted.
         // If it's via events, we might need to flush event bus.
@@ -1,3 +1,4 @@
+use crate::application::market_data::htf_trend::{HTF_TREND_SMA_PERIOD, HigherTimeframeTrend};
 use crate::application::market_data::signal_generator::SignalGenerator;
 use crate::application::monitoring::feature_engineering_service::TechnicalFeatureEngineeringService;
 use crate::application::optimization::expectancy_evaluator::MarketExpectancyEvaluator;
@@ -6,11 +7,16 @@ use crate::application::risk_management::position_manager::PositionManager;
 use crate::application::strategies::TradingStrategy;
 use crate::domain::market::market_regime::MarketRegimeDetector;
 use crate::domain::ports::{ExpectancyEvaluator, FeatureEngineeringService};
-use crate::domain::trading::types::{Candle, FeatureSet};
+use crate::domain::risk::volatility_manager::{VolatilityConfig, VolatilityManager};
+use crate::domain::trading::time::secs_to_ms;
+use crate::domain::trading::types::{Candle, FeatureRequirements, FeatureSet};
 use rust_decimal::Decimal;
 use std::collections::{HashMap, VecDeque};
 use std::sync::Arc;
 
+/// ATR samples averaged as the volatility baseline for trailing stops
+const STOP_VOLATILITY_LOOKBACK: usize = 100;
+
 /// Per-symbol trading context managing state, indicators, and strategy.
 ///
 /// This is a domain entity representing all the state needed to analyze
@@ -22,6 +28,8 @@ use std::sync::Arc;
 /// - Multi-timeframe analysis
 pub struct SymbolContext {
     pub feature_service: Box<dyn FeatureEngineeringService>,
+    /// Optional indicator groups `feature_service` computes
+    pub feature_requirements: FeatureRequirements,
     pub signal_generator: SignalGenerator,
     pub position_manager: PositionManager,
     pub strategy: Arc<dyn TradingStrategy>,
@@ -30,18 +38,36 @@ pub struct SymbolContext {
     pub regime_detector: MarketRegimeDetector,
     pub expectancy_evaluator: Box<dyn ExpectancyEvaluator>,
     pub taken_profit: bool,
+    /// Held quantity as of the last candle (negative = short)
+    pub position_quantity: Decimal,
     pub last_entry_time: Option<i64>,
+    /// Time (ms) of the last news-driven panic sell; blocks re-entry during the cooldown.
+    pub last_news_exit_time: Option<i64>,
     pub min_hold_time_ms: i64,
     pub active_strategy_mode: crate::domain::market::strategy_config::StrategyMode,
     pub last_macd_histogram: Option<Decimal>,
     pub cached_reward_risk_ratio: Decimal,
     pub warmup_succeeded: bool,
+    /// Set after a structural config change; new entries are gated until re-warmup succeeds.
+    pub rewarm_pending: bool,
+    /// Failed re-warmup attempts in a row, for the retry backoff
+    pub rewarm_failures: u32,
+    /// Earliest time (ms) of the next re-warmup attempt
+    pub rewarm_retry_at: i64,
+    /// A re-warmup history fetch is running in the background
+    pub rewarm_in_flight: bool,
+    /// Bumped on every re-warmup request; results of superseded fetches are dropped
+    pub rewarm_generation: u64,
     pub candle_history: VecDeque<Candle>,
     // Multi-timeframe support
     pub timeframe_aggregator:
         crate::application::market_data::timeframe_aggregator::TimeframeAggregator,
     pub timeframe_features: HashMap<crate::domain::market::timeframe::Timeframe, FeatureSet>,
     pub enabled_timeframes: Vec<crate::domain::market::timeframe::Timeframe>,
+    /// Trend SMA slope on `config.trend_timeframe` (REQUIRE_HTF_CONFIRMATION)
+    pub htf_trend: HigherTimeframeTrend,
+    /// ATR history for volatility-scaled trailing stops
+    pub stop_volatility: VolatilityManager,
     pub rsi_history: VecDeque<Decimal>,
     // Order Flow Imbalance (OFI) state
     pub ofi_value: Decimal,
@@ -68,30 +94,47 @@ impl SymbolContext {
         win_rate_provider: Arc<dyn WinRateProvider>,
         enabled_timeframes: Vec<crate::domain::market::timeframe::Timeframe>,
     ) -> Self {
-        let min_hold_time_ms = config.min_hold_time_minutes * 60 * 1000;
+        let min_hold_time_ms = secs_to_ms(config.min_hold_time_minutes * 60);
         use rust_decimal_macros::dec;
+        let mut signal_generator = SignalGenerator::new();
+        signal_generator.set_confirmation(Self::build_confirm_strategy(&config));
+        let feature_requirements = Self::required_features(&config, strategy.as_ref());
 
         Self {
-            feature_service: Box::new(TechnicalFeatureEngineeringService::new(&config)),
-            signal_generator: SignalGenerator::new(),
+            feature_service: Box::new(
+                TechnicalFeatureEngineeringService::new(&config)
+                    .with_requirements(feature_requirements),
+            ),
+            feature_requirements,
+            signal_generator,
             position_manager: PositionManager::new(),
             strategy,
             config: config.clone(),
             last_features: FeatureSet::default(),
-            regime_detector: MarketRegimeDetector::new(20, dec!(25.0), dec!(2.0)),
+            regime_detector: MarketRegimeDetector::new(20, dec!(25.0), dec!(2.0))
+                .with_method(config.regime_detection_method),
             expectancy_evaluator: Box::new(MarketExpectancyEvaluator::new(win_rate_provider)),
             taken_profit: false,
+            position_quantity: Decimal::ZERO,
             last_entry_time: None,
+            last_news_exit_time: None,
             min_hold_time_ms,
             active_strategy_mode: config.strategy_mode,
             last_macd_histogram: None,
             cached_reward_risk_ratio: dec!(2.0), // Default to 2:1
             warmup_succeeded: false,
-            candle_history: VecDeque::with_capacity(100),
+            rewarm_pending: false,
+            rewarm_failures: 0,
+            rewarm_retry_at: 0,
+            rewarm_in_flight: false,
+            rewarm_generation: 0,
+            candle_history: VecDeque::with_capacity(config.candle_history_cap()),
             timeframe_aggregator:
                 crate::application::market_data::timeframe_aggregator::TimeframeAggregator::new(),
             timeframe_features: HashMap::new(),
             enabled_timeframes,
+            htf_trend: HigherTimeframeTrend::new(config.trend_timeframe, HTF_TREND_SMA_PERIOD),
+            stop_volatility: Self::new_stop_volatility(),
             rsi_history: VecDeque::with_capacity(100),
             // Initialize OFI state
             ofi_value: Decimal::ZERO,
@@ -106,22 +149,113 @@ impl SymbolContext {
         }
     }
 
+    /// Whether an order on `side` would open or add to a position rather than reduce it
+    pub fn is_entry(&self, side: crate::domain::trading::types::OrderSide) -> bool {
+        side.increases_exposure(self.position_quantity)
+    }
+
+    /// Returns true while new entries are blocked after a news-driven exit.
+    pub fn in_news_reentry_cooldown(&self, timestamp_ms: i64) -> bool {
+        let cooldown_ms = secs_to_ms(self.config.news_reentry_cooldown_seconds as i64);
+        self.last_news_exit_time
+            .is_some_and(|exit| timestamp_ms - exit < cooldown_ms)
+    }
+
+    /// Secondary strategy for primary + confirm mode (`confirm_strategy`)
+    pub fn build_confirm_strategy(
+        config: &crate::application::agents::analyst_config::AnalystConfig,
+    ) -> Option<Arc<dyn TradingStrategy>> {
+        config
+            .confirm_strategy
+            .map(|mode| crate::application::strategies::StrategyFactory::create(mode, config))
+    }
+
+    /// Indicator groups read by `strategy` and the confirmation strategy. Everything is
+    /// computed when the strategy can be swapped without a re-warm (regime-adaptive mode)
+    /// or when features are collected for ML training.
+    pub fn required_features(
+        config: &crate::application::agents::analyst_config::AnalystConfig,
+        strategy: &dyn TradingStrategy,
+    ) -> FeatureRequirements {
+        use crate::domain::market::strategy_config::StrategyMode;
+
+        if config.strategy_mode == StrategyMode::RegimeAdaptive || config.enable_ml_data_collection
+        {
+            return FeatureRequirements::ALL;
+        }
+        Self::build_confirm_strategy(config)
+            .map_or(FeatureRequirements::CORE, |s| s.required_features())
+            .union(strategy.required_features())
+    }
+
+    /// True if the feature service lacks indicators the current strategy reads
+    /// (e.g. after a strategy change); the context must then be re-warmed.
+    pub fn missing_features(&self) -> bool {
+        !self.feature_requirements.covers(&Self::required_features(
+            &self.config,
+            self.strategy.as_ref(),
+        ))
+    }
+
+    fn new_stop_volatility() -> VolatilityManager {
+        VolatilityManager::new(VolatilityConfig {
+            lookback_period: STOP_VOLATILITY_LOOKBACK,
+            ..VolatilityConfig::default()
+        })
+    }
+
+    /// Trailing-stop ATR multiplier adjusted for the volatility regime: wider while ATR runs
+    /// above its recent average, tighter when calm, within `trailing_stop_vol_scale_min/max`.
+    pub fn trailing_stop_multiplier(&self) -> Decimal {
+        let base = self.config.trailing_stop_atr_multiplier;
+        match self.last_features.atr {
+            Some(atr) => {
+                base * self.stop_volatility.calculate_stop_multiplier(
+                    atr,
+                    self.config.trailing_stop_vol_scale_min,
+                    self.config.trailing_stop_vol_scale_max,
+                )
+            }
+            None => base,
+        }
+    }
+
+    /// Discard all indicator state and rebuild the feature service from the
+    /// current config. The caller is expected to re-warm the context afterwards.
+    pub fn reset_indicators(&mut self) {
+        self.feature_requirements = Self::required_features(&self.config, self.strategy.as_ref());
+        self.feature_service = Box::new(
+            TechnicalFeatureEngineeringService::new(&self.config)
+                .with_requirements(self.feature_requirements),
+        );
+        self.last_features = FeatureSet::default();
+        self.last_macd_histogram = None;
+        self.candle_history.clear();
+        self.rsi_history.clear();
+        self.timeframe_features.clear();
+        self.htf_trend =
+            HigherTimeframeTrend::new(self.config.trend_timeframe, HTF_TREND_SMA_PERIOD);
+        self.stop_volatility = Self::new_stop_volatility();
+        self.ofi_history.clear();
+        self.warmup_succeeded = false;
+    }
+
     /// Update the context with a new candle.
     ///
     /// This updates:
-    /// - Candle history (maintains last 100 candles)
+    /// - Candle history (maintains the last `config.candle_history_cap()` candles)
     /// - MACD histogram tracking
     /// - Technical features via feature service
     pub fn update(&mut self, candle: &Candle) {
-        // Update candle history (maintain 100-candle limit)
-        if self.candle_history.len() >= 100 {
-            self.candle_history.pop_front();
-        }
-        self.candle_history.push_back(candle.clone());
+        self.push_candle(candle);
 
         // Store previous MACD histogram before updating features
         self.last_macd_histogram = self.last_features.macd_hist;
         self.last_features = self.feature_service.update(candle);
+        self.htf_trend.update(candle);
+        if let Some(atr) = self.last_features.atr {
+            self.stop_volatility.update(atr);
+        }
 
         // Update RSI history
         if let Some(rsi) = self.last_features.rsi {
@@ -131,6 +265,33 @@ impl SymbolContext {
             self.rsi_history.push_back(rsi);
         }
 
+        self.update_order_flow();
+    }
+
+    /// Bulk equivalent of `update` for the start of a warmup window. The feature service
+    /// is primed in one pass without building per-candle features, so `last_features`,
+    /// the RSI history and the ATR baseline are left to the `update` calls that follow;
+    /// replaying the last 100 candles through `update` fills them as if every candle had.
+    pub fn prime(&mut self, candles: &[Candle]) {
+        self.feature_service.prime(candles);
+        for candle in candles {
+            self.push_candle(candle);
+            self.htf_trend.update(candle);
+            self.update_order_flow();
+        }
+    }
+
+    fn push_candle(&mut self, candle: &Candle) {
+        // Update candle history (a config update may have lowered the cap)
+        let cap = self.config.candle_history_cap().max(1);
+        while self.candle_history.len() >= cap {
+            self.candle_history.pop_front();
+        }
+        self.candle_history.push_back(candle.clone());
+    }
+
+    /// OFI, cumulative delta and volume profile from the candle history
+    fn update_order_flow(&mut self) {
         // Calculate Order Flow Imbalance (OFI)
         let ofi = crate::domain::market::order_flow::calculate_ofi(&self.candle_history);
         self.ofi_value = ofi.value;
@@ -202,13 +363,14 @@ mod tests {
         assert_eq!(context.cached_reward_risk_ratio, dec!(2.0));
         assert_eq!(
             context.min_hold_time_ms,
-            config.min_hold_time_minutes * 60 * 1000
+            secs_to_ms(config.min_hold_time_minutes * 60)
         );
     }
 
     #[test]
     fn test_candle_history_management() {
-        let config = create_test_config();
+        let mut config = create_test_config();
+        config.candle_history_len = 100;
         let strategy = StrategyFactory::create(StrategyMode::Advanced, &config);
         let win_rate_provider = Arc::new(StaticWinRateProvider::new(0.5));
         let timeframes = vec![crate::domain::market::timeframe::Timeframe::OneMin];
@@ -229,6 +391,34 @@ mod tests {
         assert_eq!(context.candle_history.back().unwrap().timestamp, 149);
     }
 
+    #[test]
+    fn test_candle_history_auto_cap_follows_strategy_lookback() {
+        let mut config = create_test_config();
+        config.candle_history_len = 0;
+        config.orderflow_volume_profile_lookback = 30;
+        config.smc_ob_lookback = 40;
+        config.zscore_lookback = 20;
+        config.breakout_lookback = 10;
+        config.stat_momentum_lookback = 10;
+        assert_eq!(config.max_candle_lookback(), 40);
+
+        let strategy = StrategyFactory::create(StrategyMode::Advanced, &config);
+        let win_rate_provider = Arc::new(StaticWinRateProvider::new(0.5));
+        let timeframes = vec![crate::domain::market::timeframe::Timeframe::OneMin];
+        let mut context = SymbolContext::new(config, strategy, win_rate_provider, timeframes);
+
+        for i in 0..150 {
+            context.update(&create_test_candle("BTC/USD", 50000.0 + i as f64, i));
+        }
+        assert_eq!(context.candle_history.len(), 80);
+
+        // Lowering the cap at runtime trims the history on the next candle
+        context.config.candle_history_len = 10;
+        context.update(&create_test_candle("BTC/USD", 50150.0, 150));
+        assert_eq!(context.candle_history.len(), 10);
+        assert_eq!(context.candle_history.back().unwrap().timestamp, 150);
+    }
+
     #[test]
     fn test_macd_histogram_tracking() {
         let config = create_test_config();
@@ -261,6 +451,110 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_reset_indicators_after_structural_change() {
+        let config = create_test_config();
+        let strategy = StrategyFactory::create(StrategyMode::Advanced, &config);
+        let win_rate_provider = Arc::new(StaticWinRateProvider::new(0.5));
+        let timeframes = vec![crate::domain::market::timeframe::Timeframe::OneMin];
+
+        let mut context =
+            SymbolContext::new(config.clone(), strategy, win_rate_provider, timeframes);
+        for i in 0..50 {
+            context.update(&create_test_candle("BTC/USD", 50000.0 + i as f64, i));
+        }
+        context.warmup_succeeded = true;
+        assert!(context.last_features.rsi.is_some());
+
+        let mut new_config = config.clone();
+        new_config.rsi_threshold += rust_decimal_macros::dec!(1.0);
+        assert!(!config.has_structural_change(&new_config));
+        new_config.rsi_period = 21;
+        assert!(config.has_structural_change(&new_config));
+
+        context.config = new_config;
+        context.reset_indicators();
+
+        assert!(context.candle_history.is_empty());
+        assert!(context.rsi_history.is_empty());
+        assert!(context.last_features.rsi.is_none());
+        assert!(!context.warmup_succeeded);
+    }
+
+    #[test]
+    fn test_feature_requirements_follow_strategy() {
+        let config = crate::application::agents::analyst_config::AnalystConfig {
+            strategy_mode: StrategyMode::Standard,
+            enable_ml_data_collection: false,
+            ..create_test_config()
+        };
+        let strategy = StrategyFactory::create(StrategyMode::Standard, &config);
+        let win_rate_provider = Arc::new(StaticWinRateProvider::new(0.5));
+        let mut context = SymbolContext::new(config.clone(), strategy, win_rate_provider, vec![]);
+
+        // DualSMA reads no MACD / ADX / Bollinger
+        assert_eq!(context.feature_requirements, FeatureRequirements::CORE);
+        for i in 0..60 {
+            context.update(&create_test_candle("BTC/USD", 50000.0 + i as f64, i));
+        }
+        assert!(context.last_features.sma_20.is_some());
+        assert!(context.last_features.macd_hist.is_none());
+        assert!(context.last_features.adx.is_none());
+
+        // Switching to a strategy that needs more indicators requires a re-warm
+        context.strategy = StrategyFactory::create(StrategyMode::Advanced, &config);
+        assert!(context.missing_features());
+        context.reset_indicators();
+        assert!(!context.missing_features());
+        assert!(context.feature_requirements.macd && context.feature_requirements.adx);
+
+        // Regime-adaptive swaps strategies on the fly: compute everything
+        let adaptive = crate::application::agents::analyst_config::AnalystConfig {
+            strategy_mode: StrategyMode::RegimeAdaptive,
+            ..config
+        };
+        let strategy = StrategyFactory::create(StrategyMode::Standard, &adaptive);
+        assert_eq!(
+            SymbolContext::required_features(&adaptive, strategy.as_ref()),
+            FeatureRequirements::ALL
+        );
+    }
+
+    #[test]
+    fn test_prime_then_update_matches_full_replay() {
+        let config = create_test_config();
+        let candles: Vec<Candle> = (0..500)
+            .map(|i| create_test_candle("BTC/USD", 50000.0 + (i as f64 * 0.2).sin() * 300.0, i))
+            .collect();
+        let new_context = || {
+            let strategy = StrategyFactory::create(StrategyMode::Advanced, &config);
+            let win_rate_provider = Arc::new(StaticWinRateProvider::new(0.5));
+            SymbolContext::new(config.clone(), strategy, win_rate_provider, vec![])
+        };
+
+        let mut replayed = new_context();
+        for candle in &candles {
+            replayed.update(candle);
+        }
+        let mut primed = new_context();
+        primed.prime(&candles[..400]);
+        for candle in &candles[400..] {
+            primed.update(candle);
+        }
+
+        assert_eq!(
+            serde_json::to_string(&primed.last_features).unwrap(),
+            serde_json::to_string(&replayed.last_features).unwrap()
+        );
+        assert_eq!(primed.rsi_history, replayed.rsi_history);
+        assert_eq!(primed.candle_history.len(), replayed.candle_history.len());
+        assert_eq!(
+            primed.cumulative_delta.value,
+            replayed.cumulative_delta.value
+        );
+        assert_eq!(primed.last_macd_histogram, replayed.last_macd_histogram);
+    }
+
     #[test]
     fn test_multi_timeframe_initialization() {
         let config = create_test_config();
@@ -1,19 +1,39 @@
+use chrono::{DateTime, NaiveTime, TimeDelta};
+use chrono_tz::America::New_York;
 use rust_decimal::Decimal;
 use tracing::info;
 
 use crate::application::monitoring::cost_evaluator::CostEvaluator;
 use crate::application::risk_management::position_manager::PositionManager;
+use crate::domain::trading::time::secs_to_ms;
 use crate::domain::trading::types::{OrderSide, TradeProposal};
 
 use crate::application::agents::analyst_config::AnalystConfig;
+use crate::infrastructure::observability::Metrics;
 
 pub struct TradeFilter {
     cost_evaluator: CostEvaluator,
+    metrics: Option<Metrics>,
 }
 
 impl TradeFilter {
     pub fn new(cost_evaluator: CostEvaluator) -> Self {
-        Self { cost_evaluator }
+        Self {
+            cost_evaluator,
+            metrics: None,
+        }
+    }
+
+    /// Count blocked signals per reason in `rustrade_rejections_total`
+    pub fn with_metrics(mut self, metrics: Metrics) -> Self {
+        self.metrics = Some(metrics);
+        self
+    }
+
+    fn count_rejection(&self, reason: &str) {
+        if let Some(metrics) = &self.metrics {
+            metrics.inc_rejections("filter", reason);
+        }
     }
 
     pub fn validate_signal(
@@ -25,12 +45,13 @@ impl TradeFilter {
         timestamp: i64,
         has_position: bool,
     ) -> bool {
-        // 1. Long-Only Check
-        if signal == OrderSide::Sell && !has_position {
+        // 1. Long-Only Check (a sell while flat opens a short when ALLOW_SHORTS is set)
+        if signal == OrderSide::Sell && !has_position && !config.allow_shorts {
             info!(
                 "TradeFilter: BLOCKING Sell for {} - No position (Long-Only)",
                 symbol
             );
+            self.count_rejection("long_only");
             return false;
         }
 
@@ -42,16 +63,74 @@ impl TradeFilter {
                 "TradeFilter: Signal {:?} for {} BLOCKED - Pending Order exists",
                 signal, symbol
             );
+            self.count_rejection("pending_order");
             return false;
         }
 
         // 3. Cooldown Check
-        let cooldown_ms = config.order_cooldown_seconds * 1000;
-        if timestamp - position_manager.last_signal_time < cooldown_ms as i64 {
+        let cooldown_ms = secs_to_ms(config.order_cooldown_seconds as i64);
+        if timestamp - position_manager.last_signal_time < cooldown_ms {
             // validating silent reject for cooldown
+            self.count_rejection("cooldown");
+            return false;
+        }
+
+        true
+    }
+
+    /// Block a new entry once the symbol used up its `max_trades_per_symbol_per_day`.
+    /// Only called for signals that would open a position; exits are never capped.
+    pub fn validate_daily_trade_cap(
+        &self,
+        symbol: &str,
+        position_manager: &PositionManager,
+        config: &AnalystConfig,
+        timestamp: i64,
+    ) -> bool {
+        let cap = config.max_trades_per_symbol_per_day;
+        if cap == 0 {
+            return true;
+        }
+        let day = config.trading_day_boundary.trading_day_at_ms(timestamp);
+        let entries = position_manager.entries_on(day);
+        if entries >= cap {
+            info!(
+                "TradeFilter: Entry BLOCKED for {} - daily trade cap reached ({}/{} on {})",
+                symbol, entries, cap, day
+            );
+            self.count_rejection("daily_trade_cap");
             return false;
         }
+        true
+    }
 
+    /// Block a new entry during the first `open_blackout_minutes` after the 09:30 New York
+    /// open. Only called for signals that would open a position; exits are never blocked.
+    pub fn validate_open_blackout(
+        &self,
+        symbol: &str,
+        config: &AnalystConfig,
+        timestamp: i64,
+    ) -> bool {
+        let minutes = config.open_blackout_minutes;
+        if minutes == 0 {
+            return true;
+        }
+        let local = DateTime::from_timestamp_millis(timestamp)
+            .unwrap_or_default()
+            .with_timezone(&New_York)
+            .time();
+        let open = NaiveTime::from_hms_opt(9, 30, 0).unwrap_or_default();
+        let until = open + TimeDelta::minutes(minutes as i64);
+        if local >= open && local < until {
+            info!(
+                "TradeFilter: Entry BLOCKED for {} - inside the open blackout (until {} New York)",
+                symbol,
+                until.format("%H:%M")
+            );
+            self.count_rejection("open_blackout");
+            return false;
+        }
         true
     }
 
@@ -74,6 +153,7 @@ impl TradeFilter {
                     "TradeFilter: Sell signal BLOCKED for {} - Min hold time not met ({} min remaining)",
                     symbol, remaining_minutes
                 );
+                self.count_rejection("min_hold_time");
                 return false;
             }
         }
@@ -87,6 +167,7 @@ impl TradeFilter {
                 "TradeFilter: Signal IGNORED for {} - Low Reward/Risk Ratio: {}",
                 symbol, reward_risk_ratio
             );
+            self.count_rejection("expectancy");
             return false;
         }
         true
@@ -106,6 +187,7 @@ impl TradeFilter {
                 "TradeFilter [{}]: REJECTED - Negative Expectancy after costs (Expected Profit: ${} < Costs: ${})",
                 symbol, expected_profit, estimated_cost
             );
+            self.count_rejection("profitability");
             return false;
         }
 
@@ -122,6 +204,7 @@ impl TradeFilter {
                 "TradeFilter [{}]: REJECTED by cost filter - Profit/Cost ratio {} < {} threshold (Expected Profit: ${}, Total Costs: ${})",
                 symbol, ratio, min_profit_ratio, expected_profit, costs.total_cost
             );
+            self.count_rejection("profitability");
             return false;
         }
 
@@ -160,3 +243,62 @@ impl TradeFilter {
         self.cost_evaluator.evaluate(proposal)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::trading::fee_model::ConstantFeeModel;
+    use std::sync::Arc;
+
+    #[test]
+    fn test_daily_trade_cap_resets_at_session_boundary() {
+        let filter = TradeFilter::new(CostEvaluator::new(
+            Arc::new(ConstantFeeModel::new(Decimal::ZERO, Decimal::ZERO)),
+            Decimal::ZERO,
+        ));
+        let mut config = AnalystConfig {
+            max_trades_per_symbol_per_day: 2,
+            ..AnalystConfig::default()
+        };
+        config.trading_day_boundary = "17:00@UTC".parse().unwrap();
+        let mut pm = PositionManager::new();
+        let hour = 3_600_000;
+        let morning = 1_700_006_400_000; // 2023-11-15 00:00 UTC, trading day of Nov 14
+
+        for _ in 0..2 {
+            assert!(filter.validate_daily_trade_cap("AAPL", &pm, &config, morning));
+            pm.record_entry(config.trading_day_boundary.trading_day_at_ms(morning));
+        }
+        assert!(!filter.validate_daily_trade_cap("AAPL", &pm, &config, morning));
+        // Still the same trading day until 17:00
+        assert!(!filter.validate_daily_trade_cap("AAPL", &pm, &config, morning + 16 * hour));
+        assert!(filter.validate_daily_trade_cap("AAPL", &pm, &config, morning + 17 * hour));
+
+        // 0 disables the cap
+        config.max_trades_per_symbol_per_day = 0;
+        assert!(filter.validate_daily_trade_cap("AAPL", &pm, &config, morning));
+    }
+
+    #[test]
+    fn test_open_blackout_window() {
+        let filter = TradeFilter::new(CostEvaluator::new(
+            Arc::new(ConstantFeeModel::new(Decimal::ZERO, Decimal::ZERO)),
+            Decimal::ZERO,
+        ));
+        let mut config = AnalystConfig {
+            open_blackout_minutes: 15,
+            ..AnalystConfig::default()
+        };
+        let minute = 60_000;
+        let open = 1_700_058_600_000; // 2023-11-15 14:30 UTC = 09:30 New York
+
+        assert!(filter.validate_open_blackout("AAPL", &config, open - minute));
+        assert!(!filter.validate_open_blackout("AAPL", &config, open));
+        assert!(!filter.validate_open_blackout("AAPL", &config, open + 14 * minute));
+        assert!(filter.validate_open_blackout("AAPL", &config, open + 15 * minute));
+
+        // 0 disables the blackout
+        config.open_blackout_minutes = 0;
+        assert!(filter.validate_open_blackout("AAPL", &config, open));
+    }
+}
@@ -4,6 +4,13 @@ use clap::{Parser, Subcommand};
 use rustrade::application::agents::analyst_config::AnalystConfig;
 use rustrade::application::benchmarking::engine::BenchmarkEngine;
 
+/// Risk profiles compared by `profiles`, with the score used when stored params carry none
+const PROFILE_SCORES: [(RiskProfile, u8); 3] = [
+    (RiskProfile::Conservative, 2),
+    (RiskProfile::Balanced, 5),
+    (RiskProfile::Aggressive, 8),
+];
+
 /// One benchmark window: (label, start_dt, end_dt).
 type PeriodWindow = (String, DateTime<Utc>, DateTime<Utc>);
 
@@ -66,11 +73,13 @@ fn parse_periods(s: &str, is_crypto: bool) -> anyhow::Result<Vec<PeriodWindow>>
     }
     Ok(out)
 }
-use rustrade::application::benchmarking::reporting::{BenchmarkReporter, convert_backtest_result};
+use rustrade::application::benchmarking::reporting::{
+    BenchmarkReporter, ProfileComparisonRow, convert_backtest_result,
+};
 use rustrade::application::optimization::optimizer::OptimizationResult;
 use rustrade::config::StrategyMode;
 use rustrade::domain::risk::optimal_parameters::{AssetType, OptimalParameters};
-use rustrade::domain::risk::risk_appetite::RiskAppetite;
+use rustrade::domain::risk::risk_appetite::{RiskAppetite, RiskProfile};
 use rustrade::infrastructure::optimal_parameters_persistence::OptimalParametersPersistence;
 use std::str::FromStr;
 
@@ -129,6 +138,29 @@ enum Commands {
         #[arg(long)]
         risk_levels: Option<String>,
     },
+    /// Backtest the best stored params of each risk profile (~/.rustrade) on a holdout period
+    /// and compare their out-of-sample Sharpe, return and drawdown
+    Profiles {
+        /// Symbol(s) to benchmark (comma separated)
+        #[arg(short, long, default_value = "TSLA,NVDA,AAPL")]
+        symbols: String,
+
+        /// Holdout start date (YYYY-MM-DD). Default: --days before the end
+        #[arg(long)]
+        start: Option<String>,
+
+        /// Holdout end date (YYYY-MM-DD). Default: today
+        #[arg(long)]
+        end: Option<String>,
+
+        /// Holdout length in days when --start is not set
+        #[arg(short, long, default_value = "30")]
+        days: i64,
+
+        /// Asset class (stock or crypto)
+        #[arg(long, default_value = "stock")]
+        asset_class: String,
+    },
     /// Matrix benchmark (Parameter Grid Search)
     Matrix {
         /// Symbol to test
@@ -237,8 +269,9 @@ async fn main() -> anyhow::Result<()> {
                     println!("{}", "=".repeat(80));
                     for (period_label, start_p, end_p) in &period_list {
                         for score in &risk_list {
+                            let profile = RiskAppetite::new(*score)?.profile();
                             let params = persist
-                                .get_for_risk_score(*score, asset_type)
+                                .get_best(asset_type, profile, Some(*score))
                                 .ok()
                                 .flatten()
                                 .context(format!(
@@ -445,6 +478,95 @@ async fn main() -> anyhow::Result<()> {
                 .unwrap_or_else(|| format!("Run {} {}", strategy, start));
             reporter.generate_report(&results, &report_label);
         }
+        Commands::Profiles {
+            symbols,
+            start,
+            end,
+            days,
+            asset_class,
+        } => {
+            unsafe {
+                std::env::set_var("ASSET_CLASS", &asset_class);
+            }
+            let is_crypto = asset_class.to_lowercase() == "crypto";
+            let asset_type = if is_crypto {
+                AssetType::Crypto
+            } else {
+                AssetType::Stock
+            };
+            let symbol_list: Vec<String> = symbols
+                .split(',')
+                .map(|s| s.trim().to_string())
+                .filter(|s| !s.is_empty())
+                .map(|s| {
+                    if is_crypto && !s.contains('/') {
+                        rustrade::domain::trading::types::normalize_crypto_symbol(&s).unwrap_or(s)
+                    } else {
+                        s
+                    }
+                })
+                .collect();
+
+            let (start_h, start_m, start_s) = if is_crypto { (0, 0, 0) } else { (14, 30, 0) };
+            let (end_h, end_m, end_s) = if is_crypto { (23, 59, 59) } else { (21, 0, 0) };
+            let end_date = match &end {
+                Some(e) => NaiveDate::parse_from_str(e, "%Y-%m-%d")?,
+                None => Utc::now().date_naive(),
+            };
+            let start_date = match &start {
+                Some(s) => NaiveDate::parse_from_str(s, "%Y-%m-%d")?,
+                None => end_date - chrono::Duration::days(days),
+            };
+            let start_dt =
+                Utc.from_utc_datetime(&start_date.and_hms_opt(start_h, start_m, start_s).unwrap());
+            let end_dt = Utc.from_utc_datetime(&end_date.and_hms_opt(end_h, end_m, end_s).unwrap());
+            let window = format!("{} to {}", start_date, end_date);
+
+            let persist = OptimalParametersPersistence::new()
+                .context("Failed to open ~/.rustrade (optimal_parameters.json)")?;
+
+            println!("{}", "=".repeat(80));
+            println!("🚀 RISK PROFILE BENCHMARK WITH OPTIMALS FROM ~/.rustrade");
+            println!("Symbols: {:?}", symbol_list);
+            println!("Holdout: {}", window);
+            println!("{}", "=".repeat(80));
+
+            let mut rows = Vec::new();
+            for (profile, default_score) in PROFILE_SCORES {
+                let Some(params) = persist.get_best(asset_type, profile, None)? else {
+                    println!(
+                        "⚠️ No stored {} params for {:?}. Run: cargo run --bin optimize -- run --risk-score {}",
+                        asset_type, profile, default_score
+                    );
+                    continue;
+                };
+                let score = params.risk_score.unwrap_or(default_score);
+                let config = optimal_params_to_analyst_config(&params, score)?;
+                println!("\n👉 {:?} (Risk-{})", profile, score);
+                let mut backtests = Vec::new();
+                for sym in &symbol_list {
+                    match engine
+                        .run_single_with_config(sym, start_dt, end_dt, config.clone())
+                        .await
+                    {
+                        Ok(res) => backtests.push(res),
+                        Err(e) => println!("❌ Error for {} ({:?}): {}", sym, profile, e),
+                    }
+                }
+                rows.push(ProfileComparisonRow::from_backtests(
+                    format!("{:?}", profile),
+                    score,
+                    format!(
+                        "{} @ {}",
+                        params.symbol_used,
+                        params.optimization_date.format("%Y-%m-%d")
+                    ),
+                    &backtests,
+                ));
+            }
+
+            reporter.print_profile_comparison(&rows, &window);
+        }
         Commands::Matrix { symbol: _ } => {
             println!("🔬 RUNNING EXPANDED MATRIX BENCHMARK");
 
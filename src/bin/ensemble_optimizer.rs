@@ -10,7 +10,6 @@ use rustrade::domain::ports::MarketDataService;
 use rustrade::domain::risk::risk_appetite::RiskAppetite;
 use rustrade::domain::trading::fee_model::ConstantFeeModel;
 use rustrade::domain::trading::portfolio::Portfolio;
-use rustrade::domain::trading::types::{Order, OrderSide, Trade};
 use rustrade::infrastructure::alpaca::AlpacaMarketDataService;
 use rustrade::infrastructure::mock::MockExecutionService;
 use std::collections::HashMap;
@@ -267,7 +266,7 @@ async fn run_simulation(
     }
 
     // Calculate Metrics
-    let trades = orders_to_trades(&result.trades);
+    let trades = result.round_trip_trades();
     let metrics = PerformanceMetrics::calculate_time_series_metrics(
         &trades,
         &result.daily_closes,
@@ -276,37 +275,3 @@ async fn run_simulation(
 
     Ok(Some(metrics))
 }
-
-fn orders_to_trades(orders: &[Order]) -> Vec<Trade> {
-    let mut trades = Vec::new();
-    let mut open_position: Option<&Order> = None;
-    for order in orders {
-        match order.side {
-            OrderSide::Buy => open_position = Some(order),
-            OrderSide::Sell => {
-                if let Some(buy) = open_position {
-                    let pnl = (order.price - buy.price) * order.quantity;
-                    trades.push(Trade {
-                        id: order.id.clone(),
-                        symbol: order.symbol.clone(),
-                        side: OrderSide::Buy,
-                        entry_price: buy.price,
-                        exit_price: Some(order.price),
-                        quantity: order.quantity,
-                        pnl,
-                        entry_timestamp: buy.timestamp,
-                        exit_timestamp: Some(order.timestamp),
-                        strategy_used: None,
-                        regime_detected: None,
-                        entry_reason: None,
-                        exit_reason: None,
-                        slippage: None,
-                        fees: rust_decimal::Decimal::ZERO,
-                    });
-                    open_position = None;
-                }
-            }
-        }
-    }
-    trades
-}
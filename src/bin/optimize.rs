@@ -8,11 +8,15 @@
 use anyhow::{Context, Result};
 use chrono::{NaiveDate, TimeZone, Utc};
 use clap::{Parser, Subcommand};
+use rust_decimal::Decimal;
 use rust_decimal_macros::dec;
 use rustrade::application::optimization::crypto_clusters::{default_clusters, resolve_clusters};
 use rustrade::application::optimization::engine::OptimizeEngine;
-use rustrade::application::optimization::optimizer::OptimizationResult;
 use rustrade::application::optimization::optimizer::ParameterGrid;
+use rustrade::application::optimization::optimizer::{
+    AdaptiveMutation, BasketAggregation, EarlyStopping, ObjectiveFunction, OptimizationResult,
+    PopulationSeeding, TradeCountPenalty,
+};
 use rustrade::application::optimization::reporting::OptimizeReporter;
 use rustrade::config::StrategyMode;
 use rustrade::domain::risk::optimal_parameters::{AssetType, OptimalParameters};
@@ -26,16 +30,39 @@ use tracing::info;
 struct Cli {
     #[command(subcommand)]
     command: Commands,
+
+    /// Ranking objective: blend, sharpe, calmar, min_drawdown, or return:<max drawdown %>.
+    /// Overrides OPTIMIZER_OBJECTIVE (default: blend)
+    #[arg(long, global = true)]
+    objective: Option<String>,
+
+    /// Penalize configs with fewer trades than this in the ranking and the genetic fitness.
+    /// Overrides OPTIMIZER_MIN_TRADES (default: 30)
+    #[arg(long, global = true)]
+    min_trades: Option<usize>,
+
+    /// Penalty subtracted from the objective score at zero trades, shrinking linearly to 0 at
+    /// --min-trades. Overrides OPTIMIZER_MIN_TRADES_PENALTY (default: 2.0)
+    #[arg(long, global = true)]
+    min_trades_penalty: Option<Decimal>,
 }
 
 #[derive(Subcommand)]
 enum Commands {
-    /// Run genetic optimization for a single symbol
+    /// Run genetic optimization for a single symbol (or a basket with --basket)
     Run {
         /// Symbol to optimize (e.g. TSLA, or BTC/USD for crypto)
         #[arg(short, long, default_value = "TSLA")]
         symbol: String,
 
+        /// Comma-separated basket (e.g. AAPL,MSFT,NVDA): score each config across all symbols. Overrides --symbol
+        #[arg(long)]
+        basket: Option<String>,
+
+        /// Basket score aggregation: mean or worst
+        #[arg(long, default_value = "mean")]
+        basket_aggregation: String,
+
         /// Asset type: stock or crypto (sets default session times; use with default symbol)
         #[arg(long, default_value = "stock")]
         asset_type: String,
@@ -76,6 +103,23 @@ enum Commands {
         #[arg(long)]
         mutation_rate: Option<f64>,
 
+        /// Genetic: stop after this many generations without improvement (default: run all generations)
+        #[arg(long)]
+        patience: Option<usize>,
+
+        /// Genetic: minimum objective-score gain that resets --patience
+        #[arg(long, default_value = "0.0")]
+        min_improvement: f64,
+
+        /// Genetic: adaptive mutation floor. When set (or --mutation-decay), the rate starts at
+        /// --mutation-rate (default 0.3) and decays toward this floor, resetting when diversity collapses
+        #[arg(long)]
+        mutation_floor: Option<f64>,
+
+        /// Genetic: per-generation mutation decay factor for adaptive mutation (default 0.9)
+        #[arg(long)]
+        mutation_decay: Option<f64>,
+
         /// Bar timeframe: 1Min (default), 5Min, 15Min. Coarser = fewer bars = much faster (e.g. 5Min ≈ 5× faster).
         #[arg(long, default_value = "1Min")]
         timeframe: String,
@@ -99,6 +143,11 @@ enum Commands {
         /// Risk appetite score (1-9) for optimization. When set, each evaluated config is adapted to this risk (sizing, stops, take-profit). Omit to use fixed params.
         #[arg(long)]
         risk_score: Option<u8>,
+
+        /// Genetic: share of the initial population seeded from ~/.rustrade/optimal_parameters.json
+        /// (same asset type); the rest stays random. 0 = fully random
+        #[arg(long, default_value = "0.0")]
+        seed_fraction: f64,
     },
     /// Run batch optimization for multiple symbols
     Batch {
@@ -173,6 +222,18 @@ enum Commands {
         generations: Option<usize>,
         #[arg(long)]
         mutation_rate: Option<f64>,
+        /// Stop after this many generations without improvement (default: run all generations)
+        #[arg(long)]
+        patience: Option<usize>,
+        /// Minimum objective-score gain that resets --patience
+        #[arg(long, default_value = "0.0")]
+        min_improvement: f64,
+        /// Adaptive mutation floor (enables adaptive mutation starting at --mutation-rate)
+        #[arg(long)]
+        mutation_floor: Option<f64>,
+        /// Adaptive mutation per-generation decay factor (default 0.9)
+        #[arg(long)]
+        mutation_decay: Option<f64>,
 
         /// Bar timeframe: 1Min, 5Min (default), 15Min
         #[arg(long, default_value = "5Min")]
@@ -193,6 +254,10 @@ enum Commands {
         /// Risk appetite score (1-9). When set, each evaluated config is adapted to this risk.
         #[arg(long)]
         risk_score: Option<u8>,
+
+        /// Share of the initial population seeded from saved crypto optima (0 = fully random)
+        #[arg(long, default_value = "0.0")]
+        seed_fraction: f64,
     },
 }
 
@@ -201,6 +266,8 @@ async fn run_optimize(
     engine: &OptimizeEngine,
     reporter: &OptimizeReporter,
     symbol: String,
+    basket: Option<String>,
+    basket_aggregation: BasketAggregation,
     asset_type: String,
     start: String,
     end: String,
@@ -209,18 +276,36 @@ async fn run_optimize(
     population: Option<usize>,
     generations: Option<usize>,
     mutation_rate: Option<f64>,
+    early_stopping: Option<EarlyStopping>,
+    adaptive_mutation: Option<AdaptiveMutation>,
     timeframe: String,
     output: String,
     top_n: usize,
     session_start: Option<String>,
     session_end: Option<String>,
     risk_score: Option<u8>,
+    seeding: Option<PopulationSeeding>,
 ) -> Result<()> {
     let strategy_mode = StrategyMode::from_str(&strategy).unwrap_or(StrategyMode::Advanced);
     let is_crypto = asset_type.to_lowercase().as_str() == "crypto";
-    let symbol = resolve_run_symbol(&symbol, is_crypto);
+    let symbols = match basket {
+        Some(basket) => basket
+            .split(',')
+            .map(|s| s.trim().to_string())
+            .filter(|s| !s.is_empty())
+            .collect(),
+        None => vec![resolve_run_symbol(&symbol, is_crypto)],
+    };
+    let symbol = symbols.join(",");
     let (session_start, session_end) =
         resolve_session_times(session_start.as_deref(), session_end.as_deref(), is_crypto);
+    if symbols.len() > 1 {
+        println!(
+            "Basket optimization over {} symbols ({:?} objective)",
+            symbols.len(),
+            basket_aggregation
+        );
+    }
 
     reporter.print_header(
         &symbol,
@@ -268,7 +353,8 @@ async fn run_optimize(
 
     let results = engine
         .run_genetic_optimization(
-            &symbol,
+            &symbols,
+            basket_aggregation,
             start_dt,
             end_dt,
             strategy_mode,
@@ -278,6 +364,9 @@ async fn run_optimize(
             mutation_rate,
             Some(timeframe),
             risk_score,
+            early_stopping,
+            adaptive_mutation,
+            seeding,
         )
         .await?;
 
@@ -336,11 +425,14 @@ async fn run_clusters(
     population: Option<usize>,
     generations: Option<usize>,
     mutation_rate: Option<f64>,
+    early_stopping: Option<EarlyStopping>,
+    adaptive_mutation: Option<AdaptiveMutation>,
     timeframe: String,
     cluster_ids: Vec<String>,
     top_n: usize,
     output_prefix: String,
     risk_score: Option<u8>,
+    seeding: Option<PopulationSeeding>,
 ) -> Result<()> {
     let strategy_mode = StrategyMode::from_str(&strategy).unwrap_or(StrategyMode::Advanced);
     let (session_start, session_end) = ("00:00:00".to_string(), "23:59:59".to_string());
@@ -388,7 +480,8 @@ async fn run_clusters(
 
         let results = engine
             .run_genetic_optimization(
-                &symbol,
+                std::slice::from_ref(&symbol),
+                BasketAggregation::Mean,
                 start_dt,
                 end_dt,
                 strategy_mode,
@@ -398,6 +491,9 @@ async fn run_clusters(
                 mutation_rate,
                 Some(timeframe.clone()),
                 risk_score,
+                early_stopping,
+                adaptive_mutation,
+                seeding.clone(),
             )
             .await?;
 
@@ -476,12 +572,40 @@ async fn main() -> Result<()> {
     tracing::subscriber::set_global_default(subscriber).ok();
 
     let cli = Cli::parse();
-    let engine = OptimizeEngine::new()?;
+    let mut engine = OptimizeEngine::new()?;
+    if let Some(objective) = &cli.objective {
+        engine = engine.with_objective(ObjectiveFunction::from_str(objective)?);
+    }
+    if engine.objective() != ObjectiveFunction::default() {
+        println!("Objective: {:?}", engine.objective());
+    }
+    let mut trade_penalty = engine.trade_penalty();
+    if let Some(min_trades) = cli.min_trades {
+        trade_penalty.min_trades = min_trades;
+    }
+    if let Some(strength) = cli.min_trades_penalty {
+        if strength < Decimal::ZERO {
+            anyhow::bail!(
+                "--min-trades-penalty must not be negative, got {}",
+                strength
+            );
+        }
+        trade_penalty.strength = strength;
+    }
+    if trade_penalty != TradeCountPenalty::default() {
+        println!(
+            "Trade-count penalty: {} below {} trades",
+            trade_penalty.strength, trade_penalty.min_trades
+        );
+    }
+    engine = engine.with_trade_penalty(trade_penalty);
     let reporter = OptimizeReporter::default();
 
     match cli.command {
         Commands::Run {
             symbol,
+            basket,
+            basket_aggregation,
             asset_type,
             start,
             end,
@@ -492,17 +616,28 @@ async fn main() -> Result<()> {
             population,
             generations,
             mutation_rate,
+            patience,
+            min_improvement,
+            mutation_floor,
+            mutation_decay,
             timeframe,
             output,
             top_n,
             session_start,
             session_end,
             risk_score,
+            seed_fraction,
         } => {
+            let seeding = population_seeding(
+                seed_fraction,
+                AssetType::from_str(&asset_type).unwrap_or(AssetType::Stock),
+            );
             run_optimize(
                 &engine,
                 &reporter,
                 symbol,
+                basket,
+                BasketAggregation::from_str(&basket_aggregation)?,
                 asset_type,
                 start,
                 end,
@@ -511,12 +646,15 @@ async fn main() -> Result<()> {
                 population,
                 generations,
                 mutation_rate,
+                patience.map(|p| EarlyStopping::new(p, min_improvement)),
+                adaptive_mutation(mutation_rate, mutation_floor, mutation_decay),
                 timeframe,
                 output,
                 top_n,
                 session_start,
                 session_end,
                 risk_score,
+                seeding,
             )
             .await?
         }
@@ -726,11 +864,16 @@ async fn main() -> Result<()> {
             population,
             generations,
             mutation_rate,
+            patience,
+            min_improvement,
+            mutation_floor,
+            mutation_decay,
             timeframe,
             clusters,
             top_n,
             output_prefix,
             risk_score,
+            seed_fraction,
         } => {
             run_clusters(
                 &engine,
@@ -742,11 +885,14 @@ async fn main() -> Result<()> {
                 population,
                 generations,
                 mutation_rate,
+                patience.map(|p| EarlyStopping::new(p, min_improvement)),
+                adaptive_mutation(mutation_rate, mutation_floor, mutation_decay),
                 timeframe,
                 clusters.unwrap_or_default(),
                 top_n,
                 output_prefix,
                 risk_score,
+                population_seeding(seed_fraction, AssetType::Crypto),
             )
             .await?;
         }
@@ -755,6 +901,55 @@ async fn main() -> Result<()> {
     Ok(())
 }
 
+/// Adaptive mutation schedule when --mutation-floor or --mutation-decay is given.
+fn adaptive_mutation(
+    mutation_rate: Option<f64>,
+    floor: Option<f64>,
+    decay: Option<f64>,
+) -> Option<AdaptiveMutation> {
+    if floor.is_none() && decay.is_none() {
+        return None;
+    }
+    let initial = mutation_rate.unwrap_or(0.3);
+    Some(AdaptiveMutation::new(
+        initial,
+        floor.unwrap_or(0.05).min(initial),
+        decay.unwrap_or(0.9),
+    ))
+}
+
+/// Saved optima of `asset` to seed `fraction` of the genetic population with, if any.
+fn population_seeding(fraction: f64, asset: AssetType) -> Option<PopulationSeeding> {
+    if fraction <= 0.0 {
+        return None;
+    }
+    let seeds: Vec<OptimalParameters> = OptimalParametersPersistence::new()
+        .and_then(|persistence| persistence.load())
+        .ok()
+        .flatten()
+        .map(|set| {
+            set.parameters
+                .into_iter()
+                .filter(|p| p.asset_type == asset)
+                .collect()
+        })
+        .unwrap_or_default();
+    if seeds.is_empty() {
+        println!(
+            "No saved {} optima to seed from, starting fully random\n",
+            asset
+        );
+        return None;
+    }
+    println!(
+        "Seeding {:.0}% of the population from {} saved {} optima\n",
+        fraction.clamp(0.0, 1.0) * 100.0,
+        seeds.len(),
+        asset
+    );
+    Some(PopulationSeeding::new(seeds, fraction))
+}
+
 /// Default symbol for run when asset is crypto and user kept stock default.
 fn resolve_run_symbol(symbol: &str, is_crypto: bool) -> String {
     if is_crypto && (symbol == "TSLA" || symbol == "AAPL") {
@@ -39,6 +39,8 @@ pub struct BinanceConfig {
     pub secret_key: String,
     pub base_url: String,
     pub ws_url: String,
+    /// Interval between exchangeInfo (symbol filter) refreshes
+    pub exchange_info_refresh_secs: u64,
 }
 
 impl BinanceConfig {
@@ -50,6 +52,10 @@ impl BinanceConfig {
                 .unwrap_or_else(|_| "https://api.binance.com".to_string()),
             ws_url: env::var("BINANCE_WS_URL")
                 .unwrap_or_else(|_| "wss://stream.binance.com:9443".to_string()),
+            exchange_info_refresh_secs: env::var("BINANCE_EXCHANGE_INFO_REFRESH_SECS")
+                .ok()
+                .and_then(|s| s.parse().ok())
+                .unwrap_or(3600),
         }
     }
 }
@@ -110,6 +116,7 @@ mod tests {
     fn test_binance_config_defaults() {
         let config = BinanceConfig::from_env();
         assert!(config.base_url.contains("binance.com"));
+        assert!(config.exchange_info_refresh_secs > 0);
     }
 
     #[test]
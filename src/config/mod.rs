@@ -4,12 +4,14 @@
 //! organized by domain: Broker, Strategy, Risk, and Observability.
 
 mod broker_config;
+mod news_config;
 mod observability_config;
 mod risk_env_config;
 mod simulation_config;
 mod strategy_config;
 
 pub use broker_config::{AlpacaConfig, BinanceConfig, BrokerEnvConfig, OandaConfig};
+pub use news_config::{NewsEnvConfig, NewsProviderKind};
 pub use observability_config::ObservabilityEnvConfig;
 pub use risk_env_config::RiskEnvConfig;
 pub use simulation_config::SimulationEnvConfig;
@@ -19,6 +21,7 @@ pub use strategy_config::StrategyEnvConfig;
 // Re-export StrategyMode for backward compatibility
 pub use crate::domain::market::strategy_config::StrategyMode;
 use crate::domain::market::timeframe::Timeframe;
+use crate::domain::market::trading_windows::TradingWindows;
 use crate::domain::risk::risk_appetite::RiskAppetite;
 use anyhow::{Context, Result};
 use rust_decimal::Decimal;
@@ -71,12 +74,153 @@ impl FromStr for AssetClass {
     }
 }
 
+impl From<AssetClass> for crate::domain::risk::optimal_parameters::AssetType {
+    fn from(asset_class: AssetClass) -> Self {
+        match asset_class {
+            AssetClass::Stock => Self::Stock,
+            AssetClass::Crypto => Self::Crypto,
+        }
+    }
+}
+
+/// What the Analyst does with an entry proposal when the RiskManager's channel is full.
+/// Exits are always parked and retried, whatever the policy.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ProposalChannelPolicy {
+    /// Drop the new proposal and log backpressure
+    #[default]
+    DropNewest,
+    /// Wait up to this many milliseconds for capacity, then drop
+    BlockWithTimeout(u64),
+    /// Park the proposal and retry later; a newer one for the same symbol replaces it
+    Coalesce,
+}
+
+impl ProposalChannelPolicy {
+    pub const DEFAULT_BLOCK_TIMEOUT_MS: u64 = 250;
+}
+
+impl FromStr for ProposalChannelPolicy {
+    type Err = anyhow::Error;
+
+    /// `drop_newest`, `coalesce`, `block_with_timeout` or `block_with_timeout:<ms>`
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.trim().to_lowercase();
+        let (name, arg) = match s.split_once(':') {
+            Some((name, arg)) => (name.trim(), Some(arg.trim())),
+            None => (s.as_str(), None),
+        };
+        match (name, arg) {
+            ("drop_newest", None) => Ok(Self::DropNewest),
+            ("coalesce", None) => Ok(Self::Coalesce),
+            ("block_with_timeout", None) => {
+                Ok(Self::BlockWithTimeout(Self::DEFAULT_BLOCK_TIMEOUT_MS))
+            }
+            ("block_with_timeout", Some(ms)) => {
+                Ok(Self::BlockWithTimeout(ms.parse().with_context(|| {
+                    format!("Invalid block timeout '{}'", ms)
+                })?))
+            }
+            _ => anyhow::bail!(
+                "Invalid PROPOSAL_CHANNEL_POLICY: {}. Must be 'drop_newest', 'coalesce', or 'block_with_timeout[:<ms>]'",
+                s
+            ),
+        }
+    }
+}
+
+/// Sizes of the event-bus channels wired up at startup.
+///
+/// Larger buffers absorb bursts (e.g. hundreds of symbols closing a bar at once) at the
+/// cost of memory: each slot holds one message, and a broadcast channel keeps a copy of
+/// every queued message until its slowest receiver catches up. Broadcast receivers that
+/// fall further behind than the capacity drop messages (`rustrade_channel_lagged_total`).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ChannelCapacities {
+    /// Market events from the Sentinel to the Analyst (MARKET_CHANNEL_CAPACITY)
+    pub market_channel_capacity: usize,
+    /// Trade proposals to the RiskManager (PROPOSAL_CHANNEL_CAPACITY)
+    pub proposal_channel_capacity: usize,
+    /// Orders between the RiskManager, throttler and executor (ORDER_CHANNEL_CAPACITY)
+    pub order_channel_capacity: usize,
+    /// Completed candles broadcast to the UI (CANDLE_CHANNEL_CAPACITY)
+    pub candle_channel_capacity: usize,
+    /// Sentiment updates broadcast to the UI (SENTIMENT_CHANNEL_CAPACITY)
+    pub sentiment_channel_capacity: usize,
+    /// News events broadcast to the UI (NEWS_CHANNEL_CAPACITY)
+    pub news_channel_capacity: usize,
+}
+
+impl Default for ChannelCapacities {
+    fn default() -> Self {
+        Self {
+            market_channel_capacity: 500,
+            proposal_channel_capacity: 100,
+            order_channel_capacity: 50,
+            candle_channel_capacity: 100,
+            sentiment_channel_capacity: 8,
+            news_channel_capacity: 20,
+        }
+    }
+}
+
+impl ChannelCapacities {
+    fn from_env() -> Self {
+        let defaults = Self::default();
+        // Channels cannot be created with a capacity of 0
+        let parse = |key: &str, default: usize| {
+            env::var(key)
+                .ok()
+                .and_then(|v| v.parse::<usize>().ok())
+                .filter(|&v| v > 0)
+                .unwrap_or(default)
+        };
+        Self {
+            market_channel_capacity: parse(
+                "MARKET_CHANNEL_CAPACITY",
+                defaults.market_channel_capacity,
+            ),
+            proposal_channel_capacity: parse(
+                "PROPOSAL_CHANNEL_CAPACITY",
+                defaults.proposal_channel_capacity,
+            ),
+            order_channel_capacity: parse(
+                "ORDER_CHANNEL_CAPACITY",
+                defaults.order_channel_capacity,
+            ),
+            candle_channel_capacity: parse(
+                "CANDLE_CHANNEL_CAPACITY",
+                defaults.candle_channel_capacity,
+            ),
+            sentiment_channel_capacity: parse(
+                "SENTIMENT_CHANNEL_CAPACITY",
+                defaults.sentiment_channel_capacity,
+            ),
+            news_channel_capacity: parse("NEWS_CHANNEL_CAPACITY", defaults.news_channel_capacity),
+        }
+    }
+}
+
 /// Main application configuration.
 #[derive(Debug, Clone)]
 pub struct Config {
     // Core
     pub mode: Mode,
     pub asset_class: AssetClass,
+    /// Secondary history provider for indicator warmup (WARMUP_FALLBACK_SOURCE)
+    pub warmup_fallback_source: Option<Mode>,
+    /// Symbols whose warmup found no history on any source take no new entries
+    pub require_warmup: bool,
+    /// Symbols whose warmup history is fetched concurrently (WARMUP_CONCURRENCY)
+    pub warmup_concurrency: usize,
+    /// Completed candles persisted per write (CANDLE_PERSIST_BATCH_SIZE, 1 = every candle)
+    pub candle_persist_batch_size: usize,
+    /// A partial candle batch is written once this old (CANDLE_PERSIST_FLUSH_MS)
+    pub candle_persist_flush_ms: i64,
+    /// Handling of proposals when the RiskManager channel is full (PROPOSAL_CHANNEL_POLICY)
+    pub proposal_channel_policy: ProposalChannelPolicy,
+    /// Event-bus channel sizes (*_CHANNEL_CAPACITY)
+    pub channel_capacities: ChannelCapacities,
 
     // ... (Broker fields)
     pub alpaca_api_key: String,
@@ -92,6 +236,7 @@ pub struct Config {
     pub binance_secret_key: String,
     pub binance_base_url: String,
     pub binance_ws_url: String,
+    pub binance_exchange_info_refresh_secs: u64,
 
     // ... (Strategy fields)
     pub fast_sma_period: usize,
@@ -108,11 +253,19 @@ pub struct Config {
     pub ema_fast_period: usize,
     pub ema_slow_period: usize,
     pub adx_period: usize,
+    pub hurst_lookback: usize,
     pub adx_threshold: Decimal,
     pub regime_volatility_threshold: Decimal,
+    pub regime_detection_method: crate::domain::market::market_regime::RegimeDetectionMethod,
     pub atr_period: usize,
     pub trailing_stop_atr_multiplier: Decimal,
+    pub trailing_stop_vol_scale_min: Decimal,
+    pub trailing_stop_vol_scale_max: Decimal,
+    pub candle_history_len: usize,
+    pub candle_gap_policy: crate::domain::market::strategy_config::CandleGapPolicy,
+    pub candle_gap_max_bars: usize,
     pub strategy_mode: StrategyMode,
+    pub confirm_strategy: Option<StrategyMode>,
     pub trend_divergence_threshold: Decimal,
     pub trend_tolerance_pct: Decimal,
     pub mean_reversion_rsi_exit: Decimal,
@@ -124,39 +277,101 @@ pub struct Config {
     pub enabled_timeframes: Vec<Timeframe>,
     pub trend_timeframe: Timeframe,
     pub signal_confirmation_bars: usize,
+    pub require_htf_confirmation: bool,
     pub take_profit_pct: Decimal,
+    pub take_profit_mode: crate::domain::market::strategy_config::TakeProfitMode,
+    pub take_profit_atr_multiplier: Decimal,
     pub profit_target_multiplier: Decimal,
+    pub relative_stop_benchmark: Option<String>,
+    pub relative_stop_pct: Decimal,
+    pub pyramid_max_adds: usize,
+    pub pyramid_trigger_pct: Decimal,
+    pub exits_bypass_cost_filters: bool,
+    pub allow_shorts: bool,
     pub ensemble_voting_threshold: Decimal,
 
     // ... (Risk fields)
     pub max_positions: usize,
     pub max_position_size_pct: Decimal,
     pub max_position_value_usd: Decimal,
+    pub news_trade_size_multiplier: Decimal,
+    pub news_trade_max_value_usd: Option<Decimal>,
     pub risk_per_trade_percent: Decimal,
     pub max_daily_loss_pct: Decimal,
     pub max_drawdown_pct: Decimal,
     pub consecutive_loss_limit: usize,
     pub pending_order_ttl_ms: Option<i64>,
+    pub daily_reset: Option<crate::domain::risk::session_boundary::TradingDayBoundary>,
+    pub allow_average_down: bool,
+    pub max_average_down_loss_pct: Decimal,
+    pub min_order_notional: Decimal,
+    pub allow_min_notional_bump: bool,
+    pub leverage: Decimal,
     pub max_sector_exposure_pct: Decimal,
+    pub max_gross_exposure_pct: Decimal,
     pub sector_map: HashMap<String, String>,
     pub non_pdt_mode: bool,
     pub max_orders_per_minute: u32,
+    pub max_open_orders: usize,
+    pub max_trades_per_symbol_per_day: u32,
+    pub open_blackout_minutes: u32,
+    pub post_stop_cooldown_seconds: u64,
+    pub order_submit_max_retries: u32,
+    pub order_submit_backoff_ms: u64,
+    pub order_submit_max_retry_ms: u64,
     pub order_cooldown_seconds: u64,
     pub min_hold_time_minutes: i64,
+    pub trading_windows_stock: TradingWindows,
+    pub trading_windows_crypto: TradingWindows,
+    pub earnings_blackout_days: u32,
+    pub earnings_flatten: bool,
+    pub finnhub_api_key: Option<String>,
     pub slippage_pct: Decimal,
     pub commission_per_share: Decimal,
     pub spread_bps: Decimal,
+    pub crypto_fee_tiers: Vec<crate::domain::trading::fee_model::FeeTier>,
+    pub symbol_cost_overrides:
+        HashMap<String, crate::domain::trading::fee_model::SymbolCostOverride>,
     pub min_profit_ratio: Decimal,
+    pub win_rate_provider: crate::domain::optimization::win_rate_source::WinRateSource,
+    pub win_rate_default: f64,
+    pub win_rate_min_trades: usize,
+    pub win_rate_prior_weight: f64,
+    pub win_rate_prior_alpha: f64,
+    pub win_rate_prior_beta: f64,
+    pub expectancy_lookback: usize,
+    pub expectancy_halflife: usize,
+    pub cold_start_reward_risk_ratio: Decimal,
+    pub cold_start_min_trades: usize,
     pub trade_quantity: Decimal,
     pub portfolio_staleness_ms: u64,
+    pub portfolio_max_age_ms: u64,
     pub portfolio_refresh_interval_ms: u64,
+    pub position_reconcile_interval_secs: u64,
+    pub rebalance_interval_minutes: u64,
+    pub rebalance_band_pct: Decimal,
+    pub position_reconcile_correct: bool,
+    pub lot_tracking_enabled: bool,
     pub dynamic_symbol_mode: bool,
     pub dynamic_scan_interval_minutes: u64,
+    pub scanner_sector_rotation: bool,
+    pub scanner_max_symbols: usize,
+    pub scanner_min_price: Decimal,
+    pub scanner_max_price: Option<Decimal>,
     pub symbols: Vec<String>,
     pub min_volume_threshold: Decimal,
     pub adaptive_optimization_enabled: bool,
     pub regime_detection_window: usize,
     pub adaptive_evaluation_hour: u32,
+    pub adaptive_lookback_days: u32,
+    pub adaptive_max_period_step_pct: f64,
+    pub adaptive_max_risk_step_pct: f64,
+    pub adaptive_rollback_sharpe_drop: f64,
+    pub adaptive_rollback_drawdown_rise: f64,
+    pub adaptive_shadow_days: u32,
+    pub adaptive_shadow_min_improvement_pct: Decimal,
+    /// Start from the stored optimal parameters for the risk score (implied by adaptive optimization)
+    pub load_optimal_parameters: bool,
     pub risk_appetite: Option<RiskAppetite>,
     pub enable_ml_data_collection: bool,
 
@@ -165,12 +380,24 @@ pub struct Config {
     pub simulation_latency_base_ms: u64,
     pub simulation_latency_jitter_ms: u64,
     pub simulation_slippage_volatility: Decimal,
+    pub backtest_order_throttle: bool,
     pub use_real_market_data: bool,
+    pub initial_cash: Decimal,
+
+    // News
+    pub news_provider: NewsProviderKind,
+    pub news_feed_url: Option<String>,
+    pub news_poll_interval_seconds: u64,
+    pub news_sentiment_threshold: f64,
+    pub news_provider_sentiment_threshold: f64,
+    pub news_dedup_window_seconds: u64,
+    pub news_reentry_cooldown_seconds: u64,
 
     // ... (Observability fields)
     pub observability_enabled: bool,
     pub observability_port: u16,
     pub observability_bind_address: String,
+    pub decision_log_path: Option<String>,
 }
 
 impl Config {
@@ -182,16 +409,59 @@ impl Config {
         let asset_class_str = env::var("ASSET_CLASS").unwrap_or_else(|_| "stock".to_string());
         let asset_class = AssetClass::from_str(&asset_class_str)?;
 
+        let warmup_fallback_source = env::var("WARMUP_FALLBACK_SOURCE")
+            .ok()
+            .filter(|s| !s.trim().is_empty())
+            .map(|s| Mode::from_str(s.trim()))
+            .transpose()
+            .context("Failed to parse WARMUP_FALLBACK_SOURCE")?;
+        let use_real_market_data = env::var("USE_REAL_MARKET_DATA")
+            .unwrap_or_else(|_| "false".to_string())
+            .parse()
+            .unwrap_or(false);
+        // Generated mock prices have no history to warm up from
+        let require_warmup = env::var("REQUIRE_WARMUP")
+            .ok()
+            .and_then(|v| v.parse().ok())
+            .unwrap_or(!matches!(mode, Mode::Mock) || use_real_market_data);
+        let warmup_concurrency = env::var("WARMUP_CONCURRENCY")
+            .ok()
+            .and_then(|v| v.parse().ok())
+            .unwrap_or(4);
+        let candle_persist_batch_size = env::var("CANDLE_PERSIST_BATCH_SIZE")
+            .ok()
+            .and_then(|v| v.parse().ok())
+            .unwrap_or(50);
+        let candle_persist_flush_ms = env::var("CANDLE_PERSIST_FLUSH_MS")
+            .ok()
+            .and_then(|v| v.parse().ok())
+            .unwrap_or(10_000);
+        let proposal_channel_policy = env::var("PROPOSAL_CHANNEL_POLICY")
+            .ok()
+            .filter(|s| !s.trim().is_empty())
+            .map(|s| ProposalChannelPolicy::from_str(&s))
+            .transpose()?
+            .unwrap_or_default();
+        let channel_capacities = ChannelCapacities::from_env();
+
         // Load sub-configs
         let broker = BrokerEnvConfig::from_env();
         let strategy = StrategyEnvConfig::from_env().context("Failed to load strategy config")?;
         let risk = RiskEnvConfig::from_env().context("Failed to load risk config")?;
         let observability = ObservabilityEnvConfig::from_env();
         let simulation = SimulationEnvConfig::from_env();
+        let news = NewsEnvConfig::from_env().context("Failed to load news config")?;
 
         Ok(Self {
             mode,
             asset_class,
+            warmup_fallback_source,
+            require_warmup,
+            warmup_concurrency,
+            candle_persist_batch_size,
+            candle_persist_flush_ms,
+            proposal_channel_policy,
+            channel_capacities,
 
             // ... (Broker mappings)
             alpaca_api_key: broker.alpaca.api_key,
@@ -207,6 +477,7 @@ impl Config {
             binance_secret_key: broker.binance.secret_key,
             binance_base_url: broker.binance.base_url,
             binance_ws_url: broker.binance.ws_url,
+            binance_exchange_info_refresh_secs: broker.binance.exchange_info_refresh_secs,
 
             // ... (Strategy mappings)
             fast_sma_period: strategy.fast_sma_period,
@@ -223,10 +494,18 @@ impl Config {
             ema_fast_period: strategy.ema_fast_period,
             ema_slow_period: strategy.ema_slow_period,
             adx_period: strategy.adx_period,
+            hurst_lookback: strategy.hurst_lookback,
             adx_threshold: strategy.adx_threshold,
             regime_volatility_threshold: strategy.regime_volatility_threshold,
+            regime_detection_method: strategy.regime_detection_method,
             atr_period: strategy.atr_period,
             trailing_stop_atr_multiplier: strategy.trailing_stop_atr_multiplier,
+            trailing_stop_vol_scale_min: strategy.trailing_stop_vol_scale_min,
+            trailing_stop_vol_scale_max: strategy.trailing_stop_vol_scale_max,
+            candle_history_len: strategy.candle_history_len,
+            candle_gap_policy: strategy.candle_gap_policy,
+            candle_gap_max_bars: strategy.candle_gap_max_bars,
+            confirm_strategy: strategy.confirm_strategy,
             strategy_mode: strategy.strategy_mode,
             trend_divergence_threshold: strategy.trend_divergence_threshold,
             trend_tolerance_pct: strategy.trend_tolerance_pct,
@@ -239,39 +518,99 @@ impl Config {
             enabled_timeframes: strategy.enabled_timeframes,
             trend_timeframe: strategy.trend_timeframe,
             signal_confirmation_bars: strategy.signal_confirmation_bars,
+            require_htf_confirmation: strategy.require_htf_confirmation,
             take_profit_pct: strategy.take_profit_pct,
+            take_profit_mode: strategy.take_profit_mode,
+            take_profit_atr_multiplier: strategy.take_profit_atr_multiplier,
             profit_target_multiplier: strategy.profit_target_multiplier,
+            relative_stop_benchmark: strategy.relative_stop_benchmark,
+            relative_stop_pct: strategy.relative_stop_pct,
+            pyramid_max_adds: strategy.pyramid_max_adds,
+            pyramid_trigger_pct: strategy.pyramid_trigger_pct,
+            exits_bypass_cost_filters: strategy.exits_bypass_cost_filters,
+            allow_shorts: strategy.allow_shorts,
             ensemble_voting_threshold: strategy.ensemble_voting_threshold,
 
             // ... (Risk mappings)
             max_positions: risk.max_positions,
             max_position_size_pct: risk.max_position_size_pct,
             max_position_value_usd: risk.max_position_value_usd,
+            news_trade_size_multiplier: risk.news_trade_size_multiplier,
+            news_trade_max_value_usd: risk.news_trade_max_value_usd,
             risk_per_trade_percent: risk.risk_per_trade_percent,
             max_daily_loss_pct: risk.max_daily_loss_pct,
             max_drawdown_pct: risk.max_drawdown_pct,
             consecutive_loss_limit: risk.consecutive_loss_limit,
             pending_order_ttl_ms: risk.pending_order_ttl_ms,
+            daily_reset: risk.daily_reset,
+            allow_average_down: risk.allow_average_down,
+            max_average_down_loss_pct: risk.max_average_down_loss_pct,
+            min_order_notional: risk.min_order_notional,
+            allow_min_notional_bump: risk.allow_min_notional_bump,
+            leverage: risk.leverage,
             max_sector_exposure_pct: risk.max_sector_exposure_pct,
+            max_gross_exposure_pct: risk.max_gross_exposure_pct,
             sector_map: risk.sector_map,
             non_pdt_mode: risk.non_pdt_mode,
             max_orders_per_minute: risk.max_orders_per_minute,
+            max_open_orders: risk.max_open_orders,
+            max_trades_per_symbol_per_day: risk.max_trades_per_symbol_per_day,
+            open_blackout_minutes: risk.open_blackout_minutes,
+            post_stop_cooldown_seconds: risk.post_stop_cooldown_seconds,
+            order_submit_max_retries: risk.order_submit_max_retries,
+            order_submit_backoff_ms: risk.order_submit_backoff_ms,
+            order_submit_max_retry_ms: risk.order_submit_max_retry_ms,
             order_cooldown_seconds: risk.order_cooldown_seconds,
             min_hold_time_minutes: risk.min_hold_time_minutes,
+            trading_windows_stock: risk.trading_windows_stock,
+            trading_windows_crypto: risk.trading_windows_crypto,
+            earnings_blackout_days: risk.earnings_blackout_days,
+            earnings_flatten: risk.earnings_flatten,
+            finnhub_api_key: risk.finnhub_api_key,
             slippage_pct: risk.slippage_pct,
             commission_per_share: risk.commission_per_share,
             spread_bps: risk.spread_bps,
+            crypto_fee_tiers: risk.crypto_fee_tiers,
+            symbol_cost_overrides: risk.symbol_cost_overrides,
             min_profit_ratio: risk.min_profit_ratio,
+            win_rate_provider: risk.win_rate_provider,
+            win_rate_default: risk.win_rate_default,
+            win_rate_min_trades: risk.win_rate_min_trades,
+            win_rate_prior_weight: risk.win_rate_prior_weight,
+            win_rate_prior_alpha: risk.win_rate_prior_alpha,
+            win_rate_prior_beta: risk.win_rate_prior_beta,
+            expectancy_lookback: risk.expectancy_lookback,
+            expectancy_halflife: risk.expectancy_halflife,
+            cold_start_reward_risk_ratio: risk.cold_start_reward_risk_ratio,
+            cold_start_min_trades: risk.cold_start_min_trades,
             trade_quantity: risk.trade_quantity,
             portfolio_staleness_ms: risk.portfolio_staleness_ms,
+            portfolio_max_age_ms: risk.portfolio_max_age_ms,
             portfolio_refresh_interval_ms: risk.portfolio_refresh_interval_ms,
+            position_reconcile_interval_secs: risk.position_reconcile_interval_secs,
+            rebalance_interval_minutes: risk.rebalance_interval_minutes,
+            rebalance_band_pct: risk.rebalance_band_pct,
+            position_reconcile_correct: risk.position_reconcile_correct,
+            lot_tracking_enabled: risk.lot_tracking_enabled,
             dynamic_symbol_mode: risk.dynamic_symbol_mode,
             dynamic_scan_interval_minutes: risk.dynamic_scan_interval_minutes,
+            scanner_sector_rotation: risk.scanner_sector_rotation,
+            scanner_max_symbols: risk.scanner_max_symbols,
+            scanner_min_price: risk.scanner_min_price,
+            scanner_max_price: risk.scanner_max_price,
             symbols: risk.symbols,
             min_volume_threshold: risk.min_volume_threshold,
             adaptive_optimization_enabled: risk.adaptive_optimization_enabled,
             regime_detection_window: risk.regime_detection_window,
             adaptive_evaluation_hour: risk.adaptive_evaluation_hour,
+            adaptive_lookback_days: risk.adaptive_lookback_days,
+            adaptive_max_period_step_pct: risk.adaptive_max_period_step_pct,
+            adaptive_max_risk_step_pct: risk.adaptive_max_risk_step_pct,
+            adaptive_rollback_sharpe_drop: risk.adaptive_rollback_sharpe_drop,
+            adaptive_rollback_drawdown_rise: risk.adaptive_rollback_drawdown_rise,
+            adaptive_shadow_days: risk.adaptive_shadow_days,
+            adaptive_shadow_min_improvement_pct: risk.adaptive_shadow_min_improvement_pct,
+            load_optimal_parameters: risk.load_optimal_parameters,
             risk_appetite: strategy.risk_appetite,
             enable_ml_data_collection: strategy.enable_ml_data_collection,
 
@@ -280,35 +619,133 @@ impl Config {
             simulation_latency_base_ms: simulation.simulation_latency_base_ms,
             simulation_latency_jitter_ms: simulation.simulation_latency_jitter_ms,
             simulation_slippage_volatility: simulation.simulation_slippage_volatility,
-            use_real_market_data: std::env::var("USE_REAL_MARKET_DATA")
-                .unwrap_or_else(|_| "false".to_string())
-                .parse()
-                .unwrap_or(false),
+            backtest_order_throttle: simulation.backtest_order_throttle,
+            use_real_market_data,
+            initial_cash: simulation.initial_cash,
+
+            // News
+            news_provider: news.provider,
+            news_feed_url: news.feed_url,
+            news_poll_interval_seconds: news.poll_interval_seconds,
+            news_sentiment_threshold: news.sentiment_threshold,
+            news_provider_sentiment_threshold: news.provider_sentiment_threshold,
+            news_dedup_window_seconds: news.dedup_window_seconds,
+            news_reentry_cooldown_seconds: news.reentry_cooldown_seconds,
 
             // ... (Observability mappings)
             observability_enabled: observability.enabled,
             observability_port: observability.port,
             observability_bind_address: observability.bind_address,
+            decision_log_path: observability.decision_log_path,
+        })
+    }
+
+    /// Starting capital for the mock broker; rejects a zero or negative `INITIAL_CASH`.
+    pub fn mock_initial_cash(&self) -> Result<Decimal> {
+        if self.initial_cash <= Decimal::ZERO {
+            anyhow::bail!(
+                "INITIAL_CASH must be greater than zero in mock mode (got {})",
+                self.initial_cash
+            );
+        }
+        Ok(self.initial_cash)
+    }
+
+    /// Benchmark for the relative-strength exit; only equity longs are compared.
+    pub fn relative_stop_benchmark(&self) -> Option<String> {
+        match self.asset_class {
+            AssetClass::Stock => self.relative_stop_benchmark.clone(),
+            AssetClass::Crypto => None,
+        }
+    }
+
+    /// Earnings blackout length; only US equities report earnings (crypto and forex never do).
+    pub fn earnings_blackout_days(&self) -> u32 {
+        match (self.asset_class, &self.mode) {
+            (AssetClass::Stock, Mode::Alpaca | Mode::Mock) => self.earnings_blackout_days,
+            _ => 0,
+        }
+    }
+
+    /// No-trade zone after the session open; crypto has no open, so it never applies.
+    pub fn open_blackout_minutes(&self) -> u32 {
+        match self.asset_class {
+            AssetClass::Stock => self.open_blackout_minutes,
+            AssetClass::Crypto => 0,
+        }
+    }
+
+    /// Entry windows for the configured asset class.
+    /// Configured DAILY_RESET_TIME, else the asset class default
+    pub fn trading_day_boundary(
+        &self,
+    ) -> crate::domain::risk::session_boundary::TradingDayBoundary {
+        self.daily_reset.unwrap_or_else(|| {
+            crate::domain::risk::session_boundary::TradingDayBoundary::for_asset_class(
+                self.asset_class,
+            )
         })
     }
 
+    pub fn trading_windows(&self) -> TradingWindows {
+        match self.asset_class {
+            AssetClass::Stock => self.trading_windows_stock.clone(),
+            AssetClass::Crypto => self.trading_windows_crypto.clone(),
+        }
+    }
+
     pub fn create_fee_model(
         &self,
     ) -> std::sync::Arc<dyn crate::domain::trading::fee_model::FeeModel> {
-        use crate::domain::trading::fee_model::{ConstantFeeModel, TieredFeeModel};
+        use crate::domain::trading::fee_model::{
+            ConstantFeeModel, FeeModel, PerSymbolFeeModel, TieredFeeModel, VolumeTracker,
+        };
+        use std::sync::Arc;
+
+        // Crypto tiers depend on account-wide volume, shared by every per-symbol model
+        let volume = Arc::new(VolumeTracker::new());
+        let build = |commission: Option<Decimal>, slippage_pct: Decimal| -> Arc<dyn FeeModel> {
+            match self.asset_class {
+                AssetClass::Stock => Arc::new(ConstantFeeModel::new(
+                    commission.unwrap_or(self.commission_per_share),
+                    slippage_pct,
+                )),
+                // Alpaca crypto VIP schedule by default (fallback — real fees fetched via API)
+                AssetClass::Crypto => {
+                    let model = match commission {
+                        Some(taker_fee_pct) => {
+                            TieredFeeModel::new(taker_fee_pct, taker_fee_pct, slippage_pct)
+                        }
+                        None => {
+                            TieredFeeModel::with_tiers(self.crypto_fee_tiers.clone(), slippage_pct)
+                        }
+                    };
+                    Arc::new(model.with_volume_tracker(volume.clone()))
+                }
+            }
+        };
 
-        match self.asset_class {
-            AssetClass::Stock => std::sync::Arc::new(ConstantFeeModel::new(
-                self.commission_per_share,
-                self.slippage_pct,
-            )),
-            // Alpaca crypto: maker 0.15%, taker 0.25% (fallback — real fees fetched via API)
-            AssetClass::Crypto => std::sync::Arc::new(TieredFeeModel::new(
-                rust_decimal_macros::dec!(0.0015),
-                rust_decimal_macros::dec!(0.0025),
-                self.slippage_pct,
-            )),
+        let default_model = build(None, self.slippage_pct);
+        if self.symbol_cost_overrides.is_empty() {
+            return default_model;
         }
+
+        let mut model = PerSymbolFeeModel::new(default_model);
+        for (symbol, cost) in &self.symbol_cost_overrides {
+            if cost.commission.is_some() || cost.slippage_pct.is_some() {
+                model = model.with_symbol_model(
+                    symbol.clone(),
+                    build(
+                        cost.commission,
+                        cost.slippage_pct.unwrap_or(self.slippage_pct),
+                    ),
+                );
+            }
+            if let Some(spread_bps) = cost.spread_bps {
+                model = model.with_symbol_spread(symbol.clone(), spread_bps);
+            }
+        }
+        Arc::new(model)
     }
 
     /// Create a RiskConfig domain value object from this Config
@@ -433,4 +870,39 @@ mod tests {
             AssetClass::Crypto
         ));
     }
+
+    #[test]
+    fn test_proposal_channel_policy_parsing() {
+        assert_eq!(
+            ProposalChannelPolicy::from_str("DROP_NEWEST").unwrap(),
+            ProposalChannelPolicy::DropNewest
+        );
+        assert_eq!(
+            ProposalChannelPolicy::from_str("coalesce").unwrap(),
+            ProposalChannelPolicy::Coalesce
+        );
+        assert_eq!(
+            ProposalChannelPolicy::from_str("block_with_timeout").unwrap(),
+            ProposalChannelPolicy::BlockWithTimeout(
+                ProposalChannelPolicy::DEFAULT_BLOCK_TIMEOUT_MS
+            )
+        );
+        assert_eq!(
+            ProposalChannelPolicy::from_str("block_with_timeout: 500").unwrap(),
+            ProposalChannelPolicy::BlockWithTimeout(500)
+        );
+        assert!(ProposalChannelPolicy::from_str("block_with_timeout:soon").is_err());
+        assert!(ProposalChannelPolicy::from_str("coalesce:5").is_err());
+        assert!(ProposalChannelPolicy::from_str("queue").is_err());
+    }
+
+    #[test]
+    fn test_mock_initial_cash_validation() {
+        let mut config = Config::from_env().unwrap();
+        config.initial_cash = Decimal::from(50_000);
+        assert_eq!(config.mock_initial_cash().unwrap(), Decimal::from(50_000));
+
+        config.initial_cash = Decimal::ZERO;
+        assert!(config.mock_initial_cash().is_err());
+    }
 }
@@ -0,0 +1,136 @@
+//! News ingestion configuration parsing from environment variables.
+//!
+//! This module selects which news provider feeds the Listener agent.
+
+use anyhow::{Context, Result};
+use std::env;
+use std::str::FromStr;
+
+/// News provider backing the Listener agent
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum NewsProviderKind {
+    /// Scripted mock events (default when no feed URL is configured)
+    #[default]
+    Mock,
+    /// RSS 2.0 feed poller
+    Rss,
+    /// JSON Feed (https://jsonfeed.org) poller
+    JsonFeed,
+}
+
+impl FromStr for NewsProviderKind {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "mock" => Ok(NewsProviderKind::Mock),
+            "rss" => Ok(NewsProviderKind::Rss),
+            "json" | "jsonfeed" | "json_feed" => Ok(NewsProviderKind::JsonFeed),
+            _ => anyhow::bail!(
+                "Invalid NEWS_PROVIDER: {}. Must be 'mock', 'rss', or 'json'",
+                s
+            ),
+        }
+    }
+}
+
+/// News environment configuration
+#[derive(Debug, Clone)]
+pub struct NewsEnvConfig {
+    pub provider: NewsProviderKind,
+    pub feed_url: Option<String>,
+    pub poll_interval_seconds: u64,
+    /// Score magnitude at which an unlabelled headline counts as Bullish/Bearish
+    pub sentiment_threshold: f64,
+    /// Score magnitude at which a provider-scored event counts as Bullish/Bearish
+    pub provider_sentiment_threshold: f64,
+    /// Window in which repeated headlines for a symbol are suppressed (0 = disabled)
+    pub dedup_window_seconds: u64,
+    /// Buys are blocked for this long after a news-driven panic sell (0 = disabled)
+    pub reentry_cooldown_seconds: u64,
+}
+
+impl NewsEnvConfig {
+    pub fn from_env() -> Result<Self> {
+        // NEWS_FEED_URL takes precedence; NEWS_RSS_URL is kept for backward compatibility
+        let feed_url = env::var("NEWS_FEED_URL")
+            .or_else(|_| env::var("NEWS_RSS_URL"))
+            .ok()
+            .filter(|u| !u.trim().is_empty());
+
+        let provider = match env::var("NEWS_PROVIDER") {
+            Ok(p) => NewsProviderKind::from_str(&p)?,
+            Err(_) if feed_url.is_some() => NewsProviderKind::Rss,
+            Err(_) => NewsProviderKind::Mock,
+        };
+
+        if provider != NewsProviderKind::Mock && feed_url.is_none() {
+            anyhow::bail!(
+                "NEWS_PROVIDER={:?} requires NEWS_FEED_URL to be set",
+                provider
+            );
+        }
+
+        let poll_interval_seconds = env::var("NEWS_POLL_INTERVAL_SECONDS")
+            .unwrap_or_else(|_| "60".to_string())
+            .parse::<u64>()
+            .context("Failed to parse NEWS_POLL_INTERVAL_SECONDS")?;
+
+        let sentiment_threshold = env::var("NEWS_SENTIMENT_THRESHOLD")
+            .unwrap_or_else(|_| "0.3".to_string())
+            .parse::<f64>()
+            .context("Failed to parse NEWS_SENTIMENT_THRESHOLD")?;
+
+        let provider_sentiment_threshold = env::var("NEWS_PROVIDER_SENTIMENT_THRESHOLD")
+            .unwrap_or_else(|_| "0.3".to_string())
+            .parse::<f64>()
+            .context("Failed to parse NEWS_PROVIDER_SENTIMENT_THRESHOLD")?;
+
+        let dedup_window_seconds = env::var("NEWS_DEDUP_WINDOW_SECONDS")
+            .unwrap_or_else(|_| "300".to_string())
+            .parse::<u64>()
+            .context("Failed to parse NEWS_DEDUP_WINDOW_SECONDS")?;
+
+        let reentry_cooldown_seconds = env::var("NEWS_REENTRY_COOLDOWN_SECONDS")
+            .unwrap_or_else(|_| "0".to_string())
+            .parse::<u64>()
+            .context("Failed to parse NEWS_REENTRY_COOLDOWN_SECONDS")?;
+
+        Ok(Self {
+            provider,
+            feed_url,
+            poll_interval_seconds,
+            sentiment_threshold,
+            provider_sentiment_threshold,
+            dedup_window_seconds,
+            reentry_cooldown_seconds,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_news_provider_parsing() {
+        assert_eq!(
+            NewsProviderKind::from_str("RSS").unwrap(),
+            NewsProviderKind::Rss
+        );
+        assert_eq!(
+            NewsProviderKind::from_str("json").unwrap(),
+            NewsProviderKind::JsonFeed
+        );
+        assert!(NewsProviderKind::from_str("twitter").is_err());
+    }
+
+    #[test]
+    fn test_news_config_defaults() {
+        let config = NewsEnvConfig::from_env().expect("Should parse with defaults");
+        assert_eq!(config.poll_interval_seconds, 60);
+        assert_eq!(config.sentiment_threshold, 0.3);
+        assert_eq!(config.dedup_window_seconds, 300);
+        assert_eq!(config.reentry_cooldown_seconds, 0);
+    }
+}
@@ -10,6 +10,8 @@ pub struct ObservabilityEnvConfig {
     pub enabled: bool,
     pub port: u16,
     pub bind_address: String,
+    /// JSONL file receiving the trade decision audit log (disabled when unset)
+    pub decision_log_path: Option<String>,
 }
 
 impl Default for ObservabilityEnvConfig {
@@ -18,6 +20,7 @@ impl Default for ObservabilityEnvConfig {
             enabled: true,
             port: 9090,
             bind_address: "127.0.0.1".to_string(),
+            decision_log_path: None,
         }
     }
 }
@@ -35,6 +38,9 @@ impl ObservabilityEnvConfig {
                 .unwrap_or(9090),
             bind_address: env::var("OBSERVABILITY_BIND_ADDRESS")
                 .unwrap_or_else(|_| "127.0.0.1".to_string()),
+            decision_log_path: env::var("DECISION_LOG_PATH")
+                .ok()
+                .filter(|p| !p.trim().is_empty()),
         }
     }
 }
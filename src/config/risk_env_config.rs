@@ -3,7 +3,11 @@
 //! This module handles loading risk parameters: position sizing, drawdown limits,
 //! PDT rules, sector exposure, and transaction costs.
 
+use crate::domain::market::trading_windows::TradingWindows;
+use crate::domain::optimization::win_rate_source::WinRateSource;
 use crate::domain::risk::risk_appetite::RiskAppetite;
+use crate::domain::risk::session_boundary::TradingDayBoundary;
+use crate::domain::trading::fee_model::{FeeTier, SymbolCostOverride};
 use anyhow::{Context, Result};
 use rust_decimal::Decimal;
 use std::collections::HashMap;
@@ -17,15 +21,29 @@ pub struct RiskEnvConfig {
     pub max_position_size_pct: Decimal,
     pub max_position_value_usd: Decimal,
     pub risk_per_trade_percent: Decimal,
+    pub news_trade_size_multiplier: Decimal,
+    pub news_trade_max_value_usd: Option<Decimal>,
 
     // Drawdown & Circuit Breaker
     pub max_daily_loss_pct: Decimal,
     pub max_drawdown_pct: Decimal,
     pub consecutive_loss_limit: usize,
     pub pending_order_ttl_ms: Option<i64>,
+    /// Start of the trading day for the daily loss limit (None = asset-class default)
+    pub daily_reset: Option<TradingDayBoundary>,
+
+    // Averaging down
+    pub allow_average_down: bool,
+    pub max_average_down_loss_pct: Decimal,
+    pub min_order_notional: Decimal,
+    pub allow_min_notional_bump: bool,
+    /// Account leverage: buying power = equity x leverage - used margin (1 = cash account)
+    pub leverage: Decimal,
 
     // Sector Exposure
     pub max_sector_exposure_pct: Decimal,
+    /// Total position value allowed as a fraction of equity (0 = disabled)
+    pub max_gross_exposure_pct: Decimal,
     pub sector_map: HashMap<String, String>,
 
     // PDT
@@ -33,23 +51,92 @@ pub struct RiskEnvConfig {
 
     // Trading Limits
     pub max_orders_per_minute: u32,
+    /// Broker orders allowed to be open at once before new entries are rejected (0 = unlimited)
+    pub max_open_orders: usize,
+    /// New entries per symbol per trading day (0 = unlimited)
+    pub max_trades_per_symbol_per_day: u32,
+    /// Minutes after the equity session open during which new entries are blocked (0 = disabled)
+    pub open_blackout_minutes: u32,
+    /// Re-entry block on a symbol after one of its stops fires (0 = disabled)
+    pub post_stop_cooldown_seconds: u64,
     pub order_cooldown_seconds: u64,
+    /// Resubmissions after a transient order failure (0 = submit once)
+    pub order_submit_max_retries: u32,
+    /// Delay before the first resubmission, doubled on each further attempt
+    pub order_submit_backoff_ms: u64,
+    /// Total time resubmissions of one order may take, backoff included
+    pub order_submit_max_retry_ms: u64,
     pub min_hold_time_minutes: i64,
 
+    // Trading Hours (new entries only)
+    pub trading_windows_stock: TradingWindows,
+    pub trading_windows_crypto: TradingWindows,
+
+    // Earnings (equities only)
+    /// Calendar days before a known earnings date during which new entries are blocked (0 = disabled)
+    pub earnings_blackout_days: u32,
+    /// Close positions once they enter the earnings blackout
+    pub earnings_flatten: bool,
+    /// Finnhub token for the earnings calendar (None = no calendar)
+    pub finnhub_api_key: Option<String>,
+
     // Transaction Costs
     pub slippage_pct: Decimal,
     pub commission_per_share: Decimal,
     pub spread_bps: Decimal,
+    /// Crypto maker/taker schedule keyed by trailing 30-day volume
+    pub crypto_fee_tiers: Vec<FeeTier>,
+    /// Per-symbol overrides of the cost parameters above
+    pub symbol_cost_overrides: HashMap<String, SymbolCostOverride>,
     pub min_profit_ratio: Decimal,
+    /// Win rate backend behind trade expectancy
+    pub win_rate_provider: WinRateSource,
+    /// Static rate, empirical fallback below win_rate_min_trades, and blended prior
+    pub win_rate_default: f64,
+    /// Closed trades the empirical provider needs before trusting its own rate
+    pub win_rate_min_trades: usize,
+    /// Pseudo-trades the blended provider gives to win_rate_default
+    pub win_rate_prior_weight: f64,
+    /// Beta prior of the bayesian provider: pseudo-wins and pseudo-losses
+    pub win_rate_prior_alpha: f64,
+    pub win_rate_prior_beta: f64,
+    /// Closed trades the empirical win rate behind expectancy is computed over (0 = full history)
+    pub expectancy_lookback: usize,
+    /// Closed trades after which a trade's weight in that win rate halves (0 = equal weights)
+    pub expectancy_halflife: usize,
+    /// Reward:risk assumed for a symbol with no measured ratio yet (0 = disabled)
+    pub cold_start_reward_risk_ratio: Decimal,
+    /// Closed trades after which a symbol leaves cold start
+    pub cold_start_min_trades: usize,
 
     // Portfolio Management
     pub trade_quantity: Decimal,
     pub portfolio_staleness_ms: u64,
+    /// Age of the last successful portfolio sync beyond which new entries are blocked (0 = disabled)
+    pub portfolio_max_age_ms: u64,
     pub portfolio_refresh_interval_ms: u64,
+    /// Seconds between broker position reconciliations (0 = disabled)
+    pub position_reconcile_interval_secs: u64,
+    /// Overwrite local positions with the broker's when they diverge
+    pub position_reconcile_correct: bool,
+    /// Minutes between position trims back to max_position_size_pct (0 = disabled)
+    pub rebalance_interval_minutes: u64,
+    /// Weight above max_position_size_pct tolerated before a position is trimmed
+    pub rebalance_band_pct: Decimal,
+    /// FIFO lot tracking on positions (off by default: average cost only)
+    pub lot_tracking_enabled: bool,
 
     // Dynamic Symbol Mode
     pub dynamic_symbol_mode: bool,
     pub dynamic_scan_interval_minutes: u64,
+    /// Among equally-ranked movers, prefer sectors the portfolio holds least (SECTOR_MAP)
+    pub scanner_sector_rotation: bool,
+    /// Movers kept per scan, held symbols excluded (0 = all)
+    pub scanner_max_symbols: usize,
+    /// Movers priced below this are dropped from the scan (0 = no floor)
+    pub scanner_min_price: Decimal,
+    /// Movers priced above this are dropped from the scan (None = no ceiling)
+    pub scanner_max_price: Option<Decimal>,
     pub symbols: Vec<String>,
     pub min_volume_threshold: Decimal,
 
@@ -57,6 +144,14 @@ pub struct RiskEnvConfig {
     pub adaptive_optimization_enabled: bool,
     pub regime_detection_window: usize,
     pub adaptive_evaluation_hour: u32,
+    pub adaptive_lookback_days: u32,
+    pub adaptive_max_period_step_pct: f64,
+    pub adaptive_max_risk_step_pct: f64,
+    pub adaptive_rollback_sharpe_drop: f64,
+    pub adaptive_rollback_drawdown_rise: f64,
+    pub adaptive_shadow_days: u32,
+    pub adaptive_shadow_min_improvement_pct: Decimal,
+    pub load_optimal_parameters: bool,
 
     // Risk Appetite (for derived values)
     risk_appetite: Option<RiskAppetite>,
@@ -134,33 +229,130 @@ impl RiskEnvConfig {
             max_position_size_pct,
             max_position_value_usd: Self::parse_decimal("MAX_POSITION_VALUE_USD", dec!(5000.0))?,
             risk_per_trade_percent,
+            news_trade_size_multiplier: Self::parse_decimal(
+                "NEWS_TRADE_SIZE_MULTIPLIER",
+                dec!(1.0),
+            )?,
+            news_trade_max_value_usd: env::var("NEWS_TRADE_MAX_VALUE_USD")
+                .ok()
+                .map(|s| s.parse::<Decimal>())
+                .transpose()
+                .context("Failed to parse NEWS_TRADE_MAX_VALUE_USD as Decimal")?,
             max_daily_loss_pct,
             max_drawdown_pct,
             consecutive_loss_limit: Self::parse_usize("CONSECUTIVE_LOSS_LIMIT", 3)?,
             pending_order_ttl_ms: env::var("PENDING_ORDER_TTL_MS")
                 .ok()
                 .and_then(|s| s.parse::<i64>().ok()),
+            daily_reset: env::var("DAILY_RESET_TIME")
+                .ok()
+                .filter(|s| !s.trim().is_empty())
+                .map(|s| s.parse::<TradingDayBoundary>())
+                .transpose()
+                .context("Failed to parse DAILY_RESET_TIME")?,
+            allow_average_down: Self::parse_bool("ALLOW_AVERAGE_DOWN", false),
+            max_average_down_loss_pct: Self::parse_decimal(
+                "MAX_AVERAGE_DOWN_LOSS_PCT",
+                dec!(0.02),
+            )?,
+            min_order_notional: Self::parse_decimal("MIN_ORDER_NOTIONAL", Decimal::ZERO)?,
+            allow_min_notional_bump: Self::parse_bool("ALLOW_MIN_NOTIONAL_BUMP", false),
+            leverage: Self::parse_decimal("LEVERAGE", Decimal::ONE)?,
             max_sector_exposure_pct: Self::parse_decimal("MAX_SECTOR_EXPOSURE_PCT", dec!(0.30))?,
+            max_gross_exposure_pct: Self::parse_decimal("MAX_GROSS_EXPOSURE_PCT", Decimal::ZERO)?,
             sector_map,
             non_pdt_mode: Self::parse_bool("NON_PDT_MODE", true),
             max_orders_per_minute: Self::parse_u32("MAX_ORDERS_PER_MINUTE", 10)?,
+            max_open_orders: Self::parse_usize("MAX_OPEN_ORDERS", 0)?,
+            max_trades_per_symbol_per_day: Self::parse_u32("MAX_TRADES_PER_SYMBOL_PER_DAY", 0)?,
+            open_blackout_minutes: Self::parse_u32("OPEN_BLACKOUT_MINUTES", 0)?,
+            post_stop_cooldown_seconds: Self::parse_u64("POST_STOP_COOLDOWN_SECONDS", 0)?,
             order_cooldown_seconds: Self::parse_u64("ORDER_COOLDOWN_SECONDS", 300)?,
+            order_submit_max_retries: Self::parse_u32("ORDER_SUBMIT_MAX_RETRIES", 3)?,
+            order_submit_backoff_ms: Self::parse_u64("ORDER_SUBMIT_BACKOFF_MS", 500)?,
+            order_submit_max_retry_ms: Self::parse_u64("ORDER_SUBMIT_MAX_RETRY_MS", 5000)?,
             min_hold_time_minutes: Self::parse_i64("MIN_HOLD_TIME_MINUTES", 240)?,
+            trading_windows_stock: Self::parse_trading_windows("TRADING_WINDOWS_STOCK")?,
+            trading_windows_crypto: Self::parse_trading_windows("TRADING_WINDOWS_CRYPTO")?,
+            earnings_blackout_days: Self::parse_u32("EARNINGS_BLACKOUT_DAYS", 0)?,
+            earnings_flatten: Self::parse_bool("EARNINGS_FLATTEN", false),
+            finnhub_api_key: env::var("FINNHUB_API_KEY")
+                .ok()
+                .filter(|k| !k.trim().is_empty()),
             slippage_pct: Self::parse_decimal("SLIPPAGE_PCT", dec!(0.001))?,
             commission_per_share: Self::parse_decimal("COMMISSION_PER_SHARE", dec!(0.001))?,
             spread_bps: Self::parse_decimal("SPREAD_BPS", dec!(5.0))?,
+            crypto_fee_tiers: Self::parse_fee_tiers(&env::var("CRYPTO_FEE_TIERS").unwrap_or_else(
+                |_| {
+                    // Alpaca crypto VIP schedule (volume:maker:taker)
+                    "0:0.0015:0.0025,100000:0.0012:0.0022,500000:0.001:0.002,\
+                     1000000:0.0008:0.0018,10000000:0.0005:0.0015,25000000:0.0002:0.0013,\
+                     50000000:0.0002:0.0012,100000000:0:0.001"
+                        .to_string()
+                },
+            ))?,
+            symbol_cost_overrides: Self::parse_symbol_cost_overrides(
+                &env::var("SYMBOL_COST_OVERRIDES").unwrap_or_default(),
+            )?,
             min_profit_ratio,
+            win_rate_provider: env::var("WIN_RATE_PROVIDER")
+                .unwrap_or_else(|_| "empirical".to_string())
+                .parse()?,
+            win_rate_default: Self::parse_f64("WIN_RATE_DEFAULT", 0.5)?,
+            win_rate_min_trades: Self::parse_usize("WIN_RATE_MIN_TRADES", 10)?,
+            win_rate_prior_weight: Self::parse_f64("WIN_RATE_PRIOR_WEIGHT", 10.0)?,
+            win_rate_prior_alpha: Self::parse_f64("WIN_RATE_PRIOR_ALPHA", 1.0)?,
+            win_rate_prior_beta: Self::parse_f64("WIN_RATE_PRIOR_BETA", 1.0)?,
+            expectancy_lookback: Self::parse_usize("EXPECTANCY_LOOKBACK", 0)?,
+            expectancy_halflife: Self::parse_usize("EXPECTANCY_HALFLIFE", 0)?,
+            cold_start_reward_risk_ratio: Self::parse_decimal(
+                "COLD_START_REWARD_RISK_RATIO",
+                Decimal::ONE,
+            )?,
+            cold_start_min_trades: Self::parse_usize("COLD_START_MIN_TRADES", 10)?,
             trade_quantity,
             portfolio_staleness_ms: Self::parse_u64("PORTFOLIO_STALENESS_MS", 5000).unwrap_or(5000),
+            portfolio_max_age_ms: Self::parse_u64("PORTFOLIO_MAX_AGE_MS", 30_000)?,
             portfolio_refresh_interval_ms: Self::parse_u64("PORTFOLIO_REFRESH_INTERVAL_MS", 2000)
                 .unwrap_or(2000),
+            position_reconcile_interval_secs: Self::parse_u64(
+                "POSITION_RECONCILE_INTERVAL_SECS",
+                300,
+            )?,
+            position_reconcile_correct: Self::parse_bool("POSITION_RECONCILE_CORRECT", false),
+            rebalance_interval_minutes: Self::parse_u64("REBALANCE_INTERVAL_MINUTES", 0)?,
+            rebalance_band_pct: Self::parse_decimal("REBALANCE_BAND_PCT", dec!(0.05))?,
+            lot_tracking_enabled: Self::parse_bool("LOT_TRACKING_ENABLED", false),
             dynamic_symbol_mode,
             dynamic_scan_interval_minutes: Self::parse_u64("DYNAMIC_SCAN_INTERVAL_MINUTES", 5)?,
+            scanner_sector_rotation: Self::parse_bool("SCANNER_SECTOR_ROTATION", false),
+            scanner_max_symbols: Self::parse_usize("SCANNER_MAX_SYMBOLS", 0)?,
+            scanner_min_price: Self::parse_decimal("SCANNER_MIN_PRICE", Decimal::ZERO)?,
+            scanner_max_price: env::var("SCANNER_MAX_PRICE")
+                .ok()
+                .filter(|s| !s.trim().is_empty())
+                .map(|s| s.trim().parse::<Decimal>())
+                .transpose()
+                .context("Failed to parse SCANNER_MAX_PRICE as Decimal")?,
             symbols,
             min_volume_threshold: Self::parse_decimal("MIN_VOLUME_THRESHOLD", dec!(50000.0))?,
             adaptive_optimization_enabled: Self::parse_bool("ADAPTIVE_OPTIMIZATION_ENABLED", false),
             regime_detection_window: Self::parse_usize("REGIME_DETECTION_WINDOW", 20).unwrap_or(20),
             adaptive_evaluation_hour: Self::parse_u32("ADAPTIVE_EVALUATION_HOUR", 0).unwrap_or(0),
+            adaptive_lookback_days: Self::parse_u32("ADAPTIVE_LOOKBACK_DAYS", 90).unwrap_or(90),
+            adaptive_max_period_step_pct: Self::parse_f64("ADAPTIVE_MAX_PERIOD_STEP_PCT", 0.20)?,
+            adaptive_max_risk_step_pct: Self::parse_f64("ADAPTIVE_MAX_RISK_STEP_PCT", 0.20)?,
+            adaptive_rollback_sharpe_drop: Self::parse_f64("ADAPTIVE_ROLLBACK_SHARPE_DROP", 0.25)?,
+            adaptive_rollback_drawdown_rise: Self::parse_f64(
+                "ADAPTIVE_ROLLBACK_DRAWDOWN_RISE",
+                0.02,
+            )?,
+            adaptive_shadow_days: Self::parse_u32("ADAPTIVE_SHADOW_DAYS", 7)?,
+            adaptive_shadow_min_improvement_pct: Self::parse_decimal(
+                "ADAPTIVE_SHADOW_MIN_IMPROVEMENT_PCT",
+                dec!(0.5),
+            )?,
+            load_optimal_parameters: Self::parse_bool("LOAD_OPTIMAL_PARAMETERS", false),
             risk_appetite,
         })
     }
@@ -176,6 +368,13 @@ impl RiskEnvConfig {
             .context(format!("Failed to parse {}", key))
     }
 
+    fn parse_f64(key: &str, default: f64) -> Result<f64> {
+        env::var(key)
+            .unwrap_or_else(|_| default.to_string())
+            .parse::<f64>()
+            .context(format!("Failed to parse {}", key))
+    }
+
     fn parse_decimal(key: &str, default: Decimal) -> Result<Decimal> {
         env::var(key)
             .unwrap_or_else(|_| default.to_string())
@@ -204,6 +403,58 @@ impl RiskEnvConfig {
             .context(format!("Failed to parse {}", key))
     }
 
+    fn parse_trading_windows(key: &str) -> Result<TradingWindows> {
+        env::var(key)
+            .unwrap_or_else(|_| "always".to_string())
+            .parse::<TradingWindows>()
+            .context(format!("Failed to parse {}", key))
+    }
+
+    /// Parse `volume:maker:taker,...` fee tiers
+    fn parse_fee_tiers(raw: &str) -> Result<Vec<FeeTier>> {
+        raw.split(',')
+            .filter(|e| !e.trim().is_empty())
+            .map(|entry| {
+                let parts: Vec<Decimal> = entry
+                    .split(':')
+                    .map(|v| v.trim().parse::<Decimal>())
+                    .collect::<std::result::Result<_, _>>()
+                    .map_err(|_| anyhow::anyhow!("Invalid CRYPTO_FEE_TIERS entry: {}", entry))?;
+                match parts[..] {
+                    [min_volume, maker, taker] => Ok(FeeTier::new(min_volume, maker, taker)),
+                    _ => anyhow::bail!("Invalid CRYPTO_FEE_TIERS entry: {}", entry),
+                }
+            })
+            .collect()
+    }
+
+    /// Parse `SYM:key=val;key=val,SYM:...` with keys `commission`, `slippage`, `spread`
+    fn parse_symbol_cost_overrides(raw: &str) -> Result<HashMap<String, SymbolCostOverride>> {
+        let mut overrides = HashMap::new();
+        for entry in raw.split(',').filter(|e| !e.trim().is_empty()) {
+            let (symbol, params) = entry
+                .split_once(':')
+                .ok_or_else(|| anyhow::anyhow!("Invalid SYMBOL_COST_OVERRIDES entry: {}", entry))?;
+            let mut cost = SymbolCostOverride::default();
+            for param in params.split(';').filter(|p| !p.trim().is_empty()) {
+                let (key, value) = param.split_once('=').ok_or_else(|| {
+                    anyhow::anyhow!("Invalid SYMBOL_COST_OVERRIDES param: {}", param)
+                })?;
+                let value = value.trim().parse::<Decimal>().map_err(|_| {
+                    anyhow::anyhow!("Invalid SYMBOL_COST_OVERRIDES value: {}", param)
+                })?;
+                match key.trim() {
+                    "commission" => cost.commission = Some(value),
+                    "slippage" => cost.slippage_pct = Some(value),
+                    "spread" => cost.spread_bps = Some(value),
+                    other => anyhow::bail!("Unknown SYMBOL_COST_OVERRIDES key: {}", other),
+                }
+            }
+            overrides.insert(symbol.trim().to_string(), cost);
+        }
+        Ok(overrides)
+    }
+
     fn parse_bool(key: &str, default: bool) -> bool {
         env::var(key)
             .unwrap_or_else(|_| default.to_string())
@@ -215,11 +466,59 @@ impl RiskEnvConfig {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use rust_decimal_macros::dec;
 
     #[test]
     fn test_risk_config_defaults() {
         let config = RiskEnvConfig::from_env().expect("Should parse with defaults");
         assert_eq!(config.max_positions, 5);
         assert_eq!(config.consecutive_loss_limit, 3);
+        assert_eq!(config.trading_windows_stock, TradingWindows::Always);
+        assert_eq!(config.trading_windows_crypto, TradingWindows::Always);
+        assert_eq!(config.news_trade_size_multiplier, Decimal::ONE);
+        assert_eq!(config.news_trade_max_value_usd, None);
+    }
+
+    #[test]
+    fn test_parse_symbol_cost_overrides() {
+        let overrides = RiskEnvConfig::parse_symbol_cost_overrides(
+            "BTC/USD:slippage=0.0005;spread=4, XYZ:commission=0.01",
+        )
+        .unwrap();
+        assert_eq!(overrides.len(), 2);
+        assert_eq!(
+            overrides["BTC/USD"],
+            SymbolCostOverride {
+                commission: None,
+                slippage_pct: Some(dec!(0.0005)),
+                spread_bps: Some(dec!(4)),
+            }
+        );
+        assert_eq!(overrides["XYZ"].commission, Some(dec!(0.01)));
+
+        assert!(
+            RiskEnvConfig::parse_symbol_cost_overrides("")
+                .unwrap()
+                .is_empty()
+        );
+        assert!(RiskEnvConfig::parse_symbol_cost_overrides("XYZ:fee=1").is_err());
+    }
+
+    #[test]
+    fn test_parse_fee_tiers() {
+        let tiers =
+            RiskEnvConfig::parse_fee_tiers("0:0.0015:0.0025, 100000:0.0012:0.0022").unwrap();
+        assert_eq!(
+            tiers,
+            vec![
+                FeeTier::new(dec!(0), dec!(0.0015), dec!(0.0025)),
+                FeeTier::new(dec!(100000), dec!(0.0012), dec!(0.0022)),
+            ]
+        );
+        assert!(RiskEnvConfig::parse_fee_tiers("100000:0.0012").is_err());
+
+        let defaults = RiskEnvConfig::from_env().unwrap().crypto_fee_tiers;
+        assert_eq!(defaults.len(), 8);
+        assert_eq!(defaults[0].taker_fee_pct, dec!(0.0025));
     }
 }
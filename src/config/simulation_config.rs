@@ -9,6 +9,10 @@ pub struct SimulationEnvConfig {
     pub simulation_latency_base_ms: u64,
     pub simulation_latency_jitter_ms: u64,
     pub simulation_slippage_volatility: Decimal,
+    /// Apply the live MAX_ORDERS_PER_MINUTE throttle in backtests (false = idealized fills)
+    pub backtest_order_throttle: bool,
+    /// Starting capital for mock mode and backtests
+    pub initial_cash: Decimal,
 }
 
 impl SimulationEnvConfig {
@@ -33,11 +37,23 @@ impl SimulationEnvConfig {
             .and_then(|v| v.parse::<Decimal>().ok())
             .unwrap_or(dec!(0.0005)); // Default 5bps volatility
 
+        let backtest_order_throttle = env::var("BACKTEST_ORDER_THROTTLE")
+            .ok()
+            .and_then(|v| v.parse().ok())
+            .unwrap_or(true);
+
+        let initial_cash = env::var("INITIAL_CASH")
+            .ok()
+            .and_then(|v| v.parse::<Decimal>().ok())
+            .unwrap_or(dec!(100000));
+
         Self {
             simulation_enabled,
             simulation_latency_base_ms,
             simulation_latency_jitter_ms,
             simulation_slippage_volatility,
+            backtest_order_throttle,
+            initial_cash,
         }
     }
 }
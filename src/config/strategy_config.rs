@@ -2,7 +2,8 @@
 //!
 //! This module handles loading technical indicator and strategy parameters.
 
-use crate::domain::market::strategy_config::StrategyMode;
+use crate::domain::market::market_regime::RegimeDetectionMethod;
+use crate::domain::market::strategy_config::{CandleGapPolicy, StrategyMode, TakeProfitMode};
 use crate::domain::market::timeframe::Timeframe;
 use crate::domain::risk::risk_appetite::RiskAppetite;
 use anyhow::{Context, Result};
@@ -40,13 +41,29 @@ pub struct StrategyEnvConfig {
     pub adx_threshold: Decimal,
     /// Volatility threshold for regime detection (e.g. 2.0 = 2x std dev)
     pub regime_volatility_threshold: Decimal,
+    /// Algorithm classifying the market regime
+    pub regime_detection_method: RegimeDetectionMethod,
+
+    // Hurst exponent (trend vs mean-reversion)
+    pub hurst_lookback: usize,
 
     // ATR
     pub atr_period: usize,
     pub trailing_stop_atr_multiplier: Decimal,
+    /// Bounds on the volatility-regime scaling of the trailing-stop multiplier (1/1 = off)
+    pub trailing_stop_vol_scale_min: Decimal,
+    pub trailing_stop_vol_scale_max: Decimal,
+    /// Candles kept per symbol (0 = sized from the largest strategy lookback)
+    pub candle_history_len: usize,
+    /// Fill for missing bars before they reach the indicators (CANDLE_GAP_POLICY)
+    pub candle_gap_policy: CandleGapPolicy,
+    /// Longest gap filled, in bars; longer gaps (session closes) are left as-is
+    pub candle_gap_max_bars: usize,
 
     // Strategy mode
     pub strategy_mode: StrategyMode,
+    /// Strategy that must confirm the primary's entries (CONFIRM_STRATEGY, None = off)
+    pub confirm_strategy: Option<StrategyMode>,
     pub trend_divergence_threshold: Decimal,
     pub trend_tolerance_pct: Decimal,
 
@@ -66,9 +83,28 @@ pub struct StrategyEnvConfig {
 
     // Signal Parameters
     pub signal_confirmation_bars: usize,
+    /// Buys also need a rising trend SMA on trend_timeframe
+    pub require_htf_confirmation: bool,
     pub take_profit_pct: Decimal,
+    /// Partial take-profit trigger: fixed percent or ATR multiple (TAKE_PROFIT_MODE)
+    pub take_profit_mode: TakeProfitMode,
+    pub take_profit_atr_multiplier: Decimal,
     pub profit_target_multiplier: Decimal,
 
+    // Benchmark-relative stop (equity longs)
+    pub relative_stop_benchmark: Option<String>,
+    pub relative_stop_pct: Decimal,
+
+    // Pyramiding (scale into winners)
+    pub pyramid_max_adds: usize,
+    pub pyramid_trigger_pct: Decimal,
+
+    // Exits skip the expectancy and profitability (cost) filters
+    pub exits_bypass_cost_filters: bool,
+
+    // Sell signals may open short positions (long-only when false)
+    pub allow_shorts: bool,
+
     // Risk Appetite Override
     pub risk_appetite: Option<RiskAppetite>,
 
@@ -84,6 +120,12 @@ impl StrategyEnvConfig {
         let strategy_mode_str =
             env::var("STRATEGY_MODE").unwrap_or_else(|_| "standard".to_string());
         let strategy_mode = StrategyMode::from_str(&strategy_mode_str)?;
+        let confirm_strategy = env::var("CONFIRM_STRATEGY")
+            .ok()
+            .filter(|s| !s.trim().is_empty() && s.trim().to_lowercase() != "none")
+            .map(|s| StrategyMode::from_str(s.trim()))
+            .transpose()
+            .context("Failed to parse CONFIRM_STRATEGY")?;
 
         // Parse Risk Appetite first (may override other values)
         let risk_appetite = if let Ok(score_str) = env::var("RISK_APPETITE_SCORE") {
@@ -150,6 +192,20 @@ impl StrategyEnvConfig {
             .parse::<Timeframe>()
             .context("Failed to parse TREND_TIMEFRAME")?;
 
+        let trailing_stop_vol_scale_min =
+            Self::parse_decimal("TRAILING_STOP_VOL_SCALE_MIN", Decimal::ONE)?;
+        let trailing_stop_vol_scale_max =
+            Self::parse_decimal("TRAILING_STOP_VOL_SCALE_MAX", Decimal::ONE)?;
+        if trailing_stop_vol_scale_min <= Decimal::ZERO
+            || trailing_stop_vol_scale_min > trailing_stop_vol_scale_max
+        {
+            anyhow::bail!(
+                "TRAILING_STOP_VOL_SCALE_MIN ({}) must be positive and not above TRAILING_STOP_VOL_SCALE_MAX ({})",
+                trailing_stop_vol_scale_min,
+                trailing_stop_vol_scale_max
+            );
+        }
+
         Ok(Self {
             fast_sma_period: Self::parse_usize("FAST_SMA_PERIOD", 20)?,
             slow_sma_period: Self::parse_usize("SLOW_SMA_PERIOD", 60)?,
@@ -171,9 +227,21 @@ impl StrategyEnvConfig {
                 dec!(2.0),
             )
             .unwrap_or(dec!(2.0)),
+            regime_detection_method: env::var("REGIME_DETECTION_METHOD")
+                .unwrap_or_else(|_| "adx".to_string())
+                .parse()?,
+            hurst_lookback: Self::parse_usize("HURST_LOOKBACK", 50)?,
             atr_period: Self::parse_usize("ATR_PERIOD", 14)?,
             trailing_stop_atr_multiplier,
+            trailing_stop_vol_scale_min,
+            trailing_stop_vol_scale_max,
+            candle_history_len: Self::parse_usize("CANDLE_HISTORY_LEN", 0)?,
+            candle_gap_policy: env::var("CANDLE_GAP_POLICY")
+                .unwrap_or_else(|_| "none".to_string())
+                .parse()?,
+            candle_gap_max_bars: Self::parse_usize("CANDLE_GAP_MAX_BARS", 60)?,
             strategy_mode,
+            confirm_strategy,
             trend_divergence_threshold: Self::parse_decimal(
                 "TREND_DIVERGENCE_THRESHOLD",
                 dec!(0.005),
@@ -192,9 +260,35 @@ impl StrategyEnvConfig {
             enabled_timeframes,
             trend_timeframe,
             signal_confirmation_bars: Self::parse_usize("SIGNAL_CONFIRMATION_BARS", 2)?,
+            require_htf_confirmation: env::var("REQUIRE_HTF_CONFIRMATION")
+                .unwrap_or_else(|_| "false".to_string())
+                .parse::<bool>()
+                .unwrap_or(false),
             take_profit_pct: Self::parse_decimal("TAKE_PROFIT_PCT", dec!(0.05))
                 .unwrap_or(dec!(0.05)),
+            take_profit_mode: env::var("TAKE_PROFIT_MODE")
+                .unwrap_or_else(|_| "percent".to_string())
+                .parse()?,
+            take_profit_atr_multiplier: Self::parse_decimal(
+                "TAKE_PROFIT_ATR_MULTIPLIER",
+                dec!(3.0),
+            )?,
             profit_target_multiplier,
+            relative_stop_benchmark: env::var("RELATIVE_STOP_BENCHMARK")
+                .ok()
+                .map(|s| s.trim().to_uppercase())
+                .filter(|s| !s.is_empty()),
+            relative_stop_pct: Self::parse_decimal("RELATIVE_STOP_PCT", dec!(0.05))?,
+            pyramid_max_adds: Self::parse_usize("PYRAMID_MAX_ADDS", 0)?,
+            pyramid_trigger_pct: Self::parse_decimal("PYRAMID_TRIGGER_PCT", dec!(0.02))?,
+            exits_bypass_cost_filters: env::var("EXITS_BYPASS_COST_FILTERS")
+                .unwrap_or_else(|_| "true".to_string())
+                .parse::<bool>()
+                .unwrap_or(true),
+            allow_shorts: env::var("ALLOW_SHORTS")
+                .unwrap_or_else(|_| "false".to_string())
+                .parse::<bool>()
+                .unwrap_or(false),
             risk_appetite,
             enable_ml_data_collection: env::var("ENABLE_ML_DATA_COLLECTION")
                 .unwrap_or_else(|_| "false".to_string())
@@ -234,5 +328,6 @@ mod tests {
         assert_eq!(config.fast_sma_period, 20);
         assert_eq!(config.slow_sma_period, 60);
         assert_eq!(config.rsi_period, 14);
+        assert_eq!(config.regime_detection_method, RegimeDetectionMethod::Adx);
     }
 }
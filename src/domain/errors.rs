@@ -17,6 +17,34 @@ pub enum TradingError {
     ExecutionFailed { reason: String },
 }
 
+/// Broker-side failure of an order submission, split by whether resubmitting is safe
+#[derive(Debug, Error)]
+pub enum OrderSubmitError {
+    /// Timeout, connection error or 5xx/429: the same order can be sent again
+    #[error("Order submission failed transiently: {reason}")]
+    Transient { reason: String },
+
+    /// Refused by the broker (insufficient funds, invalid symbol, ...): retrying cannot help
+    #[error("Order rejected by broker: {reason}")]
+    Rejected { reason: String },
+}
+
+impl OrderSubmitError {
+    /// Classifies a non-success HTTP response from an order endpoint
+    pub fn from_status(status: u16, reason: String) -> Self {
+        if status >= 500 || status == 429 || status == 408 {
+            Self::Transient { reason }
+        } else {
+            Self::Rejected { reason }
+        }
+    }
+
+    /// True when `err` is a transient submission failure
+    pub fn is_transient(err: &anyhow::Error) -> bool {
+        matches!(err.downcast_ref::<Self>(), Some(Self::Transient { .. }))
+    }
+}
+
 /// Errors related to risk management violations
 #[derive(Debug, Error)]
 pub enum RiskViolation {
@@ -103,6 +131,24 @@ mod tests {
         assert!(msg.contains("10.00%"));
     }
 
+    #[test]
+    fn test_order_submit_error_classification() {
+        let server = OrderSubmitError::from_status(503, "unavailable".to_string());
+        assert!(OrderSubmitError::is_transient(&anyhow::Error::from(server)));
+
+        let throttled = OrderSubmitError::from_status(429, "rate limited".to_string());
+        assert!(OrderSubmitError::is_transient(&anyhow::Error::from(
+            throttled
+        )));
+
+        let rejected = OrderSubmitError::from_status(403, "insufficient buying power".to_string());
+        assert!(!OrderSubmitError::is_transient(&anyhow::Error::from(
+            rejected
+        )));
+
+        assert!(!OrderSubmitError::is_transient(&anyhow::anyhow!("other")));
+    }
+
     #[test]
     fn test_portfolio_error_formatting() {
         let error = PortfolioError::StaleSnapshot {
@@ -19,6 +19,20 @@ pub enum NewsSentiment {
     Neutral,
 }
 
+impl NewsSentiment {
+    /// Label a `[-1.0, 1.0]` score: `|score| >= threshold` is Bullish/Bearish.
+    pub fn from_score(score: f64, threshold: f64) -> Self {
+        let threshold = threshold.abs();
+        if score >= threshold {
+            NewsSentiment::Bullish
+        } else if score <= -threshold {
+            NewsSentiment::Bearish
+        } else {
+            NewsSentiment::Neutral
+        }
+    }
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct NewsSignal {
     pub symbol: String,
@@ -31,6 +45,9 @@ pub struct NewsSignal {
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 pub enum ListenerAction {
     NotifyAnalyst(NewsSentiment), // New action type
+    /// Derive the sentiment from the event: the provider score if present,
+    /// otherwise the local headline scorer.
+    NotifyAnalystScored,
     // Deprecated for now, or keep for backward compat until full migration
     BuyImmediate,
     SellImmediate,
@@ -58,4 +75,16 @@ impl ListenerRule {
 pub struct ListenerConfig {
     pub rules: Vec<ListenerRule>,
     pub poll_interval_seconds: u64,
+    /// Absolute score at or above which an unlabelled event, scored locally from its
+    /// headline, is Bullish/Bearish
+    #[serde(default = "default_score_threshold")]
+    pub headline_score_threshold: f64,
+    /// Absolute provider score at or above which an event is Bullish/Bearish.
+    /// Kept separate from the headline threshold: providers may score on their own scale.
+    #[serde(default = "default_score_threshold")]
+    pub provider_score_threshold: f64,
+}
+
+fn default_score_threshold() -> f64 {
+    0.3
 }
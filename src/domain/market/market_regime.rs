@@ -1,8 +1,12 @@
+use crate::domain::market::statistical_features::calculate_hurst_exponent;
+use crate::domain::market::volatility::calculate_realized_volatility;
 use crate::domain::trading::types::Candle;
 use anyhow::Result;
 use rust_decimal::Decimal;
+use rust_decimal::prelude::{FromPrimitive, ToPrimitive};
 use serde::{Deserialize, Serialize};
 use std::fmt;
+use std::str::FromStr;
 
 /// Represents the current market regime
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
@@ -61,11 +65,50 @@ impl MarketRegime {
     }
 }
 
+/// Algorithm used by [`MarketRegimeDetector::detect`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum RegimeDetectionMethod {
+    /// Regression trend strength vs `adx_threshold`, ATR% vs `volatility_threshold`
+    #[default]
+    Adx,
+    /// Recent vs window realized volatility; trend from the t-stat of mean returns
+    VolatilityClustering,
+    /// Hurst exponent of prices (rescaled range): H > 0.55 trending, below ranging or volatile
+    Hurst,
+}
+
+impl FromStr for RegimeDetectionMethod {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "adx" => Ok(Self::Adx),
+            "volatility" | "volatility_clustering" => Ok(Self::VolatilityClustering),
+            "hurst" => Ok(Self::Hurst),
+            _ => anyhow::bail!(
+                "Invalid regime detection method: {}. Must be 'adx', 'volatility', or 'hurst'",
+                s
+            ),
+        }
+    }
+}
+
+impl fmt::Display for RegimeDetectionMethod {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Adx => write!(f, "adx"),
+            Self::VolatilityClustering => write!(f, "volatility"),
+            Self::Hurst => write!(f, "hurst"),
+        }
+    }
+}
+
 /// Service for detecting market regime from price action
 pub struct MarketRegimeDetector {
     window_size: usize,
     adx_threshold: Decimal,
     volatility_threshold: Decimal,
+    method: RegimeDetectionMethod,
 }
 
 impl MarketRegimeDetector {
@@ -74,9 +117,20 @@ impl MarketRegimeDetector {
             window_size,
             adx_threshold,
             volatility_threshold,
+            method: RegimeDetectionMethod::default(),
         }
     }
 
+    /// Select the detection algorithm used by [`Self::detect`]
+    pub fn with_method(mut self, method: RegimeDetectionMethod) -> Self {
+        self.method = method;
+        self
+    }
+
+    pub fn method(&self) -> RegimeDetectionMethod {
+        self.method
+    }
+
     pub fn detect_from_features(
         &self,
         hurst: Option<Decimal>,
@@ -134,6 +188,16 @@ impl MarketRegimeDetector {
 
         let recent_candles = &candles[candles.len().saturating_sub(self.window_size)..];
 
+        match self.method {
+            RegimeDetectionMethod::Adx => self.detect_adx(recent_candles),
+            RegimeDetectionMethod::VolatilityClustering => {
+                Ok(self.detect_volatility_clustering(recent_candles))
+            }
+            RegimeDetectionMethod::Hurst => Ok(self.detect_hurst(recent_candles)),
+        }
+    }
+
+    fn detect_adx(&self, recent_candles: &[Candle]) -> Result<MarketRegime> {
         // 1. Calculate Volatility (ATR / Price)
         let atr = self.calculate_atr(recent_candles, 14);
         let current_price = recent_candles
@@ -195,6 +259,105 @@ impl MarketRegimeDetector {
         ))
     }
 
+    fn detect_volatility_clustering(&self, candles: &[Candle]) -> MarketRegime {
+        use rust_decimal_macros::dec;
+
+        let prices = closes(candles);
+        let returns = log_returns(&prices);
+        if returns.len() < 8 {
+            return MarketRegime::unknown();
+        }
+
+        // Prices spanning the latest quarter of the returns
+        let recent = &prices[prices.len() - returns.len() / 4 - 1..];
+        let window_vol = return_std_dev(&prices);
+        let recent_vol = return_std_dev(recent);
+        let volatility_score = to_decimal(recent_vol * 100.0);
+
+        // Volatility clusters: the latest quarter is much noisier than the window
+        let cluster_ratio = if window_vol > 0.0 {
+            recent_vol / window_vol
+        } else {
+            0.0
+        };
+        if cluster_ratio >= 1.5 || volatility_score > self.volatility_threshold {
+            let confidence =
+                (dec!(0.5) + to_decimal((cluster_ratio - 1.0).max(0.0) * 0.25)).min(Decimal::ONE);
+            return MarketRegime::new(
+                MarketRegimeType::Volatile,
+                confidence,
+                volatility_score,
+                Decimal::ZERO,
+            );
+        }
+
+        // Drift significance: t-stat of the mean return
+        let n = returns.len() as f64;
+        let mean = returns.iter().sum::<f64>() / n;
+        let t_stat = if window_vol > 0.0 {
+            mean / (window_vol / n.sqrt())
+        } else {
+            0.0
+        };
+        let trend_strength = to_decimal(t_stat.abs());
+
+        let regime_type = if t_stat > 2.0 {
+            MarketRegimeType::TrendingUp
+        } else if t_stat < -2.0 {
+            MarketRegimeType::TrendingDown
+        } else {
+            return MarketRegime::new(
+                MarketRegimeType::Ranging,
+                dec!(0.6),
+                volatility_score,
+                trend_strength,
+            );
+        };
+
+        MarketRegime::new(
+            regime_type,
+            (dec!(0.5) + (trend_strength - dec!(2.0)) * dec!(0.1)).min(Decimal::ONE),
+            volatility_score,
+            trend_strength,
+        )
+    }
+
+    fn detect_hurst(&self, candles: &[Candle]) -> MarketRegime {
+        use rust_decimal_macros::dec;
+
+        let prices = closes(candles);
+        let lags: Vec<usize> = [2, 4, 8, 16, 32]
+            .into_iter()
+            .filter(|lag| lag * 2 <= prices.len())
+            .collect();
+        let Some(h) = calculate_hurst_exponent(&prices, &lags).filter(|h| h.is_finite()) else {
+            return MarketRegime::unknown();
+        };
+        let hurst = to_decimal(h);
+        let volatility_score = to_decimal(return_std_dev(&prices) * 100.0);
+
+        let regime_type = if h > 0.55 {
+            if self.is_uptrend(candles) {
+                MarketRegimeType::TrendingUp
+            } else {
+                MarketRegimeType::TrendingDown
+            }
+        } else if volatility_score > self.volatility_threshold {
+            MarketRegimeType::Volatile
+        } else {
+            MarketRegimeType::Ranging
+        };
+
+        let confidence = ((hurst - dec!(0.5)).abs() * dec!(2.0) + dec!(0.5)).min(Decimal::ONE);
+
+        MarketRegime::new(
+            regime_type,
+            confidence,
+            volatility_score,
+            hurst * dec!(100.0),
+        )
+    }
+
     fn calculate_atr(&self, candles: &[Candle], period: usize) -> Decimal {
         if candles.len() < period + 1 {
             return Decimal::ZERO;
@@ -260,6 +423,27 @@ impl MarketRegimeDetector {
     }
 }
 
+fn to_decimal(value: f64) -> Decimal {
+    Decimal::from_f64(value).unwrap_or(Decimal::ZERO)
+}
+
+fn closes(candles: &[Candle]) -> Vec<f64> {
+    candles.iter().filter_map(|c| c.close.to_f64()).collect()
+}
+
+fn log_returns(prices: &[f64]) -> Vec<f64> {
+    prices
+        .windows(2)
+        .filter(|w| w[0] > 0.0 && w[1] > 0.0)
+        .map(|w| (w[1] / w[0]).ln())
+        .collect()
+}
+
+/// Standard deviation of the log returns of `prices` (per bar, not annualized)
+fn return_std_dev(prices: &[f64]) -> f64 {
+    calculate_realized_volatility(prices, 1.0).unwrap_or(0.0)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -325,4 +509,89 @@ mod tests {
             .unwrap();
         assert_eq!(regime.regime_type, MarketRegimeType::Volatile);
     }
+
+    #[test]
+    fn test_method_parsing() {
+        assert_eq!(
+            "hurst".parse::<RegimeDetectionMethod>().unwrap(),
+            RegimeDetectionMethod::Hurst
+        );
+        assert_eq!(
+            "Volatility".parse::<RegimeDetectionMethod>().unwrap(),
+            RegimeDetectionMethod::VolatilityClustering
+        );
+        assert!("fourier".parse::<RegimeDetectionMethod>().is_err());
+        assert_eq!(
+            RegimeDetectionMethod::Hurst
+                .to_string()
+                .parse::<RegimeDetectionMethod>()
+                .unwrap(),
+            RegimeDetectionMethod::Hurst
+        );
+    }
+
+    #[test]
+    fn test_volatility_clustering_detects_recent_burst() {
+        use rust_decimal_macros::dec;
+        let detector = MarketRegimeDetector::new(40, dec!(25.0), dec!(50.0))
+            .with_method(RegimeDetectionMethod::VolatilityClustering);
+
+        // Calm alternating prices, then a burst of large swings
+        let mut candles: Vec<Candle> = (0..30)
+            .map(|i| create_candle(if i % 2 == 0 { 100.0 } else { 100.2 }))
+            .collect();
+        for i in 0..10 {
+            candles.push(create_candle(if i % 2 == 0 { 95.0 } else { 105.0 }));
+        }
+
+        let regime = detector.detect(&candles).unwrap();
+        assert_eq!(regime.regime_type, MarketRegimeType::Volatile);
+    }
+
+    #[test]
+    fn test_volatility_clustering_detects_steady_drift() {
+        use rust_decimal_macros::dec;
+        let detector = MarketRegimeDetector::new(40, dec!(25.0), dec!(50.0))
+            .with_method(RegimeDetectionMethod::VolatilityClustering);
+
+        let candles: Vec<Candle> = (0..40)
+            .map(|i| create_candle(100.0 * 0.99f64.powi(i) + if i % 2 == 0 { 0.1 } else { 0.0 }))
+            .collect();
+
+        let regime = detector.detect(&candles).unwrap();
+        assert_eq!(regime.regime_type, MarketRegimeType::TrendingDown);
+    }
+
+    #[test]
+    fn test_hurst_method_separates_trend_from_reversion() {
+        use rust_decimal_macros::dec;
+        let detector = MarketRegimeDetector::new(64, dec!(25.0), dec!(50.0))
+            .with_method(RegimeDetectionMethod::Hurst);
+
+        // Persistent: returns in long same-sign runs
+        let mut price = 100.0;
+        let trending: Vec<Candle> = (0..64)
+            .map(|i| {
+                price *= if (i / 8) % 4 == 3 { 0.995 } else { 1.01 };
+                create_candle(price)
+            })
+            .collect();
+        let regime = detector.detect(&trending).unwrap();
+        assert_eq!(regime.regime_type, MarketRegimeType::TrendingUp);
+
+        // Anti-persistent: every move is reversed
+        let reverting: Vec<Candle> = (0..64)
+            .map(|i| create_candle(if i % 2 == 0 { 100.0 } else { 101.0 }))
+            .collect();
+        let regime = detector.detect(&reverting).unwrap();
+        assert_eq!(regime.regime_type, MarketRegimeType::Ranging);
+
+        // Too few returns for an estimate -> Unknown rather than NaN
+        let short = MarketRegimeDetector::new(10, dec!(25.0), dec!(50.0))
+            .with_method(RegimeDetectionMethod::Hurst);
+        assert_eq!(
+            short.detect(&reverting[..10]).unwrap().regime_type,
+            MarketRegimeType::Unknown
+        );
+    }
 }
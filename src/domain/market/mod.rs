@@ -1,6 +1,11 @@
 // Market analysis domain
+// `statistical_features` and `volatility` moved here from the application layer,
+// which still re-exports them at their old paths
 pub mod market_regime;
 pub mod order_flow;
+pub mod statistical_features;
 pub mod strategy_config;
 pub mod timeframe;
 pub mod timeframe_candle;
+pub mod trading_windows;
+pub mod volatility;
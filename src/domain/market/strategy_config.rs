@@ -72,6 +72,85 @@ impl std::fmt::Display for StrategyMode {
     }
 }
 
+/// How the partial take-profit trigger is expressed
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default)]
+pub enum TakeProfitMode {
+    /// Fixed gain from entry (`take_profit_pct`)
+    #[default]
+    Percent,
+    /// Multiple of ATR above entry (`take_profit_atr_multiplier`), wider for volatile names
+    Atr,
+}
+
+impl std::str::FromStr for TakeProfitMode {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "percent" | "pct" => Ok(TakeProfitMode::Percent),
+            "atr" => Ok(TakeProfitMode::Atr),
+            _ => anyhow::bail!("Invalid TAKE_PROFIT_MODE: {}. Valid: percent, atr", s),
+        }
+    }
+}
+
+impl std::fmt::Display for TakeProfitMode {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            TakeProfitMode::Percent => write!(f, "percent"),
+            TakeProfitMode::Atr => write!(f, "atr"),
+        }
+    }
+}
+
+/// How missing bars (halts, thin overnight crypto) are filled before the indicators see them
+///
+/// The indicators count bars, not time, so a gap compresses their window: a 20-bar SMA
+/// spanning a 30-minute halt covers 50 minutes. Filling restores one bar per interval.
+/// - SMA/EMA/RSI/MACD/Bollinger expect `ForwardFill`: flat bars hold the last close, so
+///   averages keep their time span without inventing moves
+/// - ATR and volume-based filters also expect `ForwardFill`: filled bars have no range and
+///   no volume, which reads as the quiet period it was
+/// - `Interpolate` draws a straight line from the last close to the next open, spreading a
+///   gap move across the missing bars; smoother for trend indicators, but it understates
+///   the jump that ATR and stops should react to
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default)]
+pub enum CandleGapPolicy {
+    /// Leave gaps as-is
+    #[default]
+    None,
+    /// Repeat the last close with zero volume
+    ForwardFill,
+    /// Step linearly from the last close to the next open, with zero volume
+    Interpolate,
+}
+
+impl std::str::FromStr for CandleGapPolicy {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "none" | "off" => Ok(CandleGapPolicy::None),
+            "forward_fill" | "ffill" => Ok(CandleGapPolicy::ForwardFill),
+            "interpolate" => Ok(CandleGapPolicy::Interpolate),
+            _ => anyhow::bail!(
+                "Invalid CANDLE_GAP_POLICY: {}. Valid: none, forward_fill, interpolate",
+                s
+            ),
+        }
+    }
+}
+
+impl std::fmt::Display for CandleGapPolicy {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            CandleGapPolicy::None => write!(f, "none"),
+            CandleGapPolicy::ForwardFill => write!(f, "forward_fill"),
+            CandleGapPolicy::Interpolate => write!(f, "interpolate"),
+        }
+    }
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct StrategyDefinition {
     pub symbol: String,
@@ -1,3 +1,4 @@
+use crate::domain::trading::time::{ms_to_secs, secs_to_ms};
 use anyhow::{Result, anyhow};
 use serde::{Deserialize, Serialize};
 use std::fmt;
@@ -88,7 +89,7 @@ impl Timeframe {
     /// # Returns
     /// `true` if this timestamp represents the start of a new period for this timeframe
     pub fn is_period_start(&self, timestamp_ms: i64) -> bool {
-        let timestamp_sec = timestamp_ms / 1000;
+        let timestamp_sec = ms_to_secs(timestamp_ms);
         let period_sec = self.to_seconds();
 
         match self {
@@ -112,7 +113,7 @@ impl Timeframe {
     /// # Returns
     /// The start timestamp (in ms) of the period containing this timestamp
     pub fn period_start(&self, timestamp_ms: i64) -> i64 {
-        let timestamp_sec = timestamp_ms / 1000;
+        let timestamp_sec = ms_to_secs(timestamp_ms);
         let period_sec = self.to_seconds();
 
         let period_start_sec = match self {
@@ -126,7 +127,7 @@ impl Timeframe {
             }
         };
 
-        period_start_sec * 1000
+        secs_to_ms(period_start_sec)
     }
 
     /// Calculates how many candles of this timeframe are needed for warmup
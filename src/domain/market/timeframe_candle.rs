@@ -1,4 +1,5 @@
 use crate::domain::market::timeframe::Timeframe;
+use crate::domain::trading::time::secs_to_ms;
 use rust_decimal::Decimal;
 use serde::{Deserialize, Serialize};
 
@@ -89,7 +90,7 @@ impl TimeframeCandle {
 
     /// Returns the end timestamp of this timeframe period
     pub fn end_timestamp(&self) -> i64 {
-        self.timestamp + (self.timeframe.to_seconds() * 1000)
+        self.timestamp + secs_to_ms(self.timeframe.to_seconds())
     }
 }
 
@@ -0,0 +1,246 @@
+//! Trading-hours windows per asset class.
+//!
+//! A [`TradingWindows`] schedule decides whether *new entries* may be opened at a
+//! given instant. Exits (sell signals, trailing stops, take-profits) are never
+//! gated by it, so existing positions keep being managed outside the window.
+//!
+//! Accepted textual formats (case-insensitive):
+//! - `always` — no restriction (default)
+//! - `regular` — US equities regular session, Mon-Fri 09:30-16:00 America/New_York
+//! - `extended` — US equities pre/post-market, Mon-Fri 04:00-20:00 America/New_York
+//! - `[mon-fri ]HH:MM-HH:MM[,HH:MM-HH:MM...][@Timezone]` — custom windows,
+//!   evaluated in the given IANA timezone (UTC when omitted). A window whose end
+//!   is before its start wraps across midnight (e.g. `22:00-02:00`).
+
+use anyhow::{Context, Result, anyhow};
+use chrono::{DateTime, Datelike, NaiveTime, Weekday};
+use chrono_tz::Tz;
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use std::str::FromStr;
+
+/// A single intraday window in local time.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TimeWindow {
+    pub start: NaiveTime,
+    pub end: NaiveTime,
+}
+
+impl TimeWindow {
+    pub fn new(start: NaiveTime, end: NaiveTime) -> Self {
+        Self { start, end }
+    }
+
+    /// Start is inclusive, end is exclusive. Handles windows wrapping midnight.
+    pub fn contains(&self, time: NaiveTime) -> bool {
+        if self.start <= self.end {
+            time >= self.start && time < self.end
+        } else {
+            time >= self.start || time < self.end
+        }
+    }
+}
+
+/// Entry schedule for one asset class.
+#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
+pub enum TradingWindows {
+    /// Entries allowed at any time (backward-compatible default).
+    #[default]
+    Always,
+    /// Entries allowed only inside one of the windows, in the given timezone.
+    Scheduled {
+        timezone: Tz,
+        windows: Vec<TimeWindow>,
+        weekdays_only: bool,
+    },
+}
+
+impl TradingWindows {
+    /// US equities regular session (09:30-16:00 New York, Mon-Fri).
+    pub fn us_equities_regular() -> Self {
+        Self::Scheduled {
+            timezone: chrono_tz::America::New_York,
+            windows: vec![TimeWindow::new(hm(9, 30), hm(16, 0))],
+            weekdays_only: true,
+        }
+    }
+
+    /// US equities including pre- and post-market (04:00-20:00 New York, Mon-Fri).
+    pub fn us_equities_extended() -> Self {
+        Self::Scheduled {
+            timezone: chrono_tz::America::New_York,
+            windows: vec![TimeWindow::new(hm(4, 0), hm(20, 0))],
+            weekdays_only: true,
+        }
+    }
+
+    /// Returns true if new entries are allowed at `timestamp_ms` (Unix milliseconds).
+    pub fn is_open(&self, timestamp_ms: i64) -> bool {
+        match self {
+            Self::Always => true,
+            Self::Scheduled {
+                timezone,
+                windows,
+                weekdays_only,
+            } => {
+                let Some(utc) = DateTime::from_timestamp_millis(timestamp_ms) else {
+                    return false;
+                };
+                let local = utc.with_timezone(timezone);
+                if *weekdays_only && matches!(local.weekday(), Weekday::Sat | Weekday::Sun) {
+                    return false;
+                }
+                let time = local.time();
+                windows.iter().any(|w| w.contains(time))
+            }
+        }
+    }
+}
+
+fn hm(hour: u32, minute: u32) -> NaiveTime {
+    NaiveTime::from_hms_opt(hour, minute, 0).unwrap_or(NaiveTime::MIN)
+}
+
+fn parse_time(s: &str) -> Result<NaiveTime> {
+    NaiveTime::parse_from_str(s.trim(), "%H:%M").with_context(|| format!("Invalid time '{}'", s))
+}
+
+impl FromStr for TradingWindows {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let spec = s.trim();
+        match spec.to_lowercase().as_str() {
+            "" | "always" => return Ok(Self::Always),
+            "regular" => return Ok(Self::us_equities_regular()),
+            "extended" => return Ok(Self::us_equities_extended()),
+            _ => {}
+        }
+
+        let (weekdays_only, rest) = match spec.get(..8) {
+            Some(prefix) if prefix.eq_ignore_ascii_case("mon-fri ") => (true, &spec[8..]),
+            _ => (false, spec),
+        };
+
+        let (windows_part, timezone) = match rest.split_once('@') {
+            Some((w, tz)) => (
+                w,
+                tz.trim()
+                    .parse::<Tz>()
+                    .map_err(|e| anyhow!("Invalid timezone '{}': {}", tz.trim(), e))?,
+            ),
+            None => (rest, Tz::UTC),
+        };
+
+        let windows = windows_part
+            .split(',')
+            .map(|w| {
+                let (start, end) = w
+                    .split_once('-')
+                    .ok_or_else(|| anyhow!("Invalid window '{}', expected HH:MM-HH:MM", w))?;
+                Ok(TimeWindow::new(parse_time(start)?, parse_time(end)?))
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        Ok(Self::Scheduled {
+            timezone,
+            windows,
+            weekdays_only,
+        })
+    }
+}
+
+impl fmt::Display for TradingWindows {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Always => write!(f, "always"),
+            Self::Scheduled {
+                timezone,
+                windows,
+                weekdays_only,
+            } => {
+                if *weekdays_only {
+                    write!(f, "mon-fri ")?;
+                }
+                let parts: Vec<String> = windows
+                    .iter()
+                    .map(|w| format!("{}-{}", w.start.format("%H:%M"), w.end.format("%H:%M")))
+                    .collect();
+                write!(f, "{}@{}", parts.join(","), timezone)
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    fn ny_ms(y: i32, mo: u32, d: u32, h: u32, mi: u32) -> i64 {
+        chrono_tz::America::New_York
+            .with_ymd_and_hms(y, mo, d, h, mi, 0)
+            .unwrap()
+            .timestamp_millis()
+    }
+
+    #[test]
+    fn test_always_is_open() {
+        assert!(TradingWindows::Always.is_open(0));
+        assert_eq!(
+            "always".parse::<TradingWindows>().unwrap(),
+            TradingWindows::Always
+        );
+    }
+
+    #[test]
+    fn test_regular_session_respects_dst_and_weekends() {
+        let regular = TradingWindows::us_equities_regular();
+        // Wednesday in winter (EST) and summer (EDT)
+        assert!(regular.is_open(ny_ms(2026, 1, 14, 9, 30)));
+        assert!(regular.is_open(ny_ms(2026, 7, 15, 15, 59)));
+        assert!(!regular.is_open(ny_ms(2026, 7, 15, 16, 0)));
+        assert!(!regular.is_open(ny_ms(2026, 7, 15, 8, 0)));
+        // Saturday
+        assert!(!regular.is_open(ny_ms(2026, 7, 18, 12, 0)));
+    }
+
+    #[test]
+    fn test_extended_allows_pre_market() {
+        let extended: TradingWindows = "extended".parse().unwrap();
+        assert!(extended.is_open(ny_ms(2026, 7, 15, 5, 0)));
+        assert!(!TradingWindows::us_equities_regular().is_open(ny_ms(2026, 7, 15, 5, 0)));
+    }
+
+    #[test]
+    fn test_custom_windows_wrap_midnight() {
+        let windows: TradingWindows = "22:00-02:00,12:00-13:00@UTC".parse().unwrap();
+        let at = |h: u32| {
+            chrono::Utc
+                .with_ymd_and_hms(2026, 7, 18, h, 30, 0)
+                .unwrap()
+                .timestamp_millis()
+        };
+        assert!(windows.is_open(at(23)));
+        assert!(windows.is_open(at(1)));
+        assert!(windows.is_open(at(12)));
+        assert!(!windows.is_open(at(3)));
+    }
+
+    #[test]
+    fn test_parse_errors_and_roundtrip() {
+        assert!("9:30".parse::<TradingWindows>().is_err());
+        assert!(
+            "09:30-16:00@Mars/Olympus"
+                .parse::<TradingWindows>()
+                .is_err()
+        );
+
+        let parsed: TradingWindows = "mon-fri 09:30-16:00@America/New_York".parse().unwrap();
+        assert_eq!(parsed, TradingWindows::us_equities_regular());
+        assert_eq!(
+            parsed.to_string().parse::<TradingWindows>().unwrap(),
+            parsed
+        );
+    }
+}
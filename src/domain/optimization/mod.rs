@@ -1,3 +1,5 @@
 // Optimization domain
 pub mod optimization_history;
 pub mod reoptimization_trigger;
+pub mod shadow_trial;
+pub mod win_rate_source;
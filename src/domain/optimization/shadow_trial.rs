@@ -0,0 +1,14 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+/// Candidate parameters waiting out their shadow period before promotion
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ShadowTrial {
+    pub symbol: String,
+    /// Candidate `AnalystConfig`, as JSON
+    pub parameters_json: String,
+    /// Optimization metrics that selected the candidate, as JSON
+    pub performance_metrics_json: String,
+    /// Bars from this point on were never seen by the search that picked the candidate
+    pub started_at: DateTime<Utc>,
+}
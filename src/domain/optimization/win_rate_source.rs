@@ -0,0 +1,44 @@
+use serde::{Deserialize, Serialize};
+
+/// Where the win rate behind trade expectancy comes from
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default)]
+pub enum WinRateSource {
+    /// A fixed rate
+    Static,
+    /// Closed trades from the order history, the fixed rate until enough exist
+    #[default]
+    Empirical,
+    /// Empirical rate shrunk toward the fixed rate, weighted as a number of prior trades,
+    /// so a handful of results can't swing it to 0% or 100%
+    Blended,
+    /// Posterior mean of a Beta prior updated by closed trades (Beta-Binomial)
+    Bayesian,
+}
+
+impl std::str::FromStr for WinRateSource {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "static" => Ok(WinRateSource::Static),
+            "empirical" | "historical" => Ok(WinRateSource::Empirical),
+            "blended" | "shrinkage" => Ok(WinRateSource::Blended),
+            "bayesian" | "beta" => Ok(WinRateSource::Bayesian),
+            _ => anyhow::bail!(
+                "Invalid WIN_RATE_PROVIDER: {}. Valid: static, empirical, blended, bayesian",
+                s
+            ),
+        }
+    }
+}
+
+impl std::fmt::Display for WinRateSource {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            WinRateSource::Static => write!(f, "static"),
+            WinRateSource::Empirical => write!(f, "empirical"),
+            WinRateSource::Blended => write!(f, "blended"),
+            WinRateSource::Bayesian => write!(f, "bayesian"),
+        }
+    }
+}
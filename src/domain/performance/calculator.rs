@@ -138,6 +138,9 @@ mod tests {
             order_type: OrderType::Market,
             status: crate::domain::trading::types::OrderStatus::Filled,
             timestamp,
+            reduce_only: false,
+            origin: Default::default(),
+            reason: None,
         }
     }
 
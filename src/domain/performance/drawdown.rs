@@ -0,0 +1,143 @@
+use rust_decimal::Decimal;
+use rust_decimal_macros::dec;
+
+/// A contiguous period spent below the previous equity peak
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct UnderwaterPeriod {
+    /// Timestamp (ms) of the peak the equity fell from
+    pub start: i64,
+    /// Timestamp (ms) of the recovery, or of the last sample if still underwater
+    pub end: i64,
+    pub recovered: bool,
+}
+
+impl UnderwaterPeriod {
+    pub fn duration_ms(&self) -> i64 {
+        self.end - self.start
+    }
+}
+
+/// Drawdown-from-peak series derived from an equity curve.
+///
+/// Drawdowns are expressed as non-positive percentages, like
+/// `PerformanceMetrics::max_drawdown_pct`.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct UnderwaterCurve {
+    /// (timestamp ms, drawdown %) for every equity sample
+    pub points: Vec<(i64, Decimal)>,
+    pub peak_equity: Decimal,
+    pub trough_equity: Decimal,
+    pub current_drawdown_pct: Decimal,
+    pub max_drawdown_pct: Decimal,
+    pub longest_underwater: Option<UnderwaterPeriod>,
+}
+
+impl UnderwaterCurve {
+    /// Builds the curve from (timestamp ms, equity) samples in chronological order
+    pub fn from_equity(samples: &[(i64, Decimal)]) -> Self {
+        let Some(&(first_ts, first_equity)) = samples.first() else {
+            return Self::default();
+        };
+
+        let mut curve = Self {
+            peak_equity: first_equity,
+            trough_equity: first_equity,
+            ..Self::default()
+        };
+        let mut peak_ts = first_ts;
+        let mut underwater_since: Option<i64> = None;
+
+        for &(ts, equity) in samples {
+            if equity >= curve.peak_equity {
+                if let Some(start) = underwater_since.take() {
+                    curve.record_period(UnderwaterPeriod {
+                        start,
+                        end: ts,
+                        recovered: true,
+                    });
+                }
+                curve.peak_equity = equity;
+                peak_ts = ts;
+            } else if underwater_since.is_none() {
+                underwater_since = Some(peak_ts);
+            }
+
+            let drawdown_pct = if curve.peak_equity > Decimal::ZERO {
+                ((equity - curve.peak_equity) / curve.peak_equity * dec!(100)).max(dec!(-100))
+            } else {
+                Decimal::ZERO
+            };
+            if drawdown_pct < curve.max_drawdown_pct {
+                curve.max_drawdown_pct = drawdown_pct;
+                curve.trough_equity = equity;
+            }
+            curve.current_drawdown_pct = drawdown_pct;
+            curve.points.push((ts, drawdown_pct));
+        }
+
+        if let (Some(start), Some(&(last_ts, _))) = (underwater_since, samples.last()) {
+            curve.record_period(UnderwaterPeriod {
+                start,
+                end: last_ts,
+                recovered: false,
+            });
+        }
+
+        curve
+    }
+
+    fn record_period(&mut self, period: UnderwaterPeriod) {
+        if self
+            .longest_underwater
+            .is_none_or(|longest| period.duration_ms() > longest.duration_ms())
+        {
+            self.longest_underwater = Some(period);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_underwater_curve_tracks_peak_and_longest_stretch() {
+        let samples = vec![
+            (0, dec!(1000)),
+            (1, dec!(900)),  // -10%
+            (2, dec!(1000)), // recovered after 2ms
+            (3, dec!(1200)), // new peak
+            (4, dec!(1080)), // -10%
+            (5, dec!(960)),  // -20%
+            (8, dec!(1140)), // still underwater at the end
+        ];
+
+        let curve = UnderwaterCurve::from_equity(&samples);
+        assert_eq!(curve.points.len(), samples.len());
+        assert_eq!(curve.points[1].1, dec!(-10));
+        assert_eq!(curve.peak_equity, dec!(1200));
+        assert_eq!(curve.trough_equity, dec!(960));
+        assert_eq!(curve.max_drawdown_pct, dec!(-20));
+        assert_eq!(curve.current_drawdown_pct, dec!(-5));
+        assert_eq!(
+            curve.longest_underwater,
+            Some(UnderwaterPeriod {
+                start: 3,
+                end: 8,
+                recovered: false
+            })
+        );
+    }
+
+    #[test]
+    fn test_underwater_curve_empty_and_flat() {
+        assert_eq!(
+            UnderwaterCurve::from_equity(&[]),
+            UnderwaterCurve::default()
+        );
+
+        let curve = UnderwaterCurve::from_equity(&[(0, dec!(500)), (1, dec!(500))]);
+        assert_eq!(curve.max_drawdown_pct, Decimal::ZERO);
+        assert!(curve.longest_underwater.is_none());
+    }
+}
@@ -1,5 +1,6 @@
 // Performance tracking domain
 pub mod calculator;
+pub mod drawdown;
 pub mod metrics;
 pub mod monte_carlo;
 pub mod performance_evaluator;
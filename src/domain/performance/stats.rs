@@ -125,6 +125,49 @@ impl Stats {
         }
         returns
     }
+
+    /// Two-sided bootstrap p-value for a difference in means between two samples
+    /// (e.g. per-trade PnL of two strategies).
+    ///
+    /// Both samples are shifted to the pooled mean (the null hypothesis), resampled with
+    /// replacement, and the p-value is the share of resampled differences at least as
+    /// extreme as the observed one. Returns None when either sample has fewer than 2 values.
+    pub fn bootstrap_mean_diff_p_value(
+        a: &[Decimal],
+        b: &[Decimal],
+        iterations: usize,
+        seed: u64,
+    ) -> Option<f64> {
+        use rand::{Rng, SeedableRng};
+        use rust_decimal::prelude::ToPrimitive;
+
+        if a.len() < 2 || b.len() < 2 || iterations == 0 {
+            return None;
+        }
+        let a: Vec<f64> = a.iter().map(|v| v.to_f64().unwrap_or(0.0)).collect();
+        let b: Vec<f64> = b.iter().map(|v| v.to_f64().unwrap_or(0.0)).collect();
+        let mean = |xs: &[f64]| xs.iter().sum::<f64>() / xs.len() as f64;
+
+        let (mean_a, mean_b) = (mean(&a), mean(&b));
+        let observed = (mean_a - mean_b).abs();
+        let pooled =
+            (mean_a * a.len() as f64 + mean_b * b.len() as f64) / (a.len() + b.len()) as f64;
+        let a0: Vec<f64> = a.iter().map(|v| v - mean_a + pooled).collect();
+        let b0: Vec<f64> = b.iter().map(|v| v - mean_b + pooled).collect();
+
+        let mut rng = rand::rngs::StdRng::seed_from_u64(seed);
+        let mut resample_mean = |xs: &[f64]| {
+            (0..xs.len())
+                .map(|_| xs[rng.random_range(0..xs.len())])
+                .sum::<f64>()
+                / xs.len() as f64
+        };
+        let extreme = (0..iterations)
+            .filter(|_| (resample_mean(&a0) - resample_mean(&b0)).abs() >= observed - 1e-12)
+            .count();
+
+        Some(extreme as f64 / iterations as f64)
+    }
 }
 
 #[cfg(test)]
@@ -152,4 +195,17 @@ mod tests {
         assert!(alpha.abs() < dec!(1e-6));
         assert!(corr > dec!(0.99));
     }
+
+    #[test]
+    fn test_bootstrap_mean_diff_p_value() {
+        let winners: Vec<Decimal> = (0..30).map(|i| Decimal::from(10 + i % 3)).collect();
+        let losers: Vec<Decimal> = (0..30).map(|i| Decimal::from(-10 - i % 3)).collect();
+        let p = Stats::bootstrap_mean_diff_p_value(&winners, &losers, 1000, 7).unwrap();
+        assert!(p < 0.01);
+
+        let same = Stats::bootstrap_mean_diff_p_value(&winners, &winners, 1000, 7).unwrap();
+        assert!(same > 0.5);
+
+        assert!(Stats::bootstrap_mean_diff_p_value(&winners, &[dec!(1)], 1000, 7).is_none());
+    }
 }
@@ -1,8 +1,10 @@
+use crate::domain::trading::lot_size::LotSize;
 use crate::domain::trading::portfolio::Portfolio;
 use crate::domain::trading::types::{MarketEvent, Order, OrderSide, OrderStatus};
 use anyhow::Result;
 use async_trait::async_trait;
 use rust_decimal::Decimal;
+use std::sync::Arc;
 use tokio::sync::{broadcast, mpsc::Receiver};
 
 // Need async_trait for async functions in traits
@@ -39,6 +41,42 @@ pub trait ExecutionService: Send + Sync {
     async fn get_order_fees(&self, _order_id: &str) -> Result<Option<Decimal>> {
         Ok(None)
     }
+    /// Broker quantity rules applied to proposals before submission.
+    /// Returns None if the broker accepts any quantity.
+    fn quantity_rounder(&self) -> Option<Arc<dyn QuantityRounder>> {
+        None
+    }
+    /// Maximum leverage the broker allows on `symbol`.
+    /// Returns None if the broker does not publish per-symbol limits.
+    async fn max_leverage(&self, _symbol: &str) -> Result<Option<Decimal>> {
+        Ok(None)
+    }
+    /// Whether the broker holds an order placed with `client_order_id`, in any state
+    /// (open, filled, cancelled). Checked before resubmitting after a transient error.
+    /// Returns None if the broker rejects a reused client order id on its own.
+    async fn client_order_exists(
+        &self,
+        _symbol: &str,
+        _client_order_id: &str,
+    ) -> Result<Option<bool>> {
+        Ok(None)
+    }
+}
+
+/// Per-broker order-size rules (lot/step size, minimum notional).
+#[async_trait]
+pub trait QuantityRounder: Send + Sync {
+    async fn lot_size(&self, symbol: &str) -> Result<LotSize>;
+
+    /// Rounds `quantity` down to the allowed increment; zero if the broker would reject it.
+    async fn round_quantity(
+        &self,
+        symbol: &str,
+        quantity: Decimal,
+        price: Decimal,
+    ) -> Result<Decimal> {
+        Ok(self.lot_size(symbol).await?.round(quantity, price))
+    }
 }
 
 #[derive(Debug, Clone)]
@@ -61,6 +99,14 @@ pub trait FeatureEngineeringService: Send + Sync {
         &mut self,
         candle: &crate::domain::trading::types::Candle,
     ) -> crate::domain::trading::types::FeatureSet;
+
+    /// Bring the indicator state up to date with `candles` without building a feature
+    /// set for each one; leaves the same state as calling `update` on each candle.
+    fn prime(&mut self, candles: &[crate::domain::trading::types::Candle]) {
+        for candle in candles {
+            self.update(candle);
+        }
+    }
 }
 
 #[async_trait]
@@ -68,10 +114,23 @@ pub trait SectorProvider: Send + Sync {
     async fn get_sector(&self, symbol: &str) -> Result<String>;
 }
 
+/// Upcoming earnings announcements for equities.
+#[async_trait]
+pub trait EarningsCalendarProvider: Send + Sync {
+    /// Next known earnings date of `symbol` on or after `from` (None = nothing scheduled)
+    async fn next_earnings_date(
+        &self,
+        symbol: &str,
+        from: chrono::NaiveDate,
+    ) -> Result<Option<chrono::NaiveDate>>;
+}
+
 pub struct Expectancy {
     pub reward_risk_ratio: Decimal,
     pub win_prob: Decimal,
     pub expected_value: Decimal,
+    /// Closed trades behind `win_prob` (0 = no history, the rate is assumed)
+    pub sample_size: usize,
 }
 
 #[async_trait]
@@ -84,6 +143,9 @@ pub trait ExpectancyEvaluator: Send + Sync {
     ) -> Expectancy;
 }
 
+/// News provider adapter consumed by the Listener agent.
+///
+/// Implementations live in `infrastructure::news` and are selected via `NEWS_PROVIDER`.
 #[async_trait]
 pub trait NewsDataService: Send + Sync {
     /// Subscribe to a stream of news events
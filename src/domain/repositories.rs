@@ -95,14 +95,22 @@ pub trait CandleRepository: Send + Sync {
     /// Save a candle
     async fn save(&self, candle: &Candle) -> Result<()>;
 
-    /// Get candles for a symbol within a time range
+    /// Save several candles; backends override this to write them in one round-trip
+    async fn save_batch(&self, candles: &[Candle]) -> Result<()> {
+        for candle in candles {
+            self.save(candle).await?;
+        }
+        Ok(())
+    }
+
+    /// Get candles for a symbol within a time range (inclusive, Unix milliseconds)
     async fn get_range(&self, symbol: &str, start_ts: i64, end_ts: i64) -> Result<Vec<Candle>>;
 
-    /// Get the timestamp of the most recent candle for a symbol
+    /// Get the timestamp (Unix milliseconds) of the most recent candle for a symbol
     /// Returns None if no candles exist for this symbol
     async fn get_latest_timestamp(&self, symbol: &str) -> Result<Option<i64>>;
 
-    /// Count how many candles exist for a symbol within a time range
+    /// Count how many candles exist for a symbol within a time range (Unix milliseconds)
     /// Useful for determining if we have sufficient cached data
     async fn count_candles(&self, symbol: &str, start_ts: i64, end_ts: i64) -> Result<usize>;
 
@@ -127,6 +135,7 @@ pub trait StrategyRepository: Send + Sync {
 
 use crate::domain::optimization::optimization_history::OptimizationHistory;
 use crate::domain::optimization::reoptimization_trigger::ReoptimizationTrigger;
+use crate::domain::optimization::shadow_trial::ShadowTrial;
 use crate::domain::performance::performance_snapshot::PerformanceSnapshot;
 
 /// Repository for optimization history
@@ -146,6 +155,15 @@ pub trait PerformanceSnapshotRepository: Send + Sync {
     async fn get_history(&self, symbol: &str, limit: usize) -> Result<Vec<PerformanceSnapshot>>;
 }
 
+/// Repository for adaptive candidates in their shadow period, one per symbol
+#[async_trait]
+pub trait ShadowTrialRepository: Send + Sync {
+    /// Start a trial, replacing any running one for the symbol
+    async fn save(&self, trial: &ShadowTrial) -> Result<()>;
+    async fn find_by_symbol(&self, symbol: &str) -> Result<Option<ShadowTrial>>;
+    async fn delete(&self, symbol: &str) -> Result<()>;
+}
+
 /// Repository for re-optimization triggers
 #[async_trait]
 pub trait ReoptimizationTriggerRepository: Send + Sync {
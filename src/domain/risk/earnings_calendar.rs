@@ -0,0 +1,120 @@
+//! Cached earnings dates for the earnings blackout.
+//!
+//! Wraps an [`EarningsCalendarProvider`] so the risk checks can ask about a symbol on
+//! every proposal and valuation tick without hitting the provider each time. A cached
+//! date is reused until it expires or the announcement has passed.
+
+use crate::domain::ports::EarningsCalendarProvider;
+use chrono::NaiveDate;
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+use tracing::warn;
+
+/// How long a looked-up earnings date is trusted before asking the provider again
+pub const DEFAULT_EARNINGS_CACHE_TTL: Duration = Duration::from_secs(12 * 3600);
+
+pub struct EarningsCalendar {
+    provider: Arc<dyn EarningsCalendarProvider>,
+    ttl: Duration,
+    /// symbol -> (next earnings date, fetched at)
+    cache: Mutex<HashMap<String, (Option<NaiveDate>, Instant)>>,
+}
+
+impl EarningsCalendar {
+    pub fn new(provider: Arc<dyn EarningsCalendarProvider>) -> Self {
+        Self {
+            provider,
+            ttl: DEFAULT_EARNINGS_CACHE_TTL,
+            cache: Mutex::new(HashMap::new()),
+        }
+    }
+
+    /// Next earnings date of `symbol` on or after `today` (None = unknown or none scheduled).
+    /// Provider errors are logged and cached as unknown, so they never block trading.
+    pub async fn next_earnings(&self, symbol: &str, today: NaiveDate) -> Option<NaiveDate> {
+        {
+            let cache = self.cache.lock().expect("earnings cache mutex poisoned");
+            if let Some((date, fetched_at)) = cache.get(symbol)
+                && fetched_at.elapsed() < self.ttl
+                && date.is_none_or(|d| d >= today)
+            {
+                return *date;
+            }
+        }
+
+        let date = match self.provider.next_earnings_date(symbol, today).await {
+            Ok(date) => date,
+            Err(e) => {
+                warn!("EarningsCalendar: lookup failed for {}: {}", symbol, e);
+                None
+            }
+        };
+        self.cache
+            .lock()
+            .expect("earnings cache mutex poisoned")
+            .insert(symbol.to_string(), (date, Instant::now()));
+        date
+    }
+
+    /// Earnings date of `symbol` when it falls within `days` calendar days of `today`
+    pub async fn upcoming_within(
+        &self,
+        symbol: &str,
+        today: NaiveDate,
+        days: u32,
+    ) -> Option<NaiveDate> {
+        self.next_earnings(symbol, today)
+            .await
+            .filter(|date| (*date - today).num_days() <= days as i64)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use anyhow::Result;
+    use async_trait::async_trait;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    struct CountingProvider {
+        date: NaiveDate,
+        calls: AtomicUsize,
+    }
+
+    #[async_trait]
+    impl EarningsCalendarProvider for CountingProvider {
+        async fn next_earnings_date(
+            &self,
+            _symbol: &str,
+            from: NaiveDate,
+        ) -> Result<Option<NaiveDate>> {
+            self.calls.fetch_add(1, Ordering::SeqCst);
+            Ok((self.date >= from).then_some(self.date))
+        }
+    }
+
+    fn date(d: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(2024, 1, d).unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_cached_until_the_date_passes() {
+        let provider = Arc::new(CountingProvider {
+            date: date(25),
+            calls: AtomicUsize::new(0),
+        });
+        let calendar = EarningsCalendar::new(provider.clone());
+
+        assert_eq!(
+            calendar.upcoming_within("AAPL", date(20), 5).await,
+            Some(date(25))
+        );
+        assert_eq!(calendar.upcoming_within("AAPL", date(19), 5).await, None);
+        assert_eq!(provider.calls.load(Ordering::SeqCst), 1);
+
+        // Once the announcement is behind us the provider is asked again
+        assert_eq!(calendar.next_earnings("AAPL", date(26)).await, None);
+        assert_eq!(provider.calls.load(Ordering::SeqCst), 2);
+    }
+}
@@ -0,0 +1,168 @@
+use async_trait::async_trait;
+use rust_decimal::Decimal;
+
+use crate::domain::risk::filters::validator_trait::{
+    RiskValidator, ValidationContext, ValidationResult,
+};
+use crate::domain::trading::types::OrderSide;
+
+use rust_decimal_macros::dec;
+
+/// Configuration for the averaging-down guard
+#[derive(Debug, Clone)]
+pub struct AverageDownConfig {
+    /// If true, buys into a losing position are allowed (guard disabled)
+    pub allow_average_down: bool,
+
+    /// Unrealized loss beyond which adding to a position is blocked (e.g., 0.02 = 2%)
+    pub max_loss_pct: Decimal,
+}
+
+impl Default for AverageDownConfig {
+    fn default() -> Self {
+        Self {
+            allow_average_down: false,
+            max_loss_pct: dec!(0.02),
+        }
+    }
+}
+
+/// Blocks buys that would add to a losing position ("cut losers, don't add to them")
+///
+/// A buy is rejected when the symbol is already held and the current price is
+/// more than `max_loss_pct` below the position's average entry price.
+pub struct AverageDownValidator {
+    config: AverageDownConfig,
+}
+
+impl AverageDownValidator {
+    pub fn new(config: AverageDownConfig) -> Self {
+        Self { config }
+    }
+}
+
+#[async_trait]
+impl RiskValidator for AverageDownValidator {
+    fn name(&self) -> &str {
+        "AverageDownValidator"
+    }
+
+    async fn validate(&self, ctx: &ValidationContext<'_>) -> ValidationResult {
+        if !matches!(ctx.proposal.side, OrderSide::Buy) {
+            return ValidationResult::Approve;
+        }
+
+        let position = match ctx.portfolio.positions.get(&ctx.proposal.symbol) {
+            Some(p) if p.quantity > Decimal::ZERO && p.average_price > Decimal::ZERO => p,
+            _ => return ValidationResult::Approve,
+        };
+
+        let price = ctx.get_proposal_price();
+        let loss_pct = (position.average_price - price) / position.average_price;
+
+        if loss_pct > self.config.max_loss_pct {
+            return ValidationResult::reject(
+                self.name(),
+                format!(
+                    "Averaging down blocked for {}: position is {:.2}% under entry {} (limit {}%)",
+                    ctx.proposal.symbol,
+                    loss_pct * dec!(100),
+                    position.average_price,
+                    self.config.max_loss_pct * dec!(100)
+                ),
+            );
+        }
+
+        ValidationResult::Approve
+    }
+
+    fn is_enabled(&self) -> bool {
+        !self.config.allow_average_down
+    }
+
+    fn priority(&self) -> u8 {
+        25 // After PDT, before sector exposure
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::risk::state::RiskState;
+    use crate::domain::trading::portfolio::{Portfolio, Position};
+    use crate::domain::trading::types::{OrderType, TradeProposal};
+    use std::collections::{HashMap, VecDeque};
+
+    fn proposal(side: OrderSide) -> TradeProposal {
+        TradeProposal {
+            symbol: "AAPL".to_string(),
+            side,
+            price: dec!(95),
+            quantity: dec!(10),
+            order_type: OrderType::Market,
+            reason: "test".to_string(),
+            timestamp: 0,
+            stop_loss: None,
+            take_profit: None,
+            reduce_only: false,
+            origin: Default::default(),
+        }
+    }
+
+    fn portfolio_with_entry(average_price: Decimal) -> Portfolio {
+        let mut portfolio = Portfolio::new();
+        portfolio.positions.insert(
+            "AAPL".to_string(),
+            Position {
+                symbol: "AAPL".to_string(),
+                quantity: dec!(10),
+                average_price,
+                lots: VecDeque::new(),
+            },
+        );
+        portfolio
+    }
+
+    async fn validate(validator: &AverageDownValidator, side: OrderSide, entry: Decimal) -> bool {
+        let proposal = proposal(side);
+        let portfolio = portfolio_with_entry(entry);
+        let prices = HashMap::new();
+        let risk_state = RiskState::default();
+        let ctx = ValidationContext::new(
+            &proposal,
+            &portfolio,
+            dec!(100000),
+            &prices,
+            &risk_state,
+            None,
+            None,
+            None,
+            Decimal::ZERO,
+            dec!(100000),
+            None,
+        );
+        validator.validate(&ctx).await.is_approved()
+    }
+
+    #[tokio::test]
+    async fn test_blocks_buy_into_losing_position() {
+        let validator = AverageDownValidator::new(AverageDownConfig::default());
+
+        // 95 vs 100 entry: -5% beyond the 2% limit
+        assert!(!validate(&validator, OrderSide::Buy, dec!(100)).await);
+        // 95 vs 96 entry: ~-1% is tolerated
+        assert!(validate(&validator, OrderSide::Buy, dec!(96)).await);
+        // Winners can be added to, and sells are never blocked
+        assert!(validate(&validator, OrderSide::Buy, dec!(90)).await);
+        assert!(validate(&validator, OrderSide::Sell, dec!(100)).await);
+    }
+
+    #[test]
+    fn test_allow_average_down_disables_guard() {
+        let validator = AverageDownValidator::new(AverageDownConfig {
+            allow_average_down: true,
+            ..Default::default()
+        });
+        assert!(!validator.is_enabled());
+    }
+}
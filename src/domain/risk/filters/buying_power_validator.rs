@@ -6,24 +6,45 @@ use crate::domain::risk::filters::validator_trait::{
     RiskValidator, ValidationContext, ValidationResult,
 };
 use crate::domain::trading::types::OrderSide;
+use rust_decimal::Decimal;
 
 /// Configuration for buying power validation
 #[derive(Debug, Clone)]
 pub struct BuyingPowerConfig {
     /// Whether to strictly enforce buying power checks
     pub enabled: bool,
+    /// Sells may open shorts; the shorted notional must then be covered by available cash
+    pub allow_shorts: bool,
+    /// Account leverage (1 = cash account, buying power is available cash)
+    pub leverage: Decimal,
 }
 
 impl Default for BuyingPowerConfig {
     fn default() -> Self {
-        Self { enabled: true }
+        Self {
+            enabled: true,
+            allow_shorts: false,
+            leverage: Decimal::ONE,
+        }
     }
 }
 
+/// Leverage applied to an order: the account leverage capped by the symbol's limit, never below 1
+pub fn effective_leverage(account_leverage: Decimal, symbol_max: Option<Decimal>) -> Decimal {
+    symbol_max
+        .map_or(account_leverage, |max| account_leverage.min(max))
+        .max(Decimal::ONE)
+}
+
 /// Validates that there is sufficient buying power (available cash) for the trade.
 ///
 /// This validator prevents "Insufficient Funds" errors from the broker by
 /// checking available cash (Cash - Reservations) against the estimated order cost.
+/// When shorts are allowed, the part of a sell beyond the held quantity is checked
+/// the same way, so a short is never larger than the cash backing it.
+///
+/// On margin accounts (leverage > 1) buying power is equity x leverage minus the margin
+/// already used by open positions and reservations.
 pub struct BuyingPowerValidator {
     config: BuyingPowerConfig,
 }
@@ -32,6 +53,26 @@ impl BuyingPowerValidator {
     pub fn new(config: BuyingPowerConfig) -> Self {
         Self { config }
     }
+
+    /// Equity x leverage minus the gross value of open positions and reserved orders
+    fn margin_buying_power(ctx: &ValidationContext<'_>, leverage: Decimal) -> Decimal {
+        let used: Decimal = ctx
+            .portfolio
+            .positions
+            .values()
+            .map(|p| {
+                let price = ctx
+                    .current_prices
+                    .get(&p.symbol)
+                    .copied()
+                    .unwrap_or(p.average_price);
+                (p.quantity * price).abs()
+            })
+            .sum();
+        // Reservations hold the margin of pending orders (order value / leverage)
+        let reserved = (ctx.portfolio.cash - ctx.available_cash).max(Decimal::ZERO);
+        ctx.current_equity * leverage - used - reserved * leverage
+    }
 }
 
 #[async_trait]
@@ -45,30 +86,55 @@ impl RiskValidator for BuyingPowerValidator {
             return ValidationResult::Approve;
         }
 
-        // Only validate Buy orders (sells generate cash)
-        if !matches!(ctx.proposal.side, OrderSide::Buy) {
-            return ValidationResult::Approve;
-        }
-
         // Calculate estimated cost
         // Note: For market orders, this is an estimate. Using proposal price (which is usually last trade or ticker price).
-        let estimated_cost = ctx.get_proposal_price() * ctx.proposal.quantity;
+        let position_qty = ctx.get_current_position_qty();
+        let estimated_cost = match ctx.proposal.side {
+            // Covering a short is backed by its sale proceeds; only the part opening a long needs cash
+            OrderSide::Buy => {
+                let shorted = (-position_qty).max(Decimal::ZERO);
+                let long_qty = (ctx.proposal.quantity - shorted).max(Decimal::ZERO);
+                if long_qty.is_zero() {
+                    return ValidationResult::Approve;
+                }
+                ctx.get_proposal_price() * long_qty
+            }
+            // Closing a long generates cash; only the part opening a short needs backing
+            OrderSide::Sell if self.config.allow_shorts => {
+                let held = position_qty.max(Decimal::ZERO);
+                let short_qty = (ctx.proposal.quantity - held).max(Decimal::ZERO);
+                if short_qty.is_zero() {
+                    return ValidationResult::Approve;
+                }
+                ctx.get_proposal_price() * short_qty
+            }
+            OrderSide::Sell => return ValidationResult::Approve,
+        };
 
         // Apply 5% safety margin to account for stale portfolio data
         // This prevents "insufficient balance" errors when cached data differs from Alpaca's actual state
         let safety_margin = rust_decimal_macros::dec!(0.95);
-        let effective_available = ctx.available_cash * safety_margin;
+        let leverage = effective_leverage(self.config.leverage, ctx.symbol_max_leverage);
+        let available = if leverage > Decimal::ONE {
+            Self::margin_buying_power(ctx, leverage)
+        } else {
+            ctx.available_cash
+        };
+        let effective_available = available * safety_margin;
 
-        // Check against available cash with safety margin
+        // Check against buying power with safety margin
         if estimated_cost > effective_available {
             debug!(
-                "BuyingPowerValidator: Insufficient funds. Cost: {}, Available: {} (effective: {} with 5% margin)",
-                estimated_cost, ctx.available_cash, effective_available
+                "BuyingPowerValidator: Insufficient funds. Cost: {}, Available: {} (effective: {} with 5% margin, {}x leverage)",
+                estimated_cost, available, effective_available, leverage
+            );
+            return ValidationResult::reject(
+                self.name(),
+                format!(
+                    "Insufficient buying power. Cost: {}, Available: {} (with safety margin)",
+                    estimated_cost, effective_available
+                ),
             );
-            return ValidationResult::Reject(format!(
-                "Insufficient buying power. Cost: {}, Available: {} (with safety margin)",
-                estimated_cost, effective_available
-            ));
         }
 
         ValidationResult::Approve
@@ -100,6 +166,8 @@ mod tests {
             timestamp: 0,
             stop_loss: None,
             take_profit: None,
+            reduce_only: false,
+            origin: Default::default(),
         }
     }
 
@@ -190,4 +258,117 @@ mod tests {
 
         assert!(validator.validate(&ctx).await.is_approved());
     }
+
+    #[tokio::test]
+    async fn test_short_sell_needs_cash_backing() {
+        let validator = BuyingPowerValidator::new(BuyingPowerConfig {
+            allow_shorts: true,
+            ..BuyingPowerConfig::default()
+        });
+        let mut portfolio = Portfolio::new();
+        portfolio.allow_shorts = true;
+        portfolio.apply_buy_fill("ABC", dec!(4), dec!(100), Decimal::ZERO, 0);
+        let prices = HashMap::new();
+        let risk_state = RiskState::default();
+
+        // Selling 10 with 4 held shorts 6 ($600): $500 cash (475 effective) is not enough
+        let proposal = create_test_proposal(OrderSide::Sell, dec!(100), dec!(10));
+        let ctx = |cash| {
+            ValidationContext::new(
+                &proposal,
+                &portfolio,
+                dec!(2000),
+                &prices,
+                &risk_state,
+                None,
+                None,
+                None,
+                Decimal::ZERO,
+                cash,
+                None,
+            )
+        };
+        assert!(validator.validate(&ctx(dec!(500))).await.is_rejected());
+        assert!(validator.validate(&ctx(dec!(700))).await.is_approved());
+
+        // Closing the long alone never needs cash
+        let close = create_test_proposal(OrderSide::Sell, dec!(100), dec!(4));
+        let ctx = ValidationContext::new(
+            &close,
+            &portfolio,
+            dec!(2000),
+            &prices,
+            &risk_state,
+            None,
+            None,
+            None,
+            Decimal::ZERO,
+            Decimal::ZERO,
+            None,
+        );
+        assert!(validator.validate(&ctx).await.is_approved());
+    }
+
+    #[test]
+    fn test_effective_leverage_capped_by_symbol() {
+        assert_eq!(effective_leverage(dec!(5), None), dec!(5));
+        assert_eq!(effective_leverage(dec!(5), Some(dec!(3))), dec!(3));
+        assert_eq!(effective_leverage(dec!(2), Some(dec!(3))), dec!(2));
+        assert_eq!(effective_leverage(dec!(1), Some(Decimal::ZERO)), dec!(1));
+    }
+
+    #[tokio::test]
+    async fn test_margin_buying_power_uses_equity_times_leverage() {
+        let validator = BuyingPowerValidator::new(BuyingPowerConfig {
+            leverage: dec!(2),
+            ..BuyingPowerConfig::default()
+        });
+        // $1000 cash, $1000 already in XYZ: equity $2000
+        let mut portfolio = Portfolio::new();
+        portfolio.cash = dec!(2000);
+        portfolio.apply_buy_fill("XYZ", dec!(10), dec!(100), Decimal::ZERO, 0);
+        let prices = HashMap::from([("XYZ".to_string(), dec!(100))]);
+        let risk_state = RiskState::default();
+
+        // Buying power = 2000 * 2 - 1000 = 3000 (2850 with the safety margin)
+        let proposal = create_test_proposal(OrderSide::Buy, dec!(100), dec!(28));
+        let ctx = |available_cash, symbol_max| {
+            ValidationContext::new(
+                &proposal,
+                &portfolio,
+                dec!(2000),
+                &prices,
+                &risk_state,
+                None,
+                None,
+                None,
+                Decimal::ZERO,
+                available_cash,
+                None,
+            )
+            .with_symbol_max_leverage(symbol_max)
+        };
+        assert!(
+            validator
+                .validate(&ctx(dec!(1000), None))
+                .await
+                .is_approved()
+        );
+
+        // $200 of margin reserved by a pending order removes $400 of buying power
+        assert!(
+            validator
+                .validate(&ctx(dec!(800), None))
+                .await
+                .is_rejected()
+        );
+
+        // The symbol only allows cash trading: back to available cash
+        assert!(
+            validator
+                .validate(&ctx(dec!(1000), Some(dec!(1))))
+                .await
+                .is_rejected()
+        );
+    }
 }
@@ -104,17 +104,22 @@ impl RiskValidator for CircuitBreakerValidator {
     }
 
     async fn validate(&self, ctx: &ValidationContext<'_>) -> ValidationResult {
+        // Only new exposure is blocked; exits must still be able to flatten
+        if !ctx.is_entry() {
+            return ValidationResult::Approve;
+        }
+
         // Check all circuit breaker conditions
         if let Some(reason) = self.check_daily_loss(ctx) {
-            return ValidationResult::Reject(reason);
+            return ValidationResult::reject(self.name(), reason);
         }
 
         if let Some(reason) = self.check_drawdown(ctx) {
-            return ValidationResult::Reject(reason);
+            return ValidationResult::reject(self.name(), reason);
         }
 
         if let Some(reason) = self.check_consecutive_losses(ctx) {
-            return ValidationResult::Reject(reason);
+            return ValidationResult::reject(self.name(), reason);
         }
 
         ValidationResult::Approve
@@ -145,6 +150,8 @@ mod tests {
             timestamp: 0,
             stop_loss: None,
             take_profit: None,
+            reduce_only: false,
+            origin: Default::default(),
         }
     }
 
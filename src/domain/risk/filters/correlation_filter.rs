@@ -2,7 +2,7 @@ use rust_decimal::Decimal;
 use rust_decimal_macros::dec;
 
 /// Configuration for correlation-based diversification
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq)]
 pub struct CorrelationFilterConfig {
     /// Maximum allowed correlation with any existing position (e.g., 0.85)
     pub max_correlation_threshold: Decimal,
@@ -20,7 +20,6 @@ use crate::domain::risk::filters::validator_trait::{
     RiskValidator, ValidationContext, ValidationResult,
 };
 use crate::domain::trading::portfolio::Position;
-use crate::domain::trading::types::OrderSide;
 use async_trait::async_trait;
 use std::collections::HashMap;
 
@@ -75,8 +74,8 @@ impl RiskValidator for CorrelationFilter {
     }
 
     async fn validate(&self, ctx: &ValidationContext<'_>) -> ValidationResult {
-        // Only validate Buy orders
-        if !matches!(ctx.proposal.side, OrderSide::Buy) {
+        // Only validate entries
+        if !ctx.is_entry() {
             return ValidationResult::Approve;
         }
 
@@ -93,7 +92,7 @@ impl RiskValidator for CorrelationFilter {
             &self.config,
         ) {
             Ok(_) => ValidationResult::Approve,
-            Err(e) => ValidationResult::Reject(e),
+            Err(e) => ValidationResult::reject(self.name(), e),
         }
     }
 
@@ -106,6 +105,7 @@ impl RiskValidator for CorrelationFilter {
 mod tests {
     use super::*;
     use rust_decimal_macros::dec;
+    use std::collections::VecDeque;
 
     #[test]
     fn test_block_high_correlation() {
@@ -116,6 +116,7 @@ mod tests {
                 symbol: "BTC/USD".to_string(),
                 quantity: dec!(1),
                 average_price: dec!(50000),
+                lots: VecDeque::new(),
             },
         );
 
@@ -140,6 +141,7 @@ mod tests {
                 symbol: "BTC/USD".to_string(),
                 quantity: dec!(1),
                 average_price: dec!(50000),
+                lots: VecDeque::new(),
             },
         );
 
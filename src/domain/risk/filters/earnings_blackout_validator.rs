@@ -0,0 +1,175 @@
+use async_trait::async_trait;
+use std::sync::Arc;
+
+use crate::domain::risk::earnings_calendar::EarningsCalendar;
+use crate::domain::risk::filters::validator_trait::{
+    RiskValidator, ValidationContext, ValidationResult,
+};
+use crate::domain::risk::session_boundary::TradingDayBoundary;
+
+/// Configuration for the earnings blackout
+#[derive(Clone, Default)]
+pub struct EarningsBlackoutConfig {
+    /// Calendar days before a known earnings date during which entries are blocked (0 = disabled)
+    pub blackout_days: u32,
+
+    /// Shared earnings calendar (None = no provider, validator disabled)
+    pub calendar: Option<Arc<EarningsCalendar>>,
+
+    /// Day the proposal belongs to
+    pub trading_day_boundary: TradingDayBoundary,
+}
+
+/// Blocks new equity entries shortly before a known earnings announcement
+///
+/// Holding through earnings is a gap risk the stops cannot manage. Buys that open or
+/// add to a long and sells that open or add to a short are rejected when the symbol
+/// reports within `blackout_days`; exits always pass.
+pub struct EarningsBlackoutValidator {
+    config: EarningsBlackoutConfig,
+}
+
+impl EarningsBlackoutValidator {
+    pub fn new(config: EarningsBlackoutConfig) -> Self {
+        Self { config }
+    }
+}
+
+#[async_trait]
+impl RiskValidator for EarningsBlackoutValidator {
+    fn name(&self) -> &str {
+        "EarningsBlackoutValidator"
+    }
+
+    async fn validate(&self, ctx: &ValidationContext<'_>) -> ValidationResult {
+        let Some(calendar) = &self.config.calendar else {
+            return ValidationResult::Approve;
+        };
+
+        if !ctx.is_entry() {
+            return ValidationResult::Approve;
+        }
+
+        let today = self
+            .config
+            .trading_day_boundary
+            .trading_day_at_ms(ctx.proposal.timestamp);
+        if let Some(earnings) = calendar
+            .upcoming_within(&ctx.proposal.symbol, today, self.config.blackout_days)
+            .await
+        {
+            return ValidationResult::reject(
+                self.name(),
+                format!(
+                    "Entry blocked for {}: earnings on {} (within {} days)",
+                    ctx.proposal.symbol, earnings, self.config.blackout_days
+                ),
+            );
+        }
+
+        ValidationResult::Approve
+    }
+
+    fn is_enabled(&self) -> bool {
+        self.config.blackout_days > 0 && self.config.calendar.is_some()
+    }
+
+    fn priority(&self) -> u8 {
+        28 // After averaging down, before sector exposure
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::ports::EarningsCalendarProvider;
+    use crate::domain::risk::state::RiskState;
+    use crate::domain::trading::portfolio::{Portfolio, Position};
+    use crate::domain::trading::types::{OrderSide, OrderType, TradeProposal};
+    use chrono::NaiveDate;
+    use rust_decimal::Decimal;
+    use rust_decimal_macros::dec;
+    use std::collections::{HashMap, VecDeque};
+
+    struct FixedCalendar;
+
+    #[async_trait]
+    impl EarningsCalendarProvider for FixedCalendar {
+        async fn next_earnings_date(
+            &self,
+            symbol: &str,
+            _from: NaiveDate,
+        ) -> anyhow::Result<Option<NaiveDate>> {
+            Ok((symbol == "AAPL").then(|| NaiveDate::from_ymd_opt(2023, 11, 17).unwrap()))
+        }
+    }
+
+    async fn validate(symbol: &str, side: OrderSide, held: Decimal) -> bool {
+        let validator = EarningsBlackoutValidator::new(EarningsBlackoutConfig {
+            blackout_days: 3,
+            calendar: Some(Arc::new(EarningsCalendar::new(Arc::new(FixedCalendar)))),
+            trading_day_boundary: TradingDayBoundary::us_equities(),
+        });
+        let proposal = TradeProposal {
+            symbol: symbol.to_string(),
+            side,
+            price: dec!(100),
+            quantity: dec!(10),
+            order_type: OrderType::Market,
+            reason: "test".to_string(),
+            timestamp: 1_700_058_600_000, // 2023-11-15 09:30 New York
+            stop_loss: None,
+            take_profit: None,
+            reduce_only: false,
+            origin: Default::default(),
+        };
+        let mut portfolio = Portfolio::new();
+        if !held.is_zero() {
+            portfolio.positions.insert(
+                symbol.to_string(),
+                Position {
+                    symbol: symbol.to_string(),
+                    quantity: held,
+                    average_price: dec!(100),
+                    lots: VecDeque::new(),
+                },
+            );
+        }
+        let prices = HashMap::new();
+        let risk_state = RiskState::default();
+        let ctx = ValidationContext::new(
+            &proposal,
+            &portfolio,
+            dec!(100000),
+            &prices,
+            &risk_state,
+            None,
+            None,
+            None,
+            Decimal::ZERO,
+            dec!(100000),
+            None,
+        );
+        validator.validate(&ctx).await.is_approved()
+    }
+
+    #[tokio::test]
+    async fn test_blocks_entries_before_earnings() {
+        // AAPL reports two days later: entries on either side are blocked
+        assert!(!validate("AAPL", OrderSide::Buy, Decimal::ZERO).await);
+        assert!(!validate("AAPL", OrderSide::Sell, Decimal::ZERO).await);
+        // Closing the long is always allowed
+        assert!(validate("AAPL", OrderSide::Sell, dec!(10)).await);
+        // No earnings scheduled
+        assert!(validate("MSFT", OrderSide::Buy, Decimal::ZERO).await);
+    }
+
+    #[test]
+    fn test_disabled_without_calendar() {
+        let validator = EarningsBlackoutValidator::new(EarningsBlackoutConfig {
+            blackout_days: 3,
+            ..Default::default()
+        });
+        assert!(!validator.is_enabled());
+    }
+}
@@ -0,0 +1,140 @@
+use async_trait::async_trait;
+use rust_decimal::Decimal;
+
+use crate::domain::risk::filters::validator_trait::{
+    RiskValidator, ValidationContext, ValidationResult,
+};
+
+use rust_decimal_macros::dec;
+
+/// Configuration for the portfolio-wide exposure cap
+#[derive(Debug, Clone, Default)]
+pub struct GrossExposureConfig {
+    /// Maximum total position value as a fraction of equity (e.g., 0.80 = 80%, 0 = disabled)
+    pub max_gross_exposure_pct: Decimal,
+}
+
+/// Caps the capital deployed across all positions
+///
+/// Sums the absolute value of every open position plus the candidate trade and rejects
+/// entries that would push the total above `max_gross_exposure_pct` of equity, keeping
+/// a cash buffer regardless of per-position and per-sector limits. Exits always pass.
+pub struct GrossExposureValidator {
+    config: GrossExposureConfig,
+}
+
+impl GrossExposureValidator {
+    pub fn new(config: GrossExposureConfig) -> Self {
+        Self { config }
+    }
+}
+
+#[async_trait]
+impl RiskValidator for GrossExposureValidator {
+    fn name(&self) -> &str {
+        "GrossExposureValidator"
+    }
+
+    async fn validate(&self, ctx: &ValidationContext<'_>) -> ValidationResult {
+        if !ctx.is_entry() || ctx.current_equity <= Decimal::ZERO {
+            return ValidationResult::Approve;
+        }
+
+        let gross = ctx.portfolio.gross_exposure(ctx.current_prices)
+            + ctx.symbol_pending_exposure
+            + ctx.calculate_proposal_exposure();
+        let gross_pct = gross / ctx.current_equity;
+
+        if gross_pct > self.config.max_gross_exposure_pct {
+            return ValidationResult::reject(
+                self.name(),
+                format!(
+                    "Gross exposure limit exceeded for {}: {:.2}% of equity deployed (limit {}%)",
+                    ctx.proposal.symbol,
+                    gross_pct * dec!(100),
+                    self.config.max_gross_exposure_pct * dec!(100)
+                ),
+            );
+        }
+
+        ValidationResult::Approve
+    }
+
+    fn is_enabled(&self) -> bool {
+        self.config.max_gross_exposure_pct > Decimal::ZERO
+    }
+
+    fn priority(&self) -> u8 {
+        32 // After sector exposure, before correlation
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::risk::state::RiskState;
+    use crate::domain::trading::portfolio::{Portfolio, Position};
+    use crate::domain::trading::types::{OrderSide, OrderType, TradeProposal};
+    use std::collections::{HashMap, VecDeque};
+
+    async fn validate(side: OrderSide, quantity: Decimal) -> bool {
+        let validator = GrossExposureValidator::new(GrossExposureConfig {
+            max_gross_exposure_pct: dec!(0.80),
+        });
+        let proposal = TradeProposal {
+            symbol: "MSFT".to_string(),
+            side,
+            price: dec!(100),
+            quantity,
+            order_type: OrderType::Market,
+            reason: "test".to_string(),
+            timestamp: 0,
+            stop_loss: None,
+            take_profit: None,
+            reduce_only: false,
+            origin: Default::default(),
+        };
+        // $70k long AAPL on $100k equity
+        let mut portfolio = Portfolio::new();
+        portfolio.positions.insert(
+            "AAPL".to_string(),
+            Position {
+                symbol: "AAPL".to_string(),
+                quantity: dec!(700),
+                average_price: dec!(90),
+                lots: VecDeque::new(),
+            },
+        );
+        let prices = HashMap::from([("AAPL".to_string(), dec!(100))]);
+        let risk_state = RiskState::default();
+        let ctx = ValidationContext::new(
+            &proposal,
+            &portfolio,
+            dec!(100000),
+            &prices,
+            &risk_state,
+            None,
+            None,
+            None,
+            Decimal::ZERO,
+            dec!(30000),
+            None,
+        );
+        validator.validate(&ctx).await.is_approved()
+    }
+
+    #[tokio::test]
+    async fn test_caps_total_deployed_capital() {
+        // 70k + 5k = 75% fits under the 80% cap
+        assert!(validate(OrderSide::Buy, dec!(50)).await);
+        // 70k + 15k = 85% does not, on either side
+        assert!(!validate(OrderSide::Buy, dec!(150)).await);
+        assert!(!validate(OrderSide::Sell, dec!(150)).await);
+    }
+
+    #[test]
+    fn test_disabled_by_default() {
+        let validator = GrossExposureValidator::new(GrossExposureConfig::default());
+        assert!(!validator.is_enabled());
+    }
+}
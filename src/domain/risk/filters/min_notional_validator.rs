@@ -0,0 +1,178 @@
+use async_trait::async_trait;
+use rust_decimal::{Decimal, RoundingStrategy};
+
+use crate::domain::risk::filters::validator_trait::{
+    RiskValidator, ValidationContext, ValidationResult,
+};
+use crate::domain::trading::types::TradeProposal;
+
+/// Configuration for the minimum order value check
+#[derive(Debug, Clone, Default)]
+pub struct MinNotionalConfig {
+    /// Smallest accepted order value (price * quantity). Zero disables the check.
+    pub min_notional: Decimal,
+
+    /// If true, undersized entries are bumped up to the floor instead of rejected
+    pub allow_bump: bool,
+}
+
+/// Rejects entries (long buys and short sells) whose value is below the configured floor
+///
+/// Brokers reject orders under their minimum notional, which wastes the signal.
+/// Exits are never blocked so small residual positions can always be closed.
+pub struct MinNotionalValidator {
+    config: MinNotionalConfig,
+}
+
+impl MinNotionalValidator {
+    pub fn new(config: MinNotionalConfig) -> Self {
+        Self { config }
+    }
+
+    /// Quantity an entry must be raised to in order to reach the floor, given the
+    /// signed quantity already held.
+    ///
+    /// Returns `None` when bumping is disabled, the proposal is an exit, or it already meets it.
+    pub fn bumped_quantity(
+        &self,
+        proposal: &TradeProposal,
+        position_qty: Decimal,
+    ) -> Option<Decimal> {
+        if !self.config.allow_bump
+            || !self.is_enabled()
+            || !proposal.increases_exposure(position_qty)
+            || proposal.price <= Decimal::ZERO
+            || proposal.price * proposal.quantity >= self.config.min_notional
+        {
+            return None;
+        }
+        Some(
+            (self.config.min_notional / proposal.price)
+                .round_dp_with_strategy(8, RoundingStrategy::AwayFromZero),
+        )
+    }
+}
+
+#[async_trait]
+impl RiskValidator for MinNotionalValidator {
+    fn name(&self) -> &str {
+        "MinNotionalValidator"
+    }
+
+    async fn validate(&self, ctx: &ValidationContext<'_>) -> ValidationResult {
+        if !ctx.is_entry() {
+            return ValidationResult::Approve;
+        }
+
+        let notional = ctx.get_proposal_price() * ctx.proposal.quantity;
+        if notional < self.config.min_notional {
+            return ValidationResult::reject(
+                self.name(),
+                format!(
+                    "Order value ${:.2} for {} is below the minimum notional ${}",
+                    notional, ctx.proposal.symbol, self.config.min_notional
+                ),
+            );
+        }
+
+        ValidationResult::Approve
+    }
+
+    fn is_enabled(&self) -> bool {
+        self.config.min_notional > Decimal::ZERO
+    }
+
+    fn priority(&self) -> u8 {
+        15 // Cheap check right after price anomaly, before PDT
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::risk::state::RiskState;
+    use crate::domain::trading::portfolio::Portfolio;
+    use crate::domain::trading::types::{OrderSide, OrderType};
+    use rust_decimal_macros::dec;
+    use std::collections::HashMap;
+
+    fn proposal(side: OrderSide, quantity: Decimal) -> TradeProposal {
+        TradeProposal {
+            symbol: "AAPL".to_string(),
+            side,
+            price: dec!(200),
+            quantity,
+            order_type: OrderType::Market,
+            reason: "test".to_string(),
+            timestamp: 0,
+            stop_loss: None,
+            take_profit: None,
+            reduce_only: false,
+            origin: Default::default(),
+        }
+    }
+
+    async fn validate(validator: &MinNotionalValidator, proposal: &TradeProposal) -> bool {
+        let portfolio = Portfolio::new();
+        let prices = HashMap::new();
+        let risk_state = RiskState::default();
+        let ctx = ValidationContext::new(
+            proposal,
+            &portfolio,
+            dec!(1000),
+            &prices,
+            &risk_state,
+            None,
+            None,
+            None,
+            Decimal::ZERO,
+            dec!(1000),
+            None,
+        );
+        validator.validate(&ctx).await.is_approved()
+    }
+
+    #[tokio::test]
+    async fn test_rejects_buy_below_floor() {
+        let validator = MinNotionalValidator::new(MinNotionalConfig {
+            min_notional: dec!(10),
+            allow_bump: false,
+        });
+
+        // 0.04 * 200 = $8 < $10
+        assert!(!validate(&validator, &proposal(OrderSide::Buy, dec!(0.04))).await);
+        assert!(validate(&validator, &proposal(OrderSide::Buy, dec!(0.05))).await);
+        // Exits are never blocked
+        let mut exit = proposal(OrderSide::Sell, dec!(0.01));
+        exit.reduce_only = true;
+        assert!(validate(&validator, &exit).await);
+        assert_eq!(
+            validator.bumped_quantity(&proposal(OrderSide::Buy, dec!(0.04)), Decimal::ZERO),
+            None
+        );
+
+        assert!(!MinNotionalValidator::new(MinNotionalConfig::default()).is_enabled());
+    }
+
+    #[tokio::test]
+    async fn test_bump_raises_quantity_to_floor() {
+        let validator = MinNotionalValidator::new(MinNotionalConfig {
+            min_notional: dec!(10),
+            allow_bump: true,
+        });
+
+        let mut small = proposal(OrderSide::Buy, dec!(0.04));
+        small.quantity = validator.bumped_quantity(&small, Decimal::ZERO).unwrap();
+        assert_eq!(small.quantity, dec!(0.05));
+        assert!(validate(&validator, &small).await);
+
+        assert_eq!(
+            validator.bumped_quantity(&proposal(OrderSide::Buy, dec!(1)), Decimal::ZERO),
+            None
+        );
+        assert_eq!(
+            validator.bumped_quantity(&proposal(OrderSide::Sell, dec!(0.01)), dec!(1)),
+            None
+        );
+    }
+}
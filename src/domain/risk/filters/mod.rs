@@ -1,6 +1,10 @@
+pub mod average_down_validator;
 pub mod buying_power_validator;
 pub mod circuit_breaker_validator;
 pub mod correlation_filter;
+pub mod earnings_blackout_validator;
+pub mod gross_exposure_validator;
+pub mod min_notional_validator;
 pub mod pdt_validator;
 pub mod position_size_validator;
 pub mod price_anomaly_validator;
@@ -5,7 +5,6 @@ use crate::config::AssetClass;
 use crate::domain::risk::filters::validator_trait::{
     RiskValidator, ValidationContext, ValidationResult,
 };
-use crate::domain::trading::types::OrderSide;
 
 /// Configuration for PDT (Pattern Day Trader) protection
 #[derive(Debug, Clone)]
@@ -57,12 +56,12 @@ impl PdtValidator {
         current_equity < self.config.min_equity_threshold
     }
 
-    /// Check if this sell would complete a day trade
+    /// Check if this exit would complete a day trade
     ///
     /// Note: This is a simplified check. In a real system, we'd check if the
     /// position was opened today by examining the buy timestamp.
     fn is_closing_day_trade(&self, ctx: &ValidationContext<'_>) -> bool {
-        // If we have a position in this symbol, selling it could be a day trade
+        // If we have a position in this symbol, closing it could be a day trade
         ctx.portfolio.positions.contains_key(&ctx.proposal.symbol)
     }
 }
@@ -89,20 +88,26 @@ impl RiskValidator for PdtValidator {
             return ValidationResult::Approve;
         }
 
-        // Block BUY orders (prevents opening new positions that could be day traded)
-        if matches!(ctx.proposal.side, OrderSide::Buy) {
-            return ValidationResult::Reject(format!(
-                "PDT PROTECT: Cannot open new position (Day trades: {}, Equity: {})",
-                ctx.portfolio.day_trades_count, ctx.current_equity
-            ));
+        // Block entries (prevents opening new positions that could be day traded)
+        if ctx.is_entry() {
+            return ValidationResult::reject(
+                self.name(),
+                format!(
+                    "PDT PROTECT: Cannot open new position (Day trades: {}, Equity: {})",
+                    ctx.portfolio.day_trades_count, ctx.current_equity
+                ),
+            );
         }
 
-        // Block SELL orders that would complete a day trade
-        if matches!(ctx.proposal.side, OrderSide::Sell) && self.is_closing_day_trade(ctx) {
-            return ValidationResult::Reject(format!(
-                "PDT PROTECT: Cannot complete day trade (Day trades: {}, Equity: {})",
-                ctx.portfolio.day_trades_count, ctx.current_equity
-            ));
+        // Block exits that would complete a day trade
+        if self.is_closing_day_trade(ctx) {
+            return ValidationResult::reject(
+                self.name(),
+                format!(
+                    "PDT PROTECT: Cannot complete day trade (Day trades: {}, Equity: {})",
+                    ctx.portfolio.day_trades_count, ctx.current_equity
+                ),
+            );
         }
 
         ValidationResult::Approve
@@ -118,9 +123,9 @@ mod tests {
     use super::*;
     use crate::domain::risk::state::RiskState;
     use crate::domain::trading::portfolio::{Portfolio, Position};
-    use crate::domain::trading::types::{OrderType, TradeProposal};
+    use crate::domain::trading::types::{OrderSide, OrderType, TradeProposal};
     use rust_decimal_macros::dec;
-    use std::collections::HashMap;
+    use std::collections::{HashMap, VecDeque};
 
     fn create_test_proposal(side: OrderSide) -> TradeProposal {
         TradeProposal {
@@ -133,6 +138,8 @@ mod tests {
             timestamp: 0,
             stop_loss: None,
             take_profit: None,
+            reduce_only: false,
+            origin: Default::default(),
         }
     }
 
@@ -248,6 +255,7 @@ mod tests {
                 symbol: "AAPL".to_string(),
                 quantity: dec!(10),
                 average_price: dec!(145),
+                lots: VecDeque::new(),
             },
         );
 
@@ -284,7 +292,9 @@ mod tests {
     async fn test_approve_sell_no_position() {
         let validator = PdtValidator::new(PdtConfig::default());
 
-        let proposal = create_test_proposal(OrderSide::Sell);
+        // A reduce-only sell never opens a short
+        let mut proposal = create_test_proposal(OrderSide::Sell);
+        proposal.reduce_only = true;
         let mut portfolio = Portfolio::new();
         portfolio.day_trades_count = 3; // At limit
         // No position in AAPL
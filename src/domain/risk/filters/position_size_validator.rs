@@ -79,8 +79,8 @@ impl RiskValidator for PositionSizeValidator {
     }
 
     async fn validate(&self, ctx: &ValidationContext<'_>) -> ValidationResult {
-        // Only validate Buy orders (sells reduce exposure)
-        if !matches!(ctx.proposal.side, OrderSide::Buy) {
+        // Only validate entries, long or short (exits reduce exposure)
+        if !ctx.is_entry() {
             return ValidationResult::Approve;
         }
 
@@ -92,7 +92,7 @@ impl RiskValidator for PositionSizeValidator {
         // Calculate total exposure after this trade
         // Total = Existing Position (Mark-to-Market) + Pending Orders (at Entry) + New Proposal
         let current_position_qty = ctx.get_current_position_qty();
-        let existing_exposure = current_position_qty * ctx.proposal.price;
+        let existing_exposure = current_position_qty.abs() * ctx.proposal.price;
         let proposal_exposure = ctx.proposal.quantity * ctx.proposal.price;
 
         let total_exposure = existing_exposure + ctx.symbol_pending_exposure + proposal_exposure;
@@ -106,11 +106,14 @@ impl RiskValidator for PositionSizeValidator {
             .unwrap_or(Decimal::ZERO);
 
         if position_pct > adjusted_max_pct {
-            return ValidationResult::Reject(format!(
-                "Position size ({}%) exceeds limit ({}%) [Sentiment Adjusted]",
-                position_pct * dec!(100),
-                adjusted_max_pct * dec!(100)
-            ));
+            return ValidationResult::reject(
+                self.name(),
+                format!(
+                    "Position size ({}%) exceeds limit ({}%) [Sentiment Adjusted]",
+                    position_pct * dec!(100),
+                    adjusted_max_pct * dec!(100)
+                ),
+            );
         }
 
         ValidationResult::Approve
@@ -129,7 +132,7 @@ mod tests {
     use crate::domain::trading::portfolio::{Portfolio, Position};
     use crate::domain::trading::types::{OrderType, TradeProposal};
     use rust_decimal_macros::dec;
-    use std::collections::HashMap;
+    use std::collections::{HashMap, VecDeque};
 
     fn create_test_proposal(
         symbol: &str,
@@ -147,6 +150,8 @@ mod tests {
             timestamp: 0,
             stop_loss: None,
             take_profit: None,
+            reduce_only: false,
+            origin: Default::default(),
         }
     }
 
@@ -217,7 +222,16 @@ mod tests {
         let validator = PositionSizeValidator::new(PositionSizeConfig::default());
 
         let proposal = create_test_proposal("BTC/USD", OrderSide::Sell, dec!(50000), dec!(10.0));
-        let portfolio = Portfolio::new();
+        let mut portfolio = Portfolio::new();
+        portfolio.positions.insert(
+            "BTC/USD".to_string(),
+            Position {
+                symbol: "BTC/USD".to_string(),
+                quantity: dec!(10.0),
+                average_price: dec!(50000),
+                lots: VecDeque::new(),
+            },
+        );
         let prices = HashMap::new();
         let risk_state = RiskState::default();
 
@@ -235,9 +249,26 @@ mod tests {
             None, // recent_candles
         );
 
-        // Sell orders should always be approved (they reduce exposure)
+        // Selling a held long reduces exposure and is always approved
         let result = validator.validate(&ctx).await;
         assert!(result.is_approved());
+
+        // The same sell while flat opens a short and is sized like any entry
+        let flat = Portfolio::new();
+        let ctx = ValidationContext::new(
+            &proposal,
+            &flat,
+            dec!(100000),
+            &prices,
+            &risk_state,
+            None,
+            None,
+            None,
+            Decimal::ZERO,
+            dec!(100000),
+            None, // recent_candles
+        );
+        assert!(!validator.validate(&ctx).await.is_approved());
     }
 
     #[tokio::test]
@@ -341,6 +372,7 @@ mod tests {
                 symbol: "BTC/USD".to_string(),
                 quantity: dec!(0.1), // Already own 0.1 BTC
                 average_price: dec!(48000),
+                lots: VecDeque::new(),
             },
         );
 
@@ -383,6 +415,7 @@ mod tests {
                 symbol: "BTC/USD".to_string(),
                 quantity: dec!(0.1), // Already own 0.1 BTC
                 average_price: dec!(48000),
+                lots: VecDeque::new(),
             },
         );
 
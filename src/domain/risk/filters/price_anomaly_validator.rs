@@ -131,7 +131,7 @@ impl RiskValidator for PriceAnomalyValidator {
 
         // Check deviation
         if let Some(reason) = self.check_price_deviation(ctx.proposal.price, sma) {
-            return ValidationResult::Reject(reason);
+            return ValidationResult::reject(self.name(), reason);
         }
 
         ValidationResult::Approve
@@ -182,6 +182,8 @@ mod tests {
             timestamp: 0,
             stop_loss: None,
             take_profit: None,
+            reduce_only: false,
+            origin: Default::default(),
         }
     }
 
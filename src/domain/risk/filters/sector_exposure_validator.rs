@@ -7,7 +7,6 @@ use crate::domain::ports::SectorProvider;
 use crate::domain::risk::filters::validator_trait::{
     RiskValidator, ValidationContext, ValidationResult,
 };
-use crate::domain::trading::types::OrderSide;
 
 use rust_decimal_macros::dec;
 
@@ -88,8 +87,8 @@ impl RiskValidator for SectorExposureValidator {
     }
 
     async fn validate(&self, ctx: &ValidationContext<'_>) -> ValidationResult {
-        // Only Buy orders increase exposure
-        if !matches!(ctx.proposal.side, OrderSide::Buy) {
+        // Only entries (long or short) increase exposure
+        if !ctx.is_entry() {
             return ValidationResult::Approve;
         }
 
@@ -122,7 +121,7 @@ impl RiskValidator for SectorExposureValidator {
                     .get(sym)
                     .cloned()
                     .unwrap_or(position.average_price);
-                current_sector_value += price * position.quantity;
+                current_sector_value += price * position.quantity.abs();
             }
         }
 
@@ -134,13 +133,16 @@ impl RiskValidator for SectorExposureValidator {
         let new_sector_pct = new_sector_value / ctx.current_equity;
 
         if new_sector_pct > self.config.max_sector_exposure_pct {
-            return ValidationResult::Reject(format!(
-                "Sector exposure limit exceeded for {}. Sector: {}, New Exposure: {}% (Limit: {}%)",
-                ctx.proposal.symbol,
-                target_sector,
-                new_sector_pct * dec!(100),
-                self.config.max_sector_exposure_pct * dec!(100)
-            ));
+            return ValidationResult::reject(
+                self.name(),
+                format!(
+                    "Sector exposure limit exceeded for {}. Sector: {}, New Exposure: {}% (Limit: {}%)",
+                    ctx.proposal.symbol,
+                    target_sector,
+                    new_sector_pct * dec!(100),
+                    self.config.max_sector_exposure_pct * dec!(100)
+                ),
+            );
         }
 
         ValidationResult::Approve
@@ -156,9 +158,10 @@ mod tests {
     use super::*;
     use crate::domain::risk::state::RiskState;
     use crate::domain::trading::portfolio::{Portfolio, Position};
-    use crate::domain::trading::types::{OrderType, TradeProposal};
+    use crate::domain::trading::types::{OrderSide, OrderType, TradeProposal};
     use rust_decimal_macros::dec;
     use std::collections::HashMap;
+    use std::collections::VecDeque;
 
     // Mock provider
     struct MockSectorProvider {
@@ -187,6 +190,8 @@ mod tests {
             timestamp: 0,
             stop_loss: None,
             take_profit: None,
+            reduce_only: false,
+            origin: Default::default(),
         }
     }
 
@@ -287,6 +292,7 @@ mod tests {
                 symbol: "MSFT".to_string(),
                 quantity: dec!(25),
                 average_price: dec!(100),
+                lots: VecDeque::new(),
             },
         );
 
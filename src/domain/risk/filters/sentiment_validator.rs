@@ -47,8 +47,8 @@ impl RiskValidator for SentimentValidator {
             None => return ValidationResult::Approve,
         };
 
-        // Only validate Buy orders
-        if !matches!(ctx.proposal.side, OrderSide::Buy) {
+        // Only validate long entries
+        if !matches!(ctx.proposal.side, OrderSide::Buy) || !ctx.is_entry() {
             return ValidationResult::Approve;
         }
 
@@ -56,18 +56,24 @@ impl RiskValidator for SentimentValidator {
         if self.config.block_buys_on_extreme_fear
             && sentiment.classification == SentimentClassification::ExtremeFear
         {
-            return ValidationResult::Reject(format!(
-                "Market Sentiment is Extreme Fear ({}) - logic blocked via config",
-                sentiment.value
-            ));
+            return ValidationResult::reject(
+                self.name(),
+                format!(
+                    "Market Sentiment is Extreme Fear ({}) - logic blocked via config",
+                    sentiment.value
+                ),
+            );
         }
 
         // Rule 2: Minimum Score for Longs
         if sentiment.value < self.config.min_score_for_longs {
-            return ValidationResult::Reject(format!(
-                "Market Sentiment Score {} is below minimum required for longs ({})",
-                sentiment.value, self.config.min_score_for_longs
-            ));
+            return ValidationResult::reject(
+                self.name(),
+                format!(
+                    "Market Sentiment Score {} is below minimum required for longs ({})",
+                    sentiment.value, self.config.min_score_for_longs
+                ),
+            );
         }
 
         ValidationResult::Approve
@@ -100,6 +106,8 @@ mod tests {
             timestamp: 0,
             stop_loss: None,
             take_profit: None,
+            reduce_only: false,
+            origin: Default::default(),
         }
     }
 
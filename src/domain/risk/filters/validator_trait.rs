@@ -12,11 +12,20 @@ use crate::domain::trading::types::{Candle, TradeProposal};
 pub enum ValidationResult {
     /// Validation passed, trade can proceed
     Approve,
-    /// Validation failed, trade should be rejected with a reason
-    Reject(String),
+    /// Validation failed: `validator` is the name of the rejecting validator and
+    /// `reason` a human-readable explanation
+    Reject { validator: String, reason: String },
 }
 
 impl ValidationResult {
+    /// Rejection issued by the validator named `validator`
+    pub fn reject(validator: &str, reason: impl Into<String>) -> Self {
+        ValidationResult::Reject {
+            validator: validator.to_string(),
+            reason: reason.into(),
+        }
+    }
+
     /// Check if the result is approval
     pub fn is_approved(&self) -> bool {
         matches!(self, ValidationResult::Approve)
@@ -24,13 +33,21 @@ impl ValidationResult {
 
     /// Check if the result is rejection
     pub fn is_rejected(&self) -> bool {
-        matches!(self, ValidationResult::Reject(_))
+        matches!(self, ValidationResult::Reject { .. })
     }
 
     /// Get rejection reason if rejected
     pub fn rejection_reason(&self) -> Option<&str> {
         match self {
-            ValidationResult::Reject(reason) => Some(reason),
+            ValidationResult::Reject { reason, .. } => Some(reason),
+            ValidationResult::Approve => None,
+        }
+    }
+
+    /// Get the name of the rejecting validator if rejected
+    pub fn rejected_by(&self) -> Option<&str> {
+        match self {
+            ValidationResult::Reject { validator, .. } => Some(validator),
             ValidationResult::Approve => None,
         }
     }
@@ -74,6 +91,9 @@ pub struct ValidationContext<'a> {
 
     /// Recent candles for the proposal's symbol (optional, for price anomaly detection)
     pub recent_candles: Option<&'a [Candle]>,
+
+    /// Broker's maximum leverage for the proposal's symbol (None = not published)
+    pub symbol_max_leverage: Option<Decimal>,
 }
 
 impl<'a> ValidationContext<'a> {
@@ -104,9 +124,15 @@ impl<'a> ValidationContext<'a> {
             symbol_pending_exposure,
             available_cash,
             recent_candles,
+            symbol_max_leverage: None,
         }
     }
 
+    pub fn with_symbol_max_leverage(mut self, max_leverage: Option<Decimal>) -> Self {
+        self.symbol_max_leverage = max_leverage;
+        self
+    }
+
     /// Get the current price for the proposal's symbol
     pub fn get_proposal_price(&self) -> Decimal {
         self.current_prices
@@ -128,6 +154,12 @@ impl<'a> ValidationContext<'a> {
             .map(|p| p.quantity)
             .unwrap_or(Decimal::ZERO)
     }
+
+    /// Whether the proposal opens or adds to a long or short rather than reducing one
+    pub fn is_entry(&self) -> bool {
+        self.proposal
+            .increases_exposure(self.get_current_position_qty())
+    }
 }
 
 /// Trait for all risk validators
@@ -143,7 +175,7 @@ pub trait RiskValidator: Send + Sync {
     ///
     /// Returns:
     /// - `ValidationResult::Approve` if the trade passes this validator's checks
-    /// - `ValidationResult::Reject(reason)` if the trade should be blocked
+    /// - `ValidationResult::reject(self.name(), reason)` if the trade should be blocked
     async fn validate(&self, ctx: &ValidationContext<'_>) -> ValidationResult;
 
     /// Whether this validator is currently enabled
@@ -174,24 +206,34 @@ mod tests {
     #[test]
     fn test_validation_result_is_approved() {
         assert!(ValidationResult::Approve.is_approved());
-        assert!(!ValidationResult::Reject("test".to_string()).is_approved());
+        assert!(!ValidationResult::reject("Test", "test").is_approved());
     }
 
     #[test]
     fn test_validation_result_is_rejected() {
         assert!(!ValidationResult::Approve.is_rejected());
-        assert!(ValidationResult::Reject("test".to_string()).is_rejected());
+        assert!(ValidationResult::reject("Test", "test").is_rejected());
     }
 
     #[test]
     fn test_validation_result_rejection_reason() {
         assert_eq!(ValidationResult::Approve.rejection_reason(), None);
         assert_eq!(
-            ValidationResult::Reject("insufficient funds".to_string()).rejection_reason(),
+            ValidationResult::reject("BuyingPowerValidator", "insufficient funds")
+                .rejection_reason(),
             Some("insufficient funds")
         );
     }
 
+    #[test]
+    fn test_validation_result_rejected_by() {
+        assert_eq!(ValidationResult::Approve.rejected_by(), None);
+        assert_eq!(
+            ValidationResult::reject("BuyingPowerValidator", "insufficient funds").rejected_by(),
+            Some("BuyingPowerValidator")
+        );
+    }
+
     #[test]
     fn test_validation_context_get_proposal_price() {
         use crate::domain::trading::types::{OrderSide, OrderType};
@@ -206,6 +248,8 @@ mod tests {
             timestamp: 0,
             stop_loss: None,
             take_profit: None,
+            reduce_only: false,
+            origin: Default::default(),
         };
 
         let portfolio = Portfolio::new();
@@ -246,6 +290,8 @@ mod tests {
             timestamp: 0,
             stop_loss: None,
             take_profit: None,
+            reduce_only: false,
+            origin: Default::default(),
         };
 
         let portfolio = Portfolio::new();
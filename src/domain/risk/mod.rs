@@ -1,7 +1,9 @@
 // Risk management domain
+pub mod earnings_calendar;
 pub mod filters;
 pub mod optimal_parameters;
 pub mod risk_appetite;
 pub mod risk_config;
+pub mod session_boundary;
 pub mod state;
 pub mod volatility_manager;
@@ -193,6 +193,25 @@ impl OptimalParametersSet {
             .find(|p| p.asset_type == asset_type && p.risk_score == Some(score))
             .or_else(|| self.get_by_type(asset_type, profile))
     }
+
+    /// Highest-Sharpe parameters of `asset_type` for a risk level. Precedence: entries stored
+    /// with the exact `risk_score` (when given), then any entry of `risk_profile`, then none.
+    pub fn get_best(
+        &self,
+        asset_type: AssetType,
+        risk_profile: RiskProfile,
+        risk_score: Option<u8>,
+    ) -> Option<&OptimalParameters> {
+        let best = |matches: &dyn Fn(&OptimalParameters) -> bool| {
+            self.parameters
+                .iter()
+                .filter(|p| p.asset_type == asset_type && matches(p))
+                .max_by(|a, b| a.sharpe_ratio.cmp(&b.sharpe_ratio))
+        };
+        risk_score
+            .and_then(|score| best(&|p| p.risk_score == Some(score)))
+            .or_else(|| best(&|p| p.risk_profile == risk_profile))
+    }
 }
 
 /// Maps risk score 1-9 to RiskProfile (1-3 Conservative, 4-6 Balanced, 7-9 Aggressive).
@@ -346,4 +365,55 @@ mod tests {
                 .is_none()
         );
     }
+
+    #[test]
+    fn test_get_best_precedence() {
+        let stock = |sharpe| {
+            OptimalParameters::new(
+                AssetType::Stock,
+                RiskProfile::Balanced,
+                20,
+                60,
+                dec!(65.0),
+                dec!(3.0),
+                dec!(0.005),
+                300,
+                "AAPL".to_string(),
+                sharpe,
+                dec!(12.0),
+                dec!(5.0),
+                dec!(55.0),
+                40,
+            )
+        };
+        let mut set = OptimalParametersSet::new();
+        set.upsert(stock(dec!(1.5)));
+        set.upsert(stock(dec!(1.0)).with_risk_score(5));
+        set.upsert(stock(dec!(2.0)).with_risk_score(6));
+
+        // An exact score wins even over a better profile entry
+        let exact = set
+            .get_best(AssetType::Stock, RiskProfile::Balanced, Some(5))
+            .unwrap();
+        assert_eq!(exact.risk_score, Some(5));
+
+        // Otherwise the best-scoring entry of the profile
+        let fallback = set
+            .get_best(AssetType::Stock, RiskProfile::Balanced, Some(4))
+            .unwrap();
+        assert_eq!(fallback.sharpe_ratio, dec!(2.0));
+        let by_profile = set
+            .get_best(AssetType::Stock, RiskProfile::Balanced, None)
+            .unwrap();
+        assert_eq!(by_profile.sharpe_ratio, dec!(2.0));
+
+        assert!(
+            set.get_best(AssetType::Stock, RiskProfile::Conservative, Some(2))
+                .is_none()
+        );
+        assert!(
+            set.get_best(AssetType::Crypto, RiskProfile::Balanced, Some(5))
+                .is_none()
+        );
+    }
 }
@@ -1,5 +1,8 @@
-use crate::domain::ports::SectorProvider;
+use crate::config::AssetClass;
+use crate::domain::ports::{EarningsCalendarProvider, SectorProvider};
 use crate::domain::risk::filters::correlation_filter::CorrelationFilterConfig;
+use crate::domain::risk::filters::min_notional_validator::MinNotionalConfig;
+use crate::domain::risk::session_boundary::TradingDayBoundary;
 use crate::domain::risk::volatility_manager::VolatilityConfig;
 use rust_decimal::Decimal;
 use rust_decimal_macros::dec;
@@ -21,11 +24,23 @@ pub struct RiskConfig {
     pub consecutive_loss_limit: usize,  // Max consecutive losing trades before halt
     pub valuation_interval_seconds: u64, // Interval for portfolio valuation check
     pub max_sector_exposure_pct: Decimal, // Max exposure per sector
+    pub max_gross_exposure_pct: Decimal, // Max total position value vs equity (0 = disabled)
     pub sector_provider: Option<Arc<dyn SectorProvider>>,
     pub allow_pdt_risk: bool, // If true, allows opening orders even if PDT saturated (Risky!)
     pub pending_order_ttl_ms: Option<i64>, // TTL for pending orders filled but not synced
     pub correlation_config: CorrelationFilterConfig,
-    pub volatility_config: VolatilityConfig, // Added
+    pub volatility_config: VolatilityConfig,     // Added
+    pub daily_reset: Option<TradingDayBoundary>, // Start of the trading day (None = asset-class default)
+    pub allow_average_down: bool, // If true, buys into a losing position are not blocked
+    pub max_average_down_loss_pct: Decimal, // Unrealized loss beyond which adding to a position is blocked
+    pub min_order_notional: Decimal,        // Smallest accepted buy value (0 = disabled)
+    pub allow_min_notional_bump: bool, // If true, undersized buys are raised to min_order_notional
+    pub allow_shorts: bool,            // If true, sells may open short positions (cash-covered)
+    pub leverage: Decimal,             // Account leverage for buying power (1 = cash account)
+    pub max_open_orders: usize, // Open broker orders at which new entries are rejected (0 = unlimited)
+    pub earnings_provider: Option<Arc<dyn EarningsCalendarProvider>>,
+    pub earnings_blackout_days: u32, // Entries blocked this many days before earnings (0 = disabled)
+    pub earnings_flatten: bool,      // If true, positions are closed once inside the blackout
 }
 
 impl std::fmt::Debug for RiskConfig {
@@ -40,15 +55,39 @@ impl std::fmt::Debug for RiskConfig {
                 &self.valuation_interval_seconds,
             )
             .field("max_sector_exposure_pct", &self.max_sector_exposure_pct)
+            .field("max_gross_exposure_pct", &self.max_gross_exposure_pct)
             .field("allow_pdt_risk", &self.allow_pdt_risk)
             .field("pending_order_ttl_ms", &self.pending_order_ttl_ms)
             .field("correlation_config", &self.correlation_config)
             .field("volatility_config", &self.volatility_config)
+            .field("daily_reset", &self.daily_reset)
+            .field("allow_average_down", &self.allow_average_down)
+            .field("max_average_down_loss_pct", &self.max_average_down_loss_pct)
+            .field("min_order_notional", &self.min_order_notional)
+            .field("allow_min_notional_bump", &self.allow_min_notional_bump)
+            .field("allow_shorts", &self.allow_shorts)
+            .field("leverage", &self.leverage)
+            .field("max_open_orders", &self.max_open_orders)
+            .field("earnings_blackout_days", &self.earnings_blackout_days)
+            .field("earnings_flatten", &self.earnings_flatten)
             .finish()
     }
 }
 
 impl RiskConfig {
+    /// Configured start of the trading day, or the asset-class default
+    pub fn trading_day_boundary(&self, asset_class: AssetClass) -> TradingDayBoundary {
+        self.daily_reset
+            .unwrap_or_else(|| TradingDayBoundary::for_asset_class(asset_class))
+    }
+
+    pub fn min_notional_config(&self) -> MinNotionalConfig {
+        MinNotionalConfig {
+            min_notional: self.min_order_notional,
+            allow_bump: self.allow_min_notional_bump,
+        }
+    }
+
     pub fn validate(&self) -> Result<(), String> {
         if self.max_position_size_pct <= Decimal::ZERO || self.max_position_size_pct > Decimal::ONE
         {
@@ -80,6 +119,32 @@ impl RiskConfig {
                 self.max_sector_exposure_pct
             ));
         }
+        if self.max_gross_exposure_pct < Decimal::ZERO {
+            return Err(format!(
+                "Invalid max_gross_exposure_pct: {}",
+                self.max_gross_exposure_pct
+            ));
+        }
+        if self.max_average_down_loss_pct < Decimal::ZERO
+            || self.max_average_down_loss_pct > Decimal::ONE
+        {
+            return Err(format!(
+                "Invalid max_average_down_loss_pct: {}",
+                self.max_average_down_loss_pct
+            ));
+        }
+        if self.min_order_notional < Decimal::ZERO {
+            return Err(format!(
+                "Invalid min_order_notional: {}",
+                self.min_order_notional
+            ));
+        }
+        if self.leverage < Decimal::ONE {
+            return Err(format!(
+                "Invalid leverage: {} (must be >= 1)",
+                self.leverage
+            ));
+        }
         Ok(())
     }
 }
@@ -93,12 +158,24 @@ impl Default for RiskConfig {
             consecutive_loss_limit: 3,
             valuation_interval_seconds: 60,
             max_sector_exposure_pct: dec!(0.20), // Reduced from 0.30
+            max_gross_exposure_pct: Decimal::ZERO,
 
             sector_provider: None,
             allow_pdt_risk: false,
             pending_order_ttl_ms: None, // Default 5 mins
             correlation_config: CorrelationFilterConfig::default(),
             volatility_config: VolatilityConfig::default(),
+            daily_reset: None,
+            allow_average_down: false,
+            max_average_down_loss_pct: dec!(0.02),
+            min_order_notional: Decimal::ZERO,
+            allow_min_notional_bump: false,
+            allow_shorts: false,
+            leverage: Decimal::ONE,
+            max_open_orders: 0,
+            earnings_provider: None,
+            earnings_blackout_days: 0,
+            earnings_flatten: false,
         }
     }
 }
@@ -113,11 +190,23 @@ impl RiskConfig {
             consecutive_loss_limit: 6,
             valuation_interval_seconds: 60,
             max_sector_exposure_pct: dec!(0.20),
+            max_gross_exposure_pct: Decimal::ZERO,
             sector_provider: None,
             allow_pdt_risk: false,
             pending_order_ttl_ms: None,
             correlation_config: CorrelationFilterConfig::default(),
             volatility_config: VolatilityConfig::default(),
+            daily_reset: None,
+            allow_average_down: false,
+            max_average_down_loss_pct: dec!(0.02),
+            min_order_notional: Decimal::ZERO,
+            allow_min_notional_bump: false,
+            allow_shorts: false,
+            leverage: Decimal::ONE,
+            max_open_orders: 0,
+            earnings_provider: None,
+            earnings_blackout_days: 0,
+            earnings_flatten: false,
         }
     }
 }
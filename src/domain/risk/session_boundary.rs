@@ -0,0 +1,143 @@
+//! Trading-day boundary for daily risk limits.
+//!
+//! The daily-loss baseline restarts when the clock crosses the boundary. The
+//! trading day an instant belongs to is the local date in the boundary's
+//! timezone, shifted back by one day before the start time.
+//!
+//! Accepted textual format: `HH:MM[@Timezone]` (IANA timezone, UTC when omitted),
+//! e.g. `00:00@America/New_York` or `17:00@America/New_York` for an FX-style roll.
+
+use crate::config::AssetClass;
+use anyhow::{Context, Result, anyhow};
+use chrono::{DateTime, Days, NaiveDate, NaiveTime, Utc};
+use chrono_tz::Tz;
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use std::str::FromStr;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TradingDayBoundary {
+    pub timezone: Tz,
+    /// Local time at which a new trading day starts
+    pub start: NaiveTime,
+}
+
+impl TradingDayBoundary {
+    /// Calendar day in UTC (24/7 crypto markets).
+    pub fn utc_midnight() -> Self {
+        Self {
+            timezone: Tz::UTC,
+            start: NaiveTime::MIN,
+        }
+    }
+
+    /// Calendar day in New York, so pre-market, regular and post-market sessions share a day.
+    pub fn us_equities() -> Self {
+        Self {
+            timezone: chrono_tz::America::New_York,
+            start: NaiveTime::MIN,
+        }
+    }
+
+    /// Default boundary when none is configured.
+    pub fn for_asset_class(asset_class: AssetClass) -> Self {
+        match asset_class {
+            AssetClass::Stock => Self::us_equities(),
+            AssetClass::Crypto => Self::utc_midnight(),
+        }
+    }
+
+    /// Trading day `now` belongs to.
+    pub fn trading_day(&self, now: DateTime<Utc>) -> NaiveDate {
+        let local = now.with_timezone(&self.timezone);
+        let date = local.date_naive();
+        if local.time() < self.start {
+            date.checked_sub_days(Days::new(1)).unwrap_or(date)
+        } else {
+            date
+        }
+    }
+
+    /// Trading day of a millisecond timestamp (candle / order time).
+    pub fn trading_day_at_ms(&self, timestamp_ms: i64) -> NaiveDate {
+        self.trading_day(DateTime::from_timestamp_millis(timestamp_ms).unwrap_or_default())
+    }
+}
+
+impl Default for TradingDayBoundary {
+    fn default() -> Self {
+        Self::utc_midnight()
+    }
+}
+
+impl FromStr for TradingDayBoundary {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (time, timezone) = match s.trim().split_once('@') {
+            Some((time, tz)) => (
+                time,
+                tz.trim()
+                    .parse::<Tz>()
+                    .map_err(|e| anyhow!("Invalid timezone '{}': {}", tz.trim(), e))?,
+            ),
+            None => (s.trim(), Tz::UTC),
+        };
+        let start = NaiveTime::parse_from_str(time.trim(), "%H:%M")
+            .with_context(|| format!("Invalid time '{}', expected HH:MM", time.trim()))?;
+        Ok(Self { timezone, start })
+    }
+}
+
+impl fmt::Display for TradingDayBoundary {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}@{}", self.start.format("%H:%M"), self.timezone)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    fn utc(y: i32, mo: u32, d: u32, h: u32, mi: u32) -> DateTime<Utc> {
+        Utc.with_ymd_and_hms(y, mo, d, h, mi, 0).unwrap()
+    }
+
+    fn date(y: i32, mo: u32, d: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(y, mo, d).unwrap()
+    }
+
+    #[test]
+    fn test_trading_day_per_asset_class() {
+        // 02:00 UTC on Jan 15 is still Jan 14 in New York (EST, UTC-5)
+        let now = utc(2026, 1, 15, 2, 0);
+        assert_eq!(
+            TradingDayBoundary::for_asset_class(AssetClass::Crypto).trading_day(now),
+            date(2026, 1, 15)
+        );
+        assert_eq!(
+            TradingDayBoundary::for_asset_class(AssetClass::Stock).trading_day(now),
+            date(2026, 1, 14)
+        );
+    }
+
+    #[test]
+    fn test_custom_start_time_rolls_the_day() {
+        let boundary: TradingDayBoundary = "17:00@America/New_York".parse().unwrap();
+        assert_eq!(boundary.to_string(), "17:00@America/New_York");
+
+        // 16:59 and 17:00 New York (EDT, UTC-4) fall on either side of the roll
+        assert_eq!(
+            boundary.trading_day(utc(2026, 6, 10, 20, 59)),
+            date(2026, 6, 9)
+        );
+        assert_eq!(
+            boundary.trading_day(utc(2026, 6, 10, 21, 0)),
+            date(2026, 6, 10)
+        );
+
+        assert!("25:00".parse::<TradingDayBoundary>().is_err());
+        assert!("09:30@Mars/Olympus".parse::<TradingDayBoundary>().is_err());
+    }
+}
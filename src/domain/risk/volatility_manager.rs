@@ -3,7 +3,7 @@ use rust_decimal_macros::dec;
 use std::collections::VecDeque;
 
 /// Configuration for the Volatility Manager
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq)]
 pub struct VolatilityConfig {
     /// Number of periods to calculate average volatility (e.g., 20)
     pub lookback_period: usize,
@@ -81,6 +81,27 @@ impl VolatilityManager {
         multiplier.clamp(self.config.min_multiplier, self.config.max_multiplier)
     }
 
+    /// Calculate the trailing-stop width multiplier
+    ///
+    /// Inverse of the sizing multiplier: stops widen when current volatility runs above
+    /// its average (noise would stop the position out) and tighten when it is calm.
+    /// Bounds are passed by the caller so they follow live config reloads.
+    ///
+    /// Formula: Multiplier = (Current / Baseline) * Scale, clamped to [min, max]
+    pub fn calculate_stop_multiplier(
+        &self,
+        current_volatility: Decimal,
+        min: Decimal,
+        max: Decimal,
+    ) -> Decimal {
+        let avg_volatility = self.get_average_volatility();
+        if current_volatility <= Decimal::ZERO || avg_volatility <= Decimal::ZERO {
+            return Decimal::ONE;
+        }
+
+        (current_volatility / avg_volatility * self.config.scaling_factor).clamp(min, max)
+    }
+
     /// Helper to get current average volatility
     pub fn get_average_volatility(&self) -> Decimal {
         if self.history.is_empty() {
@@ -129,6 +150,38 @@ mod tests {
         assert_eq!(multiplier, dec!(1.5));
     }
 
+    #[test]
+    fn test_stop_multiplier_widens_in_high_volatility() {
+        let mut manager = VolatilityManager::new(VolatilityConfig::default());
+        for _ in 0..20 {
+            manager.update(dec!(2.0));
+        }
+
+        // ATR 1.5x its average: stops widen, capped at max
+        assert_eq!(
+            manager.calculate_stop_multiplier(dec!(3.0), dec!(0.75), dec!(1.5)),
+            dec!(1.5)
+        );
+        assert_eq!(
+            manager.calculate_stop_multiplier(dec!(6.0), dec!(0.75), dec!(1.5)),
+            dec!(1.5)
+        );
+        // Calm: tighter, floored at min
+        assert_eq!(
+            manager.calculate_stop_multiplier(dec!(1.6), dec!(0.75), dec!(1.5)),
+            dec!(0.8)
+        );
+        assert_eq!(
+            manager.calculate_stop_multiplier(dec!(0.5), dec!(0.75), dec!(1.5)),
+            dec!(0.75)
+        );
+        // Bounds of 1/1 keep the configured multiplier
+        assert_eq!(
+            manager.calculate_stop_multiplier(dec!(6.0), Decimal::ONE, Decimal::ONE),
+            Decimal::ONE
+        );
+    }
+
     #[test]
     fn test_normal_volatility() {
         let config = VolatilityConfig::default();
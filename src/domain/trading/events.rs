@@ -1,5 +1,98 @@
 use crate::domain::trading::types::{Order, OrderSide, TradeProposal};
 use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+
+/// Step of the signal-to-fill funnel recorded by a [`DecisionEvent`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum DecisionKind {
+    /// Strategy emitted a signal
+    SignalGenerated,
+    /// Signal dropped by an Analyst filter before becoming a proposal
+    SignalFiltered,
+    /// Proposal handed to the Risk Manager
+    ProposalSent,
+    /// Proposal passed risk checks and was turned into an order
+    ProposalApproved,
+    /// Proposal refused by the Risk Manager
+    ProposalRejected,
+    /// Order accepted by the broker
+    OrderSubmitted,
+    /// Order refused by the broker or never reached it
+    OrderRejected,
+    /// Order filled
+    OrderFilled,
+}
+
+/// One auditable trading decision (persisted as a JSONL line by the decision log)
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct DecisionEvent {
+    /// Milliseconds since epoch
+    pub timestamp: i64,
+    pub kind: DecisionKind,
+    pub symbol: String,
+    pub side: OrderSide,
+    pub reason: String,
+    /// Filter or risk validator that blocked the signal/proposal
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub blocked_by: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub quantity: Option<Decimal>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub price: Option<Decimal>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub order_id: Option<String>,
+}
+
+impl DecisionEvent {
+    pub fn new(
+        kind: DecisionKind,
+        symbol: &str,
+        side: OrderSide,
+        reason: impl Into<String>,
+    ) -> Self {
+        Self {
+            timestamp: chrono::Utc::now().timestamp_millis(),
+            kind,
+            symbol: symbol.to_string(),
+            side,
+            reason: reason.into(),
+            blocked_by: None,
+            quantity: None,
+            price: None,
+            order_id: None,
+        }
+    }
+
+    /// Decision about a proposal, carrying its quantity and price
+    pub fn for_proposal(
+        kind: DecisionKind,
+        proposal: &TradeProposal,
+        reason: impl Into<String>,
+    ) -> Self {
+        Self::new(kind, &proposal.symbol, proposal.side, reason)
+            .with_size(proposal.quantity, proposal.price)
+    }
+
+    /// Decision about an order, carrying its id, quantity and price
+    pub fn for_order(kind: DecisionKind, order: &Order, reason: impl Into<String>) -> Self {
+        let mut event = Self::new(kind, &order.symbol, order.side, reason)
+            .with_size(order.quantity, order.price);
+        event.order_id = Some(order.id.clone());
+        event
+    }
+
+    pub fn with_blocked_by(mut self, blocked_by: impl Into<String>) -> Self {
+        self.blocked_by = Some(blocked_by.into());
+        self
+    }
+
+    pub fn with_size(mut self, quantity: Decimal, price: Decimal) -> Self {
+        self.quantity = Some(quantity);
+        self.price = Some(price);
+        self
+    }
+}
 
 /// Trading events for observability and monitoring
 #[derive(Debug, Clone)]
@@ -55,6 +148,9 @@ pub enum TradingEvent {
         reason: String,
         cooldown_seconds: u64,
     },
+
+    /// Audit record of a trading decision
+    Decision(DecisionEvent),
 }
 
 /// Event listener trait for Observer pattern
@@ -143,6 +239,12 @@ impl EventListener for LoggingListener {
                     reason, cooldown_seconds
                 );
             }
+            TradingEvent::Decision(decision) => {
+                debug!(
+                    "🧾 Decision: {:?} {:?} {} - {}",
+                    decision.kind, decision.side, decision.symbol, decision.reason
+                );
+            }
         }
     }
 }
@@ -194,4 +296,24 @@ mod tests {
             listener.on_event(&event);
         }
     }
+
+    #[test]
+    fn test_decision_event_json_roundtrip() {
+        let event = DecisionEvent::new(
+            DecisionKind::ProposalRejected,
+            "AAPL",
+            OrderSide::Buy,
+            "Insufficient buying power",
+        )
+        .with_blocked_by("BuyingPowerValidator")
+        .with_size(dec!(10), dec!(150));
+
+        let json = serde_json::to_string(&event).unwrap();
+        assert!(json.contains("\"kind\":\"proposal_rejected\""));
+        assert!(json.contains("\"blocked_by\":\"BuyingPowerValidator\""));
+        assert!(!json.contains("order_id"));
+
+        let parsed: DecisionEvent = serde_json::from_str(&json).unwrap();
+        assert_eq!(parsed, event);
+    }
 }
@@ -1,6 +1,9 @@
-use crate::domain::trading::types::OrderSide;
+use crate::domain::trading::time::MS_PER_DAY;
+use crate::domain::trading::types::{OrderSide, OrderType};
 use rust_decimal::Decimal;
+use std::collections::{HashMap, VecDeque};
 use std::fmt::Debug;
+use std::sync::{Arc, Mutex};
 
 #[derive(Debug, Clone, PartialEq)]
 pub struct TradeCost {
@@ -25,10 +28,152 @@ pub trait FeeModel: Debug + Send + Sync {
         Decimal::ZERO
     }
 
+    /// Calculate estimated cost for a trade on a specific symbol.
+    /// Models without per-symbol pricing fall back to `calculate_cost`.
+    fn calculate_symbol_cost(
+        &self,
+        symbol: &str,
+        quantity: Decimal,
+        price: Decimal,
+        side: OrderSide,
+    ) -> TradeCost {
+        let _ = symbol;
+        self.calculate_cost(quantity, price, side)
+    }
+
+    /// Calculate estimated cost for an order of the given type on a specific symbol.
+    /// Models without separate maker/taker rates fall back to `calculate_symbol_cost`.
+    fn calculate_order_cost(
+        &self,
+        symbol: &str,
+        quantity: Decimal,
+        price: Decimal,
+        side: OrderSide,
+        order_type: OrderType,
+    ) -> TradeCost {
+        let _ = order_type;
+        self.calculate_symbol_cost(symbol, quantity, price, side)
+    }
+
+    /// Record an executed fill so volume-based models can update the account's fee tier
+    fn record_fill(&self, quantity: Decimal, price: Decimal, timestamp_ms: i64) {
+        let _ = (quantity, price, timestamp_ms); // default no-op
+    }
+
+    /// Symbol-specific spread (bps) replacing the global default, if any
+    fn spread_bps_for(&self, symbol: &str) -> Option<Decimal> {
+        let _ = symbol;
+        None
+    }
+
     /// Get description of the fee model
     fn description(&self) -> String;
 }
 
+/// Per-symbol cost parameters. Unset fields fall back to the global defaults.
+///
+/// `commission` is the per-share commission for stocks and the taker fee rate for crypto.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct SymbolCostOverride {
+    pub commission: Option<Decimal>,
+    pub slippage_pct: Option<Decimal>,
+    pub spread_bps: Option<Decimal>,
+}
+
+/// Fee model applying per-symbol overrides on top of a default model
+#[derive(Debug, Clone)]
+pub struct PerSymbolFeeModel {
+    default_model: Arc<dyn FeeModel>,
+    models: HashMap<String, Arc<dyn FeeModel>>,
+    spreads_bps: HashMap<String, Decimal>,
+}
+
+impl PerSymbolFeeModel {
+    pub fn new(default_model: Arc<dyn FeeModel>) -> Self {
+        Self {
+            default_model,
+            models: HashMap::new(),
+            spreads_bps: HashMap::new(),
+        }
+    }
+
+    /// Use a dedicated fee model for `symbol`
+    pub fn with_symbol_model(
+        mut self,
+        symbol: impl Into<String>,
+        model: Arc<dyn FeeModel>,
+    ) -> Self {
+        self.models.insert(symbol.into(), model);
+        self
+    }
+
+    /// Use a dedicated default spread (bps) for `symbol`
+    pub fn with_symbol_spread(mut self, symbol: impl Into<String>, spread_bps: Decimal) -> Self {
+        self.spreads_bps.insert(symbol.into(), spread_bps);
+        self
+    }
+
+    fn model_for(&self, symbol: &str) -> &Arc<dyn FeeModel> {
+        self.models.get(symbol).unwrap_or(&self.default_model)
+    }
+}
+
+impl FeeModel for PerSymbolFeeModel {
+    fn calculate_cost(&self, quantity: Decimal, price: Decimal, side: OrderSide) -> TradeCost {
+        self.default_model.calculate_cost(quantity, price, side)
+    }
+
+    fn calculate_symbol_cost(
+        &self,
+        symbol: &str,
+        quantity: Decimal,
+        price: Decimal,
+        side: OrderSide,
+    ) -> TradeCost {
+        self.model_for(symbol).calculate_cost(quantity, price, side)
+    }
+
+    fn calculate_order_cost(
+        &self,
+        symbol: &str,
+        quantity: Decimal,
+        price: Decimal,
+        side: OrderSide,
+        order_type: OrderType,
+    ) -> TradeCost {
+        self.model_for(symbol)
+            .calculate_order_cost(symbol, quantity, price, side, order_type)
+    }
+
+    fn calculate_funding_cost(
+        &self,
+        quantity: Decimal,
+        price: Decimal,
+        hold_time_hours: Decimal,
+    ) -> Decimal {
+        self.default_model
+            .calculate_funding_cost(quantity, price, hold_time_hours)
+    }
+
+    /// Forwarded to the default model only; per-symbol models should share its volume tracker
+    fn record_fill(&self, quantity: Decimal, price: Decimal, timestamp_ms: i64) {
+        self.default_model
+            .record_fill(quantity, price, timestamp_ms);
+    }
+
+    fn spread_bps_for(&self, symbol: &str) -> Option<Decimal> {
+        self.spreads_bps.get(symbol).copied()
+    }
+
+    fn description(&self) -> String {
+        format!(
+            "{} + {} per-symbol override(s)",
+            self.default_model.description(),
+            self.models.len().max(self.spreads_bps.len())
+        )
+    }
+}
+
 #[derive(Debug, Clone)]
 pub struct ConstantFeeModel {
     pub commission_per_share: Decimal,
@@ -66,28 +211,126 @@ impl FeeModel for ConstantFeeModel {
     }
 }
 
-#[derive(Debug, Clone)]
-pub struct TieredFeeModel {
+/// Trailing traded notional used to select the account's fee tier.
+///
+/// The window is measured from the latest recorded fill rather than the wall clock,
+/// so simulated volume accumulates correctly across a backtest.
+#[derive(Debug, Default)]
+pub struct VolumeTracker {
+    fills: Mutex<VecDeque<(i64, Decimal)>>,
+}
+
+impl VolumeTracker {
+    /// Exchanges compute VIP levels over a rolling 30-day window
+    pub const WINDOW_MS: i64 = 30 * MS_PER_DAY;
+
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn record(&self, notional: Decimal, timestamp_ms: i64) {
+        let mut fills = self.fills.lock().unwrap_or_else(|e| e.into_inner());
+        fills.push_back((timestamp_ms, notional.abs()));
+        let latest = fills
+            .iter()
+            .map(|(ts, _)| *ts)
+            .max()
+            .unwrap_or(timestamp_ms);
+        while fills
+            .front()
+            .is_some_and(|(ts, _)| *ts <= latest - Self::WINDOW_MS)
+        {
+            fills.pop_front();
+        }
+    }
+
+    /// Traded notional over the trailing window
+    pub fn volume(&self) -> Decimal {
+        let fills = self.fills.lock().unwrap_or_else(|e| e.into_inner());
+        fills.iter().map(|(_, notional)| *notional).sum()
+    }
+}
+
+/// One VIP level of an exchange fee schedule
+#[derive(Debug, Clone, PartialEq)]
+pub struct FeeTier {
+    /// Minimum trailing 30-day volume (quote currency) to qualify
+    pub min_volume: Decimal,
     pub maker_fee_pct: Decimal,
     pub taker_fee_pct: Decimal,
+}
+
+impl FeeTier {
+    pub fn new(min_volume: Decimal, maker_fee_pct: Decimal, taker_fee_pct: Decimal) -> Self {
+        Self {
+            min_volume,
+            maker_fee_pct,
+            taker_fee_pct,
+        }
+    }
+}
+
+/// Volume-tiered maker/taker fee model (crypto exchanges).
+///
+/// The applicable tier is chosen from the trailing 30-day volume fed through
+/// [`FeeModel::record_fill`]. Clones share the same volume tracker.
+#[derive(Debug, Clone)]
+pub struct TieredFeeModel {
+    /// Sorted by ascending `min_volume`
+    tiers: Vec<FeeTier>,
     pub slippage_pct: Decimal,
+    volume: Arc<VolumeTracker>,
 }
 
 impl TieredFeeModel {
+    /// Single flat tier
     pub fn new(maker_fee_pct: Decimal, taker_fee_pct: Decimal, slippage_pct: Decimal) -> Self {
+        Self::with_tiers(
+            vec![FeeTier::new(Decimal::ZERO, maker_fee_pct, taker_fee_pct)],
+            slippage_pct,
+        )
+    }
+
+    /// Fee schedule with several volume tiers. An empty table means zero fees.
+    pub fn with_tiers(mut tiers: Vec<FeeTier>, slippage_pct: Decimal) -> Self {
+        tiers.sort_by_key(|t| t.min_volume);
         Self {
-            maker_fee_pct,
-            taker_fee_pct,
+            tiers,
             slippage_pct,
+            volume: Arc::new(VolumeTracker::new()),
         }
     }
-}
 
-impl FeeModel for TieredFeeModel {
-    fn calculate_cost(&self, quantity: Decimal, price: Decimal, _side: OrderSide) -> TradeCost {
+    /// Share a volume tracker with other models (e.g. per-symbol overrides)
+    pub fn with_volume_tracker(mut self, volume: Arc<VolumeTracker>) -> Self {
+        self.volume = volume;
+        self
+    }
+
+    pub fn volume_tracker(&self) -> Arc<VolumeTracker> {
+        self.volume.clone()
+    }
+
+    /// Tier matching the current trailing volume
+    pub fn current_tier(&self) -> Option<&FeeTier> {
+        let volume = self.volume.volume();
+        self.tiers.iter().rev().find(|t| volume >= t.min_volume)
+    }
+
+    /// Cost at the current tier's maker (resting limit order) or taker rate
+    fn cost_at(&self, quantity: Decimal, price: Decimal, maker: bool) -> TradeCost {
         let trade_value = quantity * price;
-        // Assume taker fee for now as most bot orders are market/aggressive
-        let fee = trade_value * self.taker_fee_pct;
+        let fee_pct = self
+            .current_tier()
+            .map(|t| {
+                if maker {
+                    t.maker_fee_pct
+                } else {
+                    t.taker_fee_pct
+                }
+            })
+            .unwrap_or(Decimal::ZERO);
+        let fee = trade_value * fee_pct;
         let slippage_cost = trade_value * self.slippage_pct;
 
         TradeCost {
@@ -96,12 +339,39 @@ impl FeeModel for TieredFeeModel {
             total_impact: fee + slippage_cost,
         }
     }
+}
+
+impl FeeModel for TieredFeeModel {
+    /// Taker rate: without an order type, assume an aggressive (market) order
+    fn calculate_cost(&self, quantity: Decimal, price: Decimal, _side: OrderSide) -> TradeCost {
+        self.cost_at(quantity, price, false)
+    }
+
+    fn calculate_order_cost(
+        &self,
+        _symbol: &str,
+        quantity: Decimal,
+        price: Decimal,
+        _side: OrderSide,
+        order_type: OrderType,
+    ) -> TradeCost {
+        self.cost_at(quantity, price, order_type == OrderType::Limit)
+    }
+
+    fn record_fill(&self, quantity: Decimal, price: Decimal, timestamp_ms: i64) {
+        self.volume.record(quantity * price, timestamp_ms);
+    }
 
     fn description(&self) -> String {
+        let taker_fee_pct = self
+            .current_tier()
+            .map(|t| t.taker_fee_pct)
+            .unwrap_or(Decimal::ZERO);
         format!(
-            "Tiered Fee Model (Taker: {:.2}%, Slip: {:.2}%)",
-            self.taker_fee_pct * Decimal::from(100),
-            self.slippage_pct * Decimal::from(100)
+            "Tiered Fee Model (Taker: {:.2}%, Slip: {:.2}%, {} tier(s))",
+            taker_fee_pct * Decimal::from(100),
+            self.slippage_pct * Decimal::from(100),
+            self.tiers.len()
         )
     }
 }
@@ -181,4 +451,88 @@ mod tests {
         assert_eq!(basic_cost.fee, dec!(20.0)); // 50000 * 0.0004
         assert_eq!(basic_cost.total_impact, dec!(25.0)); // 20 + 5
     }
+
+    #[test]
+    fn test_tiered_fees_drop_as_volume_grows() {
+        let model = TieredFeeModel::with_tiers(
+            vec![
+                FeeTier::new(dec!(100000), dec!(0.0012), dec!(0.0022)),
+                FeeTier::new(dec!(0), dec!(0.0015), dec!(0.0025)),
+            ],
+            dec!(0),
+        );
+        let day_ms = MS_PER_DAY;
+
+        // $10k trade at base tier: 0.25%
+        let cost = model.calculate_cost(dec!(1), dec!(10000), OrderSide::Buy);
+        assert_eq!(cost.fee, dec!(25));
+
+        // Cross $100k within the window -> 0.22%
+        for day in 0..10 {
+            model.record_fill(dec!(1), dec!(10000), day * day_ms);
+        }
+        let cost = model.calculate_cost(dec!(1), dec!(10000), OrderSide::Buy);
+        assert_eq!(cost.fee, dec!(22));
+
+        // 30 days after the early fills they roll off and the discount is lost
+        model.record_fill(dec!(0.1), dec!(10000), 35 * day_ms);
+        assert_eq!(model.volume_tracker().volume(), dec!(41000));
+        let cost = model.calculate_cost(dec!(1), dec!(10000), OrderSide::Buy);
+        assert_eq!(cost.fee, dec!(25));
+    }
+
+    #[test]
+    fn test_tiered_fees_pick_maker_or_taker_rate_from_order_type() {
+        let model = TieredFeeModel::new(dec!(0.001), dec!(0.002), dec!(0));
+
+        let limit = model.calculate_order_cost(
+            "BTC/USD",
+            dec!(1),
+            dec!(10000),
+            OrderSide::Buy,
+            OrderType::Limit,
+        );
+        assert_eq!(limit.fee, dec!(10));
+
+        let market = model.calculate_order_cost(
+            "BTC/USD",
+            dec!(1),
+            dec!(10000),
+            OrderSide::Sell,
+            OrderType::Market,
+        );
+        assert_eq!(market.fee, dec!(20));
+
+        // Routed through a per-symbol wrapper as in production
+        let wrapped = PerSymbolFeeModel::new(Arc::new(model));
+        let limit = wrapped.calculate_order_cost(
+            "BTC/USD",
+            dec!(1),
+            dec!(10000),
+            OrderSide::Buy,
+            OrderType::Limit,
+        );
+        assert_eq!(limit.fee, dec!(10));
+    }
+
+    #[test]
+    fn test_per_symbol_override_falls_back_to_default() {
+        let model =
+            PerSymbolFeeModel::new(Arc::new(ConstantFeeModel::new(dec!(0.005), dec!(0.001))))
+                .with_symbol_model(
+                    "PENNY",
+                    Arc::new(ConstantFeeModel::new(dec!(0.005), dec!(0.01))),
+                )
+                .with_symbol_spread("PENNY", dec!(40));
+
+        // $1000 trade: override slippage 1% vs default 0.1%
+        let penny = model.calculate_symbol_cost("PENNY", dec!(10), dec!(100), OrderSide::Buy);
+        assert_eq!(penny.slippage_cost, dec!(10.0));
+        let aapl = model.calculate_symbol_cost("AAPL", dec!(10), dec!(100), OrderSide::Buy);
+        assert_eq!(aapl.slippage_cost, dec!(1.0));
+        assert_eq!(aapl.fee, dec!(0.05));
+
+        assert_eq!(model.spread_bps_for("PENNY"), Some(dec!(40)));
+        assert_eq!(model.spread_bps_for("AAPL"), None);
+    }
 }
@@ -0,0 +1,115 @@
+use crate::domain::trading::types::OrderSide;
+use rust_decimal::{Decimal, RoundingStrategy};
+
+/// Broker order-size rules for one symbol
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct LotSize {
+    /// Quantity increment (e.g. 1 for whole shares, 0.00001 for a Binance step size)
+    pub step_size: Decimal,
+    /// Smallest accepted quantity
+    pub min_qty: Decimal,
+    /// Smallest accepted order value (quantity * price)
+    pub min_notional: Decimal,
+    /// Price increment for limit/stop prices (zero = any price)
+    pub tick_size: Decimal,
+}
+
+impl LotSize {
+    /// Quantity rounded to `dp` decimal places with no minimum
+    pub fn decimals(dp: u32) -> Self {
+        Self {
+            step_size: Decimal::new(1, dp),
+            min_qty: Decimal::ZERO,
+            min_notional: Decimal::ZERO,
+            tick_size: Decimal::ZERO,
+        }
+    }
+
+    /// Whole units only (non-fractionable shares)
+    pub fn whole_units() -> Self {
+        Self::decimals(0)
+    }
+
+    /// Rounds `quantity` down to the step size.
+    ///
+    /// Returns zero when the result is below the minimum quantity or notional,
+    /// i.e. when the broker would reject the order.
+    pub fn round(&self, quantity: Decimal, price: Decimal) -> Decimal {
+        if quantity <= Decimal::ZERO {
+            return Decimal::ZERO;
+        }
+
+        let rounded = if self.step_size > Decimal::ZERO {
+            ((quantity / self.step_size).round_dp_with_strategy(0, RoundingStrategy::ToZero)
+                * self.step_size)
+                .normalize()
+        } else {
+            quantity
+        };
+
+        if rounded <= Decimal::ZERO
+            || rounded < self.min_qty
+            || (price > Decimal::ZERO && rounded * price < self.min_notional)
+        {
+            return Decimal::ZERO;
+        }
+        rounded
+    }
+
+    /// Rounds a limit price to the tick size, never past the requested price:
+    /// buys round down, sells round up.
+    pub fn round_price(&self, price: Decimal, side: OrderSide) -> Decimal {
+        if self.tick_size <= Decimal::ZERO || price <= Decimal::ZERO {
+            return price;
+        }
+        let strategy = match side {
+            OrderSide::Buy => RoundingStrategy::ToZero,
+            OrderSide::Sell => RoundingStrategy::AwayFromZero,
+        };
+        ((price / self.tick_size).round_dp_with_strategy(0, strategy) * self.tick_size).normalize()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rust_decimal_macros::dec;
+
+    #[test]
+    fn test_round_to_step_and_minimums() {
+        let binance = LotSize {
+            step_size: dec!(0.00100000),
+            min_qty: dec!(0.001),
+            min_notional: dec!(5),
+            tick_size: dec!(0.01),
+        };
+        assert_eq!(binance.round(dec!(0.123456), dec!(100)), dec!(0.123));
+        // Below min notional: 0.004 * 100 = 0.4 < 5
+        assert_eq!(binance.round(dec!(0.0049), dec!(100)), Decimal::ZERO);
+        // Rounds to zero below one step
+        assert_eq!(binance.round(dec!(0.0009), dec!(100000)), Decimal::ZERO);
+
+        assert_eq!(LotSize::whole_units().round(dec!(7.9), dec!(10)), dec!(7));
+        assert_eq!(
+            LotSize::whole_units().round(dec!(0.9), dec!(10)),
+            Decimal::ZERO
+        );
+        assert_eq!(
+            LotSize::decimals(4).round(dec!(1.23456), dec!(10)),
+            dec!(1.2345)
+        );
+
+        assert_eq!(
+            binance.round_price(dec!(101.239), OrderSide::Buy),
+            dec!(101.23)
+        );
+        assert_eq!(
+            binance.round_price(dec!(101.231), OrderSide::Sell),
+            dec!(101.24)
+        );
+        assert_eq!(
+            LotSize::whole_units().round_price(dec!(101.239), OrderSide::Buy),
+            dec!(101.239)
+        );
+    }
+}
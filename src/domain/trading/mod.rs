@@ -1,5 +1,7 @@
 // Core trading domain entities and value objects
 pub mod events;
 pub mod fee_model;
+pub mod lot_size;
 pub mod portfolio;
+pub mod time;
 pub mod types;
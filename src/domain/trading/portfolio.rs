@@ -1,6 +1,6 @@
 use rust_decimal::Decimal;
 use serde::Serialize;
-use std::collections::HashMap;
+use std::collections::{HashMap, VecDeque};
 
 #[derive(Debug, Clone, Serialize)]
 pub struct Portfolio {
@@ -13,13 +13,111 @@ pub struct Portfolio {
     pub max_equity: Decimal,
     pub day_trades_count: u64, // Added for PDT tracking
     pub synchronized: bool,
+    /// Keep per-lot detail on positions so sells are matched FIFO (LOT_TRACKING_ENABLED)
+    pub lot_tracking: bool,
+    /// Let sells beyond the held quantity open short positions (ALLOW_SHORTS)
+    pub allow_shorts: bool,
 }
 
 #[derive(Debug, Clone, Serialize)]
 pub struct Position {
     pub symbol: String,
+    /// Negative for a short position
     pub quantity: Decimal,
+    /// Volume-weighted cost (or short entry price) of the open quantity (derived from `lots` when they are tracked)
     pub average_price: Decimal,
+    /// Open lots, oldest first. Empty unless lot tracking is enabled.
+    pub lots: VecDeque<Lot>,
+}
+
+/// A single buy fill still (partly) held
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct Lot {
+    pub quantity: Decimal,
+    pub price: Decimal,
+    /// Fill time (ms)
+    pub timestamp: i64,
+}
+
+impl Position {
+    /// Adds bought quantity, re-averaging the cost and opening a lot when `track_lots` is set
+    pub fn add_fill(
+        &mut self,
+        quantity: Decimal,
+        price: Decimal,
+        timestamp: i64,
+        track_lots: bool,
+    ) {
+        let total_cost = self.quantity * self.average_price + quantity * price;
+        self.quantity += quantity;
+        if self.quantity > Decimal::ZERO {
+            self.average_price = total_cost / self.quantity;
+        }
+        if track_lots {
+            self.lots.push_back(Lot {
+                quantity,
+                price,
+                timestamp,
+            });
+        }
+    }
+
+    /// Removes `quantity` and returns its cost basis.
+    ///
+    /// Matched FIFO against the open lots when they account for the whole position;
+    /// otherwise (e.g. a position synced from a broker) the average price is used and
+    /// the partial lot history is dropped.
+    pub fn remove_quantity(&mut self, quantity: Decimal) -> Decimal {
+        let lot_quantity: Decimal = self.lots.iter().map(|lot| lot.quantity).sum();
+        let cost_basis = if !self.lots.is_empty() && lot_quantity == self.quantity {
+            let mut remaining = quantity;
+            let mut cost = Decimal::ZERO;
+            while remaining > Decimal::ZERO {
+                let Some(lot) = self.lots.front_mut() else {
+                    break;
+                };
+                let matched = remaining.min(lot.quantity);
+                cost += matched * lot.price;
+                lot.quantity -= matched;
+                remaining -= matched;
+                if lot.quantity.is_zero() {
+                    self.lots.pop_front();
+                }
+            }
+            cost
+        } else {
+            self.lots.clear();
+            quantity * self.average_price
+        };
+
+        self.quantity -= quantity;
+        if !self.lots.is_empty() {
+            let open_cost: Decimal = self.lots.iter().map(|lot| lot.quantity * lot.price).sum();
+            self.average_price = open_cost / self.quantity;
+        }
+        cost_basis
+    }
+
+    /// Adds `quantity` sold short, re-averaging the short entry price. Lots are not tracked.
+    pub fn add_short_fill(&mut self, quantity: Decimal, price: Decimal) {
+        let short_quantity = (-self.quantity).max(Decimal::ZERO);
+        let total_proceeds = short_quantity * self.average_price + quantity * price;
+        self.quantity -= quantity;
+        self.average_price = total_proceeds / (short_quantity + quantity);
+        self.lots.clear();
+    }
+
+    /// Buys back up to `quantity` of a short position.
+    /// Returns the quantity covered and the gross P&L of the cover.
+    pub fn cover_short(&mut self, quantity: Decimal, price: Decimal) -> (Decimal, Decimal) {
+        let covered = quantity.min(-self.quantity).max(Decimal::ZERO);
+        self.quantity += covered;
+        (covered, (self.average_price - price) * covered)
+    }
+
+    pub fn is_short(&self) -> bool {
+        self.quantity < Decimal::ZERO
+    }
 }
 
 impl Portfolio {
@@ -34,6 +132,8 @@ impl Portfolio {
             max_equity: Decimal::ZERO,
             day_trades_count: 0,
             synchronized: false,
+            lot_tracking: false,
+            allow_shorts: false,
         }
     }
 }
@@ -86,6 +186,20 @@ impl Portfolio {
         equity
     }
 
+    /// Absolute value of all positions, longs and shorts alike (average price when unpriced)
+    pub fn gross_exposure(&self, current_prices: &HashMap<String, Decimal>) -> Decimal {
+        self.positions
+            .iter()
+            .map(|(symbol, position)| {
+                let price = current_prices
+                    .get(symbol)
+                    .copied()
+                    .unwrap_or(position.average_price);
+                position.quantity.abs() * price
+            })
+            .sum()
+    }
+
     /// Calculate unrealized P&L for all positions
     pub fn unrealized_pnl(&self, current_prices: &HashMap<String, Decimal>) -> Decimal {
         let mut unrealized = Decimal::ZERO;
@@ -107,6 +221,116 @@ impl Portfolio {
         self.trade_history.push(trade);
     }
 
+    /// Applies a buy fill: pays `price * quantity + fees` and adds to the position.
+    ///
+    /// A buy against a short position covers it first; any remainder opens a long.
+    /// Returns the P&L realized by the cover (zero for a plain buy).
+    pub fn apply_buy_fill(
+        &mut self,
+        symbol: &str,
+        quantity: Decimal,
+        price: Decimal,
+        fees: Decimal,
+        timestamp: i64,
+    ) -> Decimal {
+        let track_lots = self.lot_tracking;
+        self.cash -= price * quantity + fees;
+        let position = self
+            .positions
+            .entry(symbol.to_string())
+            .or_insert_with(|| Position {
+                symbol: symbol.to_string(),
+                quantity: Decimal::ZERO,
+                average_price: Decimal::ZERO,
+                lots: VecDeque::new(),
+            });
+
+        let mut realized = Decimal::ZERO;
+        let mut remaining = quantity;
+        if position.is_short() && quantity > Decimal::ZERO {
+            let (covered, gross_pnl) = position.cover_short(quantity, price);
+            realized = gross_pnl - fees * covered / quantity;
+            remaining -= covered;
+        }
+        if remaining > Decimal::ZERO {
+            position.add_fill(remaining, price, timestamp, track_lots);
+        }
+        self.realized_pnl += realized;
+        realized
+    }
+
+    /// Applies a sell fill and books the realized P&L.
+    ///
+    /// The cost basis is matched FIFO when lots are tracked, at the average price otherwise.
+    /// `fees` covers everything charged on the exit (commission, funding).
+    /// The quantity is capped at what is held unless `allow_shorts` is set, in which case
+    /// the excess opens (or adds to) a short position. Returns the P&L realized by this fill.
+    pub fn apply_sell_fill(
+        &mut self,
+        symbol: &str,
+        quantity: Decimal,
+        price: Decimal,
+        fees: Decimal,
+    ) -> Decimal {
+        if !self.allow_shorts && !self.positions.contains_key(symbol) {
+            return Decimal::ZERO;
+        }
+        let held = self
+            .positions
+            .get(symbol)
+            .map_or(Decimal::ZERO, |p| p.quantity.max(Decimal::ZERO));
+        let closing = quantity.min(held).max(Decimal::ZERO);
+        let opening = if self.allow_shorts {
+            (quantity - closing).max(Decimal::ZERO)
+        } else {
+            Decimal::ZERO
+        };
+
+        let position = self
+            .positions
+            .entry(symbol.to_string())
+            .or_insert_with(|| Position {
+                symbol: symbol.to_string(),
+                quantity: Decimal::ZERO,
+                average_price: Decimal::ZERO,
+                lots: VecDeque::new(),
+            });
+
+        let mut realized = Decimal::ZERO;
+        if closing > Decimal::ZERO || opening.is_zero() {
+            // Fees on the short-opening part are an entry cost, like buy fees
+            let closing_fees = if opening.is_zero() {
+                fees
+            } else {
+                fees * closing / quantity
+            };
+            let cost_basis = position.remove_quantity(closing);
+            realized = price * closing - cost_basis - closing_fees;
+        }
+        if opening > Decimal::ZERO {
+            position.add_short_fill(opening, price);
+        }
+
+        self.cash += price * (closing + opening) - fees;
+        self.realized_pnl += realized;
+        realized
+    }
+
+    /// Clears positions and history and restarts from `cash`.
+    ///
+    /// Used to give mock sessions and backtest runs a clean, known base.
+    pub fn reset(&mut self, cash: Decimal) {
+        *self = Self {
+            cash,
+            starting_cash: cash,
+            max_equity: cash,
+            synchronized: self.synchronized,
+            lot_tracking: self.lot_tracking,
+            allow_shorts: self.allow_shorts,
+            ..Self::new()
+        };
+    }
+
     /// Get total P&L (realized + unrealized)
     pub fn total_pnl(&self, current_prices: &HashMap<String, Decimal>) -> Decimal {
         self.realized_pnl + self.unrealized_pnl(current_prices)
@@ -130,6 +354,7 @@ mod tests {
                 symbol: "AAPL".to_string(),
                 quantity: dec!(10),
                 average_price: dec!(100),
+                lots: VecDeque::new(),
             },
         );
 
@@ -152,6 +377,7 @@ mod tests {
                 symbol: "AAPL".to_string(),
                 quantity: dec!(10),
                 average_price: dec!(100),
+                lots: VecDeque::new(),
             },
         );
 
@@ -173,6 +399,7 @@ mod tests {
                 symbol: "TSLA".to_string(),
                 quantity: dec!(5),
                 average_price: dec!(200),
+                lots: VecDeque::new(),
             },
         );
 
@@ -223,6 +450,7 @@ mod tests {
                 symbol: "BTC".to_string(),
                 quantity: dec!(1),
                 average_price: dec!(50000),
+                lots: VecDeque::new(),
             },
         );
 
@@ -232,4 +460,166 @@ mod tests {
         // Total P&L = 500 (realized) + 2000 (unrealized) = 2500
         assert_eq!(portfolio.total_pnl(&current_prices), dec!(2500));
     }
+
+    #[test]
+    fn test_apply_sell_fill_books_realized_pnl() {
+        let mut portfolio = Portfolio::new();
+        portfolio.positions.insert(
+            "AAPL".to_string(),
+            Position {
+                symbol: "AAPL".to_string(),
+                quantity: dec!(10),
+                average_price: dec!(100),
+                lots: VecDeque::new(),
+            },
+        );
+
+        // Sell 4 @ 110 with $2 fees: (110 - 100) * 4 - 2 = 38
+        let realized = portfolio.apply_sell_fill("AAPL", dec!(4), dec!(110), dec!(2));
+        assert_eq!(realized, dec!(38));
+        assert_eq!(portfolio.realized_pnl, dec!(38));
+        assert_eq!(portfolio.cash, dec!(438));
+        assert_eq!(portfolio.positions["AAPL"].quantity, dec!(6));
+
+        // Oversized sell is capped at the held quantity, at a loss
+        let realized = portfolio.apply_sell_fill("AAPL", dec!(50), dec!(90), Decimal::ZERO);
+        assert_eq!(realized, dec!(-60));
+        assert_eq!(portfolio.realized_pnl, dec!(-22));
+        assert_eq!(portfolio.positions["AAPL"].quantity, Decimal::ZERO);
+
+        // Unrealized P&L only covers what is still held
+        let prices = HashMap::from([("AAPL".to_string(), dec!(120))]);
+        assert_eq!(portfolio.unrealized_pnl(&prices), Decimal::ZERO);
+        assert_eq!(portfolio.total_pnl(&prices), dec!(-22));
+    }
+
+    #[test]
+    fn test_lot_tracking_matches_sells_fifo() {
+        let mut portfolio = Portfolio::new();
+        portfolio.lot_tracking = true;
+        portfolio.cash = dec!(10000);
+
+        portfolio.apply_buy_fill("AAPL", dec!(10), dec!(100), Decimal::ZERO, 1);
+        portfolio.apply_buy_fill("AAPL", dec!(10), dec!(120), Decimal::ZERO, 2);
+        assert_eq!(portfolio.positions["AAPL"].average_price, dec!(110));
+        assert_eq!(portfolio.cash, dec!(7800));
+
+        // 15 @ 130 consumes the first lot and half of the second:
+        // (130 - 100) * 10 + (130 - 120) * 5 = 350 (average cost would give 300)
+        let realized = portfolio.apply_sell_fill("AAPL", dec!(15), dec!(130), Decimal::ZERO);
+        assert_eq!(realized, dec!(350));
+
+        let position = &portfolio.positions["AAPL"];
+        assert_eq!(position.quantity, dec!(5));
+        assert_eq!(position.average_price, dec!(120));
+        assert_eq!(
+            position.lots,
+            VecDeque::from([Lot {
+                quantity: dec!(5),
+                price: dec!(120),
+                timestamp: 2,
+            }])
+        );
+    }
+
+    #[test]
+    fn test_lots_not_kept_when_tracking_disabled() {
+        let mut portfolio = Portfolio::new();
+        portfolio.apply_buy_fill("AAPL", dec!(10), dec!(100), Decimal::ZERO, 1);
+        portfolio.apply_buy_fill("AAPL", dec!(10), dec!(120), Decimal::ZERO, 2);
+        assert!(portfolio.positions["AAPL"].lots.is_empty());
+
+        let realized = portfolio.apply_sell_fill("AAPL", dec!(15), dec!(130), Decimal::ZERO);
+        assert_eq!(realized, dec!(300));
+        assert_eq!(portfolio.positions["AAPL"].average_price, dec!(110));
+    }
+
+    #[test]
+    fn test_short_open_and_cover() {
+        let mut portfolio = Portfolio::new();
+        portfolio.allow_shorts = true;
+        portfolio.cash = dec!(10000);
+
+        // Sell 10 @ 100 while flat opens a short and credits the proceeds
+        let realized = portfolio.apply_sell_fill("AAPL", dec!(10), dec!(100), dec!(1));
+        assert_eq!(realized, Decimal::ZERO);
+        assert_eq!(portfolio.cash, dec!(10999));
+        assert_eq!(portfolio.positions["AAPL"].quantity, dec!(-10));
+        assert_eq!(portfolio.positions["AAPL"].average_price, dec!(100));
+
+        // Price falls: short gains, equity = cash + qty * price
+        let prices = HashMap::from([("AAPL".to_string(), dec!(90))]);
+        assert_eq!(portfolio.unrealized_pnl(&prices), dec!(100));
+        assert_eq!(portfolio.total_equity(&prices), dec!(10099));
+
+        // Buy 4 @ 90 covers part of the short: (100 - 90) * 4 - 2 = 38
+        let realized = portfolio.apply_buy_fill("AAPL", dec!(4), dec!(90), dec!(2), 2);
+        assert_eq!(realized, dec!(38));
+        assert_eq!(portfolio.positions["AAPL"].quantity, dec!(-6));
+
+        // Buy 10 @ 95 covers the remaining 6 and opens a 4-share long
+        let realized = portfolio.apply_buy_fill("AAPL", dec!(10), dec!(95), Decimal::ZERO, 3);
+        assert_eq!(realized, dec!(30));
+        assert_eq!(portfolio.realized_pnl, dec!(68));
+        let position = &portfolio.positions["AAPL"];
+        assert_eq!(position.quantity, dec!(4));
+        assert_eq!(position.average_price, dec!(95));
+    }
+
+    #[test]
+    fn test_sell_through_long_into_short() {
+        let mut portfolio = Portfolio::new();
+        portfolio.allow_shorts = true;
+        portfolio.apply_buy_fill("AAPL", dec!(5), dec!(100), Decimal::ZERO, 1);
+
+        // Sell 8 @ 110: closes the long (+50) and shorts 3 at 110
+        let realized = portfolio.apply_sell_fill("AAPL", dec!(8), dec!(110), Decimal::ZERO);
+        assert_eq!(realized, dec!(50));
+        let position = &portfolio.positions["AAPL"];
+        assert_eq!(position.quantity, dec!(-3));
+        assert_eq!(position.average_price, dec!(110));
+
+        // Adding to the short re-averages the entry
+        portfolio.apply_sell_fill("AAPL", dec!(1), dec!(130), Decimal::ZERO);
+        assert_eq!(portfolio.positions["AAPL"].average_price, dec!(115));
+    }
+
+    #[test]
+    fn test_sell_without_position_ignored_when_shorts_disabled() {
+        let mut portfolio = Portfolio::new();
+        portfolio.cash = dec!(1000);
+        let realized = portfolio.apply_sell_fill("AAPL", dec!(10), dec!(100), dec!(1));
+        assert_eq!(realized, Decimal::ZERO);
+        assert_eq!(portfolio.cash, dec!(1000));
+        assert!(portfolio.positions.is_empty());
+    }
+
+    #[test]
+    fn test_reset_restores_clean_base() {
+        let mut portfolio = Portfolio::new();
+        portfolio.cash = dec!(500);
+        portfolio.realized_pnl = dec!(-42);
+        portfolio.day_trades_count = 3;
+        portfolio.synchronized = true;
+        portfolio.positions.insert(
+            "AAPL".to_string(),
+            Position {
+                symbol: "AAPL".to_string(),
+                quantity: dec!(1),
+                average_price: dec!(100),
+                lots: VecDeque::new(),
+            },
+        );
+
+        portfolio.reset(dec!(25000));
+
+        assert_eq!(portfolio.cash, dec!(25000));
+        assert_eq!(portfolio.starting_cash, dec!(25000));
+        assert_eq!(portfolio.max_equity, dec!(25000));
+        assert_eq!(portfolio.realized_pnl, Decimal::ZERO);
+        assert_eq!(portfolio.day_trades_count, 0);
+        assert!(portfolio.positions.is_empty());
+        assert!(portfolio.trade_history.is_empty());
+        assert!(portfolio.synchronized);
+    }
 }
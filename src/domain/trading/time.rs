@@ -0,0 +1,62 @@
+//! Timestamp conventions for the trading domain.
+//!
+//! Every `timestamp` carried by [`Candle`](super::types::Candle), quotes,
+//! [`TradeProposal`](super::types::TradeProposal), [`Order`](super::types::Order) and the
+//! position manager is Unix time in **milliseconds**. Convert at the edges (broker APIs,
+//! chrono, config values in seconds) with the helpers below instead of ad-hoc `* 1000`.
+
+use chrono::{DateTime, Utc};
+
+pub const MS_PER_SECOND: i64 = 1_000;
+pub const MS_PER_DAY: i64 = 86_400 * MS_PER_SECOND;
+
+/// Current wall-clock time in milliseconds
+pub fn now_ms() -> i64 {
+    Utc::now().timestamp_millis()
+}
+
+/// Seconds (e.g. a config duration or a second-resolution API field) to milliseconds
+pub fn secs_to_ms(seconds: i64) -> i64 {
+    seconds.saturating_mul(MS_PER_SECOND)
+}
+
+/// Milliseconds to whole seconds, truncating toward negative infinity
+pub fn ms_to_secs(timestamp_ms: i64) -> i64 {
+    timestamp_ms.div_euclid(MS_PER_SECOND)
+}
+
+/// Millisecond timestamp as a UTC datetime (epoch if out of range)
+pub fn ms_to_datetime(timestamp_ms: i64) -> DateTime<Utc> {
+    DateTime::from_timestamp_millis(timestamp_ms).unwrap_or_default()
+}
+
+/// Start of the UTC day containing `timestamp_ms`, in milliseconds
+pub fn utc_day_start_ms(timestamp_ms: i64) -> i64 {
+    timestamp_ms - timestamp_ms.rem_euclid(MS_PER_DAY)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    #[test]
+    fn test_timestamp_helpers_use_milliseconds() {
+        // 2024-03-15 14:30:05.250 UTC
+        let dt = Utc.with_ymd_and_hms(2024, 3, 15, 14, 30, 5).unwrap()
+            + chrono::Duration::milliseconds(250);
+        let ms = dt.timestamp_millis();
+
+        assert_eq!(ms_to_datetime(ms), dt);
+        assert_eq!(ms_to_secs(ms), dt.timestamp());
+        assert_eq!(secs_to_ms(dt.timestamp()), ms - 250);
+        assert_eq!(
+            utc_day_start_ms(ms),
+            Utc.with_ymd_and_hms(2024, 3, 15, 0, 0, 0)
+                .unwrap()
+                .timestamp_millis()
+        );
+        assert_eq!(ms_to_secs(-1), -1);
+        assert_eq!(utc_day_start_ms(-1), -MS_PER_DAY);
+    }
+}
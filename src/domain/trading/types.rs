@@ -17,6 +17,18 @@ impl fmt::Display for OrderSide {
     }
 }
 
+impl OrderSide {
+    /// Whether an order on this side grows the absolute size of a position holding
+    /// `position_qty` (negative = short): opening or adding to a long or a short,
+    /// rather than reducing or covering it
+    pub fn increases_exposure(self, position_qty: Decimal) -> bool {
+        match self {
+            OrderSide::Buy => position_qty >= Decimal::ZERO,
+            OrderSide::Sell => position_qty <= Decimal::ZERO,
+        }
+    }
+}
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 pub enum OrderStatus {
     New,
@@ -52,6 +64,7 @@ pub struct Candle {
     pub low: Decimal,
     pub close: Decimal,
     pub volume: Decimal,
+    /// Bar open time, Unix milliseconds
     pub timestamp: i64,
 }
 
@@ -61,12 +74,17 @@ pub enum MarketEvent {
         symbol: String,
         price: Decimal,
         quantity: Decimal,
+        /// Unix milliseconds
         timestamp: i64,
     },
     Candle(Candle),
     SymbolSubscription {
         symbol: String,
     },
+    /// The feed for `symbol` was stopped; its per-symbol state can be released
+    SymbolUnsubscription {
+        symbol: String,
+    },
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
@@ -88,6 +106,16 @@ impl fmt::Display for OrderType {
     }
 }
 
+/// Strategy and market regime behind a signal, carried from the proposal to the
+/// order and on to the resulting [`Trade`] for per-strategy and per-regime analytics
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct SignalOrigin {
+    /// Strategy that generated the signal (e.g. "SMC")
+    pub strategy: Option<String>,
+    /// Market regime when the signal fired (e.g. "TrendingUp")
+    pub regime: Option<String>,
+}
+
 #[derive(Debug, Clone)]
 pub struct TradeProposal {
     pub symbol: String,
@@ -96,9 +124,21 @@ pub struct TradeProposal {
     pub quantity: Decimal,
     pub order_type: OrderType,
     pub reason: String,
+    /// Signal time (the triggering candle's timestamp), Unix milliseconds
     pub timestamp: i64,
     pub stop_loss: Option<Decimal>,
     pub take_profit: Option<Decimal>,
+    /// May only shrink the current position, never open, add to or flip it
+    pub reduce_only: bool,
+    pub origin: SignalOrigin,
+}
+
+impl TradeProposal {
+    /// Whether this proposal is an entry against a position of `position_qty`;
+    /// reduce-only proposals never are
+    pub fn increases_exposure(&self, position_qty: Decimal) -> bool {
+        !self.reduce_only && self.side.increases_exposure(position_qty)
+    }
 }
 
 #[derive(Debug, Clone)]
@@ -110,7 +150,57 @@ pub struct Order {
     pub quantity: Decimal,
     pub order_type: OrderType,
     pub status: OrderStatus,
+    /// Unix milliseconds
     pub timestamp: i64,
+    /// May only shrink the current position, never open, add to or flip it
+    pub reduce_only: bool,
+    pub origin: SignalOrigin,
+    /// Why the order was placed (the proposal reason), if it came from one
+    pub reason: Option<String>,
+}
+
+impl Order {
+    /// Deterministic client order id for a signal.
+    ///
+    /// The same symbol, side, signal timestamp, reason and quantity always map to the
+    /// same id, so a resubmitted order is recognised (and rejected) by the broker instead
+    /// of filled twice. Reason and quantity keep distinct orders from one candle (e.g. a
+    /// stop-loss and a partial take-profit) apart.
+    pub fn client_order_id(
+        symbol: &str,
+        side: OrderSide,
+        signal_timestamp: i64,
+        reason: &str,
+        quantity: Decimal,
+    ) -> String {
+        use sha2::{Digest, Sha256};
+
+        let digest = Sha256::digest(format!(
+            "{}:{}:{}:{}:{}",
+            symbol,
+            side,
+            signal_timestamp,
+            reason,
+            quantity.normalize()
+        ));
+        let mut bytes = [0u8; 16];
+        bytes.copy_from_slice(&digest[..16]);
+        uuid::Builder::from_custom_bytes(bytes)
+            .into_uuid()
+            .to_string()
+    }
+
+    /// Quantity a reduce-only order may execute against a position of `position_qty`.
+    ///
+    /// Clamped to the held size so the position can close but never reverse; `None`
+    /// when the order would open or add to a position.
+    pub fn reduce_only_quantity(&self, position_qty: Decimal) -> Option<Decimal> {
+        let reducible = match self.side {
+            OrderSide::Sell => position_qty,
+            OrderSide::Buy => -position_qty,
+        };
+        (reducible > Decimal::ZERO).then(|| self.quantity.min(reducible))
+    }
 }
 
 /// Represents a completed trade with profit/loss information.
@@ -160,9 +250,9 @@ impl Trade {
             pnl: Decimal::ZERO,
             entry_timestamp: order.timestamp,
             exit_timestamp: None,
-            strategy_used: None,
-            regime_detected: None,
-            entry_reason: None,
+            strategy_used: order.origin.strategy.clone(),
+            regime_detected: order.origin.regime.clone(),
+            entry_reason: order.reason.clone(),
             exit_reason: None,
             slippage: None,
             fees: Decimal::ZERO,
@@ -218,6 +308,9 @@ pub struct FeatureSet {
     pub ema_fast: Option<Decimal>,
     pub ema_slow: Option<Decimal>,
     pub adx: Option<Decimal>,
+    /// Wilder directional indicators (+DI / −DI)
+    pub plus_di: Option<Decimal>,
+    pub minus_di: Option<Decimal>,
     pub bb_width: Option<Decimal>,
     pub bb_position: Option<Decimal>,
     pub atr_pct: Option<Decimal>,
@@ -235,6 +328,46 @@ pub struct FeatureSet {
     pub timeframe: Option<crate::domain::market::timeframe::Timeframe>,
 }
 
+/// Optional indicator groups a strategy reads from the [`FeatureSet`]
+///
+/// Groups left out are not computed and stay `None`. SMAs, RSI, ATR, EMAs and the
+/// statistical features are always computed: stops, sizing and regime detection read them.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct FeatureRequirements {
+    pub macd: bool,
+    /// ADX with the +DI / −DI directional indicators
+    pub adx: bool,
+    /// Bollinger bands, width and position
+    pub bollinger: bool,
+}
+
+impl FeatureRequirements {
+    pub const ALL: Self = Self {
+        macd: true,
+        adx: true,
+        bollinger: true,
+    };
+    /// Only the always-computed indicators
+    pub const CORE: Self = Self {
+        macd: false,
+        adx: false,
+        bollinger: false,
+    };
+
+    pub fn union(self, other: Self) -> Self {
+        Self {
+            macd: self.macd || other.macd,
+            adx: self.adx || other.adx,
+            bollinger: self.bollinger || other.bollinger,
+        }
+    }
+
+    /// True if every group `other` needs is part of `self`
+    pub fn covers(&self, other: &Self) -> bool {
+        self.union(*other) == *self
+    }
+}
+
 // ===== Symbol Normalization =====
 
 /// Supported quote currencies for crypto pairs, ordered by priority (longest first to prefer USDT over USD)
@@ -330,6 +463,81 @@ pub fn denormalize_crypto_symbol(symbol: &str) -> String {
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_client_order_id_is_deterministic() {
+        let qty = Decimal::from(10);
+        let id = Order::client_order_id("AAPL", OrderSide::Buy, 1_700_000_000_000, "Entry", qty);
+        assert_eq!(
+            id,
+            Order::client_order_id("AAPL", OrderSide::Buy, 1_700_000_000_000, "Entry", qty)
+        );
+        // Binance caps newClientOrderId at 36 characters
+        assert_eq!(id.len(), 36);
+
+        assert_ne!(
+            id,
+            Order::client_order_id("AAPL", OrderSide::Sell, 1_700_000_000_000, "Entry", qty)
+        );
+        assert_ne!(
+            id,
+            Order::client_order_id("AAPL", OrderSide::Buy, 1_700_000_060_000, "Entry", qty)
+        );
+        assert_ne!(
+            id,
+            Order::client_order_id("MSFT", OrderSide::Buy, 1_700_000_000_000, "Entry", qty)
+        );
+        assert_ne!(
+            id,
+            Order::client_order_id("AAPL", OrderSide::Buy, 1_700_000_000_000, "Stop", qty)
+        );
+        assert_ne!(
+            id,
+            Order::client_order_id(
+                "AAPL",
+                OrderSide::Buy,
+                1_700_000_000_000,
+                "Entry",
+                Decimal::from(5)
+            )
+        );
+    }
+
+    #[test]
+    fn test_reduce_only_quantity() {
+        let order = |side, quantity| Order {
+            id: "1".to_string(),
+            symbol: "AAPL".to_string(),
+            side,
+            price: Decimal::ZERO,
+            quantity,
+            order_type: OrderType::Market,
+            status: OrderStatus::New,
+            timestamp: 0,
+            reduce_only: true,
+            origin: Default::default(),
+            reason: None,
+        };
+
+        // Clamped to the long so it cannot flip short
+        assert_eq!(
+            order(OrderSide::Sell, Decimal::from(15)).reduce_only_quantity(Decimal::from(10)),
+            Some(Decimal::from(10))
+        );
+        assert_eq!(
+            order(OrderSide::Buy, Decimal::from(5)).reduce_only_quantity(Decimal::from(-10)),
+            Some(Decimal::from(5))
+        );
+        // Flat or same-direction orders would grow the position
+        assert_eq!(
+            order(OrderSide::Sell, Decimal::from(5)).reduce_only_quantity(Decimal::ZERO),
+            None
+        );
+        assert_eq!(
+            order(OrderSide::Buy, Decimal::from(5)).reduce_only_quantity(Decimal::from(10)),
+            None
+        );
+    }
+
     #[test]
     fn test_denormalize_crypto_symbol() {
         assert_eq!(denormalize_crypto_symbol("BTC/USD"), "BTCUSD");
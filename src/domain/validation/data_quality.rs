@@ -34,7 +34,10 @@ impl StrictEventValidator {
                 true
             }
             MarketEvent::Candle(candle) => Self::validate_candle(candle),
-            MarketEvent::SymbolSubscription { .. } => true, // Meta-events are always valid
+            // Meta-events are always valid
+            MarketEvent::SymbolSubscription { .. } | MarketEvent::SymbolUnsubscription { .. } => {
+                true
+            }
         }
     }
 
@@ -1,6 +1,8 @@
 use super::trading_stream::AlpacaTradingStream;
+use crate::domain::errors::OrderSubmitError;
 use crate::domain::ports::ExecutionService;
 use crate::domain::ports::OrderUpdate;
+use crate::domain::ports::QuantityRounder;
 use crate::domain::trading::types::{Order, OrderSide};
 use crate::infrastructure::core::http_client_factory::{HttpClientFactory, build_url_with_query};
 use crate::infrastructure::observability::{LatencyGuard, Metrics};
@@ -9,10 +11,11 @@ use async_trait::async_trait;
 use reqwest_middleware::ClientWithMiddleware;
 use rust_decimal::Decimal;
 use serde::{Deserialize, Serialize};
+use std::collections::VecDeque;
 use std::str::FromStr;
 use std::sync::Arc;
 use tokio::sync::{RwLock, broadcast};
-use tracing::{error, info, instrument};
+use tracing::{error, info, instrument, warn};
 
 // ===== Execution Service (REST API) =====
 
@@ -25,6 +28,7 @@ pub struct AlpacaExecutionService {
 
     portfolio: Arc<RwLock<crate::domain::trading::portfolio::Portfolio>>, // Renamed from portfolio_cache and now injected
     metrics: Metrics,
+    quantity_rounder: Arc<super::lot_size::AlpacaQuantityRounder>,
 }
 
 impl AlpacaExecutionService {
@@ -143,6 +147,7 @@ impl AlpacaExecutionService {
                                 .avg_entry_price
                                 .parse::<Decimal>()
                                 .unwrap_or(Decimal::ZERO),
+                            lots: VecDeque::new(),
                         };
 
                         portfolio.positions.insert(normalized_symbol, pos);
@@ -172,6 +177,13 @@ impl AlpacaExecutionService {
             }
         });
 
+        let quantity_rounder = Arc::new(super::lot_size::AlpacaQuantityRounder::new(
+            client.clone(),
+            api_key.clone(),
+            api_secret.clone(),
+            base_url.clone(),
+        ));
+
         Self {
             client,
             api_key,
@@ -181,6 +193,7 @@ impl AlpacaExecutionService {
 
             portfolio,
             metrics,
+            quantity_rounder,
         }
     }
 }
@@ -243,13 +256,45 @@ struct AlpacaOrderDetail {
 #[async_trait]
 impl ExecutionService for AlpacaExecutionService {
     #[instrument(skip(self, order), fields(symbol = %order.symbol, side = ?order.side))]
-    async fn execute(&self, order: Order) -> Result<()> {
+    async fn execute(&self, mut order: Order) -> Result<()> {
         let _latency = LatencyGuard::new(
             self.metrics
                 .api_latency_seconds
                 .with_label_values(&["Alpaca", "v2/orders"]),
         );
 
+        // Alpaca has no reduce-only flag: clamp to the held quantity client-side
+        if order.reduce_only {
+            let held = self
+                .portfolio
+                .read()
+                .await
+                .positions
+                .get(&order.symbol)
+                .map(|p| p.quantity)
+                .unwrap_or(Decimal::ZERO);
+            match order.reduce_only_quantity(held) {
+                Some(qty) => {
+                    if qty < order.quantity {
+                        info!(
+                            "AlpacaExecution: Clamping reduce-only {} {} from {} to held {}",
+                            order.side, order.symbol, order.quantity, qty
+                        );
+                    }
+                    order.quantity = qty;
+                }
+                None => {
+                    return Err(OrderSubmitError::Rejected {
+                        reason: format!(
+                            "Reduce-only {} {} would open or increase the position (held {})",
+                            order.side, order.symbol, held
+                        ),
+                    }
+                    .into());
+                }
+            }
+        }
+
         let side_str = match order.side {
             OrderSide::Buy => "buy",
             OrderSide::Sell => "sell",
@@ -316,9 +361,12 @@ impl ExecutionService for AlpacaExecutionService {
             )
             .send()
             .await
-            .context("Failed to send order to Alpaca")?;
+            .map_err(|e| OrderSubmitError::Transient {
+                reason: format!("Failed to send order to Alpaca: {}", e),
+            })?;
 
-        if response.status().is_success() {
+        let status = response.status();
+        if status.is_success() {
             let order_resp: AlpacaOrderResponse = response
                 .json()
                 .await
@@ -333,7 +381,19 @@ impl ExecutionService for AlpacaExecutionService {
                 .text()
                 .await
                 .unwrap_or_else(|_| "Unknown error".to_string());
-            anyhow::bail!("Alpaca order failed: {}", error_text)
+            // A resubmitted client_order_id means the first attempt already reached Alpaca
+            if error_text.contains("client_order_id must be unique") {
+                warn!(
+                    "Alpaca order {} already submitted (duplicate client_order_id), not placing again",
+                    order.id
+                );
+                return Ok(());
+            }
+            Err(OrderSubmitError::from_status(
+                status.as_u16(),
+                format!("Alpaca order failed: {}", error_text),
+            )
+            .into())
         }
     }
 
@@ -387,7 +447,10 @@ impl ExecutionService for AlpacaExecutionService {
                     status: crate::domain::trading::types::OrderStatus::New,
                     timestamp: chrono::DateTime::parse_from_rfc3339(&ao.created_at)
                         .unwrap_or_default()
-                        .timestamp(),
+                        .timestamp_millis(),
+                    reduce_only: false,
+                    origin: Default::default(),
+                    reason: None,
                 }
             })
             .collect();
@@ -509,6 +572,9 @@ impl ExecutionService for AlpacaExecutionService {
                 order_type: crate::domain::trading::types::OrderType::Market,
                 status: crate::domain::trading::types::OrderStatus::Filled, // Today orders are usually resolved
                 timestamp: created_at,
+                reduce_only: false,
+                origin: Default::default(),
+                reason: None,
             });
         }
 
@@ -561,4 +627,8 @@ impl ExecutionService for AlpacaExecutionService {
 
         Ok(fees)
     }
+
+    fn quantity_rounder(&self) -> Option<Arc<dyn QuantityRounder>> {
+        Some(self.quantity_rounder.clone())
+    }
 }
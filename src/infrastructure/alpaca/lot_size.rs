@@ -0,0 +1,149 @@
+//! Alpaca lot-size rules
+//!
+//! Fractional shares are only accepted on `fractionable` assets (down to 1e-9
+//! share, minimum $1 notional); other equities trade in whole shares. Crypto
+//! assets publish their own `min_trade_increment` and `min_order_size`.
+
+use crate::domain::ports::QuantityRounder;
+use crate::domain::trading::lot_size::LotSize;
+use crate::domain::trading::types::denormalize_crypto_symbol;
+use anyhow::{Context, Result};
+use async_trait::async_trait;
+use reqwest_middleware::ClientWithMiddleware;
+use rust_decimal::Decimal;
+use rust_decimal_macros::dec;
+use serde::Deserialize;
+use std::collections::HashMap;
+use std::sync::RwLock;
+
+pub struct AlpacaQuantityRounder {
+    client: ClientWithMiddleware,
+    api_key: String,
+    api_secret: String,
+    base_url: String,
+    cache: RwLock<HashMap<String, LotSize>>,
+}
+
+impl AlpacaQuantityRounder {
+    pub fn new(
+        client: ClientWithMiddleware,
+        api_key: String,
+        api_secret: String,
+        base_url: String,
+    ) -> Self {
+        Self {
+            client,
+            api_key,
+            api_secret,
+            base_url,
+            cache: RwLock::new(HashMap::new()),
+        }
+    }
+}
+
+#[async_trait]
+impl QuantityRounder for AlpacaQuantityRounder {
+    async fn lot_size(&self, symbol: &str) -> Result<LotSize> {
+        if let Some(lot) = self
+            .cache
+            .read()
+            .map_err(|e| anyhow::anyhow!("lot size cache lock poisoned: {}", e))?
+            .get(symbol)
+        {
+            return Ok(*lot);
+        }
+
+        let url = format!(
+            "{}/v2/assets/{}",
+            self.base_url,
+            denormalize_crypto_symbol(symbol)
+        );
+        let response = self
+            .client
+            .get(&url)
+            .header("APCA-API-KEY-ID", &self.api_key)
+            .header("APCA-API-SECRET-KEY", &self.api_secret)
+            .send()
+            .await
+            .context("Failed to fetch Alpaca asset")?;
+
+        if !response.status().is_success() {
+            let error_text = response.text().await.unwrap_or_default();
+            anyhow::bail!("Alpaca asset fetch failed for {}: {}", symbol, error_text);
+        }
+
+        let asset: AlpacaAsset = response
+            .json()
+            .await
+            .context("Failed to parse Alpaca asset")?;
+        let lot = asset.lot_size();
+
+        self.cache
+            .write()
+            .map_err(|e| anyhow::anyhow!("lot size cache lock poisoned: {}", e))?
+            .insert(symbol.to_string(), lot);
+        Ok(lot)
+    }
+}
+
+#[derive(Debug, Deserialize)]
+struct AlpacaAsset {
+    #[serde(default)]
+    fractionable: bool,
+    #[serde(default)]
+    min_order_size: Option<String>,
+    #[serde(default)]
+    min_trade_increment: Option<String>,
+}
+
+impl AlpacaAsset {
+    fn lot_size(&self) -> LotSize {
+        let parse = |v: &Option<String>| v.as_deref().and_then(|s| s.parse::<Decimal>().ok());
+
+        if let Some(step_size) = parse(&self.min_trade_increment) {
+            return LotSize {
+                step_size: step_size.normalize(),
+                min_qty: parse(&self.min_order_size).unwrap_or(Decimal::ZERO),
+                min_notional: Decimal::ZERO,
+                tick_size: Decimal::ZERO,
+            };
+        }
+        if self.fractionable {
+            LotSize {
+                step_size: Decimal::new(1, 9),
+                min_qty: Decimal::ZERO,
+                min_notional: dec!(1),
+                tick_size: Decimal::ZERO,
+            }
+        } else {
+            LotSize::whole_units()
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn asset(json: &str) -> AlpacaAsset {
+        serde_json::from_str(json).unwrap()
+    }
+
+    #[test]
+    fn test_asset_lot_size() {
+        let whole = asset(r#"{"symbol":"BRK.A","fractionable":false}"#).lot_size();
+        assert_eq!(whole.round(dec!(2.7), dec!(600000)), dec!(2));
+
+        let fractional = asset(r#"{"symbol":"AAPL","fractionable":true}"#).lot_size();
+        assert_eq!(fractional.round(dec!(0.1234), dec!(200)), dec!(0.1234));
+        // $0.50 order is below the $1 fractional minimum
+        assert_eq!(fractional.round(dec!(0.0025), dec!(200)), Decimal::ZERO);
+
+        let crypto = asset(
+            r#"{"symbol":"BTC/USD","fractionable":true,"min_order_size":"0.0001","min_trade_increment":"0.000000001"}"#,
+        )
+        .lot_size();
+        assert_eq!(crypto.min_qty, dec!(0.0001));
+        assert_eq!(crypto.round(dec!(0.00005), dec!(60000)), Decimal::ZERO);
+    }
+}
@@ -1,11 +1,13 @@
 pub mod common;
 pub mod execution;
+pub mod lot_size;
 pub mod market_data;
 pub mod trading_stream;
 pub mod websocket;
 
 pub use common::AlpacaBar;
 pub use execution::AlpacaExecutionService;
+pub use lot_size::AlpacaQuantityRounder;
 pub use market_data::{
     AlpacaMarketDataService, AlpacaMarketDataServiceBuilder, AlpacaSectorProvider,
 };
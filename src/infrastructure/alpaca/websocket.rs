@@ -308,6 +308,7 @@ impl AlpacaWebSocketManager {
             tokio::select! {
                 // Read messages from WebSocket
                 msg_result = read.next() => {
+                    #[allow(clippy::collapsible_match)]
                     match msg_result {
                         Some(Ok(Message::Text(text))) => {
                             if let Ok(messages) = serde_json::from_str::<Vec<AlpacaMessage>>(&text) {
@@ -0,0 +1,225 @@
+//! Binance symbol filters
+//!
+//! Caches the `PRICE_FILTER` (tickSize), `LOT_SIZE` (stepSize, minQty) and
+//! `MIN_NOTIONAL`/`NOTIONAL` filters from `/api/v3/exchangeInfo` so prices and
+//! quantities can be rounded before submission (Binance rejects anything else).
+//! The cache is filled at startup and refreshed on a fixed interval.
+//! Each symbol's margin eligibility (`isMarginTradingAllowed`) is kept alongside so
+//! leveraged buying power can be capped per symbol.
+
+use crate::domain::ports::QuantityRounder;
+use crate::domain::trading::lot_size::LotSize;
+use crate::domain::trading::types::denormalize_crypto_symbol;
+use anyhow::{Context, Result};
+use async_trait::async_trait;
+use reqwest_middleware::ClientWithMiddleware;
+use rust_decimal::Decimal;
+use rust_decimal_macros::dec;
+use serde::Deserialize;
+use std::collections::HashMap;
+use std::sync::{Arc, RwLock};
+use std::time::Duration;
+use tracing::{info, warn};
+
+/// Binance cross-margin leverage cap for margin-enabled pairs
+pub const CROSS_MARGIN_MAX_LEVERAGE: Decimal = dec!(3);
+
+/// Cached trading rules for one exchange symbol
+#[derive(Debug, Clone, Copy)]
+struct SymbolRules {
+    lot: LotSize,
+    max_leverage: Decimal,
+}
+
+pub struct BinanceExchangeInfo {
+    client: ClientWithMiddleware,
+    base_url: String,
+    filters: RwLock<HashMap<String, SymbolRules>>,
+}
+
+impl BinanceExchangeInfo {
+    pub fn new(client: ClientWithMiddleware, base_url: String) -> Self {
+        Self {
+            client,
+            base_url,
+            filters: RwLock::new(HashMap::new()),
+        }
+    }
+
+    /// Fetches the filters now and then every `interval` in the background.
+    /// On failure the previous filters are kept.
+    pub fn spawn_refresh(self: &Arc<Self>, interval: Duration) {
+        let this = Arc::clone(self);
+        tokio::spawn(async move {
+            loop {
+                if let Err(e) = this.refresh().await {
+                    warn!("BinanceExchangeInfo: Refresh failed: {}", e);
+                }
+                tokio::time::sleep(interval).await;
+            }
+        });
+    }
+
+    pub async fn refresh(&self) -> Result<()> {
+        let url = format!("{}/api/v3/exchangeInfo", self.base_url);
+        let response = self
+            .client
+            .get(&url)
+            .send()
+            .await
+            .context("Failed to fetch exchangeInfo from Binance")?;
+
+        if !response.status().is_success() {
+            let error_text = response.text().await.unwrap_or_default();
+            anyhow::bail!("Binance exchangeInfo fetch failed: {}", error_text);
+        }
+
+        let body = response.text().await?;
+        let filters = parse_exchange_info(&body)?;
+        info!(
+            "BinanceExchangeInfo: Cached filters for {} symbols",
+            filters.len()
+        );
+
+        *self
+            .filters
+            .write()
+            .map_err(|e| anyhow::anyhow!("exchangeInfo cache lock poisoned: {}", e))? = filters;
+        Ok(())
+    }
+
+    fn cached(&self, api_symbol: &str) -> Result<Option<SymbolRules>> {
+        Ok(self
+            .filters
+            .read()
+            .map_err(|e| anyhow::anyhow!("exchangeInfo cache lock poisoned: {}", e))?
+            .get(api_symbol)
+            .copied())
+    }
+
+    async fn rules(&self, symbol: &str) -> Result<SymbolRules> {
+        let api_symbol = denormalize_crypto_symbol(symbol);
+        if let Some(rules) = self.cached(&api_symbol)? {
+            return Ok(rules);
+        }
+
+        // Not cached yet (startup fetch pending or new listing)
+        self.refresh().await?;
+        self.cached(&api_symbol)?
+            .ok_or_else(|| anyhow::anyhow!("No Binance symbol filters for {}", api_symbol))
+    }
+
+    /// Highest leverage allowed on `symbol` (1 when margin trading is not enabled for it)
+    pub async fn max_leverage(&self, symbol: &str) -> Result<Decimal> {
+        Ok(self.rules(symbol).await?.max_leverage)
+    }
+}
+
+#[async_trait]
+impl QuantityRounder for BinanceExchangeInfo {
+    async fn lot_size(&self, symbol: &str) -> Result<LotSize> {
+        Ok(self.rules(symbol).await?.lot)
+    }
+}
+
+#[derive(Debug, Deserialize)]
+struct SymbolFilter {
+    #[serde(rename = "filterType")]
+    filter_type: String,
+    #[serde(rename = "tickSize")]
+    tick_size: Option<String>,
+    #[serde(rename = "stepSize")]
+    step_size: Option<String>,
+    #[serde(rename = "minQty")]
+    min_qty: Option<String>,
+    #[serde(rename = "minNotional")]
+    min_notional: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+struct SymbolInfo {
+    symbol: String,
+    #[serde(default)]
+    filters: Vec<SymbolFilter>,
+    #[serde(rename = "isMarginTradingAllowed", default)]
+    is_margin_trading_allowed: bool,
+}
+
+#[derive(Debug, Deserialize)]
+struct ExchangeInfo {
+    symbols: Vec<SymbolInfo>,
+}
+
+/// Symbol filters per exchange symbol (e.g. `BTCUSDT`)
+fn parse_exchange_info(body: &str) -> Result<HashMap<String, SymbolRules>> {
+    let info: ExchangeInfo =
+        serde_json::from_str(body).context("Failed to parse Binance exchangeInfo")?;
+    let parse = |v: &Option<String>| {
+        v.as_deref()
+            .and_then(|s| s.parse::<Decimal>().ok())
+            .unwrap_or(Decimal::ZERO)
+            .normalize()
+    };
+
+    Ok(info
+        .symbols
+        .into_iter()
+        .map(|s| {
+            let mut lot = LotSize {
+                step_size: Decimal::ZERO,
+                min_qty: Decimal::ZERO,
+                min_notional: Decimal::ZERO,
+                tick_size: Decimal::ZERO,
+            };
+            for f in &s.filters {
+                match f.filter_type.as_str() {
+                    "PRICE_FILTER" => lot.tick_size = parse(&f.tick_size),
+                    "LOT_SIZE" => {
+                        lot.step_size = parse(&f.step_size);
+                        lot.min_qty = parse(&f.min_qty);
+                    }
+                    "MIN_NOTIONAL" | "NOTIONAL" => {
+                        lot.min_notional = lot.min_notional.max(parse(&f.min_notional));
+                    }
+                    _ => {}
+                }
+            }
+            let max_leverage = if s.is_margin_trading_allowed {
+                CROSS_MARGIN_MAX_LEVERAGE
+            } else {
+                Decimal::ONE
+            };
+            (s.symbol, SymbolRules { lot, max_leverage })
+        })
+        .collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::trading::types::OrderSide;
+    use rust_decimal_macros::dec;
+
+    #[test]
+    fn test_parse_exchange_info_filters() {
+        let body = r#"{"symbols":[{"symbol":"BTCUSDT","isMarginTradingAllowed":true,"filters":[
+            {"filterType":"PRICE_FILTER","minPrice":"0.01","maxPrice":"1000000.00","tickSize":"0.01000000"},
+            {"filterType":"LOT_SIZE","minQty":"0.00001000","maxQty":"9000.0","stepSize":"0.00001000"},
+            {"filterType":"NOTIONAL","minNotional":"5.00000000","applyMinToMarket":true}
+        ]},{"symbol":"PEPEUSDT","filters":[]}]}"#;
+
+        let filters = parse_exchange_info(body).unwrap();
+        assert_eq!(filters["BTCUSDT"].max_leverage, CROSS_MARGIN_MAX_LEVERAGE);
+        assert_eq!(filters["PEPEUSDT"].max_leverage, Decimal::ONE);
+        let btc = filters["BTCUSDT"].lot;
+        assert_eq!(btc.tick_size, dec!(0.01));
+        assert_eq!(btc.step_size, dec!(0.00001));
+        assert_eq!(btc.min_qty, dec!(0.00001));
+        assert_eq!(btc.min_notional, dec!(5));
+        assert_eq!(btc.round(dec!(0.0123456), dec!(60000)), dec!(0.01234));
+        assert_eq!(
+            btc.round_price(dec!(60000.12345), OrderSide::Buy),
+            dec!(60000.12)
+        );
+    }
+}
@@ -6,7 +6,9 @@
 //! - Open orders management
 //! - HMAC-SHA256 request signing
 
-use crate::domain::ports::{ExecutionService, OrderUpdate};
+use super::exchange_info::BinanceExchangeInfo;
+use crate::domain::errors::OrderSubmitError;
+use crate::domain::ports::{ExecutionService, OrderUpdate, QuantityRounder};
 use crate::domain::trading::portfolio::{Portfolio, Position};
 use crate::domain::trading::types::{
     Order, OrderSide, OrderType, denormalize_crypto_symbol, normalize_crypto_symbol,
@@ -21,6 +23,7 @@ use reqwest_middleware::ClientWithMiddleware;
 use rust_decimal::Decimal;
 use serde::Deserialize;
 use sha2::Sha256;
+use std::collections::VecDeque;
 use std::sync::Arc;
 use tokio::sync::broadcast;
 use tracing::{info, warn};
@@ -32,6 +35,7 @@ pub struct BinanceExecutionService {
     base_url: String,
     order_update_tx: broadcast::Sender<OrderUpdate>,
     circuit_breaker: Arc<CircuitBreaker>,
+    exchange_info: Arc<BinanceExchangeInfo>,
 }
 
 impl BinanceExecutionService {
@@ -45,6 +49,8 @@ impl BinanceExecutionService {
             std::time::Duration::from_secs(60),
         ));
 
+        let exchange_info = Arc::new(BinanceExchangeInfo::new(client.clone(), base_url.clone()));
+
         Self {
             client,
             api_key,
@@ -52,9 +58,15 @@ impl BinanceExecutionService {
             base_url,
             order_update_tx,
             circuit_breaker,
+            exchange_info,
         }
     }
 
+    /// Starts fetching symbol filters now and every `interval` thereafter
+    pub fn spawn_exchange_info_refresh(&self, interval: std::time::Duration) {
+        self.exchange_info.spawn_refresh(interval);
+    }
+
     /// Generate HMAC-SHA256 signature for Binance API requests
     fn sign_request(&self, query_string: &str) -> String {
         type HmacSha256 = Hmac<Sha256>;
@@ -69,7 +81,27 @@ impl BinanceExecutionService {
 
 #[async_trait]
 impl ExecutionService for BinanceExecutionService {
-    async fn execute(&self, order: Order) -> Result<()> {
+    async fn execute(&self, mut order: Order) -> Result<()> {
+        // Spot has no reduceOnly parameter (futures-only): clamp against the live balance
+        if order.reduce_only {
+            let held = self
+                .get_portfolio()
+                .await?
+                .positions
+                .get(&order.symbol)
+                .map(|p| p.quantity)
+                .unwrap_or(Decimal::ZERO);
+            order.quantity =
+                order
+                    .reduce_only_quantity(held)
+                    .ok_or_else(|| OrderSubmitError::Rejected {
+                        reason: format!(
+                            "Reduce-only {} {} would open or increase the position (held {})",
+                            order.side, order.symbol, held
+                        ),
+                    })?;
+        }
+
         self.circuit_breaker
             .call(async move {
                 let api_symbol = denormalize_crypto_symbol(&order.symbol);
@@ -86,21 +118,43 @@ impl ExecutionService for BinanceExecutionService {
                     OrderType::StopLimit => "STOP_LOSS_LIMIT",
                 };
 
+                // Round to the symbol's step/tick size; Binance rejects anything else
+                let (quantity, price) = match self.exchange_info.lot_size(&order.symbol).await {
+                    Ok(lot) => {
+                        let quantity = lot.round(order.quantity, order.price);
+                        if quantity <= Decimal::ZERO {
+                            anyhow::bail!(
+                                "Order for {} rejected locally: quantity {} is below the Binance lot size / min notional",
+                                order.symbol,
+                                order.quantity
+                            );
+                        }
+                        (quantity, lot.round_price(order.price, order.side))
+                    }
+                    Err(e) => {
+                        warn!(
+                            "BinanceExecution: Symbol filters unavailable for {} ({}). Submitting unrounded",
+                            order.symbol, e
+                        );
+                        (order.quantity, order.price)
+                    }
+                };
+
                 let timestamp = chrono::Utc::now().timestamp_millis();
 
                 let mut params = vec![
                     ("symbol", api_symbol.clone()),
                     ("side", side.to_string()),
                     ("type", order_type.to_string()),
-                    ("quantity", order.quantity.to_string()),
+                    ("quantity", quantity.to_string()),
                     ("newClientOrderId", order.id.clone()),
                     ("timestamp", timestamp.to_string()),
                 ];
 
                 if let OrderType::Limit = order.order_type
-                    && order.price > Decimal::ZERO
+                    && price > Decimal::ZERO
                 {
-                    params.push(("price", order.price.to_string()));
+                    params.push(("price", price.to_string()));
                     params.push(("timeInForce", "GTC".to_string()));
                 }
 
@@ -121,11 +175,26 @@ impl ExecutionService for BinanceExecutionService {
                     .header("X-MBX-APIKEY", &self.api_key)
                     .send()
                     .await
-                    .context("Failed to place order on Binance")?;
+                    .map_err(|e| OrderSubmitError::Transient {
+                        reason: format!("Failed to place order on Binance: {}", e),
+                    })?;
 
-                if !response.status().is_success() {
+                let status = response.status();
+                if !status.is_success() {
                     let error_text = response.text().await.unwrap_or_default();
-                    anyhow::bail!("Binance order placement failed: {}", error_text);
+                    // A reused newClientOrderId means the first attempt already reached Binance
+                    if error_text.contains("Duplicate order sent") {
+                        warn!(
+                            "Binance order {} already submitted (duplicate newClientOrderId), not placing again",
+                            order.id
+                        );
+                        return Ok(());
+                    }
+                    return Err(OrderSubmitError::from_status(
+                        status.as_u16(),
+                        format!("Binance order placement failed: {}", error_text),
+                    )
+                    .into());
                 }
 
                 let response_json: serde_json::Value = response.json().await?;
@@ -204,6 +273,7 @@ impl ExecutionService for BinanceExecutionService {
                                     symbol,
                                     quantity: total,
                                     average_price: Decimal::ZERO, // Need to fetch average if possible
+                                    lots: VecDeque::new(),
                                 },
                             );
                         }
@@ -308,7 +378,10 @@ impl ExecutionService for BinanceExecutionService {
                     quantity,
                     price,
                     status: crate::domain::trading::types::OrderStatus::New,
-                    timestamp: chrono::Utc::now().timestamp(),
+                    timestamp: chrono::Utc::now().timestamp_millis(),
+                    reduce_only: false,
+                    origin: Default::default(),
+                    reason: None,
                 })
             })
             .collect();
@@ -316,6 +389,42 @@ impl ExecutionService for BinanceExecutionService {
         Ok(orders)
     }
 
+    async fn client_order_exists(
+        &self,
+        symbol: &str,
+        client_order_id: &str,
+    ) -> Result<Option<bool>> {
+        // newClientOrderId is only unique among open orders, so a filled order must be looked up
+        let api_symbol = denormalize_crypto_symbol(symbol);
+        let timestamp = chrono::Utc::now().timestamp_millis();
+        let query_string = format!(
+            "symbol={}&origClientOrderId={}&timestamp={}",
+            api_symbol, client_order_id, timestamp
+        );
+        let signature = self.sign_request(&query_string);
+        let signed_query = format!("{}&signature={}", query_string, signature);
+
+        let url = format!("{}/api/v3/order?{}", self.base_url, signed_query);
+
+        let response = self
+            .client
+            .get(&url)
+            .header("X-MBX-APIKEY", &self.api_key)
+            .send()
+            .await
+            .context(format!("Failed to look up order {}", client_order_id))?;
+
+        if response.status().is_success() {
+            return Ok(Some(true));
+        }
+        let error_text = response.text().await.unwrap_or_default();
+        // -2013: "Order does not exist."
+        if error_text.contains("-2013") {
+            return Ok(Some(false));
+        }
+        anyhow::bail!("Binance order lookup failed: {}", error_text);
+    }
+
     async fn cancel_order(&self, order_id: &str, symbol: &str) -> Result<()> {
         let api_symbol = denormalize_crypto_symbol(symbol);
         let timestamp = chrono::Utc::now().timestamp_millis();
@@ -400,6 +509,14 @@ impl ExecutionService for BinanceExecutionService {
 
         Ok(self.order_update_tx.subscribe())
     }
+
+    fn quantity_rounder(&self) -> Option<Arc<dyn QuantityRounder>> {
+        Some(self.exchange_info.clone())
+    }
+
+    async fn max_leverage(&self, symbol: &str) -> Result<Option<Decimal>> {
+        self.exchange_info.max_leverage(symbol).await.map(Some)
+    }
 }
 
 #[cfg(test)]
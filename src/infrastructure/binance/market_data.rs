@@ -390,8 +390,8 @@ impl MarketDataService for BinanceMarketDataService {
 
         // Check cache first
         if let Some(repo) = &self.candle_repository {
-            let start_ts = start.timestamp();
-            let end_ts = end.timestamp();
+            let start_ts = start.timestamp_millis();
+            let end_ts = end.timestamp_millis();
 
             if let Ok(cached_candles) = repo.get_range(symbol, start_ts, end_ts).await
                 && cached_candles.len() >= MIN_REQUIRED_BARS
@@ -1,9 +1,11 @@
 pub mod common;
+pub mod exchange_info;
 pub mod execution;
 pub mod market_data;
 pub mod sector_provider;
 pub mod websocket;
 
+pub use exchange_info::BinanceExchangeInfo;
 pub use execution::BinanceExecutionService;
 pub use market_data::{BinanceMarketDataService, BinanceMarketDataServiceBuilder};
 pub use sector_provider::BinanceSectorProvider;
@@ -0,0 +1,112 @@
+use crate::domain::ports::EarningsCalendarProvider;
+use anyhow::Context;
+use async_trait::async_trait;
+use chrono::{Days, NaiveDate};
+use reqwest::Client;
+use serde::Deserialize;
+use std::time::Duration;
+
+/// Days ahead searched for the next announcement (about one quarter)
+const LOOKAHEAD_DAYS: u64 = 100;
+
+#[derive(Debug, Deserialize)]
+struct FinnhubEarningsResponse {
+    #[serde(rename = "earningsCalendar", default)]
+    earnings_calendar: Vec<FinnhubEarning>,
+}
+
+#[derive(Debug, Deserialize)]
+struct FinnhubEarning {
+    date: String,
+}
+
+/// Upcoming earnings dates from Finnhub's `/calendar/earnings` endpoint
+pub struct FinnhubEarningsCalendar {
+    client: Client,
+    api_key: String,
+    base_url: String,
+}
+
+impl FinnhubEarningsCalendar {
+    pub fn new(api_key: String) -> Self {
+        Self {
+            client: Client::builder()
+                .timeout(Duration::from_secs(10))
+                .build()
+                .unwrap_or_default(),
+            api_key,
+            base_url: "https://finnhub.io/api/v1".to_string(),
+        }
+    }
+}
+
+/// Earliest announcement on or after `from` in a calendar response
+fn earliest_date(body: FinnhubEarningsResponse, from: NaiveDate) -> Option<NaiveDate> {
+    body.earnings_calendar
+        .iter()
+        .filter_map(|e| NaiveDate::parse_from_str(&e.date, "%Y-%m-%d").ok())
+        .filter(|d| *d >= from)
+        .min()
+}
+
+#[async_trait]
+impl EarningsCalendarProvider for FinnhubEarningsCalendar {
+    async fn next_earnings_date(
+        &self,
+        symbol: &str,
+        from: NaiveDate,
+    ) -> anyhow::Result<Option<NaiveDate>> {
+        let to = from
+            .checked_add_days(Days::new(LOOKAHEAD_DAYS))
+            .unwrap_or(from);
+        let url = format!("{}/calendar/earnings", self.base_url);
+
+        let response = self
+            .client
+            .get(&url)
+            .query(&[
+                ("symbol", symbol),
+                ("from", &from.to_string()),
+                ("to", &to.to_string()),
+                ("token", &self.api_key),
+            ])
+            .send()
+            .await
+            .context("Failed to send request to Finnhub")?;
+
+        if !response.status().is_success() {
+            anyhow::bail!("Finnhub API returned status: {}", response.status());
+        }
+
+        let body: FinnhubEarningsResponse = response
+            .json()
+            .await
+            .context("Failed to parse Finnhub earnings response")?;
+        Ok(earliest_date(body, from))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_earliest_upcoming_date() {
+        let body: FinnhubEarningsResponse = serde_json::from_str(
+            r#"{"earningsCalendar":[
+                {"date":"2024-04-25","symbol":"AAPL","hour":"amc"},
+                {"date":"2024-01-25","symbol":"AAPL","hour":"amc"},
+                {"date":"2023-10-26","symbol":"AAPL","hour":"amc"}
+            ]}"#,
+        )
+        .unwrap();
+        let from = NaiveDate::from_ymd_opt(2024, 1, 2).unwrap();
+        assert_eq!(
+            earliest_date(body, from),
+            NaiveDate::from_ymd_opt(2024, 1, 25)
+        );
+
+        let empty: FinnhubEarningsResponse = serde_json::from_str("{}").unwrap();
+        assert_eq!(earliest_date(empty, from), None);
+    }
+}
@@ -0,0 +1,7 @@
+//! Earnings calendar providers for the earnings blackout.
+//!
+//! Provides [FinnhubEarningsCalendar], selected when `FINNHUB_API_KEY` is set.
+
+pub mod finnhub;
+
+pub use finnhub::FinnhubEarningsCalendar;
@@ -10,10 +10,47 @@ use crate::infrastructure::observability::Metrics;
 use rust_decimal::prelude::ToPrimitive;
 use std::sync::Arc;
 use tokio::sync::RwLock;
+use tracing::{info, warn};
 
 pub struct ServiceFactory;
 
 impl ServiceFactory {
+    /// History-only market data client for WARMUP_FALLBACK_SOURCE.
+    ///
+    /// Only brokers with a historical bars endpoint qualify (alpaca, binance).
+    pub fn create_warmup_fallback(config: &Config) -> Option<Arc<dyn MarketDataService>> {
+        let source = config.warmup_fallback_source.clone()?;
+        let service: Arc<dyn MarketDataService> = match source {
+            Mode::Alpaca => Arc::new(
+                AlpacaMarketDataService::builder()
+                    .api_key(config.alpaca_api_key.clone())
+                    .api_secret(config.alpaca_secret_key.clone())
+                    .ws_url(config.alpaca_ws_url.clone())
+                    .data_base_url(config.alpaca_data_url.clone())
+                    .api_base_url(config.alpaca_base_url.clone())
+                    .min_volume_threshold(config.min_volume_threshold.to_f64().unwrap_or(10000.0))
+                    .asset_class(config.asset_class)
+                    .build(),
+            ),
+            Mode::Binance => Arc::new(
+                BinanceMarketDataService::builder()
+                    .api_key(config.binance_api_key.clone())
+                    .base_url(config.binance_base_url.clone())
+                    .ws_url(config.binance_ws_url.clone())
+                    .build(),
+            ),
+            Mode::Mock | Mode::Oanda => {
+                warn!(
+                    "ServiceFactory: WARMUP_FALLBACK_SOURCE={:?} has no history endpoint, ignored",
+                    source
+                );
+                return None;
+            }
+        };
+        info!("ServiceFactory: Warmup fallback source: {:?}", source);
+        Some(service)
+    }
+
     pub fn create_services(
         config: &Config,
         candle_repo: Option<Arc<dyn CandleRepository>>,
@@ -157,6 +194,9 @@ impl ServiceFactory {
                     config.binance_secret_key.clone(),
                     config.binance_base_url.clone(),
                 );
+                execution_service.spawn_exchange_info_refresh(std::time::Duration::from_secs(
+                    config.binance_exchange_info_refresh_secs.max(60),
+                ));
 
                 (
                     Arc::new(market_service),
@@ -206,6 +206,64 @@ mod tests {
         }
     }
 
+    /// `{param}` placeholders used in a format string
+    fn placeholders(text: &str) -> Vec<&str> {
+        let mut found: Vec<&str> = text
+            .split('{')
+            .skip(1)
+            .filter_map(|part| part.split_once('}').map(|(name, _)| name))
+            .collect();
+        found.sort_unstable();
+        found.dedup();
+        found
+    }
+
+    #[test]
+    fn test_all_bundles_cover_english_keys() {
+        let i18n = I18nService::new();
+        let reference = i18n
+            .translations
+            .get("en")
+            .expect("English bundle must exist");
+        assert!(
+            i18n.translations.len() >= 2,
+            "Expected at least one bundle besides English"
+        );
+
+        let mut missing = Vec::new();
+        for (code, data) in &i18n.translations {
+            if code == "en" {
+                continue;
+            }
+            for (key, text) in &reference.ui {
+                match data.ui.get(key) {
+                    None => missing.push(format!("{}: ui.{}", code, key)),
+                    Some(translated) if placeholders(translated) != placeholders(text) => {
+                        missing.push(format!("{}: ui.{} placeholders differ", code, key))
+                    }
+                    Some(_) => {}
+                }
+            }
+            for key in reference.help_categories.keys() {
+                if !data.help_categories.contains_key(key) {
+                    missing.push(format!("{}: help_categories.{}", code, key));
+                }
+            }
+            for topic in &reference.help_topics {
+                if !data.help_topics.iter().any(|t| t.id == topic.id) {
+                    missing.push(format!("{}: help_topics.{}", code, topic.id));
+                }
+            }
+        }
+
+        missing.sort();
+        assert!(
+            missing.is_empty(),
+            "Translation bundles are missing keys:\n{}",
+            missing.join("\n")
+        );
+    }
+
     #[test]
     fn test_translation_loading() {
         let i18n = I18nService::new();
@@ -1,5 +1,6 @@
-use crate::domain::ports::{ExecutionService, MarketDataService, OrderUpdate};
+use crate::domain::ports::{ExecutionService, MarketDataService, OrderUpdate, QuantityRounder};
 use crate::domain::trading::fee_model::{ConstantFeeModel, FeeModel}; // Added
+use crate::domain::trading::lot_size::LotSize;
 use crate::domain::trading::types::{MarketEvent, Order};
 use anyhow::Result;
 use async_trait::async_trait;
@@ -92,7 +93,7 @@ impl MockMarketDataService {
             symbol: symbol.to_string(),
             price,
             quantity: Decimal::ONE,
-            timestamp: chrono::Utc::now().timestamp(),
+            timestamp: chrono::Utc::now().timestamp_millis(),
         })
         .await;
     }
@@ -156,7 +157,7 @@ impl MarketDataService for MockMarketDataService {
                             symbol: symbol.clone(),
                             price: Decimal::from_f64_retain(new_price).unwrap_or(Decimal::ZERO),
                             quantity: Decimal::ONE,
-                            timestamp: Utc::now().timestamp(),
+                            timestamp: Utc::now().timestamp_millis(),
                         };
 
                         service_clone.publish(event).await;
@@ -236,11 +237,15 @@ use crate::infrastructure::simulation::slippage_model::{SlippageModel, ZeroSlipp
 pub struct MockExecutionService {
     portfolio: Arc<RwLock<Portfolio>>,
     orders: Arc<RwLock<Vec<Order>>>,
+    // Resting orders reported by get_open_orders (mock fills are immediate)
+    open_orders: Arc<RwLock<Vec<Order>>>,
     fee_model: Arc<dyn FeeModel>,
     // New simulation models
     latency_model: Arc<dyn LatencyModel>,
     slippage_model: Arc<dyn SlippageModel>,
     order_update_sender: broadcast::Sender<OrderUpdate>,
+    // Broker lot size applied to every symbol, if any
+    lot_size: Option<LotSize>,
 }
 
 impl MockExecutionService {
@@ -257,10 +262,12 @@ impl MockExecutionService {
         Self {
             portfolio,
             orders: Arc::new(RwLock::new(Vec::new())),
+            open_orders: Arc::new(RwLock::new(Vec::new())),
             fee_model: Arc::new(ConstantFeeModel::new(Decimal::ZERO, Decimal::ZERO)),
             latency_model: Arc::new(ZeroLatency),
             slippage_model: Arc::new(ZeroSlippage),
             order_update_sender: broadcast::channel(100).0,
+            lot_size: None,
         }
     }
 
@@ -273,10 +280,12 @@ impl MockExecutionService {
         Self {
             portfolio,
             orders: Arc::new(RwLock::new(Vec::new())),
+            open_orders: Arc::new(RwLock::new(Vec::new())),
             fee_model,
             latency_model,
             slippage_model,
             order_update_sender: broadcast::channel(100).0,
+            lot_size: None,
         }
     }
 
@@ -284,17 +293,35 @@ impl MockExecutionService {
         Self {
             portfolio,
             orders: Arc::new(RwLock::new(Vec::new())),
+            open_orders: Arc::new(RwLock::new(Vec::new())),
             fee_model,
             latency_model: Arc::new(ZeroLatency),
             slippage_model: Arc::new(ZeroSlippage),
             order_update_sender: broadcast::channel(100).0,
+            lot_size: None,
         }
     }
+
+    /// Round proposals to `lot_size` for every symbol, like a real broker
+    pub fn with_lot_size(mut self, lot_size: LotSize) -> Self {
+        self.lot_size = Some(lot_size);
+        self
+    }
+
+    /// Orders to report as still open at the broker
+    pub async fn set_open_orders(&self, orders: Vec<Order>) {
+        *self.open_orders.write().await = orders;
+    }
+
+    /// Orders to report as placed today, as if they came from the broker's history
+    pub async fn set_today_orders(&self, orders: Vec<Order>) {
+        *self.orders.write().await = orders;
+    }
 }
 
 #[async_trait]
 impl ExecutionService for MockExecutionService {
-    async fn execute(&self, order: Order) -> Result<()> {
+    async fn execute(&self, mut order: Order) -> Result<()> {
         info!("MockExecution: Placing order {}...", order.id);
 
         // Simulate Network Latency
@@ -313,6 +340,22 @@ impl ExecutionService for MockExecutionService {
                     )
                 })?;
 
+        if order.reduce_only {
+            let held = port
+                .positions
+                .get(&order.symbol)
+                .map(|p| p.quantity)
+                .unwrap_or(Decimal::ZERO);
+            order.quantity = order.reduce_only_quantity(held).ok_or_else(|| {
+                anyhow::anyhow!(
+                    "MockExecution: Reduce-only {} {} would open or increase the position (held {})",
+                    order.side,
+                    order.symbol,
+                    held
+                )
+            })?;
+        }
+
         // Calculate Execution Price with Slippage
         let execution_price =
             self.slippage_model
@@ -325,9 +368,13 @@ impl ExecutionService for MockExecutionService {
         // For backwards compatibility, if FeeModel returns slippage_cost, we add it.
         // But cleaner is to use execution price.
 
-        let costs = self
-            .fee_model
-            .calculate_cost(order.quantity, execution_price, order.side);
+        let costs = self.fee_model.calculate_order_cost(
+            &order.symbol,
+            order.quantity,
+            execution_price,
+            order.side,
+            order.order_type,
+        );
 
         let commission = costs.fee;
 
@@ -364,58 +411,53 @@ impl ExecutionService for MockExecutionService {
                         ));
                     }
                     // Execute with reduced quantity
-                    let reduced_cost = execution_price * affordable_qty;
                     let reduced_commission = self
                         .fee_model
-                        .calculate_cost(affordable_qty, execution_price, order.side)
+                        .calculate_order_cost(
+                            &order.symbol,
+                            affordable_qty,
+                            execution_price,
+                            order.side,
+                            order.order_type,
+                        )
                         .fee;
                     info!(
                         "MockExecution: Order {} reduced qty {} -> {} (cash ${} < needed ${})",
                         order.id, order.quantity, affordable_qty, port.cash, total_needed
                     );
-                    port.cash -= reduced_cost + reduced_commission;
-                    let pos = port.positions.entry(order.symbol.clone()).or_insert(
-                        crate::domain::trading::portfolio::Position {
-                            symbol: order.symbol.clone(),
-                            quantity: Decimal::ZERO,
-                            average_price: Decimal::ZERO,
-                        },
+                    port.apply_buy_fill(
+                        &order.symbol,
+                        affordable_qty,
+                        execution_price,
+                        reduced_commission,
+                        order.timestamp,
                     );
-                    let total_qty = pos.quantity + affordable_qty;
-                    let total_cost_pos = (pos.quantity * pos.average_price) + reduced_cost;
-                    if total_qty > Decimal::ZERO {
-                        pos.average_price = total_cost_pos
-                            .checked_div(total_qty)
-                            .unwrap_or(Decimal::ZERO);
-                    }
-                    pos.quantity = total_qty;
+                    self.fee_model
+                        .record_fill(affordable_qty, execution_price, order.timestamp);
                 } else {
-                    port.cash -= total_needed;
-                    let pos = port.positions.entry(order.symbol.clone()).or_insert(
-                        crate::domain::trading::portfolio::Position {
-                            symbol: order.symbol.clone(),
-                            quantity: Decimal::ZERO,
-                            average_price: Decimal::ZERO,
-                        },
+                    port.apply_buy_fill(
+                        &order.symbol,
+                        order.quantity,
+                        execution_price,
+                        commission,
+                        order.timestamp,
                     );
-                    let total_qty = pos.quantity + order.quantity;
-                    let total_cost_pos = (pos.quantity * pos.average_price) + cost;
-                    if total_qty > Decimal::ZERO {
-                        pos.average_price = total_cost_pos
-                            .checked_div(total_qty)
-                            .unwrap_or(Decimal::ZERO);
-                    }
-                    pos.quantity = total_qty;
+                    self.fee_model
+                        .record_fill(order.quantity, execution_price, order.timestamp);
                 }
             }
             crate::domain::trading::types::OrderSide::Sell => {
-                // Prevent selling more than we hold
+                // Prevent selling more than we hold, unless the excess opens a short
                 let current_qty = port
                     .positions
                     .get(&order.symbol)
                     .map(|p| p.quantity)
                     .unwrap_or(Decimal::ZERO);
-                let sell_qty = order.quantity.min(current_qty);
+                let sell_qty = if port.allow_shorts {
+                    order.quantity
+                } else {
+                    order.quantity.min(current_qty)
+                };
                 if sell_qty <= Decimal::ZERO {
                     info!(
                         "MockExecution: Sell order {} REJECTED — no position to sell",
@@ -423,10 +465,15 @@ impl ExecutionService for MockExecutionService {
                     );
                     return Err(anyhow::anyhow!("No position to sell for {}", order.symbol));
                 }
-                let sell_proceeds = execution_price * sell_qty;
                 let sell_commission = self
                     .fee_model
-                    .calculate_cost(sell_qty, execution_price, order.side)
+                    .calculate_order_cost(
+                        &order.symbol,
+                        sell_qty,
+                        execution_price,
+                        order.side,
+                        order.order_type,
+                    )
                     .fee;
 
                 // Calculate hold time and funding cost
@@ -454,21 +501,25 @@ impl ExecutionService for MockExecutionService {
                 use rust_decimal_macros::dec;
                 let hold_time_hours = rust_decimal::Decimal::from(hold_time_ms) / dec!(3600000.0);
 
+                // Funding only accrues on the long quantity being closed
                 let funding_cost = self.fee_model.calculate_funding_cost(
-                    sell_qty,
+                    sell_qty.min(current_qty.max(Decimal::ZERO)),
                     execution_price,
                     hold_time_hours,
                 );
 
-                port.cash += sell_proceeds - sell_commission - funding_cost;
-                let pos = port.positions.entry(order.symbol.clone()).or_insert(
-                    crate::domain::trading::portfolio::Position {
-                        symbol: order.symbol.clone(),
-                        quantity: Decimal::ZERO,
-                        average_price: Decimal::ZERO,
-                    },
+                let realized = port.apply_sell_fill(
+                    &order.symbol,
+                    sell_qty,
+                    execution_price,
+                    sell_commission + funding_cost,
+                );
+                self.fee_model
+                    .record_fill(sell_qty, execution_price, order.timestamp);
+                info!(
+                    "MockExecution: Sell order {} realized P&L ${:.2} (session total ${:.2})",
+                    order.id, realized, port.realized_pnl
                 );
-                pos.quantity -= sell_qty;
             }
         }
 
@@ -508,7 +559,7 @@ impl ExecutionService for MockExecutionService {
     }
 
     async fn get_open_orders(&self) -> Result<Vec<Order>> {
-        Ok(vec![])
+        Ok(self.open_orders.read().await.clone())
     }
 
     async fn cancel_order(&self, _order_id: &str, _symbol: &str) -> Result<()> {
@@ -526,6 +577,21 @@ impl ExecutionService for MockExecutionService {
     async fn subscribe_order_updates(&self) -> Result<broadcast::Receiver<OrderUpdate>> {
         Ok(self.order_update_sender.subscribe())
     }
+
+    fn quantity_rounder(&self) -> Option<Arc<dyn QuantityRounder>> {
+        self.lot_size
+            .map(|lot_size| Arc::new(FixedLotSize(lot_size)) as Arc<dyn QuantityRounder>)
+    }
+}
+
+/// Same lot size for every symbol
+struct FixedLotSize(LotSize);
+
+#[async_trait]
+impl QuantityRounder for FixedLotSize {
+    async fn lot_size(&self, _symbol: &str) -> Result<LotSize> {
+        Ok(self.0)
+    }
 }
 
 pub struct NullTradeRepository;
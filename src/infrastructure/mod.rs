@@ -1,6 +1,7 @@
 pub mod alpaca;
 pub mod binance;
 pub mod core;
+pub mod earnings;
 pub mod factory;
 pub mod i18n;
 pub mod mock;
@@ -0,0 +1,203 @@
+//! JSON Feed news provider.
+//!
+//! Polls a [JSON Feed 1.1](https://www.jsonfeed.org/version/1.1/) endpoint and
+//! emits each unseen item as a [`NewsEvent`], scored with the local sentiment analyzer.
+
+use crate::domain::listener::NewsEvent;
+use crate::domain::ports::NewsDataService;
+use crate::infrastructure::news::sentiment_analyzer::SentimentAnalyzer;
+use anyhow::{Context, Result};
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use reqwest::Client;
+use serde::Deserialize;
+use std::collections::HashSet;
+use std::sync::Arc;
+use tokio::sync::Mutex;
+use tokio::sync::mpsc::{self, Receiver};
+use tracing::{debug, error, info};
+
+#[derive(Debug, Deserialize)]
+struct JsonFeed {
+    #[serde(default)]
+    title: Option<String>,
+    #[serde(default)]
+    items: Vec<JsonFeedItem>,
+}
+
+#[derive(Debug, Deserialize)]
+struct JsonFeedItem {
+    id: String,
+    #[serde(default)]
+    url: Option<String>,
+    #[serde(default)]
+    title: Option<String>,
+    #[serde(default)]
+    content_text: Option<String>,
+    #[serde(default)]
+    content_html: Option<String>,
+    #[serde(default)]
+    summary: Option<String>,
+    #[serde(default)]
+    date_published: Option<String>,
+}
+
+/// Parse a JSON Feed document into news events (without sentiment scores).
+fn parse_feed(bytes: &[u8]) -> Result<Vec<NewsEvent>> {
+    let feed: JsonFeed = serde_json::from_slice(bytes).context("Invalid JSON Feed document")?;
+    let source = feed.title.unwrap_or_else(|| "JSON Feed".to_string());
+
+    Ok(feed
+        .items
+        .into_iter()
+        .map(|item| {
+            let timestamp = item
+                .date_published
+                .as_deref()
+                .and_then(|d| DateTime::parse_from_rfc3339(d).ok())
+                .map(|d| d.with_timezone(&Utc))
+                .unwrap_or_else(Utc::now);
+            let content = item
+                .content_text
+                .or(item.summary)
+                .or(item.content_html)
+                .unwrap_or_default();
+
+            NewsEvent {
+                id: item.id,
+                source: source.clone(),
+                title: item.title.unwrap_or_else(|| "No Title".to_string()),
+                content,
+                url: item.url,
+                timestamp,
+                sentiment_score: None,
+            }
+        })
+        .collect())
+}
+
+pub struct JsonFeedNewsService {
+    url: String,
+    client: Client,
+    seen_ids: Arc<Mutex<HashSet<String>>>,
+    poll_interval_seconds: u64,
+    sentiment_analyzer: Arc<SentimentAnalyzer>,
+}
+
+impl JsonFeedNewsService {
+    pub fn new(url: &str, poll_interval_seconds: u64) -> Self {
+        Self {
+            url: url.to_string(),
+            client: Client::new(),
+            seen_ids: Arc::new(Mutex::new(HashSet::new())),
+            poll_interval_seconds,
+            sentiment_analyzer: Arc::new(SentimentAnalyzer::new()),
+        }
+    }
+}
+
+async fn fetch_feed(client: &Client, url: &str) -> Result<Vec<NewsEvent>> {
+    let bytes = client.get(url).send().await?.bytes().await?;
+    parse_feed(&bytes)
+}
+
+#[async_trait]
+impl NewsDataService for JsonFeedNewsService {
+    async fn subscribe_news(&self) -> Result<Receiver<NewsEvent>> {
+        let (tx, rx) = mpsc::channel(100);
+        let url = self.url.clone();
+        let client = self.client.clone();
+        let seen_ids = self.seen_ids.clone();
+        let interval_sec = self.poll_interval_seconds;
+        let sentiment_analyzer = self.sentiment_analyzer.clone();
+
+        tokio::spawn(async move {
+            info!("Starting JSON Feed News Poller for: {}", url);
+
+            // Mark the current items as seen to avoid flooding on restart
+            match fetch_feed(&client, &url).await {
+                Ok(events) => {
+                    let mut ids = seen_ids.lock().await;
+                    ids.extend(events.into_iter().map(|e| e.id));
+                    info!(
+                        "Initialized JSON Feed Poller: Marked {} items as seen.",
+                        ids.len()
+                    );
+                }
+                Err(e) => error!("Initial JSON Feed fetch failed: {}", e),
+            }
+
+            loop {
+                tokio::time::sleep(tokio::time::Duration::from_secs(interval_sec)).await;
+
+                debug!("Polling JSON Feed...");
+                let events = match fetch_feed(&client, &url).await {
+                    Ok(events) => events,
+                    Err(e) => {
+                        error!("Failed to fetch JSON Feed: {}", e);
+                        continue;
+                    }
+                };
+
+                let mut ids = seen_ids.lock().await;
+                for mut event in events {
+                    if !ids.insert(event.id.clone()) {
+                        continue;
+                    }
+
+                    let score = sentiment_analyzer.analyze_news(&event.title, &event.content);
+                    event.sentiment_score = Some(score);
+                    info!("JSON Feed New Item: {} (score: {:.2})", event.title, score);
+
+                    if let Err(e) = tx.send(event).await {
+                        error!("Failed to send JSON Feed event: {}", e);
+                        return; // Channel closed
+                    }
+                }
+            }
+        });
+
+        Ok(rx)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_feed_items() {
+        let doc = br#"{
+            "version": "https://jsonfeed.org/version/1.1",
+            "title": "Crypto Wire",
+            "items": [
+                {
+                    "id": "1",
+                    "url": "https://example.com/1",
+                    "title": "Bitcoin ETF approved",
+                    "content_text": "Regulators approve the first spot ETF.",
+                    "date_published": "2026-01-14T15:30:00Z"
+                },
+                { "id": "2", "summary": "No title here" }
+            ]
+        }"#;
+
+        let events = parse_feed(doc).unwrap();
+        assert_eq!(events.len(), 2);
+        assert_eq!(events[0].source, "Crypto Wire");
+        assert_eq!(events[0].title, "Bitcoin ETF approved");
+        assert_eq!(events[0].url.as_deref(), Some("https://example.com/1"));
+        assert_eq!(
+            events[0].timestamp.to_rfc3339(),
+            "2026-01-14T15:30:00+00:00"
+        );
+        assert_eq!(events[1].title, "No Title");
+        assert_eq!(events[1].content, "No title here");
+        assert!(events[1].sentiment_score.is_none());
+    }
+
+    #[test]
+    fn test_parse_feed_rejects_invalid_document() {
+        assert!(parse_feed(b"<rss></rss>").is_err());
+    }
+}
@@ -1,3 +1,4 @@
+pub mod json_feed;
 pub mod mock_news;
 pub mod rss;
 pub mod sentiment_analyzer;
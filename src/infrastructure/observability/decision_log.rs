@@ -0,0 +1,138 @@
+//! Append-only JSONL audit log of trading decisions.
+//!
+//! Subscribes to the [`EventBus`](crate::infrastructure::core::event_bus::EventBus) and
+//! writes every [`TradingEvent::Decision`] as one JSON line, so the full
+//! signal → proposal → order → fill funnel can be replayed after the fact.
+
+use crate::domain::trading::events::{DecisionEvent, EventListener, TradingEvent};
+use std::fs::{File, OpenOptions};
+use std::io::{BufRead, BufReader, Write};
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+use tracing::warn;
+
+/// File sink persisting decision events as JSONL
+pub struct DecisionLogSink {
+    path: PathBuf,
+    file: Mutex<File>,
+}
+
+impl DecisionLogSink {
+    /// Open (or create) the log file in append mode
+    pub fn open(path: impl AsRef<Path>) -> anyhow::Result<Self> {
+        let path = path.as_ref().to_path_buf();
+        if let Some(parent) = path.parent().filter(|p| !p.as_os_str().is_empty()) {
+            std::fs::create_dir_all(parent)?;
+        }
+        let file = OpenOptions::new().create(true).append(true).open(&path)?;
+        Ok(Self {
+            path,
+            file: Mutex::new(file),
+        })
+    }
+
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    fn append(&self, decision: &DecisionEvent) -> anyhow::Result<()> {
+        let line = serde_json::to_string(decision)?;
+        let mut file = self
+            .file
+            .lock()
+            .map_err(|_| anyhow::anyhow!("decision log lock poisoned"))?;
+        writeln!(file, "{}", line)?;
+        Ok(())
+    }
+
+    /// Load decisions from a log file, optionally restricted to one symbol.
+    /// Malformed lines are skipped.
+    pub fn load(
+        path: impl AsRef<Path>,
+        symbol: Option<&str>,
+    ) -> anyhow::Result<Vec<DecisionEvent>> {
+        let reader = BufReader::new(File::open(path)?);
+        let mut events = Vec::new();
+        for line in reader.lines() {
+            let line = line?;
+            if line.trim().is_empty() {
+                continue;
+            }
+            match serde_json::from_str::<DecisionEvent>(&line) {
+                Ok(event) if symbol.is_none_or(|s| event.symbol == s) => events.push(event),
+                Ok(_) => {}
+                Err(e) => warn!("DecisionLog: skipping malformed line: {}", e),
+            }
+        }
+        Ok(events)
+    }
+}
+
+impl EventListener for DecisionLogSink {
+    fn on_event(&self, event: &TradingEvent) {
+        if let TradingEvent::Decision(decision) = event
+            && let Err(e) = self.append(decision)
+        {
+            warn!(
+                "DecisionLog: failed to write to {}: {}",
+                self.path.display(),
+                e
+            );
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::trading::events::DecisionKind;
+    use crate::domain::trading::types::OrderSide;
+    use crate::infrastructure::core::event_bus::EventBus;
+    use std::sync::Arc;
+
+    #[tokio::test]
+    async fn test_decision_log_persists_and_filters() {
+        let path =
+            std::env::temp_dir().join(format!("rustrade_decisions_{}.jsonl", uuid::Uuid::new_v4()));
+        let bus = EventBus::new();
+        bus.subscribe(Arc::new(DecisionLogSink::open(&path).unwrap()))
+            .await;
+
+        bus.publish(TradingEvent::Decision(DecisionEvent::new(
+            DecisionKind::SignalGenerated,
+            "AAPL",
+            OrderSide::Buy,
+            "SMA crossover",
+        )))
+        .await;
+        bus.publish(TradingEvent::Decision(
+            DecisionEvent::new(
+                DecisionKind::ProposalRejected,
+                "MSFT",
+                OrderSide::Buy,
+                "Sector exposure too high",
+            )
+            .with_blocked_by("SectorExposureValidator"),
+        ))
+        .await;
+        // Non-decision events are ignored by the sink
+        bus.publish(TradingEvent::CircuitBreakerActivated {
+            reason: "test".to_string(),
+            cooldown_seconds: 1,
+        })
+        .await;
+
+        let all = DecisionLogSink::load(&path, None).unwrap();
+        assert_eq!(all.len(), 2);
+        assert_eq!(all[0].kind, DecisionKind::SignalGenerated);
+
+        let msft = DecisionLogSink::load(&path, Some("MSFT")).unwrap();
+        assert_eq!(msft.len(), 1);
+        assert_eq!(
+            msft[0].blocked_by.as_deref(),
+            Some("SectorExposureValidator")
+        );
+
+        let _ = std::fs::remove_file(&path);
+    }
+}
@@ -26,6 +26,8 @@ pub struct Metrics {
     pub orders_total: CounterVec,
     /// Circuit breaker status (0=open, 1=tripped)
     pub circuit_breaker_status: GenericGauge<AtomicF64>,
+    /// Operator pause status (0=active, 1=paused)
+    pub trading_paused: GenericGauge<AtomicF64>,
     /// Sentiment score (Fear & Greed index)
     pub sentiment_score: GenericGauge<AtomicF64>,
     /// Uptime in seconds
@@ -36,16 +38,28 @@ pub struct Metrics {
     pub websocket_reconnects_total: CounterVec,
     /// Strategy signals generated
     pub trade_signals_total: CounterVec,
+    /// Proposals received by the Risk Manager
+    pub proposals_total: CounterVec,
+    /// Signals and proposals blocked, by stage (filter/risk) and reason
+    pub rejections_total: CounterVec,
     /// Current win rate (0-1)
     pub win_rate_current: GenericGauge<AtomicF64>,
     /// Current drawdown (0-1)
     pub drawdown_current: GenericGauge<AtomicF64>,
+    /// Total position value as a fraction of equity
+    pub gross_exposure_pct: GenericGauge<AtomicF64>,
     /// Trades today
     pub trades_today: CounterVec,
     /// Agent status (1=Online, 0=Offline)
     pub agent_up: GaugeVec,
     /// Agent last heartbeat timestamp
     pub agent_last_heartbeat: GaugeVec,
+    /// Value of position mismatches between local state and the broker
+    pub position_divergence_usd: GenericGauge<AtomicF64>,
+    /// Messages queued per event-bus channel
+    pub channel_depth: GaugeVec,
+    /// Broadcast messages a lagging receiver missed, per channel
+    pub channel_lagged_total: CounterVec,
 }
 
 impl Metrics {
@@ -96,6 +110,12 @@ impl Metrics {
         ))?;
         registry.register(Box::new(circuit_breaker_status.clone()))?;
 
+        let trading_paused = Gauge::with_opts(Opts::new(
+            "rustrade_trading_paused",
+            "Operator pause status (0=active, 1=paused)",
+        ))?;
+        registry.register(Box::new(trading_paused.clone()))?;
+
         let sentiment_score = Gauge::with_opts(Opts::new(
             "rustrade_sentiment_score",
             "Fear & Greed sentiment index (0-100)",
@@ -138,6 +158,24 @@ impl Metrics {
         )?;
         registry.register(Box::new(trade_signals_total.clone()))?;
 
+        let proposals_total = CounterVec::new(
+            Opts::new(
+                "rustrade_proposals_total",
+                "Total trade proposals sent to the risk manager",
+            ),
+            &["side"],
+        )?;
+        registry.register(Box::new(proposals_total.clone()))?;
+
+        let rejections_total = CounterVec::new(
+            Opts::new(
+                "rustrade_rejections_total",
+                "Total signals and proposals blocked, by stage and reason",
+            ),
+            &["stage", "reason"],
+        )?;
+        registry.register(Box::new(rejections_total.clone()))?;
+
         let win_rate_current = Gauge::with_opts(Opts::new(
             "rustrade_win_rate_current",
             "Current win rate (0-1)",
@@ -150,6 +188,12 @@ impl Metrics {
         ))?;
         registry.register(Box::new(drawdown_current.clone()))?;
 
+        let gross_exposure_pct = Gauge::with_opts(Opts::new(
+            "rustrade_gross_exposure_pct",
+            "Total position value as a fraction of equity",
+        ))?;
+        registry.register(Box::new(gross_exposure_pct.clone()))?;
+
         let trades_today = CounterVec::new(
             Opts::new("rustrade_trades_today", "Total trades executed today"),
             &["side", "outcome"],
@@ -171,6 +215,30 @@ impl Metrics {
         )?;
         registry.register(Box::new(agent_last_heartbeat.clone()))?;
 
+        let position_divergence_usd = Gauge::with_opts(Opts::new(
+            "rustrade_position_divergence_usd",
+            "Value of position mismatches between local state and the broker in USD",
+        ))?;
+        registry.register(Box::new(position_divergence_usd.clone()))?;
+
+        let channel_depth = GaugeVec::new(
+            Opts::new(
+                "rustrade_channel_depth",
+                "Messages currently queued per event-bus channel",
+            ),
+            &["channel"],
+        )?;
+        registry.register(Box::new(channel_depth.clone()))?;
+
+        let channel_lagged_total = CounterVec::new(
+            Opts::new(
+                "rustrade_channel_lagged_total",
+                "Broadcast messages dropped because a receiver lagged, per channel",
+            ),
+            &["channel"],
+        )?;
+        registry.register(Box::new(channel_lagged_total.clone()))?;
+
         Ok(Self {
             registry: Arc::new(registry),
             portfolio_value_usd,
@@ -180,16 +248,23 @@ impl Metrics {
             daily_pnl_usd,
             orders_total,
             circuit_breaker_status,
+            trading_paused,
             sentiment_score,
             uptime_seconds,
             api_latency_seconds,
             websocket_reconnects_total,
             trade_signals_total,
+            proposals_total,
+            rejections_total,
             win_rate_current,
             drawdown_current,
+            gross_exposure_pct,
             trades_today,
             agent_up,
             agent_last_heartbeat,
+            position_divergence_usd,
+            channel_depth,
+            channel_lagged_total,
         })
     }
 
@@ -234,6 +309,59 @@ impl Metrics {
             .with_label_values(&[strategy, signal_type])
             .inc();
     }
+
+    /// Increment proposals sent to the risk manager
+    pub fn inc_proposals(&self, side: &str) {
+        self.proposals_total.with_label_values(&[side]).inc();
+    }
+
+    /// Increment rejections (`stage` is "filter" for Analyst filters, "risk" for the Risk Manager)
+    pub fn inc_rejections(&self, stage: &str, reason: &str) {
+        self.rejections_total
+            .with_label_values(&[stage, reason])
+            .inc();
+    }
+
+    /// Record how many messages are queued on a channel
+    pub fn set_channel_depth(&self, channel: &str, depth: usize) {
+        self.channel_depth
+            .with_label_values(&[channel])
+            .set(depth as f64);
+    }
+
+    /// Count broadcast messages a lagging receiver skipped
+    pub fn inc_channel_lagged(&self, channel: &str, missed: u64) {
+        self.channel_lagged_total
+            .with_label_values(&[channel])
+            .inc_by(missed as f64);
+    }
+
+    /// Current depth and total lagged drops per channel, sorted by channel name
+    pub fn channel_stats(&self) -> Vec<(String, f64, f64)> {
+        use prometheus::core::Collector;
+
+        let mut stats: std::collections::BTreeMap<String, (f64, f64)> = Default::default();
+        let label = |m: &prometheus::proto::Metric| {
+            m.get_label()
+                .first()
+                .map(|l| l.value().to_string())
+                .unwrap_or_default()
+        };
+        for family in self.channel_depth.collect() {
+            for m in family.get_metric() {
+                stats.entry(label(m)).or_default().0 = m.get_gauge().value();
+            }
+        }
+        for family in self.channel_lagged_total.collect() {
+            for m in family.get_metric() {
+                stats.entry(label(m)).or_default().1 = m.get_counter().value();
+            }
+        }
+        stats
+            .into_iter()
+            .map(|(channel, (depth, lagged))| (channel, depth, lagged))
+            .collect()
+    }
 }
 
 impl Default for Metrics {
@@ -252,6 +380,24 @@ mod tests {
         assert!(metrics.render().contains("rustrade_"));
     }
 
+    #[test]
+    fn test_channel_stats() {
+        let metrics = Metrics::new().expect("Failed to create metrics");
+        metrics.set_channel_depth("candles", 12);
+        metrics.inc_channel_lagged("candles", 3);
+        metrics.inc_channel_lagged("candles", 2);
+        metrics.inc_channel_lagged("news", 1);
+
+        assert_eq!(
+            metrics.channel_stats(),
+            vec![
+                ("candles".to_string(), 12.0, 5.0),
+                ("news".to_string(), 0.0, 1.0)
+            ]
+        );
+        assert!(metrics.render().contains("rustrade_channel_lagged_total"));
+    }
+
     #[test]
     fn test_portfolio_value_update() {
         let metrics = Metrics::new().expect("Failed to create metrics");
@@ -279,4 +425,25 @@ mod tests {
         let output = metrics.render();
         assert!(output.contains("rustrade_orders_total"));
     }
+
+    #[test]
+    fn test_decision_funnel_counters() {
+        let metrics = Metrics::new().expect("Failed to create metrics");
+        metrics.inc_signals("Standard", "buy");
+        metrics.inc_proposals("buy");
+        metrics.inc_rejections("filter", "profitability");
+        metrics.inc_rejections("risk", "PdtValidator");
+        metrics.inc_rejections("risk", "PdtValidator");
+
+        assert_eq!(
+            metrics
+                .rejections_total
+                .with_label_values(&["risk", "PdtValidator"])
+                .get(),
+            2.0
+        );
+        let output = metrics.render();
+        assert!(output.contains("rustrade_proposals_total"));
+        assert!(output.contains("reason=\"profitability\""));
+    }
 }
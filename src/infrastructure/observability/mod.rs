@@ -8,10 +8,12 @@
 //!
 //! **Security**: This system only SENDS data, it never accepts requests.
 
+pub mod decision_log;
 pub mod latency_tracker;
 pub mod metrics;
 pub mod reporter;
 
+pub use decision_log::DecisionLogSink;
 pub use latency_tracker::LatencyGuard;
 pub use metrics::Metrics;
 pub use reporter::MetricsReporter;
@@ -22,6 +22,7 @@ pub struct MetricsSnapshot {
     pub version: String,
     pub portfolio: PortfolioSnapshot,
     pub system: SystemSnapshot,
+    pub channels: Vec<ChannelSnapshot>,
 }
 
 #[derive(Serialize)]
@@ -40,9 +41,17 @@ pub struct PositionSnapshot {
     pub current_value: f64,
 }
 
+#[derive(Serialize)]
+pub struct ChannelSnapshot {
+    pub channel: String,
+    pub depth: u64,
+    pub lagged_total: u64,
+}
+
 #[derive(Serialize)]
 pub struct SystemSnapshot {
     pub circuit_breaker_tripped: bool,
+    pub trading_paused: bool,
     pub sentiment_score: Option<u32>,
 }
 
@@ -154,6 +163,7 @@ impl MetricsReporter {
             },
             system: SystemSnapshot {
                 circuit_breaker_tripped: self.metrics.circuit_breaker_status.get() > 0.0,
+                trading_paused: self.metrics.trading_paused.get() > 0.0,
                 sentiment_score: {
                     let score = self.metrics.sentiment_score.get();
                     if score > 0.0 {
@@ -163,6 +173,16 @@ impl MetricsReporter {
                     }
                 },
             },
+            channels: self
+                .metrics
+                .channel_stats()
+                .into_iter()
+                .map(|(channel, depth, lagged)| ChannelSnapshot {
+                    channel,
+                    depth: depth as u64,
+                    lagged_total: lagged as u64,
+                })
+                .collect(),
         })
     }
 }
@@ -206,8 +226,14 @@ mod tests {
             },
             system: SystemSnapshot {
                 circuit_breaker_tripped: false,
+                trading_paused: false,
                 sentiment_score: Some(50),
             },
+            channels: vec![ChannelSnapshot {
+                channel: "candles".to_string(),
+                depth: 4,
+                lagged_total: 0,
+            }],
         };
 
         let json = serde_json::to_string(&snapshot).expect("Failed to serialize");
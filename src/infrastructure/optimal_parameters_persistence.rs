@@ -82,6 +82,20 @@ impl OptimalParametersPersistence {
         }
     }
 
+    /// Gets the best stored parameters for an asset type and risk level: exact risk-score
+    /// match, then profile match, then none. See `OptimalParametersSet::get_best`.
+    pub fn get_best(
+        &self,
+        asset_type: crate::domain::risk::optimal_parameters::AssetType,
+        risk_profile: RiskProfile,
+        risk_score: Option<u8>,
+    ) -> Result<Option<OptimalParameters>> {
+        match self.load()? {
+            Some(set) => Ok(set.get_best(asset_type, risk_profile, risk_score).cloned()),
+            None => Ok(None),
+        }
+    }
+
     /// Updates or inserts parameters for a single profile.
     pub fn upsert(&self, params: OptimalParameters) -> Result<()> {
         let mut set = self.load()?.unwrap_or_default();
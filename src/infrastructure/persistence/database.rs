@@ -59,7 +59,10 @@ impl Database {
                 quantity TEXT NOT NULL,
                 order_type TEXT DEFAULT 'MARKET',
                 status TEXT DEFAULT 'NEW',
-                timestamp INTEGER NOT NULL
+                timestamp INTEGER NOT NULL,
+                strategy TEXT,
+                regime TEXT,
+                reason TEXT
             );
             "#,
         )
@@ -77,6 +80,13 @@ impl Database {
             .execute(&mut *conn)
             .await;
 
+        // Trade attribution (strategy, regime, reason) for orders saved before it existed
+        for column in ["strategy", "regime", "reason"] {
+            let _ = sqlx::query(&format!("ALTER TABLE orders ADD COLUMN {} TEXT", column))
+                .execute(&mut *conn)
+                .await;
+        }
+
         // 2. Candles Table
         sqlx::query(
             r#"
@@ -235,6 +245,21 @@ impl Database {
         .await
         .context("Failed to create completed_trades table")?;
 
+        // 9. Adaptive candidates in their shadow period (one per symbol)
+        sqlx::query(
+            r#"
+            CREATE TABLE IF NOT EXISTS shadow_trials (
+                symbol TEXT PRIMARY KEY,
+                parameters_json TEXT NOT NULL,
+                performance_metrics_json TEXT NOT NULL,
+                started_at INTEGER NOT NULL
+            );
+            "#,
+        )
+        .execute(&mut *conn)
+        .await
+        .context("Failed to create shadow_trials table")?;
+
         info!("Database schema initialized.");
         Ok(())
     }
@@ -155,7 +155,10 @@ mod tests {
             price: dec!(100),
             order_type: crate::domain::trading::types::OrderType::Market,
             status: crate::domain::trading::types::OrderStatus::New,
-            timestamp: Utc::now().timestamp(),
+            timestamp: Utc::now().timestamp_millis(),
+            reduce_only: false,
+            origin: Default::default(),
+            reason: None,
         }
     }
 
@@ -1,8 +1,8 @@
 use crate::domain::repositories::{CandleRepository, TradeRepository};
-use crate::domain::trading::types::{Candle, Order, OrderSide};
+use crate::domain::trading::time::{MS_PER_DAY, now_ms};
+use crate::domain::trading::types::{Candle, Order, OrderSide, SignalOrigin};
 use anyhow::{Context, Result};
 use async_trait::async_trait;
-use chrono::Utc;
 use rust_decimal::Decimal;
 use rust_decimal::prelude::ToPrimitive;
 use sqlx::{Row, SqlitePool};
@@ -24,6 +24,9 @@ pub use reoptimization_trigger_repository::SqliteReoptimizationTriggerRepository
 pub mod risk_state_repository;
 pub use risk_state_repository::SqliteRiskStateRepository;
 
+pub mod shadow_trial_repository;
+pub use shadow_trial_repository::SqliteShadowTrialRepository;
+
 pub struct SqliteOrderRepository {
     pool: SqlitePool,
 }
@@ -39,8 +42,9 @@ impl TradeRepository for SqliteOrderRepository {
     async fn save(&self, order: &Order) -> Result<()> {
         sqlx::query(
             r#"
-            INSERT INTO orders (id, symbol, side, price, quantity, order_type, status, timestamp)
-            VALUES (?, ?, ?, ?, ?, ?, ?, ?)
+            INSERT INTO orders (id, symbol, side, price, quantity, order_type, status, timestamp,
+                                strategy, regime, reason)
+            VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?)
             ON CONFLICT(id) DO UPDATE SET
                 status = excluded.status,
                 price = excluded.price,
@@ -55,6 +59,9 @@ impl TradeRepository for SqliteOrderRepository {
         .bind(format!("{}", order.order_type)) // Enum as string
         .bind(format!("{:?}", order.status)) // Enum as string (New, Filled, etc.)
         .bind(order.timestamp)
+        .bind(&order.origin.strategy)
+        .bind(&order.origin.regime)
+        .bind(&order.reason)
         .execute(&self.pool)
         .await
         .context("Failed to save order")?;
@@ -151,6 +158,12 @@ impl SqliteOrderRepository {
                 order_type,
                 status,
                 timestamp: row.try_get("timestamp")?,
+                reduce_only: false,
+                origin: SignalOrigin {
+                    strategy: row.try_get("strategy").unwrap_or_default(),
+                    regime: row.try_get("regime").unwrap_or_default(),
+                },
+                reason: row.try_get("reason").unwrap_or_default(),
             });
         }
         Ok(orders)
@@ -191,6 +204,32 @@ impl CandleRepository for SqliteCandleRepository {
         Ok(())
     }
 
+    async fn save_batch(&self, candles: &[Candle]) -> Result<()> {
+        // One transaction instead of a commit per candle
+        let mut tx = self.pool.begin().await?;
+        for candle in candles {
+            sqlx::query(
+                r#"
+                INSERT OR IGNORE INTO candles (symbol, timestamp, open, high, low, close, volume)
+                VALUES (?, ?, ?, ?, ?, ?, ?)
+                "#,
+            )
+            .bind(&candle.symbol)
+            .bind(candle.timestamp)
+            .bind(candle.open.to_string())
+            .bind(candle.high.to_string())
+            .bind(candle.low.to_string())
+            .bind(candle.close.to_string())
+            .bind(candle.volume.to_f64().unwrap_or(0.0))
+            .execute(&mut *tx)
+            .await
+            .context("Failed to save candle batch")?;
+        }
+        tx.commit().await.context("Failed to commit candle batch")?;
+
+        Ok(())
+    }
+
     async fn get_range(&self, symbol: &str, start_ts: i64, end_ts: i64) -> Result<Vec<Candle>> {
         let rows = sqlx::query(
             "SELECT * FROM candles WHERE symbol = ? AND timestamp >= ? AND timestamp <= ? ORDER BY timestamp ASC",
@@ -246,7 +285,7 @@ impl CandleRepository for SqliteCandleRepository {
     }
 
     async fn prune(&self, days_retention: i64) -> Result<u64> {
-        let cutoff_ts = Utc::now().timestamp() - (days_retention * 24 * 60 * 60);
+        let cutoff_ts = now_ms() - days_retention * MS_PER_DAY;
 
         let result = sqlx::query("DELETE FROM candles WHERE timestamp < ?")
             .bind(cutoff_ts)
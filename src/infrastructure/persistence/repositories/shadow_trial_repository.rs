@@ -0,0 +1,127 @@
+use crate::domain::optimization::shadow_trial::ShadowTrial;
+use crate::domain::repositories::ShadowTrialRepository;
+use anyhow::{Context, Result};
+use async_trait::async_trait;
+use chrono::{TimeZone, Utc};
+use sqlx::{Row, SqlitePool};
+
+pub struct SqliteShadowTrialRepository {
+    pool: SqlitePool,
+}
+
+impl SqliteShadowTrialRepository {
+    pub fn new(pool: SqlitePool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl ShadowTrialRepository for SqliteShadowTrialRepository {
+    async fn save(&self, trial: &ShadowTrial) -> Result<()> {
+        sqlx::query(
+            r#"
+            INSERT INTO shadow_trials (symbol, parameters_json, performance_metrics_json, started_at)
+            VALUES (?, ?, ?, ?)
+            ON CONFLICT(symbol) DO UPDATE SET
+                parameters_json = excluded.parameters_json,
+                performance_metrics_json = excluded.performance_metrics_json,
+                started_at = excluded.started_at
+            "#,
+        )
+        .bind(&trial.symbol)
+        .bind(&trial.parameters_json)
+        .bind(&trial.performance_metrics_json)
+        .bind(trial.started_at.timestamp_millis())
+        .execute(&self.pool)
+        .await
+        .context("Failed to save shadow trial")?;
+
+        Ok(())
+    }
+
+    async fn find_by_symbol(&self, symbol: &str) -> Result<Option<ShadowTrial>> {
+        let row = sqlx::query("SELECT * FROM shadow_trials WHERE symbol = ?")
+            .bind(symbol)
+            .fetch_optional(&self.pool)
+            .await?;
+
+        let Some(row) = row else {
+            return Ok(None);
+        };
+        let started_at_ms: i64 = row.try_get("started_at")?;
+        let started_at = Utc
+            .timestamp_millis_opt(started_at_ms)
+            .single()
+            .ok_or_else(|| anyhow::anyhow!("Invalid timestamp value: {}", started_at_ms))?;
+
+        Ok(Some(ShadowTrial {
+            symbol: row.try_get("symbol")?,
+            parameters_json: row.try_get("parameters_json")?,
+            performance_metrics_json: row.try_get("performance_metrics_json")?,
+            started_at,
+        }))
+    }
+
+    async fn delete(&self, symbol: &str) -> Result<()> {
+        sqlx::query("DELETE FROM shadow_trials WHERE symbol = ?")
+            .bind(symbol)
+            .execute(&self.pool)
+            .await
+            .context("Failed to delete shadow trial")?;
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::infrastructure::persistence::database::Database;
+
+    #[tokio::test]
+    async fn test_shadow_trial_survives_reconnect() {
+        let path = std::env::temp_dir().join(format!(
+            "rustrade_shadow_trials_{}.db",
+            uuid::Uuid::new_v4()
+        ));
+        let url = format!("sqlite://{}", path.display());
+        let started_at = Utc.timestamp_millis_opt(1_700_000_000_123).unwrap();
+        let trial = ShadowTrial {
+            symbol: "AAPL".to_string(),
+            parameters_json: r#"{"fast_sma_period":10}"#.to_string(),
+            performance_metrics_json: "{}".to_string(),
+            started_at,
+        };
+
+        {
+            let db = Database::new(&url).await.unwrap();
+            let repo = SqliteShadowTrialRepository::new(db.pool.clone());
+            repo.save(&trial).await.unwrap();
+            db.pool.close().await;
+        }
+
+        // A restart finds the running trial, and a new candidate replaces it
+        let db = Database::new(&url).await.unwrap();
+        let repo = SqliteShadowTrialRepository::new(db.pool.clone());
+        assert_eq!(
+            repo.find_by_symbol("AAPL").await.unwrap(),
+            Some(trial.clone())
+        );
+
+        let replacement = ShadowTrial {
+            parameters_json: r#"{"fast_sma_period":12}"#.to_string(),
+            ..trial
+        };
+        repo.save(&replacement).await.unwrap();
+        assert_eq!(
+            repo.find_by_symbol("AAPL").await.unwrap(),
+            Some(replacement)
+        );
+
+        repo.delete("AAPL").await.unwrap();
+        assert!(repo.find_by_symbol("AAPL").await.unwrap().is_none());
+
+        db.pool.close().await;
+        let _ = std::fs::remove_file(&path);
+    }
+}
@@ -5,8 +5,9 @@ use crate::interfaces::components::{
     metrics::{render_metric_card, render_status_pill},
 };
 use crate::interfaces::dashboard_components::{
-    activity_feed::render_activity_feed, chart_panel::render_chart_panel,
-    news_feed::render_news_feed, symbol_card::render_symbol_card,
+    activity_feed::render_activity_feed, agent_health::render_agent_health,
+    chart_panel::render_chart_panel, news_feed::render_news_feed, order_entry::render_order_entry,
+    symbol_card::render_symbol_card,
 };
 use crate::interfaces::design_system::DesignSystem;
 use crate::interfaces::view_models::dashboard_view_model::DashboardViewModel;
@@ -21,6 +22,8 @@ pub fn render_dashboard(ui: &mut egui::Ui, agent: &mut UserAgent) {
     let risk_metrics = DashboardViewModel::get_risk_metrics(agent);
     let sentiment_metrics = DashboardViewModel::get_sentiment_metrics(agent);
 
+    render_flatten_confirm(ui.ctx(), agent);
+
     // ---------------------------------------------------------
     // 1. TOP HEADER (Total Value + System Status)
     // ---------------------------------------------------------
@@ -63,16 +66,29 @@ pub fn render_dashboard(ui: &mut egui::Ui, agent: &mut UserAgent) {
             ui.group(|ui| {
                 ui.set_style(ui.style().clone()); // Reset style if needed
                 ui.horizontal(|ui| {
-                    ui.label(
-                        egui::RichText::new("●")
-                            .size(10.0)
-                            .color(DesignSystem::SUCCESS),
-                    );
+                    // Emergency pause toggle (new entries only; exits keep running)
+                    let toggle_label = if agent.trading_paused {
+                        agent.i18n.t("resume_trading_button")
+                    } else {
+                        agent.i18n.t("pause_trading_button")
+                    };
+                    if ui.button(toggle_label).clicked() {
+                        let paused = !agent.trading_paused;
+                        agent.set_trading_paused(paused);
+                    }
+                    ui.add_space(DesignSystem::SPACING_SMALL);
+
+                    let (status_color, status_key) = if agent.trading_paused {
+                        (DesignSystem::WARNING, "status_paused")
+                    } else {
+                        (DesignSystem::SUCCESS, "status_active")
+                    };
+                    ui.label(egui::RichText::new("●").size(10.0).color(status_color));
                     ui.label(
                         egui::RichText::new(
                             agent
                                 .i18n
-                                .tf("status_label", &[("status", agent.i18n.t("status_active"))]),
+                                .tf("status_label", &[("status", agent.i18n.t(status_key))]),
                         )
                         .size(12.0)
                         .color(DesignSystem::TEXT_SECONDARY),
@@ -98,22 +114,28 @@ pub fn render_dashboard(ui: &mut egui::Ui, agent: &mut UserAgent) {
     // 2. METRICS CARDS (5 Columns)
     // ---------------------------------------------------------
     ui.columns(5, |columns| {
-        // Card 1: DAILY P&L
-        columns[0].push_id("card_daily_pnl", |ui| {
+        // Card 1: SESSION P&L (realized since startup + open positions)
+        columns[0].push_id("card_session_pnl", |ui| {
             render_metric_card(
                 ui,
-                agent.i18n.t("metric_daily_pnl"),
+                agent.i18n.t("metric_session_pnl"),
                 &agent.i18n.tf(
                     "pnl_value_format",
                     &[
-                        ("amount", &format!("{:.2}", metrics.pnl_value.abs())),
-                        ("sign", metrics.pnl_sign),
+                        ("amount", &format!("{:.2}", metrics.total_pnl.abs())),
+                        ("sign", metrics.total_pnl_sign),
                     ],
                 ),
-                metrics.pnl_color,
-                Some(agent.i18n.t("last_24h")), // Context
-                Some(metrics.pnl_arrow),        // Icon
-                true,                           // Active styling
+                metrics.total_pnl_color,
+                Some(&agent.i18n.tf(
+                    "pnl_breakdown_format",
+                    &[
+                        ("realized", &format_signed(metrics.realized_pnl)),
+                        ("unrealized", &format_signed(metrics.pnl_value)),
+                    ],
+                )), // Context
+                Some(metrics.total_pnl_arrow), // Icon
+                true,                          // Active styling
             );
         });
 
@@ -296,14 +318,28 @@ pub fn render_dashboard(ui: &mut egui::Ui, agent: &mut UserAgent) {
             egui::vec2(right_panel_width, available_height),
             egui::Layout::top_down(egui::Align::LEFT),
             |ui| {
-                ui.label(
-                    egui::RichText::new(agent.i18n.t("market_and_positions"))
-                        .size(12.0)
-                        .strong()
-                        .color(DesignSystem::TEXT_SECONDARY),
-                );
+                ui.horizontal(|ui| {
+                    ui.label(
+                        egui::RichText::new(agent.i18n.t("market_and_positions"))
+                            .size(12.0)
+                            .strong()
+                            .color(DesignSystem::TEXT_SECONDARY),
+                    );
+                    ui.with_layout(egui::Layout::right_to_left(egui::Align::Center), |ui| {
+                        let flatten = egui::Button::new(
+                            egui::RichText::new(agent.i18n.t("flatten_all_button"))
+                                .size(11.0)
+                                .color(DesignSystem::DANGER),
+                        );
+                        if ui.add(flatten).clicked() {
+                            agent.flatten_confirm_open = true;
+                        }
+                    });
+                });
                 ui.add_space(DesignSystem::SPACING_SMALL);
 
+                let mut close_requests: Vec<String> = Vec::new();
+
                 egui::ScrollArea::vertical()
                     .id_salt("market_list_scroll")
                     .max_height(available_height * 0.35)
@@ -331,11 +367,32 @@ pub fn render_dashboard(ui: &mut egui::Ui, agent: &mut UserAgent) {
                                 {
                                     agent.selected_chart_tab = Some(symbol.clone());
                                 }
+                                if pos.is_some_and(|p| p.quantity > rust_decimal::Decimal::ZERO)
+                                    && ui
+                                        .small_button(agent.i18n.t("close_position_button"))
+                                        .clicked()
+                                {
+                                    close_requests.push(symbol.clone());
+                                }
                                 ui.add_space(DesignSystem::SPACING_SMALL);
                             }
                         }
                     });
 
+                for symbol in close_requests {
+                    agent.close_position(&symbol);
+                }
+
+                ui.add_space(DesignSystem::SPACING_MEDIUM);
+
+                // --- MANUAL ORDER ENTRY ---
+                render_order_entry(ui, agent);
+
+                ui.add_space(DesignSystem::SPACING_MEDIUM);
+
+                // --- AGENT HEALTH ---
+                render_agent_health(ui, agent);
+
                 ui.add_space(DesignSystem::SPACING_MEDIUM);
 
                 // --- NEWS FEED SECTION ---
@@ -367,5 +424,44 @@ pub fn render_dashboard(ui: &mut egui::Ui, agent: &mut UserAgent) {
     });
 }
 
+/// Confirmation dialog for "Flatten All" (closes every open position)
+fn render_flatten_confirm(ctx: &egui::Context, agent: &mut UserAgent) {
+    if !agent.flatten_confirm_open {
+        return;
+    }
+
+    let mut confirmed = false;
+    let mut cancelled = false;
+    egui::Window::new(agent.i18n.t("flatten_all_confirm_title"))
+        .collapsible(false)
+        .resizable(false)
+        .anchor(egui::Align2::CENTER_CENTER, egui::vec2(0.0, 0.0))
+        .show(ctx, |ui| {
+            ui.label(agent.i18n.t("flatten_all_confirm_body"));
+            ui.add_space(DesignSystem::SPACING_SMALL);
+            ui.horizontal(|ui| {
+                let confirm = egui::Button::new(
+                    egui::RichText::new(agent.i18n.t("flatten_all_confirm"))
+                        .color(DesignSystem::DANGER),
+                );
+                confirmed = ui.add(confirm).clicked();
+                cancelled = ui.button(agent.i18n.t("flatten_all_cancel")).clicked();
+            });
+        });
+
+    if confirmed {
+        agent.flatten_confirm_open = false;
+        agent.flatten_all();
+    } else if cancelled {
+        agent.flatten_confirm_open = false;
+    }
+}
+
 // --- Helpers ---
 // The render_symbol_card helper has been moved to dashboard_components::symbol_card
+
+/// "+$12.50" / "-$3.20"
+fn format_signed(amount: f64) -> String {
+    let sign = if amount < 0.0 { "-" } else { "+" };
+    format!("{}${:.2}", sign, amount.abs())
+}
@@ -0,0 +1,148 @@
+//! Agent health grid.
+//!
+//! One row per core agent with a traffic-light dot derived from the
+//! [`AgentStatusRegistry`](crate::application::monitoring::agent_status::AgentStatusRegistry)
+//! and the age of its last heartbeat.
+
+use crate::application::agents::user_agent::UserAgent;
+use crate::application::monitoring::agent_status::{AgentStatus, HealthStatus};
+use crate::interfaces::components::card::Card;
+use crate::interfaces::design_system::DesignSystem;
+use chrono::{DateTime, Utc};
+use eframe::egui;
+
+/// Agents shown in the grid, in display order (names as registered in the registry)
+pub const CORE_AGENTS: [&str; 6] = [
+    "Analyst",
+    "Sentinel",
+    "RiskManager",
+    "Executor",
+    "Scanner",
+    "Listener",
+];
+
+/// Agents heartbeat every 5s: yellow after ~3 missed beats, red after ~6
+const STALE_WARNING_SECS: i64 = 15;
+const STALE_DEAD_SECS: i64 = 30;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum HealthLight {
+    Green,
+    Yellow,
+    Red,
+}
+
+impl HealthLight {
+    fn color(self) -> egui::Color32 {
+        match self {
+            HealthLight::Green => DesignSystem::SUCCESS,
+            HealthLight::Yellow => DesignSystem::WARNING,
+            HealthLight::Red => DesignSystem::DANGER,
+        }
+    }
+}
+
+/// Traffic light for an agent; a missing registry entry means it never reported
+pub fn health_light(status: Option<&AgentStatus>, now: DateTime<Utc>) -> HealthLight {
+    let Some(status) = status else {
+        return HealthLight::Red;
+    };
+    let age = (now - status.last_heartbeat).num_seconds();
+    match status.health {
+        HealthStatus::Dead => HealthLight::Red,
+        _ if age > STALE_DEAD_SECS => HealthLight::Red,
+        HealthStatus::Degraded | HealthStatus::Starting => HealthLight::Yellow,
+        HealthStatus::Healthy if age > STALE_WARNING_SECS => HealthLight::Yellow,
+        HealthStatus::Healthy => HealthLight::Green,
+    }
+}
+
+/// Renders the agent health card
+pub fn render_agent_health(ui: &mut egui::Ui, agent: &UserAgent) {
+    let statuses = agent.client.agent_registry().get_all_sync();
+    let now = Utc::now();
+
+    Card::new()
+        .title(agent.i18n.t("agent_health_title"))
+        .show(ui, |ui| {
+            egui::Grid::new("agent_health_grid")
+                .num_columns(3)
+                .spacing([8.0, 4.0])
+                .show(ui, |ui| {
+                    for name in CORE_AGENTS {
+                        let status = statuses.get(name);
+                        let light = health_light(status, now);
+
+                        let (rect, _) =
+                            ui.allocate_exact_size(egui::vec2(10.0, 10.0), egui::Sense::hover());
+                        ui.painter()
+                            .circle_filled(rect.center(), 4.0, light.color());
+
+                        ui.label(
+                            egui::RichText::new(name)
+                                .size(12.0)
+                                .color(DesignSystem::TEXT_PRIMARY),
+                        );
+
+                        let age = match status {
+                            Some(s) => agent.i18n.tf(
+                                "agent_health_heartbeat_age",
+                                &[(
+                                    "seconds",
+                                    &(now - s.last_heartbeat).num_seconds().max(0).to_string(),
+                                )],
+                            ),
+                            None => agent.i18n.t("agent_health_no_heartbeat").to_string(),
+                        };
+                        ui.label(
+                            egui::RichText::new(age)
+                                .size(11.0)
+                                .color(DesignSystem::TEXT_SECONDARY),
+                        );
+                        ui.end_row();
+                    }
+                });
+        });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashMap;
+
+    fn status(health: HealthStatus, age_secs: i64, now: DateTime<Utc>) -> AgentStatus {
+        AgentStatus {
+            name: "Analyst".to_string(),
+            health,
+            last_heartbeat: now - chrono::Duration::seconds(age_secs),
+            message: None,
+            metrics: HashMap::new(),
+        }
+    }
+
+    #[test]
+    fn test_health_light() {
+        let now = Utc::now();
+        assert_eq!(health_light(None, now), HealthLight::Red);
+        assert_eq!(
+            health_light(Some(&status(HealthStatus::Healthy, 2, now)), now),
+            HealthLight::Green
+        );
+        assert_eq!(
+            health_light(Some(&status(HealthStatus::Healthy, 20, now)), now),
+            HealthLight::Yellow
+        );
+        assert_eq!(
+            health_light(Some(&status(HealthStatus::Degraded, 2, now)), now),
+            HealthLight::Yellow
+        );
+        assert_eq!(
+            health_light(Some(&status(HealthStatus::Healthy, 45, now)), now),
+            HealthLight::Red
+        );
+        assert_eq!(
+            health_light(Some(&status(HealthStatus::Dead, 0, now)), now),
+            HealthLight::Red
+        );
+    }
+}
@@ -1,4 +1,5 @@
 use crate::application::agents::user_agent::UserAgent;
+use crate::interfaces::dashboard_components::drawdown_panel::render_drawdown_panel;
 use crate::interfaces::dashboard_components::metrics_card::render_mini_metric;
 use crate::interfaces::design_system::DesignSystem;
 use eframe::egui;
@@ -66,6 +67,11 @@ pub fn render_analytics_view(ui: &mut egui::Ui, agent: &mut UserAgent) {
                     });
                 }
 
+                ui.add_space(30.0);
+
+                // --- SECTION 2b: DRAWDOWN / UNDERWATER ---
+                render_drawdown_panel(ui, agent);
+
                 ui.add_space(30.0);
 
                  // --- SECTION 3: RECENT TRADES ---
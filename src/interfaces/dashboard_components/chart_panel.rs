@@ -1,10 +1,33 @@
 use crate::application::agents::user_agent::UserAgent;
+use crate::domain::trading::types::Candle;
 use crate::interfaces::design_system::DesignSystem;
 use chrono::{TimeZone, Utc};
 use eframe::egui;
 use egui_plot::{BoxElem, BoxSpread, Legend, Plot};
 use rust_decimal::prelude::ToPrimitive;
 
+/// Which indicator overlays are drawn on the price chart
+#[derive(Debug, Clone, Copy)]
+pub struct ChartOverlays {
+    pub fast_sma: bool,
+    pub slow_sma: bool,
+    pub trend_sma: bool,
+    pub bollinger: bool,
+    pub vwap: bool,
+}
+
+impl Default for ChartOverlays {
+    fn default() -> Self {
+        Self {
+            fast_sma: true,
+            slow_sma: true,
+            trend_sma: false,
+            bollinger: false,
+            vwap: false,
+        }
+    }
+}
+
 /// Helper function to render the chart panel (Moved from ui.rs)
 pub fn render_chart_panel(agent: &mut UserAgent, ui: &mut egui::Ui) {
     // --- Tabs for Charts ---
@@ -39,6 +62,17 @@ pub fn render_chart_panel(agent: &mut UserAgent, ui: &mut egui::Ui) {
                     .color(DesignSystem::TEXT_PRIMARY),
                 );
             }
+
+            ui.with_layout(egui::Layout::right_to_left(egui::Align::Center), |ui| {
+                let overlays = &mut agent.chart_overlays;
+                let i18n = &agent.i18n;
+                // Right-to-left: listed in reverse display order
+                ui.checkbox(&mut overlays.vwap, i18n.t("chart_overlay_vwap"));
+                ui.checkbox(&mut overlays.bollinger, i18n.t("chart_overlay_bollinger"));
+                ui.checkbox(&mut overlays.trend_sma, i18n.t("chart_overlay_trend_sma"));
+                ui.checkbox(&mut overlays.slow_sma, i18n.t("chart_overlay_slow_sma"));
+                ui.checkbox(&mut overlays.fast_sma, i18n.t("chart_overlay_fast_sma"));
+            });
         });
 
         ui.add_space(8.0);
@@ -50,6 +84,9 @@ pub fn render_chart_panel(agent: &mut UserAgent, ui: &mut egui::Ui) {
             if candles.is_empty() {
                 ui.label(agent.i18n.tf("no_candles", &[("symbol", selected_symbol)]));
             } else {
+                // Periods and mode the Analyst is actually running with
+                let config = agent.active_analyst_config();
+
                 // Info Panel
                 if let Some(strat_info) = agent.strategy_info.get(selected_symbol) {
                     egui::Frame::NONE
@@ -91,8 +128,10 @@ pub fn render_chart_panel(agent: &mut UserAgent, ui: &mut egui::Ui) {
                                     egui::RichText::new(agent.i18n.tf(
                                         "sma_label",
                                         &[
-                                            ("fast", &strat_info.fast_sma.to_string()),
-                                            ("slow", &strat_info.slow_sma.to_string()),
+                                            ("fast_period", &config.fast_sma_period.to_string()),
+                                            ("slow_period", &config.slow_sma_period.to_string()),
+                                            ("fast", &format!("{:.2}", strat_info.fast_sma)),
+                                            ("slow", &format!("{:.2}", strat_info.slow_sma)),
                                         ],
                                     ))
                                     .color(egui::Color32::from_gray(160))
@@ -103,6 +142,8 @@ pub fn render_chart_panel(agent: &mut UserAgent, ui: &mut egui::Ui) {
                     ui.add_space(6.0);
                 }
 
+                let overlays = agent.chart_overlays;
+
                 // The Plot
                 let height = ui.available_height() - 20.0;
                 Plot::new(format!("chart_{}", selected_symbol))
@@ -118,12 +159,8 @@ pub fn render_chart_panel(agent: &mut UserAgent, ui: &mut egui::Ui) {
                     })
                     .show(ui, |plot_ui| {
                         let mut box_elems = Vec::new();
-                        let mut fast_sma_points = Vec::new();
-                        let mut slow_sma_points = Vec::new();
-                        let fast_period = 20;
-                        let slow_period = 50;
 
-                        for (i, c) in candles.iter().enumerate() {
+                        for c in candles.iter() {
                             let t = c.timestamp as f64;
                             let open = c.open.to_f64().unwrap_or(0.0);
                             let close = c.close.to_f64().unwrap_or(0.0);
@@ -144,40 +181,181 @@ pub fn render_chart_panel(agent: &mut UserAgent, ui: &mut egui::Ui) {
                                     .stroke(egui::Stroke::new(1.0, color))
                                     .box_width(45.0),
                             );
-
-                            if i >= fast_period - 1 {
-                                let fast_sum: f64 = candles[i - (fast_period - 1)..=i]
-                                    .iter()
-                                    .map(|c| c.close.to_f64().unwrap_or(0.0))
-                                    .sum();
-                                fast_sma_points.push([t, fast_sum / fast_period as f64]);
-                            }
-                            if i >= slow_period - 1 {
-                                let slow_sum: f64 = candles[i - (slow_period - 1)..=i]
-                                    .iter()
-                                    .map(|c| c.close.to_f64().unwrap_or(0.0))
-                                    .sum();
-                                slow_sma_points.push([t, slow_sum / slow_period as f64]);
-                            }
                         }
 
                         plot_ui
                             .box_plot(egui_plot::BoxPlot::new(selected_symbol.clone(), box_elems));
 
-                        if !fast_sma_points.is_empty() {
-                            plot_ui.line(
-                                egui_plot::Line::new(agent.i18n.t("sma_20_label"), fast_sma_points)
-                                    .color(DesignSystem::ACCENT_SECONDARY),
-                            );
+                        let mut sma_lines = Vec::new();
+                        if overlays.fast_sma {
+                            sma_lines.push((
+                                "chart_overlay_fast_sma_format",
+                                config.fast_sma_period,
+                                DesignSystem::ACCENT_SECONDARY,
+                            ));
+                        }
+                        if overlays.slow_sma {
+                            sma_lines.push((
+                                "chart_overlay_slow_sma_format",
+                                config.slow_sma_period,
+                                DesignSystem::WARNING,
+                            ));
+                        }
+                        if overlays.trend_sma {
+                            sma_lines.push((
+                                "chart_overlay_trend_sma_format",
+                                config.trend_sma_period,
+                                DesignSystem::ACCENT_PRIMARY,
+                            ));
                         }
-                        if !slow_sma_points.is_empty() {
-                            plot_ui.line(
-                                egui_plot::Line::new(agent.i18n.t("sma_50_label"), slow_sma_points)
-                                    .color(DesignSystem::WARNING),
+                        for (label_key, sma_period, color) in sma_lines {
+                            let points = sma_series(candles, sma_period);
+                            if !points.is_empty() {
+                                let name = agent
+                                    .i18n
+                                    .tf(label_key, &[("period", &sma_period.to_string())]);
+                                plot_ui.line(egui_plot::Line::new(name, points).color(color));
+                            }
+                        }
+
+                        if overlays.bollinger {
+                            let std_dev = config.bb_std_dev.to_f64().unwrap_or(2.0);
+                            let (upper, lower) =
+                                bollinger_series(candles, config.mean_reversion_bb_period, std_dev);
+                            let name = agent.i18n.tf(
+                                "chart_overlay_bollinger_format",
+                                &[
+                                    ("period", &config.mean_reversion_bb_period.to_string()),
+                                    ("std_dev", &config.bb_std_dev.to_string()),
+                                ],
                             );
+                            if !upper.is_empty() {
+                                let color = egui::Color32::from_gray(140);
+                                plot_ui.line(
+                                    egui_plot::Line::new(name.clone(), upper)
+                                        .color(color)
+                                        .style(egui_plot::LineStyle::dashed_loose()),
+                                );
+                                plot_ui.line(
+                                    egui_plot::Line::new(name, lower)
+                                        .color(color)
+                                        .style(egui_plot::LineStyle::dashed_loose()),
+                                );
+                            }
+                        }
+
+                        if overlays.vwap {
+                            let points = vwap_series(candles);
+                            if !points.is_empty() {
+                                plot_ui.line(
+                                    egui_plot::Line::new(
+                                        agent.i18n.t("chart_overlay_vwap"),
+                                        points,
+                                    )
+                                    .color(DesignSystem::SUCCESS),
+                                );
+                            }
                         }
                     });
             }
         }
     }
 }
+
+fn close_f64(candle: &Candle) -> f64 {
+    candle.close.to_f64().unwrap_or(0.0)
+}
+
+/// Simple moving average of closes, one point per candle once `period` candles are available
+fn sma_series(candles: &[Candle], period: usize) -> Vec<[f64; 2]> {
+    if period == 0 {
+        return Vec::new();
+    }
+    candles
+        .windows(period)
+        .map(|w| {
+            let sum: f64 = w.iter().map(close_f64).sum();
+            [w[period - 1].timestamp as f64, sum / period as f64]
+        })
+        .collect()
+}
+
+/// Upper and lower Bollinger Bands (population standard deviation of closes)
+fn bollinger_series(
+    candles: &[Candle],
+    period: usize,
+    std_dev: f64,
+) -> (Vec<[f64; 2]>, Vec<[f64; 2]>) {
+    if period == 0 {
+        return (Vec::new(), Vec::new());
+    }
+    candles
+        .windows(period)
+        .map(|w| {
+            let t = w[period - 1].timestamp as f64;
+            let mean = w.iter().map(close_f64).sum::<f64>() / period as f64;
+            let variance =
+                w.iter().map(|c| (close_f64(c) - mean).powi(2)).sum::<f64>() / period as f64;
+            let band = variance.sqrt() * std_dev;
+            ([t, mean + band], [t, mean - band])
+        })
+        .unzip()
+}
+
+/// Cumulative volume-weighted average of the typical price over the visible candles
+fn vwap_series(candles: &[Candle]) -> Vec<[f64; 2]> {
+    let mut pv = 0.0;
+    let mut volume = 0.0;
+    let mut points = Vec::with_capacity(candles.len());
+    for c in candles {
+        let typical = (c.high + c.low + c.close).to_f64().unwrap_or(0.0) / 3.0;
+        let v = c.volume.to_f64().unwrap_or(0.0);
+        pv += typical * v;
+        volume += v;
+        if volume > 0.0 {
+            points.push([c.timestamp as f64, pv / volume]);
+        }
+    }
+    points
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rust_decimal::Decimal;
+
+    fn candle(ts: i64, close: i64, volume: i64) -> Candle {
+        let price = Decimal::from(close);
+        Candle {
+            symbol: "TEST".to_string(),
+            open: price,
+            high: price,
+            low: price,
+            close: price,
+            volume: Decimal::from(volume),
+            timestamp: ts,
+        }
+    }
+
+    #[test]
+    fn test_sma_series_uses_requested_period() {
+        let candles: Vec<Candle> = (1..=5).map(|i| candle(i, i * 10, 1)).collect();
+        assert_eq!(
+            sma_series(&candles, 3),
+            vec![[3.0, 20.0], [4.0, 30.0], [5.0, 40.0]]
+        );
+        assert!(sma_series(&candles, 6).is_empty());
+        assert!(sma_series(&candles, 0).is_empty());
+    }
+
+    #[test]
+    fn test_bollinger_and_vwap_series() {
+        let candles = vec![candle(1, 10, 1), candle(2, 20, 3)];
+
+        let (upper, lower) = bollinger_series(&candles, 2, 2.0);
+        assert_eq!(upper, vec![[2.0, 25.0]]);
+        assert_eq!(lower, vec![[2.0, 5.0]]);
+
+        assert_eq!(vwap_series(&candles), vec![[1.0, 10.0], [2.0, 17.5]]);
+    }
+}
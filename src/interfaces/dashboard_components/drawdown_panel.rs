@@ -0,0 +1,134 @@
+//! Drawdown / underwater-equity panel.
+//!
+//! Plots the session drawdown from peak equity and shades the longest stretch
+//! spent below a previous peak.
+
+use crate::application::agents::user_agent::UserAgent;
+use crate::interfaces::dashboard_components::metrics_card::render_mini_metric;
+use crate::interfaces::design_system::DesignSystem;
+use eframe::egui;
+use rust_decimal::prelude::ToPrimitive;
+
+/// Renders the drawdown section of the analytics view
+pub fn render_drawdown_panel(ui: &mut egui::Ui, agent: &UserAgent) {
+    let curve = agent.get_underwater_curve();
+
+    ui.label(
+        egui::RichText::new(agent.i18n.t("drawdown_title"))
+            .size(18.0)
+            .strong(),
+    );
+    ui.add_space(10.0);
+
+    if curve.points.len() < 2 {
+        ui.label(
+            egui::RichText::new(agent.i18n.t("drawdown_not_enough_data"))
+                .italics()
+                .color(DesignSystem::TEXT_MUTED),
+        );
+        return;
+    }
+
+    let longest = curve.longest_underwater;
+    ui.columns(4, |cols| {
+        render_mini_metric(
+            &mut cols[0],
+            agent.i18n.t("drawdown_current").to_string(),
+            &format!("{:.2}%", curve.current_drawdown_pct.to_f64().unwrap_or(0.0)),
+            if curve.current_drawdown_pct.is_zero() {
+                DesignSystem::SUCCESS
+            } else {
+                DesignSystem::WARNING
+            },
+        );
+        render_mini_metric(
+            &mut cols[1],
+            agent.i18n.t("drawdown_max").to_string(),
+            &format!("{:.2}%", curve.max_drawdown_pct.to_f64().unwrap_or(0.0)),
+            DesignSystem::DANGER,
+        );
+        render_mini_metric(
+            &mut cols[2],
+            agent.i18n.t("drawdown_peak_trough").to_string(),
+            &format!(
+                "${:.0} / ${:.0}",
+                curve.peak_equity.to_f64().unwrap_or(0.0),
+                curve.trough_equity.to_f64().unwrap_or(0.0)
+            ),
+            DesignSystem::TEXT_PRIMARY,
+        );
+        let longest_label = match longest {
+            Some(period) => {
+                let text = format_duration(period.duration_ms());
+                if period.recovered {
+                    text
+                } else {
+                    agent.i18n.tf("drawdown_ongoing", &[("duration", &text)])
+                }
+            }
+            None => "-".to_string(),
+        };
+        render_mini_metric(
+            &mut cols[3],
+            agent.i18n.t("drawdown_longest").to_string(),
+            &longest_label,
+            DesignSystem::TEXT_SECONDARY,
+        );
+    });
+    ui.add_space(10.0);
+
+    // Seconds on the x axis, like the equity curve
+    let points: Vec<[f64; 2]> = curve
+        .points
+        .iter()
+        .map(|(ts, dd)| [*ts as f64 / 1000.0, dd.to_f64().unwrap_or(0.0)])
+        .collect();
+    let floor = curve.max_drawdown_pct.to_f64().unwrap_or(0.0).min(-0.01);
+
+    egui_plot::Plot::new("underwater_plot")
+        .height(180.0)
+        .show_axes([true, true])
+        .show_grid([true, true])
+        .include_y(0.0)
+        .y_axis_formatter(|mark, _range| format!("{:.1}%", mark.value))
+        .show(ui, |plot_ui| {
+            if let Some(period) = longest {
+                let (start, end) = (period.start as f64 / 1000.0, period.end as f64 / 1000.0);
+                plot_ui.polygon(
+                    egui_plot::Polygon::new(
+                        agent.i18n.t("drawdown_longest"),
+                        egui_plot::PlotPoints::from(vec![
+                            [start, 0.0],
+                            [end, 0.0],
+                            [end, floor],
+                            [start, floor],
+                        ]),
+                    )
+                    .fill_color(DesignSystem::WARNING.gamma_multiply(0.15))
+                    .stroke(egui::Stroke::NONE),
+                );
+            }
+            plot_ui.line(
+                egui_plot::Line::new(
+                    agent.i18n.t("drawdown_title"),
+                    egui_plot::PlotPoints::from(points),
+                )
+                .color(DesignSystem::DANGER)
+                .fill(0.0)
+                .width(1.5),
+            );
+        });
+}
+
+/// Compact human-readable duration ("2h 05m", "45s")
+fn format_duration(ms: i64) -> String {
+    let secs = ms.max(0) / 1000;
+    let (hours, minutes, seconds) = (secs / 3600, (secs % 3600) / 60, secs % 60);
+    if hours > 0 {
+        format!("{}h {:02}m", hours, minutes)
+    } else if minutes > 0 {
+        format!("{}m {:02}s", minutes, seconds)
+    } else {
+        format!("{}s", seconds)
+    }
+}
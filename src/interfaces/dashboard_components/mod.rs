@@ -1,7 +1,10 @@
 pub mod activity_feed;
+pub mod agent_health;
 pub mod analytics_view;
 pub mod architecture_view;
 pub mod chart_panel;
+pub mod drawdown_panel;
 pub mod metrics_card;
 pub mod news_feed;
+pub mod order_entry;
 pub mod symbol_card;
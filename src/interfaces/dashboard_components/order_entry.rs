@@ -0,0 +1,267 @@
+//! Manual order-entry panel.
+//!
+//! Builds a [`TradeProposal`] from the form and submits it through the regular
+//! proposal channel, so discretionary orders go through the same RiskManager
+//! checks and executor as strategy signals.
+
+use crate::application::agents::user_agent::{ActivityEventType, EventSeverity, UserAgent};
+use crate::domain::trading::types::{OrderSide, OrderType, TradeProposal};
+use crate::interfaces::components::card::Card;
+use crate::interfaces::design_system::DesignSystem;
+use eframe::egui;
+use rust_decimal::Decimal;
+use std::str::FromStr;
+
+/// Form state for the order-entry panel
+pub struct OrderEntryState {
+    pub symbol: String,
+    pub side: OrderSide,
+    pub order_type: OrderType,
+    pub quantity: String,
+    pub limit_price: String,
+    /// Feedback from the last submission: (message, is_error)
+    pub last_result: Option<(String, bool)>,
+}
+
+impl Default for OrderEntryState {
+    fn default() -> Self {
+        Self {
+            symbol: String::new(),
+            side: OrderSide::Buy,
+            order_type: OrderType::Market,
+            quantity: String::new(),
+            limit_price: String::new(),
+            last_result: None,
+        }
+    }
+}
+
+impl OrderEntryState {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Validates the form and builds a proposal.
+    ///
+    /// Market orders are priced at `reference_price` (last known close) and rejected
+    /// without one. Errors are i18n keys.
+    pub fn build_proposal(
+        &self,
+        reference_price: Option<Decimal>,
+        reason: &str,
+    ) -> Result<TradeProposal, &'static str> {
+        let symbol = self.symbol.trim().to_uppercase();
+        if symbol.is_empty() {
+            return Err("order_entry_error_symbol");
+        }
+
+        let quantity = Decimal::from_str(self.quantity.trim())
+            .ok()
+            .filter(|q| *q > Decimal::ZERO)
+            .ok_or("order_entry_error_quantity")?;
+
+        let price = match self.order_type {
+            OrderType::Limit => Decimal::from_str(self.limit_price.trim())
+                .ok()
+                .filter(|p| *p > Decimal::ZERO)
+                .ok_or("order_entry_error_price")?,
+            _ => reference_price
+                .filter(|p| *p > Decimal::ZERO)
+                .ok_or("order_entry_error_no_price")?,
+        };
+
+        Ok(TradeProposal {
+            symbol,
+            side: self.side,
+            price,
+            quantity,
+            order_type: self.order_type,
+            reason: reason.to_string(),
+            timestamp: chrono::Utc::now().timestamp_millis(),
+            stop_loss: None,
+            take_profit: None,
+            reduce_only: false,
+            origin: Default::default(),
+        })
+    }
+}
+
+/// Renders the manual order-entry card
+pub fn render_order_entry(ui: &mut egui::Ui, agent: &mut UserAgent) {
+    let submitted = Card::new()
+        .title(agent.i18n.t("order_entry_title"))
+        .show(ui, |ui| {
+            let state = &mut agent.order_entry;
+
+            ui.horizontal(|ui| {
+                ui.add(
+                    egui::TextEdit::singleline(&mut state.symbol)
+                        .hint_text(agent.i18n.t("header_symbol"))
+                        .desired_width(90.0),
+                );
+                ui.add(
+                    egui::TextEdit::singleline(&mut state.quantity)
+                        .hint_text(agent.i18n.t("order_entry_quantity"))
+                        .desired_width(70.0),
+                );
+            });
+
+            ui.horizontal(|ui| {
+                ui.selectable_value(&mut state.side, OrderSide::Buy, agent.i18n.t("side_buy"));
+                ui.selectable_value(&mut state.side, OrderSide::Sell, agent.i18n.t("side_sell"));
+                ui.separator();
+                ui.selectable_value(
+                    &mut state.order_type,
+                    OrderType::Market,
+                    agent.i18n.t("order_entry_market"),
+                );
+                ui.selectable_value(
+                    &mut state.order_type,
+                    OrderType::Limit,
+                    agent.i18n.t("order_entry_limit"),
+                );
+            });
+
+            if state.order_type == OrderType::Limit {
+                ui.add(
+                    egui::TextEdit::singleline(&mut state.limit_price)
+                        .hint_text(agent.i18n.t("order_entry_limit_price"))
+                        .desired_width(90.0),
+                );
+            }
+
+            ui.add_space(4.0);
+            let submitted = ui.button(agent.i18n.t("order_entry_submit")).clicked();
+
+            if let Some((message, is_error)) = &state.last_result {
+                ui.label(egui::RichText::new(message).size(11.0).color(if *is_error {
+                    DesignSystem::DANGER
+                } else {
+                    DesignSystem::TEXT_SECONDARY
+                }));
+            }
+
+            submitted
+        })
+        .inner;
+
+    if submitted {
+        submit_order(agent);
+    }
+}
+
+fn submit_order(agent: &mut UserAgent) {
+    let symbol = agent.order_entry.symbol.trim().to_uppercase();
+    let reference_price = agent
+        .market_data
+        .get(&symbol)
+        .and_then(|candles| candles.last())
+        .map(|c| c.close);
+
+    let result = agent
+        .order_entry
+        .build_proposal(reference_price, agent.i18n.t("activity_user_command"))
+        .map_err(|key| agent.i18n.t(key).to_string())
+        .and_then(|proposal| {
+            let side = proposal.side;
+            let qty = proposal.quantity;
+            agent
+                .client
+                .submit_proposal(proposal)
+                .map(|_| {
+                    agent.i18n.tf(
+                        "cmd_proposal_sent",
+                        &[
+                            (
+                                "side",
+                                agent
+                                    .i18n
+                                    .t(&format!("side_{}", side.to_string().to_lowercase())),
+                            ),
+                            ("qty", &qty.to_string()),
+                            ("symbol", &symbol),
+                        ],
+                    )
+                })
+                .map_err(|e| {
+                    agent
+                        .i18n
+                        .tf("cmd_proposal_failed", &[("error", &e.to_string())])
+                })
+        });
+
+    match result {
+        Ok(message) => {
+            agent.add_activity(
+                ActivityEventType::Signal,
+                message.clone(),
+                EventSeverity::Info,
+            );
+            agent.order_entry.quantity.clear();
+            agent.order_entry.last_result = Some((message, false));
+        }
+        Err(message) => agent.order_entry.last_result = Some((message, true)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rust_decimal_macros::dec;
+
+    #[test]
+    fn test_build_market_proposal_uses_reference_price() {
+        let state = OrderEntryState {
+            symbol: " aapl ".to_string(),
+            side: OrderSide::Sell,
+            quantity: "5".to_string(),
+            ..OrderEntryState::default()
+        };
+
+        let proposal = state.build_proposal(Some(dec!(190.5)), "Manual").unwrap();
+        assert_eq!(proposal.symbol, "AAPL");
+        assert_eq!(proposal.side, OrderSide::Sell);
+        assert_eq!(proposal.quantity, dec!(5));
+        assert_eq!(proposal.price, dec!(190.5));
+        assert_eq!(proposal.order_type, OrderType::Market);
+    }
+
+    #[test]
+    fn test_build_proposal_validation() {
+        let mut state = OrderEntryState {
+            symbol: "BTC/USD".to_string(),
+            quantity: "0".to_string(),
+            ..OrderEntryState::default()
+        };
+        assert_eq!(
+            state.build_proposal(None, "Manual").unwrap_err(),
+            "order_entry_error_quantity"
+        );
+
+        state.quantity = "0.5".to_string();
+        state.order_type = OrderType::Limit;
+        state.limit_price = "abc".to_string();
+        assert_eq!(
+            state.build_proposal(None, "Manual").unwrap_err(),
+            "order_entry_error_price"
+        );
+
+        state.order_type = OrderType::Market;
+        assert_eq!(
+            state.build_proposal(None, "Manual").unwrap_err(),
+            "order_entry_error_no_price"
+        );
+
+        state.order_type = OrderType::Limit;
+        state.limit_price = "42000".to_string();
+        let proposal = state.build_proposal(Some(dec!(43000)), "Manual").unwrap();
+        assert_eq!(proposal.price, dec!(42000));
+        assert_eq!(proposal.order_type, OrderType::Limit);
+
+        state.symbol.clear();
+        assert_eq!(
+            state.build_proposal(None, "Manual").unwrap_err(),
+            "order_entry_error_symbol"
+        );
+    }
+}
@@ -3,6 +3,7 @@ pub mod dashboard;
 pub mod dashboard_components;
 pub mod design_system;
 pub mod settings_components;
+pub mod shortcuts;
 pub mod ui;
 pub mod ui_components;
 pub mod view_models;
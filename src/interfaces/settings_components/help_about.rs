@@ -1,6 +1,7 @@
 //! Help, Shortcuts, and About tab components
 
 use crate::infrastructure::i18n::I18nService;
+use crate::interfaces::shortcuts::SHORTCUTS;
 use eframe::egui;
 
 /// Renders the Help settings tab
@@ -13,6 +14,19 @@ pub fn render_help_tab(ui: &mut egui::Ui, i18n: &I18nService) {
 pub fn render_shortcuts_tab(ui: &mut egui::Ui, i18n: &I18nService) {
     ui.heading(i18n.t("tab_shortcuts"));
     ui.label(i18n.t("shortcuts_description"));
+    ui.add_space(10.0);
+
+    egui::Grid::new("shortcuts_grid")
+        .num_columns(2)
+        .spacing([24.0, 8.0])
+        .striped(true)
+        .show(ui, |ui| {
+            for shortcut in SHORTCUTS {
+                ui.label(egui::RichText::new(ui.ctx().format_shortcut(&shortcut.keys)).monospace());
+                ui.label(i18n.t(shortcut.label_key));
+                ui.end_row();
+            }
+        });
 }
 
 /// Renders the About settings tab
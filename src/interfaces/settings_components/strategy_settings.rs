@@ -1,5 +1,6 @@
 //! Strategy settings component (Advanced Mode)
 
+use crate::domain::market::strategy_config::StrategyMode;
 use crate::infrastructure::i18n::I18nService;
 use crate::interfaces::components::card::Card;
 use crate::interfaces::design_system::DesignSystem;
@@ -56,6 +57,25 @@ fn ui_setting_with_hint(ui: &mut egui::Ui, label: &str, value: &mut String, hint
     ui.add_space(20.0);
 }
 
+/// Strategies selectable from the UI
+const SELECTABLE_STRATEGIES: [StrategyMode; 15] = [
+    StrategyMode::Standard,
+    StrategyMode::Advanced,
+    StrategyMode::Dynamic,
+    StrategyMode::TrendRiding,
+    StrategyMode::MeanReversion,
+    StrategyMode::RegimeAdaptive,
+    StrategyMode::SMC,
+    StrategyMode::VWAP,
+    StrategyMode::Breakout,
+    StrategyMode::Momentum,
+    StrategyMode::Ensemble,
+    StrategyMode::ZScoreMR,
+    StrategyMode::StatMomentum,
+    StrategyMode::OrderFlow,
+    StrategyMode::ML,
+];
+
 /// Renders the Advanced Mode strategy settings
 pub fn render_strategy_settings(ui: &mut egui::Ui, panel: &mut SettingsPanel, i18n: &I18nService) {
     ui.add_space(20.0); // Space at top
@@ -105,6 +125,30 @@ pub fn render_strategy_settings(ui: &mut egui::Ui, panel: &mut SettingsPanel, i1
         .show(ui, |ui| {
             ui.add_space(15.0);
 
+            ui.horizontal(|ui| {
+                ui.label(
+                    egui::RichText::new(i18n.t("settings_strat_mode"))
+                        .size(14.0)
+                        .color(DesignSystem::TEXT_PRIMARY),
+                )
+                .on_hover_text(i18n.t("settings_strat_mode_hint"));
+
+                ui.with_layout(egui::Layout::right_to_left(egui::Align::Center), |ui| {
+                    egui::ComboBox::from_id_salt("strategy_mode_select")
+                        .selected_text(format!("{:?}", panel.selected_strategy))
+                        .show_ui(ui, |ui| {
+                            for mode in SELECTABLE_STRATEGIES {
+                                ui.selectable_value(
+                                    &mut panel.selected_strategy,
+                                    mode,
+                                    format!("{:?}", mode),
+                                );
+                            }
+                        });
+                });
+            });
+            ui.add_space(20.0);
+
             ui.collapsing(i18n.t("settings_subgroup_trend"), |ui| {
                 ui_setting_with_hint(
                     ui,
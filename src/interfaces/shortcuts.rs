@@ -0,0 +1,213 @@
+//! Keyboard shortcuts.
+//!
+//! [`SHORTCUTS`] is the single source of truth: `ui.rs` binds exactly these
+//! entries and the Shortcuts settings tab lists exactly these entries, so the
+//! documentation cannot drift from the behaviour.
+
+use crate::application::agents::user_agent::UserAgent;
+use crate::interfaces::ui_components::{DashboardView, SettingsTab};
+use eframe::egui::{self, Key, KeyboardShortcut, Modifiers};
+
+/// Actions reachable from the keyboard
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ShortcutAction {
+    OpenSettings,
+    OpenHelp,
+    OpenShortcuts,
+    NextChartTab,
+    PreviousChartTab,
+    ToggleLogs,
+    TogglePause,
+}
+
+impl ShortcutAction {
+    pub const ALL: [ShortcutAction; 7] = [
+        ShortcutAction::OpenSettings,
+        ShortcutAction::OpenHelp,
+        ShortcutAction::OpenShortcuts,
+        ShortcutAction::NextChartTab,
+        ShortcutAction::PreviousChartTab,
+        ShortcutAction::ToggleLogs,
+        ShortcutAction::TogglePause,
+    ];
+
+    /// Whether the key combination also edits text (Cmd+Arrow moves the cursor
+    /// to the line start/end), so it must be left to a focused text field
+    pub fn edits_text(self) -> bool {
+        matches!(
+            self,
+            ShortcutAction::NextChartTab | ShortcutAction::PreviousChartTab
+        )
+    }
+}
+
+/// A bound key combination and the i18n key describing it
+pub struct Shortcut {
+    pub action: ShortcutAction,
+    pub keys: KeyboardShortcut,
+    pub label_key: &'static str,
+}
+
+pub const SHORTCUTS: &[Shortcut] = &[
+    Shortcut {
+        action: ShortcutAction::OpenSettings,
+        keys: KeyboardShortcut::new(Modifiers::COMMAND, Key::Comma),
+        label_key: "shortcuts_settings",
+    },
+    Shortcut {
+        action: ShortcutAction::OpenHelp,
+        keys: KeyboardShortcut::new(Modifiers::NONE, Key::F1),
+        label_key: "shortcuts_help",
+    },
+    Shortcut {
+        action: ShortcutAction::OpenShortcuts,
+        keys: KeyboardShortcut::new(Modifiers::COMMAND, Key::K),
+        label_key: "shortcuts_shortcuts",
+    },
+    Shortcut {
+        action: ShortcutAction::NextChartTab,
+        keys: KeyboardShortcut::new(Modifiers::COMMAND, Key::ArrowRight),
+        label_key: "shortcuts_next_chart",
+    },
+    Shortcut {
+        action: ShortcutAction::PreviousChartTab,
+        keys: KeyboardShortcut::new(Modifiers::COMMAND, Key::ArrowLeft),
+        label_key: "shortcuts_previous_chart",
+    },
+    Shortcut {
+        action: ShortcutAction::ToggleLogs,
+        keys: KeyboardShortcut::new(Modifiers::COMMAND, Key::L),
+        label_key: "shortcuts_toggle_logs",
+    },
+    Shortcut {
+        action: ShortcutAction::TogglePause,
+        keys: KeyboardShortcut::new(Modifiers::COMMAND, Key::P),
+        label_key: "shortcuts_toggle_pause",
+    },
+];
+
+/// Consumes every bound shortcut pressed this frame and applies it to the agent.
+///
+/// Shortcuts that double as text-editing keys are skipped while a text field has focus.
+pub fn handle_shortcuts(ctx: &egui::Context, agent: &mut UserAgent) {
+    let editing_text = ctx.wants_keyboard_input();
+    let pressed: Vec<ShortcutAction> = ctx.input_mut(|i| {
+        SHORTCUTS
+            .iter()
+            .filter(|s| !(editing_text && s.action.edits_text()))
+            .filter(|s| i.consume_shortcut(&s.keys))
+            .map(|s| s.action)
+            .collect()
+    });
+
+    for action in pressed {
+        apply(agent, action);
+    }
+}
+
+fn apply(agent: &mut UserAgent, action: ShortcutAction) {
+    match action {
+        ShortcutAction::OpenSettings => agent.current_view = DashboardView::Settings,
+        ShortcutAction::OpenHelp => {
+            agent.current_view = DashboardView::Settings;
+            agent.settings_panel.active_tab = SettingsTab::Help;
+        }
+        ShortcutAction::OpenShortcuts => {
+            agent.current_view = DashboardView::Settings;
+            agent.settings_panel.active_tab = SettingsTab::Shortcuts;
+        }
+        ShortcutAction::NextChartTab | ShortcutAction::PreviousChartTab => {
+            let mut symbols: Vec<String> = agent.market_data.keys().cloned().collect();
+            symbols.sort();
+            let step = if action == ShortcutAction::NextChartTab {
+                1
+            } else {
+                -1
+            };
+            if let Some(symbol) = cycle_symbol(&symbols, agent.selected_chart_tab.as_deref(), step)
+            {
+                agent.selected_chart_tab = Some(symbol);
+            }
+        }
+        ShortcutAction::ToggleLogs => agent.logs_collapsed = !agent.logs_collapsed,
+        ShortcutAction::TogglePause => {
+            let paused = !agent.trading_paused;
+            agent.set_trading_paused(paused);
+        }
+    }
+}
+
+/// Symbol `step` positions away from `current` in the sorted list, wrapping around
+fn cycle_symbol(symbols: &[String], current: Option<&str>, step: isize) -> Option<String> {
+    if symbols.is_empty() {
+        return None;
+    }
+    let len = symbols.len() as isize;
+    let index = match current.and_then(|c| symbols.iter().position(|s| s == c)) {
+        Some(i) => (i as isize + step).rem_euclid(len),
+        None => 0,
+    };
+    Some(symbols[index as usize].clone())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::infrastructure::i18n::I18nService;
+
+    #[test]
+    fn test_documented_shortcuts_match_bound_actions() {
+        // Every action is bound exactly once and nothing else is listed
+        for action in ShortcutAction::ALL {
+            assert_eq!(
+                SHORTCUTS.iter().filter(|s| s.action == action).count(),
+                1,
+                "{:?} must have exactly one shortcut",
+                action
+            );
+        }
+        assert_eq!(SHORTCUTS.len(), ShortcutAction::ALL.len());
+
+        // No two actions share a key combination
+        for (i, a) in SHORTCUTS.iter().enumerate() {
+            for b in &SHORTCUTS[i + 1..] {
+                assert_ne!(a.keys, b.keys, "{:?} and {:?} collide", a.action, b.action);
+            }
+        }
+
+        // Every documented entry has a translated description
+        let mut i18n = I18nService::new();
+        assert!(i18n.set_language("en"));
+        for shortcut in SHORTCUTS {
+            assert_ne!(
+                i18n.t(shortcut.label_key),
+                shortcut.label_key,
+                "Missing translation for {}",
+                shortcut.label_key
+            );
+        }
+    }
+
+    #[test]
+    fn test_arrow_shortcuts_yield_to_text_fields() {
+        for shortcut in SHORTCUTS {
+            let is_arrow = matches!(shortcut.keys.logical_key, Key::ArrowLeft | Key::ArrowRight);
+            assert_eq!(
+                shortcut.action.edits_text(),
+                is_arrow,
+                "{:?} must only yield to text fields if it is a cursor key",
+                shortcut.action
+            );
+        }
+    }
+
+    #[test]
+    fn test_cycle_symbol_wraps() {
+        let symbols = vec!["AAPL".to_string(), "MSFT".to_string(), "TSLA".to_string()];
+        assert_eq!(cycle_symbol(&symbols, Some("AAPL"), 1).unwrap(), "MSFT");
+        assert_eq!(cycle_symbol(&symbols, Some("TSLA"), 1).unwrap(), "AAPL");
+        assert_eq!(cycle_symbol(&symbols, Some("AAPL"), -1).unwrap(), "TSLA");
+        assert_eq!(cycle_symbol(&symbols, None, 1).unwrap(), "AAPL");
+        assert!(cycle_symbol(&[], Some("AAPL"), 1).is_none());
+    }
+}
@@ -10,30 +10,11 @@ impl eframe::App for UserAgent {
         ctx.set_visuals(crate::interfaces::design_system::DesignSystem::theme());
 
         // --- Keyboard Shortcuts ---
-        ctx.input(|i| {
-            // Ctrl/Cmd + , to go to settings
-            if i.modifiers.command && i.key_pressed(egui::Key::Comma) {
-                self.current_view = crate::interfaces::ui_components::DashboardView::Settings;
-            }
-
-            // F1 to open help (inside settings)
-            if i.key_pressed(egui::Key::F1) {
-                self.current_view = crate::interfaces::ui_components::DashboardView::Settings;
-                self.settings_panel.active_tab =
-                    crate::interfaces::ui_components::SettingsTab::Help;
-            }
-
-            // Ctrl/Cmd + K to open shortcuts (inside settings)
-            if i.modifiers.command && i.key_pressed(egui::Key::K) {
-                self.current_view = crate::interfaces::ui_components::DashboardView::Settings;
-                self.settings_panel.active_tab =
-                    crate::interfaces::ui_components::SettingsTab::Shortcuts;
-            }
-        });
+        crate::interfaces::shortcuts::handle_shortcuts(ctx, self);
 
         // --- 1. Process System Events (Logs & Candles) ---
-        self.update();
-        ctx.request_repaint(); // Ensure continuous updates for logs/charts
+        let had_activity = self.update();
+        ctx.request_repaint_after(self.next_repaint_delay(had_activity));
 
         // --- 2. Sidebar (Left) ---
         egui::SidePanel::left("sidebar_panel")
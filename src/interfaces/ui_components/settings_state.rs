@@ -36,6 +36,16 @@ pub struct SettingsPanel {
 
     pub sma_threshold: String,
     pub profit_target_multiplier: String,
+
+    /// Inline validation error (i18n key) shown when the last save was rejected
+    pub validation_error: Option<String>,
+
+    /// Whether the form was loaded from settings the user saved earlier
+    pub has_persisted_settings: bool,
+
+    // --- Last settings sent to the agents (unsaved edits excluded) ---
+    pub applied_risk_score: u8,
+    pub applied_analyst_config: AnalystConfig,
 }
 
 impl Default for SettingsPanel {
@@ -70,6 +80,13 @@ impl SettingsPanel {
 
             sma_threshold: "0.001".to_string(),
             profit_target_multiplier: "2.0".to_string(),
+
+            validation_error: None,
+
+            has_persisted_settings: false,
+
+            applied_risk_score: 5,
+            applied_analyst_config: AnalystConfig::default(),
         };
         // Initialize strings based on default risk score
         panel.update_from_score(5);
@@ -80,6 +97,7 @@ impl SettingsPanel {
                 Ok(Some(settings)) => {
                     info!("Applying persisted settings");
                     panel.apply_persisted_settings(&settings);
+                    panel.has_persisted_settings = true;
                 }
                 Ok(None) => info!("No persisted settings found, using defaults"),
                 Err(e) => error!("Failed to load settings: {}", e),
@@ -87,9 +105,38 @@ impl SettingsPanel {
             Err(e) => error!("Failed to initialize settings persistence: {}", e),
         }
 
+        let defaults = panel.to_analyst_config(&AnalystConfig::default());
+        panel.mark_applied(defaults);
+
         panel
     }
 
+    /// Records the current risk score and `analyst_config` as what the agents are running with
+    pub fn mark_applied(&mut self, analyst_config: AnalystConfig) {
+        self.applied_risk_score = self.risk_score;
+        self.applied_analyst_config = analyst_config;
+    }
+
+    /// Fills the form with the settings the engine is running with
+    pub fn apply_engine_config(&mut self, risk: &RiskConfig, analyst: &AnalystConfig) {
+        self.selected_strategy = analyst.strategy_mode;
+
+        self.max_position_size_pct = risk.max_position_size_pct.to_string();
+        self.max_daily_loss_pct = risk.max_daily_loss_pct.to_string();
+        self.max_drawdown_pct = risk.max_drawdown_pct.to_string();
+        self.consecutive_loss_limit = risk.consecutive_loss_limit.to_string();
+
+        self.fast_sma_period = analyst.fast_sma_period.to_string();
+        self.slow_sma_period = analyst.slow_sma_period.to_string();
+        self.rsi_period = analyst.rsi_period.to_string();
+        self.rsi_threshold = analyst.rsi_threshold.to_string();
+        self.macd_min_threshold = analyst.macd_min_threshold.to_string();
+        self.adx_threshold = analyst.adx_threshold.to_string();
+        self.min_profit_ratio = analyst.min_profit_ratio.to_string();
+        self.sma_threshold = analyst.sma_threshold.to_string();
+        self.profit_target_multiplier = analyst.profit_target_multiplier.to_string();
+    }
+
     /// Applies persisted settings to the panel
     pub fn apply_persisted_settings(&mut self, settings: &PersistedSettings) {
         // Mode & Score
@@ -113,6 +160,10 @@ impl SettingsPanel {
             "Dynamic" => StrategyMode::Dynamic,
             "VWAP" => StrategyMode::VWAP,
             "Ensemble" => StrategyMode::Ensemble,
+            "ZScoreMR" => StrategyMode::ZScoreMR,
+            "StatMomentum" => StrategyMode::StatMomentum,
+            "OrderFlow" => StrategyMode::OrderFlow,
+            "ML" => StrategyMode::ML,
             _ => Self::select_strategy_for_risk(settings.risk_score), // Fallback to risk-based
         };
 
@@ -190,20 +241,69 @@ impl SettingsPanel {
         }
     }
 
-    /// Converts current UI state to RiskConfig
-    pub fn to_risk_config(&self) -> RiskConfig {
+    /// Checks that every field parses and is in range before it is sent to the agents.
+    ///
+    /// Returns the i18n key of the first problem found.
+    pub fn validate(&self) -> Result<(), &'static str> {
+        fn period(value: &str) -> Option<usize> {
+            value.trim().parse::<usize>().ok().filter(|p| *p > 0)
+        }
+        fn decimal(value: &str) -> Option<Decimal> {
+            value.trim().parse::<Decimal>().ok()
+        }
+        fn fraction(value: &str) -> Option<Decimal> {
+            decimal(value).filter(|v| *v > Decimal::ZERO && *v <= Decimal::ONE)
+        }
+
+        let fast = period(&self.fast_sma_period).ok_or("settings_error_period")?;
+        let slow = period(&self.slow_sma_period).ok_or("settings_error_period")?;
+        period(&self.rsi_period).ok_or("settings_error_period")?;
+        if fast >= slow {
+            return Err("settings_error_fast_slow");
+        }
+
+        decimal(&self.rsi_threshold)
+            .filter(|v| *v > Decimal::ZERO && *v < Decimal::ONE_HUNDRED)
+            .ok_or("settings_error_rsi_threshold")?;
+        // MACD threshold may be negative for aggressive profiles
+        decimal(&self.macd_min_threshold).ok_or("settings_error_number")?;
+        for value in [&self.adx_threshold, &self.sma_threshold] {
+            decimal(value)
+                .filter(|v| *v >= Decimal::ZERO)
+                .ok_or("settings_error_number")?;
+        }
+        for value in [&self.min_profit_ratio, &self.profit_target_multiplier] {
+            decimal(value)
+                .filter(|v| *v > Decimal::ZERO)
+                .ok_or("settings_error_number")?;
+        }
+
+        for value in [
+            &self.max_position_size_pct,
+            &self.max_daily_loss_pct,
+            &self.max_drawdown_pct,
+        ] {
+            fraction(value).ok_or("settings_error_fraction")?;
+        }
+        period(&self.consecutive_loss_limit).ok_or("settings_error_period")?;
+
+        Ok(())
+    }
+
+    /// Overwrites the fields edited in the UI on top of `base` (the running config)
+    pub fn to_risk_config(&self, base: &RiskConfig) -> RiskConfig {
         use rust_decimal_macros::dec;
         RiskConfig {
             max_position_size_pct: self.max_position_size_pct.parse().unwrap_or(dec!(0.10)),
             max_daily_loss_pct: self.max_daily_loss_pct.parse().unwrap_or(dec!(0.02)),
             max_drawdown_pct: self.max_drawdown_pct.parse().unwrap_or(dec!(0.05)),
             consecutive_loss_limit: self.consecutive_loss_limit.parse().unwrap_or(3),
-            ..RiskConfig::default()
+            ..base.clone()
         }
     }
 
-    /// Converts current UI state to AnalystConfig
-    pub fn to_analyst_config(&self) -> AnalystConfig {
+    /// Overwrites the fields edited in the UI on top of `base` (the running config)
+    pub fn to_analyst_config(&self, base: &AnalystConfig) -> AnalystConfig {
         use rust_decimal_macros::dec;
         AnalystConfig {
             strategy_mode: self.selected_strategy, // Include selected strategy
@@ -216,7 +316,88 @@ impl SettingsPanel {
             adx_threshold: self.adx_threshold.parse().unwrap_or(dec!(25.0)),
             min_profit_ratio: self.min_profit_ratio.parse().unwrap_or(dec!(1.5)),
             profit_target_multiplier: self.profit_target_multiplier.parse().unwrap_or(dec!(2.0)),
-            ..AnalystConfig::default()
+            ..base.clone()
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_validate_rejects_bad_periods() {
+        let mut panel = SettingsPanel::new();
+        panel.fast_sma_period = "10".to_string();
+        panel.slow_sma_period = "30".to_string();
+        assert_eq!(panel.validate(), Ok(()));
+
+        panel.fast_sma_period = "30".to_string();
+        assert_eq!(panel.validate(), Err("settings_error_fast_slow"));
+
+        panel.fast_sma_period = "0".to_string();
+        assert_eq!(panel.validate(), Err("settings_error_period"));
+
+        panel.fast_sma_period = "abc".to_string();
+        assert_eq!(panel.validate(), Err("settings_error_period"));
+    }
+
+    #[test]
+    fn test_validate_rejects_bad_thresholds() {
+        let mut panel = SettingsPanel::new();
+        panel.fast_sma_period = "10".to_string();
+        panel.slow_sma_period = "30".to_string();
+
+        panel.rsi_threshold = "120".to_string();
+        assert_eq!(panel.validate(), Err("settings_error_rsi_threshold"));
+        panel.rsi_threshold = "70".to_string();
+
+        panel.max_daily_loss_pct = "2".to_string();
+        assert_eq!(panel.validate(), Err("settings_error_fraction"));
+    }
+
+    #[test]
+    fn test_applied_config_ignores_unsaved_edits() {
+        let mut panel = SettingsPanel::new();
+        panel.fast_sma_period = "7".to_string();
+        panel.risk_score = 8;
+        let config = panel.to_analyst_config(&AnalystConfig::default());
+        panel.mark_applied(config);
+        assert_eq!(panel.applied_analyst_config.fast_sma_period, 7);
+        assert_eq!(panel.applied_risk_score, 8);
+
+        // Editing the form does not change what the agents run with until saved
+        panel.fast_sma_period = "12".to_string();
+        panel.risk_score = 2;
+        assert_eq!(panel.applied_analyst_config.fast_sma_period, 7);
+        assert_eq!(panel.applied_risk_score, 8);
+    }
+
+    #[test]
+    fn test_configs_keep_running_fields_the_form_does_not_cover() {
+        use rust_decimal_macros::dec;
+        let mut panel = SettingsPanel::new();
+        panel.max_position_size_pct = "0.15".to_string();
+        panel.fast_sma_period = "9".to_string();
+
+        let running_risk = RiskConfig {
+            allow_shorts: true,
+            leverage: dec!(2),
+            max_open_orders: 4,
+            ..RiskConfig::default()
+        };
+        let risk = panel.to_risk_config(&running_risk);
+        assert_eq!(risk.max_position_size_pct, dec!(0.15));
+        assert!(risk.allow_shorts);
+        assert_eq!(risk.leverage, dec!(2));
+        assert_eq!(risk.max_open_orders, 4);
+
+        let running_analyst = AnalystConfig {
+            trend_sma_period: 123,
+            ..AnalystConfig::default()
+        };
+        let analyst = panel.to_analyst_config(&running_analyst);
+        assert_eq!(analyst.fast_sma_period, 9);
+        assert_eq!(analyst.trend_sma_period, 123);
+    }
+}
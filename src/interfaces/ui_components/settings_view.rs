@@ -71,6 +71,17 @@ pub fn render_settings_view(
                     });
                 });
 
+                // Inline validation error from the last save attempt
+                if panel.active_tab == SettingsTab::TradingEngine
+                    && let Some(key) = &panel.validation_error
+                {
+                    ui.label(
+                        egui::RichText::new(format!("⚠ {}", i18n.t(key)))
+                            .size(13.0)
+                            .color(DesignSystem::DANGER),
+                    );
+                }
+
                 ui.add_space(DesignSystem::SPACING_MEDIUM);
                 ui.separator();
                 ui.add_space(DesignSystem::SPACING_MEDIUM);
@@ -259,7 +270,7 @@ fn render_mode_toggle(ui: &mut egui::Ui, panel: &mut SettingsPanel, i18n: &I18nS
 /// Renders the save button and handles configuration parsing and sending
 fn render_save_button(
     ui: &mut egui::Ui,
-    panel: &SettingsPanel,
+    panel: &mut SettingsPanel,
     i18n: &I18nService,
     client: &SystemClient,
 ) {
@@ -267,6 +278,13 @@ fn render_save_button(
         .button(egui::RichText::new(i18n.t("settings_save_button")).size(18.0))
         .clicked()
     {
+        // Reject invalid input before anything is persisted or sent to the engine
+        if let Err(key) = panel.validate() {
+            panel.validation_error = Some(key.to_string());
+            return;
+        }
+        panel.validation_error = None;
+
         // --- Save Settings to Disk ---
         let persisted_settings = PersistedSettings {
             config_mode: match panel.config_mode {
@@ -303,18 +321,34 @@ fn render_save_button(
         }
 
         // --- Send Updates to System ---
-        // Risk Config
-        let risk_config = panel.to_risk_config();
-        if let Err(e) = client.send_risk_command(RiskCommand::UpdateConfig(Box::new(risk_config))) {
-            error!("Failed to send update config command: {}", e);
-        }
+        send_settings_to_agents(panel, client);
+    }
+}
 
-        // Analyst Config
-        let analyst_cfg = panel.to_analyst_config();
-        if let Err(e) =
-            client.send_analyst_command(AnalystCommand::UpdateConfig(Box::new(analyst_cfg)))
-        {
-            error!("Failed to send analyst config update: {}", e);
-        }
+/// Sends the form fields to the RiskManager and Analyst, merged onto the configs they
+/// are running with so settings the form doesn't cover (env-only) are kept.
+pub fn send_settings_to_agents(panel: &mut SettingsPanel, client: &SystemClient) {
+    let (Ok(running_risk), Ok(running_analyst)) = (
+        client.risk_config().try_read().map(|c| c.clone()),
+        client.analyst_config().try_read().map(|c| c.clone()),
+    ) else {
+        error!("Running config is being updated, settings not sent; save again");
+        return;
+    };
+
+    // Risk Config
+    let risk_config = panel.to_risk_config(&running_risk);
+    if let Err(e) = client.send_risk_command(RiskCommand::UpdateConfig(Box::new(risk_config))) {
+        error!("Failed to send update config command: {}", e);
+    }
+
+    // Analyst Config
+    let analyst_cfg = panel.to_analyst_config(&running_analyst);
+    if let Err(e) =
+        client.send_analyst_command(AnalystCommand::UpdateConfig(Box::new(analyst_cfg.clone())))
+    {
+        error!("Failed to send analyst config update: {}", e);
+    } else {
+        panel.mark_applied(analyst_cfg);
     }
 }
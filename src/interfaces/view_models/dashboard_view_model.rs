@@ -10,6 +10,13 @@ pub struct DashboardMetrics {
     pub pnl_color: egui::Color32,
     pub pnl_sign: &'static str,
     pub pnl_arrow: &'static str,
+    /// P&L booked by closed (sold) quantity this session
+    pub realized_pnl: f64,
+    /// Realized + unrealized
+    pub total_pnl: f64,
+    pub total_pnl_color: egui::Color32,
+    pub total_pnl_sign: &'static str,
+    pub total_pnl_arrow: &'static str,
     pub position_count: usize,
     pub market_value: f64,
 }
@@ -39,56 +46,77 @@ impl DashboardViewModel {
     pub fn get_metrics(agent: &UserAgent) -> DashboardMetrics {
         let total_value = agent.calculate_total_value().to_f64().unwrap_or(0.0);
 
-        let (pnl_value, pnl_pct, position_count, market_value) = match agent.portfolio.try_read() {
-            Ok(pf) => {
-                let mut cost_basis = Decimal::ZERO;
-                let mut mv = Decimal::ZERO;
-                for (symbol, pos) in pf.positions.iter() {
-                    let position_cost = pos.quantity * pos.average_price;
-                    cost_basis += position_cost;
-                    if let Some(info) = agent.strategy_info.get(symbol) {
-                        mv += pos.quantity * info.current_price;
-                    } else {
-                        mv += position_cost;
+        let (pnl_value, pnl_pct, realized_pnl, position_count, market_value) =
+            match agent.portfolio.try_read() {
+                Ok(pf) => {
+                    let mut cost_basis = Decimal::ZERO;
+                    let mut mv = Decimal::ZERO;
+                    for (symbol, pos) in pf.positions.iter() {
+                        let position_cost = pos.quantity * pos.average_price;
+                        cost_basis += position_cost;
+                        if let Some(info) = agent.strategy_info.get(symbol) {
+                            mv += pos.quantity * info.current_price;
+                        } else {
+                            mv += position_cost;
+                        }
                     }
+                    let pnl = mv - cost_basis;
+                    let pnl_pct = if cost_basis > Decimal::ZERO {
+                        (pnl / cost_basis * Decimal::from(100))
+                            .to_f64()
+                            .unwrap_or(0.0)
+                    } else {
+                        0.0
+                    };
+                    (
+                        pnl.to_f64().unwrap_or(0.0),
+                        pnl_pct,
+                        pf.realized_pnl.to_f64().unwrap_or(0.0),
+                        pf.positions.len(),
+                        mv.to_f64().unwrap_or(0.0),
+                    )
                 }
-                let pnl = mv - cost_basis;
-                let pnl_pct = if cost_basis > Decimal::ZERO {
-                    (pnl / cost_basis * Decimal::from(100))
-                        .to_f64()
-                        .unwrap_or(0.0)
-                } else {
-                    0.0
-                };
-                (
-                    pnl.to_f64().unwrap_or(0.0),
-                    pnl_pct,
-                    pf.positions.len(),
-                    mv.to_f64().unwrap_or(0.0),
-                )
-            }
-            Err(_) => (0.0, 0.0, 0, 0.0),
-        };
+                Err(_) => (0.0, 0.0, 0.0, 0, 0.0),
+            };
 
-        let is_positive = pnl_value >= 0.0;
-        let pnl_color = if is_positive {
-            egui::Color32::from_rgb(0, 230, 118) // Neon Green
-        } else {
-            egui::Color32::from_rgb(255, 23, 68) // Neon Red
-        };
+        let (pnl_color, pnl_sign, pnl_arrow) = Self::pnl_style(pnl_value);
+        let total_pnl = realized_pnl + pnl_value;
+        let (total_pnl_color, total_pnl_sign, total_pnl_arrow) = Self::pnl_style(total_pnl);
 
         DashboardMetrics {
             total_value,
             pnl_value,
             pnl_pct,
             pnl_color,
-            pnl_sign: if is_positive { "+" } else { "" },
-            pnl_arrow: if is_positive { "↗" } else { "↘" },
+            pnl_sign,
+            pnl_arrow,
+            realized_pnl,
+            total_pnl,
+            total_pnl_color,
+            total_pnl_sign,
+            total_pnl_arrow,
             position_count,
             market_value,
         }
     }
 
+    /// (color, sign prefix, arrow) for a P&L amount
+    pub fn pnl_style(value: f64) -> (egui::Color32, &'static str, &'static str) {
+        if value >= 0.0 {
+            (
+                egui::Color32::from_rgb(0, 230, 118), // Neon Green
+                "+",
+                "↗",
+            )
+        } else {
+            (
+                egui::Color32::from_rgb(255, 23, 68), // Neon Red
+                "",
+                "↘",
+            )
+        }
+    }
+
     pub fn get_win_rate(agent: &UserAgent) -> WinRateMetrics {
         WinRateMetrics {
             rate: agent.calculate_win_rate().to_f64().unwrap_or(0.0),
@@ -98,7 +126,7 @@ impl DashboardViewModel {
     }
 
     pub fn get_risk_metrics(agent: &UserAgent) -> RiskMetrics {
-        let risk_score = agent.settings_panel.risk_score;
+        let risk_score = agent.settings_panel.applied_risk_score;
         let (label_key, color) = match risk_score {
             1..=3 => ("risk_low", egui::Color32::from_rgb(0, 230, 118)),
             4..=7 => ("risk_medium", egui::Color32::from_rgb(255, 212, 59)),
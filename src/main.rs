@@ -1,4 +1,7 @@
-use rustrade::application::agents::user_agent::{UserAgent, UserAgentConfig};
+use rustrade::application::agents::user_agent::{
+    DEFAULT_CHART_CANDLE_LIMIT, DEFAULT_LOG_MAX_LINES, DEFAULT_UI_IDLE_REPAINT_HZ,
+    DEFAULT_UI_MAX_FPS, UserAgent, UserAgentConfig,
+};
 use rustrade::application::client::SystemClient;
 
 use rustrade::application::system::Application;
@@ -137,6 +140,28 @@ fn main() -> anyhow::Result<()> {
     let config = UserAgentConfig {
         strategy_mode,
         risk_appetite,
+        chart_candle_limit: std::env::var("UI_CHART_CANDLES")
+            .ok()
+            .and_then(|v| v.parse().ok())
+            .unwrap_or(DEFAULT_CHART_CANDLE_LIMIT),
+        chart_downsample_older: std::env::var("UI_CHART_DOWNSAMPLE")
+            .map(|v| v.to_lowercase() == "true")
+            .unwrap_or(false),
+        log_max_lines: std::env::var("UI_LOG_MAX_LINES")
+            .ok()
+            .and_then(|v| v.parse().ok())
+            .unwrap_or(DEFAULT_LOG_MAX_LINES),
+        log_keep_warnings: std::env::var("UI_LOG_KEEP_WARNINGS")
+            .map(|v| v.to_lowercase() != "false")
+            .unwrap_or(true),
+        max_fps: std::env::var("UI_MAX_FPS")
+            .ok()
+            .and_then(|v| v.parse().ok())
+            .unwrap_or(DEFAULT_UI_MAX_FPS),
+        idle_repaint_hz: std::env::var("UI_IDLE_REPAINT_HZ")
+            .ok()
+            .and_then(|v| v.parse().ok())
+            .unwrap_or(DEFAULT_UI_IDLE_REPAINT_HZ),
     };
 
     // Load available symbols in crypto mode
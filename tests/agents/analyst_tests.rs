@@ -1,5 +1,7 @@
 use rustrade::application::agents::analyst::{Analyst, AnalystConfig, AnalystDependencies};
 use rustrade::application::market_data::spread_cache::SpreadCache;
+use rustrade::domain::market::timeframe::Timeframe;
+use rustrade::domain::risk::session_boundary::TradingDayBoundary;
 use rustrade::domain::trading::types::{Candle, MarketEvent, OrderSide};
 use rustrade::infrastructure::mock::{MockExecutionService, MockMarketDataService};
 // use rustrade::domain::market::strategy_config::StrategyMode;
@@ -7,6 +9,7 @@ use rustrade::infrastructure::mock::{MockExecutionService, MockMarketDataService
 use rust_decimal::Decimal;
 use rust_decimal::prelude::FromPrimitive;
 use rust_decimal_macros::dec;
+use std::collections::VecDeque;
 use std::sync::{Arc, Once};
 use tokio::sync::{RwLock, mpsc};
 
@@ -104,6 +107,269 @@ async fn test_immediate_warmup() {
     let _context = analyst.get_context("BTC/USD").unwrap();
 }
 
+#[tokio::test]
+async fn test_unsubscribe_releases_context() {
+    setup_logging();
+    let (market_tx, market_rx) = mpsc::channel(10);
+    let (_cmd_tx, cmd_rx) = mpsc::channel(10);
+    let (proposal_tx, _proposal_rx) = mpsc::channel(10);
+
+    use rustrade::domain::trading::portfolio::Portfolio;
+    let portfolio_lock = Arc::new(RwLock::new(Portfolio::new()));
+    let exec_service = Arc::new(MockExecutionService::new(portfolio_lock));
+
+    let config = AnalystConfig::default();
+    let strategy = rustrade::application::strategies::StrategyFactory::create(
+        rustrade::domain::market::strategy_config::StrategyMode::Advanced,
+        &config,
+    );
+
+    let mut analyst = Analyst::new(
+        market_rx,
+        cmd_rx,
+        proposal_tx,
+        config,
+        strategy,
+        AnalystDependencies {
+            execution_service: exec_service,
+            market_service: Arc::new(MockMarketDataService::new()),
+            candle_repository: None,
+            strategy_repository: None,
+            win_rate_provider: None,
+            ui_candle_tx: None,
+            spread_cache: Arc::new(SpreadCache::new()),
+            connection_health_service: create_online_health_service().await,
+            agent_registry: Arc::new(
+                rustrade::application::monitoring::agent_status::AgentStatusRegistry::new(
+                    rustrade::infrastructure::observability::Metrics::new().unwrap(),
+                ),
+            ),
+        },
+    );
+
+    let symbol = "BTC/USD".to_string();
+    market_tx
+        .send(MarketEvent::SymbolSubscription {
+            symbol: symbol.clone(),
+        })
+        .await
+        .unwrap();
+    market_tx
+        .send(MarketEvent::SymbolUnsubscription {
+            symbol: symbol.clone(),
+        })
+        .await
+        .unwrap();
+    // A candle still in flight after the unsubscribe must not recreate the state
+    market_tx
+        .send(MarketEvent::Candle(Candle {
+            symbol: symbol.clone(),
+            open: dec!(100),
+            high: dec!(100),
+            low: dec!(100),
+            close: dec!(100),
+            volume: dec!(1),
+            timestamp: 1_700_000_000_000,
+        }))
+        .await
+        .unwrap();
+
+    tokio::select! {
+        _ = analyst.run() => {},
+        _ = tokio::time::sleep(std::time::Duration::from_millis(100)) => {},
+    }
+
+    assert!(
+        analyst.get_context(&symbol).is_none(),
+        "Context should be released after unsubscribe"
+    );
+}
+
+#[tokio::test]
+async fn test_failed_rewarm_backs_off_and_keeps_indicators() {
+    setup_logging();
+    let (market_tx, market_rx) = mpsc::channel(10);
+    let (_cmd_tx, cmd_rx) = mpsc::channel(10);
+    let (proposal_tx, _proposal_rx) = mpsc::channel(10);
+
+    use rustrade::domain::trading::portfolio::Portfolio;
+    let portfolio_lock = Arc::new(RwLock::new(Portfolio::new()));
+    let exec_service = Arc::new(MockExecutionService::new(portfolio_lock));
+
+    let config = AnalystConfig::default();
+    let strategy = rustrade::application::strategies::StrategyFactory::create(
+        rustrade::domain::market::strategy_config::StrategyMode::Advanced,
+        &config,
+    );
+
+    // The mock market data returns no bars, so every warmup fails
+    let mut analyst = Analyst::new(
+        market_rx,
+        cmd_rx,
+        proposal_tx,
+        config,
+        strategy,
+        AnalystDependencies {
+            execution_service: exec_service,
+            market_service: Arc::new(MockMarketDataService::new()),
+            candle_repository: None,
+            strategy_repository: None,
+            win_rate_provider: None,
+            ui_candle_tx: None,
+            spread_cache: Arc::new(SpreadCache::new()),
+            connection_health_service: create_online_health_service().await,
+            agent_registry: Arc::new(
+                rustrade::application::monitoring::agent_status::AgentStatusRegistry::new(
+                    rustrade::infrastructure::observability::Metrics::new().unwrap(),
+                ),
+            ),
+        },
+    );
+
+    let symbol = "BTC/USD".to_string();
+    analyst
+        .ensure_symbol_initialized(&symbol, chrono::Utc::now())
+        .await;
+    {
+        // Indicators warmed before a structural change asked for a re-warmup
+        let context = analyst.get_context_mut(&symbol).unwrap();
+        context.warmup_succeeded = true;
+        context.rewarm_pending = true;
+    }
+
+    for i in 0..3 {
+        market_tx
+            .send(MarketEvent::Candle(Candle {
+                symbol: symbol.clone(),
+                open: dec!(100),
+                high: dec!(100),
+                low: dec!(100),
+                close: dec!(100),
+                volume: dec!(1),
+                timestamp: BASE_TS + i * 20_000,
+            }))
+            .await
+            .unwrap();
+    }
+
+    tokio::select! {
+        _ = analyst.run() => {},
+        _ = tokio::time::sleep(std::time::Duration::from_millis(200)) => {},
+    }
+
+    let context = analyst.get_context(&symbol).unwrap();
+    assert!(context.rewarm_pending, "Entries stay gated");
+    assert_eq!(
+        context.rewarm_failures, 1,
+        "A failed re-warmup is not retried on the next candles"
+    );
+    assert_eq!(context.rewarm_retry_at, BASE_TS + 60_000);
+    assert!(
+        context.warmup_succeeded,
+        "Indicators are not reset when the history fetch fails"
+    );
+}
+
+#[tokio::test]
+async fn test_symbol_config_override_applies_to_one_symbol() {
+    setup_logging();
+    let (market_tx, market_rx) = mpsc::channel(10);
+    let (cmd_tx, cmd_rx) = mpsc::channel(10);
+    let (proposal_tx, _proposal_rx) = mpsc::channel(10);
+
+    use rustrade::application::agents::analyst::{AnalystCommand, TunedParams};
+    use rustrade::domain::trading::portfolio::Portfolio;
+    let portfolio_lock = Arc::new(RwLock::new(Portfolio::new()));
+    let exec_service = Arc::new(MockExecutionService::new(portfolio_lock));
+
+    let config = AnalystConfig::default();
+    let strategy = rustrade::application::strategies::StrategyFactory::create(
+        rustrade::domain::market::strategy_config::StrategyMode::Advanced,
+        &config,
+    );
+    let default_fast = config.fast_sma_period;
+
+    let mut analyst = Analyst::new(
+        market_rx,
+        cmd_rx,
+        proposal_tx,
+        config.clone(),
+        strategy,
+        AnalystDependencies {
+            execution_service: exec_service,
+            market_service: Arc::new(MockMarketDataService::new()),
+            candle_repository: None,
+            strategy_repository: None,
+            win_rate_provider: None,
+            ui_candle_tx: None,
+            spread_cache: Arc::new(SpreadCache::new()),
+            connection_health_service: create_online_health_service().await,
+            agent_registry: Arc::new(
+                rustrade::application::monitoring::agent_status::AgentStatusRegistry::new(
+                    rustrade::infrastructure::observability::Metrics::new().unwrap(),
+                ),
+            ),
+        },
+    );
+
+    for symbol in ["BTC/USD", "ETH/USD"] {
+        market_tx
+            .send(MarketEvent::SymbolSubscription {
+                symbol: symbol.to_string(),
+            })
+            .await
+            .unwrap();
+    }
+    // A settings change after boot (UI / reload) is not reverted by the tuned parameters
+    cmd_tx
+        .send(AnalystCommand::UpdateConfig(Box::new(AnalystConfig {
+            max_positions: config.max_positions + 2,
+            ..config.clone()
+        })))
+        .await
+        .unwrap();
+    let tuned = AnalystConfig {
+        fast_sma_period: default_fast + 7,
+        ..config.clone()
+    };
+    cmd_tx
+        .send(AnalystCommand::UpdateSymbolParams(
+            "ETH/USD".to_string(),
+            Box::new(TunedParams::from(&tuned)),
+        ))
+        .await
+        .unwrap();
+
+    tokio::select! {
+        _ = analyst.run() => {},
+        _ = tokio::time::sleep(std::time::Duration::from_millis(100)) => {},
+    }
+
+    assert_eq!(
+        analyst
+            .get_context("ETH/USD")
+            .unwrap()
+            .config
+            .fast_sma_period,
+        default_fast + 7,
+        "Override applies to its symbol"
+    );
+    assert_eq!(
+        analyst.get_context("ETH/USD").unwrap().config.max_positions,
+        config.max_positions + 2,
+        "Override keeps the analyst's current untuned settings"
+    );
+    assert_eq!(
+        analyst
+            .get_context("BTC/USD")
+            .unwrap()
+            .config
+            .fast_sma_period,
+        default_fast,
+        "Other symbols keep the default config"
+    );
+}
+
 #[tokio::test]
 async fn test_golden_cross() {
     setup_logging();
@@ -148,6 +414,8 @@ async fn test_golden_cross() {
         ema_fast_period: 50,
         ema_slow_period: 150,
         take_profit_pct: dec!(0.05),
+        take_profit_mode: Default::default(),
+        take_profit_atr_multiplier: dec!(3.0),
         min_hold_time_minutes: 0,
         signal_confirmation_bars: 1,
         spread_bps: dec!(0.0),
@@ -182,6 +450,33 @@ async fn test_golden_cross() {
         orderflow_volume_profile_lookback: 100,
         ensemble_weights: Default::default(),
         ensemble_voting_threshold: dec!(0.5),
+        trading_windows: Default::default(),
+        news_dedup_window_seconds: 300,
+        news_trade_size_multiplier: dec!(1.0),
+        news_trade_max_value_usd: None,
+        news_reentry_cooldown_seconds: 0,
+        regime_detection_method: Default::default(),
+        hurst_lookback: 50,
+        relative_stop_benchmark: None,
+        relative_stop_pct: dec!(0.05),
+        pyramid_max_adds: 0,
+        pyramid_trigger_pct: dec!(0.02),
+        exits_bypass_cost_filters: true,
+        allow_shorts: false,
+        require_htf_confirmation: false,
+        trend_timeframe: Timeframe::OneHour,
+        max_trades_per_symbol_per_day: 0,
+        open_blackout_minutes: 0,
+        trading_day_boundary: TradingDayBoundary::default(),
+        post_stop_cooldown_seconds: 0,
+        trailing_stop_vol_scale_min: Decimal::ONE,
+        trailing_stop_vol_scale_max: Decimal::ONE,
+        candle_history_len: 0,
+        candle_gap_policy: Default::default(),
+        candle_gap_max_bars: 60,
+        cold_start_reward_risk_ratio: dec!(1.0),
+        cold_start_min_trades: 10,
+        confirm_strategy: None,
     };
     let strategy = Arc::new(rustrade::application::strategies::DualSMAStrategy::new(
         config.fast_sma_period,
@@ -284,6 +579,8 @@ async fn test_prevent_short_selling() {
         ema_fast_period: 50,
         ema_slow_period: 150,
         take_profit_pct: dec!(0.05),
+        take_profit_mode: Default::default(),
+        take_profit_atr_multiplier: dec!(3.0),
         min_hold_time_minutes: 0,
         signal_confirmation_bars: 1,
         spread_bps: dec!(5.0),
@@ -317,6 +614,33 @@ async fn test_prevent_short_selling() {
         orderflow_volume_profile_lookback: 100,
         ensemble_weights: Default::default(),
         ensemble_voting_threshold: dec!(0.5),
+        trading_windows: Default::default(),
+        news_dedup_window_seconds: 300,
+        news_trade_size_multiplier: dec!(1.0),
+        news_trade_max_value_usd: None,
+        news_reentry_cooldown_seconds: 0,
+        regime_detection_method: Default::default(),
+        hurst_lookback: 50,
+        relative_stop_benchmark: None,
+        relative_stop_pct: dec!(0.05),
+        pyramid_max_adds: 0,
+        pyramid_trigger_pct: dec!(0.02),
+        exits_bypass_cost_filters: true,
+        allow_shorts: false,
+        require_htf_confirmation: false,
+        trend_timeframe: Timeframe::OneHour,
+        max_trades_per_symbol_per_day: 0,
+        open_blackout_minutes: 0,
+        trading_day_boundary: TradingDayBoundary::default(),
+        post_stop_cooldown_seconds: 0,
+        trailing_stop_vol_scale_min: Decimal::ONE,
+        trailing_stop_vol_scale_max: Decimal::ONE,
+        candle_history_len: 0,
+        candle_gap_policy: Default::default(),
+        candle_gap_max_bars: 60,
+        cold_start_reward_risk_ratio: dec!(1.0),
+        cold_start_min_trades: 10,
+        confirm_strategy: None,
     };
     let strategy = Arc::new(rustrade::application::strategies::DualSMAStrategy::new(
         config.fast_sma_period,
@@ -396,6 +720,7 @@ async fn test_sell_signal_with_position() {
         symbol: "BTC".to_string(),
         quantity: Decimal::from(10),
         average_price: Decimal::from(100),
+        lots: VecDeque::new(),
     };
     portfolio.positions.insert("BTC".to_string(), pos);
 
@@ -433,6 +758,8 @@ async fn test_sell_signal_with_position() {
         ema_fast_period: 50,
         ema_slow_period: 150,
         take_profit_pct: dec!(0.05),
+        take_profit_mode: Default::default(),
+        take_profit_atr_multiplier: dec!(3.0),
         min_hold_time_minutes: 0,
         signal_confirmation_bars: 1,
         spread_bps: dec!(0.0),
@@ -466,6 +793,33 @@ async fn test_sell_signal_with_position() {
         orderflow_volume_profile_lookback: 100,
         ensemble_weights: Default::default(),
         ensemble_voting_threshold: dec!(0.5),
+        trading_windows: Default::default(),
+        news_dedup_window_seconds: 300,
+        news_trade_size_multiplier: dec!(1.0),
+        news_trade_max_value_usd: None,
+        news_reentry_cooldown_seconds: 0,
+        regime_detection_method: Default::default(),
+        hurst_lookback: 50,
+        relative_stop_benchmark: None,
+        relative_stop_pct: dec!(0.05),
+        pyramid_max_adds: 0,
+        pyramid_trigger_pct: dec!(0.02),
+        exits_bypass_cost_filters: true,
+        allow_shorts: false,
+        require_htf_confirmation: false,
+        trend_timeframe: Timeframe::OneHour,
+        max_trades_per_symbol_per_day: 0,
+        open_blackout_minutes: 0,
+        trading_day_boundary: TradingDayBoundary::default(),
+        post_stop_cooldown_seconds: 0,
+        trailing_stop_vol_scale_min: Decimal::ONE,
+        trailing_stop_vol_scale_max: Decimal::ONE,
+        candle_history_len: 0,
+        candle_gap_policy: Default::default(),
+        candle_gap_max_bars: 60,
+        cold_start_reward_risk_ratio: dec!(1.0),
+        cold_start_min_trades: 10,
+        confirm_strategy: None,
     };
     let strategy = Arc::new(rustrade::application::strategies::DualSMAStrategy::new(
         config.fast_sma_period,
@@ -577,6 +931,8 @@ async fn test_dynamic_quantity_scaling() {
         ema_fast_period: 50,
         ema_slow_period: 150,
         take_profit_pct: dec!(0.05),
+        take_profit_mode: Default::default(),
+        take_profit_atr_multiplier: dec!(3.0),
         min_hold_time_minutes: 0,
         signal_confirmation_bars: 1,
         spread_bps: dec!(0.0),
@@ -610,6 +966,33 @@ async fn test_dynamic_quantity_scaling() {
         orderflow_volume_profile_lookback: 100,
         ensemble_weights: Default::default(),
         ensemble_voting_threshold: dec!(0.5),
+        trading_windows: Default::default(),
+        news_dedup_window_seconds: 300,
+        news_trade_size_multiplier: dec!(1.0),
+        news_trade_max_value_usd: None,
+        news_reentry_cooldown_seconds: 0,
+        regime_detection_method: Default::default(),
+        hurst_lookback: 50,
+        relative_stop_benchmark: None,
+        relative_stop_pct: dec!(0.05),
+        pyramid_max_adds: 0,
+        pyramid_trigger_pct: dec!(0.02),
+        exits_bypass_cost_filters: true,
+        allow_shorts: false,
+        require_htf_confirmation: false,
+        trend_timeframe: Timeframe::OneHour,
+        max_trades_per_symbol_per_day: 0,
+        open_blackout_minutes: 0,
+        trading_day_boundary: TradingDayBoundary::default(),
+        post_stop_cooldown_seconds: 0,
+        trailing_stop_vol_scale_min: Decimal::ONE,
+        trailing_stop_vol_scale_max: Decimal::ONE,
+        candle_history_len: 0,
+        candle_gap_policy: Default::default(),
+        candle_gap_max_bars: 60,
+        cold_start_reward_risk_ratio: dec!(1.0),
+        cold_start_min_trades: 10,
+        confirm_strategy: None,
     };
     let strategy = Arc::new(rustrade::application::strategies::DualSMAStrategy::new(
         config.fast_sma_period,
@@ -708,6 +1091,7 @@ async fn test_multi_symbol_isolation() {
             symbol: "ETH".to_string(),
             quantity: Decimal::from(10),
             average_price: Decimal::from(100),
+            lots: VecDeque::new(),
         },
     );
     let portfolio_lock = Arc::new(RwLock::new(portfolio));
@@ -746,6 +1130,8 @@ async fn test_multi_symbol_isolation() {
         ema_fast_period: 50,
         ema_slow_period: 150,
         take_profit_pct: dec!(0.05),
+        take_profit_mode: Default::default(),
+        take_profit_atr_multiplier: dec!(3.0),
         min_hold_time_minutes: 0,
         signal_confirmation_bars: 1,
         spread_bps: dec!(0.0),
@@ -779,6 +1165,33 @@ async fn test_multi_symbol_isolation() {
         orderflow_volume_profile_lookback: 100,
         ensemble_weights: Default::default(),
         ensemble_voting_threshold: dec!(0.5),
+        trading_windows: Default::default(),
+        news_dedup_window_seconds: 300,
+        news_trade_size_multiplier: dec!(1.0),
+        news_trade_max_value_usd: None,
+        news_reentry_cooldown_seconds: 0,
+        regime_detection_method: Default::default(),
+        hurst_lookback: 50,
+        relative_stop_benchmark: None,
+        relative_stop_pct: dec!(0.05),
+        pyramid_max_adds: 0,
+        pyramid_trigger_pct: dec!(0.02),
+        exits_bypass_cost_filters: true,
+        allow_shorts: false,
+        require_htf_confirmation: false,
+        trend_timeframe: Timeframe::OneHour,
+        max_trades_per_symbol_per_day: 0,
+        open_blackout_minutes: 0,
+        trading_day_boundary: TradingDayBoundary::default(),
+        post_stop_cooldown_seconds: 0,
+        trailing_stop_vol_scale_min: Decimal::ONE,
+        trailing_stop_vol_scale_max: Decimal::ONE,
+        candle_history_len: 0,
+        candle_gap_policy: Default::default(),
+        candle_gap_max_bars: 60,
+        cold_start_reward_risk_ratio: dec!(1.0),
+        cold_start_min_trades: 10,
+        confirm_strategy: None,
     };
     let strategy = Arc::new(rustrade::application::strategies::DualSMAStrategy::new(
         config.fast_sma_period,
@@ -908,6 +1321,8 @@ async fn test_advanced_strategy_trend_filter() {
         ema_fast_period: 50,
         ema_slow_period: 150,
         take_profit_pct: dec!(0.05),
+        take_profit_mode: Default::default(),
+        take_profit_atr_multiplier: dec!(3.0),
         min_hold_time_minutes: 0,
         signal_confirmation_bars: 1,
         spread_bps: dec!(5.0),
@@ -942,6 +1357,33 @@ async fn test_advanced_strategy_trend_filter() {
         orderflow_volume_profile_lookback: 100,
         ensemble_weights: Default::default(),
         ensemble_voting_threshold: dec!(0.5),
+        trading_windows: Default::default(),
+        news_dedup_window_seconds: 300,
+        news_trade_size_multiplier: dec!(1.0),
+        news_trade_max_value_usd: None,
+        news_reentry_cooldown_seconds: 0,
+        regime_detection_method: Default::default(),
+        hurst_lookback: 50,
+        relative_stop_benchmark: None,
+        relative_stop_pct: dec!(0.05),
+        pyramid_max_adds: 0,
+        pyramid_trigger_pct: dec!(0.02),
+        exits_bypass_cost_filters: true,
+        allow_shorts: false,
+        require_htf_confirmation: false,
+        trend_timeframe: Timeframe::OneHour,
+        max_trades_per_symbol_per_day: 0,
+        open_blackout_minutes: 0,
+        trading_day_boundary: TradingDayBoundary::default(),
+        post_stop_cooldown_seconds: 0,
+        trailing_stop_vol_scale_min: Decimal::ONE,
+        trailing_stop_vol_scale_max: Decimal::ONE,
+        candle_history_len: 0,
+        candle_gap_policy: Default::default(),
+        candle_gap_max_bars: 60,
+        cold_start_reward_risk_ratio: dec!(1.0),
+        cold_start_min_trades: 10,
+        confirm_strategy: None,
     };
     let strategy = Arc::new(rustrade::application::strategies::DualSMAStrategy::new(
         config.fast_sma_period,
@@ -1071,6 +1513,8 @@ async fn test_risk_based_quantity_calculation() {
         ema_fast_period: 50,
         ema_slow_period: 150,
         take_profit_pct: dec!(0.05),
+        take_profit_mode: Default::default(),
+        take_profit_atr_multiplier: dec!(3.0),
         min_hold_time_minutes: 0,
         signal_confirmation_bars: 1,
         spread_bps: dec!(0.0),
@@ -1103,6 +1547,33 @@ async fn test_risk_based_quantity_calculation() {
         orderflow_volume_profile_lookback: 100,
         ensemble_weights: Default::default(),
         ensemble_voting_threshold: dec!(0.5),
+        trading_windows: Default::default(),
+        news_dedup_window_seconds: 300,
+        news_trade_size_multiplier: dec!(1.0),
+        news_trade_max_value_usd: None,
+        news_reentry_cooldown_seconds: 0,
+        regime_detection_method: Default::default(),
+        hurst_lookback: 50,
+        relative_stop_benchmark: None,
+        relative_stop_pct: dec!(0.05),
+        pyramid_max_adds: 0,
+        pyramid_trigger_pct: dec!(0.02),
+        exits_bypass_cost_filters: true,
+        allow_shorts: false,
+        require_htf_confirmation: false,
+        trend_timeframe: Timeframe::OneHour,
+        max_trades_per_symbol_per_day: 0,
+        open_blackout_minutes: 0,
+        trading_day_boundary: TradingDayBoundary::default(),
+        post_stop_cooldown_seconds: 0,
+        trailing_stop_vol_scale_min: Decimal::ONE,
+        trailing_stop_vol_scale_max: Decimal::ONE,
+        candle_history_len: 0,
+        candle_gap_policy: Default::default(),
+        candle_gap_max_bars: 60,
+        cold_start_reward_risk_ratio: dec!(1.0),
+        cold_start_min_trades: 10,
+        confirm_strategy: None,
     };
 
     let strategy = Arc::new(rustrade::application::strategies::DualSMAStrategy::new(
@@ -1301,6 +1772,7 @@ async fn test_trailing_stop_suppresses_sell_signal() {
         symbol: "AAPL".to_string(),
         quantity: Decimal::from(10),
         average_price: Decimal::from(150),
+        lots: VecDeque::new(),
     };
     portfolio.positions.insert("AAPL".to_string(), pos);
 
@@ -1393,3 +1865,422 @@ async fn test_trailing_stop_suppresses_sell_signal() {
         }
     }
 }
+
+#[tokio::test]
+async fn test_stale_portfolio_blocks_entries() {
+    setup_logging();
+    let (market_tx, market_rx) = mpsc::channel(10);
+    let (_cmd_tx, cmd_rx) = mpsc::channel(10);
+    let (proposal_tx, mut proposal_rx) = mpsc::channel(10);
+
+    use rustrade::domain::trading::portfolio::Portfolio;
+    let mut portfolio = Portfolio::new();
+    portfolio.cash = Decimal::from(100000);
+    let exec_service = Arc::new(MockExecutionService::new(Arc::new(RwLock::new(portfolio))));
+
+    let config = AnalystConfig {
+        fast_sma_period: 2,
+        slow_sma_period: 3,
+        max_positions: 1,
+        trade_quantity: Decimal::from(1),
+        sma_threshold: dec!(0.0),
+        order_cooldown_seconds: 0,
+        risk_per_trade_percent: dec!(0.0),
+        strategy_mode: rustrade::domain::market::strategy_config::StrategyMode::Standard,
+        trend_sma_period: 100,
+        rsi_period: 14,
+        macd_fast_period: 12,
+        macd_slow_period: 26,
+        macd_signal_period: 9,
+        trend_divergence_threshold: dec!(0.005),
+        trailing_stop_atr_multiplier: dec!(3.0),
+        atr_period: 14,
+        rsi_threshold: dec!(99.0),
+        trend_riding_exit_buffer_pct: dec!(0.03),
+        mean_reversion_rsi_exit: dec!(50.0),
+        mean_reversion_bb_period: 20,
+        fee_model: Arc::new(rustrade::domain::trading::fee_model::ConstantFeeModel::new(
+            Decimal::ZERO,
+            Decimal::ZERO,
+        )),
+        max_position_size_pct: dec!(0.0),
+        bb_std_dev: dec!(2.0),
+        ema_fast_period: 50,
+        ema_slow_period: 150,
+        take_profit_pct: dec!(0.05),
+        take_profit_mode: Default::default(),
+        take_profit_atr_multiplier: dec!(3.0),
+        min_hold_time_minutes: 0,
+        signal_confirmation_bars: 1,
+        spread_bps: dec!(0.0),
+        min_profit_ratio: dec!(0.0),
+
+        macd_requires_rising: true,
+
+        trend_tolerance_pct: dec!(0.0),
+
+        macd_min_threshold: dec!(0.0),
+
+        profit_target_multiplier: dec!(1.5),
+        adx_period: 14,
+        adx_threshold: dec!(25.0),
+        smc_ob_lookback: 20,
+        smc_min_fvg_size_pct: dec!(0.005),
+        risk_appetite_score: None,
+        breakout_lookback: 10,
+        breakout_threshold_pct: dec!(0.002),
+        breakout_volume_mult: dec!(1.1),
+        max_loss_per_trade_pct: dec!(-0.05),
+        smc_volume_multiplier: dec!(1.5),
+        enable_ml_data_collection: false,
+        stat_momentum_lookback: 10,
+        stat_momentum_threshold: dec!(1.5),
+        stat_momentum_trend_confirmation: true,
+        zscore_lookback: 20,
+        zscore_entry_threshold: dec!(-2.0),
+        zscore_exit_threshold: dec!(0.0),
+        orderflow_ofi_threshold: dec!(0.3),
+        orderflow_stacked_count: 3,
+        orderflow_volume_profile_lookback: 100,
+        ensemble_weights: Default::default(),
+        ensemble_voting_threshold: dec!(0.5),
+        trading_windows: Default::default(),
+        news_dedup_window_seconds: 300,
+        news_trade_size_multiplier: dec!(1.0),
+        news_trade_max_value_usd: None,
+        news_reentry_cooldown_seconds: 0,
+        regime_detection_method: Default::default(),
+        hurst_lookback: 50,
+        relative_stop_benchmark: None,
+        relative_stop_pct: dec!(0.05),
+        pyramid_max_adds: 0,
+        pyramid_trigger_pct: dec!(0.02),
+        exits_bypass_cost_filters: true,
+        allow_shorts: false,
+        require_htf_confirmation: false,
+        trend_timeframe: Timeframe::OneHour,
+        max_trades_per_symbol_per_day: 0,
+        open_blackout_minutes: 0,
+        trading_day_boundary: TradingDayBoundary::default(),
+        post_stop_cooldown_seconds: 0,
+        trailing_stop_vol_scale_min: Decimal::ONE,
+        trailing_stop_vol_scale_max: Decimal::ONE,
+        candle_history_len: 0,
+        candle_gap_policy: Default::default(),
+        candle_gap_max_bars: 60,
+        cold_start_reward_risk_ratio: dec!(1.0),
+        cold_start_min_trades: 10,
+        confirm_strategy: None,
+    };
+    let strategy = Arc::new(rustrade::application::strategies::DualSMAStrategy::new(
+        config.fast_sma_period,
+        config.slow_sma_period,
+        config.sma_threshold,
+    ));
+    // Synced once, then never refreshed (1h staleness) while entries need a sync < 1ms old
+    let mut analyst = Analyst::new(
+        market_rx,
+        cmd_rx,
+        proposal_tx,
+        config,
+        strategy,
+        AnalystDependencies {
+            execution_service: exec_service,
+            market_service: Arc::new(MockMarketDataService::new()),
+            candle_repository: None,
+            strategy_repository: None,
+            win_rate_provider: None,
+            ui_candle_tx: None,
+            spread_cache: Arc::new(SpreadCache::new()),
+            connection_health_service: create_online_health_service().await,
+            agent_registry: Arc::new(
+                rustrade::application::monitoring::agent_status::AgentStatusRegistry::new(
+                    rustrade::infrastructure::observability::Metrics::new().unwrap(),
+                ),
+            ),
+        },
+    )
+    .with_portfolio_cache(3_600_000, 1);
+
+    tokio::spawn(async move {
+        analyst.run().await;
+    });
+
+    // Same golden cross as test_golden_cross
+    let prices = [100.0, 100.0, 100.0, 90.0, 110.0, 120.0];
+    for (i, p) in prices.iter().enumerate() {
+        let price = Decimal::from_f64_retain(*p).unwrap();
+        let candle = Candle {
+            symbol: "BTC".to_string(),
+            open: price,
+            high: price,
+            low: price,
+            close: price,
+            volume: Decimal::new(100, 0),
+            timestamp: BASE_TS + (i as i64) * 600000,
+        };
+        market_tx.send(MarketEvent::Candle(candle)).await.unwrap();
+        tokio::time::sleep(std::time::Duration::from_millis(5)).await;
+    }
+
+    let result =
+        tokio::time::timeout(std::time::Duration::from_millis(500), proposal_rx.recv()).await;
+    assert!(
+        result.is_err(),
+        "Buy must not be proposed on a stale portfolio"
+    );
+}
+
+#[test]
+fn test_apply_tuned_params_keeps_untuned_fields() {
+    let mut live = AnalystConfig {
+        fast_sma_period: 20,
+        slow_sma_period: 60,
+        max_positions: 3,
+        ..AnalystConfig::default()
+    };
+    let tuned = AnalystConfig {
+        fast_sma_period: 10,
+        slow_sma_period: 40,
+        max_positions: 9,
+        ..AnalystConfig::default()
+    };
+
+    live.apply_tuned_params(&tuned);
+
+    assert_eq!(live.fast_sma_period, 10);
+    assert_eq!(live.slow_sma_period, 40);
+    assert_eq!(live.max_positions, 3, "Untuned fields keep the live value");
+}
+
+#[test]
+fn test_strategy_change_ignores_unrelated_fields() {
+    let live = AnalystConfig::default();
+
+    let sizing_only = AnalystConfig {
+        max_positions: live.max_positions + 1,
+        ..live.clone()
+    };
+    assert!(!live.has_strategy_change(&sizing_only));
+
+    let threshold = AnalystConfig {
+        rsi_threshold: live.rsi_threshold + dec!(1),
+        ..live.clone()
+    };
+    assert!(live.has_strategy_change(&threshold));
+    assert!(!live.has_structural_change(&threshold));
+}
+
+#[tokio::test]
+async fn test_startup_recovers_entry_time_of_short_position() {
+    setup_logging();
+    let (_market_tx, market_rx) = mpsc::channel(10);
+    let (_cmd_tx, cmd_rx) = mpsc::channel(10);
+    let (proposal_tx, _proposal_rx) = mpsc::channel(10);
+
+    use rustrade::domain::trading::portfolio::{Portfolio, Position};
+    use rustrade::domain::trading::types::{Order, OrderStatus, OrderType};
+    let mut portfolio = Portfolio::new();
+    portfolio.positions.insert(
+        "BTC/USD".to_string(),
+        Position {
+            symbol: "BTC/USD".to_string(),
+            quantity: dec!(-2),
+            average_price: dec!(100),
+            lots: VecDeque::new(),
+        },
+    );
+    let exec_service = Arc::new(MockExecutionService::new(Arc::new(RwLock::new(portfolio))));
+
+    let order = |id: &str, side: OrderSide, timestamp: i64| Order {
+        id: id.to_string(),
+        symbol: "BTC/USD".to_string(),
+        side,
+        price: dec!(100),
+        quantity: dec!(2),
+        order_type: OrderType::Market,
+        status: OrderStatus::Filled,
+        timestamp,
+        reduce_only: false,
+        origin: Default::default(),
+        reason: None,
+    };
+    // The short was opened by the later sell; the earlier buy closed a prior long
+    exec_service
+        .set_today_orders(vec![
+            order("buy", OrderSide::Buy, BASE_TS + 60_000),
+            order("sell", OrderSide::Sell, BASE_TS),
+        ])
+        .await;
+
+    let config = AnalystConfig::default();
+    let strategy = rustrade::application::strategies::StrategyFactory::create(
+        rustrade::domain::market::strategy_config::StrategyMode::Advanced,
+        &config,
+    );
+    let mut analyst = Analyst::new(
+        market_rx,
+        cmd_rx,
+        proposal_tx,
+        config,
+        strategy,
+        AnalystDependencies {
+            execution_service: exec_service,
+            market_service: Arc::new(MockMarketDataService::new()),
+            candle_repository: None,
+            strategy_repository: None,
+            win_rate_provider: None,
+            ui_candle_tx: None,
+            spread_cache: Arc::new(SpreadCache::new()),
+            connection_health_service: create_online_health_service().await,
+            agent_registry: Arc::new(
+                rustrade::application::monitoring::agent_status::AgentStatusRegistry::new(
+                    rustrade::infrastructure::observability::Metrics::new().unwrap(),
+                ),
+            ),
+        },
+    );
+
+    analyst
+        .ensure_symbol_initialized("BTC/USD", chrono::Utc::now())
+        .await;
+
+    assert_eq!(
+        analyst.get_context("BTC/USD").unwrap().last_entry_time,
+        Some(BASE_TS),
+        "A short's entry time comes from its last filled sell"
+    );
+}
+
+/// Serves flat history after a delay, like a slow broker
+struct SlowHistoryMarketData;
+
+#[async_trait::async_trait]
+impl rustrade::domain::ports::MarketDataService for SlowHistoryMarketData {
+    async fn subscribe(
+        &self,
+        _symbols: Vec<String>,
+    ) -> anyhow::Result<mpsc::Receiver<MarketEvent>> {
+        Ok(mpsc::channel(1).1)
+    }
+    async fn get_top_movers(&self) -> anyhow::Result<Vec<String>> {
+        Ok(vec![])
+    }
+    async fn get_tradable_assets(&self) -> anyhow::Result<Vec<String>> {
+        Ok(vec![])
+    }
+    async fn get_prices(
+        &self,
+        _symbols: Vec<String>,
+    ) -> anyhow::Result<std::collections::HashMap<String, Decimal>> {
+        Ok(std::collections::HashMap::new())
+    }
+    async fn get_historical_bars(
+        &self,
+        symbol: &str,
+        _start: chrono::DateTime<chrono::Utc>,
+        _end: chrono::DateTime<chrono::Utc>,
+        _timeframe: &str,
+    ) -> anyhow::Result<Vec<Candle>> {
+        tokio::time::sleep(std::time::Duration::from_millis(300)).await;
+        Ok((1..=100)
+            .rev()
+            .map(|i| Candle {
+                symbol: symbol.to_string(),
+                open: dec!(100),
+                high: dec!(100),
+                low: dec!(100),
+                close: dec!(100),
+                volume: dec!(1),
+                timestamp: BASE_TS - i * 60_000,
+            })
+            .collect())
+    }
+}
+
+#[tokio::test]
+async fn test_rewarm_runs_in_background_while_candles_flow() {
+    setup_logging();
+    let (market_tx, market_rx) = mpsc::channel(10);
+    let (_cmd_tx, cmd_rx) = mpsc::channel(10);
+    let (proposal_tx, _proposal_rx) = mpsc::channel(10);
+
+    use rustrade::domain::trading::portfolio::Portfolio;
+    let exec_service = Arc::new(MockExecutionService::new(Arc::new(RwLock::new(
+        Portfolio::new(),
+    ))));
+    let config = AnalystConfig::default();
+    let strategy = rustrade::application::strategies::StrategyFactory::create(
+        rustrade::domain::market::strategy_config::StrategyMode::Advanced,
+        &config,
+    );
+    let mut analyst = Analyst::new(
+        market_rx,
+        cmd_rx,
+        proposal_tx,
+        config,
+        strategy,
+        AnalystDependencies {
+            execution_service: exec_service,
+            market_service: Arc::new(SlowHistoryMarketData),
+            candle_repository: None,
+            strategy_repository: None,
+            win_rate_provider: None,
+            ui_candle_tx: None,
+            spread_cache: Arc::new(SpreadCache::new()),
+            connection_health_service: create_online_health_service().await,
+            agent_registry: Arc::new(
+                rustrade::application::monitoring::agent_status::AgentStatusRegistry::new(
+                    rustrade::infrastructure::observability::Metrics::new().unwrap(),
+                ),
+            ),
+        },
+    );
+
+    let symbol = "BTC/USD".to_string();
+    analyst
+        .ensure_symbol_initialized(&symbol, chrono::Utc::now())
+        .await;
+    analyst.get_context_mut(&symbol).unwrap().rewarm_pending = true;
+
+    for i in 0..3 {
+        market_tx
+            .send(MarketEvent::Candle(Candle {
+                symbol: symbol.clone(),
+                open: dec!(100),
+                high: dec!(100),
+                low: dec!(100),
+                close: dec!(100),
+                volume: dec!(1),
+                timestamp: BASE_TS + i * 20_000,
+            }))
+            .await
+            .unwrap();
+    }
+
+    tokio::select! {
+        _ = analyst.run() => {},
+        _ = tokio::time::sleep(std::time::Duration::from_millis(100)) => {},
+    }
+    {
+        let context = analyst.get_context(&symbol).unwrap();
+        assert!(context.rewarm_in_flight, "History is still being fetched");
+        assert!(context.rewarm_pending, "Entries stay gated meanwhile");
+        assert_eq!(
+            context.candle_history.back().map(|c| c.timestamp),
+            Some(BASE_TS + 40_000),
+            "Candles are processed while the fetch runs"
+        );
+    }
+
+    tokio::select! {
+        _ = analyst.run() => {},
+        _ = tokio::time::sleep(std::time::Duration::from_millis(500)) => {},
+    }
+    let context = analyst.get_context(&symbol).unwrap();
+    assert!(!context.rewarm_in_flight);
+    assert!(
+        !context.rewarm_pending,
+        "Applied once the history came back"
+    );
+}
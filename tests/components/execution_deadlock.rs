@@ -79,6 +79,9 @@ async fn test_execution_timeouts_on_deadlock() {
         order_type: OrderType::Market,
         status: rustrade::domain::trading::types::OrderStatus::New,
         timestamp: 0,
+        reduce_only: false,
+        origin: Default::default(),
+        reason: None,
     };
 
     let start = std::time::Instant::now();
@@ -9,7 +9,7 @@ use rustrade::domain::risk::risk_config::RiskConfig;
 use rustrade::domain::trading::portfolio::{Portfolio, Position};
 use rustrade::domain::trading::types::{Candle, MarketEvent, Order};
 use rustrade::infrastructure::observability::Metrics;
-use std::collections::HashMap;
+use std::collections::{HashMap, VecDeque};
 use std::sync::Arc;
 use std::time::Duration;
 use tokio::sync::RwLock;
@@ -99,6 +99,7 @@ async fn test_circuit_breaker_skipped_on_missing_prices() {
             symbol: "AAPL".to_string(),
             quantity: dec!(10),
             average_price: dec!(100),
+            lots: VecDeque::new(),
         },
     );
     portfolio.synchronized = true;
@@ -8,6 +8,7 @@ use rustrade::domain::trading::portfolio::{Portfolio, Position};
 use rustrade::domain::trading::types::{OrderSide, OrderType, TradeProposal};
 use rustrade::infrastructure::mock::{MockExecutionService, MockMarketDataService};
 use rustrade::infrastructure::observability::Metrics;
+use std::collections::VecDeque;
 use std::sync::Arc;
 use tokio::sync::{RwLock, mpsc};
 
@@ -27,6 +28,7 @@ async fn test_consecutive_loss_triggers_circuit_breaker() {
             symbol: "AAPL".to_string(),
             quantity: dec!(10.0),
             average_price: dec!(100.0),
+            lots: VecDeque::new(),
         },
     );
     let portfolio = Arc::new(RwLock::new(portfolio));
@@ -47,12 +49,24 @@ async fn test_consecutive_loss_triggers_circuit_breaker() {
         max_position_size_pct: dec!(1.0),
         valuation_interval_seconds: 1,
         max_sector_exposure_pct: dec!(1.0),
+        max_gross_exposure_pct: dec!(0),
         sector_provider: None,
         allow_pdt_risk: false,
         pending_order_ttl_ms: None,
         correlation_config:
             rustrade::domain::risk::filters::correlation_filter::CorrelationFilterConfig::default(),
         volatility_config: rustrade::domain::risk::volatility_manager::VolatilityConfig::default(),
+        daily_reset: None,
+        allow_average_down: false,
+        max_average_down_loss_pct: dec!(0.02),
+        min_order_notional: dec!(0),
+        allow_min_notional_bump: false,
+        allow_shorts: false,
+        leverage: dec!(1),
+        max_open_orders: 0,
+        earnings_provider: None,
+        earnings_blackout_days: 0,
+        earnings_flatten: false,
     };
 
     let state_manager = Arc::new(PortfolioStateManager::new(mock_exec.clone(), 5000));
@@ -123,6 +137,8 @@ async fn test_consecutive_loss_triggers_circuit_breaker() {
             timestamp: chrono::Utc::now().timestamp_millis(),
             stop_loss: None,
             take_profit: None,
+            reduce_only: false,
+            origin: Default::default(),
         };
 
         proposal_tx.send(proposal).await.unwrap();
@@ -155,6 +171,8 @@ async fn test_consecutive_loss_triggers_circuit_breaker() {
         timestamp: chrono::Utc::now().timestamp_millis(),
         stop_loss: None,
         take_profit: None,
+        reduce_only: false,
+        origin: Default::default(),
     };
 
     proposal_tx.send(proposal).await.unwrap();
@@ -205,6 +223,7 @@ async fn test_pending_order_ttl_cleanup() {
         max_daily_loss_pct: dec!(0.50), // Allow 50% loss
         max_drawdown_pct: dec!(0.50),   // Allow 50% drawdown
         max_sector_exposure_pct: dec!(1.0),
+        max_gross_exposure_pct: dec!(0),
         allow_pdt_risk: true, // Allow PDT risk
         ..RiskConfig::default()
     };
@@ -266,6 +285,8 @@ async fn test_pending_order_ttl_cleanup() {
         timestamp: chrono::Utc::now().timestamp_millis(),
         stop_loss: None,
         take_profit: None,
+        reduce_only: false,
+        origin: Default::default(),
     };
     proposal_tx.send(proposal).await.unwrap();
 
@@ -6,6 +6,7 @@ use rustrade::domain::trading::portfolio::{Portfolio, Position};
 use rustrade::domain::trading::types::{OrderSide, TradeProposal};
 use rustrade::infrastructure::mock::{MockExecutionService, MockMarketDataService};
 use rustrade::infrastructure::observability::Metrics;
+use std::collections::VecDeque;
 use std::sync::Arc;
 use tokio::sync::{RwLock, mpsc};
 use tracing::{Level, info};
@@ -30,6 +31,7 @@ async fn test_circuit_breaker_triggers_on_crash() {
             symbol: "TSLA".to_string(),
             quantity: dec!(100.0),       // 100 shares
             average_price: dec!(1000.0), // @ $1000 = $100,000 value
+            lots: VecDeque::new(),
         },
     );
     let execution_service = Arc::new(MockExecutionService::new(Arc::new(RwLock::new(portfolio))));
@@ -50,11 +52,23 @@ async fn test_circuit_breaker_triggers_on_crash() {
         consecutive_loss_limit: 5,
         valuation_interval_seconds: 1, // Fast tick for test
         max_sector_exposure_pct: dec!(1.0),
+        max_gross_exposure_pct: dec!(0),
         sector_provider: None,
         allow_pdt_risk: false,
         correlation_config:
             rustrade::domain::risk::filters::correlation_filter::CorrelationFilterConfig::default(),
         volatility_config: rustrade::domain::risk::volatility_manager::VolatilityConfig::default(),
+        daily_reset: None,
+        allow_average_down: false,
+        max_average_down_loss_pct: dec!(0.02),
+        min_order_notional: dec!(0),
+        allow_min_notional_bump: false,
+        allow_shorts: false,
+        leverage: dec!(1),
+        max_open_orders: 0,
+        earnings_provider: None,
+        earnings_blackout_days: 0,
+        earnings_flatten: false,
     };
 
     let (_, dummy_cmd_rx) = tokio::sync::mpsc::channel(1);
@@ -140,6 +154,8 @@ async fn test_circuit_breaker_triggers_on_crash() {
         timestamp: chrono::Utc::now().timestamp_millis(),
         stop_loss: None,
         take_profit: None,
+        reduce_only: false,
+        origin: Default::default(),
     };
 
     proposal_tx.send(proposal).await.unwrap();
@@ -38,12 +38,24 @@ async fn test_concurrent_proposals_respect_limits() {
         consecutive_loss_limit: 3,
         valuation_interval_seconds: 60,
         max_sector_exposure_pct: dec!(0.30),
+        max_gross_exposure_pct: Decimal::ZERO,
         sector_provider: None,
         allow_pdt_risk: false,
         pending_order_ttl_ms: None,
         correlation_config:
             rustrade::domain::risk::filters::correlation_filter::CorrelationFilterConfig::default(),
         volatility_config: rustrade::domain::risk::volatility_manager::VolatilityConfig::default(),
+        daily_reset: None,
+        allow_average_down: false,
+        max_average_down_loss_pct: dec!(0.02),
+        min_order_notional: Decimal::ZERO,
+        allow_min_notional_bump: false,
+        allow_shorts: false,
+        leverage: dec!(1),
+        max_open_orders: 0,
+        earnings_provider: None,
+        earnings_blackout_days: 0,
+        earnings_flatten: false,
     };
 
     let state_manager = Arc::new(PortfolioStateManager::new(
@@ -118,6 +130,8 @@ async fn test_concurrent_proposals_respect_limits() {
                 timestamp: chrono::Utc::now().timestamp_millis(),
                 stop_loss: None,
                 take_profit: None,
+                reduce_only: false,
+                origin: Default::default(),
             };
 
             tx.send(proposal).await.ok();
@@ -239,6 +253,8 @@ async fn test_backpressure_drops_excess_proposals() {
             timestamp: chrono::Utc::now().timestamp_millis(),
             stop_loss: None,
             take_profit: None,
+            reduce_only: false,
+            origin: Default::default(),
         };
 
         match proposal_tx.try_send(proposal) {
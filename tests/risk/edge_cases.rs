@@ -39,6 +39,7 @@ async fn test_pdt_protection_boundary() {
         )
         .await;
 
+    let metrics = Metrics::default();
     let mut risk_manager = RiskManager::new(
         proposal_rx,
         dummy_cmd_rx,
@@ -55,7 +56,7 @@ async fn test_pdt_protection_boundary() {
         None,
         Arc::new(SpreadCache::new()),
         health_service,
-        Metrics::default(),
+        metrics.clone(),
         Arc::new(
             rustrade::application::monitoring::agent_status::AgentStatusRegistry::new(
                 rustrade::infrastructure::observability::Metrics::new().unwrap(),
@@ -82,6 +83,8 @@ async fn test_pdt_protection_boundary() {
         timestamp: chrono::Utc::now().timestamp_millis(),
         stop_loss: None,
         take_profit: None,
+        reduce_only: false,
+        origin: Default::default(),
     };
 
     proposal_tx.send(proposal).await.unwrap();
@@ -92,6 +95,13 @@ async fn test_pdt_protection_boundary() {
         timeout.is_err() || timeout.unwrap().is_none(),
         "Order should be rejected due to PDT rule (< $25k)"
     );
+    assert_eq!(
+        metrics
+            .rejections_total
+            .with_label_values(&["risk", "PdtValidator"])
+            .get(),
+        1.0
+    );
 
     // 4. Update Portfolio > $25k
     {
@@ -113,6 +123,8 @@ async fn test_pdt_protection_boundary() {
         timestamp: chrono::Utc::now().timestamp_millis(),
         stop_loss: None,
         take_profit: None,
+        reduce_only: false,
+        origin: Default::default(),
     };
     proposal_tx.send(proposal2).await.unwrap();
 
@@ -204,6 +216,8 @@ async fn test_max_daily_loss_prevents_trading() {
         timestamp: chrono::Utc::now().timestamp_millis(),
         stop_loss: None,
         take_profit: None,
+        reduce_only: false,
+        origin: Default::default(),
     };
 
     proposal_tx.send(proposal).await.unwrap();
@@ -293,6 +307,8 @@ async fn test_circuit_breaker_on_drawdown() {
         timestamp: chrono::Utc::now().timestamp_millis(),
         stop_loss: None,
         take_profit: None,
+        reduce_only: false,
+        origin: Default::default(),
     };
     proposal_tx.send(proposal).await.unwrap();
 
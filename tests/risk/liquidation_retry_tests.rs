@@ -7,7 +7,7 @@ use rustrade::application::risk_management::liquidation_service::LiquidationServ
 use rustrade::domain::ports::{ExecutionService, MarketDataService, OrderUpdate};
 use rustrade::domain::trading::portfolio::{Portfolio, Position};
 use rustrade::domain::trading::types::{Candle, Order, OrderType};
-use std::collections::HashMap;
+use std::collections::{HashMap, VecDeque};
 use std::sync::Arc;
 use tokio::sync::{RwLock, mpsc};
 
@@ -90,6 +90,7 @@ async fn test_smart_liquidation_uses_limit_when_spread_available() {
             symbol: "BTC/USD".to_string(),
             quantity: Decimal::from(1),
             average_price: Decimal::from(50000),
+            lots: VecDeque::new(),
         },
     );
 
@@ -145,6 +146,7 @@ async fn test_smart_liquidation_falls_back_to_market_when_no_spread() {
             symbol: "ETH/USD".to_string(),
             quantity: Decimal::from(10),
             average_price: Decimal::from(3000),
+            lots: VecDeque::new(),
         },
     );
 
@@ -197,6 +199,7 @@ async fn test_panic_mode_forces_market_order() {
             symbol: "SOL/USD".to_string(),
             quantity: Decimal::from(100),
             average_price: Decimal::from(20),
+            lots: VecDeque::new(),
         },
     );
 
@@ -7,10 +7,11 @@ use rustrade::application::monitoring::portfolio_state_manager::PortfolioStateMa
 use rustrade::application::risk_management::commands::RiskCommand;
 use rustrade::application::risk_management::risk_manager::RiskManager;
 use rustrade::config::AssetClass;
-use rustrade::domain::ports::{ExecutionService, MarketDataService, SectorProvider};
+use rustrade::domain::ports::{ExecutionService, MarketDataService, OrderUpdate, SectorProvider};
 use rustrade::domain::risk::filters::correlation_filter::CorrelationFilterConfig;
 use rustrade::domain::risk::risk_config::RiskConfig;
 use rustrade::domain::sentiment::{Sentiment, SentimentClassification};
+use rustrade::domain::trading::lot_size::LotSize;
 use rustrade::domain::trading::portfolio::{Portfolio, Position};
 use rustrade::domain::trading::types::{
     Candle, MarketEvent, Order, OrderSide, OrderType, TradeProposal,
@@ -23,7 +24,7 @@ use rust_decimal::Decimal;
 use rust_decimal::prelude::FromPrimitive;
 use rust_decimal_macros::dec;
 use rustrade::application::monitoring::connection_health_service::ConnectionStatus;
-use std::collections::HashMap;
+use std::collections::{HashMap, VecDeque};
 use std::sync::{Arc, Mutex};
 use tokio::sync::{RwLock, mpsc};
 use tracing::info;
@@ -102,6 +103,39 @@ impl SectorProvider for MockSectorProvider {
     }
 }
 
+/// Mock broker whose open-order lookup takes a while, so each entry review is slow
+struct SlowOpenOrdersExecution {
+    inner: Arc<MockExecutionService>,
+}
+
+#[async_trait::async_trait]
+impl ExecutionService for SlowOpenOrdersExecution {
+    async fn execute(&self, order: Order) -> Result<(), anyhow::Error> {
+        self.inner.execute(order).await
+    }
+    async fn get_portfolio(&self) -> Result<Portfolio, anyhow::Error> {
+        self.inner.get_portfolio().await
+    }
+    async fn get_today_orders(&self) -> Result<Vec<Order>, anyhow::Error> {
+        self.inner.get_today_orders().await
+    }
+    async fn get_open_orders(&self) -> Result<Vec<Order>, anyhow::Error> {
+        tokio::time::sleep(std::time::Duration::from_millis(2)).await;
+        self.inner.get_open_orders().await
+    }
+    async fn cancel_order(&self, order_id: &str, symbol: &str) -> Result<(), anyhow::Error> {
+        self.inner.cancel_order(order_id, symbol).await
+    }
+    async fn cancel_all_orders(&self) -> Result<(), anyhow::Error> {
+        self.inner.cancel_all_orders().await
+    }
+    async fn subscribe_order_updates(
+        &self,
+    ) -> Result<tokio::sync::broadcast::Receiver<OrderUpdate>, anyhow::Error> {
+        self.inner.subscribe_order_updates().await
+    }
+}
+
 // ============================================================================
 // TESTS
 // ============================================================================
@@ -120,6 +154,7 @@ async fn test_circuit_breaker_on_market_crash() {
             symbol: "TSLA".to_string(),
             quantity: Decimal::from(100),
             average_price: Decimal::from(100),
+            lots: VecDeque::new(),
         },
     );
     let portfolio = Arc::new(RwLock::new(port));
@@ -185,6 +220,8 @@ async fn test_circuit_breaker_on_market_crash() {
         timestamp: Utc::now().timestamp_millis(),
         stop_loss: None,
         take_profit: None,
+        reduce_only: false,
+        origin: Default::default(),
     };
     proposal_tx.send(proposal).await.unwrap();
 
@@ -260,6 +297,8 @@ async fn test_buy_approval() {
         timestamp: Utc::now().timestamp_millis(),
         stop_loss: None,
         take_profit: None,
+        reduce_only: false,
+        origin: Default::default(),
     };
     proposal_tx.send(proposal).await.unwrap();
 
@@ -319,6 +358,8 @@ async fn test_buy_rejection_insufficient_funds() {
         timestamp: Utc::now().timestamp_millis(),
         stop_loss: None,
         take_profit: None,
+        reduce_only: false,
+        origin: Default::default(),
     };
     proposal_tx.send(proposal).await.unwrap();
 
@@ -340,6 +381,7 @@ async fn test_buy_rejection_insufficient_buying_power_high_equity() {
             symbol: "AAPL".to_string(),
             quantity: Decimal::from(1000),
             average_price: Decimal::from(100),
+            lots: VecDeque::new(),
         },
     );
     let portfolio = Arc::new(RwLock::new(port));
@@ -394,6 +436,8 @@ async fn test_buy_rejection_insufficient_buying_power_high_equity() {
         timestamp: Utc::now().timestamp_millis(),
         stop_loss: None,
         take_profit: None,
+        reduce_only: false,
+        origin: Default::default(),
     };
     proposal_tx.send(proposal).await.unwrap();
 
@@ -418,6 +462,7 @@ async fn test_sell_approval() {
             symbol: "ABC".to_string(),
             quantity: Decimal::from(10), // Own 10
             average_price: Decimal::from(50),
+            lots: VecDeque::new(),
         },
     );
     let portfolio = Arc::new(RwLock::new(port));
@@ -468,6 +513,8 @@ async fn test_sell_approval() {
         timestamp: Utc::now().timestamp_millis(),
         stop_loss: None,
         take_profit: None,
+        reduce_only: false,
+        origin: Default::default(),
     };
     proposal_tx.send(proposal).await.unwrap();
 
@@ -478,6 +525,91 @@ async fn test_sell_approval() {
     assert_eq!(order.symbol, "ABC");
 }
 
+#[tokio::test]
+async fn test_same_candle_exits_get_distinct_order_ids() {
+    let (proposal_tx, proposal_rx) = mpsc::channel(2);
+    let (order_tx, mut order_rx) = mpsc::channel(2);
+    let mut port = Portfolio::new();
+    port.positions.insert(
+        "ABC".to_string(),
+        Position {
+            symbol: "ABC".to_string(),
+            quantity: Decimal::from(10),
+            average_price: Decimal::from(50),
+            lots: VecDeque::new(),
+        },
+    );
+    let portfolio = Arc::new(RwLock::new(port));
+    let exec_service = Arc::new(MockExecutionService::new(portfolio.clone()));
+    let market_service = Arc::new(MockMarketDataService::new());
+    let state_manager = Arc::new(PortfolioStateManager::new(exec_service.clone(), 5000));
+    let (_, dummy_cmd_rx) = mpsc::channel(1);
+    let connection_service = Arc::new(ConnectionHealthService::new());
+    connection_service
+        .set_market_data_status(ConnectionStatus::Online, None)
+        .await;
+
+    let mut rm = RiskManager::new(
+        proposal_rx,
+        dummy_cmd_rx,
+        order_tx,
+        exec_service,
+        market_service,
+        state_manager,
+        false,
+        AssetClass::Stock,
+        RiskConfig::default(),
+        None,
+        None,
+        None,
+        None,
+        Arc::new(SpreadCache::new()),
+        connection_service,
+        Metrics::default(),
+        Arc::new(
+            rustrade::application::monitoring::agent_status::AgentStatusRegistry::new(
+                rustrade::infrastructure::observability::Metrics::new().unwrap(),
+            ),
+        ),
+    )
+    .expect("Test config should be valid");
+    tokio::spawn(async move { rm.run().await });
+
+    // A stop-loss and a partial take-profit triggered by the same candle
+    let candle_ts = Utc::now().timestamp_millis();
+    for (reason, quantity) in [("Stop loss", 4), ("Partial take profit", 3)] {
+        proposal_tx
+            .send(TradeProposal {
+                symbol: "ABC".to_string(),
+                side: OrderSide::Sell,
+                price: Decimal::from(100),
+                quantity: Decimal::from(quantity),
+                order_type: OrderType::Market,
+                reason: reason.to_string(),
+                timestamp: candle_ts,
+                stop_loss: None,
+                take_profit: None,
+                reduce_only: true,
+                origin: Default::default(),
+            })
+            .await
+            .unwrap();
+    }
+
+    let mut ids = Vec::new();
+    for _ in 0..2 {
+        let order = tokio::time::timeout(std::time::Duration::from_millis(2500), order_rx.recv())
+            .await
+            .expect("Should not timeout")
+            .expect("Should approve");
+        ids.push(order.id);
+    }
+    assert_ne!(
+        ids[0], ids[1],
+        "Same-candle exits must not share a client order id"
+    );
+}
+
 #[tokio::test]
 async fn test_pdt_protection_rejection() {
     let (_proposal_tx, proposal_rx) = mpsc::channel(1);
@@ -491,6 +623,7 @@ async fn test_pdt_protection_rejection() {
             symbol: "ABC".to_string(),
             quantity: Decimal::from(10),
             average_price: Decimal::from(50),
+            lots: VecDeque::new(),
         },
     );
     let portfolio = Arc::new(RwLock::new(port));
@@ -507,6 +640,9 @@ async fn test_pdt_protection_rejection() {
             order_type: OrderType::Limit,
             status: rustrade::domain::trading::types::OrderStatus::Filled,
             timestamp: Utc::now().timestamp_millis(),
+            reduce_only: false,
+            origin: Default::default(),
+            reason: None,
         })
         .await
         .unwrap();
@@ -556,6 +692,8 @@ async fn test_pdt_protection_rejection() {
         timestamp: Utc::now().timestamp_millis(),
         stop_loss: None,
         take_profit: None,
+        reduce_only: false,
+        origin: Default::default(),
     };
 
     // Handle command directly (via Command Pattern!)
@@ -584,6 +722,7 @@ async fn test_sector_exposure_limit() {
             symbol: "AAPL".to_string(),
             quantity: Decimal::from(100),
             average_price: Decimal::from(250),
+            lots: VecDeque::new(),
         },
     );
     let portfolio = Arc::new(RwLock::new(port));
@@ -646,6 +785,8 @@ async fn test_sector_exposure_limit() {
         order_type: OrderType::Market,
         stop_loss: None,
         take_profit: None,
+        reduce_only: false,
+        origin: Default::default(),
     };
     proposal_tx.send(proposal).await.unwrap();
 
@@ -671,6 +812,7 @@ async fn test_circuit_breaker_triggers_liquidation() {
             symbol: "TSLA".to_string(),
             quantity: Decimal::from(10),
             average_price: Decimal::from(1000),
+            lots: VecDeque::new(),
         },
     );
     let portfolio = Arc::new(RwLock::new(port));
@@ -734,6 +876,8 @@ async fn test_circuit_breaker_triggers_liquidation() {
         timestamp: Utc::now().timestamp_millis(),
         stop_loss: None,
         take_profit: None,
+        reduce_only: false,
+        origin: Default::default(),
     };
     proposal_tx.send(proposal).await.unwrap();
 
@@ -760,6 +904,8 @@ async fn test_circuit_breaker_triggers_liquidation() {
         timestamp: Utc::now().timestamp_millis(),
         stop_loss: None,
         take_profit: None,
+        reduce_only: false,
+        origin: Default::default(),
     };
     proposal_tx.send(proposal2).await.unwrap();
 
@@ -925,6 +1071,8 @@ async fn test_sentiment_risk_adjustment() {
         timestamp: Utc::now().timestamp_millis(),
         stop_loss: None,
         take_profit: None,
+        reduce_only: false,
+        origin: Default::default(),
     };
     proposal_tx.send(proposal).await.unwrap();
 
@@ -959,6 +1107,8 @@ async fn test_sentiment_risk_adjustment() {
         timestamp: Utc::now().timestamp_millis(),
         stop_loss: None,
         take_profit: None,
+        reduce_only: false,
+        origin: Default::default(),
     };
     proposal_tx.send(proposal2).await.unwrap();
 
@@ -998,6 +1148,7 @@ async fn test_blind_liquidation_panic_mode() {
                 symbol: "BTC".to_string(),
                 quantity: Decimal::from(10),
                 average_price: Decimal::from(100),
+                lots: VecDeque::new(),
             },
         );
     }
@@ -1049,3 +1200,751 @@ async fn test_blind_liquidation_panic_mode() {
         "Must be Market order in panic mode"
     );
 }
+
+#[tokio::test]
+async fn test_pause_rejects_buys_until_resumed() {
+    let (proposal_tx, proposal_rx) = mpsc::channel(1);
+    let (risk_cmd_tx, risk_cmd_rx) = mpsc::channel(1);
+    let (order_tx, mut order_rx) = mpsc::channel(1);
+    let mut port = Portfolio::new();
+    port.cash = Decimal::from(1000);
+    let portfolio = Arc::new(RwLock::new(port));
+    let exec_service = Arc::new(MockExecutionService::new(portfolio.clone()));
+    let market_service = Arc::new(MockMarketDataService::new());
+
+    let state_manager = Arc::new(PortfolioStateManager::new(exec_service.clone(), 5000));
+
+    let connection_service = Arc::new(ConnectionHealthService::new());
+    connection_service
+        .set_market_data_status(ConnectionStatus::Online, None)
+        .await;
+
+    let mut rm = RiskManager::new(
+        proposal_rx,
+        risk_cmd_rx,
+        order_tx,
+        exec_service,
+        market_service,
+        state_manager,
+        false,
+        AssetClass::Stock,
+        RiskConfig::default(),
+        None,
+        None,
+        None,
+        None,
+        Arc::new(SpreadCache::new()),
+        connection_service,
+        Metrics::default(),
+        Arc::new(
+            rustrade::application::monitoring::agent_status::AgentStatusRegistry::new(
+                rustrade::infrastructure::observability::Metrics::new().unwrap(),
+            ),
+        ),
+    )
+    .expect("Test config should be valid");
+    tokio::spawn(async move { rm.run().await });
+
+    let buy = || TradeProposal {
+        symbol: "ABC".to_string(),
+        side: OrderSide::Buy,
+        price: Decimal::from(100),
+        quantity: Decimal::from(1),
+        order_type: OrderType::Market,
+        reason: "Test Pause".to_string(),
+        timestamp: Utc::now().timestamp_millis(),
+        stop_loss: None,
+        take_profit: None,
+        reduce_only: false,
+        origin: Default::default(),
+    };
+
+    // 1. Paused: buy is rejected
+    risk_cmd_tx.send(RiskCommand::Pause).await.unwrap();
+    tokio::time::sleep(std::time::Duration::from_millis(50)).await;
+    proposal_tx.send(buy()).await.unwrap();
+    tokio::time::sleep(std::time::Duration::from_millis(100)).await;
+    assert!(
+        order_rx.try_recv().is_err(),
+        "Buy should be rejected while trading is paused"
+    );
+
+    // 2. Resumed: buy goes through
+    risk_cmd_tx.send(RiskCommand::Resume).await.unwrap();
+    tokio::time::sleep(std::time::Duration::from_millis(50)).await;
+    proposal_tx.send(buy()).await.unwrap();
+    let order = tokio::time::timeout(std::time::Duration::from_millis(2500), order_rx.recv())
+        .await
+        .expect("Should not timeout")
+        .expect("Should approve after resume");
+    assert_eq!(order.symbol, "ABC");
+}
+
+#[tokio::test]
+async fn test_full_halt_still_lets_manual_close_through() {
+    let (proposal_tx, proposal_rx) = mpsc::channel(1);
+    let (risk_cmd_tx, risk_cmd_rx) = mpsc::channel(1);
+    let (order_tx, mut order_rx) = mpsc::channel(1);
+    let mut port = Portfolio::new();
+    port.cash = Decimal::from(1000);
+    port.positions.insert(
+        "ABC".to_string(),
+        Position {
+            symbol: "ABC".to_string(),
+            quantity: Decimal::from(2),
+            average_price: Decimal::from(100),
+            lots: VecDeque::new(),
+        },
+    );
+    let portfolio = Arc::new(RwLock::new(port));
+    let exec_service = Arc::new(MockExecutionService::new(portfolio.clone()));
+    let market_service = Arc::new(MockMarketDataService::new());
+
+    let state_manager = Arc::new(PortfolioStateManager::new(exec_service.clone(), 5000));
+
+    let connection_service = Arc::new(ConnectionHealthService::new());
+    connection_service
+        .set_market_data_status(ConnectionStatus::Online, None)
+        .await;
+
+    let mut rm = RiskManager::new(
+        proposal_rx,
+        risk_cmd_rx,
+        order_tx,
+        exec_service,
+        market_service,
+        state_manager,
+        false,
+        AssetClass::Stock,
+        RiskConfig::default(),
+        None,
+        None,
+        None,
+        None,
+        Arc::new(SpreadCache::new()),
+        connection_service,
+        Metrics::default(),
+        Arc::new(
+            rustrade::application::monitoring::agent_status::AgentStatusRegistry::new(
+                rustrade::infrastructure::observability::Metrics::new().unwrap(),
+            ),
+        ),
+    )
+    .expect("Test config should be valid");
+    tokio::spawn(async move { rm.run().await });
+    tokio::time::sleep(std::time::Duration::from_millis(50)).await;
+
+    let proposal = |side: OrderSide, reduce_only: bool| TradeProposal {
+        symbol: "ABC".to_string(),
+        side,
+        price: Decimal::from(100),
+        quantity: Decimal::from(2),
+        order_type: OrderType::Market,
+        reason: "Manual Close".to_string(),
+        timestamp: Utc::now().timestamp_millis(),
+        stop_loss: None,
+        take_profit: None,
+        reduce_only,
+        origin: Default::default(),
+    };
+
+    // FullHalt; drain the panic liquidation order
+    risk_cmd_tx
+        .send(RiskCommand::CircuitBreakerTrigger)
+        .await
+        .unwrap();
+    tokio::time::timeout(std::time::Duration::from_millis(2500), order_rx.recv())
+        .await
+        .expect("Should not timeout")
+        .expect("Should receive liquidation order");
+
+    // 1. Entries stay blocked
+    proposal_tx
+        .send(proposal(OrderSide::Buy, false))
+        .await
+        .unwrap();
+    tokio::time::sleep(std::time::Duration::from_millis(100)).await;
+    assert!(
+        order_rx.try_recv().is_err(),
+        "Buy should be rejected while trading is halted"
+    );
+
+    // 2. A full close is forwarded unscaled
+    proposal_tx
+        .send(proposal(OrderSide::Sell, true))
+        .await
+        .unwrap();
+    let order = tokio::time::timeout(std::time::Duration::from_millis(2500), order_rx.recv())
+        .await
+        .expect("Should not timeout")
+        .expect("Manual close must not be blocked by the halt");
+    assert_eq!(order.side, OrderSide::Sell);
+    assert_eq!(order.quantity, Decimal::from(2));
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+async fn test_proposal_flood_does_not_starve_commands() {
+    let (proposal_tx, proposal_rx) = mpsc::channel(8);
+    let (risk_cmd_tx, risk_cmd_rx) = mpsc::channel(1);
+    let (order_tx, mut order_rx) = mpsc::channel(10);
+    let mut port = Portfolio::new();
+    port.cash = Decimal::from(10000);
+    port.positions.insert(
+        "TSLA".to_string(),
+        Position {
+            symbol: "TSLA".to_string(),
+            quantity: Decimal::from(10),
+            average_price: Decimal::from(1000),
+            lots: VecDeque::new(),
+        },
+    );
+    let portfolio = Arc::new(RwLock::new(port));
+    let exec_service = Arc::new(SlowOpenOrdersExecution {
+        inner: Arc::new(MockExecutionService::new(portfolio.clone())),
+    });
+    let market_data = Arc::new(ConfigurableMockMarketData::new());
+    market_data.set_price("TSLA", Decimal::from(1000));
+    let state_manager = Arc::new(PortfolioStateManager::new(exec_service.clone(), 5000));
+
+    let mut rm = RiskManager::new(
+        proposal_rx,
+        risk_cmd_rx,
+        order_tx,
+        exec_service,
+        market_data,
+        state_manager,
+        false,
+        AssetClass::Stock,
+        RiskConfig {
+            max_open_orders: 5,
+            ..RiskConfig::default()
+        },
+        None,
+        None,
+        None,
+        None,
+        Arc::new(SpreadCache::new()),
+        Arc::new(ConnectionHealthService::new()),
+        Metrics::default(),
+        Arc::new(
+            rustrade::application::monitoring::agent_status::AgentStatusRegistry::new(
+                rustrade::infrastructure::observability::Metrics::new().unwrap(),
+            ),
+        ),
+    )
+    .expect("Test config should be valid");
+    tokio::spawn(async move { rm.run().await });
+    tokio::time::sleep(std::time::Duration::from_millis(100)).await;
+
+    // Keep the proposal channel full of sells that are rejected (nothing held)
+    let flood = tokio::spawn(async move {
+        loop {
+            let proposal = TradeProposal {
+                symbol: "XYZ".to_string(),
+                side: OrderSide::Sell,
+                price: Decimal::from(10),
+                quantity: Decimal::from(1),
+                order_type: OrderType::Market,
+                reason: "Flood".to_string(),
+                timestamp: Utc::now().timestamp_millis(),
+                stop_loss: None,
+                take_profit: None,
+                reduce_only: false,
+                origin: Default::default(),
+            };
+            if proposal_tx.send(proposal).await.is_err() {
+                break;
+            }
+        }
+    });
+    tokio::time::sleep(std::time::Duration::from_millis(50)).await;
+
+    risk_cmd_tx
+        .send(RiskCommand::CircuitBreakerTrigger)
+        .await
+        .unwrap();
+    let order = tokio::time::timeout(std::time::Duration::from_millis(2500), order_rx.recv())
+        .await
+        .expect("Circuit breaker command must not wait for the proposal flood to end")
+        .expect("Should receive liquidation order");
+    flood.abort();
+
+    assert_eq!(order.symbol, "TSLA");
+    assert_eq!(order.side, OrderSide::Sell);
+}
+
+#[tokio::test]
+async fn test_max_open_orders_rejects_buys_only() {
+    let (proposal_tx, proposal_rx) = mpsc::channel(1);
+    let (_, risk_cmd_rx) = mpsc::channel(1);
+    let (order_tx, mut order_rx) = mpsc::channel(1);
+    let mut port = Portfolio::new();
+    port.cash = Decimal::from(1000);
+    port.positions.insert(
+        "ABC".to_string(),
+        Position {
+            symbol: "ABC".to_string(),
+            quantity: Decimal::from(2),
+            average_price: Decimal::from(100),
+            lots: VecDeque::new(),
+        },
+    );
+    let portfolio = Arc::new(RwLock::new(port));
+    let exec_service = Arc::new(MockExecutionService::new(portfolio.clone()));
+    let market_service = Arc::new(MockMarketDataService::new());
+
+    let open_order = |id: &str| Order {
+        id: id.to_string(),
+        symbol: "XYZ".to_string(),
+        side: OrderSide::Buy,
+        price: Decimal::from(10),
+        quantity: Decimal::from(1),
+        order_type: OrderType::Limit,
+        status: rustrade::domain::trading::types::OrderStatus::New,
+        timestamp: Utc::now().timestamp_millis(),
+        reduce_only: false,
+        origin: Default::default(),
+        reason: None,
+    };
+    exec_service
+        .set_open_orders(vec![open_order("o1"), open_order("o2")])
+        .await;
+
+    let state_manager = Arc::new(PortfolioStateManager::new(exec_service.clone(), 5000));
+
+    let connection_service = Arc::new(ConnectionHealthService::new());
+    connection_service
+        .set_market_data_status(ConnectionStatus::Online, None)
+        .await;
+
+    let mut rm = RiskManager::new(
+        proposal_rx,
+        risk_cmd_rx,
+        order_tx,
+        exec_service.clone(),
+        market_service,
+        state_manager,
+        false,
+        AssetClass::Stock,
+        RiskConfig {
+            max_open_orders: 2,
+            ..RiskConfig::default()
+        },
+        None,
+        None,
+        None,
+        None,
+        Arc::new(SpreadCache::new()),
+        connection_service,
+        Metrics::default(),
+        Arc::new(
+            rustrade::application::monitoring::agent_status::AgentStatusRegistry::new(
+                rustrade::infrastructure::observability::Metrics::new().unwrap(),
+            ),
+        ),
+    )
+    .expect("Test config should be valid");
+    tokio::spawn(async move { rm.run().await });
+
+    // Buys open a new name (ABC is already held and would trip position sizing)
+    let proposal = |side| TradeProposal {
+        symbol: if side == OrderSide::Buy { "DEF" } else { "ABC" }.to_string(),
+        side,
+        price: Decimal::from(100),
+        quantity: Decimal::from(1),
+        order_type: OrderType::Market,
+        reason: "Test Max Open Orders".to_string(),
+        timestamp: Utc::now().timestamp_millis(),
+        stop_loss: None,
+        take_profit: None,
+        reduce_only: false,
+        origin: Default::default(),
+    };
+
+    // 1. Two orders already working: buy is rejected
+    proposal_tx.send(proposal(OrderSide::Buy)).await.unwrap();
+    tokio::time::sleep(std::time::Duration::from_millis(100)).await;
+    assert!(
+        order_rx.try_recv().is_err(),
+        "Buy should be rejected at the open orders cap"
+    );
+
+    // 2. Exits are never capped
+    proposal_tx.send(proposal(OrderSide::Sell)).await.unwrap();
+    let order = tokio::time::timeout(std::time::Duration::from_millis(2500), order_rx.recv())
+        .await
+        .expect("Should not timeout")
+        .expect("Sell should pass the open orders cap");
+    assert_eq!(order.side, OrderSide::Sell);
+
+    // 3. One order fills: back under the cap
+    exec_service.set_open_orders(vec![open_order("o1")]).await;
+    proposal_tx.send(proposal(OrderSide::Buy)).await.unwrap();
+    let order = tokio::time::timeout(std::time::Duration::from_millis(2500), order_rx.recv())
+        .await
+        .expect("Should not timeout")
+        .expect("Buy should pass below the open orders cap");
+    assert_eq!(order.side, OrderSide::Buy);
+}
+
+#[tokio::test]
+async fn test_short_entry_and_cover_are_gated_by_direction() {
+    let (proposal_tx, proposal_rx) = mpsc::channel(1);
+    let (risk_cmd_tx, risk_cmd_rx) = mpsc::channel(1);
+    let (order_tx, mut order_rx) = mpsc::channel(1);
+    let mut port = Portfolio::new();
+    port.cash = Decimal::from(1000);
+    port.positions.insert(
+        "ABC".to_string(),
+        Position {
+            symbol: "ABC".to_string(),
+            quantity: Decimal::from(-2),
+            average_price: Decimal::from(10),
+            lots: VecDeque::new(),
+        },
+    );
+    let portfolio = Arc::new(RwLock::new(port));
+    let exec_service = Arc::new(MockExecutionService::new(portfolio.clone()));
+    let market_service = Arc::new(MockMarketDataService::new());
+
+    let state_manager = Arc::new(PortfolioStateManager::new(exec_service.clone(), 5000));
+
+    let connection_service = Arc::new(ConnectionHealthService::new());
+    connection_service
+        .set_market_data_status(ConnectionStatus::Online, None)
+        .await;
+
+    let mut rm = RiskManager::new(
+        proposal_rx,
+        risk_cmd_rx,
+        order_tx,
+        exec_service,
+        market_service,
+        state_manager,
+        false,
+        AssetClass::Stock,
+        RiskConfig::default(),
+        None,
+        None,
+        None,
+        None,
+        Arc::new(SpreadCache::new()),
+        connection_service,
+        Metrics::default(),
+        Arc::new(
+            rustrade::application::monitoring::agent_status::AgentStatusRegistry::new(
+                rustrade::infrastructure::observability::Metrics::new().unwrap(),
+            ),
+        ),
+    )
+    .expect("Test config should be valid");
+    tokio::spawn(async move { rm.run().await });
+
+    let proposal = |symbol: &str, side, quantity: i64| TradeProposal {
+        symbol: symbol.to_string(),
+        side,
+        price: Decimal::from(10),
+        quantity: Decimal::from(quantity),
+        order_type: OrderType::Market,
+        reason: "Test Shorts".to_string(),
+        timestamp: Utc::now().timestamp_millis(),
+        stop_loss: None,
+        take_profit: None,
+        reduce_only: false,
+        origin: Default::default(),
+    };
+
+    // 1. Paused: opening a short is an entry and is rejected
+    risk_cmd_tx.send(RiskCommand::Pause).await.unwrap();
+    tokio::time::sleep(std::time::Duration::from_millis(50)).await;
+    proposal_tx
+        .send(proposal("DEF", OrderSide::Sell, 1))
+        .await
+        .unwrap();
+    tokio::time::sleep(std::time::Duration::from_millis(100)).await;
+    assert!(
+        order_rx.try_recv().is_err(),
+        "Short entry should be rejected while trading is paused"
+    );
+
+    // 2. Paused: covering the existing short is an exit and goes through
+    proposal_tx
+        .send(proposal("ABC", OrderSide::Buy, 2))
+        .await
+        .unwrap();
+    let order = tokio::time::timeout(std::time::Duration::from_millis(2500), order_rx.recv())
+        .await
+        .expect("Should not timeout")
+        .expect("Cover should pass while paused");
+    assert_eq!(order.symbol, "ABC");
+    assert_eq!(order.side, OrderSide::Buy);
+}
+
+#[tokio::test]
+async fn test_update_config_refreshes_short_buying_power_check() {
+    let (proposal_tx, proposal_rx) = mpsc::channel(1);
+    let (risk_cmd_tx, risk_cmd_rx) = mpsc::channel(1);
+    let (order_tx, mut order_rx) = mpsc::channel(1);
+    let mut port = Portfolio::new();
+    port.cash = Decimal::from(1000);
+    let portfolio = Arc::new(RwLock::new(port));
+    let exec_service = Arc::new(MockExecutionService::new(portfolio.clone()));
+    let market_service = Arc::new(MockMarketDataService::new());
+
+    let state_manager = Arc::new(PortfolioStateManager::new(exec_service.clone(), 5000));
+
+    let connection_service = Arc::new(ConnectionHealthService::new());
+    connection_service
+        .set_market_data_status(ConnectionStatus::Online, None)
+        .await;
+
+    let config = |allow_shorts| RiskConfig {
+        max_position_size_pct: Decimal::ONE,
+        allow_shorts,
+        ..RiskConfig::default()
+    };
+
+    let mut rm = RiskManager::new(
+        proposal_rx,
+        risk_cmd_rx,
+        order_tx,
+        exec_service,
+        market_service,
+        state_manager,
+        false,
+        AssetClass::Stock,
+        config(true),
+        None,
+        None,
+        None,
+        None,
+        Arc::new(SpreadCache::new()),
+        connection_service,
+        Metrics::default(),
+        Arc::new(
+            rustrade::application::monitoring::agent_status::AgentStatusRegistry::new(
+                rustrade::infrastructure::observability::Metrics::new().unwrap(),
+            ),
+        ),
+    )
+    .expect("Test config should be valid");
+    tokio::spawn(async move { rm.run().await });
+
+    // $970 short: within cash, but above the 95% safety margin
+    let short = || TradeProposal {
+        symbol: "DEF".to_string(),
+        side: OrderSide::Sell,
+        price: Decimal::from(10),
+        quantity: Decimal::from(97),
+        order_type: OrderType::Market,
+        reason: "Test Short Config".to_string(),
+        timestamp: Utc::now().timestamp_millis(),
+        stop_loss: None,
+        take_profit: None,
+        reduce_only: false,
+        origin: Default::default(),
+    };
+
+    // 1. Shorts are checked against buying power
+    proposal_tx.send(short()).await.unwrap();
+    tokio::time::sleep(std::time::Duration::from_millis(100)).await;
+    assert!(
+        order_rx.try_recv().is_err(),
+        "Short should be rejected by the buying power check"
+    );
+
+    // 2. A config update rebuilds the validators with the new setting
+    risk_cmd_tx
+        .send(RiskCommand::UpdateConfig(Box::new(config(false))))
+        .await
+        .unwrap();
+    tokio::time::sleep(std::time::Duration::from_millis(50)).await;
+    proposal_tx.send(short()).await.unwrap();
+    let order = tokio::time::timeout(std::time::Duration::from_millis(2500), order_rx.recv())
+        .await
+        .expect("Should not timeout")
+        .expect("Short should skip the buying power check once disabled");
+    assert_eq!(order.side, OrderSide::Sell);
+}
+
+#[tokio::test]
+async fn test_rebalance_trims_to_live_position_limit() {
+    let (_proposal_tx, proposal_rx) = mpsc::channel(1);
+    let (risk_cmd_tx, risk_cmd_rx) = mpsc::channel(1);
+    let (order_tx, mut order_rx) = mpsc::channel(1);
+    let mut port = Portfolio::new();
+    port.cash = Decimal::from(8000);
+    // 2000 of 10000 equity: 20%
+    port.positions.insert(
+        "ABC".to_string(),
+        Position {
+            symbol: "ABC".to_string(),
+            quantity: Decimal::from(20),
+            average_price: Decimal::from(100),
+            lots: VecDeque::new(),
+        },
+    );
+    let portfolio = Arc::new(RwLock::new(port));
+    let exec_service = Arc::new(MockExecutionService::new(portfolio.clone()));
+    let market_data = ConfigurableMockMarketData::new();
+    market_data.set_price("ABC", Decimal::from(100));
+    let market_service = Arc::new(market_data);
+
+    let state_manager = Arc::new(PortfolioStateManager::new(exec_service.clone(), 5000));
+
+    let connection_service = Arc::new(ConnectionHealthService::new());
+    connection_service
+        .set_market_data_status(ConnectionStatus::Online, None)
+        .await;
+
+    let mut rm = RiskManager::new(
+        proposal_rx,
+        risk_cmd_rx,
+        order_tx,
+        exec_service,
+        market_service,
+        state_manager,
+        false,
+        AssetClass::Stock,
+        RiskConfig::default(),
+        None,
+        None,
+        None,
+        None,
+        Arc::new(SpreadCache::new()),
+        connection_service,
+        Metrics::default(),
+        Arc::new(
+            rustrade::application::monitoring::agent_status::AgentStatusRegistry::new(
+                rustrade::infrastructure::observability::Metrics::new().unwrap(),
+            ),
+        ),
+    )
+    .expect("Test config should be valid");
+    tokio::spawn(async move { rm.run().await });
+
+    // Raise the limit from 10% to 15% after startup
+    risk_cmd_tx
+        .send(RiskCommand::UpdateConfig(Box::new(RiskConfig {
+            max_position_size_pct: dec!(0.15),
+            ..RiskConfig::default()
+        })))
+        .await
+        .unwrap();
+    risk_cmd_tx
+        .send(RiskCommand::Rebalance {
+            band: Decimal::ZERO,
+        })
+        .await
+        .unwrap();
+
+    let order = tokio::time::timeout(std::time::Duration::from_millis(2500), order_rx.recv())
+        .await
+        .expect("Should not timeout")
+        .expect("Rebalance should submit a trim");
+    assert_eq!(order.symbol, "ABC");
+    assert_eq!(order.side, OrderSide::Sell);
+    assert!(order.reduce_only);
+    // Trimmed back to 15% of equity, not the 10% the manager started with
+    assert_eq!(order.quantity, Decimal::from(5));
+}
+
+#[tokio::test]
+async fn test_exit_rounded_up_instead_of_leaving_dust() {
+    let (proposal_tx, proposal_rx) = mpsc::channel(1);
+    let (_risk_cmd_tx, risk_cmd_rx) = mpsc::channel(1);
+    let (order_tx, mut order_rx) = mpsc::channel(1);
+    let mut port = Portfolio::new();
+    port.cash = Decimal::from(1000);
+    for (symbol, quantity) in [("ABC", dec!(0.03)), ("DEF", dec!(0.005))] {
+        port.positions.insert(
+            symbol.to_string(),
+            Position {
+                symbol: symbol.to_string(),
+                quantity,
+                average_price: Decimal::from(1000),
+                lots: VecDeque::new(),
+            },
+        );
+    }
+    let portfolio = Arc::new(RwLock::new(port));
+    let exec_service = Arc::new(MockExecutionService::new(portfolio.clone()).with_lot_size(
+        LotSize {
+            step_size: dec!(0.001),
+            min_qty: Decimal::ZERO,
+            min_notional: Decimal::from(10),
+            tick_size: Decimal::ZERO,
+        },
+    ));
+    let market_service = Arc::new(MockMarketDataService::new());
+
+    let state_manager = Arc::new(PortfolioStateManager::new(exec_service.clone(), 5000));
+
+    let connection_service = Arc::new(ConnectionHealthService::new());
+    connection_service
+        .set_market_data_status(ConnectionStatus::Online, None)
+        .await;
+
+    let mut rm = RiskManager::new(
+        proposal_rx,
+        risk_cmd_rx,
+        order_tx,
+        exec_service,
+        market_service,
+        state_manager,
+        false,
+        AssetClass::Stock,
+        RiskConfig::default(),
+        None,
+        None,
+        None,
+        None,
+        Arc::new(SpreadCache::new()),
+        connection_service,
+        Metrics::default(),
+        Arc::new(
+            rustrade::application::monitoring::agent_status::AgentStatusRegistry::new(
+                rustrade::infrastructure::observability::Metrics::new().unwrap(),
+            ),
+        ),
+    )
+    .expect("Test config should be valid");
+    tokio::spawn(async move { rm.run().await });
+
+    let proposal = |symbol: &str, quantity| TradeProposal {
+        symbol: symbol.to_string(),
+        side: OrderSide::Sell,
+        price: Decimal::from(1000),
+        quantity,
+        order_type: OrderType::Market,
+        reason: "Test Lot Size".to_string(),
+        timestamp: Utc::now().timestamp_millis(),
+        stop_loss: None,
+        take_profit: None,
+        reduce_only: false,
+        origin: Default::default(),
+    };
+
+    // 1. The whole remaining position is below the minimum notional: rejected, not sent
+    proposal_tx
+        .send(proposal("DEF", dec!(0.005)))
+        .await
+        .unwrap();
+    tokio::time::sleep(std::time::Duration::from_millis(100)).await;
+    assert!(
+        order_rx.try_recv().is_err(),
+        "An exit the broker cannot accept should be rejected"
+    );
+
+    // 2. Selling 0.025 would leave $5 behind, below the minimum notional: sell it all
+    proposal_tx
+        .send(proposal("ABC", dec!(0.025)))
+        .await
+        .unwrap();
+    let order = tokio::time::timeout(std::time::Duration::from_millis(2500), order_rx.recv())
+        .await
+        .expect("Should not timeout")
+        .expect("Exit should be sent");
+    assert_eq!(order.symbol, "ABC");
+    assert_eq!(order.quantity, dec!(0.03));
+}
@@ -20,7 +20,7 @@ use rustrade::domain::risk::state::RiskState;
 use rustrade::domain::risk::volatility_manager::{VolatilityConfig, VolatilityManager};
 use rustrade::domain::trading::portfolio::{Portfolio, Position};
 use rustrade::domain::trading::types::{Candle, MarketEvent, Order};
-use std::collections::HashMap;
+use std::collections::{HashMap, VecDeque};
 use std::sync::Arc;
 use tokio::sync::{RwLock, mpsc};
 
@@ -145,6 +145,7 @@ async fn test_session_manager_integration() {
             symbol: "AAPL".to_string(),
             quantity: Decimal::from(10),
             average_price: Decimal::from(140),
+            lots: VecDeque::new(),
         },
     );
 
@@ -231,6 +232,7 @@ async fn test_liquidation_service_integration() {
             symbol: "AAPL".to_string(),
             quantity: Decimal::from(10),
             average_price: Decimal::from(150),
+            lots: VecDeque::new(),
         },
     );
     portfolio.positions.insert(
@@ -239,6 +241,7 @@ async fn test_liquidation_service_integration() {
             symbol: "GOOGL".to_string(),
             quantity: Decimal::from(5),
             average_price: Decimal::from(140),
+            lots: VecDeque::new(),
         },
     );
 
@@ -8,6 +8,8 @@ use rustrade::application::monitoring::connection_health_service::{
     ConnectionHealthService, ConnectionStatus,
 };
 use rustrade::application::strategies::DualSMAStrategy;
+use rustrade::domain::market::timeframe::Timeframe;
+use rustrade::domain::risk::session_boundary::TradingDayBoundary;
 use rustrade::domain::trading::portfolio::Portfolio;
 use rustrade::domain::trading::types::{Candle, MarketEvent, OrderSide};
 use rustrade::infrastructure::mock::{MockExecutionService, MockMarketDataService};
@@ -90,6 +92,8 @@ async fn test_repro_dynamic_empty_portfolio_buys() {
         ema_fast_period: 50,
         ema_slow_period: 150,
         take_profit_pct: dec!(0.05),
+        take_profit_mode: Default::default(),
+        take_profit_atr_multiplier: dec!(3.0),
         min_hold_time_minutes: 0,
         signal_confirmation_bars: 1,
         spread_bps: dec!(5.0),
@@ -120,6 +124,33 @@ async fn test_repro_dynamic_empty_portfolio_buys() {
         orderflow_volume_profile_lookback: 100,
         ensemble_weights: Default::default(),
         ensemble_voting_threshold: dec!(0.5),
+        trading_windows: Default::default(),
+        news_dedup_window_seconds: 300,
+        news_trade_size_multiplier: dec!(1.0),
+        news_trade_max_value_usd: None,
+        news_reentry_cooldown_seconds: 0,
+        regime_detection_method: Default::default(),
+        hurst_lookback: 50,
+        relative_stop_benchmark: None,
+        relative_stop_pct: dec!(0.05),
+        pyramid_max_adds: 0,
+        pyramid_trigger_pct: dec!(0.02),
+        exits_bypass_cost_filters: true,
+        allow_shorts: false,
+        require_htf_confirmation: false,
+        trend_timeframe: Timeframe::OneHour,
+        max_trades_per_symbol_per_day: 0,
+        open_blackout_minutes: 0,
+        trading_day_boundary: TradingDayBoundary::default(),
+        post_stop_cooldown_seconds: 0,
+        trailing_stop_vol_scale_min: Decimal::ONE,
+        trailing_stop_vol_scale_max: Decimal::ONE,
+        candle_history_len: 0,
+        candle_gap_policy: Default::default(),
+        candle_gap_max_bars: 60,
+        cold_start_reward_risk_ratio: dec!(1.0),
+        cold_start_min_trades: 10,
+        confirm_strategy: None,
     };
 
     let strategy = Arc::new(DualSMAStrategy::new(2, 3, dec!(0.0)));
@@ -52,6 +52,8 @@ async fn test_stress_daily_loss_breach_rejects_proposal() {
         timestamp: 0,
         stop_loss: None,
         take_profit: None,
+        reduce_only: false,
+        origin: Default::default(),
     };
 
     let portfolio = Portfolio::new();
@@ -78,7 +80,10 @@ async fn test_stress_daily_loss_breach_rejects_proposal() {
 
     let result = validator.validate(&ctx).await;
     match &result {
-        ValidationResult::Reject(reason) => assert!(reason.contains("Daily loss")),
+        ValidationResult::Reject { validator, reason } => {
+            assert_eq!(validator, "CircuitBreakerValidator");
+            assert!(reason.contains("Daily loss"));
+        }
         _ => panic!("Expected Reject for daily loss breach, got {:?}", result),
     }
 }
@@ -6,7 +6,7 @@ use rustrade::application::monitoring::connection_health_service::{
     ConnectionHealthService, ConnectionStatus,
 };
 use rustrade::application::system::Application;
-use rustrade::config::{Config, Mode};
+use rustrade::config::{Config, Mode, ProposalChannelPolicy};
 use rustrade::domain::ports::ExecutionService;
 use rustrade::domain::trading::types::{MarketEvent, OrderSide};
 use rustrade::infrastructure::mock::{MockExecutionService, MockMarketDataService};
@@ -41,6 +41,13 @@ async fn test_e2e_golden_cross_buy() -> anyhow::Result<()> {
     let mut config = Config::from_env().unwrap_or_else(|_| Config {
         // Fallback minimal config if env missing (though .env.example exists)
         mode: Mode::Mock,
+        warmup_fallback_source: None,
+        require_warmup: false,
+        warmup_concurrency: 4,
+        candle_persist_batch_size: 1,
+        candle_persist_flush_ms: 0,
+        proposal_channel_policy: ProposalChannelPolicy::DropNewest,
+        channel_capacities: Default::default(),
         alpaca_api_key: "".into(),
         alpaca_secret_key: "".into(),
         alpaca_base_url: "".into(),
@@ -56,9 +63,20 @@ async fn test_e2e_golden_cross_buy() -> anyhow::Result<()> {
         order_cooldown_seconds: 0,
         risk_per_trade_percent: dec!(0.01),
         max_orders_per_minute: 100,
+        max_open_orders: 0,
+        max_trades_per_symbol_per_day: 0,
+        open_blackout_minutes: 0,
+        post_stop_cooldown_seconds: 0,
+        order_submit_max_retries: 0,
+        order_submit_backoff_ms: 0,
+        order_submit_max_retry_ms: 0,
         non_pdt_mode: false,
         dynamic_symbol_mode: false,
         dynamic_scan_interval_minutes: 60,
+        scanner_sector_rotation: false,
+        scanner_max_symbols: 0,
+        scanner_min_price: Decimal::ZERO,
+        scanner_max_price: None,
         strategy_mode: rustrade::config::StrategyMode::Dynamic,
         trend_sma_period: 50,
         rsi_period: 14,
@@ -68,12 +86,21 @@ async fn test_e2e_golden_cross_buy() -> anyhow::Result<()> {
         trend_divergence_threshold: dec!(0.005),
         rsi_threshold: dec!(99.0),
         trailing_stop_atr_multiplier: dec!(3.0),
+        trailing_stop_vol_scale_min: Decimal::ONE,
+        trailing_stop_vol_scale_max: Decimal::ONE,
+        candle_history_len: 0,
+        candle_gap_policy: Default::default(),
+        candle_gap_max_bars: 60,
+        cold_start_reward_risk_ratio: dec!(1.0),
+        cold_start_min_trades: 10,
+        confirm_strategy: None,
         atr_period: 14,
         max_position_size_pct: dec!(0.25),
         max_daily_loss_pct: dec!(0.02),
         max_drawdown_pct: dec!(0.10),
         consecutive_loss_limit: 3,
         pending_order_ttl_ms: None,
+        daily_reset: None,
         slippage_pct: dec!(0.001),
         commission_per_share: dec!(0.001),
         trend_riding_exit_buffer_pct: dec!(0.03),
@@ -81,10 +108,19 @@ async fn test_e2e_golden_cross_buy() -> anyhow::Result<()> {
         mean_reversion_bb_period: 20,
         risk_appetite: None,
         max_sector_exposure_pct: dec!(0.3),
+        max_gross_exposure_pct: Decimal::ZERO,
         sector_map: std::collections::HashMap::new(),
         adaptive_optimization_enabled: false,
         regime_detection_window: 20,
         adaptive_evaluation_hour: 0,
+        adaptive_lookback_days: 90,
+        adaptive_max_period_step_pct: 0.20,
+        adaptive_max_risk_step_pct: 0.20,
+        adaptive_rollback_sharpe_drop: 0.25,
+        adaptive_rollback_drawdown_rise: 0.02,
+        adaptive_shadow_days: 7,
+        adaptive_shadow_min_improvement_pct: dec!(0.5),
+        load_optimal_parameters: false,
         asset_class: rustrade::config::AssetClass::Stock,
         oanda_api_key: "".to_string(),
         oanda_account_id: "".to_string(),
@@ -94,13 +130,31 @@ async fn test_e2e_golden_cross_buy() -> anyhow::Result<()> {
         ema_fast_period: 50,
         ema_slow_period: 150,
         take_profit_pct: dec!(0.05),
+        take_profit_mode: Default::default(),
+        take_profit_atr_multiplier: dec!(3.0),
         max_position_value_usd: dec!(5000.0),
         min_hold_time_minutes: 0,
         signal_confirmation_bars: 1,
         spread_bps: dec!(0.0),
+        crypto_fee_tiers: vec![],
+        symbol_cost_overrides: std::collections::HashMap::new(),
         min_profit_ratio: dec!(0.0),
+        win_rate_provider: Default::default(),
+        win_rate_default: 0.5,
+        win_rate_min_trades: 10,
+        win_rate_prior_weight: 10.0,
+        win_rate_prior_alpha: 1.0,
+        win_rate_prior_beta: 1.0,
+        expectancy_lookback: 0,
+        expectancy_halflife: 0,
         portfolio_staleness_ms: 3000,
+        portfolio_max_age_ms: 0,
         portfolio_refresh_interval_ms: 60000,
+        position_reconcile_interval_secs: 0,
+        rebalance_interval_minutes: 0,
+        rebalance_band_pct: rust_decimal_macros::dec!(0.05),
+        position_reconcile_correct: false,
+        lot_tracking_enabled: false,
         macd_requires_rising: true,
         trend_tolerance_pct: dec!(0.0),
         macd_min_threshold: dec!(0.0),
@@ -114,9 +168,11 @@ async fn test_e2e_golden_cross_buy() -> anyhow::Result<()> {
         binance_secret_key: "".to_string(),
         binance_base_url: "".to_string(),
         binance_ws_url: "".to_string(),
+        binance_exchange_info_refresh_secs: 3600,
         observability_enabled: false, // Disable for tests
         observability_port: 9090,
         observability_bind_address: "127.0.0.1".to_string(),
+        decision_log_path: None,
         primary_timeframe: rustrade::domain::market::timeframe::Timeframe::OneMin,
         enabled_timeframes: vec![rustrade::domain::market::timeframe::Timeframe::OneMin],
         trend_timeframe: rustrade::domain::market::timeframe::Timeframe::OneHour,
@@ -125,8 +181,38 @@ async fn test_e2e_golden_cross_buy() -> anyhow::Result<()> {
         simulation_latency_base_ms: 0,
         simulation_latency_jitter_ms: 0,
         simulation_slippage_volatility: dec!(0.0),
+        backtest_order_throttle: true,
         use_real_market_data: false,
+        initial_cash: dec!(100000),
         ensemble_voting_threshold: dec!(0.5),
+        trading_windows_stock: Default::default(),
+        trading_windows_crypto: Default::default(),
+        earnings_blackout_days: 0,
+        earnings_flatten: false,
+        finnhub_api_key: None,
+        news_provider: Default::default(),
+        news_feed_url: None,
+        news_poll_interval_seconds: 60,
+        news_sentiment_threshold: 0.3,
+        news_provider_sentiment_threshold: 0.3,
+        news_dedup_window_seconds: 300,
+        news_trade_size_multiplier: dec!(1.0),
+        news_trade_max_value_usd: None,
+        news_reentry_cooldown_seconds: 0,
+        regime_detection_method: Default::default(),
+        hurst_lookback: 50,
+        relative_stop_benchmark: None,
+        relative_stop_pct: dec!(0.05),
+        pyramid_max_adds: 0,
+        pyramid_trigger_pct: dec!(0.02),
+        exits_bypass_cost_filters: true,
+        allow_shorts: false,
+        require_htf_confirmation: false,
+        allow_average_down: false,
+        max_average_down_loss_pct: dec!(0.02),
+        min_order_notional: Decimal::ZERO,
+        allow_min_notional_bump: false,
+        leverage: dec!(1),
     });
 
     config.mode = Mode::Mock;
@@ -236,6 +322,11 @@ async fn test_e2e_golden_cross_buy() -> anyhow::Result<()> {
     let trigger_repo = std::sync::Arc::new(
         rustrade::infrastructure::persistence::repositories::SqliteReoptimizationTriggerRepository::new(db.pool.clone())
     );
+    let shadow_trial_repo = std::sync::Arc::new(
+        rustrade::infrastructure::persistence::repositories::SqliteShadowTrialRepository::new(
+            db.pool.clone(),
+        ),
+    );
 
     let persistence = rustrade::application::bootstrap::persistence::PersistenceHandle {
         db,
@@ -248,6 +339,7 @@ async fn test_e2e_golden_cross_buy() -> anyhow::Result<()> {
         opt_history_repo,
         snapshot_repo,
         trigger_repo,
+        shadow_trial_repo,
     };
 
     // --- Services Setup ---
@@ -54,6 +54,8 @@ fn create_context(candles: VecDeque<Candle>) -> AnalysisContext {
         realized_volatility: None,
         feature_set: None,
         position: None,
+        plus_di: None,
+        minus_di: None,
     }
 }
 